@@ -32,6 +32,9 @@ mod imp {
     const DEFAULT_COMPRESS: bool = false;
     const DEFAULT_IRADIO_MODE: bool = true;
     const DEFAULT_KEEP_ALIVE: bool = true;
+    const DEFAULT_RETRIES: i32 = 5;
+    const DEFAULT_RETRY_BACKOFF_FACTOR: f64 = 0.5;
+    const DEFAULT_RETRY_BACKOFF_MAX: f64 = 8.0;
     /// Buffer size when serving the preloaded head, sized like a typical
     /// network chunk so downstream behaves identically.
     const PRELOADED_HEAD_CHUNK: u64 = 256 * 1024;
@@ -48,6 +51,13 @@ mod imp {
         cookies: Vec<String>,
         iradio_mode: bool,
         keep_alive: bool,
+        /// Reconnect attempts after the connection is lost mid-stream before
+        /// the error surfaces (-1 = unlimited, 0 = none).
+        retries: i32,
+        /// Seconds before the first reconnect attempt, doubled per attempt.
+        retry_backoff_factor: f64,
+        /// Upper bound in seconds for a single reconnect delay.
+        retry_backoff_max: f64,
         /// Already-downloaded first bytes of the resource, served from memory
         /// before any network request. The first read past it opens the
         /// connection at that offset. Only valid together with `preloaded_size`
@@ -70,6 +80,9 @@ mod imp {
                 cookies: Vec::new(),
                 iradio_mode: DEFAULT_IRADIO_MODE,
                 keep_alive: DEFAULT_KEEP_ALIVE,
+                retries: DEFAULT_RETRIES,
+                retry_backoff_factor: DEFAULT_RETRY_BACKOFF_FACTOR,
+                retry_backoff_max: DEFAULT_RETRY_BACKOFF_MAX,
                 preloaded_head: None,
                 preloaded_size: 0,
             }
@@ -108,9 +121,72 @@ mod imp {
             /// lazy head-to-network transition re-requests the same
             /// range.
             stop: Option<u64>,
+            /// Identity of the resource as first served, checked when a lost
+            /// connection is resumed.
+            validators: Validators,
         },
     }
 
+    /// `ETag` and `Last-Modified` of a response. A reconnect sends them as
+    /// `If-Range` and refuses a response whose own validators differ, so
+    /// bytes of a replaced file are never spliced onto the old one.
+    #[derive(Debug, Clone, Default)]
+    struct Validators {
+        etag: Option<reqwest::header::HeaderValue>,
+        last_modified: Option<reqwest::header::HeaderValue>,
+    }
+
+    impl Validators {
+        fn from_headers(headers: &reqwest::header::HeaderMap) -> Self {
+            use reqwest::header;
+
+            Validators {
+                etag: headers.get(header::ETAG).cloned(),
+                last_modified: headers.get(header::LAST_MODIFIED).cloned(),
+            }
+        }
+
+        /// The `If-Range` value: a strong ETag, else the modification date.
+        /// Weak ETags are not allowed in `If-Range`.
+        fn if_range(&self) -> Option<&reqwest::header::HeaderValue> {
+            self.etag
+                .as_ref()
+                .filter(|etag| !etag.as_bytes().starts_with(b"W/"))
+                .or(self.last_modified.as_ref())
+        }
+
+        /// Whether `other` describes a different resource. Only validators
+        /// present on both sides count: a server dropping a header on the
+        /// resumed response proves nothing.
+        fn conflicts_with(&self, other: &Validators) -> bool {
+            let differs =
+                |a: &Option<_>, b: &Option<_>| matches!((a, b), (Some(a), Some(b)) if a != b);
+            differs(&self.etag, &other.etag) || differs(&self.last_modified, &other.last_modified)
+        }
+    }
+
+    /// Why a request or read failed.
+    #[derive(Debug)]
+    enum RequestError {
+        /// Interrupted by `unlock` (flush or state change).
+        Flushing,
+        /// The connection failed, timed out or the server is temporarily
+        /// unavailable. Reconnecting may succeed.
+        Transient(gst::ErrorMessage),
+        /// Retrying won't help (missing, forbidden or changed resource).
+        Fatal(gst::ErrorMessage),
+    }
+
+    impl RequestError {
+        /// The error to post, `None` when flushing.
+        fn into_message(self) -> Option<gst::ErrorMessage> {
+            match self {
+                RequestError::Flushing => None,
+                RequestError::Transient(err) | RequestError::Fatal(err) => Some(err),
+            }
+        }
+    }
+
     #[derive(Default)]
     enum Canceller {
         #[default]
@@ -242,12 +318,16 @@ mod imp {
             Ok(client)
         }
 
+        /// Request `start..stop` of `uri`. `resume` carries the validators of
+        /// the interrupted response when reconnecting: they go out as
+        /// `If-Range` and the new response must match them.
         fn do_request(
             &self,
             uri: Url,
             start: u64,
             stop: Option<u64>,
-        ) -> Result<State, Option<gst::ErrorMessage>> {
+            resume: Option<&Validators>,
+        ) -> Result<State, RequestError> {
             use headers::{
                 Connection, ContentLength, ContentRange, HeaderMapExt, Range, UserAgent,
             };
@@ -277,6 +357,12 @@ mod imp {
                 }
             }
 
+            if start != 0
+                && let Some(if_range) = resume.and_then(Validators::if_range)
+            {
+                headers.insert(header::IF_RANGE, if_range.clone());
+            }
+
             headers.typed_insert(settings.user_agent.parse::<UserAgent>().unwrap());
 
             if !settings.compress {
@@ -387,11 +473,11 @@ mod imp {
                 Ok(res) => res,
                 Err(Some(err)) => {
                     gst::debug!(CAT, imp = self, "Error {:?}", err);
-                    return Err(Some(err));
+                    return Err(RequestError::Transient(err));
                 }
                 Err(None) => {
                     gst::debug!(CAT, imp = self, "Flushing");
-                    return Err(None);
+                    return Err(RequestError::Flushing);
                 }
             };
 
@@ -401,7 +487,7 @@ mod imp {
                 match res.status() {
                     StatusCode::NOT_FOUND => {
                         gst::error!(CAT, imp = self, "Resource not found URL={}", res.url());
-                        return Err(Some(gst::error_msg!(
+                        return Err(RequestError::Fatal(gst::error_msg!(
                             gst::ResourceError::NotFound,
                             ["Resource '{}' not found", uri]
                         )));
@@ -411,17 +497,29 @@ mod imp {
                     | StatusCode::FORBIDDEN
                     | StatusCode::PROXY_AUTHENTICATION_REQUIRED => {
                         gst::error!(CAT, imp = self, "Not authorized: {}", res.status());
-                        return Err(Some(gst::error_msg!(
+                        return Err(RequestError::Fatal(gst::error_msg!(
                             gst::ResourceError::NotAuthorized,
                             ["Not Authorized for resource '{}': {}", uri, res.status()]
                         )));
                     }
-                    _ => {
-                        gst::error!(CAT, imp = self, "Request failed: {}", res.status());
-                        return Err(Some(gst::error_msg!(
+                    status => {
+                        gst::error!(CAT, imp = self, "Request failed: {}", status);
+                        let err = gst::error_msg!(
                             gst::ResourceError::OpenRead,
-                            ["Request for '{}' failed: {}", uri, res.status()]
-                        )));
+                            ["Request for '{}' failed: {}", uri, status]
+                        );
+                        // Overload and gateway failures pass; anything else
+                        // is the server's final word.
+                        return Err(
+                            if status.is_server_error()
+                                || status == StatusCode::REQUEST_TIMEOUT
+                                || status == StatusCode::TOO_MANY_REQUESTS
+                            {
+                                RequestError::Transient(err)
+                            } else {
+                                RequestError::Fatal(err)
+                            },
+                        );
                     }
                 }
             }
@@ -450,12 +548,28 @@ mod imp {
             };
 
             if position != start {
-                return Err(Some(gst::error_msg!(
+                // When resuming, a full 200 answer means the `If-Range` no
+                // longer matched: the resource changed.
+                if resume.is_some() {
+                    return Err(RequestError::Fatal(gst::error_msg!(
+                        gst::ResourceError::Read,
+                        ["Can't resume '{}' at {}: the resource changed", uri, start]
+                    )));
+                }
+                return Err(RequestError::Fatal(gst::error_msg!(
                     gst::ResourceError::Seek,
                     ["Failed to seek to {}: Got {}", start, position]
                 )));
             }
 
+            let validators = Validators::from_headers(headers);
+            if resume.is_some_and(|resume| resume.conflicts_with(&validators)) {
+                return Err(RequestError::Fatal(gst::error_msg!(
+                    gst::ResourceError::Read,
+                    ["Can't resume '{}' at {}: the resource changed", uri, start]
+                )));
+            }
+
             let mut caps = headers
                 .get("icy-metaint")
                 .and_then(|s| s.to_str().ok())
@@ -527,6 +641,7 @@ mod imp {
                 tags: if tags.n_tags() > 0 { Some(tags) } else { None },
                 serving_head: false,
                 stop,
+                validators,
             })
         }
 
@@ -557,16 +672,344 @@ mod imp {
                 tags: None,
                 serving_head: true,
                 stop,
+                validators: Validators::default(),
             })
         }
 
+        /// Read the next chunk of the open response, resuming it first if
+        /// the connection broke off.
+        fn create_from_network(&self) -> Result<CreateSuccess, gst::FlowError> {
+            loop {
+                let mut state = self.state.lock();
+                let (response, position, caps, tags) = match *state {
+                    State::Started {
+                        ref mut response,
+                        ref mut position,
+                        ref mut tags,
+                        ref mut caps,
+                        ..
+                    } => (response, position, caps, tags),
+                    State::Stopped => {
+                        gst::element_imp_error!(
+                            self,
+                            gst::LibraryError::Failed,
+                            ["Not started yet"]
+                        );
+
+                        return Err(gst::FlowError::Error);
+                    }
+                };
+
+                let offset = *position;
+
+                let mut current_response = match response.take() {
+                    Some(response) => response,
+                    None => {
+                        gst::error!(CAT, imp = self, "Don't have a response");
+                        gst::element_imp_error!(
+                            self,
+                            gst::ResourceError::Read,
+                            ["Don't have a response"]
+                        );
+
+                        return Err(gst::FlowError::Error);
+                    }
+                };
+
+                let tags = tags.take();
+                let caps = caps.take();
+                drop(state);
+
+                if let Some(caps) = caps {
+                    gst::debug!(CAT, imp = self, "Setting caps {:?}", caps);
+                    self.obj()
+                        .set_caps(&caps)
+                        .map_err(|_| gst::FlowError::NotNegotiated)?;
+                }
+
+                if let Some(tags) = tags {
+                    gst::debug!(CAT, imp = self, "Sending iradio tags {:?}", tags);
+                    self.obj().src_pad().push_event(gst::event::Tag::new(tags));
+                }
+
+                let future = async {
+                    current_response.chunk().await.map_err(move |err| {
+                        gst::error_msg!(
+                            gst::ResourceError::Read,
+                            ["Failed to read chunk at offset {}: {:?}", offset, err]
+                        )
+                    })
+                };
+                let res = self.wait(future);
+
+                let res = match res {
+                    Ok(res) => res,
+                    Err(Some(err)) => {
+                        gst::debug!(CAT, imp = self, "Error {:?}", err);
+                        // The connection broke off: resume at `offset`, the first
+                        // byte not delivered yet.
+                        self.reconnect(err)
+                            .map_err(|err| self.request_failed(err))?;
+                        continue;
+                    }
+                    Err(None) => {
+                        gst::debug!(CAT, imp = self, "Flushing");
+                        return Err(gst::FlowError::Flushing);
+                    }
+                };
+
+                let mut state = self.state.lock();
+                let (response, position) = match *state {
+                    State::Started {
+                        ref mut response,
+                        ref mut position,
+                        ..
+                    } => (response, position),
+                    State::Stopped => {
+                        gst::element_imp_error!(
+                            self,
+                            gst::LibraryError::Failed,
+                            ["Not started yet"]
+                        );
+
+                        return Err(gst::FlowError::Error);
+                    }
+                };
+
+                if let Some(chunk) = res {
+                    if !chunk.is_empty() {
+                        gst::trace!(
+                            CAT,
+                            imp = self,
+                            "Chunk of {} bytes received at offset {}",
+                            chunk.len(),
+                            offset
+                        );
+                        let size = chunk.len();
+
+                        *position += size as u64;
+
+                        let mut buffer = gst::Buffer::from_slice(chunk);
+
+                        *response = Some(current_response);
+
+                        {
+                            let buffer = buffer.get_mut().unwrap();
+                            buffer.set_offset(offset);
+                            buffer.set_offset_end(offset + size as u64);
+                        }
+
+                        return Ok(CreateSuccess::NewBuffer(buffer));
+                    }
+                }
+
+                /* No further data, end of stream */
+                gst::debug!(CAT, imp = self, "End of stream");
+                *response = Some(current_response);
+                return Err(gst::FlowError::Eos);
+            }
+        }
+
+        /// Reconnect after the response broke off with `err`, resuming at the
+        /// first byte not delivered yet. Retries with exponential backoff and
+        /// posts `reconnect` progress messages meanwhile, so the application
+        /// can show the stall as such rather than as an error. Gives up at once
+        /// when the server can't resume (no range support) or the resource
+        /// changed, and with the last error when the attempts run out.
+        fn reconnect(&self, err: gst::ErrorMessage) -> Result<(), RequestError> {
+            let (uri, position, size, stop, validators) = match *self.state.lock() {
+                State::Started {
+                    ref uri,
+                    position,
+                    size,
+                    seekable,
+                    stop,
+                    ref validators,
+                    ..
+                } if position == 0 || seekable => {
+                    (uri.clone(), position, size, stop, validators.clone())
+                }
+                _ => return Err(RequestError::Fatal(err)),
+            };
+
+            let (retries, backoff_factor, backoff_max) = {
+                let settings = self.settings.lock();
+                (
+                    settings.retries,
+                    settings.retry_backoff_factor,
+                    settings.retry_backoff_max,
+                )
+            };
+            if retries == 0 {
+                return Err(RequestError::Fatal(err));
+            }
+
+            gst::warning!(
+                CAT,
+                imp = self,
+                "Connection lost at offset {}, reconnecting: {:?}",
+                position,
+                err
+            );
+
+            let mut last_err = err;
+            let mut attempt = 0;
+            loop {
+                if retries > 0 && attempt >= retries {
+                    gst::error!(CAT, imp = self, "Giving up after {} attempts", attempt);
+                    self.post_reconnect_progress(gst::ProgressType::Error, "Reconnecting failed");
+                    return Err(RequestError::Transient(last_err));
+                }
+                attempt += 1;
+
+                self.post_reconnect_progress(
+                    if attempt == 1 {
+                        gst::ProgressType::Start
+                    } else {
+                        gst::ProgressType::Continue
+                    },
+                    &format!("Reconnecting (attempt {attempt})"),
+                );
+
+                let delay = (backoff_factor * 2f64.powi(attempt.min(31) - 1)).min(backoff_max);
+                gst::debug!(
+                    CAT,
+                    imp = self,
+                    "Reconnect attempt {} in {}s",
+                    attempt,
+                    delay
+                );
+                if self.sleep(Duration::from_secs_f64(delay)).is_err() {
+                    self.post_reconnect_progress(
+                        gst::ProgressType::Canceled,
+                        "Reconnecting canceled",
+                    );
+                    return Err(RequestError::Flushing);
+                }
+
+                let mut resumed =
+                    match self.do_request(uri.clone(), position, stop, Some(&validators)) {
+                        Ok(resumed) => resumed,
+                        Err(RequestError::Transient(err)) => {
+                            gst::debug!(
+                                CAT,
+                                imp = self,
+                                "Reconnect attempt {} failed: {:?}",
+                                attempt,
+                                err
+                            );
+                            last_err = err;
+                            continue;
+                        }
+                        Err(RequestError::Flushing) => {
+                            self.post_reconnect_progress(
+                                gst::ProgressType::Canceled,
+                                "Reconnecting canceled",
+                            );
+                            return Err(RequestError::Flushing);
+                        }
+                        Err(err) => {
+                            self.post_reconnect_progress(
+                                gst::ProgressType::Error,
+                                "Reconnecting failed",
+                            );
+                            return Err(err);
+                        }
+                    };
+
+                if let State::Started {
+                    size: ref resumed_size,
+                    ref mut caps,
+                    ref mut tags,
+                    validators: ref mut resumed_validators,
+                    ..
+                } = resumed
+                {
+                    if size.is_some() && resumed_size.is_some() && *resumed_size != size {
+                        self.post_reconnect_progress(
+                            gst::ProgressType::Error,
+                            "Reconnecting failed",
+                        );
+                        return Err(RequestError::Fatal(gst::error_msg!(
+                            gst::ResourceError::Read,
+                            [
+                                "Can't resume '{}' at {}: the resource changed",
+                                uri,
+                                position
+                            ]
+                        )));
+                    }
+                    // Same stream, so caps and tags are already out. Keep
+                    // the validators the server omitted this time.
+                    *caps = None;
+                    *tags = None;
+                    resumed_validators.etag = resumed_validators.etag.take().or(validators.etag);
+                    resumed_validators.last_modified = resumed_validators
+                        .last_modified
+                        .take()
+                        .or(validators.last_modified);
+                }
+
+                gst::info!(
+                    CAT,
+                    imp = self,
+                    "Resumed at offset {} after {} attempts",
+                    position,
+                    attempt
+                );
+                self.post_reconnect_progress(gst::ProgressType::Complete, "Reconnected");
+                *self.state.lock() = resumed;
+                return Ok(());
+            }
+        }
+
+        fn post_reconnect_progress(&self, type_: gst::ProgressType, text: &str) {
+            let obj = self.obj();
+            let _ = obj.post_message(
+                gst::message::Progress::builder(type_, "reconnect", text)
+                    .src(&*obj)
+                    .build(),
+            );
+        }
+
+        /// Post a failed request's error and map it to the flow return.
+        fn request_failed(&self, err: RequestError) -> gst::FlowError {
+            match err.into_message() {
+                Some(err) => {
+                    self.post_error_message(err);
+                    gst::FlowError::Error
+                }
+                None => gst::FlowError::Flushing,
+            }
+        }
+
+        /// Sleep for `delay` unless unlocked first (`Err`).
+        fn sleep(&self, delay: Duration) -> Result<(), ()> {
+            let future = async {
+                tokio::time::sleep(delay).await;
+                Ok::<_, gst::ErrorMessage>(())
+            };
+            self.wait_with_timeout(future, 0).map_err(|_| ())
+        }
+
         fn wait<F, T>(&self, future: F) -> Result<T, Option<gst::ErrorMessage>>
         where
             F: Send + Future<Output = Result<T, gst::ErrorMessage>>,
             T: Send + 'static,
         {
             let timeout = self.settings.lock().timeout;
+            self.wait_with_timeout(future, timeout)
+        }
 
+        fn wait_with_timeout<F, T>(
+            &self,
+            future: F,
+            timeout: u32,
+        ) -> Result<T, Option<gst::ErrorMessage>>
+        where
+            F: Send + Future<Output = Result<T, gst::ErrorMessage>>,
+            T: Send + 'static,
+        {
             let mut canceller = self.canceller.lock();
             if matches!(*canceller, Canceller::Cancelled) {
                 return Err(None);
@@ -689,6 +1132,32 @@ mod imp {
                         .readwrite()
                         .mutable_ready()
                         .build(),
+                    glib::ParamSpecInt::builder("retries")
+                        .nick("Retries")
+                        .blurb("Maximum number of reconnect attempts when the connection is lost mid-stream (-1 = unlimited, 0 = fail at once)")
+                        .minimum(-1)
+                        .default_value(DEFAULT_RETRIES)
+                        .readwrite()
+                        .mutable_ready()
+                        .build(),
+                    glib::ParamSpecDouble::builder("retry-backoff-factor")
+                        .nick("Retry Backoff Factor")
+                        .blurb("Seconds before the first reconnect attempt, doubled for each further attempt")
+                        .minimum(0.0)
+                        .maximum(3600.0)
+                        .default_value(DEFAULT_RETRY_BACKOFF_FACTOR)
+                        .readwrite()
+                        .mutable_ready()
+                        .build(),
+                    glib::ParamSpecDouble::builder("retry-backoff-max")
+                        .nick("Retry Backoff Max")
+                        .blurb("Maximum seconds between reconnect attempts")
+                        .minimum(0.0)
+                        .maximum(3600.0)
+                        .default_value(DEFAULT_RETRY_BACKOFF_MAX)
+                        .readwrite()
+                        .mutable_ready()
+                        .build(),
                     glib::ParamSpecBoxed::builder::<glib::Bytes>("preloaded-head")
                         .nick("Preloaded Head")
                         .blurb("Already-downloaded first bytes of the resource, served from memory before any request (requires preloaded-size, only valid for range-capable servers)")
@@ -774,6 +1243,21 @@ mod imp {
                     settings.keep_alive = keep_alive;
                     Ok(())
                 }
+                "retries" => {
+                    let mut settings = self.settings.lock();
+                    settings.retries = value.get().expect("type checked upstream");
+                    Ok(())
+                }
+                "retry-backoff-factor" => {
+                    let mut settings = self.settings.lock();
+                    settings.retry_backoff_factor = value.get().expect("type checked upstream");
+                    Ok(())
+                }
+                "retry-backoff-max" => {
+                    let mut settings = self.settings.lock();
+                    settings.retry_backoff_max = value.get().expect("type checked upstream");
+                    Ok(())
+                }
                 "preloaded-head" => {
                     let mut settings = self.settings.lock();
                     settings.preloaded_head = value.get().expect("type checked upstream");
@@ -843,6 +1327,18 @@ mod imp {
                     let settings = self.settings.lock();
                     settings.keep_alive.to_value()
                 }
+                "retries" => {
+                    let settings = self.settings.lock();
+                    settings.retries.to_value()
+                }
+                "retry-backoff-factor" => {
+                    let settings = self.settings.lock();
+                    settings.retry_backoff_factor.to_value()
+                }
+                "retry-backoff-max" => {
+                    let settings = self.settings.lock();
+                    settings.retry_backoff_max.to_value()
+                }
                 "preloaded-head" => {
                     let settings = self.settings.lock();
                     settings.preloaded_head.to_value()
@@ -976,8 +1472,8 @@ mod imp {
                 return Ok(());
             }
 
-            *state = self.do_request(uri, 0, None).map_err(|err| {
-                err.unwrap_or_else(|| {
+            *state = self.do_request(uri, 0, None, None).map_err(|err| {
+                err.into_message().unwrap_or_else(|| {
                     gst::error_msg!(gst::LibraryError::Failed, ["Interrupted during start"])
                 })
             })?;
@@ -1039,16 +1535,17 @@ mod imp {
             }
 
             *state = State::Stopped;
-            match self.do_request(uri, start, stop) {
+            match self.do_request(uri, start, stop, None) {
                 Ok(s) => {
                     *state = s;
                     true
                 }
-                Err(Some(err)) => {
-                    self.post_error_message(err);
+                Err(err) => {
+                    if let Some(err) = err.into_message() {
+                        self.post_error_message(err);
+                    }
                     false
                 }
-                Err(None) => false,
             }
         }
     }
@@ -1110,131 +1607,20 @@ mod imp {
                     position
                 );
                 let uri = uri.clone();
-                match self.do_request(uri, position, stop) {
+                match self.do_request(uri, position, stop, None) {
                     Ok(s) => *state = s,
-                    Err(Some(err)) => {
-                        self.post_error_message(err);
-                        return Err(gst::FlowError::Error);
+                    Err(RequestError::Transient(err)) => {
+                        drop(state);
+                        self.reconnect(err)
+                            .map_err(|err| self.request_failed(err))?;
+                        return self.create_from_network();
                     }
-                    Err(None) => return Err(gst::FlowError::Flushing),
+                    Err(err) => return Err(self.request_failed(err)),
                 }
             }
-
-            let (response, position, caps, tags) = match *state {
-                State::Started {
-                    ref mut response,
-                    ref mut position,
-                    ref mut tags,
-                    ref mut caps,
-                    ..
-                } => (response, position, caps, tags),
-                State::Stopped => {
-                    gst::element_imp_error!(self, gst::LibraryError::Failed, ["Not started yet"]);
-
-                    return Err(gst::FlowError::Error);
-                }
-            };
-
-            let offset = *position;
-
-            let mut current_response = match response.take() {
-                Some(response) => response,
-                None => {
-                    gst::error!(CAT, imp = self, "Don't have a response");
-                    gst::element_imp_error!(
-                        self,
-                        gst::ResourceError::Read,
-                        ["Don't have a response"]
-                    );
-
-                    return Err(gst::FlowError::Error);
-                }
-            };
-
-            let tags = tags.take();
-            let caps = caps.take();
             drop(state);
 
-            if let Some(caps) = caps {
-                gst::debug!(CAT, imp = self, "Setting caps {:?}", caps);
-                self.obj()
-                    .set_caps(&caps)
-                    .map_err(|_| gst::FlowError::NotNegotiated)?;
-            }
-
-            if let Some(tags) = tags {
-                gst::debug!(CAT, imp = self, "Sending iradio tags {:?}", tags);
-                self.obj().src_pad().push_event(gst::event::Tag::new(tags));
-            }
-
-            let future = async {
-                current_response.chunk().await.map_err(move |err| {
-                    gst::error_msg!(
-                        gst::ResourceError::Read,
-                        ["Failed to read chunk at offset {}: {:?}", offset, err]
-                    )
-                })
-            };
-            let res = self.wait(future);
-
-            let res = match res {
-                Ok(res) => res,
-                Err(Some(err)) => {
-                    gst::debug!(CAT, imp = self, "Error {:?}", err);
-                    self.post_error_message(err);
-                    return Err(gst::FlowError::Error);
-                }
-                Err(None) => {
-                    gst::debug!(CAT, imp = self, "Flushing");
-                    return Err(gst::FlowError::Flushing);
-                }
-            };
-
-            let mut state = self.state.lock();
-            let (response, position) = match *state {
-                State::Started {
-                    ref mut response,
-                    ref mut position,
-                    ..
-                } => (response, position),
-                State::Stopped => {
-                    gst::element_imp_error!(self, gst::LibraryError::Failed, ["Not started yet"]);
-
-                    return Err(gst::FlowError::Error);
-                }
-            };
-
-            if let Some(chunk) = res {
-                if !chunk.is_empty() {
-                    gst::trace!(
-                        CAT,
-                        imp = self,
-                        "Chunk of {} bytes received at offset {}",
-                        chunk.len(),
-                        offset
-                    );
-                    let size = chunk.len();
-
-                    *position += size as u64;
-
-                    let mut buffer = gst::Buffer::from_slice(chunk);
-
-                    *response = Some(current_response);
-
-                    {
-                        let buffer = buffer.get_mut().unwrap();
-                        buffer.set_offset(offset);
-                        buffer.set_offset_end(offset + size as u64);
-                    }
-
-                    return Ok(CreateSuccess::NewBuffer(buffer));
-                }
-            }
-
-            /* No further data, end of stream */
-            gst::debug!(CAT, imp = self, "End of stream");
-            *response = Some(current_response);
-            Err(gst::FlowError::Eos)
+            self.create_from_network()
         }
     }
 
@@ -2148,6 +2534,163 @@ mod tests {
         }
     }
 
+    /// Serves `data` with ETag `"v1"`, cutting the first response off after
+    /// 1000 bytes. Resumed requests get the rest as a 206 while the ETag
+    /// still matches, `changed` simulates a replaced file instead.
+    fn truncating_server(
+        data: Vec<u8>,
+        changed: bool,
+    ) -> impl FnMut(
+        hyper::Request<hyper::body::Incoming>,
+    ) -> hyper::Response<BoxBody<bytes::Bytes, hyper::Error>>
+    + Send
+    + 'static {
+        let mut requests = 0;
+        move |req| {
+            requests += 1;
+            let len = data.len();
+            if requests == 1 {
+                // Announces the full length but ends early, so the
+                // connection drops mid-body.
+                return hyper::Response::builder()
+                    .header("content-length", len)
+                    .header("accept-ranges", "bytes")
+                    .header("etag", "\"v1\"")
+                    .body(full_body(data[..1000].to_vec()))
+                    .unwrap();
+            }
+
+            if changed {
+                return hyper::Response::builder()
+                    .header("content-length", len)
+                    .header("accept-ranges", "bytes")
+                    .header("etag", "\"v2\"")
+                    .body(full_body(data.clone()))
+                    .unwrap();
+            }
+
+            let headers = req.headers();
+            let Some(range) = headers.get("range") else {
+                // Nothing was delivered before the drop: start over.
+                return hyper::Response::builder()
+                    .header("content-length", len)
+                    .header("accept-ranges", "bytes")
+                    .header("etag", "\"v1\"")
+                    .body(full_body(data.clone()))
+                    .unwrap();
+            };
+            assert_eq!(headers.get("if-range").unwrap(), "\"v1\"");
+            let start = range
+                .to_str()
+                .unwrap()
+                .strip_prefix("bytes=")
+                .and_then(|range| range.strip_suffix('-'))
+                .and_then(|start| start.parse::<usize>().ok())
+                .expect("open-ended byte range");
+
+            hyper::Response::builder()
+                .status(hyper::StatusCode::PARTIAL_CONTENT)
+                .header("content-length", len - start)
+                .header("accept-ranges", "bytes")
+                .header("content-range", format!("bytes {start}-{}/{len}", len - 1))
+                .header("etag", "\"v1\"")
+                .body(full_body(data[start..].to_vec()))
+                .unwrap()
+        }
+    }
+
+    #[test]
+    fn test_reconnect_resume() {
+        init();
+
+        let mut data = vec![0; 8192];
+        for (i, d) in data.iter_mut().enumerate() {
+            *d = (i % 256) as u8;
+        }
+
+        let mut h = Harness::new(truncating_server(data.clone(), false), |src| {
+            src.set_property("retry-backoff-factor", 0.01);
+        });
+
+        h.run(|src| {
+            src.set_state(gst::State::Playing).unwrap();
+        });
+
+        let mut output = Vec::new();
+        let mut progress = Vec::new();
+        loop {
+            match h.receiver.as_mut().unwrap().recv().unwrap() {
+                // The truncated first response.
+                Message::ServerError(_) => (),
+                Message::Event(ev) => {
+                    if let gst::EventView::Eos(_) = ev.view() {
+                        break;
+                    }
+                }
+                Message::Message(msg) => match msg.view() {
+                    gst::MessageView::Error(err) => {
+                        panic!("Got error: {} ({:?})", err.error(), err.debug());
+                    }
+                    gst::MessageView::Progress(p) => {
+                        let (type_, code, _) = p.get();
+                        assert_eq!(code, "reconnect");
+                        progress.push(type_);
+                    }
+                    _ => (),
+                },
+                Message::Buffer(buffer) => {
+                    assert_eq!(buffer.offset(), output.len() as u64);
+                    output.extend_from_slice(&buffer.map_readable().unwrap());
+                }
+            }
+        }
+
+        assert_eq!(output, data);
+        assert_eq!(progress.first(), Some(&gst::ProgressType::Start));
+        assert_eq!(progress.last(), Some(&gst::ProgressType::Complete));
+    }
+
+    #[test]
+    fn test_reconnect_resource_changed() {
+        init();
+
+        let mut data = vec![0; 8192];
+        for (i, d) in data.iter_mut().enumerate() {
+            *d = (i % 256) as u8;
+        }
+
+        let mut h = Harness::new(truncating_server(data, true), |src| {
+            src.set_property("retry-backoff-factor", 0.01);
+        });
+
+        h.run(|src| {
+            src.set_state(gst::State::Playing).unwrap();
+        });
+
+        let mut progress = Vec::new();
+        let err = loop {
+            match h.receiver.as_mut().unwrap().recv().unwrap() {
+                Message::ServerError(_) | Message::Buffer(_) => (),
+                Message::Event(ev) => {
+                    if let gst::EventView::Eos(_) = ev.view() {
+                        panic!("Got EOS but expected error");
+                    }
+                }
+                Message::Message(msg) => match msg.view() {
+                    gst::MessageView::Error(err) => break err.error(),
+                    gst::MessageView::Progress(p) => progress.push(p.get().0),
+                    _ => (),
+                },
+            }
+        };
+
+        assert_eq!(
+            err.kind::<gst::ResourceError>(),
+            Some(gst::ResourceError::Read)
+        );
+        assert_eq!(progress.last(), Some(&gst::ProgressType::Error));
+    }
+
     #[test]
     fn test_seek_after_ready() {
         use std::io::{Cursor, Read};
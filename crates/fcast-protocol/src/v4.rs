@@ -18,6 +18,14 @@ pub use fcast_flatbuffers::fcast::{v4 as flat, v4::PlaybackState};
 
 pub const MAX_PACKET_SIZE: usize = 512 * 1024;
 
+/// The queue cap of receivers that advertise no `max_queue_items`.
+pub const LEGACY_MAX_QUEUE_ITEMS: u32 = 256;
+
+/// How many items a sender puts in one `Load` or `QueueInsertBatch` page when
+/// sending a long queue. Small enough that a page of items with metadata stays
+/// well under [`MAX_PACKET_SIZE`].
+pub const QUEUE_PAGE_ITEMS: usize = 128;
+
 pub struct ConstructedMessage<'a> {
     builder: flatbuffers::FlatBufferBuilder<'a>,
 }
@@ -153,6 +161,28 @@ impl<'a> MessageBuilder<'a> {
         create_msg!(self, VolumeChanged, volume)
     }
 
+    pub fn seek_relative(mut self, offset_micros: i64) -> ConstructedMessage<'a> {
+        create_msg!(self, SeekRelative, offset_micros)
+    }
+
+    pub fn frame_step(mut self, direction: flat::FrameStepDirection) -> ConstructedMessage<'a> {
+        create_msg!(self, FrameStep, direction)
+    }
+
+    pub fn seek_to_chapter(mut self, target: ChapterTarget) -> ConstructedMessage<'a> {
+        let (target, index) = match target {
+            ChapterTarget::Index(index) => (flat::ChapterTarget::Index, index),
+            ChapterTarget::Next => (flat::ChapterTarget::Next, 0),
+            ChapterTarget::Previous => (flat::ChapterTarget::Previous, 0),
+        };
+        create_msg!(self, SeekToChapter, target, index)
+    }
+
+    pub fn chapters_available(mut self, chapters: &[Chapter]) -> ConstructedMessage<'a> {
+        let chapters = self.build_chapters(chapters);
+        create_msg!(self, ChaptersAvailable, chapters: Some(chapters))
+    }
+
     pub fn speed_changed(mut self, speed: f32) -> ConstructedMessage<'a> {
         create_msg!(self, SpeedChanged, speed)
     }
@@ -238,9 +268,29 @@ impl<'a> MessageBuilder<'a> {
         )
     }
 
-    pub fn sender_introduction(mut self, device_info: &DeviceInfo) -> ConstructedMessage<'a> {
+    pub fn sender_introduction(
+        mut self,
+        device_info: &DeviceInfo,
+        pairing_token: Option<&str>,
+    ) -> ConstructedMessage<'a> {
         let device_info = create_device_info!(self, device_info);
-        create_msg!(self, SenderIntroduction, device_info: Some(device_info))
+        let pairing_token = maybe_create_str!(self, pairing_token);
+        create_msg!(
+            self,
+            SenderIntroduction,
+            device_info: Some(device_info),
+            pairing_token
+        )
+    }
+
+    pub fn pairing_request(mut self, pin: &str) -> ConstructedMessage<'a> {
+        let pin = create_str!(self, pin);
+        create_msg!(self, PairingRequest, pin)
+    }
+
+    pub fn pairing_result(mut self, token: &str) -> ConstructedMessage<'a> {
+        let token = create_str!(self, token);
+        create_msg!(self, PairingResult, token)
     }
 
     pub fn start_mirroring_session(mut self, session_id: u16) -> ConstructedMessage<'a> {
@@ -280,6 +330,7 @@ impl<'a> MessageBuilder<'a> {
             metadata_type: flat::Metadata::NONE,
             metadata: None,
             extra_metadata,
+            resume: item.resume(),
         };
         flat::MediaItem::create(&mut self.builder, &args)
     }
@@ -316,6 +367,7 @@ impl<'a> MessageBuilder<'a> {
                         items: Some(items),
                         start_index: msg.start_index(),
                         autoplay: msg.autoplay(),
+                        wide_start_index: msg.wide_start_index(),
                     },
                 )
                 .as_union_value();
@@ -333,7 +385,9 @@ impl<'a> MessageBuilder<'a> {
         insert: &flat::QueueInsert,
     ) -> Option<ConstructedMessage<'a>> {
         let position = match insert.position_type() {
-            flat::QueuePosition::Index => QueuePosition::Index(insert.position_as_index()?.index()),
+            flat::QueuePosition::Index => {
+                QueuePosition::Index(read_queue_index(&insert.position_as_index()?))
+            }
             flat::QueuePosition::Front => QueuePosition::Front,
             flat::QueuePosition::Back => QueuePosition::Back,
             _ => return None,
@@ -457,6 +511,29 @@ impl<'a> MessageBuilder<'a> {
         ))
     }
 
+    fn build_chapters(
+        &mut self,
+        chapters: &[Chapter],
+    ) -> flatbuffers::WIPOffset<
+        flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<flat::Chapter<'a>>>,
+    > {
+        let chapters = chapters
+            .iter()
+            .map(|chapter| {
+                let start = Self::time_from_secs_f64(chapter.start);
+                let length = chapter.length.and_then(Self::time_from_secs_f64);
+                let args = flat::ChapterArgs {
+                    start: start.as_ref(),
+                    length: length.as_ref(),
+                    title: create_str!(self, chapter.title),
+                    skip_label: maybe_create_str!(self, chapter.skip_label.as_ref()),
+                };
+                flat::Chapter::create(&mut self.builder, &args)
+            })
+            .collect::<Vec<_>>();
+        self.builder.create_vector(&chapters)
+    }
+
     fn construct_media_item(
         &mut self,
         item: MediaItem,
@@ -478,16 +555,22 @@ impl<'a> MessageBuilder<'a> {
         };
 
         let (metadata_type, metadata) = match item.metadata {
-            Some(Metadata::Video { .. }) => {
-                let meta = flat::VideoMetadataArgs { chapters: None };
+            Some(Metadata::Video { chapters, .. }) => {
+                let chapters = (!chapters.is_empty()).then(|| self.build_chapters(&chapters));
+                let meta = flat::VideoMetadataArgs { chapters };
                 let meta = flat::VideoMetadata::create(&mut self.builder, &meta).as_union_value();
                 (flat::Metadata::Video, Some(meta))
             }
-            Some(Metadata::Audio { artist, album }) => {
+            Some(Metadata::Audio {
+                artist,
+                album,
+                chapters,
+            }) => {
+                let chapters = (!chapters.is_empty()).then(|| self.build_chapters(&chapters));
                 let meta = flat::AudioMetadataArgs {
                     artist: maybe_create_str!(self, artist),
                     album: maybe_create_str!(self, album),
-                    chapters: None,
+                    chapters,
                 };
                 let meta = flat::AudioMetadata::create(&mut self.builder, &meta).as_union_value();
                 (flat::Metadata::Audio, Some(meta))
@@ -514,6 +597,7 @@ impl<'a> MessageBuilder<'a> {
             metadata_type,
             metadata,
             extra_metadata,
+            resume: item.resume,
         };
 
         flat::MediaItem::create(&mut self.builder, &item)
@@ -530,9 +614,37 @@ impl<'a> MessageBuilder<'a> {
     pub fn load_queue(
         mut self,
         items: impl Iterator<Item = (MediaItem, Option<f64>)>,
-        start_index: Option<u8>,
+        start_index: Option<u32>,
         autoplay: bool,
     ) -> ConstructedMessage<'a> {
+        let items = self.build_queue_items(items);
+        // Indexes that fit keep the old field so receivers without wide indexes
+        // still read them.
+        let (start_index, wide_start_index) = match start_index.map(u8::try_from) {
+            Some(Ok(index)) => (Some(index), None),
+            Some(Err(_)) => (None, start_index),
+            None => (None, None),
+        };
+        let queue = flat::Queue::create(
+            &mut self.builder,
+            &flat::QueueArgs {
+                items: Some(items),
+                start_index,
+                autoplay,
+                wide_start_index,
+            },
+        )
+        .as_union_value();
+
+        create_msg!(self, Load, source_type: flat::MediaSource::Queue, source: Some(queue))
+    }
+
+    fn build_queue_items(
+        &mut self,
+        items: impl Iterator<Item = (MediaItem, Option<f64>)>,
+    ) -> flatbuffers::WIPOffset<
+        flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<flat::QueueItem<'a>>>,
+    > {
         let items = items
             .map(|(item, playback_duration)| {
                 let item = self.construct_media_item(item);
@@ -546,19 +658,7 @@ impl<'a> MessageBuilder<'a> {
                 )
             })
             .collect::<Vec<_>>();
-
-        let items = self.builder.create_vector(&items);
-        let queue = flat::Queue::create(
-            &mut self.builder,
-            &flat::QueueArgs {
-                items: Some(items),
-                start_index,
-                autoplay,
-            },
-        )
-        .as_union_value();
-
-        create_msg!(self, Load, source_type: flat::MediaSource::Queue, source: Some(queue))
+        self.builder.create_vector(&items)
     }
 
     fn queue_position(
@@ -569,11 +669,22 @@ impl<'a> MessageBuilder<'a> {
         flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>,
     ) {
         match position {
-            QueuePosition::Index(index) => (
-                flat::QueuePosition::Index,
-                flat::QueueIndex::create(&mut self.builder, &flat::QueueIndexArgs { index })
-                    .as_union_value(),
-            ),
+            QueuePosition::Index(index) => {
+                let args = match u8::try_from(index) {
+                    Ok(index) => flat::QueueIndexArgs {
+                        index,
+                        wide_index: None,
+                    },
+                    Err(_) => flat::QueueIndexArgs {
+                        index: 0,
+                        wide_index: Some(index),
+                    },
+                };
+                (
+                    flat::QueuePosition::Index,
+                    flat::QueueIndex::create(&mut self.builder, &args).as_union_value(),
+                )
+            }
             QueuePosition::Front => (
                 flat::QueuePosition::Front,
                 flat::QueueMarkerFront::create(&mut self.builder, &flat::QueueMarkerFrontArgs {})
@@ -592,6 +703,81 @@ impl<'a> MessageBuilder<'a> {
         create_msg!(self, QueueRemove, position_type: typ, position: Some(position))
     }
 
+    pub fn queue_move(mut self, from: QueuePosition, to: QueuePosition) -> ConstructedMessage<'a> {
+        let (from_type, from) = self.queue_position(from);
+        let (to_type, to) = self.queue_position(to);
+        create_msg!(self, QueueMove, from_type, from: Some(from), to_type, to: Some(to))
+    }
+
+    pub fn queue_mode_changed(
+        mut self,
+        repeat: flat::RepeatMode,
+        shuffle: bool,
+    ) -> ConstructedMessage<'a> {
+        create_msg!(self, QueueModeChanged, repeat, shuffle)
+    }
+
+    pub fn sleep_timer_changed(
+        mut self,
+        mode: flat::SleepTimerMode,
+        remaining: Option<flat::Time>,
+        fade: Option<flat::Time>,
+    ) -> ConstructedMessage<'a> {
+        create_msg!(self, SleepTimerChanged, mode, remaining: remaining.as_ref(), fade: fade.as_ref())
+    }
+
+    pub fn playback_history_request(
+        mut self,
+        request_id: u32,
+        limit: u32,
+    ) -> ConstructedMessage<'a> {
+        create_msg!(self, PlaybackHistoryRequest, request_id, limit)
+    }
+
+    pub fn playback_history(
+        mut self,
+        request_id: u32,
+        entries: &[HistoryEntry],
+    ) -> ConstructedMessage<'a> {
+        let entries = entries
+            .iter()
+            .map(|entry| {
+                let watched = Self::time_from_secs_f64(entry.watched);
+                let duration = entry.duration.and_then(Self::time_from_secs_f64);
+                let args = flat::HistoryEntryArgs {
+                    url: create_str!(self, entry.url),
+                    container: create_str!(self, entry.container),
+                    title: maybe_create_str!(self, entry.title.as_ref()),
+                    thumbnail_url: maybe_create_str!(self, entry.thumbnail_url.as_ref()),
+                    watched: watched.as_ref(),
+                    duration: duration.as_ref(),
+                    played_at: entry.played_at,
+                    sender_name: maybe_create_str!(self, entry.sender_name.as_ref()),
+                };
+                flat::HistoryEntry::create(&mut self.builder, &args)
+            })
+            .collect::<Vec<_>>();
+        let entries = self.builder.create_vector(&entries);
+        create_msg!(self, PlaybackHistory, request_id, entries: Some(entries))
+    }
+
+    pub fn subtitle_style_changed(
+        mut self,
+        size: Option<f32>,
+        position: Option<f32>,
+        background: Option<bool>,
+    ) -> ConstructedMessage<'a> {
+        create_msg!(self, SubtitleStyleChanged, size, position, background)
+    }
+
+    pub fn delay_changed(
+        mut self,
+        subtitle_micros: Option<i64>,
+        audio_micros: Option<i64>,
+    ) -> ConstructedMessage<'a> {
+        create_msg!(self, DelayChanged, subtitle_micros, audio_micros)
+    }
+
     pub fn queue_insert(
         mut self,
         item: MediaItem,
@@ -611,6 +797,51 @@ impl<'a> MessageBuilder<'a> {
         create_msg!(self, QueueInsert, item: Some(q_item), position_type: pos_type, position: Some(position))
     }
 
+    /// Build a `QueueInsertBatch`, the items inserted in order starting at
+    /// `position`.
+    pub fn queue_insert_batch(
+        mut self,
+        items: impl Iterator<Item = (MediaItem, Option<f64>)>,
+        position: QueuePosition,
+    ) -> ConstructedMessage<'a> {
+        let (position_type, position) = self.queue_position(position);
+        let items = self.build_queue_items(items);
+        create_msg!(self, QueueInsertBatch, items: Some(items), position_type, position: Some(position))
+    }
+
+    pub fn from_queue_insert_batch_stripped(
+        mut self,
+        batch: &flat::QueueInsertBatch,
+    ) -> Option<ConstructedMessage<'a>> {
+        let position = match batch.position_type() {
+            flat::QueuePosition::Index => {
+                QueuePosition::Index(read_queue_index(&batch.position_as_index()?))
+            }
+            flat::QueuePosition::Front => QueuePosition::Front,
+            flat::QueuePosition::Back => QueuePosition::Back,
+            _ => return None,
+        };
+        let (position_type, position) = self.queue_position(position);
+        let items = batch
+            .items()
+            .iter()
+            .map(|queue_item| {
+                let item = self.strip_flat_media_item(queue_item.media_item());
+                flat::QueueItem::create(
+                    &mut self.builder,
+                    &flat::QueueItemArgs {
+                        media_item: Some(item),
+                        playback_duration: queue_item.playback_duration(),
+                    },
+                )
+            })
+            .collect::<Vec<_>>();
+        let items = self.builder.create_vector(&items);
+        Some(
+            create_msg!(self, QueueInsertBatch, items: Some(items), position_type, position: Some(position)),
+        )
+    }
+
     pub fn queue_select(mut self, position: QueuePosition) -> ConstructedMessage<'a> {
         let (position_type, position) = self.queue_position(position);
         create_msg!(self, QueueItemSelected, position_type, position: Some(position))
@@ -702,6 +933,8 @@ impl<'a> MessageBuilder<'a> {
         supports_external_subtitles: bool,
         supports_mirroring: bool,
         volume_step_interval: f32,
+        pairing_required: bool,
+        max_queue_items: Option<u32>,
     ) -> ConstructedMessage<'a> {
         let device_info = Some(create_device_info!(self, device_info));
         let protocols = self.create_str_vector(supported_streaming_protocols);
@@ -746,7 +979,14 @@ impl<'a> MessageBuilder<'a> {
             },
         ));
 
-        create_msg!(self, ReceiverIntroduction, device_info, capabilities)
+        create_msg!(
+            self,
+            ReceiverIntroduction,
+            device_info,
+            capabilities,
+            pairing_required,
+            max_queue_items
+        )
     }
 
     pub fn error(
@@ -849,13 +1089,104 @@ pub fn read_extra_metadata(item: &flat::MediaItem) -> Option<HashMap<String, Met
     Some(map)
 }
 
+/// Read the chapters of a flat chapter list, skipping entries without a
+/// start time.
+pub fn read_chapters<'a>(
+    chapters: flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<flat::Chapter<'a>>>,
+) -> Vec<Chapter> {
+    chapters
+        .iter()
+        .filter_map(|chapter| {
+            Some(Chapter {
+                start: Duration::from_micros(chapter.start()?.micros()).as_secs_f64(),
+                length: chapter
+                    .length()
+                    .map(|t| Duration::from_micros(t.micros()).as_secs_f64()),
+                title: chapter.title().to_owned(),
+                skip_label: chapter.skip_label().map(ToOwned::to_owned),
+            })
+        })
+        .collect()
+}
+
+/// Read the chapters a sender attached to a `MediaItem`'s typed metadata.
+/// Empty when the item carries none.
+pub fn read_media_item_chapters(item: &flat::MediaItem) -> Vec<Chapter> {
+    let chapters = match item.metadata_type() {
+        flat::Metadata::Video => item.metadata_as_video().and_then(|m| m.chapters()),
+        flat::Metadata::Audio => item.metadata_as_audio().and_then(|m| m.chapters()),
+        _ => None,
+    };
+    chapters.map(read_chapters).unwrap_or_default()
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Chapter {
+    /// Start of the chapter in seconds
+    pub start: f64,
+    /// Length of the chapter in seconds, if known
+    pub length: Option<f64>,
+    pub title: String,
+    /// Label for a "skip" button, for chapters like intros or recaps
+    pub skip_label: Option<String>,
+}
+
+/// One item of a receiver's playback history.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HistoryEntry {
+    /// Source URL without its query
+    pub url: String,
+    pub container: String,
+    pub title: Option<String>,
+    pub thumbnail_url: Option<String>,
+    /// Seconds of the item that were played
+    pub watched: f64,
+    /// Duration in seconds, `None` for live streams
+    pub duration: Option<f64>,
+    /// Start of playback in seconds since the Unix epoch
+    pub played_at: u64,
+    pub sender_name: Option<String>,
+}
+
+/// Read the entries of a `PlaybackHistory`.
+pub fn read_history(history: &flat::PlaybackHistory) -> Vec<HistoryEntry> {
+    history
+        .entries()
+        .iter()
+        .flat_map(|entries| entries.iter())
+        .map(|entry| HistoryEntry {
+            url: entry.url().to_owned(),
+            container: entry.container().to_owned(),
+            title: entry.title().map(ToOwned::to_owned),
+            thumbnail_url: entry.thumbnail_url().map(ToOwned::to_owned),
+            watched: entry
+                .watched()
+                .map_or(0.0, |t| Duration::from_micros(t.micros()).as_secs_f64()),
+            duration: entry
+                .duration()
+                .map(|t| Duration::from_micros(t.micros()).as_secs_f64()),
+            played_at: entry.played_at(),
+            sender_name: entry.sender_name().map(ToOwned::to_owned),
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ChapterTarget {
+    Index(u32),
+    Next,
+    Previous,
+}
+
 pub enum Metadata {
     Video {
         subtitle_url: Option<String>,
+        chapters: Vec<Chapter>,
     },
     Audio {
         artist: Option<String>,
         album: Option<String>,
+        chapters: Vec<Chapter>,
     },
 }
 
@@ -875,6 +1206,8 @@ pub struct MediaItem {
     pub thumbnail_url: Option<String>,
     pub metadata: Option<Metadata>,
     pub extra_metadata: Option<HashMap<String, MetaValue>>,
+    /// Resume from the receiver's remembered position for `source_url`
+    pub resume: bool,
 }
 
 #[derive(Debug)]
@@ -899,11 +1232,23 @@ pub struct MediaTrack {
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum QueuePosition {
-    Index(u8),
+    Index(u32),
     Front,
     Back,
 }
 
+/// Read a `QueueIndex`, wide or not.
+pub fn read_queue_index(index: &flat::QueueIndex) -> u32 {
+    index.wide_index().unwrap_or(index.index() as u32)
+}
+
+/// Read a `Queue`'s start index, wide or not.
+pub fn read_queue_start_index(queue: &flat::Queue) -> Option<u32> {
+    queue
+        .wide_start_index()
+        .or(queue.start_index().map(u32::from))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -920,6 +1265,7 @@ mod tests {
             thumbnail_url: None,
             metadata: None,
             extra_metadata: Some(extra),
+            resume: false,
         }
     }
 
@@ -1003,5 +1349,128 @@ mod tests {
         );
         // Headers are still deliberately dropped on relay.
         assert!(single.headers().is_none());
+        assert!(!single.resume());
+    }
+
+    #[test]
+    fn resume_flag_survives_relay_strip() {
+        let mut item = media_item_with_extra(HashMap::new());
+        item.resume = true;
+        let msg = MessageBuilder::new().load_single(item);
+        let load = flat::root_as_packet(&msg)
+            .unwrap()
+            .payload_as_load()
+            .unwrap();
+        let relayed = MessageBuilder::new().from_play_stripped(&load).unwrap();
+        let single = flat::root_as_packet(&relayed)
+            .unwrap()
+            .payload_as_load()
+            .unwrap()
+            .source_as_single()
+            .unwrap();
+        assert!(single.resume());
+    }
+
+    #[test]
+    fn queue_indexes_past_a_ubyte_use_the_wide_fields() {
+        let index_of = |msg: &ConstructedMessage| {
+            let packet = flat::root_as_packet(msg).unwrap();
+            let index = packet
+                .payload_as_queue_item_selected()
+                .unwrap()
+                .position_as_index()
+                .unwrap();
+            (index.index(), index.wide_index(), read_queue_index(&index))
+        };
+        // Small indexes stay readable by receivers without wide indexes.
+        let msg = MessageBuilder::new().queue_select(QueuePosition::Index(255));
+        assert_eq!(index_of(&msg), (255, None, 255));
+        let msg = MessageBuilder::new().queue_select(QueuePosition::Index(4999));
+        assert_eq!(index_of(&msg), (0, Some(4999), 4999));
+
+        let items = (0..3).map(|_| (media_item_with_extra(HashMap::new()), None));
+        let msg = MessageBuilder::new().load_queue(items, Some(300), true);
+        let queue = flat::root_as_packet(&msg)
+            .unwrap()
+            .payload_as_load()
+            .unwrap()
+            .source_as_queue()
+            .unwrap();
+        assert_eq!(queue.start_index(), None);
+        assert_eq!(read_queue_start_index(&queue), Some(300));
+    }
+
+    #[test]
+    fn playback_history_round_trip() {
+        let entries = vec![
+            HistoryEntry {
+                url: "https://example.com/episode.mp4".to_owned(),
+                container: "video/mp4".to_owned(),
+                title: Some("Episode 1".to_owned()),
+                thumbnail_url: None,
+                watched: 754.5,
+                duration: Some(1800.0),
+                played_at: 1_760_000_000,
+                sender_name: Some("Phone".to_owned()),
+            },
+            HistoryEntry {
+                url: "https://example.com/live.m3u8".to_owned(),
+                container: "application/vnd.apple.mpegurl".to_owned(),
+                title: None,
+                thumbnail_url: Some("https://example.com/live.jpg".to_owned()),
+                watched: 60.0,
+                duration: None,
+                played_at: 1_759_990_000,
+                sender_name: None,
+            },
+        ];
+        let msg = MessageBuilder::new().playback_history(7, &entries);
+        let history = flat::root_as_packet(&msg)
+            .unwrap()
+            .payload_as_playback_history()
+            .unwrap();
+        assert_eq!(history.request_id(), 7);
+        assert_eq!(read_history(&history), entries);
+    }
+
+    /// Sender-supplied chapters must survive serialization, and a chapter
+    /// without a length must stay open-ended rather than read back as zero.
+    #[test]
+    fn media_item_chapters_round_trip() {
+        let chapters = vec![
+            Chapter {
+                start: 0.0,
+                length: Some(90.0),
+                title: "Intro".to_owned(),
+                skip_label: Some("Skip intro".to_owned()),
+            },
+            Chapter {
+                start: 90.0,
+                length: None,
+                title: "Part 1".to_owned(),
+                skip_label: None,
+            },
+        ];
+        let mut item = media_item_with_extra(HashMap::new());
+        item.metadata = Some(Metadata::Video {
+            subtitle_url: None,
+            chapters: chapters.clone(),
+        });
+
+        let msg = MessageBuilder::new().load_single(item);
+        let single = flat::root_as_packet(&msg)
+            .unwrap()
+            .payload_as_load()
+            .unwrap()
+            .source_as_single()
+            .unwrap();
+        assert_eq!(read_media_item_chapters(&single), chapters);
+
+        let msg = MessageBuilder::new().chapters_available(&chapters);
+        let available = flat::root_as_packet(&msg)
+            .unwrap()
+            .payload_as_chapters_available()
+            .unwrap();
+        assert_eq!(read_chapters(available.chapters().unwrap()), chapters);
     }
 }
@@ -13,10 +13,10 @@ pub mod v4 {
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
 pub const ENUM_MIN_MESSAGE: u8 = 0;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
-pub const ENUM_MAX_MESSAGE: u8 = 23;
+pub const ENUM_MAX_MESSAGE: u8 = 37;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_MESSAGE: [Message; 24] = [
+pub const ENUM_VALUES_MESSAGE: [Message; 38] = [
   Message::NONE,
   Message::Load,
   Message::ProgressChanged,
@@ -41,6 +41,20 @@ pub const ENUM_VALUES_MESSAGE: [Message; 24] = [
   Message::CompanionResourceInfoResponse,
   Message::CompanionResourceRequest,
   Message::Error,
+  Message::PairingRequest,
+  Message::PairingResult,
+  Message::SeekRelative,
+  Message::FrameStep,
+  Message::ChaptersAvailable,
+  Message::SeekToChapter,
+  Message::QueueMove,
+  Message::QueueModeChanged,
+  Message::QueueInsertBatch,
+  Message::SleepTimerChanged,
+  Message::PlaybackHistoryRequest,
+  Message::PlaybackHistory,
+  Message::SubtitleStyleChanged,
+  Message::DelayChanged,
 ];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -72,9 +86,23 @@ impl Message {
   pub const CompanionResourceInfoResponse: Self = Self(21);
   pub const CompanionResourceRequest: Self = Self(22);
   pub const Error: Self = Self(23);
+  pub const PairingRequest: Self = Self(24);
+  pub const PairingResult: Self = Self(25);
+  pub const SeekRelative: Self = Self(26);
+  pub const FrameStep: Self = Self(27);
+  pub const ChaptersAvailable: Self = Self(28);
+  pub const SeekToChapter: Self = Self(29);
+  pub const QueueMove: Self = Self(30);
+  pub const QueueModeChanged: Self = Self(31);
+  pub const QueueInsertBatch: Self = Self(32);
+  pub const SleepTimerChanged: Self = Self(33);
+  pub const PlaybackHistoryRequest: Self = Self(34);
+  pub const PlaybackHistory: Self = Self(35);
+  pub const SubtitleStyleChanged: Self = Self(36);
+  pub const DelayChanged: Self = Self(37);
 
   pub const ENUM_MIN: u8 = 0;
-  pub const ENUM_MAX: u8 = 23;
+  pub const ENUM_MAX: u8 = 37;
   pub const ENUM_VALUES: &'static [Self] = &[
     Self::NONE,
     Self::Load,
@@ -100,6 +128,20 @@ impl Message {
     Self::CompanionResourceInfoResponse,
     Self::CompanionResourceRequest,
     Self::Error,
+    Self::PairingRequest,
+    Self::PairingResult,
+    Self::SeekRelative,
+    Self::FrameStep,
+    Self::ChaptersAvailable,
+    Self::SeekToChapter,
+    Self::QueueMove,
+    Self::QueueModeChanged,
+    Self::QueueInsertBatch,
+    Self::SleepTimerChanged,
+    Self::PlaybackHistoryRequest,
+    Self::PlaybackHistory,
+    Self::SubtitleStyleChanged,
+    Self::DelayChanged,
   ];
   /// Returns the variant's name or "" if unknown.
   pub fn variant_name(self) -> Option<&'static str> {
@@ -128,6 +170,20 @@ impl Message {
       Self::CompanionResourceInfoResponse => Some("CompanionResourceInfoResponse"),
       Self::CompanionResourceRequest => Some("CompanionResourceRequest"),
       Self::Error => Some("Error"),
+      Self::PairingRequest => Some("PairingRequest"),
+      Self::PairingResult => Some("PairingResult"),
+      Self::SeekRelative => Some("SeekRelative"),
+      Self::FrameStep => Some("FrameStep"),
+      Self::ChaptersAvailable => Some("ChaptersAvailable"),
+      Self::SeekToChapter => Some("SeekToChapter"),
+      Self::QueueMove => Some("QueueMove"),
+      Self::QueueModeChanged => Some("QueueModeChanged"),
+      Self::QueueInsertBatch => Some("QueueInsertBatch"),
+      Self::SleepTimerChanged => Some("SleepTimerChanged"),
+      Self::PlaybackHistoryRequest => Some("PlaybackHistoryRequest"),
+      Self::PlaybackHistory => Some("PlaybackHistory"),
+      Self::SubtitleStyleChanged => Some("SubtitleStyleChanged"),
+      Self::DelayChanged => Some("DelayChanged"),
       _ => None,
     }
   }
@@ -751,44 +807,44 @@ impl ::flatbuffers::SimpleToVerifyInSlice for MediaTrackMetadata {}
 pub struct MediaTrackMetadataUnionTableOffset {}
 
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
-pub const ENUM_MIN_MEDIA_TRACK_TYPE: u8 = 0;
+pub const ENUM_MIN_REPEAT_MODE: u8 = 0;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
-pub const ENUM_MAX_MEDIA_TRACK_TYPE: u8 = 2;
+pub const ENUM_MAX_REPEAT_MODE: u8 = 2;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_MEDIA_TRACK_TYPE: [MediaTrackType; 3] = [
-  MediaTrackType::Video,
-  MediaTrackType::Audio,
-  MediaTrackType::Subtitle,
+pub const ENUM_VALUES_REPEAT_MODE: [RepeatMode; 3] = [
+  RepeatMode::Off,
+  RepeatMode::One,
+  RepeatMode::All,
 ];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 #[repr(transparent)]
-pub struct MediaTrackType(pub u8);
+pub struct RepeatMode(pub u8);
 #[allow(non_upper_case_globals)]
-impl MediaTrackType {
-  pub const Video: Self = Self(0);
-  pub const Audio: Self = Self(1);
-  pub const Subtitle: Self = Self(2);
+impl RepeatMode {
+  pub const Off: Self = Self(0);
+  pub const One: Self = Self(1);
+  pub const All: Self = Self(2);
 
   pub const ENUM_MIN: u8 = 0;
   pub const ENUM_MAX: u8 = 2;
   pub const ENUM_VALUES: &'static [Self] = &[
-    Self::Video,
-    Self::Audio,
-    Self::Subtitle,
+    Self::Off,
+    Self::One,
+    Self::All,
   ];
   /// Returns the variant's name or "" if unknown.
   pub fn variant_name(self) -> Option<&'static str> {
     match self {
-      Self::Video => Some("Video"),
-      Self::Audio => Some("Audio"),
-      Self::Subtitle => Some("Subtitle"),
+      Self::Off => Some("Off"),
+      Self::One => Some("One"),
+      Self::All => Some("All"),
       _ => None,
     }
   }
 }
-impl ::core::fmt::Debug for MediaTrackType {
+impl ::core::fmt::Debug for RepeatMode {
   fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
     if let Some(name) = self.variant_name() {
       f.write_str(name)
@@ -797,7 +853,7 @@ impl ::core::fmt::Debug for MediaTrackType {
     }
   }
 }
-impl<'a> ::flatbuffers::Follow<'a> for MediaTrackType {
+impl<'a> ::flatbuffers::Follow<'a> for RepeatMode {
   type Inner = Self;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
@@ -806,15 +862,15 @@ impl<'a> ::flatbuffers::Follow<'a> for MediaTrackType {
   }
 }
 
-impl ::flatbuffers::Push for MediaTrackType {
-    type Output = MediaTrackType;
+impl ::flatbuffers::Push for RepeatMode {
+    type Output = RepeatMode;
     #[inline]
     unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
         unsafe { ::flatbuffers::emplace_scalar::<u8>(dst, self.0) };
     }
 }
 
-impl ::flatbuffers::EndianScalar for MediaTrackType {
+impl ::flatbuffers::EndianScalar for RepeatMode {
   type Scalar = u8;
   #[inline]
   fn to_little_endian(self) -> u8 {
@@ -828,7 +884,7 @@ impl ::flatbuffers::EndianScalar for MediaTrackType {
   }
 }
 
-impl<'a> ::flatbuffers::Verifiable for MediaTrackType {
+impl<'a> ::flatbuffers::Verifiable for RepeatMode {
   #[inline]
   fn run_verifier(
     v: &mut ::flatbuffers::Verifier, pos: usize
@@ -837,86 +893,46 @@ impl<'a> ::flatbuffers::Verifiable for MediaTrackType {
   }
 }
 
-impl ::flatbuffers::SimpleToVerifyInSlice for MediaTrackType {}
+impl ::flatbuffers::SimpleToVerifyInSlice for RepeatMode {}
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
-pub const ENUM_MIN_ERROR_KIND: u8 = 0;
+pub const ENUM_MIN_SLEEP_TIMER_MODE: u8 = 0;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
-pub const ENUM_MAX_ERROR_KIND: u8 = 12;
+pub const ENUM_MAX_SLEEP_TIMER_MODE: u8 = 2;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_ERROR_KIND: [ErrorKind; 13] = [
-  ErrorKind::InvalidOpcode,
-  ErrorKind::ResourceNotFound,
-  ErrorKind::SeekOutOfRange,
-  ErrorKind::VolumeOutOfRange,
-  ErrorKind::RateOutOfRange,
-  ErrorKind::UnsupportedFormat,
-  ErrorKind::MalformedBody,
-  ErrorKind::InvalidState,
-  ErrorKind::QueuePositionOutOfRange,
-  ErrorKind::QueueRemovePlayingItem,
-  ErrorKind::QueueFull,
-  ErrorKind::InvalidPayloadType,
-  ErrorKind::Internal,
+pub const ENUM_VALUES_SLEEP_TIMER_MODE: [SleepTimerMode; 3] = [
+  SleepTimerMode::Off,
+  SleepTimerMode::EndOfItem,
+  SleepTimerMode::Deadline,
 ];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 #[repr(transparent)]
-pub struct ErrorKind(pub u8);
+pub struct SleepTimerMode(pub u8);
 #[allow(non_upper_case_globals)]
-impl ErrorKind {
-  pub const InvalidOpcode: Self = Self(0);
-  pub const ResourceNotFound: Self = Self(1);
-  pub const SeekOutOfRange: Self = Self(2);
-  pub const VolumeOutOfRange: Self = Self(3);
-  pub const RateOutOfRange: Self = Self(4);
-  pub const UnsupportedFormat: Self = Self(5);
-  pub const MalformedBody: Self = Self(6);
-  pub const InvalidState: Self = Self(7);
-  pub const QueuePositionOutOfRange: Self = Self(8);
-  pub const QueueRemovePlayingItem: Self = Self(9);
-  pub const QueueFull: Self = Self(10);
-  pub const InvalidPayloadType: Self = Self(11);
-  pub const Internal: Self = Self(12);
+impl SleepTimerMode {
+  pub const Off: Self = Self(0);
+  pub const EndOfItem: Self = Self(1);
+  pub const Deadline: Self = Self(2);
 
   pub const ENUM_MIN: u8 = 0;
-  pub const ENUM_MAX: u8 = 12;
+  pub const ENUM_MAX: u8 = 2;
   pub const ENUM_VALUES: &'static [Self] = &[
-    Self::InvalidOpcode,
-    Self::ResourceNotFound,
-    Self::SeekOutOfRange,
-    Self::VolumeOutOfRange,
-    Self::RateOutOfRange,
-    Self::UnsupportedFormat,
-    Self::MalformedBody,
-    Self::InvalidState,
-    Self::QueuePositionOutOfRange,
-    Self::QueueRemovePlayingItem,
-    Self::QueueFull,
-    Self::InvalidPayloadType,
-    Self::Internal,
+    Self::Off,
+    Self::EndOfItem,
+    Self::Deadline,
   ];
   /// Returns the variant's name or "" if unknown.
   pub fn variant_name(self) -> Option<&'static str> {
     match self {
-      Self::InvalidOpcode => Some("InvalidOpcode"),
-      Self::ResourceNotFound => Some("ResourceNotFound"),
-      Self::SeekOutOfRange => Some("SeekOutOfRange"),
-      Self::VolumeOutOfRange => Some("VolumeOutOfRange"),
-      Self::RateOutOfRange => Some("RateOutOfRange"),
-      Self::UnsupportedFormat => Some("UnsupportedFormat"),
-      Self::MalformedBody => Some("MalformedBody"),
-      Self::InvalidState => Some("InvalidState"),
-      Self::QueuePositionOutOfRange => Some("QueuePositionOutOfRange"),
-      Self::QueueRemovePlayingItem => Some("QueueRemovePlayingItem"),
-      Self::QueueFull => Some("QueueFull"),
-      Self::InvalidPayloadType => Some("InvalidPayloadType"),
-      Self::Internal => Some("Internal"),
+      Self::Off => Some("Off"),
+      Self::EndOfItem => Some("EndOfItem"),
+      Self::Deadline => Some("Deadline"),
       _ => None,
     }
   }
 }
-impl ::core::fmt::Debug for ErrorKind {
+impl ::core::fmt::Debug for SleepTimerMode {
   fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
     if let Some(name) = self.variant_name() {
       f.write_str(name)
@@ -925,7 +941,7 @@ impl ::core::fmt::Debug for ErrorKind {
     }
   }
 }
-impl<'a> ::flatbuffers::Follow<'a> for ErrorKind {
+impl<'a> ::flatbuffers::Follow<'a> for SleepTimerMode {
   type Inner = Self;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
@@ -934,15 +950,15 @@ impl<'a> ::flatbuffers::Follow<'a> for ErrorKind {
   }
 }
 
-impl ::flatbuffers::Push for ErrorKind {
-    type Output = ErrorKind;
+impl ::flatbuffers::Push for SleepTimerMode {
+    type Output = SleepTimerMode;
     #[inline]
     unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
         unsafe { ::flatbuffers::emplace_scalar::<u8>(dst, self.0) };
     }
 }
 
-impl ::flatbuffers::EndianScalar for ErrorKind {
+impl ::flatbuffers::EndianScalar for SleepTimerMode {
   type Scalar = u8;
   #[inline]
   fn to_little_endian(self) -> u8 {
@@ -956,7 +972,7 @@ impl ::flatbuffers::EndianScalar for ErrorKind {
   }
 }
 
-impl<'a> ::flatbuffers::Verifiable for ErrorKind {
+impl<'a> ::flatbuffers::Verifiable for SleepTimerMode {
   #[inline]
   fn run_verifier(
     v: &mut ::flatbuffers::Verifier, pos: usize
@@ -965,46 +981,50 @@ impl<'a> ::flatbuffers::Verifiable for ErrorKind {
   }
 }
 
-impl ::flatbuffers::SimpleToVerifyInSlice for ErrorKind {}
+impl ::flatbuffers::SimpleToVerifyInSlice for SleepTimerMode {}
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
-pub const ENUM_MIN_COMPANION_RESOURCE_SIZE: u8 = 0;
+pub const ENUM_MIN_MEDIA_TRACK_TYPE: u8 = 0;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
-pub const ENUM_MAX_COMPANION_RESOURCE_SIZE: u8 = 2;
+pub const ENUM_MAX_MEDIA_TRACK_TYPE: u8 = 3;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_COMPANION_RESOURCE_SIZE: [CompanionResourceSize; 3] = [
-  CompanionResourceSize::NONE,
-  CompanionResourceSize::Unknown,
-  CompanionResourceSize::Known,
+pub const ENUM_VALUES_MEDIA_TRACK_TYPE: [MediaTrackType; 4] = [
+  MediaTrackType::Video,
+  MediaTrackType::Audio,
+  MediaTrackType::Subtitle,
+  MediaTrackType::SecondarySubtitle,
 ];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 #[repr(transparent)]
-pub struct CompanionResourceSize(pub u8);
+pub struct MediaTrackType(pub u8);
 #[allow(non_upper_case_globals)]
-impl CompanionResourceSize {
-  pub const NONE: Self = Self(0);
-  pub const Unknown: Self = Self(1);
-  pub const Known: Self = Self(2);
+impl MediaTrackType {
+  pub const Video: Self = Self(0);
+  pub const Audio: Self = Self(1);
+  pub const Subtitle: Self = Self(2);
+  pub const SecondarySubtitle: Self = Self(3);
 
   pub const ENUM_MIN: u8 = 0;
-  pub const ENUM_MAX: u8 = 2;
+  pub const ENUM_MAX: u8 = 3;
   pub const ENUM_VALUES: &'static [Self] = &[
-    Self::NONE,
-    Self::Unknown,
-    Self::Known,
+    Self::Video,
+    Self::Audio,
+    Self::Subtitle,
+    Self::SecondarySubtitle,
   ];
   /// Returns the variant's name or "" if unknown.
   pub fn variant_name(self) -> Option<&'static str> {
     match self {
-      Self::NONE => Some("NONE"),
-      Self::Unknown => Some("Unknown"),
-      Self::Known => Some("Known"),
+      Self::Video => Some("Video"),
+      Self::Audio => Some("Audio"),
+      Self::Subtitle => Some("Subtitle"),
+      Self::SecondarySubtitle => Some("SecondarySubtitle"),
       _ => None,
     }
   }
 }
-impl ::core::fmt::Debug for CompanionResourceSize {
+impl ::core::fmt::Debug for MediaTrackType {
   fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
     if let Some(name) = self.variant_name() {
       f.write_str(name)
@@ -1013,7 +1033,7 @@ impl ::core::fmt::Debug for CompanionResourceSize {
     }
   }
 }
-impl<'a> ::flatbuffers::Follow<'a> for CompanionResourceSize {
+impl<'a> ::flatbuffers::Follow<'a> for MediaTrackType {
   type Inner = Self;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
@@ -1022,15 +1042,15 @@ impl<'a> ::flatbuffers::Follow<'a> for CompanionResourceSize {
   }
 }
 
-impl ::flatbuffers::Push for CompanionResourceSize {
-    type Output = CompanionResourceSize;
+impl ::flatbuffers::Push for MediaTrackType {
+    type Output = MediaTrackType;
     #[inline]
     unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
         unsafe { ::flatbuffers::emplace_scalar::<u8>(dst, self.0) };
     }
 }
 
-impl ::flatbuffers::EndianScalar for CompanionResourceSize {
+impl ::flatbuffers::EndianScalar for MediaTrackType {
   type Scalar = u8;
   #[inline]
   fn to_little_endian(self) -> u8 {
@@ -1044,7 +1064,7 @@ impl ::flatbuffers::EndianScalar for CompanionResourceSize {
   }
 }
 
-impl<'a> ::flatbuffers::Verifiable for CompanionResourceSize {
+impl<'a> ::flatbuffers::Verifiable for MediaTrackType {
   #[inline]
   fn run_verifier(
     v: &mut ::flatbuffers::Verifier, pos: usize
@@ -1053,299 +1073,466 @@ impl<'a> ::flatbuffers::Verifiable for CompanionResourceSize {
   }
 }
 
-impl ::flatbuffers::SimpleToVerifyInSlice for CompanionResourceSize {}
-pub struct CompanionResourceSizeUnionTableOffset {}
+impl ::flatbuffers::SimpleToVerifyInSlice for MediaTrackType {}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_FRAME_STEP_DIRECTION: u8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_FRAME_STEP_DIRECTION: u8 = 1;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_FRAME_STEP_DIRECTION: [FrameStepDirection; 2] = [
+  FrameStepDirection::Forward,
+  FrameStepDirection::Backward,
+];
 
-// struct Time, aligned to 8
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 #[repr(transparent)]
-#[derive(Clone, Copy, PartialEq)]
-pub struct Time(pub [u8; 8]);
-impl Default for Time { 
-  fn default() -> Self { 
-    Self([0; 8])
+pub struct FrameStepDirection(pub u8);
+#[allow(non_upper_case_globals)]
+impl FrameStepDirection {
+  pub const Forward: Self = Self(0);
+  pub const Backward: Self = Self(1);
+
+  pub const ENUM_MIN: u8 = 0;
+  pub const ENUM_MAX: u8 = 1;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Forward,
+    Self::Backward,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Forward => Some("Forward"),
+      Self::Backward => Some("Backward"),
+      _ => None,
+    }
   }
 }
-impl ::core::fmt::Debug for Time {
+impl ::core::fmt::Debug for FrameStepDirection {
   fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-    f.debug_struct("Time")
-      .field("micros", &self.micros())
-      .finish()
-  }
-}
-
-impl ::flatbuffers::SimpleToVerifyInSlice for Time {}
-impl<'a> ::flatbuffers::Follow<'a> for Time {
-  type Inner = &'a Time;
-  #[inline]
-  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-    unsafe { <&'a Time>::follow(buf, loc) }
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
   }
 }
-impl<'a> ::flatbuffers::Follow<'a> for &'a Time {
-  type Inner = &'a Time;
+impl<'a> ::flatbuffers::Follow<'a> for FrameStepDirection {
+  type Inner = Self;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-    unsafe { ::flatbuffers::follow_cast_ref::<Time>(buf, loc) }
+    let b = unsafe { ::flatbuffers::read_scalar_at::<u8>(buf, loc) };
+    Self(b)
   }
 }
-impl<'b> ::flatbuffers::Push for Time {
-    type Output = Time;
+
+impl ::flatbuffers::Push for FrameStepDirection {
+    type Output = FrameStepDirection;
     #[inline]
     unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
-        let src = unsafe { ::core::slice::from_raw_parts(self as *const Time as *const u8, <Self as ::flatbuffers::Push>::size()) };
-        dst.copy_from_slice(src);
-    }
-    #[inline]
-    fn alignment() -> ::flatbuffers::PushAlignment {
-        ::flatbuffers::PushAlignment::new(8)
+        unsafe { ::flatbuffers::emplace_scalar::<u8>(dst, self.0) };
     }
 }
 
-impl<'a> ::flatbuffers::Verifiable for Time {
+impl ::flatbuffers::EndianScalar for FrameStepDirection {
+  type Scalar = u8;
+  #[inline]
+  fn to_little_endian(self) -> u8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: u8) -> Self {
+    let b = u8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> ::flatbuffers::Verifiable for FrameStepDirection {
   #[inline]
   fn run_verifier(
     v: &mut ::flatbuffers::Verifier, pos: usize
   ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
-    v.in_buffer::<Self>(pos)
+    u8::run_verifier(v, pos)
   }
 }
 
-impl<'a> Time {
-  #[allow(clippy::too_many_arguments)]
-  pub fn new(
-    micros: u64,
-  ) -> Self {
-    let mut s = Self([0; 8]);
-    s.set_micros(micros);
-    s
-  }
-
-  pub fn micros(&self) -> u64 {
-    let mut mem = ::core::mem::MaybeUninit::<<u64 as ::flatbuffers::EndianScalar>::Scalar>::uninit();
-    // Safety:
-    // Created from a valid Table for this object
-    // Which contains a valid value in this slot
-    ::flatbuffers::EndianScalar::from_little_endian(unsafe {
-      ::core::ptr::copy_nonoverlapping(
-        self.0[0..].as_ptr(),
-        mem.as_mut_ptr() as *mut u8,
-        ::core::mem::size_of::<<u64 as ::flatbuffers::EndianScalar>::Scalar>(),
-      );
-      mem.assume_init()
-    })
-  }
-
-  pub fn set_micros(&mut self, x: u64) {
-    let x_le = ::flatbuffers::EndianScalar::to_little_endian(x);
-    // Safety:
-    // Created from a valid Table for this object
-    // Which contains a valid value in this slot
-    unsafe {
-      ::core::ptr::copy_nonoverlapping(
-        &x_le as *const _ as *const u8,
-        self.0[0..].as_mut_ptr(),
-        ::core::mem::size_of::<<u64 as ::flatbuffers::EndianScalar>::Scalar>(),
-      );
-    }
-  }
-
-}
+impl ::flatbuffers::SimpleToVerifyInSlice for FrameStepDirection {}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_CHAPTER_TARGET: u8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_CHAPTER_TARGET: u8 = 2;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_CHAPTER_TARGET: [ChapterTarget; 3] = [
+  ChapterTarget::Index,
+  ChapterTarget::Next,
+  ChapterTarget::Previous,
+];
 
-// struct VideoResolution, aligned to 4
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 #[repr(transparent)]
-#[derive(Clone, Copy, PartialEq)]
-pub struct VideoResolution(pub [u8; 8]);
-impl Default for VideoResolution { 
-  fn default() -> Self { 
-    Self([0; 8])
+pub struct ChapterTarget(pub u8);
+#[allow(non_upper_case_globals)]
+impl ChapterTarget {
+  pub const Index: Self = Self(0);
+  pub const Next: Self = Self(1);
+  pub const Previous: Self = Self(2);
+
+  pub const ENUM_MIN: u8 = 0;
+  pub const ENUM_MAX: u8 = 2;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Index,
+    Self::Next,
+    Self::Previous,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Index => Some("Index"),
+      Self::Next => Some("Next"),
+      Self::Previous => Some("Previous"),
+      _ => None,
+    }
   }
 }
-impl ::core::fmt::Debug for VideoResolution {
+impl ::core::fmt::Debug for ChapterTarget {
   fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-    f.debug_struct("VideoResolution")
-      .field("width", &self.width())
-      .field("height", &self.height())
-      .finish()
-  }
-}
-
-impl ::flatbuffers::SimpleToVerifyInSlice for VideoResolution {}
-impl<'a> ::flatbuffers::Follow<'a> for VideoResolution {
-  type Inner = &'a VideoResolution;
-  #[inline]
-  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-    unsafe { <&'a VideoResolution>::follow(buf, loc) }
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
   }
 }
-impl<'a> ::flatbuffers::Follow<'a> for &'a VideoResolution {
-  type Inner = &'a VideoResolution;
+impl<'a> ::flatbuffers::Follow<'a> for ChapterTarget {
+  type Inner = Self;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-    unsafe { ::flatbuffers::follow_cast_ref::<VideoResolution>(buf, loc) }
+    let b = unsafe { ::flatbuffers::read_scalar_at::<u8>(buf, loc) };
+    Self(b)
   }
 }
-impl<'b> ::flatbuffers::Push for VideoResolution {
-    type Output = VideoResolution;
+
+impl ::flatbuffers::Push for ChapterTarget {
+    type Output = ChapterTarget;
     #[inline]
     unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
-        let src = unsafe { ::core::slice::from_raw_parts(self as *const VideoResolution as *const u8, <Self as ::flatbuffers::Push>::size()) };
-        dst.copy_from_slice(src);
-    }
-    #[inline]
-    fn alignment() -> ::flatbuffers::PushAlignment {
-        ::flatbuffers::PushAlignment::new(4)
+        unsafe { ::flatbuffers::emplace_scalar::<u8>(dst, self.0) };
     }
 }
 
-impl<'a> ::flatbuffers::Verifiable for VideoResolution {
+impl ::flatbuffers::EndianScalar for ChapterTarget {
+  type Scalar = u8;
+  #[inline]
+  fn to_little_endian(self) -> u8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: u8) -> Self {
+    let b = u8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> ::flatbuffers::Verifiable for ChapterTarget {
   #[inline]
   fn run_verifier(
     v: &mut ::flatbuffers::Verifier, pos: usize
   ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
-    v.in_buffer::<Self>(pos)
+    u8::run_verifier(v, pos)
   }
 }
 
-impl<'a> VideoResolution {
-  #[allow(clippy::too_many_arguments)]
-  pub fn new(
-    width: u32,
-    height: u32,
-  ) -> Self {
-    let mut s = Self([0; 8]);
-    s.set_width(width);
-    s.set_height(height);
-    s
-  }
-
-  pub fn width(&self) -> u32 {
-    let mut mem = ::core::mem::MaybeUninit::<<u32 as ::flatbuffers::EndianScalar>::Scalar>::uninit();
-    // Safety:
-    // Created from a valid Table for this object
-    // Which contains a valid value in this slot
-    ::flatbuffers::EndianScalar::from_little_endian(unsafe {
-      ::core::ptr::copy_nonoverlapping(
-        self.0[0..].as_ptr(),
-        mem.as_mut_ptr() as *mut u8,
-        ::core::mem::size_of::<<u32 as ::flatbuffers::EndianScalar>::Scalar>(),
-      );
-      mem.assume_init()
-    })
-  }
-
-  pub fn set_width(&mut self, x: u32) {
-    let x_le = ::flatbuffers::EndianScalar::to_little_endian(x);
-    // Safety:
-    // Created from a valid Table for this object
-    // Which contains a valid value in this slot
-    unsafe {
-      ::core::ptr::copy_nonoverlapping(
-        &x_le as *const _ as *const u8,
-        self.0[0..].as_mut_ptr(),
-        ::core::mem::size_of::<<u32 as ::flatbuffers::EndianScalar>::Scalar>(),
-      );
-    }
-  }
+impl ::flatbuffers::SimpleToVerifyInSlice for ChapterTarget {}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_ERROR_KIND: u8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_ERROR_KIND: u8 = 13;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_ERROR_KIND: [ErrorKind; 14] = [
+  ErrorKind::InvalidOpcode,
+  ErrorKind::ResourceNotFound,
+  ErrorKind::SeekOutOfRange,
+  ErrorKind::VolumeOutOfRange,
+  ErrorKind::RateOutOfRange,
+  ErrorKind::UnsupportedFormat,
+  ErrorKind::MalformedBody,
+  ErrorKind::InvalidState,
+  ErrorKind::QueuePositionOutOfRange,
+  ErrorKind::QueueRemovePlayingItem,
+  ErrorKind::QueueFull,
+  ErrorKind::InvalidPayloadType,
+  ErrorKind::Internal,
+  ErrorKind::Unauthorized,
+];
 
-  pub fn height(&self) -> u32 {
-    let mut mem = ::core::mem::MaybeUninit::<<u32 as ::flatbuffers::EndianScalar>::Scalar>::uninit();
-    // Safety:
-    // Created from a valid Table for this object
-    // Which contains a valid value in this slot
-    ::flatbuffers::EndianScalar::from_little_endian(unsafe {
-      ::core::ptr::copy_nonoverlapping(
-        self.0[4..].as_ptr(),
-        mem.as_mut_ptr() as *mut u8,
-        ::core::mem::size_of::<<u32 as ::flatbuffers::EndianScalar>::Scalar>(),
-      );
-      mem.assume_init()
-    })
-  }
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct ErrorKind(pub u8);
+#[allow(non_upper_case_globals)]
+impl ErrorKind {
+  pub const InvalidOpcode: Self = Self(0);
+  pub const ResourceNotFound: Self = Self(1);
+  pub const SeekOutOfRange: Self = Self(2);
+  pub const VolumeOutOfRange: Self = Self(3);
+  pub const RateOutOfRange: Self = Self(4);
+  pub const UnsupportedFormat: Self = Self(5);
+  pub const MalformedBody: Self = Self(6);
+  pub const InvalidState: Self = Self(7);
+  pub const QueuePositionOutOfRange: Self = Self(8);
+  pub const QueueRemovePlayingItem: Self = Self(9);
+  pub const QueueFull: Self = Self(10);
+  pub const InvalidPayloadType: Self = Self(11);
+  pub const Internal: Self = Self(12);
+  pub const Unauthorized: Self = Self(13);
 
-  pub fn set_height(&mut self, x: u32) {
-    let x_le = ::flatbuffers::EndianScalar::to_little_endian(x);
-    // Safety:
-    // Created from a valid Table for this object
-    // Which contains a valid value in this slot
-    unsafe {
-      ::core::ptr::copy_nonoverlapping(
-        &x_le as *const _ as *const u8,
-        self.0[4..].as_mut_ptr(),
-        ::core::mem::size_of::<<u32 as ::flatbuffers::EndianScalar>::Scalar>(),
-      );
+  pub const ENUM_MIN: u8 = 0;
+  pub const ENUM_MAX: u8 = 13;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::InvalidOpcode,
+    Self::ResourceNotFound,
+    Self::SeekOutOfRange,
+    Self::VolumeOutOfRange,
+    Self::RateOutOfRange,
+    Self::UnsupportedFormat,
+    Self::MalformedBody,
+    Self::InvalidState,
+    Self::QueuePositionOutOfRange,
+    Self::QueueRemovePlayingItem,
+    Self::QueueFull,
+    Self::InvalidPayloadType,
+    Self::Internal,
+    Self::Unauthorized,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::InvalidOpcode => Some("InvalidOpcode"),
+      Self::ResourceNotFound => Some("ResourceNotFound"),
+      Self::SeekOutOfRange => Some("SeekOutOfRange"),
+      Self::VolumeOutOfRange => Some("VolumeOutOfRange"),
+      Self::RateOutOfRange => Some("RateOutOfRange"),
+      Self::UnsupportedFormat => Some("UnsupportedFormat"),
+      Self::MalformedBody => Some("MalformedBody"),
+      Self::InvalidState => Some("InvalidState"),
+      Self::QueuePositionOutOfRange => Some("QueuePositionOutOfRange"),
+      Self::QueueRemovePlayingItem => Some("QueueRemovePlayingItem"),
+      Self::QueueFull => Some("QueueFull"),
+      Self::InvalidPayloadType => Some("InvalidPayloadType"),
+      Self::Internal => Some("Internal"),
+      Self::Unauthorized => Some("Unauthorized"),
+      _ => None,
     }
   }
-
-}
-
-// struct ResourceReadHead, aligned to 8
-#[repr(transparent)]
-#[derive(Clone, Copy, PartialEq)]
-pub struct ResourceReadHead(pub [u8; 16]);
-impl Default for ResourceReadHead { 
-  fn default() -> Self { 
-    Self([0; 16])
-  }
 }
-impl ::core::fmt::Debug for ResourceReadHead {
+impl ::core::fmt::Debug for ErrorKind {
   fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-    f.debug_struct("ResourceReadHead")
-      .field("start", &self.start())
-      .field("stop_inclusive", &self.stop_inclusive())
-      .finish()
-  }
-}
-
-impl ::flatbuffers::SimpleToVerifyInSlice for ResourceReadHead {}
-impl<'a> ::flatbuffers::Follow<'a> for ResourceReadHead {
-  type Inner = &'a ResourceReadHead;
-  #[inline]
-  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-    unsafe { <&'a ResourceReadHead>::follow(buf, loc) }
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
   }
 }
-impl<'a> ::flatbuffers::Follow<'a> for &'a ResourceReadHead {
-  type Inner = &'a ResourceReadHead;
+impl<'a> ::flatbuffers::Follow<'a> for ErrorKind {
+  type Inner = Self;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-    unsafe { ::flatbuffers::follow_cast_ref::<ResourceReadHead>(buf, loc) }
+    let b = unsafe { ::flatbuffers::read_scalar_at::<u8>(buf, loc) };
+    Self(b)
   }
 }
-impl<'b> ::flatbuffers::Push for ResourceReadHead {
-    type Output = ResourceReadHead;
+
+impl ::flatbuffers::Push for ErrorKind {
+    type Output = ErrorKind;
     #[inline]
     unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
-        let src = unsafe { ::core::slice::from_raw_parts(self as *const ResourceReadHead as *const u8, <Self as ::flatbuffers::Push>::size()) };
-        dst.copy_from_slice(src);
-    }
-    #[inline]
-    fn alignment() -> ::flatbuffers::PushAlignment {
-        ::flatbuffers::PushAlignment::new(8)
+        unsafe { ::flatbuffers::emplace_scalar::<u8>(dst, self.0) };
     }
 }
 
-impl<'a> ::flatbuffers::Verifiable for ResourceReadHead {
+impl ::flatbuffers::EndianScalar for ErrorKind {
+  type Scalar = u8;
   #[inline]
-  fn run_verifier(
-    v: &mut ::flatbuffers::Verifier, pos: usize
+  fn to_little_endian(self) -> u8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: u8) -> Self {
+    let b = u8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> ::flatbuffers::Verifiable for ErrorKind {
+  #[inline]
+  fn run_verifier(
+    v: &mut ::flatbuffers::Verifier, pos: usize
+  ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
+    u8::run_verifier(v, pos)
+  }
+}
+
+impl ::flatbuffers::SimpleToVerifyInSlice for ErrorKind {}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_COMPANION_RESOURCE_SIZE: u8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_COMPANION_RESOURCE_SIZE: u8 = 2;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_COMPANION_RESOURCE_SIZE: [CompanionResourceSize; 3] = [
+  CompanionResourceSize::NONE,
+  CompanionResourceSize::Unknown,
+  CompanionResourceSize::Known,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct CompanionResourceSize(pub u8);
+#[allow(non_upper_case_globals)]
+impl CompanionResourceSize {
+  pub const NONE: Self = Self(0);
+  pub const Unknown: Self = Self(1);
+  pub const Known: Self = Self(2);
+
+  pub const ENUM_MIN: u8 = 0;
+  pub const ENUM_MAX: u8 = 2;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::NONE,
+    Self::Unknown,
+    Self::Known,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::NONE => Some("NONE"),
+      Self::Unknown => Some("Unknown"),
+      Self::Known => Some("Known"),
+      _ => None,
+    }
+  }
+}
+impl ::core::fmt::Debug for CompanionResourceSize {
+  fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> ::flatbuffers::Follow<'a> for CompanionResourceSize {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = unsafe { ::flatbuffers::read_scalar_at::<u8>(buf, loc) };
+    Self(b)
+  }
+}
+
+impl ::flatbuffers::Push for CompanionResourceSize {
+    type Output = CompanionResourceSize;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        unsafe { ::flatbuffers::emplace_scalar::<u8>(dst, self.0) };
+    }
+}
+
+impl ::flatbuffers::EndianScalar for CompanionResourceSize {
+  type Scalar = u8;
+  #[inline]
+  fn to_little_endian(self) -> u8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: u8) -> Self {
+    let b = u8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> ::flatbuffers::Verifiable for CompanionResourceSize {
+  #[inline]
+  fn run_verifier(
+    v: &mut ::flatbuffers::Verifier, pos: usize
+  ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
+    u8::run_verifier(v, pos)
+  }
+}
+
+impl ::flatbuffers::SimpleToVerifyInSlice for CompanionResourceSize {}
+pub struct CompanionResourceSizeUnionTableOffset {}
+
+// struct Time, aligned to 8
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq)]
+pub struct Time(pub [u8; 8]);
+impl Default for Time { 
+  fn default() -> Self { 
+    Self([0; 8])
+  }
+}
+impl ::core::fmt::Debug for Time {
+  fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+    f.debug_struct("Time")
+      .field("micros", &self.micros())
+      .finish()
+  }
+}
+
+impl ::flatbuffers::SimpleToVerifyInSlice for Time {}
+impl<'a> ::flatbuffers::Follow<'a> for Time {
+  type Inner = &'a Time;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    unsafe { <&'a Time>::follow(buf, loc) }
+  }
+}
+impl<'a> ::flatbuffers::Follow<'a> for &'a Time {
+  type Inner = &'a Time;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    unsafe { ::flatbuffers::follow_cast_ref::<Time>(buf, loc) }
+  }
+}
+impl<'b> ::flatbuffers::Push for Time {
+    type Output = Time;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        let src = unsafe { ::core::slice::from_raw_parts(self as *const Time as *const u8, <Self as ::flatbuffers::Push>::size()) };
+        dst.copy_from_slice(src);
+    }
+    #[inline]
+    fn alignment() -> ::flatbuffers::PushAlignment {
+        ::flatbuffers::PushAlignment::new(8)
+    }
+}
+
+impl<'a> ::flatbuffers::Verifiable for Time {
+  #[inline]
+  fn run_verifier(
+    v: &mut ::flatbuffers::Verifier, pos: usize
   ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
     v.in_buffer::<Self>(pos)
   }
 }
 
-impl<'a> ResourceReadHead {
+impl<'a> Time {
   #[allow(clippy::too_many_arguments)]
   pub fn new(
-    start: u64,
-    stop_inclusive: u64,
+    micros: u64,
   ) -> Self {
-    let mut s = Self([0; 16]);
-    s.set_start(start);
-    s.set_stop_inclusive(stop_inclusive);
+    let mut s = Self([0; 8]);
+    s.set_micros(micros);
     s
   }
 
-  pub fn start(&self) -> u64 {
+  pub fn micros(&self) -> u64 {
     let mut mem = ::core::mem::MaybeUninit::<<u64 as ::flatbuffers::EndianScalar>::Scalar>::uninit();
     // Safety:
     // Created from a valid Table for this object
@@ -1360,7 +1547,7 @@ impl<'a> ResourceReadHead {
     })
   }
 
-  pub fn set_start(&mut self, x: u64) {
+  pub fn set_micros(&mut self, x: u64) {
     let x_le = ::flatbuffers::EndianScalar::to_little_endian(x);
     // Safety:
     // Created from a valid Table for this object
@@ -1374,22 +1561,91 @@ impl<'a> ResourceReadHead {
     }
   }
 
-  pub fn stop_inclusive(&self) -> u64 {
-    let mut mem = ::core::mem::MaybeUninit::<<u64 as ::flatbuffers::EndianScalar>::Scalar>::uninit();
+}
+
+// struct VideoResolution, aligned to 4
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq)]
+pub struct VideoResolution(pub [u8; 8]);
+impl Default for VideoResolution { 
+  fn default() -> Self { 
+    Self([0; 8])
+  }
+}
+impl ::core::fmt::Debug for VideoResolution {
+  fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+    f.debug_struct("VideoResolution")
+      .field("width", &self.width())
+      .field("height", &self.height())
+      .finish()
+  }
+}
+
+impl ::flatbuffers::SimpleToVerifyInSlice for VideoResolution {}
+impl<'a> ::flatbuffers::Follow<'a> for VideoResolution {
+  type Inner = &'a VideoResolution;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    unsafe { <&'a VideoResolution>::follow(buf, loc) }
+  }
+}
+impl<'a> ::flatbuffers::Follow<'a> for &'a VideoResolution {
+  type Inner = &'a VideoResolution;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    unsafe { ::flatbuffers::follow_cast_ref::<VideoResolution>(buf, loc) }
+  }
+}
+impl<'b> ::flatbuffers::Push for VideoResolution {
+    type Output = VideoResolution;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        let src = unsafe { ::core::slice::from_raw_parts(self as *const VideoResolution as *const u8, <Self as ::flatbuffers::Push>::size()) };
+        dst.copy_from_slice(src);
+    }
+    #[inline]
+    fn alignment() -> ::flatbuffers::PushAlignment {
+        ::flatbuffers::PushAlignment::new(4)
+    }
+}
+
+impl<'a> ::flatbuffers::Verifiable for VideoResolution {
+  #[inline]
+  fn run_verifier(
+    v: &mut ::flatbuffers::Verifier, pos: usize
+  ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
+    v.in_buffer::<Self>(pos)
+  }
+}
+
+impl<'a> VideoResolution {
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    width: u32,
+    height: u32,
+  ) -> Self {
+    let mut s = Self([0; 8]);
+    s.set_width(width);
+    s.set_height(height);
+    s
+  }
+
+  pub fn width(&self) -> u32 {
+    let mut mem = ::core::mem::MaybeUninit::<<u32 as ::flatbuffers::EndianScalar>::Scalar>::uninit();
     // Safety:
     // Created from a valid Table for this object
     // Which contains a valid value in this slot
     ::flatbuffers::EndianScalar::from_little_endian(unsafe {
       ::core::ptr::copy_nonoverlapping(
-        self.0[8..].as_ptr(),
+        self.0[0..].as_ptr(),
         mem.as_mut_ptr() as *mut u8,
-        ::core::mem::size_of::<<u64 as ::flatbuffers::EndianScalar>::Scalar>(),
+        ::core::mem::size_of::<<u32 as ::flatbuffers::EndianScalar>::Scalar>(),
       );
       mem.assume_init()
     })
   }
 
-  pub fn set_stop_inclusive(&mut self, x: u64) {
+  pub fn set_width(&mut self, x: u32) {
     let x_le = ::flatbuffers::EndianScalar::to_little_endian(x);
     // Safety:
     // Created from a valid Table for this object
@@ -1397,9 +1653,165 @@ impl<'a> ResourceReadHead {
     unsafe {
       ::core::ptr::copy_nonoverlapping(
         &x_le as *const _ as *const u8,
-        self.0[8..].as_mut_ptr(),
-        ::core::mem::size_of::<<u64 as ::flatbuffers::EndianScalar>::Scalar>(),
-      );
+        self.0[0..].as_mut_ptr(),
+        ::core::mem::size_of::<<u32 as ::flatbuffers::EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn height(&self) -> u32 {
+    let mut mem = ::core::mem::MaybeUninit::<<u32 as ::flatbuffers::EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    ::flatbuffers::EndianScalar::from_little_endian(unsafe {
+      ::core::ptr::copy_nonoverlapping(
+        self.0[4..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        ::core::mem::size_of::<<u32 as ::flatbuffers::EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_height(&mut self, x: u32) {
+    let x_le = ::flatbuffers::EndianScalar::to_little_endian(x);
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      ::core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[4..].as_mut_ptr(),
+        ::core::mem::size_of::<<u32 as ::flatbuffers::EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+}
+
+// struct ResourceReadHead, aligned to 8
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq)]
+pub struct ResourceReadHead(pub [u8; 16]);
+impl Default for ResourceReadHead { 
+  fn default() -> Self { 
+    Self([0; 16])
+  }
+}
+impl ::core::fmt::Debug for ResourceReadHead {
+  fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+    f.debug_struct("ResourceReadHead")
+      .field("start", &self.start())
+      .field("stop_inclusive", &self.stop_inclusive())
+      .finish()
+  }
+}
+
+impl ::flatbuffers::SimpleToVerifyInSlice for ResourceReadHead {}
+impl<'a> ::flatbuffers::Follow<'a> for ResourceReadHead {
+  type Inner = &'a ResourceReadHead;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    unsafe { <&'a ResourceReadHead>::follow(buf, loc) }
+  }
+}
+impl<'a> ::flatbuffers::Follow<'a> for &'a ResourceReadHead {
+  type Inner = &'a ResourceReadHead;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    unsafe { ::flatbuffers::follow_cast_ref::<ResourceReadHead>(buf, loc) }
+  }
+}
+impl<'b> ::flatbuffers::Push for ResourceReadHead {
+    type Output = ResourceReadHead;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        let src = unsafe { ::core::slice::from_raw_parts(self as *const ResourceReadHead as *const u8, <Self as ::flatbuffers::Push>::size()) };
+        dst.copy_from_slice(src);
+    }
+    #[inline]
+    fn alignment() -> ::flatbuffers::PushAlignment {
+        ::flatbuffers::PushAlignment::new(8)
+    }
+}
+
+impl<'a> ::flatbuffers::Verifiable for ResourceReadHead {
+  #[inline]
+  fn run_verifier(
+    v: &mut ::flatbuffers::Verifier, pos: usize
+  ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
+    v.in_buffer::<Self>(pos)
+  }
+}
+
+impl<'a> ResourceReadHead {
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    start: u64,
+    stop_inclusive: u64,
+  ) -> Self {
+    let mut s = Self([0; 16]);
+    s.set_start(start);
+    s.set_stop_inclusive(stop_inclusive);
+    s
+  }
+
+  pub fn start(&self) -> u64 {
+    let mut mem = ::core::mem::MaybeUninit::<<u64 as ::flatbuffers::EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    ::flatbuffers::EndianScalar::from_little_endian(unsafe {
+      ::core::ptr::copy_nonoverlapping(
+        self.0[0..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        ::core::mem::size_of::<<u64 as ::flatbuffers::EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_start(&mut self, x: u64) {
+    let x_le = ::flatbuffers::EndianScalar::to_little_endian(x);
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      ::core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[0..].as_mut_ptr(),
+        ::core::mem::size_of::<<u64 as ::flatbuffers::EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn stop_inclusive(&self) -> u64 {
+    let mut mem = ::core::mem::MaybeUninit::<<u64 as ::flatbuffers::EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    ::flatbuffers::EndianScalar::from_little_endian(unsafe {
+      ::core::ptr::copy_nonoverlapping(
+        self.0[8..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        ::core::mem::size_of::<<u64 as ::flatbuffers::EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_stop_inclusive(&mut self, x: u64) {
+    let x_le = ::flatbuffers::EndianScalar::to_little_endian(x);
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      ::core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[8..].as_mut_ptr(),
+        ::core::mem::size_of::<<u64 as ::flatbuffers::EndianScalar>::Scalar>(),
+      );
     }
   }
 
@@ -1798,3467 +2210,6159 @@ impl<'a> Packet<'a> {
       None
     }
   }
-
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_pairing_request(&self) -> Option<PairingRequest<'a>> {
+    if self.payload_type() == Message::PairingRequest {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { PairingRequest::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_pairing_result(&self) -> Option<PairingResult<'a>> {
+    if self.payload_type() == Message::PairingResult {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { PairingResult::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_seek_relative(&self) -> Option<SeekRelative<'a>> {
+    if self.payload_type() == Message::SeekRelative {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { SeekRelative::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_frame_step(&self) -> Option<FrameStep<'a>> {
+    if self.payload_type() == Message::FrameStep {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { FrameStep::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_chapters_available(&self) -> Option<ChaptersAvailable<'a>> {
+    if self.payload_type() == Message::ChaptersAvailable {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { ChaptersAvailable::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_seek_to_chapter(&self) -> Option<SeekToChapter<'a>> {
+    if self.payload_type() == Message::SeekToChapter {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { SeekToChapter::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_queue_move(&self) -> Option<QueueMove<'a>> {
+    if self.payload_type() == Message::QueueMove {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { QueueMove::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_queue_mode_changed(&self) -> Option<QueueModeChanged<'a>> {
+    if self.payload_type() == Message::QueueModeChanged {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { QueueModeChanged::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_queue_insert_batch(&self) -> Option<QueueInsertBatch<'a>> {
+    if self.payload_type() == Message::QueueInsertBatch {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { QueueInsertBatch::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_sleep_timer_changed(&self) -> Option<SleepTimerChanged<'a>> {
+    if self.payload_type() == Message::SleepTimerChanged {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { SleepTimerChanged::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_playback_history_request(&self) -> Option<PlaybackHistoryRequest<'a>> {
+    if self.payload_type() == Message::PlaybackHistoryRequest {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { PlaybackHistoryRequest::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_playback_history(&self) -> Option<PlaybackHistory<'a>> {
+    if self.payload_type() == Message::PlaybackHistory {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { PlaybackHistory::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_subtitle_style_changed(&self) -> Option<SubtitleStyleChanged<'a>> {
+    if self.payload_type() == Message::SubtitleStyleChanged {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { SubtitleStyleChanged::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_delay_changed(&self) -> Option<DelayChanged<'a>> {
+    if self.payload_type() == Message::DelayChanged {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { DelayChanged::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+}
+
+impl ::flatbuffers::Verifiable for Packet<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut ::flatbuffers::Verifier, pos: usize
+  ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
+    v.visit_table(pos)?
+     .visit_union::<Message, _>("payload_type", Self::VT_PAYLOAD_TYPE, "payload", Self::VT_PAYLOAD, false, |key, v, pos| {
+        match key {
+          Message::Load => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<Load>>("Message::Load", pos),
+          Message::ProgressChanged => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<ProgressChanged>>("Message::ProgressChanged", pos),
+          Message::VolumeChanged => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<VolumeChanged>>("Message::VolumeChanged", pos),
+          Message::PlaybackStateChanged => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<PlaybackStateChanged>>("Message::PlaybackStateChanged", pos),
+          Message::SpeedChanged => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<SpeedChanged>>("Message::SpeedChanged", pos),
+          Message::SenderIntroduction => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<SenderIntroduction>>("Message::SenderIntroduction", pos),
+          Message::ReceiverIntroduction => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<ReceiverIntroduction>>("Message::ReceiverIntroduction", pos),
+          Message::StopPlayback => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<StopPlayback>>("Message::StopPlayback", pos),
+          Message::StartMirroringSession => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<StartMirroringSession>>("Message::StartMirroringSession", pos),
+          Message::MirroringSessionDescription => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<MirroringSessionDescription>>("Message::MirroringSessionDescription", pos),
+          Message::QueueInsert => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<QueueInsert>>("Message::QueueInsert", pos),
+          Message::QueueRemove => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<QueueRemove>>("Message::QueueRemove", pos),
+          Message::TracksAvailable => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<TracksAvailable>>("Message::TracksAvailable", pos),
+          Message::ChangeTrack => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<ChangeTrack>>("Message::ChangeTrack", pos),
+          Message::QueueItemSelected => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<QueueItemSelected>>("Message::QueueItemSelected", pos),
+          Message::AddSubtitleSource => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<AddSubtitleSource>>("Message::AddSubtitleSource", pos),
+          Message::SetProgressUpdateInterval => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<SetProgressUpdateInterval>>("Message::SetProgressUpdateInterval", pos),
+          Message::CompanionHelloRequest => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<CompanionHelloRequest>>("Message::CompanionHelloRequest", pos),
+          Message::CompanionHelloResponse => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<CompanionHelloResponse>>("Message::CompanionHelloResponse", pos),
+          Message::CompanionResourceInfoRequest => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<CompanionResourceInfoRequest>>("Message::CompanionResourceInfoRequest", pos),
+          Message::CompanionResourceInfoResponse => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<CompanionResourceInfoResponse>>("Message::CompanionResourceInfoResponse", pos),
+          Message::CompanionResourceRequest => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<CompanionResourceRequest>>("Message::CompanionResourceRequest", pos),
+          Message::Error => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<Error>>("Message::Error", pos),
+          Message::PairingRequest => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<PairingRequest>>("Message::PairingRequest", pos),
+          Message::PairingResult => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<PairingResult>>("Message::PairingResult", pos),
+          Message::SeekRelative => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<SeekRelative>>("Message::SeekRelative", pos),
+          Message::FrameStep => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<FrameStep>>("Message::FrameStep", pos),
+          Message::ChaptersAvailable => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<ChaptersAvailable>>("Message::ChaptersAvailable", pos),
+          Message::SeekToChapter => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<SeekToChapter>>("Message::SeekToChapter", pos),
+          Message::QueueMove => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<QueueMove>>("Message::QueueMove", pos),
+          Message::QueueModeChanged => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<QueueModeChanged>>("Message::QueueModeChanged", pos),
+          Message::QueueInsertBatch => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<QueueInsertBatch>>("Message::QueueInsertBatch", pos),
+          Message::SleepTimerChanged => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<SleepTimerChanged>>("Message::SleepTimerChanged", pos),
+          Message::PlaybackHistoryRequest => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<PlaybackHistoryRequest>>("Message::PlaybackHistoryRequest", pos),
+          Message::PlaybackHistory => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<PlaybackHistory>>("Message::PlaybackHistory", pos),
+          Message::SubtitleStyleChanged => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<SubtitleStyleChanged>>("Message::SubtitleStyleChanged", pos),
+          Message::DelayChanged => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<DelayChanged>>("Message::DelayChanged", pos),
+          _ => Ok(()),
+        }
+     })?
+     .finish();
+    Ok(())
+  }
+}
+pub struct PacketArgs {
+    pub payload_type: Message,
+    pub payload: Option<::flatbuffers::WIPOffset<::flatbuffers::UnionWIPOffset>>,
+}
+impl<'a> Default for PacketArgs {
+  #[inline]
+  fn default() -> Self {
+    PacketArgs {
+      payload_type: Message::NONE,
+      payload: None,
+    }
+  }
+}
+
+pub struct PacketBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: ::flatbuffers::WIPOffset<::flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> PacketBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_payload_type(&mut self, payload_type: Message) {
+    self.fbb_.push_slot::<Message>(Packet::VT_PAYLOAD_TYPE, payload_type, Message::NONE);
+  }
+  #[inline]
+  pub fn add_payload(&mut self, payload: ::flatbuffers::WIPOffset<::flatbuffers::UnionWIPOffset>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(Packet::VT_PAYLOAD, payload);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> PacketBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    PacketBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> ::flatbuffers::WIPOffset<Packet<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    ::flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl ::core::fmt::Debug for Packet<'_> {
+  fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+    let mut ds = f.debug_struct("Packet");
+      ds.field("payload_type", &self.payload_type());
+      match self.payload_type() {
+        Message::Load => {
+          if let Some(x) = self.payload_as_load() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Message::ProgressChanged => {
+          if let Some(x) = self.payload_as_progress_changed() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Message::VolumeChanged => {
+          if let Some(x) = self.payload_as_volume_changed() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Message::PlaybackStateChanged => {
+          if let Some(x) = self.payload_as_playback_state_changed() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Message::SpeedChanged => {
+          if let Some(x) = self.payload_as_speed_changed() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Message::SenderIntroduction => {
+          if let Some(x) = self.payload_as_sender_introduction() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Message::ReceiverIntroduction => {
+          if let Some(x) = self.payload_as_receiver_introduction() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Message::StopPlayback => {
+          if let Some(x) = self.payload_as_stop_playback() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Message::StartMirroringSession => {
+          if let Some(x) = self.payload_as_start_mirroring_session() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Message::MirroringSessionDescription => {
+          if let Some(x) = self.payload_as_mirroring_session_description() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Message::QueueInsert => {
+          if let Some(x) = self.payload_as_queue_insert() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Message::QueueRemove => {
+          if let Some(x) = self.payload_as_queue_remove() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Message::TracksAvailable => {
+          if let Some(x) = self.payload_as_tracks_available() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Message::ChangeTrack => {
+          if let Some(x) = self.payload_as_change_track() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Message::QueueItemSelected => {
+          if let Some(x) = self.payload_as_queue_item_selected() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Message::AddSubtitleSource => {
+          if let Some(x) = self.payload_as_add_subtitle_source() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Message::SetProgressUpdateInterval => {
+          if let Some(x) = self.payload_as_set_progress_update_interval() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Message::CompanionHelloRequest => {
+          if let Some(x) = self.payload_as_companion_hello_request() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Message::CompanionHelloResponse => {
+          if let Some(x) = self.payload_as_companion_hello_response() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Message::CompanionResourceInfoRequest => {
+          if let Some(x) = self.payload_as_companion_resource_info_request() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Message::CompanionResourceInfoResponse => {
+          if let Some(x) = self.payload_as_companion_resource_info_response() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Message::CompanionResourceRequest => {
+          if let Some(x) = self.payload_as_companion_resource_request() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Message::Error => {
+          if let Some(x) = self.payload_as_error() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Message::PairingRequest => {
+          if let Some(x) = self.payload_as_pairing_request() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Message::PairingResult => {
+          if let Some(x) = self.payload_as_pairing_result() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Message::SeekRelative => {
+          if let Some(x) = self.payload_as_seek_relative() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Message::FrameStep => {
+          if let Some(x) = self.payload_as_frame_step() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Message::ChaptersAvailable => {
+          if let Some(x) = self.payload_as_chapters_available() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Message::SeekToChapter => {
+          if let Some(x) = self.payload_as_seek_to_chapter() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Message::QueueMove => {
+          if let Some(x) = self.payload_as_queue_move() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Message::QueueModeChanged => {
+          if let Some(x) = self.payload_as_queue_mode_changed() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Message::QueueInsertBatch => {
+          if let Some(x) = self.payload_as_queue_insert_batch() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Message::SleepTimerChanged => {
+          if let Some(x) = self.payload_as_sleep_timer_changed() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Message::PlaybackHistoryRequest => {
+          if let Some(x) = self.payload_as_playback_history_request() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Message::PlaybackHistory => {
+          if let Some(x) = self.payload_as_playback_history() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Message::SubtitleStyleChanged => {
+          if let Some(x) = self.payload_as_subtitle_style_changed() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Message::DelayChanged => {
+          if let Some(x) = self.payload_as_delay_changed() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        _ => {
+          let x: Option<()> = None;
+          ds.field("payload", &x)
+        },
+      };
+      ds.finish()
+  }
+}
+pub enum ChapterOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Chapter<'a> {
+  pub _tab: ::flatbuffers::Table<'a>,
+}
+
+impl<'a> ::flatbuffers::Follow<'a> for Chapter<'a> {
+  type Inner = Chapter<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { ::flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Chapter<'a> {
+  pub const VT_START: ::flatbuffers::VOffsetT = 4;
+  pub const VT_LENGTH: ::flatbuffers::VOffsetT = 6;
+  pub const VT_TITLE: ::flatbuffers::VOffsetT = 8;
+  pub const VT_SKIP_LABEL: ::flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: ::flatbuffers::Table<'a>) -> Self {
+    Chapter { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: ::flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut ::flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ChapterArgs<'args>
+  ) -> ::flatbuffers::WIPOffset<Chapter<'bldr>> {
+    let mut builder = ChapterBuilder::new(_fbb);
+    if let Some(x) = args.skip_label { builder.add_skip_label(x); }
+    if let Some(x) = args.title { builder.add_title(x); }
+    if let Some(x) = args.length { builder.add_length(x); }
+    if let Some(x) = args.start { builder.add_start(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn start(&self) -> Option<&'a Time> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Time>(Chapter::VT_START, None)}
+  }
+  #[inline]
+  pub fn length(&self) -> Option<&'a Time> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Time>(Chapter::VT_LENGTH, None)}
+  }
+  #[inline]
+  pub fn title(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<&str>>(Chapter::VT_TITLE, None).unwrap()}
+  }
+  #[inline]
+  pub fn skip_label(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<&str>>(Chapter::VT_SKIP_LABEL, None)}
+  }
+}
+
+impl ::flatbuffers::Verifiable for Chapter<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut ::flatbuffers::Verifier, pos: usize
+  ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
+    v.visit_table(pos)?
+     .visit_field::<Time>("start", Self::VT_START, false)?
+     .visit_field::<Time>("length", Self::VT_LENGTH, false)?
+     .visit_field::<::flatbuffers::ForwardsUOffset<&str>>("title", Self::VT_TITLE, true)?
+     .visit_field::<::flatbuffers::ForwardsUOffset<&str>>("skip_label", Self::VT_SKIP_LABEL, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ChapterArgs<'a> {
+    pub start: Option<&'a Time>,
+    pub length: Option<&'a Time>,
+    pub title: Option<::flatbuffers::WIPOffset<&'a str>>,
+    pub skip_label: Option<::flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for ChapterArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ChapterArgs {
+      start: None,
+      length: None,
+      title: None, // required field
+      skip_label: None,
+    }
+  }
+}
+
+pub struct ChapterBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: ::flatbuffers::WIPOffset<::flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> ChapterBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_start(&mut self, start: &Time) {
+    self.fbb_.push_slot_always::<&Time>(Chapter::VT_START, start);
+  }
+  #[inline]
+  pub fn add_length(&mut self, length: &Time) {
+    self.fbb_.push_slot_always::<&Time>(Chapter::VT_LENGTH, length);
+  }
+  #[inline]
+  pub fn add_title(&mut self, title: ::flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(Chapter::VT_TITLE, title);
+  }
+  #[inline]
+  pub fn add_skip_label(&mut self, skip_label: ::flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(Chapter::VT_SKIP_LABEL, skip_label);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> ChapterBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ChapterBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> ::flatbuffers::WIPOffset<Chapter<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, Chapter::VT_TITLE,"title");
+    ::flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl ::core::fmt::Debug for Chapter<'_> {
+  fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+    let mut ds = f.debug_struct("Chapter");
+      ds.field("start", &self.start());
+      ds.field("length", &self.length());
+      ds.field("title", &self.title());
+      ds.field("skip_label", &self.skip_label());
+      ds.finish()
+  }
+}
+pub enum VideoMetadataOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct VideoMetadata<'a> {
+  pub _tab: ::flatbuffers::Table<'a>,
+}
+
+impl<'a> ::flatbuffers::Follow<'a> for VideoMetadata<'a> {
+  type Inner = VideoMetadata<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { ::flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> VideoMetadata<'a> {
+  pub const VT_CHAPTERS: ::flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: ::flatbuffers::Table<'a>) -> Self {
+    VideoMetadata { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: ::flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut ::flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args VideoMetadataArgs<'args>
+  ) -> ::flatbuffers::WIPOffset<VideoMetadata<'bldr>> {
+    let mut builder = VideoMetadataBuilder::new(_fbb);
+    if let Some(x) = args.chapters { builder.add_chapters(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn chapters(&self) -> Option<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<Chapter<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<Chapter>>>>(VideoMetadata::VT_CHAPTERS, None)}
+  }
+}
+
+impl ::flatbuffers::Verifiable for VideoMetadata<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut ::flatbuffers::Verifier, pos: usize
+  ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
+    v.visit_table(pos)?
+     .visit_field::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'_, ::flatbuffers::ForwardsUOffset<Chapter>>>>("chapters", Self::VT_CHAPTERS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct VideoMetadataArgs<'a> {
+    pub chapters: Option<::flatbuffers::WIPOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<Chapter<'a>>>>>,
+}
+impl<'a> Default for VideoMetadataArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    VideoMetadataArgs {
+      chapters: None,
+    }
+  }
+}
+
+pub struct VideoMetadataBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: ::flatbuffers::WIPOffset<::flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> VideoMetadataBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_chapters(&mut self, chapters: ::flatbuffers::WIPOffset<::flatbuffers::Vector<'b , ::flatbuffers::ForwardsUOffset<Chapter<'b >>>>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(VideoMetadata::VT_CHAPTERS, chapters);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> VideoMetadataBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    VideoMetadataBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> ::flatbuffers::WIPOffset<VideoMetadata<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    ::flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl ::core::fmt::Debug for VideoMetadata<'_> {
+  fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+    let mut ds = f.debug_struct("VideoMetadata");
+      ds.field("chapters", &self.chapters());
+      ds.finish()
+  }
+}
+pub enum AudioMetadataOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct AudioMetadata<'a> {
+  pub _tab: ::flatbuffers::Table<'a>,
+}
+
+impl<'a> ::flatbuffers::Follow<'a> for AudioMetadata<'a> {
+  type Inner = AudioMetadata<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { ::flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> AudioMetadata<'a> {
+  pub const VT_ARTIST: ::flatbuffers::VOffsetT = 4;
+  pub const VT_ALBUM: ::flatbuffers::VOffsetT = 6;
+  pub const VT_CHAPTERS: ::flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: ::flatbuffers::Table<'a>) -> Self {
+    AudioMetadata { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: ::flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut ::flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args AudioMetadataArgs<'args>
+  ) -> ::flatbuffers::WIPOffset<AudioMetadata<'bldr>> {
+    let mut builder = AudioMetadataBuilder::new(_fbb);
+    if let Some(x) = args.chapters { builder.add_chapters(x); }
+    if let Some(x) = args.album { builder.add_album(x); }
+    if let Some(x) = args.artist { builder.add_artist(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn artist(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<&str>>(AudioMetadata::VT_ARTIST, None)}
+  }
+  #[inline]
+  pub fn album(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<&str>>(AudioMetadata::VT_ALBUM, None)}
+  }
+  #[inline]
+  pub fn chapters(&self) -> Option<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<Chapter<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<Chapter>>>>(AudioMetadata::VT_CHAPTERS, None)}
+  }
+}
+
+impl ::flatbuffers::Verifiable for AudioMetadata<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut ::flatbuffers::Verifier, pos: usize
+  ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
+    v.visit_table(pos)?
+     .visit_field::<::flatbuffers::ForwardsUOffset<&str>>("artist", Self::VT_ARTIST, false)?
+     .visit_field::<::flatbuffers::ForwardsUOffset<&str>>("album", Self::VT_ALBUM, false)?
+     .visit_field::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'_, ::flatbuffers::ForwardsUOffset<Chapter>>>>("chapters", Self::VT_CHAPTERS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct AudioMetadataArgs<'a> {
+    pub artist: Option<::flatbuffers::WIPOffset<&'a str>>,
+    pub album: Option<::flatbuffers::WIPOffset<&'a str>>,
+    pub chapters: Option<::flatbuffers::WIPOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<Chapter<'a>>>>>,
+}
+impl<'a> Default for AudioMetadataArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    AudioMetadataArgs {
+      artist: None,
+      album: None,
+      chapters: None,
+    }
+  }
+}
+
+pub struct AudioMetadataBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: ::flatbuffers::WIPOffset<::flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> AudioMetadataBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_artist(&mut self, artist: ::flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(AudioMetadata::VT_ARTIST, artist);
+  }
+  #[inline]
+  pub fn add_album(&mut self, album: ::flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(AudioMetadata::VT_ALBUM, album);
+  }
+  #[inline]
+  pub fn add_chapters(&mut self, chapters: ::flatbuffers::WIPOffset<::flatbuffers::Vector<'b , ::flatbuffers::ForwardsUOffset<Chapter<'b >>>>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(AudioMetadata::VT_CHAPTERS, chapters);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> AudioMetadataBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    AudioMetadataBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> ::flatbuffers::WIPOffset<AudioMetadata<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    ::flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl ::core::fmt::Debug for AudioMetadata<'_> {
+  fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+    let mut ds = f.debug_struct("AudioMetadata");
+      ds.field("artist", &self.artist());
+      ds.field("album", &self.album());
+      ds.field("chapters", &self.chapters());
+      ds.finish()
+  }
+}
+pub enum RequestHeaderOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct RequestHeader<'a> {
+  pub _tab: ::flatbuffers::Table<'a>,
+}
+
+impl<'a> ::flatbuffers::Follow<'a> for RequestHeader<'a> {
+  type Inner = RequestHeader<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { ::flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> RequestHeader<'a> {
+  pub const VT_KEY: ::flatbuffers::VOffsetT = 4;
+  pub const VT_VALUE: ::flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: ::flatbuffers::Table<'a>) -> Self {
+    RequestHeader { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: ::flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut ::flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args RequestHeaderArgs<'args>
+  ) -> ::flatbuffers::WIPOffset<RequestHeader<'bldr>> {
+    let mut builder = RequestHeaderBuilder::new(_fbb);
+    if let Some(x) = args.value { builder.add_value(x); }
+    if let Some(x) = args.key { builder.add_key(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn key(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<&str>>(RequestHeader::VT_KEY, None).unwrap()}
+  }
+  #[inline]
+  pub fn value(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<&str>>(RequestHeader::VT_VALUE, None).unwrap()}
+  }
+}
+
+impl ::flatbuffers::Verifiable for RequestHeader<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut ::flatbuffers::Verifier, pos: usize
+  ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
+    v.visit_table(pos)?
+     .visit_field::<::flatbuffers::ForwardsUOffset<&str>>("key", Self::VT_KEY, true)?
+     .visit_field::<::flatbuffers::ForwardsUOffset<&str>>("value", Self::VT_VALUE, true)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct RequestHeaderArgs<'a> {
+    pub key: Option<::flatbuffers::WIPOffset<&'a str>>,
+    pub value: Option<::flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for RequestHeaderArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    RequestHeaderArgs {
+      key: None, // required field
+      value: None, // required field
+    }
+  }
+}
+
+pub struct RequestHeaderBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: ::flatbuffers::WIPOffset<::flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> RequestHeaderBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_key(&mut self, key: ::flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(RequestHeader::VT_KEY, key);
+  }
+  #[inline]
+  pub fn add_value(&mut self, value: ::flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(RequestHeader::VT_VALUE, value);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> RequestHeaderBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    RequestHeaderBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> ::flatbuffers::WIPOffset<RequestHeader<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, RequestHeader::VT_KEY,"key");
+    self.fbb_.required(o, RequestHeader::VT_VALUE,"value");
+    ::flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl ::core::fmt::Debug for RequestHeader<'_> {
+  fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+    let mut ds = f.debug_struct("RequestHeader");
+      ds.field("key", &self.key());
+      ds.field("value", &self.value());
+      ds.finish()
+  }
+}
+pub enum GenericMetaStringOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct GenericMetaString<'a> {
+  pub _tab: ::flatbuffers::Table<'a>,
+}
+
+impl<'a> ::flatbuffers::Follow<'a> for GenericMetaString<'a> {
+  type Inner = GenericMetaString<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { ::flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> GenericMetaString<'a> {
+  pub const VT_VALUE: ::flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: ::flatbuffers::Table<'a>) -> Self {
+    GenericMetaString { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: ::flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut ::flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args GenericMetaStringArgs<'args>
+  ) -> ::flatbuffers::WIPOffset<GenericMetaString<'bldr>> {
+    let mut builder = GenericMetaStringBuilder::new(_fbb);
+    if let Some(x) = args.value { builder.add_value(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn value(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<&str>>(GenericMetaString::VT_VALUE, None)}
+  }
+}
+
+impl ::flatbuffers::Verifiable for GenericMetaString<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut ::flatbuffers::Verifier, pos: usize
+  ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
+    v.visit_table(pos)?
+     .visit_field::<::flatbuffers::ForwardsUOffset<&str>>("value", Self::VT_VALUE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct GenericMetaStringArgs<'a> {
+    pub value: Option<::flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for GenericMetaStringArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    GenericMetaStringArgs {
+      value: None,
+    }
+  }
+}
+
+pub struct GenericMetaStringBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: ::flatbuffers::WIPOffset<::flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> GenericMetaStringBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_value(&mut self, value: ::flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(GenericMetaString::VT_VALUE, value);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> GenericMetaStringBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    GenericMetaStringBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> ::flatbuffers::WIPOffset<GenericMetaString<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    ::flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl ::core::fmt::Debug for GenericMetaString<'_> {
+  fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+    let mut ds = f.debug_struct("GenericMetaString");
+      ds.field("value", &self.value());
+      ds.finish()
+  }
+}
+pub enum GenericMetaFloatOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct GenericMetaFloat<'a> {
+  pub _tab: ::flatbuffers::Table<'a>,
+}
+
+impl<'a> ::flatbuffers::Follow<'a> for GenericMetaFloat<'a> {
+  type Inner = GenericMetaFloat<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { ::flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> GenericMetaFloat<'a> {
+  pub const VT_VALUE: ::flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: ::flatbuffers::Table<'a>) -> Self {
+    GenericMetaFloat { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: ::flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut ::flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args GenericMetaFloatArgs
+  ) -> ::flatbuffers::WIPOffset<GenericMetaFloat<'bldr>> {
+    let mut builder = GenericMetaFloatBuilder::new(_fbb);
+    builder.add_value(args.value);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn value(&self) -> f64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f64>(GenericMetaFloat::VT_VALUE, Some(0.0)).unwrap()}
+  }
+}
+
+impl ::flatbuffers::Verifiable for GenericMetaFloat<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut ::flatbuffers::Verifier, pos: usize
+  ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
+    v.visit_table(pos)?
+     .visit_field::<f64>("value", Self::VT_VALUE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct GenericMetaFloatArgs {
+    pub value: f64,
+}
+impl<'a> Default for GenericMetaFloatArgs {
+  #[inline]
+  fn default() -> Self {
+    GenericMetaFloatArgs {
+      value: 0.0,
+    }
+  }
+}
+
+pub struct GenericMetaFloatBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: ::flatbuffers::WIPOffset<::flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> GenericMetaFloatBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_value(&mut self, value: f64) {
+    self.fbb_.push_slot::<f64>(GenericMetaFloat::VT_VALUE, value, 0.0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> GenericMetaFloatBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    GenericMetaFloatBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> ::flatbuffers::WIPOffset<GenericMetaFloat<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    ::flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl ::core::fmt::Debug for GenericMetaFloat<'_> {
+  fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+    let mut ds = f.debug_struct("GenericMetaFloat");
+      ds.field("value", &self.value());
+      ds.finish()
+  }
+}
+pub enum GenericMetaIntOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct GenericMetaInt<'a> {
+  pub _tab: ::flatbuffers::Table<'a>,
+}
+
+impl<'a> ::flatbuffers::Follow<'a> for GenericMetaInt<'a> {
+  type Inner = GenericMetaInt<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { ::flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> GenericMetaInt<'a> {
+  pub const VT_VALUE: ::flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: ::flatbuffers::Table<'a>) -> Self {
+    GenericMetaInt { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: ::flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut ::flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args GenericMetaIntArgs
+  ) -> ::flatbuffers::WIPOffset<GenericMetaInt<'bldr>> {
+    let mut builder = GenericMetaIntBuilder::new(_fbb);
+    builder.add_value(args.value);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn value(&self) -> i64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i64>(GenericMetaInt::VT_VALUE, Some(0)).unwrap()}
+  }
+}
+
+impl ::flatbuffers::Verifiable for GenericMetaInt<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut ::flatbuffers::Verifier, pos: usize
+  ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
+    v.visit_table(pos)?
+     .visit_field::<i64>("value", Self::VT_VALUE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct GenericMetaIntArgs {
+    pub value: i64,
+}
+impl<'a> Default for GenericMetaIntArgs {
+  #[inline]
+  fn default() -> Self {
+    GenericMetaIntArgs {
+      value: 0,
+    }
+  }
+}
+
+pub struct GenericMetaIntBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: ::flatbuffers::WIPOffset<::flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> GenericMetaIntBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_value(&mut self, value: i64) {
+    self.fbb_.push_slot::<i64>(GenericMetaInt::VT_VALUE, value, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> GenericMetaIntBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    GenericMetaIntBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> ::flatbuffers::WIPOffset<GenericMetaInt<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    ::flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl ::core::fmt::Debug for GenericMetaInt<'_> {
+  fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+    let mut ds = f.debug_struct("GenericMetaInt");
+      ds.field("value", &self.value());
+      ds.finish()
+  }
+}
+pub enum GenericMetaListOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct GenericMetaList<'a> {
+  pub _tab: ::flatbuffers::Table<'a>,
+}
+
+impl<'a> ::flatbuffers::Follow<'a> for GenericMetaList<'a> {
+  type Inner = GenericMetaList<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { ::flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> GenericMetaList<'a> {
+  pub const VT_VALUE: ::flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: ::flatbuffers::Table<'a>) -> Self {
+    GenericMetaList { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: ::flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut ::flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args GenericMetaListArgs<'args>
+  ) -> ::flatbuffers::WIPOffset<GenericMetaList<'bldr>> {
+    let mut builder = GenericMetaListBuilder::new(_fbb);
+    if let Some(x) = args.value { builder.add_value(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn value(&self) -> Option<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<WrappedGenericMetaValue<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<WrappedGenericMetaValue>>>>(GenericMetaList::VT_VALUE, None)}
+  }
+}
+
+impl ::flatbuffers::Verifiable for GenericMetaList<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut ::flatbuffers::Verifier, pos: usize
+  ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
+    v.visit_table(pos)?
+     .visit_field::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'_, ::flatbuffers::ForwardsUOffset<WrappedGenericMetaValue>>>>("value", Self::VT_VALUE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct GenericMetaListArgs<'a> {
+    pub value: Option<::flatbuffers::WIPOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<WrappedGenericMetaValue<'a>>>>>,
+}
+impl<'a> Default for GenericMetaListArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    GenericMetaListArgs {
+      value: None,
+    }
+  }
+}
+
+pub struct GenericMetaListBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: ::flatbuffers::WIPOffset<::flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> GenericMetaListBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_value(&mut self, value: ::flatbuffers::WIPOffset<::flatbuffers::Vector<'b , ::flatbuffers::ForwardsUOffset<WrappedGenericMetaValue<'b >>>>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(GenericMetaList::VT_VALUE, value);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> GenericMetaListBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    GenericMetaListBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> ::flatbuffers::WIPOffset<GenericMetaList<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    ::flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl ::core::fmt::Debug for GenericMetaList<'_> {
+  fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+    let mut ds = f.debug_struct("GenericMetaList");
+      ds.field("value", &self.value());
+      ds.finish()
+  }
+}
+pub enum WrappedGenericMetaValueOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct WrappedGenericMetaValue<'a> {
+  pub _tab: ::flatbuffers::Table<'a>,
+}
+
+impl<'a> ::flatbuffers::Follow<'a> for WrappedGenericMetaValue<'a> {
+  type Inner = WrappedGenericMetaValue<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { ::flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> WrappedGenericMetaValue<'a> {
+  pub const VT_VALUE_TYPE: ::flatbuffers::VOffsetT = 4;
+  pub const VT_VALUE: ::flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: ::flatbuffers::Table<'a>) -> Self {
+    WrappedGenericMetaValue { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: ::flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut ::flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args WrappedGenericMetaValueArgs
+  ) -> ::flatbuffers::WIPOffset<WrappedGenericMetaValue<'bldr>> {
+    let mut builder = WrappedGenericMetaValueBuilder::new(_fbb);
+    if let Some(x) = args.value { builder.add_value(x); }
+    builder.add_value_type(args.value_type);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn value_type(&self) -> GenericMetaValue {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<GenericMetaValue>(WrappedGenericMetaValue::VT_VALUE_TYPE, Some(GenericMetaValue::NONE)).unwrap()}
+  }
+  #[inline]
+  pub fn value(&self) -> Option<::flatbuffers::Table<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<::flatbuffers::Table<'a>>>(WrappedGenericMetaValue::VT_VALUE, None)}
+  }
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn value_as_string(&self) -> Option<GenericMetaString<'a>> {
+    if self.value_type() == GenericMetaValue::String {
+      self.value().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { GenericMetaString::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn value_as_float(&self) -> Option<GenericMetaFloat<'a>> {
+    if self.value_type() == GenericMetaValue::Float {
+      self.value().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { GenericMetaFloat::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn value_as_int(&self) -> Option<GenericMetaInt<'a>> {
+    if self.value_type() == GenericMetaValue::Int {
+      self.value().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { GenericMetaInt::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn value_as_list(&self) -> Option<GenericMetaList<'a>> {
+    if self.value_type() == GenericMetaValue::List {
+      self.value().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { GenericMetaList::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn value_as_kvpair(&self) -> Option<MetadataKV<'a>> {
+    if self.value_type() == GenericMetaValue::KVPair {
+      self.value().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { MetadataKV::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+}
+
+impl ::flatbuffers::Verifiable for WrappedGenericMetaValue<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut ::flatbuffers::Verifier, pos: usize
+  ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
+    v.visit_table(pos)?
+     .visit_union::<GenericMetaValue, _>("value_type", Self::VT_VALUE_TYPE, "value", Self::VT_VALUE, false, |key, v, pos| {
+        match key {
+          GenericMetaValue::String => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<GenericMetaString>>("GenericMetaValue::String", pos),
+          GenericMetaValue::Float => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<GenericMetaFloat>>("GenericMetaValue::Float", pos),
+          GenericMetaValue::Int => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<GenericMetaInt>>("GenericMetaValue::Int", pos),
+          GenericMetaValue::List => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<GenericMetaList>>("GenericMetaValue::List", pos),
+          GenericMetaValue::KVPair => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<MetadataKV>>("GenericMetaValue::KVPair", pos),
+          _ => Ok(()),
+        }
+     })?
+     .finish();
+    Ok(())
+  }
+}
+pub struct WrappedGenericMetaValueArgs {
+    pub value_type: GenericMetaValue,
+    pub value: Option<::flatbuffers::WIPOffset<::flatbuffers::UnionWIPOffset>>,
+}
+impl<'a> Default for WrappedGenericMetaValueArgs {
+  #[inline]
+  fn default() -> Self {
+    WrappedGenericMetaValueArgs {
+      value_type: GenericMetaValue::NONE,
+      value: None,
+    }
+  }
+}
+
+pub struct WrappedGenericMetaValueBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: ::flatbuffers::WIPOffset<::flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> WrappedGenericMetaValueBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_value_type(&mut self, value_type: GenericMetaValue) {
+    self.fbb_.push_slot::<GenericMetaValue>(WrappedGenericMetaValue::VT_VALUE_TYPE, value_type, GenericMetaValue::NONE);
+  }
+  #[inline]
+  pub fn add_value(&mut self, value: ::flatbuffers::WIPOffset<::flatbuffers::UnionWIPOffset>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(WrappedGenericMetaValue::VT_VALUE, value);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> WrappedGenericMetaValueBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    WrappedGenericMetaValueBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> ::flatbuffers::WIPOffset<WrappedGenericMetaValue<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    ::flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl ::core::fmt::Debug for WrappedGenericMetaValue<'_> {
+  fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+    let mut ds = f.debug_struct("WrappedGenericMetaValue");
+      ds.field("value_type", &self.value_type());
+      match self.value_type() {
+        GenericMetaValue::String => {
+          if let Some(x) = self.value_as_string() {
+            ds.field("value", &x)
+          } else {
+            ds.field("value", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        GenericMetaValue::Float => {
+          if let Some(x) = self.value_as_float() {
+            ds.field("value", &x)
+          } else {
+            ds.field("value", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        GenericMetaValue::Int => {
+          if let Some(x) = self.value_as_int() {
+            ds.field("value", &x)
+          } else {
+            ds.field("value", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        GenericMetaValue::List => {
+          if let Some(x) = self.value_as_list() {
+            ds.field("value", &x)
+          } else {
+            ds.field("value", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        GenericMetaValue::KVPair => {
+          if let Some(x) = self.value_as_kvpair() {
+            ds.field("value", &x)
+          } else {
+            ds.field("value", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        _ => {
+          let x: Option<()> = None;
+          ds.field("value", &x)
+        },
+      };
+      ds.finish()
+  }
+}
+pub enum MetadataKVOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct MetadataKV<'a> {
+  pub _tab: ::flatbuffers::Table<'a>,
+}
+
+impl<'a> ::flatbuffers::Follow<'a> for MetadataKV<'a> {
+  type Inner = MetadataKV<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { ::flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> MetadataKV<'a> {
+  pub const VT_KEY: ::flatbuffers::VOffsetT = 4;
+  pub const VT_VALUE_TYPE: ::flatbuffers::VOffsetT = 6;
+  pub const VT_VALUE: ::flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: ::flatbuffers::Table<'a>) -> Self {
+    MetadataKV { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: ::flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut ::flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args MetadataKVArgs<'args>
+  ) -> ::flatbuffers::WIPOffset<MetadataKV<'bldr>> {
+    let mut builder = MetadataKVBuilder::new(_fbb);
+    if let Some(x) = args.value { builder.add_value(x); }
+    if let Some(x) = args.key { builder.add_key(x); }
+    builder.add_value_type(args.value_type);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn key(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<&str>>(MetadataKV::VT_KEY, None).unwrap()}
+  }
+  #[inline]
+  pub fn value_type(&self) -> GenericMetaValue {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<GenericMetaValue>(MetadataKV::VT_VALUE_TYPE, Some(GenericMetaValue::NONE)).unwrap()}
+  }
+  #[inline]
+  pub fn value(&self) -> Option<::flatbuffers::Table<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<::flatbuffers::Table<'a>>>(MetadataKV::VT_VALUE, None)}
+  }
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn value_as_string(&self) -> Option<GenericMetaString<'a>> {
+    if self.value_type() == GenericMetaValue::String {
+      self.value().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { GenericMetaString::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn value_as_float(&self) -> Option<GenericMetaFloat<'a>> {
+    if self.value_type() == GenericMetaValue::Float {
+      self.value().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { GenericMetaFloat::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn value_as_int(&self) -> Option<GenericMetaInt<'a>> {
+    if self.value_type() == GenericMetaValue::Int {
+      self.value().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { GenericMetaInt::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn value_as_list(&self) -> Option<GenericMetaList<'a>> {
+    if self.value_type() == GenericMetaValue::List {
+      self.value().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { GenericMetaList::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn value_as_kvpair(&self) -> Option<MetadataKV<'a>> {
+    if self.value_type() == GenericMetaValue::KVPair {
+      self.value().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { MetadataKV::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+}
+
+impl ::flatbuffers::Verifiable for MetadataKV<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut ::flatbuffers::Verifier, pos: usize
+  ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
+    v.visit_table(pos)?
+     .visit_field::<::flatbuffers::ForwardsUOffset<&str>>("key", Self::VT_KEY, true)?
+     .visit_union::<GenericMetaValue, _>("value_type", Self::VT_VALUE_TYPE, "value", Self::VT_VALUE, false, |key, v, pos| {
+        match key {
+          GenericMetaValue::String => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<GenericMetaString>>("GenericMetaValue::String", pos),
+          GenericMetaValue::Float => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<GenericMetaFloat>>("GenericMetaValue::Float", pos),
+          GenericMetaValue::Int => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<GenericMetaInt>>("GenericMetaValue::Int", pos),
+          GenericMetaValue::List => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<GenericMetaList>>("GenericMetaValue::List", pos),
+          GenericMetaValue::KVPair => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<MetadataKV>>("GenericMetaValue::KVPair", pos),
+          _ => Ok(()),
+        }
+     })?
+     .finish();
+    Ok(())
+  }
+}
+pub struct MetadataKVArgs<'a> {
+    pub key: Option<::flatbuffers::WIPOffset<&'a str>>,
+    pub value_type: GenericMetaValue,
+    pub value: Option<::flatbuffers::WIPOffset<::flatbuffers::UnionWIPOffset>>,
+}
+impl<'a> Default for MetadataKVArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    MetadataKVArgs {
+      key: None, // required field
+      value_type: GenericMetaValue::NONE,
+      value: None,
+    }
+  }
+}
+
+pub struct MetadataKVBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: ::flatbuffers::WIPOffset<::flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> MetadataKVBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_key(&mut self, key: ::flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(MetadataKV::VT_KEY, key);
+  }
+  #[inline]
+  pub fn add_value_type(&mut self, value_type: GenericMetaValue) {
+    self.fbb_.push_slot::<GenericMetaValue>(MetadataKV::VT_VALUE_TYPE, value_type, GenericMetaValue::NONE);
+  }
+  #[inline]
+  pub fn add_value(&mut self, value: ::flatbuffers::WIPOffset<::flatbuffers::UnionWIPOffset>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(MetadataKV::VT_VALUE, value);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> MetadataKVBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    MetadataKVBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> ::flatbuffers::WIPOffset<MetadataKV<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, MetadataKV::VT_KEY,"key");
+    ::flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl ::core::fmt::Debug for MetadataKV<'_> {
+  fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+    let mut ds = f.debug_struct("MetadataKV");
+      ds.field("key", &self.key());
+      ds.field("value_type", &self.value_type());
+      match self.value_type() {
+        GenericMetaValue::String => {
+          if let Some(x) = self.value_as_string() {
+            ds.field("value", &x)
+          } else {
+            ds.field("value", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        GenericMetaValue::Float => {
+          if let Some(x) = self.value_as_float() {
+            ds.field("value", &x)
+          } else {
+            ds.field("value", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        GenericMetaValue::Int => {
+          if let Some(x) = self.value_as_int() {
+            ds.field("value", &x)
+          } else {
+            ds.field("value", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        GenericMetaValue::List => {
+          if let Some(x) = self.value_as_list() {
+            ds.field("value", &x)
+          } else {
+            ds.field("value", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        GenericMetaValue::KVPair => {
+          if let Some(x) = self.value_as_kvpair() {
+            ds.field("value", &x)
+          } else {
+            ds.field("value", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        _ => {
+          let x: Option<()> = None;
+          ds.field("value", &x)
+        },
+      };
+      ds.finish()
+  }
+}
+pub enum MediaItemOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct MediaItem<'a> {
+  pub _tab: ::flatbuffers::Table<'a>,
+}
+
+impl<'a> ::flatbuffers::Follow<'a> for MediaItem<'a> {
+  type Inner = MediaItem<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { ::flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> MediaItem<'a> {
+  pub const VT_CONTAINER: ::flatbuffers::VOffsetT = 4;
+  pub const VT_SOURCE_URL: ::flatbuffers::VOffsetT = 6;
+  pub const VT_START_TIME: ::flatbuffers::VOffsetT = 8;
+  pub const VT_VOLUME: ::flatbuffers::VOffsetT = 10;
+  pub const VT_SPEED: ::flatbuffers::VOffsetT = 12;
+  pub const VT_HEADERS: ::flatbuffers::VOffsetT = 14;
+  pub const VT_TITLE: ::flatbuffers::VOffsetT = 16;
+  pub const VT_THUMBNAIL_URL: ::flatbuffers::VOffsetT = 18;
+  pub const VT_METADATA_TYPE: ::flatbuffers::VOffsetT = 20;
+  pub const VT_METADATA: ::flatbuffers::VOffsetT = 22;
+  pub const VT_EXTRA_METADATA: ::flatbuffers::VOffsetT = 24;
+  pub const VT_RESUME: ::flatbuffers::VOffsetT = 26;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: ::flatbuffers::Table<'a>) -> Self {
+    MediaItem { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: ::flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut ::flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args MediaItemArgs<'args>
+  ) -> ::flatbuffers::WIPOffset<MediaItem<'bldr>> {
+    let mut builder = MediaItemBuilder::new(_fbb);
+    if let Some(x) = args.extra_metadata { builder.add_extra_metadata(x); }
+    if let Some(x) = args.metadata { builder.add_metadata(x); }
+    if let Some(x) = args.thumbnail_url { builder.add_thumbnail_url(x); }
+    if let Some(x) = args.title { builder.add_title(x); }
+    if let Some(x) = args.headers { builder.add_headers(x); }
+    if let Some(x) = args.speed { builder.add_speed(x); }
+    if let Some(x) = args.volume { builder.add_volume(x); }
+    if let Some(x) = args.start_time { builder.add_start_time(x); }
+    if let Some(x) = args.source_url { builder.add_source_url(x); }
+    if let Some(x) = args.container { builder.add_container(x); }
+    builder.add_resume(args.resume);
+    builder.add_metadata_type(args.metadata_type);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn container(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<&str>>(MediaItem::VT_CONTAINER, None).unwrap()}
+  }
+  #[inline]
+  pub fn source_url(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<&str>>(MediaItem::VT_SOURCE_URL, None).unwrap()}
+  }
+  #[inline]
+  pub fn start_time(&self) -> Option<&'a Time> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Time>(MediaItem::VT_START_TIME, None)}
+  }
+  #[inline]
+  pub fn volume(&self) -> Option<f32> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(MediaItem::VT_VOLUME, None)}
+  }
+  #[inline]
+  pub fn speed(&self) -> Option<f32> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(MediaItem::VT_SPEED, None)}
+  }
+  #[inline]
+  pub fn headers(&self) -> Option<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<RequestHeader<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<RequestHeader>>>>(MediaItem::VT_HEADERS, None)}
+  }
+  #[inline]
+  pub fn title(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<&str>>(MediaItem::VT_TITLE, None)}
+  }
+  #[inline]
+  pub fn thumbnail_url(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<&str>>(MediaItem::VT_THUMBNAIL_URL, None)}
+  }
+  #[inline]
+  pub fn metadata_type(&self) -> Metadata {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Metadata>(MediaItem::VT_METADATA_TYPE, Some(Metadata::NONE)).unwrap()}
+  }
+  #[inline]
+  pub fn metadata(&self) -> Option<::flatbuffers::Table<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<::flatbuffers::Table<'a>>>(MediaItem::VT_METADATA, None)}
+  }
+  #[inline]
+  pub fn extra_metadata(&self) -> Option<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<MetadataKV<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<MetadataKV>>>>(MediaItem::VT_EXTRA_METADATA, None)}
+  }
+  #[inline]
+  pub fn resume(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(MediaItem::VT_RESUME, Some(false)).unwrap()}
+  }
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn metadata_as_video(&self) -> Option<VideoMetadata<'a>> {
+    if self.metadata_type() == Metadata::Video {
+      self.metadata().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { VideoMetadata::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn metadata_as_audio(&self) -> Option<AudioMetadata<'a>> {
+    if self.metadata_type() == Metadata::Audio {
+      self.metadata().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { AudioMetadata::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+}
+
+impl ::flatbuffers::Verifiable for MediaItem<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut ::flatbuffers::Verifier, pos: usize
+  ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
+    v.visit_table(pos)?
+     .visit_field::<::flatbuffers::ForwardsUOffset<&str>>("container", Self::VT_CONTAINER, true)?
+     .visit_field::<::flatbuffers::ForwardsUOffset<&str>>("source_url", Self::VT_SOURCE_URL, true)?
+     .visit_field::<Time>("start_time", Self::VT_START_TIME, false)?
+     .visit_field::<f32>("volume", Self::VT_VOLUME, false)?
+     .visit_field::<f32>("speed", Self::VT_SPEED, false)?
+     .visit_field::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'_, ::flatbuffers::ForwardsUOffset<RequestHeader>>>>("headers", Self::VT_HEADERS, false)?
+     .visit_field::<::flatbuffers::ForwardsUOffset<&str>>("title", Self::VT_TITLE, false)?
+     .visit_field::<::flatbuffers::ForwardsUOffset<&str>>("thumbnail_url", Self::VT_THUMBNAIL_URL, false)?
+     .visit_union::<Metadata, _>("metadata_type", Self::VT_METADATA_TYPE, "metadata", Self::VT_METADATA, false, |key, v, pos| {
+        match key {
+          Metadata::Video => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<VideoMetadata>>("Metadata::Video", pos),
+          Metadata::Audio => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<AudioMetadata>>("Metadata::Audio", pos),
+          _ => Ok(()),
+        }
+     })?
+     .visit_field::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'_, ::flatbuffers::ForwardsUOffset<MetadataKV>>>>("extra_metadata", Self::VT_EXTRA_METADATA, false)?
+     .visit_field::<bool>("resume", Self::VT_RESUME, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct MediaItemArgs<'a> {
+    pub container: Option<::flatbuffers::WIPOffset<&'a str>>,
+    pub source_url: Option<::flatbuffers::WIPOffset<&'a str>>,
+    pub start_time: Option<&'a Time>,
+    pub volume: Option<f32>,
+    pub speed: Option<f32>,
+    pub headers: Option<::flatbuffers::WIPOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<RequestHeader<'a>>>>>,
+    pub title: Option<::flatbuffers::WIPOffset<&'a str>>,
+    pub thumbnail_url: Option<::flatbuffers::WIPOffset<&'a str>>,
+    pub metadata_type: Metadata,
+    pub metadata: Option<::flatbuffers::WIPOffset<::flatbuffers::UnionWIPOffset>>,
+    pub extra_metadata: Option<::flatbuffers::WIPOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<MetadataKV<'a>>>>>,
+    pub resume: bool,
+}
+impl<'a> Default for MediaItemArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    MediaItemArgs {
+      container: None, // required field
+      source_url: None, // required field
+      start_time: None,
+      volume: None,
+      speed: None,
+      headers: None,
+      title: None,
+      thumbnail_url: None,
+      metadata_type: Metadata::NONE,
+      metadata: None,
+      extra_metadata: None,
+      resume: false,
+    }
+  }
+}
+
+pub struct MediaItemBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: ::flatbuffers::WIPOffset<::flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> MediaItemBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_container(&mut self, container: ::flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(MediaItem::VT_CONTAINER, container);
+  }
+  #[inline]
+  pub fn add_source_url(&mut self, source_url: ::flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(MediaItem::VT_SOURCE_URL, source_url);
+  }
+  #[inline]
+  pub fn add_start_time(&mut self, start_time: &Time) {
+    self.fbb_.push_slot_always::<&Time>(MediaItem::VT_START_TIME, start_time);
+  }
+  #[inline]
+  pub fn add_volume(&mut self, volume: f32) {
+    self.fbb_.push_slot_always::<f32>(MediaItem::VT_VOLUME, volume);
+  }
+  #[inline]
+  pub fn add_speed(&mut self, speed: f32) {
+    self.fbb_.push_slot_always::<f32>(MediaItem::VT_SPEED, speed);
+  }
+  #[inline]
+  pub fn add_headers(&mut self, headers: ::flatbuffers::WIPOffset<::flatbuffers::Vector<'b , ::flatbuffers::ForwardsUOffset<RequestHeader<'b >>>>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(MediaItem::VT_HEADERS, headers);
+  }
+  #[inline]
+  pub fn add_title(&mut self, title: ::flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(MediaItem::VT_TITLE, title);
+  }
+  #[inline]
+  pub fn add_thumbnail_url(&mut self, thumbnail_url: ::flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(MediaItem::VT_THUMBNAIL_URL, thumbnail_url);
+  }
+  #[inline]
+  pub fn add_metadata_type(&mut self, metadata_type: Metadata) {
+    self.fbb_.push_slot::<Metadata>(MediaItem::VT_METADATA_TYPE, metadata_type, Metadata::NONE);
+  }
+  #[inline]
+  pub fn add_metadata(&mut self, metadata: ::flatbuffers::WIPOffset<::flatbuffers::UnionWIPOffset>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(MediaItem::VT_METADATA, metadata);
+  }
+  #[inline]
+  pub fn add_extra_metadata(&mut self, extra_metadata: ::flatbuffers::WIPOffset<::flatbuffers::Vector<'b , ::flatbuffers::ForwardsUOffset<MetadataKV<'b >>>>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(MediaItem::VT_EXTRA_METADATA, extra_metadata);
+  }
+  #[inline]
+  pub fn add_resume(&mut self, resume: bool) {
+    self.fbb_.push_slot::<bool>(MediaItem::VT_RESUME, resume, false);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> MediaItemBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    MediaItemBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> ::flatbuffers::WIPOffset<MediaItem<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, MediaItem::VT_CONTAINER,"container");
+    self.fbb_.required(o, MediaItem::VT_SOURCE_URL,"source_url");
+    ::flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl ::core::fmt::Debug for MediaItem<'_> {
+  fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+    let mut ds = f.debug_struct("MediaItem");
+      ds.field("container", &self.container());
+      ds.field("source_url", &self.source_url());
+      ds.field("start_time", &self.start_time());
+      ds.field("volume", &self.volume());
+      ds.field("speed", &self.speed());
+      ds.field("headers", &self.headers());
+      ds.field("title", &self.title());
+      ds.field("thumbnail_url", &self.thumbnail_url());
+      ds.field("metadata_type", &self.metadata_type());
+      match self.metadata_type() {
+        Metadata::Video => {
+          if let Some(x) = self.metadata_as_video() {
+            ds.field("metadata", &x)
+          } else {
+            ds.field("metadata", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Metadata::Audio => {
+          if let Some(x) = self.metadata_as_audio() {
+            ds.field("metadata", &x)
+          } else {
+            ds.field("metadata", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        _ => {
+          let x: Option<()> = None;
+          ds.field("metadata", &x)
+        },
+      };
+      ds.field("extra_metadata", &self.extra_metadata());
+      ds.field("resume", &self.resume());
+      ds.finish()
+  }
+}
+pub enum QueueItemOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct QueueItem<'a> {
+  pub _tab: ::flatbuffers::Table<'a>,
+}
+
+impl<'a> ::flatbuffers::Follow<'a> for QueueItem<'a> {
+  type Inner = QueueItem<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { ::flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> QueueItem<'a> {
+  pub const VT_MEDIA_ITEM: ::flatbuffers::VOffsetT = 4;
+  pub const VT_PLAYBACK_DURATION: ::flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: ::flatbuffers::Table<'a>) -> Self {
+    QueueItem { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: ::flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut ::flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args QueueItemArgs<'args>
+  ) -> ::flatbuffers::WIPOffset<QueueItem<'bldr>> {
+    let mut builder = QueueItemBuilder::new(_fbb);
+    if let Some(x) = args.playback_duration { builder.add_playback_duration(x); }
+    if let Some(x) = args.media_item { builder.add_media_item(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn media_item(&self) -> MediaItem<'a> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<MediaItem>>(QueueItem::VT_MEDIA_ITEM, None).unwrap()}
+  }
+  #[inline]
+  pub fn playback_duration(&self) -> Option<&'a Time> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Time>(QueueItem::VT_PLAYBACK_DURATION, None)}
+  }
+}
+
+impl ::flatbuffers::Verifiable for QueueItem<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut ::flatbuffers::Verifier, pos: usize
+  ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
+    v.visit_table(pos)?
+     .visit_field::<::flatbuffers::ForwardsUOffset<MediaItem>>("media_item", Self::VT_MEDIA_ITEM, true)?
+     .visit_field::<Time>("playback_duration", Self::VT_PLAYBACK_DURATION, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct QueueItemArgs<'a> {
+    pub media_item: Option<::flatbuffers::WIPOffset<MediaItem<'a>>>,
+    pub playback_duration: Option<&'a Time>,
+}
+impl<'a> Default for QueueItemArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    QueueItemArgs {
+      media_item: None, // required field
+      playback_duration: None,
+    }
+  }
+}
+
+pub struct QueueItemBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: ::flatbuffers::WIPOffset<::flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> QueueItemBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_media_item(&mut self, media_item: ::flatbuffers::WIPOffset<MediaItem<'b >>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<MediaItem>>(QueueItem::VT_MEDIA_ITEM, media_item);
+  }
+  #[inline]
+  pub fn add_playback_duration(&mut self, playback_duration: &Time) {
+    self.fbb_.push_slot_always::<&Time>(QueueItem::VT_PLAYBACK_DURATION, playback_duration);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> QueueItemBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    QueueItemBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> ::flatbuffers::WIPOffset<QueueItem<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, QueueItem::VT_MEDIA_ITEM,"media_item");
+    ::flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl ::core::fmt::Debug for QueueItem<'_> {
+  fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+    let mut ds = f.debug_struct("QueueItem");
+      ds.field("media_item", &self.media_item());
+      ds.field("playback_duration", &self.playback_duration());
+      ds.finish()
+  }
+}
+pub enum QueueOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Queue<'a> {
+  pub _tab: ::flatbuffers::Table<'a>,
+}
+
+impl<'a> ::flatbuffers::Follow<'a> for Queue<'a> {
+  type Inner = Queue<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { ::flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Queue<'a> {
+  pub const VT_ITEMS: ::flatbuffers::VOffsetT = 4;
+  pub const VT_START_INDEX: ::flatbuffers::VOffsetT = 6;
+  pub const VT_AUTOPLAY: ::flatbuffers::VOffsetT = 8;
+  pub const VT_WIDE_START_INDEX: ::flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: ::flatbuffers::Table<'a>) -> Self {
+    Queue { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: ::flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut ::flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args QueueArgs<'args>
+  ) -> ::flatbuffers::WIPOffset<Queue<'bldr>> {
+    let mut builder = QueueBuilder::new(_fbb);
+    if let Some(x) = args.wide_start_index { builder.add_wide_start_index(x); }
+    if let Some(x) = args.items { builder.add_items(x); }
+    builder.add_autoplay(args.autoplay);
+    if let Some(x) = args.start_index { builder.add_start_index(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn items(&self) -> ::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<QueueItem<'a>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<QueueItem>>>>(Queue::VT_ITEMS, None).unwrap()}
+  }
+  #[inline]
+  pub fn start_index(&self) -> Option<u8> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(Queue::VT_START_INDEX, None)}
+  }
+  #[inline]
+  pub fn autoplay(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(Queue::VT_AUTOPLAY, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn wide_start_index(&self) -> Option<u32> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Queue::VT_WIDE_START_INDEX, None)}
+  }
 }
 
-impl ::flatbuffers::Verifiable for Packet<'_> {
+impl ::flatbuffers::Verifiable for Queue<'_> {
   #[inline]
   fn run_verifier(
     v: &mut ::flatbuffers::Verifier, pos: usize
   ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
     v.visit_table(pos)?
-     .visit_union::<Message, _>("payload_type", Self::VT_PAYLOAD_TYPE, "payload", Self::VT_PAYLOAD, false, |key, v, pos| {
-        match key {
-          Message::Load => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<Load>>("Message::Load", pos),
-          Message::ProgressChanged => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<ProgressChanged>>("Message::ProgressChanged", pos),
-          Message::VolumeChanged => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<VolumeChanged>>("Message::VolumeChanged", pos),
-          Message::PlaybackStateChanged => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<PlaybackStateChanged>>("Message::PlaybackStateChanged", pos),
-          Message::SpeedChanged => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<SpeedChanged>>("Message::SpeedChanged", pos),
-          Message::SenderIntroduction => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<SenderIntroduction>>("Message::SenderIntroduction", pos),
-          Message::ReceiverIntroduction => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<ReceiverIntroduction>>("Message::ReceiverIntroduction", pos),
-          Message::StopPlayback => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<StopPlayback>>("Message::StopPlayback", pos),
-          Message::StartMirroringSession => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<StartMirroringSession>>("Message::StartMirroringSession", pos),
-          Message::MirroringSessionDescription => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<MirroringSessionDescription>>("Message::MirroringSessionDescription", pos),
-          Message::QueueInsert => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<QueueInsert>>("Message::QueueInsert", pos),
-          Message::QueueRemove => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<QueueRemove>>("Message::QueueRemove", pos),
-          Message::TracksAvailable => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<TracksAvailable>>("Message::TracksAvailable", pos),
-          Message::ChangeTrack => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<ChangeTrack>>("Message::ChangeTrack", pos),
-          Message::QueueItemSelected => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<QueueItemSelected>>("Message::QueueItemSelected", pos),
-          Message::AddSubtitleSource => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<AddSubtitleSource>>("Message::AddSubtitleSource", pos),
-          Message::SetProgressUpdateInterval => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<SetProgressUpdateInterval>>("Message::SetProgressUpdateInterval", pos),
-          Message::CompanionHelloRequest => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<CompanionHelloRequest>>("Message::CompanionHelloRequest", pos),
-          Message::CompanionHelloResponse => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<CompanionHelloResponse>>("Message::CompanionHelloResponse", pos),
-          Message::CompanionResourceInfoRequest => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<CompanionResourceInfoRequest>>("Message::CompanionResourceInfoRequest", pos),
-          Message::CompanionResourceInfoResponse => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<CompanionResourceInfoResponse>>("Message::CompanionResourceInfoResponse", pos),
-          Message::CompanionResourceRequest => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<CompanionResourceRequest>>("Message::CompanionResourceRequest", pos),
-          Message::Error => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<Error>>("Message::Error", pos),
-          _ => Ok(()),
-        }
-     })?
+     .visit_field::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'_, ::flatbuffers::ForwardsUOffset<QueueItem>>>>("items", Self::VT_ITEMS, true)?
+     .visit_field::<u8>("start_index", Self::VT_START_INDEX, false)?
+     .visit_field::<bool>("autoplay", Self::VT_AUTOPLAY, false)?
+     .visit_field::<u32>("wide_start_index", Self::VT_WIDE_START_INDEX, false)?
      .finish();
     Ok(())
   }
 }
-pub struct PacketArgs {
-    pub payload_type: Message,
-    pub payload: Option<::flatbuffers::WIPOffset<::flatbuffers::UnionWIPOffset>>,
+pub struct QueueArgs<'a> {
+    pub items: Option<::flatbuffers::WIPOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<QueueItem<'a>>>>>,
+    pub start_index: Option<u8>,
+    pub autoplay: bool,
+    pub wide_start_index: Option<u32>,
 }
-impl<'a> Default for PacketArgs {
+impl<'a> Default for QueueArgs<'a> {
   #[inline]
   fn default() -> Self {
-    PacketArgs {
-      payload_type: Message::NONE,
-      payload: None,
+    QueueArgs {
+      items: None, // required field
+      start_index: None,
+      autoplay: false,
+      wide_start_index: None,
     }
   }
 }
 
-pub struct PacketBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
+pub struct QueueBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
   fbb_: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>,
   start_: ::flatbuffers::WIPOffset<::flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> PacketBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> QueueBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_payload_type(&mut self, payload_type: Message) {
-    self.fbb_.push_slot::<Message>(Packet::VT_PAYLOAD_TYPE, payload_type, Message::NONE);
+  pub fn add_items(&mut self, items: ::flatbuffers::WIPOffset<::flatbuffers::Vector<'b , ::flatbuffers::ForwardsUOffset<QueueItem<'b >>>>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(Queue::VT_ITEMS, items);
   }
   #[inline]
-  pub fn add_payload(&mut self, payload: ::flatbuffers::WIPOffset<::flatbuffers::UnionWIPOffset>) {
-    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(Packet::VT_PAYLOAD, payload);
+  pub fn add_start_index(&mut self, start_index: u8) {
+    self.fbb_.push_slot_always::<u8>(Queue::VT_START_INDEX, start_index);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> PacketBuilder<'a, 'b, A> {
+  pub fn add_autoplay(&mut self, autoplay: bool) {
+    self.fbb_.push_slot::<bool>(Queue::VT_AUTOPLAY, autoplay, false);
+  }
+  #[inline]
+  pub fn add_wide_start_index(&mut self, wide_start_index: u32) {
+    self.fbb_.push_slot_always::<u32>(Queue::VT_WIDE_START_INDEX, wide_start_index);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> QueueBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    PacketBuilder {
+    QueueBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> ::flatbuffers::WIPOffset<Packet<'a>> {
+  pub fn finish(self) -> ::flatbuffers::WIPOffset<Queue<'a>> {
     let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, Queue::VT_ITEMS,"items");
     ::flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl ::core::fmt::Debug for Packet<'_> {
+impl ::core::fmt::Debug for Queue<'_> {
   fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-    let mut ds = f.debug_struct("Packet");
-      ds.field("payload_type", &self.payload_type());
-      match self.payload_type() {
-        Message::Load => {
-          if let Some(x) = self.payload_as_load() {
-            ds.field("payload", &x)
-          } else {
-            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
-          }
-        },
-        Message::ProgressChanged => {
-          if let Some(x) = self.payload_as_progress_changed() {
-            ds.field("payload", &x)
-          } else {
-            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
-          }
-        },
-        Message::VolumeChanged => {
-          if let Some(x) = self.payload_as_volume_changed() {
-            ds.field("payload", &x)
-          } else {
-            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
-          }
-        },
-        Message::PlaybackStateChanged => {
-          if let Some(x) = self.payload_as_playback_state_changed() {
-            ds.field("payload", &x)
-          } else {
-            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
-          }
-        },
-        Message::SpeedChanged => {
-          if let Some(x) = self.payload_as_speed_changed() {
-            ds.field("payload", &x)
-          } else {
-            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
-          }
-        },
-        Message::SenderIntroduction => {
-          if let Some(x) = self.payload_as_sender_introduction() {
-            ds.field("payload", &x)
-          } else {
-            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
-          }
-        },
-        Message::ReceiverIntroduction => {
-          if let Some(x) = self.payload_as_receiver_introduction() {
-            ds.field("payload", &x)
-          } else {
-            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
-          }
-        },
-        Message::StopPlayback => {
-          if let Some(x) = self.payload_as_stop_playback() {
-            ds.field("payload", &x)
-          } else {
-            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
-          }
-        },
-        Message::StartMirroringSession => {
-          if let Some(x) = self.payload_as_start_mirroring_session() {
-            ds.field("payload", &x)
-          } else {
-            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
-          }
-        },
-        Message::MirroringSessionDescription => {
-          if let Some(x) = self.payload_as_mirroring_session_description() {
-            ds.field("payload", &x)
-          } else {
-            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
-          }
-        },
-        Message::QueueInsert => {
-          if let Some(x) = self.payload_as_queue_insert() {
-            ds.field("payload", &x)
-          } else {
-            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
-          }
-        },
-        Message::QueueRemove => {
-          if let Some(x) = self.payload_as_queue_remove() {
-            ds.field("payload", &x)
-          } else {
-            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
-          }
-        },
-        Message::TracksAvailable => {
-          if let Some(x) = self.payload_as_tracks_available() {
-            ds.field("payload", &x)
-          } else {
-            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
-          }
-        },
-        Message::ChangeTrack => {
-          if let Some(x) = self.payload_as_change_track() {
-            ds.field("payload", &x)
-          } else {
-            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
-          }
-        },
-        Message::QueueItemSelected => {
-          if let Some(x) = self.payload_as_queue_item_selected() {
-            ds.field("payload", &x)
-          } else {
-            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
-          }
-        },
-        Message::AddSubtitleSource => {
-          if let Some(x) = self.payload_as_add_subtitle_source() {
-            ds.field("payload", &x)
-          } else {
-            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
-          }
-        },
-        Message::SetProgressUpdateInterval => {
-          if let Some(x) = self.payload_as_set_progress_update_interval() {
-            ds.field("payload", &x)
-          } else {
-            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
-          }
-        },
-        Message::CompanionHelloRequest => {
-          if let Some(x) = self.payload_as_companion_hello_request() {
-            ds.field("payload", &x)
-          } else {
-            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
-          }
-        },
-        Message::CompanionHelloResponse => {
-          if let Some(x) = self.payload_as_companion_hello_response() {
-            ds.field("payload", &x)
-          } else {
-            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
-          }
-        },
-        Message::CompanionResourceInfoRequest => {
-          if let Some(x) = self.payload_as_companion_resource_info_request() {
-            ds.field("payload", &x)
-          } else {
-            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
-          }
-        },
-        Message::CompanionResourceInfoResponse => {
-          if let Some(x) = self.payload_as_companion_resource_info_response() {
-            ds.field("payload", &x)
-          } else {
-            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
-          }
-        },
-        Message::CompanionResourceRequest => {
-          if let Some(x) = self.payload_as_companion_resource_request() {
-            ds.field("payload", &x)
-          } else {
-            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
-          }
-        },
-        Message::Error => {
-          if let Some(x) = self.payload_as_error() {
-            ds.field("payload", &x)
-          } else {
-            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
-          }
-        },
-        _ => {
-          let x: Option<()> = None;
-          ds.field("payload", &x)
-        },
-      };
+    let mut ds = f.debug_struct("Queue");
+      ds.field("items", &self.items());
+      ds.field("start_index", &self.start_index());
+      ds.field("autoplay", &self.autoplay());
+      ds.field("wide_start_index", &self.wide_start_index());
       ds.finish()
   }
 }
-pub enum ChapterOffset {}
+pub enum QueueIndexOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct Chapter<'a> {
+pub struct QueueIndex<'a> {
   pub _tab: ::flatbuffers::Table<'a>,
 }
 
-impl<'a> ::flatbuffers::Follow<'a> for Chapter<'a> {
-  type Inner = Chapter<'a>;
+impl<'a> ::flatbuffers::Follow<'a> for QueueIndex<'a> {
+  type Inner = QueueIndex<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: unsafe { ::flatbuffers::Table::new(buf, loc) } }
   }
 }
 
-impl<'a> Chapter<'a> {
-  pub const VT_START: ::flatbuffers::VOffsetT = 4;
-  pub const VT_LENGTH: ::flatbuffers::VOffsetT = 6;
-  pub const VT_TITLE: ::flatbuffers::VOffsetT = 8;
-  pub const VT_SKIP_LABEL: ::flatbuffers::VOffsetT = 10;
+impl<'a> QueueIndex<'a> {
+  pub const VT_INDEX: ::flatbuffers::VOffsetT = 4;
+  pub const VT_WIDE_INDEX: ::flatbuffers::VOffsetT = 6;
 
   #[inline]
   pub unsafe fn init_from_table(table: ::flatbuffers::Table<'a>) -> Self {
-    Chapter { _tab: table }
+    QueueIndex { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: ::flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut ::flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args ChapterArgs<'args>
-  ) -> ::flatbuffers::WIPOffset<Chapter<'bldr>> {
-    let mut builder = ChapterBuilder::new(_fbb);
-    if let Some(x) = args.skip_label { builder.add_skip_label(x); }
-    if let Some(x) = args.title { builder.add_title(x); }
-    if let Some(x) = args.length { builder.add_length(x); }
-    if let Some(x) = args.start { builder.add_start(x); }
+    args: &'args QueueIndexArgs
+  ) -> ::flatbuffers::WIPOffset<QueueIndex<'bldr>> {
+    let mut builder = QueueIndexBuilder::new(_fbb);
+    if let Some(x) = args.wide_index { builder.add_wide_index(x); }
+    builder.add_index(args.index);
     builder.finish()
   }
 
 
   #[inline]
-  pub fn start(&self) -> Option<&'a Time> {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<Time>(Chapter::VT_START, None)}
-  }
-  #[inline]
-  pub fn length(&self) -> Option<&'a Time> {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<Time>(Chapter::VT_LENGTH, None)}
-  }
-  #[inline]
-  pub fn title(&self) -> &'a str {
+  pub fn index(&self) -> u8 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<&str>>(Chapter::VT_TITLE, None).unwrap()}
+    unsafe { self._tab.get::<u8>(QueueIndex::VT_INDEX, Some(0)).unwrap()}
   }
   #[inline]
-  pub fn skip_label(&self) -> Option<&'a str> {
+  pub fn wide_index(&self) -> Option<u32> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<&str>>(Chapter::VT_SKIP_LABEL, None)}
+    unsafe { self._tab.get::<u32>(QueueIndex::VT_WIDE_INDEX, None)}
   }
 }
 
-impl ::flatbuffers::Verifiable for Chapter<'_> {
+impl ::flatbuffers::Verifiable for QueueIndex<'_> {
   #[inline]
   fn run_verifier(
     v: &mut ::flatbuffers::Verifier, pos: usize
   ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
     v.visit_table(pos)?
-     .visit_field::<Time>("start", Self::VT_START, false)?
-     .visit_field::<Time>("length", Self::VT_LENGTH, false)?
-     .visit_field::<::flatbuffers::ForwardsUOffset<&str>>("title", Self::VT_TITLE, true)?
-     .visit_field::<::flatbuffers::ForwardsUOffset<&str>>("skip_label", Self::VT_SKIP_LABEL, false)?
+     .visit_field::<u8>("index", Self::VT_INDEX, false)?
+     .visit_field::<u32>("wide_index", Self::VT_WIDE_INDEX, false)?
      .finish();
     Ok(())
   }
 }
-pub struct ChapterArgs<'a> {
-    pub start: Option<&'a Time>,
-    pub length: Option<&'a Time>,
-    pub title: Option<::flatbuffers::WIPOffset<&'a str>>,
-    pub skip_label: Option<::flatbuffers::WIPOffset<&'a str>>,
+pub struct QueueIndexArgs {
+    pub index: u8,
+    pub wide_index: Option<u32>,
 }
-impl<'a> Default for ChapterArgs<'a> {
+impl<'a> Default for QueueIndexArgs {
   #[inline]
   fn default() -> Self {
-    ChapterArgs {
-      start: None,
-      length: None,
-      title: None, // required field
-      skip_label: None,
+    QueueIndexArgs {
+      index: 0,
+      wide_index: None,
     }
   }
 }
 
-pub struct ChapterBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
+pub struct QueueIndexBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
   fbb_: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>,
   start_: ::flatbuffers::WIPOffset<::flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> ChapterBuilder<'a, 'b, A> {
-  #[inline]
-  pub fn add_start(&mut self, start: &Time) {
-    self.fbb_.push_slot_always::<&Time>(Chapter::VT_START, start);
-  }
-  #[inline]
-  pub fn add_length(&mut self, length: &Time) {
-    self.fbb_.push_slot_always::<&Time>(Chapter::VT_LENGTH, length);
-  }
+impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> QueueIndexBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_title(&mut self, title: ::flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(Chapter::VT_TITLE, title);
+  pub fn add_index(&mut self, index: u8) {
+    self.fbb_.push_slot::<u8>(QueueIndex::VT_INDEX, index, 0);
   }
   #[inline]
-  pub fn add_skip_label(&mut self, skip_label: ::flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(Chapter::VT_SKIP_LABEL, skip_label);
+  pub fn add_wide_index(&mut self, wide_index: u32) {
+    self.fbb_.push_slot_always::<u32>(QueueIndex::VT_WIDE_INDEX, wide_index);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> ChapterBuilder<'a, 'b, A> {
+  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> QueueIndexBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    ChapterBuilder {
+    QueueIndexBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> ::flatbuffers::WIPOffset<Chapter<'a>> {
+  pub fn finish(self) -> ::flatbuffers::WIPOffset<QueueIndex<'a>> {
     let o = self.fbb_.end_table(self.start_);
-    self.fbb_.required(o, Chapter::VT_TITLE,"title");
     ::flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl ::core::fmt::Debug for Chapter<'_> {
+impl ::core::fmt::Debug for QueueIndex<'_> {
   fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-    let mut ds = f.debug_struct("Chapter");
-      ds.field("start", &self.start());
-      ds.field("length", &self.length());
-      ds.field("title", &self.title());
-      ds.field("skip_label", &self.skip_label());
+    let mut ds = f.debug_struct("QueueIndex");
+      ds.field("index", &self.index());
+      ds.field("wide_index", &self.wide_index());
       ds.finish()
   }
 }
-pub enum VideoMetadataOffset {}
+pub enum QueueMarkerFrontOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct VideoMetadata<'a> {
+pub struct QueueMarkerFront<'a> {
   pub _tab: ::flatbuffers::Table<'a>,
 }
 
-impl<'a> ::flatbuffers::Follow<'a> for VideoMetadata<'a> {
-  type Inner = VideoMetadata<'a>;
+impl<'a> ::flatbuffers::Follow<'a> for QueueMarkerFront<'a> {
+  type Inner = QueueMarkerFront<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: unsafe { ::flatbuffers::Table::new(buf, loc) } }
   }
 }
 
-impl<'a> VideoMetadata<'a> {
-  pub const VT_CHAPTERS: ::flatbuffers::VOffsetT = 4;
+impl<'a> QueueMarkerFront<'a> {
 
   #[inline]
   pub unsafe fn init_from_table(table: ::flatbuffers::Table<'a>) -> Self {
-    VideoMetadata { _tab: table }
+    QueueMarkerFront { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: ::flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut ::flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args VideoMetadataArgs<'args>
-  ) -> ::flatbuffers::WIPOffset<VideoMetadata<'bldr>> {
-    let mut builder = VideoMetadataBuilder::new(_fbb);
-    if let Some(x) = args.chapters { builder.add_chapters(x); }
+    _args: &'args QueueMarkerFrontArgs
+  ) -> ::flatbuffers::WIPOffset<QueueMarkerFront<'bldr>> {
+    let mut builder = QueueMarkerFrontBuilder::new(_fbb);
     builder.finish()
   }
 
-
-  #[inline]
-  pub fn chapters(&self) -> Option<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<Chapter<'a>>>> {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<Chapter>>>>(VideoMetadata::VT_CHAPTERS, None)}
-  }
 }
 
-impl ::flatbuffers::Verifiable for VideoMetadata<'_> {
+impl ::flatbuffers::Verifiable for QueueMarkerFront<'_> {
   #[inline]
   fn run_verifier(
     v: &mut ::flatbuffers::Verifier, pos: usize
   ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
     v.visit_table(pos)?
-     .visit_field::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'_, ::flatbuffers::ForwardsUOffset<Chapter>>>>("chapters", Self::VT_CHAPTERS, false)?
      .finish();
     Ok(())
   }
 }
-pub struct VideoMetadataArgs<'a> {
-    pub chapters: Option<::flatbuffers::WIPOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<Chapter<'a>>>>>,
+pub struct QueueMarkerFrontArgs {
 }
-impl<'a> Default for VideoMetadataArgs<'a> {
+impl<'a> Default for QueueMarkerFrontArgs {
   #[inline]
   fn default() -> Self {
-    VideoMetadataArgs {
-      chapters: None,
+    QueueMarkerFrontArgs {
     }
   }
 }
 
-pub struct VideoMetadataBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
+pub struct QueueMarkerFrontBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
   fbb_: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>,
   start_: ::flatbuffers::WIPOffset<::flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> VideoMetadataBuilder<'a, 'b, A> {
-  #[inline]
-  pub fn add_chapters(&mut self, chapters: ::flatbuffers::WIPOffset<::flatbuffers::Vector<'b , ::flatbuffers::ForwardsUOffset<Chapter<'b >>>>) {
-    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(VideoMetadata::VT_CHAPTERS, chapters);
-  }
+impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> QueueMarkerFrontBuilder<'a, 'b, A> {
   #[inline]
-  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> VideoMetadataBuilder<'a, 'b, A> {
+  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> QueueMarkerFrontBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    VideoMetadataBuilder {
+    QueueMarkerFrontBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> ::flatbuffers::WIPOffset<VideoMetadata<'a>> {
+  pub fn finish(self) -> ::flatbuffers::WIPOffset<QueueMarkerFront<'a>> {
     let o = self.fbb_.end_table(self.start_);
     ::flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl ::core::fmt::Debug for VideoMetadata<'_> {
+impl ::core::fmt::Debug for QueueMarkerFront<'_> {
   fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-    let mut ds = f.debug_struct("VideoMetadata");
-      ds.field("chapters", &self.chapters());
+    let mut ds = f.debug_struct("QueueMarkerFront");
       ds.finish()
   }
 }
-pub enum AudioMetadataOffset {}
+pub enum QueueMarkerBackOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct AudioMetadata<'a> {
+pub struct QueueMarkerBack<'a> {
   pub _tab: ::flatbuffers::Table<'a>,
 }
 
-impl<'a> ::flatbuffers::Follow<'a> for AudioMetadata<'a> {
-  type Inner = AudioMetadata<'a>;
+impl<'a> ::flatbuffers::Follow<'a> for QueueMarkerBack<'a> {
+  type Inner = QueueMarkerBack<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: unsafe { ::flatbuffers::Table::new(buf, loc) } }
   }
 }
 
-impl<'a> AudioMetadata<'a> {
-  pub const VT_ARTIST: ::flatbuffers::VOffsetT = 4;
-  pub const VT_ALBUM: ::flatbuffers::VOffsetT = 6;
-  pub const VT_CHAPTERS: ::flatbuffers::VOffsetT = 8;
+impl<'a> QueueMarkerBack<'a> {
 
   #[inline]
   pub unsafe fn init_from_table(table: ::flatbuffers::Table<'a>) -> Self {
-    AudioMetadata { _tab: table }
+    QueueMarkerBack { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: ::flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut ::flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args AudioMetadataArgs<'args>
-  ) -> ::flatbuffers::WIPOffset<AudioMetadata<'bldr>> {
-    let mut builder = AudioMetadataBuilder::new(_fbb);
-    if let Some(x) = args.chapters { builder.add_chapters(x); }
-    if let Some(x) = args.album { builder.add_album(x); }
-    if let Some(x) = args.artist { builder.add_artist(x); }
+    _args: &'args QueueMarkerBackArgs
+  ) -> ::flatbuffers::WIPOffset<QueueMarkerBack<'bldr>> {
+    let mut builder = QueueMarkerBackBuilder::new(_fbb);
     builder.finish()
   }
 
-
-  #[inline]
-  pub fn artist(&self) -> Option<&'a str> {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<&str>>(AudioMetadata::VT_ARTIST, None)}
-  }
-  #[inline]
-  pub fn album(&self) -> Option<&'a str> {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<&str>>(AudioMetadata::VT_ALBUM, None)}
-  }
-  #[inline]
-  pub fn chapters(&self) -> Option<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<Chapter<'a>>>> {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<Chapter>>>>(AudioMetadata::VT_CHAPTERS, None)}
-  }
 }
 
-impl ::flatbuffers::Verifiable for AudioMetadata<'_> {
+impl ::flatbuffers::Verifiable for QueueMarkerBack<'_> {
   #[inline]
   fn run_verifier(
     v: &mut ::flatbuffers::Verifier, pos: usize
   ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
     v.visit_table(pos)?
-     .visit_field::<::flatbuffers::ForwardsUOffset<&str>>("artist", Self::VT_ARTIST, false)?
-     .visit_field::<::flatbuffers::ForwardsUOffset<&str>>("album", Self::VT_ALBUM, false)?
-     .visit_field::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'_, ::flatbuffers::ForwardsUOffset<Chapter>>>>("chapters", Self::VT_CHAPTERS, false)?
      .finish();
     Ok(())
   }
 }
-pub struct AudioMetadataArgs<'a> {
-    pub artist: Option<::flatbuffers::WIPOffset<&'a str>>,
-    pub album: Option<::flatbuffers::WIPOffset<&'a str>>,
-    pub chapters: Option<::flatbuffers::WIPOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<Chapter<'a>>>>>,
+pub struct QueueMarkerBackArgs {
 }
-impl<'a> Default for AudioMetadataArgs<'a> {
+impl<'a> Default for QueueMarkerBackArgs {
   #[inline]
   fn default() -> Self {
-    AudioMetadataArgs {
-      artist: None,
-      album: None,
-      chapters: None,
+    QueueMarkerBackArgs {
     }
   }
 }
 
-pub struct AudioMetadataBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
+pub struct QueueMarkerBackBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
   fbb_: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>,
   start_: ::flatbuffers::WIPOffset<::flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> AudioMetadataBuilder<'a, 'b, A> {
-  #[inline]
-  pub fn add_artist(&mut self, artist: ::flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(AudioMetadata::VT_ARTIST, artist);
-  }
-  #[inline]
-  pub fn add_album(&mut self, album: ::flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(AudioMetadata::VT_ALBUM, album);
-  }
-  #[inline]
-  pub fn add_chapters(&mut self, chapters: ::flatbuffers::WIPOffset<::flatbuffers::Vector<'b , ::flatbuffers::ForwardsUOffset<Chapter<'b >>>>) {
-    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(AudioMetadata::VT_CHAPTERS, chapters);
-  }
+impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> QueueMarkerBackBuilder<'a, 'b, A> {
   #[inline]
-  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> AudioMetadataBuilder<'a, 'b, A> {
+  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> QueueMarkerBackBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    AudioMetadataBuilder {
+    QueueMarkerBackBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> ::flatbuffers::WIPOffset<AudioMetadata<'a>> {
+  pub fn finish(self) -> ::flatbuffers::WIPOffset<QueueMarkerBack<'a>> {
     let o = self.fbb_.end_table(self.start_);
     ::flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl ::core::fmt::Debug for AudioMetadata<'_> {
+impl ::core::fmt::Debug for QueueMarkerBack<'_> {
   fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-    let mut ds = f.debug_struct("AudioMetadata");
-      ds.field("artist", &self.artist());
-      ds.field("album", &self.album());
-      ds.field("chapters", &self.chapters());
+    let mut ds = f.debug_struct("QueueMarkerBack");
       ds.finish()
   }
 }
-pub enum RequestHeaderOffset {}
+pub enum VideoTrackMetaOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct RequestHeader<'a> {
+pub struct VideoTrackMeta<'a> {
   pub _tab: ::flatbuffers::Table<'a>,
 }
 
-impl<'a> ::flatbuffers::Follow<'a> for RequestHeader<'a> {
-  type Inner = RequestHeader<'a>;
+impl<'a> ::flatbuffers::Follow<'a> for VideoTrackMeta<'a> {
+  type Inner = VideoTrackMeta<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: unsafe { ::flatbuffers::Table::new(buf, loc) } }
   }
 }
 
-impl<'a> RequestHeader<'a> {
-  pub const VT_KEY: ::flatbuffers::VOffsetT = 4;
-  pub const VT_VALUE: ::flatbuffers::VOffsetT = 6;
+impl<'a> VideoTrackMeta<'a> {
+  pub const VT_RESOLUTION: ::flatbuffers::VOffsetT = 4;
 
   #[inline]
   pub unsafe fn init_from_table(table: ::flatbuffers::Table<'a>) -> Self {
-    RequestHeader { _tab: table }
+    VideoTrackMeta { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: ::flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut ::flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args RequestHeaderArgs<'args>
-  ) -> ::flatbuffers::WIPOffset<RequestHeader<'bldr>> {
-    let mut builder = RequestHeaderBuilder::new(_fbb);
-    if let Some(x) = args.value { builder.add_value(x); }
-    if let Some(x) = args.key { builder.add_key(x); }
+    args: &'args VideoTrackMetaArgs<'args>
+  ) -> ::flatbuffers::WIPOffset<VideoTrackMeta<'bldr>> {
+    let mut builder = VideoTrackMetaBuilder::new(_fbb);
+    if let Some(x) = args.resolution { builder.add_resolution(x); }
     builder.finish()
   }
 
 
   #[inline]
-  pub fn key(&self) -> &'a str {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<&str>>(RequestHeader::VT_KEY, None).unwrap()}
-  }
-  #[inline]
-  pub fn value(&self) -> &'a str {
+  pub fn resolution(&self) -> Option<&'a VideoResolution> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<&str>>(RequestHeader::VT_VALUE, None).unwrap()}
+    unsafe { self._tab.get::<VideoResolution>(VideoTrackMeta::VT_RESOLUTION, None)}
   }
 }
 
-impl ::flatbuffers::Verifiable for RequestHeader<'_> {
+impl ::flatbuffers::Verifiable for VideoTrackMeta<'_> {
   #[inline]
   fn run_verifier(
     v: &mut ::flatbuffers::Verifier, pos: usize
   ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
     v.visit_table(pos)?
-     .visit_field::<::flatbuffers::ForwardsUOffset<&str>>("key", Self::VT_KEY, true)?
-     .visit_field::<::flatbuffers::ForwardsUOffset<&str>>("value", Self::VT_VALUE, true)?
+     .visit_field::<VideoResolution>("resolution", Self::VT_RESOLUTION, false)?
      .finish();
     Ok(())
   }
 }
-pub struct RequestHeaderArgs<'a> {
-    pub key: Option<::flatbuffers::WIPOffset<&'a str>>,
-    pub value: Option<::flatbuffers::WIPOffset<&'a str>>,
+pub struct VideoTrackMetaArgs<'a> {
+    pub resolution: Option<&'a VideoResolution>,
 }
-impl<'a> Default for RequestHeaderArgs<'a> {
+impl<'a> Default for VideoTrackMetaArgs<'a> {
   #[inline]
   fn default() -> Self {
-    RequestHeaderArgs {
-      key: None, // required field
-      value: None, // required field
+    VideoTrackMetaArgs {
+      resolution: None,
     }
   }
 }
 
-pub struct RequestHeaderBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
+pub struct VideoTrackMetaBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
   fbb_: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>,
   start_: ::flatbuffers::WIPOffset<::flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> RequestHeaderBuilder<'a, 'b, A> {
-  #[inline]
-  pub fn add_key(&mut self, key: ::flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(RequestHeader::VT_KEY, key);
-  }
+impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> VideoTrackMetaBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_value(&mut self, value: ::flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(RequestHeader::VT_VALUE, value);
+  pub fn add_resolution(&mut self, resolution: &VideoResolution) {
+    self.fbb_.push_slot_always::<&VideoResolution>(VideoTrackMeta::VT_RESOLUTION, resolution);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> RequestHeaderBuilder<'a, 'b, A> {
+  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> VideoTrackMetaBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    RequestHeaderBuilder {
+    VideoTrackMetaBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> ::flatbuffers::WIPOffset<RequestHeader<'a>> {
+  pub fn finish(self) -> ::flatbuffers::WIPOffset<VideoTrackMeta<'a>> {
     let o = self.fbb_.end_table(self.start_);
-    self.fbb_.required(o, RequestHeader::VT_KEY,"key");
-    self.fbb_.required(o, RequestHeader::VT_VALUE,"value");
     ::flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl ::core::fmt::Debug for RequestHeader<'_> {
+impl ::core::fmt::Debug for VideoTrackMeta<'_> {
   fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-    let mut ds = f.debug_struct("RequestHeader");
-      ds.field("key", &self.key());
-      ds.field("value", &self.value());
+    let mut ds = f.debug_struct("VideoTrackMeta");
+      ds.field("resolution", &self.resolution());
       ds.finish()
   }
 }
-pub enum GenericMetaStringOffset {}
+pub enum AudioTrackMetaOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct GenericMetaString<'a> {
+pub struct AudioTrackMeta<'a> {
   pub _tab: ::flatbuffers::Table<'a>,
 }
 
-impl<'a> ::flatbuffers::Follow<'a> for GenericMetaString<'a> {
-  type Inner = GenericMetaString<'a>;
+impl<'a> ::flatbuffers::Follow<'a> for AudioTrackMeta<'a> {
+  type Inner = AudioTrackMeta<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: unsafe { ::flatbuffers::Table::new(buf, loc) } }
   }
 }
 
-impl<'a> GenericMetaString<'a> {
-  pub const VT_VALUE: ::flatbuffers::VOffsetT = 4;
+impl<'a> AudioTrackMeta<'a> {
 
   #[inline]
   pub unsafe fn init_from_table(table: ::flatbuffers::Table<'a>) -> Self {
-    GenericMetaString { _tab: table }
+    AudioTrackMeta { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: ::flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut ::flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args GenericMetaStringArgs<'args>
-  ) -> ::flatbuffers::WIPOffset<GenericMetaString<'bldr>> {
-    let mut builder = GenericMetaStringBuilder::new(_fbb);
-    if let Some(x) = args.value { builder.add_value(x); }
+    _args: &'args AudioTrackMetaArgs
+  ) -> ::flatbuffers::WIPOffset<AudioTrackMeta<'bldr>> {
+    let mut builder = AudioTrackMetaBuilder::new(_fbb);
     builder.finish()
   }
 
-
-  #[inline]
-  pub fn value(&self) -> Option<&'a str> {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<&str>>(GenericMetaString::VT_VALUE, None)}
-  }
 }
 
-impl ::flatbuffers::Verifiable for GenericMetaString<'_> {
+impl ::flatbuffers::Verifiable for AudioTrackMeta<'_> {
   #[inline]
   fn run_verifier(
     v: &mut ::flatbuffers::Verifier, pos: usize
   ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
     v.visit_table(pos)?
-     .visit_field::<::flatbuffers::ForwardsUOffset<&str>>("value", Self::VT_VALUE, false)?
      .finish();
     Ok(())
   }
 }
-pub struct GenericMetaStringArgs<'a> {
-    pub value: Option<::flatbuffers::WIPOffset<&'a str>>,
+pub struct AudioTrackMetaArgs {
 }
-impl<'a> Default for GenericMetaStringArgs<'a> {
+impl<'a> Default for AudioTrackMetaArgs {
   #[inline]
   fn default() -> Self {
-    GenericMetaStringArgs {
-      value: None,
+    AudioTrackMetaArgs {
     }
   }
 }
 
-pub struct GenericMetaStringBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
+pub struct AudioTrackMetaBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
   fbb_: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>,
   start_: ::flatbuffers::WIPOffset<::flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> GenericMetaStringBuilder<'a, 'b, A> {
-  #[inline]
-  pub fn add_value(&mut self, value: ::flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(GenericMetaString::VT_VALUE, value);
-  }
+impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> AudioTrackMetaBuilder<'a, 'b, A> {
   #[inline]
-  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> GenericMetaStringBuilder<'a, 'b, A> {
+  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> AudioTrackMetaBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    GenericMetaStringBuilder {
+    AudioTrackMetaBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> ::flatbuffers::WIPOffset<GenericMetaString<'a>> {
+  pub fn finish(self) -> ::flatbuffers::WIPOffset<AudioTrackMeta<'a>> {
     let o = self.fbb_.end_table(self.start_);
     ::flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl ::core::fmt::Debug for GenericMetaString<'_> {
+impl ::core::fmt::Debug for AudioTrackMeta<'_> {
   fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-    let mut ds = f.debug_struct("GenericMetaString");
-      ds.field("value", &self.value());
+    let mut ds = f.debug_struct("AudioTrackMeta");
       ds.finish()
   }
 }
-pub enum GenericMetaFloatOffset {}
+pub enum SubtitleTrackMetaOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct GenericMetaFloat<'a> {
+pub struct SubtitleTrackMeta<'a> {
   pub _tab: ::flatbuffers::Table<'a>,
 }
 
-impl<'a> ::flatbuffers::Follow<'a> for GenericMetaFloat<'a> {
-  type Inner = GenericMetaFloat<'a>;
+impl<'a> ::flatbuffers::Follow<'a> for SubtitleTrackMeta<'a> {
+  type Inner = SubtitleTrackMeta<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: unsafe { ::flatbuffers::Table::new(buf, loc) } }
   }
 }
 
-impl<'a> GenericMetaFloat<'a> {
-  pub const VT_VALUE: ::flatbuffers::VOffsetT = 4;
+impl<'a> SubtitleTrackMeta<'a> {
 
   #[inline]
   pub unsafe fn init_from_table(table: ::flatbuffers::Table<'a>) -> Self {
-    GenericMetaFloat { _tab: table }
+    SubtitleTrackMeta { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: ::flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut ::flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args GenericMetaFloatArgs
-  ) -> ::flatbuffers::WIPOffset<GenericMetaFloat<'bldr>> {
-    let mut builder = GenericMetaFloatBuilder::new(_fbb);
-    builder.add_value(args.value);
+    _args: &'args SubtitleTrackMetaArgs
+  ) -> ::flatbuffers::WIPOffset<SubtitleTrackMeta<'bldr>> {
+    let mut builder = SubtitleTrackMetaBuilder::new(_fbb);
     builder.finish()
   }
 
-
-  #[inline]
-  pub fn value(&self) -> f64 {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<f64>(GenericMetaFloat::VT_VALUE, Some(0.0)).unwrap()}
-  }
 }
 
-impl ::flatbuffers::Verifiable for GenericMetaFloat<'_> {
+impl ::flatbuffers::Verifiable for SubtitleTrackMeta<'_> {
   #[inline]
   fn run_verifier(
     v: &mut ::flatbuffers::Verifier, pos: usize
   ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
     v.visit_table(pos)?
-     .visit_field::<f64>("value", Self::VT_VALUE, false)?
      .finish();
     Ok(())
   }
 }
-pub struct GenericMetaFloatArgs {
-    pub value: f64,
+pub struct SubtitleTrackMetaArgs {
 }
-impl<'a> Default for GenericMetaFloatArgs {
+impl<'a> Default for SubtitleTrackMetaArgs {
   #[inline]
   fn default() -> Self {
-    GenericMetaFloatArgs {
-      value: 0.0,
+    SubtitleTrackMetaArgs {
     }
   }
 }
 
-pub struct GenericMetaFloatBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
+pub struct SubtitleTrackMetaBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
   fbb_: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>,
   start_: ::flatbuffers::WIPOffset<::flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> GenericMetaFloatBuilder<'a, 'b, A> {
-  #[inline]
-  pub fn add_value(&mut self, value: f64) {
-    self.fbb_.push_slot::<f64>(GenericMetaFloat::VT_VALUE, value, 0.0);
-  }
+impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> SubtitleTrackMetaBuilder<'a, 'b, A> {
   #[inline]
-  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> GenericMetaFloatBuilder<'a, 'b, A> {
+  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> SubtitleTrackMetaBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    GenericMetaFloatBuilder {
+    SubtitleTrackMetaBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> ::flatbuffers::WIPOffset<GenericMetaFloat<'a>> {
+  pub fn finish(self) -> ::flatbuffers::WIPOffset<SubtitleTrackMeta<'a>> {
     let o = self.fbb_.end_table(self.start_);
     ::flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl ::core::fmt::Debug for GenericMetaFloat<'_> {
+impl ::core::fmt::Debug for SubtitleTrackMeta<'_> {
   fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-    let mut ds = f.debug_struct("GenericMetaFloat");
-      ds.field("value", &self.value());
+    let mut ds = f.debug_struct("SubtitleTrackMeta");
       ds.finish()
   }
 }
-pub enum GenericMetaIntOffset {}
+pub enum DeviceInfoOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct GenericMetaInt<'a> {
+pub struct DeviceInfo<'a> {
   pub _tab: ::flatbuffers::Table<'a>,
 }
 
-impl<'a> ::flatbuffers::Follow<'a> for GenericMetaInt<'a> {
-  type Inner = GenericMetaInt<'a>;
+impl<'a> ::flatbuffers::Follow<'a> for DeviceInfo<'a> {
+  type Inner = DeviceInfo<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: unsafe { ::flatbuffers::Table::new(buf, loc) } }
   }
 }
 
-impl<'a> GenericMetaInt<'a> {
-  pub const VT_VALUE: ::flatbuffers::VOffsetT = 4;
+impl<'a> DeviceInfo<'a> {
+  pub const VT_DISPLAY_NAME: ::flatbuffers::VOffsetT = 4;
+  pub const VT_APP_NAME: ::flatbuffers::VOffsetT = 6;
+  pub const VT_APP_VERSION: ::flatbuffers::VOffsetT = 8;
 
   #[inline]
   pub unsafe fn init_from_table(table: ::flatbuffers::Table<'a>) -> Self {
-    GenericMetaInt { _tab: table }
+    DeviceInfo { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: ::flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut ::flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args GenericMetaIntArgs
-  ) -> ::flatbuffers::WIPOffset<GenericMetaInt<'bldr>> {
-    let mut builder = GenericMetaIntBuilder::new(_fbb);
-    builder.add_value(args.value);
+    args: &'args DeviceInfoArgs<'args>
+  ) -> ::flatbuffers::WIPOffset<DeviceInfo<'bldr>> {
+    let mut builder = DeviceInfoBuilder::new(_fbb);
+    if let Some(x) = args.app_version { builder.add_app_version(x); }
+    if let Some(x) = args.app_name { builder.add_app_name(x); }
+    if let Some(x) = args.display_name { builder.add_display_name(x); }
     builder.finish()
   }
 
 
   #[inline]
-  pub fn value(&self) -> i64 {
+  pub fn display_name(&self) -> Option<&'a str> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<i64>(GenericMetaInt::VT_VALUE, Some(0)).unwrap()}
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<&str>>(DeviceInfo::VT_DISPLAY_NAME, None)}
+  }
+  #[inline]
+  pub fn app_name(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<&str>>(DeviceInfo::VT_APP_NAME, None)}
+  }
+  #[inline]
+  pub fn app_version(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<&str>>(DeviceInfo::VT_APP_VERSION, None)}
   }
 }
 
-impl ::flatbuffers::Verifiable for GenericMetaInt<'_> {
+impl ::flatbuffers::Verifiable for DeviceInfo<'_> {
   #[inline]
   fn run_verifier(
     v: &mut ::flatbuffers::Verifier, pos: usize
   ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
     v.visit_table(pos)?
-     .visit_field::<i64>("value", Self::VT_VALUE, false)?
+     .visit_field::<::flatbuffers::ForwardsUOffset<&str>>("display_name", Self::VT_DISPLAY_NAME, false)?
+     .visit_field::<::flatbuffers::ForwardsUOffset<&str>>("app_name", Self::VT_APP_NAME, false)?
+     .visit_field::<::flatbuffers::ForwardsUOffset<&str>>("app_version", Self::VT_APP_VERSION, false)?
      .finish();
     Ok(())
   }
 }
-pub struct GenericMetaIntArgs {
-    pub value: i64,
+pub struct DeviceInfoArgs<'a> {
+    pub display_name: Option<::flatbuffers::WIPOffset<&'a str>>,
+    pub app_name: Option<::flatbuffers::WIPOffset<&'a str>>,
+    pub app_version: Option<::flatbuffers::WIPOffset<&'a str>>,
 }
-impl<'a> Default for GenericMetaIntArgs {
+impl<'a> Default for DeviceInfoArgs<'a> {
   #[inline]
   fn default() -> Self {
-    GenericMetaIntArgs {
-      value: 0,
+    DeviceInfoArgs {
+      display_name: None,
+      app_name: None,
+      app_version: None,
     }
   }
 }
 
-pub struct GenericMetaIntBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
+pub struct DeviceInfoBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
   fbb_: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>,
   start_: ::flatbuffers::WIPOffset<::flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> GenericMetaIntBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> DeviceInfoBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_value(&mut self, value: i64) {
-    self.fbb_.push_slot::<i64>(GenericMetaInt::VT_VALUE, value, 0);
+  pub fn add_display_name(&mut self, display_name: ::flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(DeviceInfo::VT_DISPLAY_NAME, display_name);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> GenericMetaIntBuilder<'a, 'b, A> {
+  pub fn add_app_name(&mut self, app_name: ::flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(DeviceInfo::VT_APP_NAME, app_name);
+  }
+  #[inline]
+  pub fn add_app_version(&mut self, app_version: ::flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(DeviceInfo::VT_APP_VERSION, app_version);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> DeviceInfoBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    GenericMetaIntBuilder {
+    DeviceInfoBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> ::flatbuffers::WIPOffset<GenericMetaInt<'a>> {
+  pub fn finish(self) -> ::flatbuffers::WIPOffset<DeviceInfo<'a>> {
     let o = self.fbb_.end_table(self.start_);
     ::flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl ::core::fmt::Debug for GenericMetaInt<'_> {
+impl ::core::fmt::Debug for DeviceInfo<'_> {
   fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-    let mut ds = f.debug_struct("GenericMetaInt");
-      ds.field("value", &self.value());
+    let mut ds = f.debug_struct("DeviceInfo");
+      ds.field("display_name", &self.display_name());
+      ds.field("app_name", &self.app_name());
+      ds.field("app_version", &self.app_version());
       ds.finish()
   }
 }
-pub enum GenericMetaListOffset {}
+pub enum DisplayCapabilitiesOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct GenericMetaList<'a> {
+pub struct DisplayCapabilities<'a> {
   pub _tab: ::flatbuffers::Table<'a>,
 }
 
-impl<'a> ::flatbuffers::Follow<'a> for GenericMetaList<'a> {
-  type Inner = GenericMetaList<'a>;
+impl<'a> ::flatbuffers::Follow<'a> for DisplayCapabilities<'a> {
+  type Inner = DisplayCapabilities<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: unsafe { ::flatbuffers::Table::new(buf, loc) } }
   }
 }
 
-impl<'a> GenericMetaList<'a> {
-  pub const VT_VALUE: ::flatbuffers::VOffsetT = 4;
+impl<'a> DisplayCapabilities<'a> {
+  pub const VT_RESOLUTION: ::flatbuffers::VOffsetT = 4;
 
   #[inline]
   pub unsafe fn init_from_table(table: ::flatbuffers::Table<'a>) -> Self {
-    GenericMetaList { _tab: table }
+    DisplayCapabilities { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: ::flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut ::flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args GenericMetaListArgs<'args>
-  ) -> ::flatbuffers::WIPOffset<GenericMetaList<'bldr>> {
-    let mut builder = GenericMetaListBuilder::new(_fbb);
-    if let Some(x) = args.value { builder.add_value(x); }
+    args: &'args DisplayCapabilitiesArgs<'args>
+  ) -> ::flatbuffers::WIPOffset<DisplayCapabilities<'bldr>> {
+    let mut builder = DisplayCapabilitiesBuilder::new(_fbb);
+    if let Some(x) = args.resolution { builder.add_resolution(x); }
     builder.finish()
   }
 
 
   #[inline]
-  pub fn value(&self) -> Option<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<WrappedGenericMetaValue<'a>>>> {
+  pub fn resolution(&self) -> Option<&'a VideoResolution> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<WrappedGenericMetaValue>>>>(GenericMetaList::VT_VALUE, None)}
+    unsafe { self._tab.get::<VideoResolution>(DisplayCapabilities::VT_RESOLUTION, None)}
   }
 }
 
-impl ::flatbuffers::Verifiable for GenericMetaList<'_> {
+impl ::flatbuffers::Verifiable for DisplayCapabilities<'_> {
   #[inline]
   fn run_verifier(
     v: &mut ::flatbuffers::Verifier, pos: usize
   ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
     v.visit_table(pos)?
-     .visit_field::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'_, ::flatbuffers::ForwardsUOffset<WrappedGenericMetaValue>>>>("value", Self::VT_VALUE, false)?
+     .visit_field::<VideoResolution>("resolution", Self::VT_RESOLUTION, false)?
      .finish();
     Ok(())
   }
 }
-pub struct GenericMetaListArgs<'a> {
-    pub value: Option<::flatbuffers::WIPOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<WrappedGenericMetaValue<'a>>>>>,
+pub struct DisplayCapabilitiesArgs<'a> {
+    pub resolution: Option<&'a VideoResolution>,
 }
-impl<'a> Default for GenericMetaListArgs<'a> {
+impl<'a> Default for DisplayCapabilitiesArgs<'a> {
   #[inline]
   fn default() -> Self {
-    GenericMetaListArgs {
-      value: None,
+    DisplayCapabilitiesArgs {
+      resolution: None,
     }
   }
 }
 
-pub struct GenericMetaListBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
+pub struct DisplayCapabilitiesBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
   fbb_: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>,
   start_: ::flatbuffers::WIPOffset<::flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> GenericMetaListBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> DisplayCapabilitiesBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_value(&mut self, value: ::flatbuffers::WIPOffset<::flatbuffers::Vector<'b , ::flatbuffers::ForwardsUOffset<WrappedGenericMetaValue<'b >>>>) {
-    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(GenericMetaList::VT_VALUE, value);
+  pub fn add_resolution(&mut self, resolution: &VideoResolution) {
+    self.fbb_.push_slot_always::<&VideoResolution>(DisplayCapabilities::VT_RESOLUTION, resolution);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> GenericMetaListBuilder<'a, 'b, A> {
+  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> DisplayCapabilitiesBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    GenericMetaListBuilder {
+    DisplayCapabilitiesBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> ::flatbuffers::WIPOffset<GenericMetaList<'a>> {
+  pub fn finish(self) -> ::flatbuffers::WIPOffset<DisplayCapabilities<'a>> {
     let o = self.fbb_.end_table(self.start_);
     ::flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl ::core::fmt::Debug for GenericMetaList<'_> {
+impl ::core::fmt::Debug for DisplayCapabilities<'_> {
   fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-    let mut ds = f.debug_struct("GenericMetaList");
-      ds.field("value", &self.value());
+    let mut ds = f.debug_struct("DisplayCapabilities");
+      ds.field("resolution", &self.resolution());
       ds.finish()
   }
 }
-pub enum WrappedGenericMetaValueOffset {}
+pub enum MediaCapabilitiesOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct WrappedGenericMetaValue<'a> {
+pub struct MediaCapabilities<'a> {
   pub _tab: ::flatbuffers::Table<'a>,
 }
 
-impl<'a> ::flatbuffers::Follow<'a> for WrappedGenericMetaValue<'a> {
-  type Inner = WrappedGenericMetaValue<'a>;
+impl<'a> ::flatbuffers::Follow<'a> for MediaCapabilities<'a> {
+  type Inner = MediaCapabilities<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: unsafe { ::flatbuffers::Table::new(buf, loc) } }
   }
 }
 
-impl<'a> WrappedGenericMetaValue<'a> {
-  pub const VT_VALUE_TYPE: ::flatbuffers::VOffsetT = 4;
-  pub const VT_VALUE: ::flatbuffers::VOffsetT = 6;
+impl<'a> MediaCapabilities<'a> {
+  pub const VT_PROTOCOLS: ::flatbuffers::VOffsetT = 4;
+  pub const VT_CONTAINERS: ::flatbuffers::VOffsetT = 6;
+  pub const VT_VIDEO_FORMATS: ::flatbuffers::VOffsetT = 8;
+  pub const VT_AUDIO_FORMATS: ::flatbuffers::VOffsetT = 10;
+  pub const VT_SUBTITLE_FORMATS: ::flatbuffers::VOffsetT = 12;
+  pub const VT_HDR_FORMATS: ::flatbuffers::VOffsetT = 14;
+  pub const VT_IMAGE_FORMATS: ::flatbuffers::VOffsetT = 16;
+  pub const VT_EXTERNAL_SUBTITLES: ::flatbuffers::VOffsetT = 18;
+  pub const VT_MIRRORING: ::flatbuffers::VOffsetT = 20;
 
   #[inline]
   pub unsafe fn init_from_table(table: ::flatbuffers::Table<'a>) -> Self {
-    WrappedGenericMetaValue { _tab: table }
+    MediaCapabilities { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: ::flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut ::flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args WrappedGenericMetaValueArgs
-  ) -> ::flatbuffers::WIPOffset<WrappedGenericMetaValue<'bldr>> {
-    let mut builder = WrappedGenericMetaValueBuilder::new(_fbb);
-    if let Some(x) = args.value { builder.add_value(x); }
-    builder.add_value_type(args.value_type);
+    args: &'args MediaCapabilitiesArgs<'args>
+  ) -> ::flatbuffers::WIPOffset<MediaCapabilities<'bldr>> {
+    let mut builder = MediaCapabilitiesBuilder::new(_fbb);
+    if let Some(x) = args.image_formats { builder.add_image_formats(x); }
+    if let Some(x) = args.hdr_formats { builder.add_hdr_formats(x); }
+    if let Some(x) = args.subtitle_formats { builder.add_subtitle_formats(x); }
+    if let Some(x) = args.audio_formats { builder.add_audio_formats(x); }
+    if let Some(x) = args.video_formats { builder.add_video_formats(x); }
+    if let Some(x) = args.containers { builder.add_containers(x); }
+    if let Some(x) = args.protocols { builder.add_protocols(x); }
+    builder.add_mirroring(args.mirroring);
+    builder.add_external_subtitles(args.external_subtitles);
     builder.finish()
   }
 
 
   #[inline]
-  pub fn value_type(&self) -> GenericMetaValue {
+  pub fn protocols(&self) -> Option<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<&'a str>>> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<GenericMetaValue>(WrappedGenericMetaValue::VT_VALUE_TYPE, Some(GenericMetaValue::NONE)).unwrap()}
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<&'a str>>>>(MediaCapabilities::VT_PROTOCOLS, None)}
   }
   #[inline]
-  pub fn value(&self) -> Option<::flatbuffers::Table<'a>> {
+  pub fn containers(&self) -> Option<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<&'a str>>> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<::flatbuffers::Table<'a>>>(WrappedGenericMetaValue::VT_VALUE, None)}
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<&'a str>>>>(MediaCapabilities::VT_CONTAINERS, None)}
   }
   #[inline]
-  #[allow(non_snake_case)]
-  pub fn value_as_string(&self) -> Option<GenericMetaString<'a>> {
-    if self.value_type() == GenericMetaValue::String {
-      self.value().map(|t| {
-       // Safety:
-       // Created from a valid Table for this object
-       // Which contains a valid union in this slot
-       unsafe { GenericMetaString::init_from_table(t) }
-     })
-    } else {
-      None
-    }
+  pub fn video_formats(&self) -> Option<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<&'a str>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<&'a str>>>>(MediaCapabilities::VT_VIDEO_FORMATS, None)}
   }
-
   #[inline]
-  #[allow(non_snake_case)]
-  pub fn value_as_float(&self) -> Option<GenericMetaFloat<'a>> {
-    if self.value_type() == GenericMetaValue::Float {
-      self.value().map(|t| {
-       // Safety:
-       // Created from a valid Table for this object
-       // Which contains a valid union in this slot
-       unsafe { GenericMetaFloat::init_from_table(t) }
-     })
-    } else {
-      None
-    }
+  pub fn audio_formats(&self) -> Option<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<&'a str>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<&'a str>>>>(MediaCapabilities::VT_AUDIO_FORMATS, None)}
   }
-
   #[inline]
-  #[allow(non_snake_case)]
-  pub fn value_as_int(&self) -> Option<GenericMetaInt<'a>> {
-    if self.value_type() == GenericMetaValue::Int {
-      self.value().map(|t| {
-       // Safety:
-       // Created from a valid Table for this object
-       // Which contains a valid union in this slot
-       unsafe { GenericMetaInt::init_from_table(t) }
-     })
-    } else {
-      None
-    }
+  pub fn subtitle_formats(&self) -> Option<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<&'a str>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<&'a str>>>>(MediaCapabilities::VT_SUBTITLE_FORMATS, None)}
   }
-
   #[inline]
-  #[allow(non_snake_case)]
-  pub fn value_as_list(&self) -> Option<GenericMetaList<'a>> {
-    if self.value_type() == GenericMetaValue::List {
-      self.value().map(|t| {
-       // Safety:
-       // Created from a valid Table for this object
-       // Which contains a valid union in this slot
-       unsafe { GenericMetaList::init_from_table(t) }
-     })
-    } else {
-      None
-    }
+  pub fn hdr_formats(&self) -> Option<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<&'a str>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<&'a str>>>>(MediaCapabilities::VT_HDR_FORMATS, None)}
   }
-
   #[inline]
-  #[allow(non_snake_case)]
-  pub fn value_as_kvpair(&self) -> Option<MetadataKV<'a>> {
-    if self.value_type() == GenericMetaValue::KVPair {
-      self.value().map(|t| {
-       // Safety:
-       // Created from a valid Table for this object
-       // Which contains a valid union in this slot
-       unsafe { MetadataKV::init_from_table(t) }
-     })
-    } else {
-      None
-    }
+  pub fn image_formats(&self) -> Option<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<&'a str>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<&'a str>>>>(MediaCapabilities::VT_IMAGE_FORMATS, None)}
+  }
+  #[inline]
+  pub fn external_subtitles(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(MediaCapabilities::VT_EXTERNAL_SUBTITLES, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn mirroring(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(MediaCapabilities::VT_MIRRORING, Some(false)).unwrap()}
   }
-
 }
 
-impl ::flatbuffers::Verifiable for WrappedGenericMetaValue<'_> {
+impl ::flatbuffers::Verifiable for MediaCapabilities<'_> {
   #[inline]
   fn run_verifier(
     v: &mut ::flatbuffers::Verifier, pos: usize
   ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
     v.visit_table(pos)?
-     .visit_union::<GenericMetaValue, _>("value_type", Self::VT_VALUE_TYPE, "value", Self::VT_VALUE, false, |key, v, pos| {
-        match key {
-          GenericMetaValue::String => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<GenericMetaString>>("GenericMetaValue::String", pos),
-          GenericMetaValue::Float => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<GenericMetaFloat>>("GenericMetaValue::Float", pos),
-          GenericMetaValue::Int => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<GenericMetaInt>>("GenericMetaValue::Int", pos),
-          GenericMetaValue::List => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<GenericMetaList>>("GenericMetaValue::List", pos),
-          GenericMetaValue::KVPair => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<MetadataKV>>("GenericMetaValue::KVPair", pos),
-          _ => Ok(()),
-        }
-     })?
+     .visit_field::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'_, ::flatbuffers::ForwardsUOffset<&'_ str>>>>("protocols", Self::VT_PROTOCOLS, false)?
+     .visit_field::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'_, ::flatbuffers::ForwardsUOffset<&'_ str>>>>("containers", Self::VT_CONTAINERS, false)?
+     .visit_field::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'_, ::flatbuffers::ForwardsUOffset<&'_ str>>>>("video_formats", Self::VT_VIDEO_FORMATS, false)?
+     .visit_field::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'_, ::flatbuffers::ForwardsUOffset<&'_ str>>>>("audio_formats", Self::VT_AUDIO_FORMATS, false)?
+     .visit_field::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'_, ::flatbuffers::ForwardsUOffset<&'_ str>>>>("subtitle_formats", Self::VT_SUBTITLE_FORMATS, false)?
+     .visit_field::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'_, ::flatbuffers::ForwardsUOffset<&'_ str>>>>("hdr_formats", Self::VT_HDR_FORMATS, false)?
+     .visit_field::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'_, ::flatbuffers::ForwardsUOffset<&'_ str>>>>("image_formats", Self::VT_IMAGE_FORMATS, false)?
+     .visit_field::<bool>("external_subtitles", Self::VT_EXTERNAL_SUBTITLES, false)?
+     .visit_field::<bool>("mirroring", Self::VT_MIRRORING, false)?
      .finish();
     Ok(())
   }
 }
-pub struct WrappedGenericMetaValueArgs {
-    pub value_type: GenericMetaValue,
-    pub value: Option<::flatbuffers::WIPOffset<::flatbuffers::UnionWIPOffset>>,
+pub struct MediaCapabilitiesArgs<'a> {
+    pub protocols: Option<::flatbuffers::WIPOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<&'a str>>>>,
+    pub containers: Option<::flatbuffers::WIPOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<&'a str>>>>,
+    pub video_formats: Option<::flatbuffers::WIPOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<&'a str>>>>,
+    pub audio_formats: Option<::flatbuffers::WIPOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<&'a str>>>>,
+    pub subtitle_formats: Option<::flatbuffers::WIPOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<&'a str>>>>,
+    pub hdr_formats: Option<::flatbuffers::WIPOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<&'a str>>>>,
+    pub image_formats: Option<::flatbuffers::WIPOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<&'a str>>>>,
+    pub external_subtitles: bool,
+    pub mirroring: bool,
 }
-impl<'a> Default for WrappedGenericMetaValueArgs {
+impl<'a> Default for MediaCapabilitiesArgs<'a> {
   #[inline]
   fn default() -> Self {
-    WrappedGenericMetaValueArgs {
-      value_type: GenericMetaValue::NONE,
-      value: None,
+    MediaCapabilitiesArgs {
+      protocols: None,
+      containers: None,
+      video_formats: None,
+      audio_formats: None,
+      subtitle_formats: None,
+      hdr_formats: None,
+      image_formats: None,
+      external_subtitles: false,
+      mirroring: false,
     }
   }
 }
 
-pub struct WrappedGenericMetaValueBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
+pub struct MediaCapabilitiesBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
   fbb_: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>,
   start_: ::flatbuffers::WIPOffset<::flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> WrappedGenericMetaValueBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> MediaCapabilitiesBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_value_type(&mut self, value_type: GenericMetaValue) {
-    self.fbb_.push_slot::<GenericMetaValue>(WrappedGenericMetaValue::VT_VALUE_TYPE, value_type, GenericMetaValue::NONE);
+  pub fn add_protocols(&mut self, protocols: ::flatbuffers::WIPOffset<::flatbuffers::Vector<'b , ::flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(MediaCapabilities::VT_PROTOCOLS, protocols);
   }
   #[inline]
-  pub fn add_value(&mut self, value: ::flatbuffers::WIPOffset<::flatbuffers::UnionWIPOffset>) {
-    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(WrappedGenericMetaValue::VT_VALUE, value);
+  pub fn add_containers(&mut self, containers: ::flatbuffers::WIPOffset<::flatbuffers::Vector<'b , ::flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(MediaCapabilities::VT_CONTAINERS, containers);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> WrappedGenericMetaValueBuilder<'a, 'b, A> {
+  pub fn add_video_formats(&mut self, video_formats: ::flatbuffers::WIPOffset<::flatbuffers::Vector<'b , ::flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(MediaCapabilities::VT_VIDEO_FORMATS, video_formats);
+  }
+  #[inline]
+  pub fn add_audio_formats(&mut self, audio_formats: ::flatbuffers::WIPOffset<::flatbuffers::Vector<'b , ::flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(MediaCapabilities::VT_AUDIO_FORMATS, audio_formats);
+  }
+  #[inline]
+  pub fn add_subtitle_formats(&mut self, subtitle_formats: ::flatbuffers::WIPOffset<::flatbuffers::Vector<'b , ::flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(MediaCapabilities::VT_SUBTITLE_FORMATS, subtitle_formats);
+  }
+  #[inline]
+  pub fn add_hdr_formats(&mut self, hdr_formats: ::flatbuffers::WIPOffset<::flatbuffers::Vector<'b , ::flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(MediaCapabilities::VT_HDR_FORMATS, hdr_formats);
+  }
+  #[inline]
+  pub fn add_image_formats(&mut self, image_formats: ::flatbuffers::WIPOffset<::flatbuffers::Vector<'b , ::flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(MediaCapabilities::VT_IMAGE_FORMATS, image_formats);
+  }
+  #[inline]
+  pub fn add_external_subtitles(&mut self, external_subtitles: bool) {
+    self.fbb_.push_slot::<bool>(MediaCapabilities::VT_EXTERNAL_SUBTITLES, external_subtitles, false);
+  }
+  #[inline]
+  pub fn add_mirroring(&mut self, mirroring: bool) {
+    self.fbb_.push_slot::<bool>(MediaCapabilities::VT_MIRRORING, mirroring, false);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> MediaCapabilitiesBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    WrappedGenericMetaValueBuilder {
+    MediaCapabilitiesBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> ::flatbuffers::WIPOffset<WrappedGenericMetaValue<'a>> {
+  pub fn finish(self) -> ::flatbuffers::WIPOffset<MediaCapabilities<'a>> {
     let o = self.fbb_.end_table(self.start_);
     ::flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl ::core::fmt::Debug for WrappedGenericMetaValue<'_> {
+impl ::core::fmt::Debug for MediaCapabilities<'_> {
   fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-    let mut ds = f.debug_struct("WrappedGenericMetaValue");
-      ds.field("value_type", &self.value_type());
-      match self.value_type() {
-        GenericMetaValue::String => {
-          if let Some(x) = self.value_as_string() {
-            ds.field("value", &x)
-          } else {
-            ds.field("value", &"InvalidFlatbuffer: Union discriminant does not match value.")
-          }
-        },
-        GenericMetaValue::Float => {
-          if let Some(x) = self.value_as_float() {
-            ds.field("value", &x)
-          } else {
-            ds.field("value", &"InvalidFlatbuffer: Union discriminant does not match value.")
-          }
-        },
-        GenericMetaValue::Int => {
-          if let Some(x) = self.value_as_int() {
-            ds.field("value", &x)
-          } else {
-            ds.field("value", &"InvalidFlatbuffer: Union discriminant does not match value.")
-          }
-        },
-        GenericMetaValue::List => {
-          if let Some(x) = self.value_as_list() {
-            ds.field("value", &x)
-          } else {
-            ds.field("value", &"InvalidFlatbuffer: Union discriminant does not match value.")
-          }
-        },
-        GenericMetaValue::KVPair => {
-          if let Some(x) = self.value_as_kvpair() {
-            ds.field("value", &x)
-          } else {
-            ds.field("value", &"InvalidFlatbuffer: Union discriminant does not match value.")
-          }
-        },
-        _ => {
-          let x: Option<()> = None;
-          ds.field("value", &x)
-        },
-      };
+    let mut ds = f.debug_struct("MediaCapabilities");
+      ds.field("protocols", &self.protocols());
+      ds.field("containers", &self.containers());
+      ds.field("video_formats", &self.video_formats());
+      ds.field("audio_formats", &self.audio_formats());
+      ds.field("subtitle_formats", &self.subtitle_formats());
+      ds.field("hdr_formats", &self.hdr_formats());
+      ds.field("image_formats", &self.image_formats());
+      ds.field("external_subtitles", &self.external_subtitles());
+      ds.field("mirroring", &self.mirroring());
       ds.finish()
   }
 }
-pub enum MetadataKVOffset {}
+pub enum AudioCapabilitiesOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct MetadataKV<'a> {
+pub struct AudioCapabilities<'a> {
   pub _tab: ::flatbuffers::Table<'a>,
 }
 
-impl<'a> ::flatbuffers::Follow<'a> for MetadataKV<'a> {
-  type Inner = MetadataKV<'a>;
+impl<'a> ::flatbuffers::Follow<'a> for AudioCapabilities<'a> {
+  type Inner = AudioCapabilities<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: unsafe { ::flatbuffers::Table::new(buf, loc) } }
   }
 }
 
-impl<'a> MetadataKV<'a> {
-  pub const VT_KEY: ::flatbuffers::VOffsetT = 4;
-  pub const VT_VALUE_TYPE: ::flatbuffers::VOffsetT = 6;
-  pub const VT_VALUE: ::flatbuffers::VOffsetT = 8;
+impl<'a> AudioCapabilities<'a> {
+  pub const VT_VOLUME_STEP_INTERVAL: ::flatbuffers::VOffsetT = 4;
 
   #[inline]
   pub unsafe fn init_from_table(table: ::flatbuffers::Table<'a>) -> Self {
-    MetadataKV { _tab: table }
+    AudioCapabilities { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: ::flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut ::flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args MetadataKVArgs<'args>
-  ) -> ::flatbuffers::WIPOffset<MetadataKV<'bldr>> {
-    let mut builder = MetadataKVBuilder::new(_fbb);
-    if let Some(x) = args.value { builder.add_value(x); }
-    if let Some(x) = args.key { builder.add_key(x); }
-    builder.add_value_type(args.value_type);
-    builder.finish()
-  }
-
-
-  #[inline]
-  pub fn key(&self) -> &'a str {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<&str>>(MetadataKV::VT_KEY, None).unwrap()}
-  }
-  #[inline]
-  pub fn value_type(&self) -> GenericMetaValue {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<GenericMetaValue>(MetadataKV::VT_VALUE_TYPE, Some(GenericMetaValue::NONE)).unwrap()}
-  }
-  #[inline]
-  pub fn value(&self) -> Option<::flatbuffers::Table<'a>> {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<::flatbuffers::Table<'a>>>(MetadataKV::VT_VALUE, None)}
-  }
-  #[inline]
-  #[allow(non_snake_case)]
-  pub fn value_as_string(&self) -> Option<GenericMetaString<'a>> {
-    if self.value_type() == GenericMetaValue::String {
-      self.value().map(|t| {
-       // Safety:
-       // Created from a valid Table for this object
-       // Which contains a valid union in this slot
-       unsafe { GenericMetaString::init_from_table(t) }
-     })
-    } else {
-      None
-    }
-  }
-
-  #[inline]
-  #[allow(non_snake_case)]
-  pub fn value_as_float(&self) -> Option<GenericMetaFloat<'a>> {
-    if self.value_type() == GenericMetaValue::Float {
-      self.value().map(|t| {
-       // Safety:
-       // Created from a valid Table for this object
-       // Which contains a valid union in this slot
-       unsafe { GenericMetaFloat::init_from_table(t) }
-     })
-    } else {
-      None
-    }
-  }
-
-  #[inline]
-  #[allow(non_snake_case)]
-  pub fn value_as_int(&self) -> Option<GenericMetaInt<'a>> {
-    if self.value_type() == GenericMetaValue::Int {
-      self.value().map(|t| {
-       // Safety:
-       // Created from a valid Table for this object
-       // Which contains a valid union in this slot
-       unsafe { GenericMetaInt::init_from_table(t) }
-     })
-    } else {
-      None
-    }
-  }
-
-  #[inline]
-  #[allow(non_snake_case)]
-  pub fn value_as_list(&self) -> Option<GenericMetaList<'a>> {
-    if self.value_type() == GenericMetaValue::List {
-      self.value().map(|t| {
-       // Safety:
-       // Created from a valid Table for this object
-       // Which contains a valid union in this slot
-       unsafe { GenericMetaList::init_from_table(t) }
-     })
-    } else {
-      None
-    }
-  }
-
-  #[inline]
-  #[allow(non_snake_case)]
-  pub fn value_as_kvpair(&self) -> Option<MetadataKV<'a>> {
-    if self.value_type() == GenericMetaValue::KVPair {
-      self.value().map(|t| {
-       // Safety:
-       // Created from a valid Table for this object
-       // Which contains a valid union in this slot
-       unsafe { MetadataKV::init_from_table(t) }
-     })
-    } else {
-      None
-    }
+    args: &'args AudioCapabilitiesArgs
+  ) -> ::flatbuffers::WIPOffset<AudioCapabilities<'bldr>> {
+    let mut builder = AudioCapabilitiesBuilder::new(_fbb);
+    builder.add_volume_step_interval(args.volume_step_interval);
+    builder.finish()
   }
 
+
+  #[inline]
+  pub fn volume_step_interval(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(AudioCapabilities::VT_VOLUME_STEP_INTERVAL, Some(0.0)).unwrap()}
+  }
 }
 
-impl ::flatbuffers::Verifiable for MetadataKV<'_> {
+impl ::flatbuffers::Verifiable for AudioCapabilities<'_> {
   #[inline]
   fn run_verifier(
     v: &mut ::flatbuffers::Verifier, pos: usize
   ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
     v.visit_table(pos)?
-     .visit_field::<::flatbuffers::ForwardsUOffset<&str>>("key", Self::VT_KEY, true)?
-     .visit_union::<GenericMetaValue, _>("value_type", Self::VT_VALUE_TYPE, "value", Self::VT_VALUE, false, |key, v, pos| {
-        match key {
-          GenericMetaValue::String => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<GenericMetaString>>("GenericMetaValue::String", pos),
-          GenericMetaValue::Float => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<GenericMetaFloat>>("GenericMetaValue::Float", pos),
-          GenericMetaValue::Int => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<GenericMetaInt>>("GenericMetaValue::Int", pos),
-          GenericMetaValue::List => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<GenericMetaList>>("GenericMetaValue::List", pos),
-          GenericMetaValue::KVPair => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<MetadataKV>>("GenericMetaValue::KVPair", pos),
-          _ => Ok(()),
-        }
-     })?
+     .visit_field::<f32>("volume_step_interval", Self::VT_VOLUME_STEP_INTERVAL, false)?
      .finish();
     Ok(())
   }
 }
-pub struct MetadataKVArgs<'a> {
-    pub key: Option<::flatbuffers::WIPOffset<&'a str>>,
-    pub value_type: GenericMetaValue,
-    pub value: Option<::flatbuffers::WIPOffset<::flatbuffers::UnionWIPOffset>>,
+pub struct AudioCapabilitiesArgs {
+    pub volume_step_interval: f32,
 }
-impl<'a> Default for MetadataKVArgs<'a> {
+impl<'a> Default for AudioCapabilitiesArgs {
   #[inline]
   fn default() -> Self {
-    MetadataKVArgs {
-      key: None, // required field
-      value_type: GenericMetaValue::NONE,
-      value: None,
+    AudioCapabilitiesArgs {
+      volume_step_interval: 0.0,
     }
   }
 }
 
-pub struct MetadataKVBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
+pub struct AudioCapabilitiesBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
   fbb_: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>,
   start_: ::flatbuffers::WIPOffset<::flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> MetadataKVBuilder<'a, 'b, A> {
-  #[inline]
-  pub fn add_key(&mut self, key: ::flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(MetadataKV::VT_KEY, key);
-  }
-  #[inline]
-  pub fn add_value_type(&mut self, value_type: GenericMetaValue) {
-    self.fbb_.push_slot::<GenericMetaValue>(MetadataKV::VT_VALUE_TYPE, value_type, GenericMetaValue::NONE);
-  }
+impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> AudioCapabilitiesBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_value(&mut self, value: ::flatbuffers::WIPOffset<::flatbuffers::UnionWIPOffset>) {
-    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(MetadataKV::VT_VALUE, value);
+  pub fn add_volume_step_interval(&mut self, volume_step_interval: f32) {
+    self.fbb_.push_slot::<f32>(AudioCapabilities::VT_VOLUME_STEP_INTERVAL, volume_step_interval, 0.0);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> MetadataKVBuilder<'a, 'b, A> {
+  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> AudioCapabilitiesBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    MetadataKVBuilder {
+    AudioCapabilitiesBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> ::flatbuffers::WIPOffset<MetadataKV<'a>> {
+  pub fn finish(self) -> ::flatbuffers::WIPOffset<AudioCapabilities<'a>> {
     let o = self.fbb_.end_table(self.start_);
-    self.fbb_.required(o, MetadataKV::VT_KEY,"key");
     ::flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl ::core::fmt::Debug for MetadataKV<'_> {
+impl ::core::fmt::Debug for AudioCapabilities<'_> {
   fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-    let mut ds = f.debug_struct("MetadataKV");
-      ds.field("key", &self.key());
-      ds.field("value_type", &self.value_type());
-      match self.value_type() {
-        GenericMetaValue::String => {
-          if let Some(x) = self.value_as_string() {
-            ds.field("value", &x)
-          } else {
-            ds.field("value", &"InvalidFlatbuffer: Union discriminant does not match value.")
-          }
-        },
-        GenericMetaValue::Float => {
-          if let Some(x) = self.value_as_float() {
-            ds.field("value", &x)
-          } else {
-            ds.field("value", &"InvalidFlatbuffer: Union discriminant does not match value.")
-          }
-        },
-        GenericMetaValue::Int => {
-          if let Some(x) = self.value_as_int() {
-            ds.field("value", &x)
-          } else {
-            ds.field("value", &"InvalidFlatbuffer: Union discriminant does not match value.")
-          }
-        },
-        GenericMetaValue::List => {
-          if let Some(x) = self.value_as_list() {
-            ds.field("value", &x)
-          } else {
-            ds.field("value", &"InvalidFlatbuffer: Union discriminant does not match value.")
-          }
-        },
-        GenericMetaValue::KVPair => {
-          if let Some(x) = self.value_as_kvpair() {
-            ds.field("value", &x)
-          } else {
-            ds.field("value", &"InvalidFlatbuffer: Union discriminant does not match value.")
-          }
-        },
-        _ => {
-          let x: Option<()> = None;
-          ds.field("value", &x)
-        },
-      };
+    let mut ds = f.debug_struct("AudioCapabilities");
+      ds.field("volume_step_interval", &self.volume_step_interval());
       ds.finish()
   }
 }
-pub enum MediaItemOffset {}
+pub enum ReceiverCapabilitiesOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct MediaItem<'a> {
+pub struct ReceiverCapabilities<'a> {
   pub _tab: ::flatbuffers::Table<'a>,
 }
 
-impl<'a> ::flatbuffers::Follow<'a> for MediaItem<'a> {
-  type Inner = MediaItem<'a>;
+impl<'a> ::flatbuffers::Follow<'a> for ReceiverCapabilities<'a> {
+  type Inner = ReceiverCapabilities<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: unsafe { ::flatbuffers::Table::new(buf, loc) } }
   }
 }
 
-impl<'a> MediaItem<'a> {
-  pub const VT_CONTAINER: ::flatbuffers::VOffsetT = 4;
-  pub const VT_SOURCE_URL: ::flatbuffers::VOffsetT = 6;
-  pub const VT_START_TIME: ::flatbuffers::VOffsetT = 8;
-  pub const VT_VOLUME: ::flatbuffers::VOffsetT = 10;
-  pub const VT_SPEED: ::flatbuffers::VOffsetT = 12;
-  pub const VT_HEADERS: ::flatbuffers::VOffsetT = 14;
-  pub const VT_TITLE: ::flatbuffers::VOffsetT = 16;
-  pub const VT_THUMBNAIL_URL: ::flatbuffers::VOffsetT = 18;
-  pub const VT_METADATA_TYPE: ::flatbuffers::VOffsetT = 20;
-  pub const VT_METADATA: ::flatbuffers::VOffsetT = 22;
-  pub const VT_EXTRA_METADATA: ::flatbuffers::VOffsetT = 24;
+impl<'a> ReceiverCapabilities<'a> {
+  pub const VT_MEDIA: ::flatbuffers::VOffsetT = 4;
+  pub const VT_DISPLAY: ::flatbuffers::VOffsetT = 6;
+  pub const VT_AUDIO: ::flatbuffers::VOffsetT = 8;
 
   #[inline]
   pub unsafe fn init_from_table(table: ::flatbuffers::Table<'a>) -> Self {
-    MediaItem { _tab: table }
+    ReceiverCapabilities { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: ::flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut ::flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args MediaItemArgs<'args>
-  ) -> ::flatbuffers::WIPOffset<MediaItem<'bldr>> {
-    let mut builder = MediaItemBuilder::new(_fbb);
-    if let Some(x) = args.extra_metadata { builder.add_extra_metadata(x); }
-    if let Some(x) = args.metadata { builder.add_metadata(x); }
-    if let Some(x) = args.thumbnail_url { builder.add_thumbnail_url(x); }
-    if let Some(x) = args.title { builder.add_title(x); }
-    if let Some(x) = args.headers { builder.add_headers(x); }
-    if let Some(x) = args.speed { builder.add_speed(x); }
-    if let Some(x) = args.volume { builder.add_volume(x); }
-    if let Some(x) = args.start_time { builder.add_start_time(x); }
-    if let Some(x) = args.source_url { builder.add_source_url(x); }
-    if let Some(x) = args.container { builder.add_container(x); }
-    builder.add_metadata_type(args.metadata_type);
+    args: &'args ReceiverCapabilitiesArgs<'args>
+  ) -> ::flatbuffers::WIPOffset<ReceiverCapabilities<'bldr>> {
+    let mut builder = ReceiverCapabilitiesBuilder::new(_fbb);
+    if let Some(x) = args.audio { builder.add_audio(x); }
+    if let Some(x) = args.display { builder.add_display(x); }
+    if let Some(x) = args.media { builder.add_media(x); }
     builder.finish()
   }
 
 
   #[inline]
-  pub fn container(&self) -> &'a str {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<&str>>(MediaItem::VT_CONTAINER, None).unwrap()}
-  }
-  #[inline]
-  pub fn source_url(&self) -> &'a str {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<&str>>(MediaItem::VT_SOURCE_URL, None).unwrap()}
-  }
-  #[inline]
-  pub fn start_time(&self) -> Option<&'a Time> {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<Time>(MediaItem::VT_START_TIME, None)}
-  }
-  #[inline]
-  pub fn volume(&self) -> Option<f32> {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<f32>(MediaItem::VT_VOLUME, None)}
-  }
-  #[inline]
-  pub fn speed(&self) -> Option<f32> {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<f32>(MediaItem::VT_SPEED, None)}
-  }
-  #[inline]
-  pub fn headers(&self) -> Option<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<RequestHeader<'a>>>> {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<RequestHeader>>>>(MediaItem::VT_HEADERS, None)}
-  }
-  #[inline]
-  pub fn title(&self) -> Option<&'a str> {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<&str>>(MediaItem::VT_TITLE, None)}
-  }
-  #[inline]
-  pub fn thumbnail_url(&self) -> Option<&'a str> {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<&str>>(MediaItem::VT_THUMBNAIL_URL, None)}
-  }
-  #[inline]
-  pub fn metadata_type(&self) -> Metadata {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<Metadata>(MediaItem::VT_METADATA_TYPE, Some(Metadata::NONE)).unwrap()}
-  }
-  #[inline]
-  pub fn metadata(&self) -> Option<::flatbuffers::Table<'a>> {
+  pub fn media(&self) -> Option<MediaCapabilities<'a>> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<::flatbuffers::Table<'a>>>(MediaItem::VT_METADATA, None)}
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<MediaCapabilities>>(ReceiverCapabilities::VT_MEDIA, None)}
   }
   #[inline]
-  pub fn extra_metadata(&self) -> Option<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<MetadataKV<'a>>>> {
+  pub fn display(&self) -> Option<DisplayCapabilities<'a>> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<MetadataKV>>>>(MediaItem::VT_EXTRA_METADATA, None)}
-  }
-  #[inline]
-  #[allow(non_snake_case)]
-  pub fn metadata_as_video(&self) -> Option<VideoMetadata<'a>> {
-    if self.metadata_type() == Metadata::Video {
-      self.metadata().map(|t| {
-       // Safety:
-       // Created from a valid Table for this object
-       // Which contains a valid union in this slot
-       unsafe { VideoMetadata::init_from_table(t) }
-     })
-    } else {
-      None
-    }
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<DisplayCapabilities>>(ReceiverCapabilities::VT_DISPLAY, None)}
   }
-
   #[inline]
-  #[allow(non_snake_case)]
-  pub fn metadata_as_audio(&self) -> Option<AudioMetadata<'a>> {
-    if self.metadata_type() == Metadata::Audio {
-      self.metadata().map(|t| {
-       // Safety:
-       // Created from a valid Table for this object
-       // Which contains a valid union in this slot
-       unsafe { AudioMetadata::init_from_table(t) }
-     })
-    } else {
-      None
-    }
+  pub fn audio(&self) -> Option<AudioCapabilities<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<AudioCapabilities>>(ReceiverCapabilities::VT_AUDIO, None)}
   }
-
 }
 
-impl ::flatbuffers::Verifiable for MediaItem<'_> {
+impl ::flatbuffers::Verifiable for ReceiverCapabilities<'_> {
   #[inline]
   fn run_verifier(
     v: &mut ::flatbuffers::Verifier, pos: usize
   ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
     v.visit_table(pos)?
-     .visit_field::<::flatbuffers::ForwardsUOffset<&str>>("container", Self::VT_CONTAINER, true)?
-     .visit_field::<::flatbuffers::ForwardsUOffset<&str>>("source_url", Self::VT_SOURCE_URL, true)?
-     .visit_field::<Time>("start_time", Self::VT_START_TIME, false)?
-     .visit_field::<f32>("volume", Self::VT_VOLUME, false)?
-     .visit_field::<f32>("speed", Self::VT_SPEED, false)?
-     .visit_field::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'_, ::flatbuffers::ForwardsUOffset<RequestHeader>>>>("headers", Self::VT_HEADERS, false)?
-     .visit_field::<::flatbuffers::ForwardsUOffset<&str>>("title", Self::VT_TITLE, false)?
-     .visit_field::<::flatbuffers::ForwardsUOffset<&str>>("thumbnail_url", Self::VT_THUMBNAIL_URL, false)?
-     .visit_union::<Metadata, _>("metadata_type", Self::VT_METADATA_TYPE, "metadata", Self::VT_METADATA, false, |key, v, pos| {
-        match key {
-          Metadata::Video => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<VideoMetadata>>("Metadata::Video", pos),
-          Metadata::Audio => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<AudioMetadata>>("Metadata::Audio", pos),
-          _ => Ok(()),
-        }
-     })?
-     .visit_field::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'_, ::flatbuffers::ForwardsUOffset<MetadataKV>>>>("extra_metadata", Self::VT_EXTRA_METADATA, false)?
+     .visit_field::<::flatbuffers::ForwardsUOffset<MediaCapabilities>>("media", Self::VT_MEDIA, false)?
+     .visit_field::<::flatbuffers::ForwardsUOffset<DisplayCapabilities>>("display", Self::VT_DISPLAY, false)?
+     .visit_field::<::flatbuffers::ForwardsUOffset<AudioCapabilities>>("audio", Self::VT_AUDIO, false)?
      .finish();
     Ok(())
   }
 }
-pub struct MediaItemArgs<'a> {
-    pub container: Option<::flatbuffers::WIPOffset<&'a str>>,
-    pub source_url: Option<::flatbuffers::WIPOffset<&'a str>>,
-    pub start_time: Option<&'a Time>,
-    pub volume: Option<f32>,
-    pub speed: Option<f32>,
-    pub headers: Option<::flatbuffers::WIPOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<RequestHeader<'a>>>>>,
-    pub title: Option<::flatbuffers::WIPOffset<&'a str>>,
-    pub thumbnail_url: Option<::flatbuffers::WIPOffset<&'a str>>,
-    pub metadata_type: Metadata,
-    pub metadata: Option<::flatbuffers::WIPOffset<::flatbuffers::UnionWIPOffset>>,
-    pub extra_metadata: Option<::flatbuffers::WIPOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<MetadataKV<'a>>>>>,
+pub struct ReceiverCapabilitiesArgs<'a> {
+    pub media: Option<::flatbuffers::WIPOffset<MediaCapabilities<'a>>>,
+    pub display: Option<::flatbuffers::WIPOffset<DisplayCapabilities<'a>>>,
+    pub audio: Option<::flatbuffers::WIPOffset<AudioCapabilities<'a>>>,
 }
-impl<'a> Default for MediaItemArgs<'a> {
+impl<'a> Default for ReceiverCapabilitiesArgs<'a> {
   #[inline]
   fn default() -> Self {
-    MediaItemArgs {
-      container: None, // required field
-      source_url: None, // required field
-      start_time: None,
-      volume: None,
-      speed: None,
-      headers: None,
-      title: None,
-      thumbnail_url: None,
-      metadata_type: Metadata::NONE,
-      metadata: None,
-      extra_metadata: None,
+    ReceiverCapabilitiesArgs {
+      media: None,
+      display: None,
+      audio: None,
     }
   }
 }
 
-pub struct MediaItemBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
+pub struct ReceiverCapabilitiesBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
   fbb_: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>,
   start_: ::flatbuffers::WIPOffset<::flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> MediaItemBuilder<'a, 'b, A> {
-  #[inline]
-  pub fn add_container(&mut self, container: ::flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(MediaItem::VT_CONTAINER, container);
-  }
-  #[inline]
-  pub fn add_source_url(&mut self, source_url: ::flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(MediaItem::VT_SOURCE_URL, source_url);
-  }
-  #[inline]
-  pub fn add_start_time(&mut self, start_time: &Time) {
-    self.fbb_.push_slot_always::<&Time>(MediaItem::VT_START_TIME, start_time);
-  }
-  #[inline]
-  pub fn add_volume(&mut self, volume: f32) {
-    self.fbb_.push_slot_always::<f32>(MediaItem::VT_VOLUME, volume);
-  }
-  #[inline]
-  pub fn add_speed(&mut self, speed: f32) {
-    self.fbb_.push_slot_always::<f32>(MediaItem::VT_SPEED, speed);
-  }
-  #[inline]
-  pub fn add_headers(&mut self, headers: ::flatbuffers::WIPOffset<::flatbuffers::Vector<'b , ::flatbuffers::ForwardsUOffset<RequestHeader<'b >>>>) {
-    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(MediaItem::VT_HEADERS, headers);
-  }
-  #[inline]
-  pub fn add_title(&mut self, title: ::flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(MediaItem::VT_TITLE, title);
-  }
-  #[inline]
-  pub fn add_thumbnail_url(&mut self, thumbnail_url: ::flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(MediaItem::VT_THUMBNAIL_URL, thumbnail_url);
-  }
+impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> ReceiverCapabilitiesBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_metadata_type(&mut self, metadata_type: Metadata) {
-    self.fbb_.push_slot::<Metadata>(MediaItem::VT_METADATA_TYPE, metadata_type, Metadata::NONE);
+  pub fn add_media(&mut self, media: ::flatbuffers::WIPOffset<MediaCapabilities<'b >>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<MediaCapabilities>>(ReceiverCapabilities::VT_MEDIA, media);
   }
   #[inline]
-  pub fn add_metadata(&mut self, metadata: ::flatbuffers::WIPOffset<::flatbuffers::UnionWIPOffset>) {
-    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(MediaItem::VT_METADATA, metadata);
+  pub fn add_display(&mut self, display: ::flatbuffers::WIPOffset<DisplayCapabilities<'b >>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<DisplayCapabilities>>(ReceiverCapabilities::VT_DISPLAY, display);
   }
   #[inline]
-  pub fn add_extra_metadata(&mut self, extra_metadata: ::flatbuffers::WIPOffset<::flatbuffers::Vector<'b , ::flatbuffers::ForwardsUOffset<MetadataKV<'b >>>>) {
-    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(MediaItem::VT_EXTRA_METADATA, extra_metadata);
+  pub fn add_audio(&mut self, audio: ::flatbuffers::WIPOffset<AudioCapabilities<'b >>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<AudioCapabilities>>(ReceiverCapabilities::VT_AUDIO, audio);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> MediaItemBuilder<'a, 'b, A> {
+  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> ReceiverCapabilitiesBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    MediaItemBuilder {
+    ReceiverCapabilitiesBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> ::flatbuffers::WIPOffset<MediaItem<'a>> {
+  pub fn finish(self) -> ::flatbuffers::WIPOffset<ReceiverCapabilities<'a>> {
     let o = self.fbb_.end_table(self.start_);
-    self.fbb_.required(o, MediaItem::VT_CONTAINER,"container");
-    self.fbb_.required(o, MediaItem::VT_SOURCE_URL,"source_url");
     ::flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl ::core::fmt::Debug for MediaItem<'_> {
+impl ::core::fmt::Debug for ReceiverCapabilities<'_> {
   fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-    let mut ds = f.debug_struct("MediaItem");
-      ds.field("container", &self.container());
-      ds.field("source_url", &self.source_url());
-      ds.field("start_time", &self.start_time());
-      ds.field("volume", &self.volume());
-      ds.field("speed", &self.speed());
-      ds.field("headers", &self.headers());
-      ds.field("title", &self.title());
-      ds.field("thumbnail_url", &self.thumbnail_url());
-      ds.field("metadata_type", &self.metadata_type());
-      match self.metadata_type() {
-        Metadata::Video => {
-          if let Some(x) = self.metadata_as_video() {
-            ds.field("metadata", &x)
-          } else {
-            ds.field("metadata", &"InvalidFlatbuffer: Union discriminant does not match value.")
-          }
-        },
-        Metadata::Audio => {
-          if let Some(x) = self.metadata_as_audio() {
-            ds.field("metadata", &x)
-          } else {
-            ds.field("metadata", &"InvalidFlatbuffer: Union discriminant does not match value.")
-          }
-        },
-        _ => {
-          let x: Option<()> = None;
-          ds.field("metadata", &x)
-        },
-      };
-      ds.field("extra_metadata", &self.extra_metadata());
+    let mut ds = f.debug_struct("ReceiverCapabilities");
+      ds.field("media", &self.media());
+      ds.field("display", &self.display());
+      ds.field("audio", &self.audio());
       ds.finish()
   }
 }
-pub enum QueueItemOffset {}
+pub enum MediaTrackOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct QueueItem<'a> {
+pub struct MediaTrack<'a> {
   pub _tab: ::flatbuffers::Table<'a>,
 }
 
-impl<'a> ::flatbuffers::Follow<'a> for QueueItem<'a> {
-  type Inner = QueueItem<'a>;
+impl<'a> ::flatbuffers::Follow<'a> for MediaTrack<'a> {
+  type Inner = MediaTrack<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: unsafe { ::flatbuffers::Table::new(buf, loc) } }
   }
 }
 
-impl<'a> QueueItem<'a> {
-  pub const VT_MEDIA_ITEM: ::flatbuffers::VOffsetT = 4;
-  pub const VT_PLAYBACK_DURATION: ::flatbuffers::VOffsetT = 6;
+impl<'a> MediaTrack<'a> {
+  pub const VT_ID: ::flatbuffers::VOffsetT = 4;
+  pub const VT_ISO_639: ::flatbuffers::VOffsetT = 6;
+  pub const VT_TITLE: ::flatbuffers::VOffsetT = 8;
+  pub const VT_METADATA_TYPE: ::flatbuffers::VOffsetT = 10;
+  pub const VT_METADATA: ::flatbuffers::VOffsetT = 12;
 
   #[inline]
   pub unsafe fn init_from_table(table: ::flatbuffers::Table<'a>) -> Self {
-    QueueItem { _tab: table }
+    MediaTrack { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: ::flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut ::flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args QueueItemArgs<'args>
-  ) -> ::flatbuffers::WIPOffset<QueueItem<'bldr>> {
-    let mut builder = QueueItemBuilder::new(_fbb);
-    if let Some(x) = args.playback_duration { builder.add_playback_duration(x); }
-    if let Some(x) = args.media_item { builder.add_media_item(x); }
+    args: &'args MediaTrackArgs<'args>
+  ) -> ::flatbuffers::WIPOffset<MediaTrack<'bldr>> {
+    let mut builder = MediaTrackBuilder::new(_fbb);
+    if let Some(x) = args.metadata { builder.add_metadata(x); }
+    if let Some(x) = args.title { builder.add_title(x); }
+    if let Some(x) = args.iso_639 { builder.add_iso_639(x); }
+    builder.add_id(args.id);
+    builder.add_metadata_type(args.metadata_type);
     builder.finish()
   }
 
 
   #[inline]
-  pub fn media_item(&self) -> MediaItem<'a> {
+  pub fn id(&self) -> u32 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<MediaItem>>(QueueItem::VT_MEDIA_ITEM, None).unwrap()}
+    unsafe { self._tab.get::<u32>(MediaTrack::VT_ID, Some(0)).unwrap()}
   }
   #[inline]
-  pub fn playback_duration(&self) -> Option<&'a Time> {
+  pub fn iso_639(&self) -> &'a str {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<Time>(QueueItem::VT_PLAYBACK_DURATION, None)}
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<&str>>(MediaTrack::VT_ISO_639, None).unwrap()}
+  }
+  #[inline]
+  pub fn title(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<&str>>(MediaTrack::VT_TITLE, None)}
+  }
+  #[inline]
+  pub fn metadata_type(&self) -> MediaTrackMetadata {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<MediaTrackMetadata>(MediaTrack::VT_METADATA_TYPE, Some(MediaTrackMetadata::NONE)).unwrap()}
+  }
+  #[inline]
+  pub fn metadata(&self) -> Option<::flatbuffers::Table<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<::flatbuffers::Table<'a>>>(MediaTrack::VT_METADATA, None)}
+  }
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn metadata_as_video(&self) -> Option<VideoTrackMeta<'a>> {
+    if self.metadata_type() == MediaTrackMetadata::Video {
+      self.metadata().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { VideoTrackMeta::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn metadata_as_audio(&self) -> Option<AudioTrackMeta<'a>> {
+    if self.metadata_type() == MediaTrackMetadata::Audio {
+      self.metadata().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { AudioTrackMeta::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn metadata_as_subtitle(&self) -> Option<SubtitleTrackMeta<'a>> {
+    if self.metadata_type() == MediaTrackMetadata::Subtitle {
+      self.metadata().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { SubtitleTrackMeta::init_from_table(t) }
+     })
+    } else {
+      None
+    }
   }
+
 }
 
-impl ::flatbuffers::Verifiable for QueueItem<'_> {
+impl ::flatbuffers::Verifiable for MediaTrack<'_> {
   #[inline]
   fn run_verifier(
     v: &mut ::flatbuffers::Verifier, pos: usize
   ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
     v.visit_table(pos)?
-     .visit_field::<::flatbuffers::ForwardsUOffset<MediaItem>>("media_item", Self::VT_MEDIA_ITEM, true)?
-     .visit_field::<Time>("playback_duration", Self::VT_PLAYBACK_DURATION, false)?
+     .visit_field::<u32>("id", Self::VT_ID, false)?
+     .visit_field::<::flatbuffers::ForwardsUOffset<&str>>("iso_639", Self::VT_ISO_639, true)?
+     .visit_field::<::flatbuffers::ForwardsUOffset<&str>>("title", Self::VT_TITLE, false)?
+     .visit_union::<MediaTrackMetadata, _>("metadata_type", Self::VT_METADATA_TYPE, "metadata", Self::VT_METADATA, false, |key, v, pos| {
+        match key {
+          MediaTrackMetadata::Video => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<VideoTrackMeta>>("MediaTrackMetadata::Video", pos),
+          MediaTrackMetadata::Audio => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<AudioTrackMeta>>("MediaTrackMetadata::Audio", pos),
+          MediaTrackMetadata::Subtitle => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<SubtitleTrackMeta>>("MediaTrackMetadata::Subtitle", pos),
+          _ => Ok(()),
+        }
+     })?
      .finish();
     Ok(())
   }
 }
-pub struct QueueItemArgs<'a> {
-    pub media_item: Option<::flatbuffers::WIPOffset<MediaItem<'a>>>,
-    pub playback_duration: Option<&'a Time>,
+pub struct MediaTrackArgs<'a> {
+    pub id: u32,
+    pub iso_639: Option<::flatbuffers::WIPOffset<&'a str>>,
+    pub title: Option<::flatbuffers::WIPOffset<&'a str>>,
+    pub metadata_type: MediaTrackMetadata,
+    pub metadata: Option<::flatbuffers::WIPOffset<::flatbuffers::UnionWIPOffset>>,
 }
-impl<'a> Default for QueueItemArgs<'a> {
+impl<'a> Default for MediaTrackArgs<'a> {
   #[inline]
   fn default() -> Self {
-    QueueItemArgs {
-      media_item: None, // required field
-      playback_duration: None,
+    MediaTrackArgs {
+      id: 0,
+      iso_639: None, // required field
+      title: None,
+      metadata_type: MediaTrackMetadata::NONE,
+      metadata: None,
     }
   }
 }
 
-pub struct QueueItemBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
+pub struct MediaTrackBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
   fbb_: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>,
   start_: ::flatbuffers::WIPOffset<::flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> QueueItemBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> MediaTrackBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_media_item(&mut self, media_item: ::flatbuffers::WIPOffset<MediaItem<'b >>) {
-    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<MediaItem>>(QueueItem::VT_MEDIA_ITEM, media_item);
+  pub fn add_id(&mut self, id: u32) {
+    self.fbb_.push_slot::<u32>(MediaTrack::VT_ID, id, 0);
   }
   #[inline]
-  pub fn add_playback_duration(&mut self, playback_duration: &Time) {
-    self.fbb_.push_slot_always::<&Time>(QueueItem::VT_PLAYBACK_DURATION, playback_duration);
+  pub fn add_iso_639(&mut self, iso_639: ::flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(MediaTrack::VT_ISO_639, iso_639);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> QueueItemBuilder<'a, 'b, A> {
+  pub fn add_title(&mut self, title: ::flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(MediaTrack::VT_TITLE, title);
+  }
+  #[inline]
+  pub fn add_metadata_type(&mut self, metadata_type: MediaTrackMetadata) {
+    self.fbb_.push_slot::<MediaTrackMetadata>(MediaTrack::VT_METADATA_TYPE, metadata_type, MediaTrackMetadata::NONE);
+  }
+  #[inline]
+  pub fn add_metadata(&mut self, metadata: ::flatbuffers::WIPOffset<::flatbuffers::UnionWIPOffset>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(MediaTrack::VT_METADATA, metadata);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> MediaTrackBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    QueueItemBuilder {
+    MediaTrackBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> ::flatbuffers::WIPOffset<QueueItem<'a>> {
+  pub fn finish(self) -> ::flatbuffers::WIPOffset<MediaTrack<'a>> {
     let o = self.fbb_.end_table(self.start_);
-    self.fbb_.required(o, QueueItem::VT_MEDIA_ITEM,"media_item");
+    self.fbb_.required(o, MediaTrack::VT_ISO_639,"iso_639");
     ::flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl ::core::fmt::Debug for QueueItem<'_> {
+impl ::core::fmt::Debug for MediaTrack<'_> {
   fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-    let mut ds = f.debug_struct("QueueItem");
-      ds.field("media_item", &self.media_item());
-      ds.field("playback_duration", &self.playback_duration());
+    let mut ds = f.debug_struct("MediaTrack");
+      ds.field("id", &self.id());
+      ds.field("iso_639", &self.iso_639());
+      ds.field("title", &self.title());
+      ds.field("metadata_type", &self.metadata_type());
+      match self.metadata_type() {
+        MediaTrackMetadata::Video => {
+          if let Some(x) = self.metadata_as_video() {
+            ds.field("metadata", &x)
+          } else {
+            ds.field("metadata", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        MediaTrackMetadata::Audio => {
+          if let Some(x) = self.metadata_as_audio() {
+            ds.field("metadata", &x)
+          } else {
+            ds.field("metadata", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        MediaTrackMetadata::Subtitle => {
+          if let Some(x) = self.metadata_as_subtitle() {
+            ds.field("metadata", &x)
+          } else {
+            ds.field("metadata", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        _ => {
+          let x: Option<()> = None;
+          ds.field("metadata", &x)
+        },
+      };
       ds.finish()
   }
 }
-pub enum QueueOffset {}
+pub enum ProgressChangedOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct Queue<'a> {
+pub struct ProgressChanged<'a> {
   pub _tab: ::flatbuffers::Table<'a>,
 }
 
-impl<'a> ::flatbuffers::Follow<'a> for Queue<'a> {
-  type Inner = Queue<'a>;
+impl<'a> ::flatbuffers::Follow<'a> for ProgressChanged<'a> {
+  type Inner = ProgressChanged<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: unsafe { ::flatbuffers::Table::new(buf, loc) } }
   }
 }
 
-impl<'a> Queue<'a> {
-  pub const VT_ITEMS: ::flatbuffers::VOffsetT = 4;
-  pub const VT_START_INDEX: ::flatbuffers::VOffsetT = 6;
-  pub const VT_AUTOPLAY: ::flatbuffers::VOffsetT = 8;
+impl<'a> ProgressChanged<'a> {
+  pub const VT_POSITION: ::flatbuffers::VOffsetT = 4;
+  pub const VT_DURATION: ::flatbuffers::VOffsetT = 6;
 
   #[inline]
   pub unsafe fn init_from_table(table: ::flatbuffers::Table<'a>) -> Self {
-    Queue { _tab: table }
+    ProgressChanged { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: ::flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut ::flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args QueueArgs<'args>
-  ) -> ::flatbuffers::WIPOffset<Queue<'bldr>> {
-    let mut builder = QueueBuilder::new(_fbb);
-    if let Some(x) = args.items { builder.add_items(x); }
-    builder.add_autoplay(args.autoplay);
-    if let Some(x) = args.start_index { builder.add_start_index(x); }
+    args: &'args ProgressChangedArgs<'args>
+  ) -> ::flatbuffers::WIPOffset<ProgressChanged<'bldr>> {
+    let mut builder = ProgressChangedBuilder::new(_fbb);
+    if let Some(x) = args.duration { builder.add_duration(x); }
+    if let Some(x) = args.position { builder.add_position(x); }
     builder.finish()
   }
 
 
   #[inline]
-  pub fn items(&self) -> ::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<QueueItem<'a>>> {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<QueueItem>>>>(Queue::VT_ITEMS, None).unwrap()}
-  }
-  #[inline]
-  pub fn start_index(&self) -> Option<u8> {
+  pub fn position(&self) -> Option<&'a Time> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<u8>(Queue::VT_START_INDEX, None)}
+    unsafe { self._tab.get::<Time>(ProgressChanged::VT_POSITION, None)}
   }
   #[inline]
-  pub fn autoplay(&self) -> bool {
+  pub fn duration(&self) -> Option<&'a Time> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<bool>(Queue::VT_AUTOPLAY, Some(false)).unwrap()}
+    unsafe { self._tab.get::<Time>(ProgressChanged::VT_DURATION, None)}
   }
 }
 
-impl ::flatbuffers::Verifiable for Queue<'_> {
+impl ::flatbuffers::Verifiable for ProgressChanged<'_> {
   #[inline]
   fn run_verifier(
     v: &mut ::flatbuffers::Verifier, pos: usize
   ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
     v.visit_table(pos)?
-     .visit_field::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'_, ::flatbuffers::ForwardsUOffset<QueueItem>>>>("items", Self::VT_ITEMS, true)?
-     .visit_field::<u8>("start_index", Self::VT_START_INDEX, false)?
-     .visit_field::<bool>("autoplay", Self::VT_AUTOPLAY, false)?
+     .visit_field::<Time>("position", Self::VT_POSITION, false)?
+     .visit_field::<Time>("duration", Self::VT_DURATION, false)?
      .finish();
     Ok(())
   }
 }
-pub struct QueueArgs<'a> {
-    pub items: Option<::flatbuffers::WIPOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<QueueItem<'a>>>>>,
-    pub start_index: Option<u8>,
-    pub autoplay: bool,
+pub struct ProgressChangedArgs<'a> {
+    pub position: Option<&'a Time>,
+    pub duration: Option<&'a Time>,
 }
-impl<'a> Default for QueueArgs<'a> {
+impl<'a> Default for ProgressChangedArgs<'a> {
   #[inline]
   fn default() -> Self {
-    QueueArgs {
-      items: None, // required field
-      start_index: None,
-      autoplay: false,
+    ProgressChangedArgs {
+      position: None,
+      duration: None,
     }
   }
 }
 
-pub struct QueueBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
+pub struct ProgressChangedBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
   fbb_: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>,
   start_: ::flatbuffers::WIPOffset<::flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> QueueBuilder<'a, 'b, A> {
-  #[inline]
-  pub fn add_items(&mut self, items: ::flatbuffers::WIPOffset<::flatbuffers::Vector<'b , ::flatbuffers::ForwardsUOffset<QueueItem<'b >>>>) {
-    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(Queue::VT_ITEMS, items);
-  }
+impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> ProgressChangedBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_start_index(&mut self, start_index: u8) {
-    self.fbb_.push_slot_always::<u8>(Queue::VT_START_INDEX, start_index);
+  pub fn add_position(&mut self, position: &Time) {
+    self.fbb_.push_slot_always::<&Time>(ProgressChanged::VT_POSITION, position);
   }
   #[inline]
-  pub fn add_autoplay(&mut self, autoplay: bool) {
-    self.fbb_.push_slot::<bool>(Queue::VT_AUTOPLAY, autoplay, false);
+  pub fn add_duration(&mut self, duration: &Time) {
+    self.fbb_.push_slot_always::<&Time>(ProgressChanged::VT_DURATION, duration);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> QueueBuilder<'a, 'b, A> {
+  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> ProgressChangedBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    QueueBuilder {
+    ProgressChangedBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> ::flatbuffers::WIPOffset<Queue<'a>> {
+  pub fn finish(self) -> ::flatbuffers::WIPOffset<ProgressChanged<'a>> {
     let o = self.fbb_.end_table(self.start_);
-    self.fbb_.required(o, Queue::VT_ITEMS,"items");
     ::flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl ::core::fmt::Debug for Queue<'_> {
+impl ::core::fmt::Debug for ProgressChanged<'_> {
   fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-    let mut ds = f.debug_struct("Queue");
-      ds.field("items", &self.items());
-      ds.field("start_index", &self.start_index());
-      ds.field("autoplay", &self.autoplay());
+    let mut ds = f.debug_struct("ProgressChanged");
+      ds.field("position", &self.position());
+      ds.field("duration", &self.duration());
       ds.finish()
   }
 }
-pub enum QueueIndexOffset {}
+pub enum LoadOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct QueueIndex<'a> {
+pub struct Load<'a> {
   pub _tab: ::flatbuffers::Table<'a>,
 }
 
-impl<'a> ::flatbuffers::Follow<'a> for QueueIndex<'a> {
-  type Inner = QueueIndex<'a>;
+impl<'a> ::flatbuffers::Follow<'a> for Load<'a> {
+  type Inner = Load<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: unsafe { ::flatbuffers::Table::new(buf, loc) } }
   }
 }
 
-impl<'a> QueueIndex<'a> {
-  pub const VT_INDEX: ::flatbuffers::VOffsetT = 4;
+impl<'a> Load<'a> {
+  pub const VT_SOURCE_TYPE: ::flatbuffers::VOffsetT = 4;
+  pub const VT_SOURCE: ::flatbuffers::VOffsetT = 6;
 
   #[inline]
   pub unsafe fn init_from_table(table: ::flatbuffers::Table<'a>) -> Self {
-    QueueIndex { _tab: table }
+    Load { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: ::flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut ::flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args QueueIndexArgs
-  ) -> ::flatbuffers::WIPOffset<QueueIndex<'bldr>> {
-    let mut builder = QueueIndexBuilder::new(_fbb);
-    builder.add_index(args.index);
+    args: &'args LoadArgs
+  ) -> ::flatbuffers::WIPOffset<Load<'bldr>> {
+    let mut builder = LoadBuilder::new(_fbb);
+    if let Some(x) = args.source { builder.add_source(x); }
+    builder.add_source_type(args.source_type);
     builder.finish()
   }
 
-
+
+  #[inline]
+  pub fn source_type(&self) -> MediaSource {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<MediaSource>(Load::VT_SOURCE_TYPE, Some(MediaSource::NONE)).unwrap()}
+  }
+  #[inline]
+  pub fn source(&self) -> ::flatbuffers::Table<'a> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<::flatbuffers::Table<'a>>>(Load::VT_SOURCE, None).unwrap()}
+  }
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn source_as_single(&self) -> Option<MediaItem<'a>> {
+    if self.source_type() == MediaSource::Single {
+      let u = self.source();
+      // Safety:
+      // Created from a valid Table for this object
+      // Which contains a valid union in this slot
+      Some(unsafe { MediaItem::init_from_table(u) })
+    } else {
+      None
+    }
+  }
+
   #[inline]
-  pub fn index(&self) -> u8 {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<u8>(QueueIndex::VT_INDEX, Some(0)).unwrap()}
+  #[allow(non_snake_case)]
+  pub fn source_as_queue(&self) -> Option<Queue<'a>> {
+    if self.source_type() == MediaSource::Queue {
+      let u = self.source();
+      // Safety:
+      // Created from a valid Table for this object
+      // Which contains a valid union in this slot
+      Some(unsafe { Queue::init_from_table(u) })
+    } else {
+      None
+    }
   }
+
 }
 
-impl ::flatbuffers::Verifiable for QueueIndex<'_> {
+impl ::flatbuffers::Verifiable for Load<'_> {
   #[inline]
   fn run_verifier(
     v: &mut ::flatbuffers::Verifier, pos: usize
   ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
     v.visit_table(pos)?
-     .visit_field::<u8>("index", Self::VT_INDEX, false)?
+     .visit_union::<MediaSource, _>("source_type", Self::VT_SOURCE_TYPE, "source", Self::VT_SOURCE, true, |key, v, pos| {
+        match key {
+          MediaSource::Single => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<MediaItem>>("MediaSource::Single", pos),
+          MediaSource::Queue => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<Queue>>("MediaSource::Queue", pos),
+          _ => Ok(()),
+        }
+     })?
      .finish();
     Ok(())
   }
 }
-pub struct QueueIndexArgs {
-    pub index: u8,
+pub struct LoadArgs {
+    pub source_type: MediaSource,
+    pub source: Option<::flatbuffers::WIPOffset<::flatbuffers::UnionWIPOffset>>,
 }
-impl<'a> Default for QueueIndexArgs {
+impl<'a> Default for LoadArgs {
   #[inline]
   fn default() -> Self {
-    QueueIndexArgs {
-      index: 0,
+    LoadArgs {
+      source_type: MediaSource::NONE,
+      source: None, // required field
     }
   }
 }
 
-pub struct QueueIndexBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
+pub struct LoadBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
   fbb_: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>,
   start_: ::flatbuffers::WIPOffset<::flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> QueueIndexBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> LoadBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_index(&mut self, index: u8) {
-    self.fbb_.push_slot::<u8>(QueueIndex::VT_INDEX, index, 0);
+  pub fn add_source_type(&mut self, source_type: MediaSource) {
+    self.fbb_.push_slot::<MediaSource>(Load::VT_SOURCE_TYPE, source_type, MediaSource::NONE);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> QueueIndexBuilder<'a, 'b, A> {
+  pub fn add_source(&mut self, source: ::flatbuffers::WIPOffset<::flatbuffers::UnionWIPOffset>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(Load::VT_SOURCE, source);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> LoadBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    QueueIndexBuilder {
+    LoadBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> ::flatbuffers::WIPOffset<QueueIndex<'a>> {
+  pub fn finish(self) -> ::flatbuffers::WIPOffset<Load<'a>> {
     let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, Load::VT_SOURCE,"source");
     ::flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl ::core::fmt::Debug for QueueIndex<'_> {
+impl ::core::fmt::Debug for Load<'_> {
   fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-    let mut ds = f.debug_struct("QueueIndex");
-      ds.field("index", &self.index());
+    let mut ds = f.debug_struct("Load");
+      ds.field("source_type", &self.source_type());
+      match self.source_type() {
+        MediaSource::Single => {
+          if let Some(x) = self.source_as_single() {
+            ds.field("source", &x)
+          } else {
+            ds.field("source", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        MediaSource::Queue => {
+          if let Some(x) = self.source_as_queue() {
+            ds.field("source", &x)
+          } else {
+            ds.field("source", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        _ => {
+          let x: Option<()> = None;
+          ds.field("source", &x)
+        },
+      };
       ds.finish()
   }
 }
-pub enum QueueMarkerFrontOffset {}
+pub enum QueueInsertOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct QueueMarkerFront<'a> {
+pub struct QueueInsert<'a> {
   pub _tab: ::flatbuffers::Table<'a>,
 }
 
-impl<'a> ::flatbuffers::Follow<'a> for QueueMarkerFront<'a> {
-  type Inner = QueueMarkerFront<'a>;
+impl<'a> ::flatbuffers::Follow<'a> for QueueInsert<'a> {
+  type Inner = QueueInsert<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: unsafe { ::flatbuffers::Table::new(buf, loc) } }
   }
 }
 
-impl<'a> QueueMarkerFront<'a> {
+impl<'a> QueueInsert<'a> {
+  pub const VT_ITEM: ::flatbuffers::VOffsetT = 4;
+  pub const VT_POSITION_TYPE: ::flatbuffers::VOffsetT = 6;
+  pub const VT_POSITION: ::flatbuffers::VOffsetT = 8;
 
   #[inline]
   pub unsafe fn init_from_table(table: ::flatbuffers::Table<'a>) -> Self {
-    QueueMarkerFront { _tab: table }
+    QueueInsert { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: ::flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut ::flatbuffers::FlatBufferBuilder<'bldr, A>,
-    _args: &'args QueueMarkerFrontArgs
-  ) -> ::flatbuffers::WIPOffset<QueueMarkerFront<'bldr>> {
-    let mut builder = QueueMarkerFrontBuilder::new(_fbb);
+    args: &'args QueueInsertArgs<'args>
+  ) -> ::flatbuffers::WIPOffset<QueueInsert<'bldr>> {
+    let mut builder = QueueInsertBuilder::new(_fbb);
+    if let Some(x) = args.position { builder.add_position(x); }
+    if let Some(x) = args.item { builder.add_item(x); }
+    builder.add_position_type(args.position_type);
     builder.finish()
   }
 
+
+  #[inline]
+  pub fn item(&self) -> QueueItem<'a> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<QueueItem>>(QueueInsert::VT_ITEM, None).unwrap()}
+  }
+  #[inline]
+  pub fn position_type(&self) -> QueuePosition {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<QueuePosition>(QueueInsert::VT_POSITION_TYPE, Some(QueuePosition::NONE)).unwrap()}
+  }
+  #[inline]
+  pub fn position(&self) -> ::flatbuffers::Table<'a> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<::flatbuffers::Table<'a>>>(QueueInsert::VT_POSITION, None).unwrap()}
+  }
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn position_as_index(&self) -> Option<QueueIndex<'a>> {
+    if self.position_type() == QueuePosition::Index {
+      let u = self.position();
+      // Safety:
+      // Created from a valid Table for this object
+      // Which contains a valid union in this slot
+      Some(unsafe { QueueIndex::init_from_table(u) })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn position_as_front(&self) -> Option<QueueMarkerFront<'a>> {
+    if self.position_type() == QueuePosition::Front {
+      let u = self.position();
+      // Safety:
+      // Created from a valid Table for this object
+      // Which contains a valid union in this slot
+      Some(unsafe { QueueMarkerFront::init_from_table(u) })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn position_as_back(&self) -> Option<QueueMarkerBack<'a>> {
+    if self.position_type() == QueuePosition::Back {
+      let u = self.position();
+      // Safety:
+      // Created from a valid Table for this object
+      // Which contains a valid union in this slot
+      Some(unsafe { QueueMarkerBack::init_from_table(u) })
+    } else {
+      None
+    }
+  }
+
 }
 
-impl ::flatbuffers::Verifiable for QueueMarkerFront<'_> {
+impl ::flatbuffers::Verifiable for QueueInsert<'_> {
   #[inline]
   fn run_verifier(
     v: &mut ::flatbuffers::Verifier, pos: usize
   ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
     v.visit_table(pos)?
+     .visit_field::<::flatbuffers::ForwardsUOffset<QueueItem>>("item", Self::VT_ITEM, true)?
+     .visit_union::<QueuePosition, _>("position_type", Self::VT_POSITION_TYPE, "position", Self::VT_POSITION, true, |key, v, pos| {
+        match key {
+          QueuePosition::Index => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<QueueIndex>>("QueuePosition::Index", pos),
+          QueuePosition::Front => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<QueueMarkerFront>>("QueuePosition::Front", pos),
+          QueuePosition::Back => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<QueueMarkerBack>>("QueuePosition::Back", pos),
+          _ => Ok(()),
+        }
+     })?
      .finish();
     Ok(())
   }
 }
-pub struct QueueMarkerFrontArgs {
+pub struct QueueInsertArgs<'a> {
+    pub item: Option<::flatbuffers::WIPOffset<QueueItem<'a>>>,
+    pub position_type: QueuePosition,
+    pub position: Option<::flatbuffers::WIPOffset<::flatbuffers::UnionWIPOffset>>,
 }
-impl<'a> Default for QueueMarkerFrontArgs {
+impl<'a> Default for QueueInsertArgs<'a> {
   #[inline]
   fn default() -> Self {
-    QueueMarkerFrontArgs {
+    QueueInsertArgs {
+      item: None, // required field
+      position_type: QueuePosition::NONE,
+      position: None, // required field
     }
   }
 }
 
-pub struct QueueMarkerFrontBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
+pub struct QueueInsertBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
   fbb_: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>,
   start_: ::flatbuffers::WIPOffset<::flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> QueueMarkerFrontBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> QueueInsertBuilder<'a, 'b, A> {
   #[inline]
-  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> QueueMarkerFrontBuilder<'a, 'b, A> {
+  pub fn add_item(&mut self, item: ::flatbuffers::WIPOffset<QueueItem<'b >>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<QueueItem>>(QueueInsert::VT_ITEM, item);
+  }
+  #[inline]
+  pub fn add_position_type(&mut self, position_type: QueuePosition) {
+    self.fbb_.push_slot::<QueuePosition>(QueueInsert::VT_POSITION_TYPE, position_type, QueuePosition::NONE);
+  }
+  #[inline]
+  pub fn add_position(&mut self, position: ::flatbuffers::WIPOffset<::flatbuffers::UnionWIPOffset>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(QueueInsert::VT_POSITION, position);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> QueueInsertBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    QueueMarkerFrontBuilder {
+    QueueInsertBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> ::flatbuffers::WIPOffset<QueueMarkerFront<'a>> {
+  pub fn finish(self) -> ::flatbuffers::WIPOffset<QueueInsert<'a>> {
     let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, QueueInsert::VT_ITEM,"item");
+    self.fbb_.required(o, QueueInsert::VT_POSITION,"position");
     ::flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl ::core::fmt::Debug for QueueMarkerFront<'_> {
+impl ::core::fmt::Debug for QueueInsert<'_> {
   fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-    let mut ds = f.debug_struct("QueueMarkerFront");
+    let mut ds = f.debug_struct("QueueInsert");
+      ds.field("item", &self.item());
+      ds.field("position_type", &self.position_type());
+      match self.position_type() {
+        QueuePosition::Index => {
+          if let Some(x) = self.position_as_index() {
+            ds.field("position", &x)
+          } else {
+            ds.field("position", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        QueuePosition::Front => {
+          if let Some(x) = self.position_as_front() {
+            ds.field("position", &x)
+          } else {
+            ds.field("position", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        QueuePosition::Back => {
+          if let Some(x) = self.position_as_back() {
+            ds.field("position", &x)
+          } else {
+            ds.field("position", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        _ => {
+          let x: Option<()> = None;
+          ds.field("position", &x)
+        },
+      };
       ds.finish()
   }
 }
-pub enum QueueMarkerBackOffset {}
+pub enum QueueRemoveOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct QueueMarkerBack<'a> {
+pub struct QueueRemove<'a> {
   pub _tab: ::flatbuffers::Table<'a>,
 }
 
-impl<'a> ::flatbuffers::Follow<'a> for QueueMarkerBack<'a> {
-  type Inner = QueueMarkerBack<'a>;
+impl<'a> ::flatbuffers::Follow<'a> for QueueRemove<'a> {
+  type Inner = QueueRemove<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: unsafe { ::flatbuffers::Table::new(buf, loc) } }
   }
 }
 
-impl<'a> QueueMarkerBack<'a> {
+impl<'a> QueueRemove<'a> {
+  pub const VT_POSITION_TYPE: ::flatbuffers::VOffsetT = 4;
+  pub const VT_POSITION: ::flatbuffers::VOffsetT = 6;
 
   #[inline]
   pub unsafe fn init_from_table(table: ::flatbuffers::Table<'a>) -> Self {
-    QueueMarkerBack { _tab: table }
+    QueueRemove { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: ::flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut ::flatbuffers::FlatBufferBuilder<'bldr, A>,
-    _args: &'args QueueMarkerBackArgs
-  ) -> ::flatbuffers::WIPOffset<QueueMarkerBack<'bldr>> {
-    let mut builder = QueueMarkerBackBuilder::new(_fbb);
+    args: &'args QueueRemoveArgs
+  ) -> ::flatbuffers::WIPOffset<QueueRemove<'bldr>> {
+    let mut builder = QueueRemoveBuilder::new(_fbb);
+    if let Some(x) = args.position { builder.add_position(x); }
+    builder.add_position_type(args.position_type);
     builder.finish()
   }
 
+
+  #[inline]
+  pub fn position_type(&self) -> QueuePosition {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<QueuePosition>(QueueRemove::VT_POSITION_TYPE, Some(QueuePosition::NONE)).unwrap()}
+  }
+  #[inline]
+  pub fn position(&self) -> ::flatbuffers::Table<'a> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<::flatbuffers::Table<'a>>>(QueueRemove::VT_POSITION, None).unwrap()}
+  }
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn position_as_index(&self) -> Option<QueueIndex<'a>> {
+    if self.position_type() == QueuePosition::Index {
+      let u = self.position();
+      // Safety:
+      // Created from a valid Table for this object
+      // Which contains a valid union in this slot
+      Some(unsafe { QueueIndex::init_from_table(u) })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn position_as_front(&self) -> Option<QueueMarkerFront<'a>> {
+    if self.position_type() == QueuePosition::Front {
+      let u = self.position();
+      // Safety:
+      // Created from a valid Table for this object
+      // Which contains a valid union in this slot
+      Some(unsafe { QueueMarkerFront::init_from_table(u) })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn position_as_back(&self) -> Option<QueueMarkerBack<'a>> {
+    if self.position_type() == QueuePosition::Back {
+      let u = self.position();
+      // Safety:
+      // Created from a valid Table for this object
+      // Which contains a valid union in this slot
+      Some(unsafe { QueueMarkerBack::init_from_table(u) })
+    } else {
+      None
+    }
+  }
+
 }
 
-impl ::flatbuffers::Verifiable for QueueMarkerBack<'_> {
+impl ::flatbuffers::Verifiable for QueueRemove<'_> {
   #[inline]
   fn run_verifier(
     v: &mut ::flatbuffers::Verifier, pos: usize
   ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
     v.visit_table(pos)?
+     .visit_union::<QueuePosition, _>("position_type", Self::VT_POSITION_TYPE, "position", Self::VT_POSITION, true, |key, v, pos| {
+        match key {
+          QueuePosition::Index => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<QueueIndex>>("QueuePosition::Index", pos),
+          QueuePosition::Front => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<QueueMarkerFront>>("QueuePosition::Front", pos),
+          QueuePosition::Back => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<QueueMarkerBack>>("QueuePosition::Back", pos),
+          _ => Ok(()),
+        }
+     })?
      .finish();
     Ok(())
   }
 }
-pub struct QueueMarkerBackArgs {
+pub struct QueueRemoveArgs {
+    pub position_type: QueuePosition,
+    pub position: Option<::flatbuffers::WIPOffset<::flatbuffers::UnionWIPOffset>>,
 }
-impl<'a> Default for QueueMarkerBackArgs {
+impl<'a> Default for QueueRemoveArgs {
   #[inline]
   fn default() -> Self {
-    QueueMarkerBackArgs {
+    QueueRemoveArgs {
+      position_type: QueuePosition::NONE,
+      position: None, // required field
     }
   }
 }
 
-pub struct QueueMarkerBackBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
+pub struct QueueRemoveBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
   fbb_: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>,
   start_: ::flatbuffers::WIPOffset<::flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> QueueMarkerBackBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> QueueRemoveBuilder<'a, 'b, A> {
   #[inline]
-  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> QueueMarkerBackBuilder<'a, 'b, A> {
+  pub fn add_position_type(&mut self, position_type: QueuePosition) {
+    self.fbb_.push_slot::<QueuePosition>(QueueRemove::VT_POSITION_TYPE, position_type, QueuePosition::NONE);
+  }
+  #[inline]
+  pub fn add_position(&mut self, position: ::flatbuffers::WIPOffset<::flatbuffers::UnionWIPOffset>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(QueueRemove::VT_POSITION, position);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> QueueRemoveBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    QueueMarkerBackBuilder {
+    QueueRemoveBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> ::flatbuffers::WIPOffset<QueueMarkerBack<'a>> {
+  pub fn finish(self) -> ::flatbuffers::WIPOffset<QueueRemove<'a>> {
     let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, QueueRemove::VT_POSITION,"position");
     ::flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl ::core::fmt::Debug for QueueMarkerBack<'_> {
+impl ::core::fmt::Debug for QueueRemove<'_> {
   fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-    let mut ds = f.debug_struct("QueueMarkerBack");
+    let mut ds = f.debug_struct("QueueRemove");
+      ds.field("position_type", &self.position_type());
+      match self.position_type() {
+        QueuePosition::Index => {
+          if let Some(x) = self.position_as_index() {
+            ds.field("position", &x)
+          } else {
+            ds.field("position", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        QueuePosition::Front => {
+          if let Some(x) = self.position_as_front() {
+            ds.field("position", &x)
+          } else {
+            ds.field("position", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        QueuePosition::Back => {
+          if let Some(x) = self.position_as_back() {
+            ds.field("position", &x)
+          } else {
+            ds.field("position", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        _ => {
+          let x: Option<()> = None;
+          ds.field("position", &x)
+        },
+      };
       ds.finish()
   }
 }
-pub enum VideoTrackMetaOffset {}
+pub enum QueueMoveOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct VideoTrackMeta<'a> {
+pub struct QueueMove<'a> {
   pub _tab: ::flatbuffers::Table<'a>,
 }
 
-impl<'a> ::flatbuffers::Follow<'a> for VideoTrackMeta<'a> {
-  type Inner = VideoTrackMeta<'a>;
+impl<'a> ::flatbuffers::Follow<'a> for QueueMove<'a> {
+  type Inner = QueueMove<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: unsafe { ::flatbuffers::Table::new(buf, loc) } }
   }
 }
 
-impl<'a> VideoTrackMeta<'a> {
-  pub const VT_RESOLUTION: ::flatbuffers::VOffsetT = 4;
+impl<'a> QueueMove<'a> {
+  pub const VT_FROM_TYPE: ::flatbuffers::VOffsetT = 4;
+  pub const VT_FROM: ::flatbuffers::VOffsetT = 6;
+  pub const VT_TO_TYPE: ::flatbuffers::VOffsetT = 8;
+  pub const VT_TO: ::flatbuffers::VOffsetT = 10;
 
   #[inline]
   pub unsafe fn init_from_table(table: ::flatbuffers::Table<'a>) -> Self {
-    VideoTrackMeta { _tab: table }
+    QueueMove { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: ::flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut ::flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args VideoTrackMetaArgs<'args>
-  ) -> ::flatbuffers::WIPOffset<VideoTrackMeta<'bldr>> {
-    let mut builder = VideoTrackMetaBuilder::new(_fbb);
-    if let Some(x) = args.resolution { builder.add_resolution(x); }
+    args: &'args QueueMoveArgs
+  ) -> ::flatbuffers::WIPOffset<QueueMove<'bldr>> {
+    let mut builder = QueueMoveBuilder::new(_fbb);
+    if let Some(x) = args.to { builder.add_to(x); }
+    if let Some(x) = args.from { builder.add_from(x); }
+    builder.add_to_type(args.to_type);
+    builder.add_from_type(args.from_type);
     builder.finish()
   }
 
 
   #[inline]
-  pub fn resolution(&self) -> Option<&'a VideoResolution> {
+  pub fn from_type(&self) -> QueuePosition {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<VideoResolution>(VideoTrackMeta::VT_RESOLUTION, None)}
+    unsafe { self._tab.get::<QueuePosition>(QueueMove::VT_FROM_TYPE, Some(QueuePosition::NONE)).unwrap()}
+  }
+  #[inline]
+  pub fn from(&self) -> ::flatbuffers::Table<'a> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<::flatbuffers::Table<'a>>>(QueueMove::VT_FROM, None).unwrap()}
+  }
+  #[inline]
+  pub fn to_type(&self) -> QueuePosition {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<QueuePosition>(QueueMove::VT_TO_TYPE, Some(QueuePosition::NONE)).unwrap()}
+  }
+  #[inline]
+  pub fn to(&self) -> ::flatbuffers::Table<'a> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<::flatbuffers::Table<'a>>>(QueueMove::VT_TO, None).unwrap()}
+  }
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn from_as_index(&self) -> Option<QueueIndex<'a>> {
+    if self.from_type() == QueuePosition::Index {
+      let u = self.from();
+      // Safety:
+      // Created from a valid Table for this object
+      // Which contains a valid union in this slot
+      Some(unsafe { QueueIndex::init_from_table(u) })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn from_as_front(&self) -> Option<QueueMarkerFront<'a>> {
+    if self.from_type() == QueuePosition::Front {
+      let u = self.from();
+      // Safety:
+      // Created from a valid Table for this object
+      // Which contains a valid union in this slot
+      Some(unsafe { QueueMarkerFront::init_from_table(u) })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn from_as_back(&self) -> Option<QueueMarkerBack<'a>> {
+    if self.from_type() == QueuePosition::Back {
+      let u = self.from();
+      // Safety:
+      // Created from a valid Table for this object
+      // Which contains a valid union in this slot
+      Some(unsafe { QueueMarkerBack::init_from_table(u) })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn to_as_index(&self) -> Option<QueueIndex<'a>> {
+    if self.to_type() == QueuePosition::Index {
+      let u = self.to();
+      // Safety:
+      // Created from a valid Table for this object
+      // Which contains a valid union in this slot
+      Some(unsafe { QueueIndex::init_from_table(u) })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn to_as_front(&self) -> Option<QueueMarkerFront<'a>> {
+    if self.to_type() == QueuePosition::Front {
+      let u = self.to();
+      // Safety:
+      // Created from a valid Table for this object
+      // Which contains a valid union in this slot
+      Some(unsafe { QueueMarkerFront::init_from_table(u) })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn to_as_back(&self) -> Option<QueueMarkerBack<'a>> {
+    if self.to_type() == QueuePosition::Back {
+      let u = self.to();
+      // Safety:
+      // Created from a valid Table for this object
+      // Which contains a valid union in this slot
+      Some(unsafe { QueueMarkerBack::init_from_table(u) })
+    } else {
+      None
+    }
   }
+
 }
 
-impl ::flatbuffers::Verifiable for VideoTrackMeta<'_> {
+impl ::flatbuffers::Verifiable for QueueMove<'_> {
   #[inline]
   fn run_verifier(
     v: &mut ::flatbuffers::Verifier, pos: usize
   ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
     v.visit_table(pos)?
-     .visit_field::<VideoResolution>("resolution", Self::VT_RESOLUTION, false)?
+     .visit_union::<QueuePosition, _>("from_type", Self::VT_FROM_TYPE, "from", Self::VT_FROM, true, |key, v, pos| {
+        match key {
+          QueuePosition::Index => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<QueueIndex>>("QueuePosition::Index", pos),
+          QueuePosition::Front => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<QueueMarkerFront>>("QueuePosition::Front", pos),
+          QueuePosition::Back => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<QueueMarkerBack>>("QueuePosition::Back", pos),
+          _ => Ok(()),
+        }
+     })?
+     .visit_union::<QueuePosition, _>("to_type", Self::VT_TO_TYPE, "to", Self::VT_TO, true, |key, v, pos| {
+        match key {
+          QueuePosition::Index => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<QueueIndex>>("QueuePosition::Index", pos),
+          QueuePosition::Front => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<QueueMarkerFront>>("QueuePosition::Front", pos),
+          QueuePosition::Back => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<QueueMarkerBack>>("QueuePosition::Back", pos),
+          _ => Ok(()),
+        }
+     })?
      .finish();
     Ok(())
   }
 }
-pub struct VideoTrackMetaArgs<'a> {
-    pub resolution: Option<&'a VideoResolution>,
+pub struct QueueMoveArgs {
+    pub from_type: QueuePosition,
+    pub from: Option<::flatbuffers::WIPOffset<::flatbuffers::UnionWIPOffset>>,
+    pub to_type: QueuePosition,
+    pub to: Option<::flatbuffers::WIPOffset<::flatbuffers::UnionWIPOffset>>,
 }
-impl<'a> Default for VideoTrackMetaArgs<'a> {
+impl<'a> Default for QueueMoveArgs {
   #[inline]
   fn default() -> Self {
-    VideoTrackMetaArgs {
-      resolution: None,
+    QueueMoveArgs {
+      from_type: QueuePosition::NONE,
+      from: None, // required field
+      to_type: QueuePosition::NONE,
+      to: None, // required field
     }
   }
 }
 
-pub struct VideoTrackMetaBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
+pub struct QueueMoveBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
   fbb_: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>,
   start_: ::flatbuffers::WIPOffset<::flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> VideoTrackMetaBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> QueueMoveBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_resolution(&mut self, resolution: &VideoResolution) {
-    self.fbb_.push_slot_always::<&VideoResolution>(VideoTrackMeta::VT_RESOLUTION, resolution);
+  pub fn add_from_type(&mut self, from_type: QueuePosition) {
+    self.fbb_.push_slot::<QueuePosition>(QueueMove::VT_FROM_TYPE, from_type, QueuePosition::NONE);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> VideoTrackMetaBuilder<'a, 'b, A> {
+  pub fn add_from(&mut self, from: ::flatbuffers::WIPOffset<::flatbuffers::UnionWIPOffset>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(QueueMove::VT_FROM, from);
+  }
+  #[inline]
+  pub fn add_to_type(&mut self, to_type: QueuePosition) {
+    self.fbb_.push_slot::<QueuePosition>(QueueMove::VT_TO_TYPE, to_type, QueuePosition::NONE);
+  }
+  #[inline]
+  pub fn add_to(&mut self, to: ::flatbuffers::WIPOffset<::flatbuffers::UnionWIPOffset>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(QueueMove::VT_TO, to);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> QueueMoveBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    VideoTrackMetaBuilder {
+    QueueMoveBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> ::flatbuffers::WIPOffset<VideoTrackMeta<'a>> {
+  pub fn finish(self) -> ::flatbuffers::WIPOffset<QueueMove<'a>> {
     let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, QueueMove::VT_FROM,"from");
+    self.fbb_.required(o, QueueMove::VT_TO,"to");
     ::flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl ::core::fmt::Debug for VideoTrackMeta<'_> {
+impl ::core::fmt::Debug for QueueMove<'_> {
   fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-    let mut ds = f.debug_struct("VideoTrackMeta");
-      ds.field("resolution", &self.resolution());
+    let mut ds = f.debug_struct("QueueMove");
+      ds.field("from_type", &self.from_type());
+      match self.from_type() {
+        QueuePosition::Index => {
+          if let Some(x) = self.from_as_index() {
+            ds.field("from", &x)
+          } else {
+            ds.field("from", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        QueuePosition::Front => {
+          if let Some(x) = self.from_as_front() {
+            ds.field("from", &x)
+          } else {
+            ds.field("from", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        QueuePosition::Back => {
+          if let Some(x) = self.from_as_back() {
+            ds.field("from", &x)
+          } else {
+            ds.field("from", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        _ => {
+          let x: Option<()> = None;
+          ds.field("from", &x)
+        },
+      };
+      ds.field("to_type", &self.to_type());
+      match self.to_type() {
+        QueuePosition::Index => {
+          if let Some(x) = self.to_as_index() {
+            ds.field("to", &x)
+          } else {
+            ds.field("to", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        QueuePosition::Front => {
+          if let Some(x) = self.to_as_front() {
+            ds.field("to", &x)
+          } else {
+            ds.field("to", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        QueuePosition::Back => {
+          if let Some(x) = self.to_as_back() {
+            ds.field("to", &x)
+          } else {
+            ds.field("to", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        _ => {
+          let x: Option<()> = None;
+          ds.field("to", &x)
+        },
+      };
       ds.finish()
   }
 }
-pub enum AudioTrackMetaOffset {}
+pub enum QueueModeChangedOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct AudioTrackMeta<'a> {
+pub struct QueueModeChanged<'a> {
   pub _tab: ::flatbuffers::Table<'a>,
 }
 
-impl<'a> ::flatbuffers::Follow<'a> for AudioTrackMeta<'a> {
-  type Inner = AudioTrackMeta<'a>;
+impl<'a> ::flatbuffers::Follow<'a> for QueueModeChanged<'a> {
+  type Inner = QueueModeChanged<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: unsafe { ::flatbuffers::Table::new(buf, loc) } }
   }
 }
 
-impl<'a> AudioTrackMeta<'a> {
+impl<'a> QueueModeChanged<'a> {
+  pub const VT_REPEAT: ::flatbuffers::VOffsetT = 4;
+  pub const VT_SHUFFLE: ::flatbuffers::VOffsetT = 6;
 
   #[inline]
   pub unsafe fn init_from_table(table: ::flatbuffers::Table<'a>) -> Self {
-    AudioTrackMeta { _tab: table }
+    QueueModeChanged { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: ::flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut ::flatbuffers::FlatBufferBuilder<'bldr, A>,
-    _args: &'args AudioTrackMetaArgs
-  ) -> ::flatbuffers::WIPOffset<AudioTrackMeta<'bldr>> {
-    let mut builder = AudioTrackMetaBuilder::new(_fbb);
+    args: &'args QueueModeChangedArgs
+  ) -> ::flatbuffers::WIPOffset<QueueModeChanged<'bldr>> {
+    let mut builder = QueueModeChangedBuilder::new(_fbb);
+    builder.add_shuffle(args.shuffle);
+    builder.add_repeat(args.repeat);
     builder.finish()
   }
 
+
+  #[inline]
+  pub fn repeat(&self) -> RepeatMode {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<RepeatMode>(QueueModeChanged::VT_REPEAT, Some(RepeatMode::Off)).unwrap()}
+  }
+  #[inline]
+  pub fn shuffle(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(QueueModeChanged::VT_SHUFFLE, Some(false)).unwrap()}
+  }
 }
 
-impl ::flatbuffers::Verifiable for AudioTrackMeta<'_> {
+impl ::flatbuffers::Verifiable for QueueModeChanged<'_> {
   #[inline]
   fn run_verifier(
     v: &mut ::flatbuffers::Verifier, pos: usize
   ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
     v.visit_table(pos)?
+     .visit_field::<RepeatMode>("repeat", Self::VT_REPEAT, false)?
+     .visit_field::<bool>("shuffle", Self::VT_SHUFFLE, false)?
      .finish();
     Ok(())
   }
 }
-pub struct AudioTrackMetaArgs {
+pub struct QueueModeChangedArgs {
+    pub repeat: RepeatMode,
+    pub shuffle: bool,
 }
-impl<'a> Default for AudioTrackMetaArgs {
+impl<'a> Default for QueueModeChangedArgs {
   #[inline]
   fn default() -> Self {
-    AudioTrackMetaArgs {
+    QueueModeChangedArgs {
+      repeat: RepeatMode::Off,
+      shuffle: false,
     }
   }
 }
 
-pub struct AudioTrackMetaBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
+pub struct QueueModeChangedBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
   fbb_: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>,
   start_: ::flatbuffers::WIPOffset<::flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> AudioTrackMetaBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> QueueModeChangedBuilder<'a, 'b, A> {
   #[inline]
-  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> AudioTrackMetaBuilder<'a, 'b, A> {
+  pub fn add_repeat(&mut self, repeat: RepeatMode) {
+    self.fbb_.push_slot::<RepeatMode>(QueueModeChanged::VT_REPEAT, repeat, RepeatMode::Off);
+  }
+  #[inline]
+  pub fn add_shuffle(&mut self, shuffle: bool) {
+    self.fbb_.push_slot::<bool>(QueueModeChanged::VT_SHUFFLE, shuffle, false);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> QueueModeChangedBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    AudioTrackMetaBuilder {
+    QueueModeChangedBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> ::flatbuffers::WIPOffset<AudioTrackMeta<'a>> {
+  pub fn finish(self) -> ::flatbuffers::WIPOffset<QueueModeChanged<'a>> {
     let o = self.fbb_.end_table(self.start_);
     ::flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl ::core::fmt::Debug for AudioTrackMeta<'_> {
+impl ::core::fmt::Debug for QueueModeChanged<'_> {
   fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-    let mut ds = f.debug_struct("AudioTrackMeta");
+    let mut ds = f.debug_struct("QueueModeChanged");
+      ds.field("repeat", &self.repeat());
+      ds.field("shuffle", &self.shuffle());
       ds.finish()
   }
 }
-pub enum SubtitleTrackMetaOffset {}
+pub enum QueueInsertBatchOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct SubtitleTrackMeta<'a> {
+pub struct QueueInsertBatch<'a> {
   pub _tab: ::flatbuffers::Table<'a>,
 }
 
-impl<'a> ::flatbuffers::Follow<'a> for SubtitleTrackMeta<'a> {
-  type Inner = SubtitleTrackMeta<'a>;
+impl<'a> ::flatbuffers::Follow<'a> for QueueInsertBatch<'a> {
+  type Inner = QueueInsertBatch<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: unsafe { ::flatbuffers::Table::new(buf, loc) } }
   }
 }
 
-impl<'a> SubtitleTrackMeta<'a> {
+impl<'a> QueueInsertBatch<'a> {
+  pub const VT_ITEMS: ::flatbuffers::VOffsetT = 4;
+  pub const VT_POSITION_TYPE: ::flatbuffers::VOffsetT = 6;
+  pub const VT_POSITION: ::flatbuffers::VOffsetT = 8;
 
   #[inline]
   pub unsafe fn init_from_table(table: ::flatbuffers::Table<'a>) -> Self {
-    SubtitleTrackMeta { _tab: table }
+    QueueInsertBatch { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: ::flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut ::flatbuffers::FlatBufferBuilder<'bldr, A>,
-    _args: &'args SubtitleTrackMetaArgs
-  ) -> ::flatbuffers::WIPOffset<SubtitleTrackMeta<'bldr>> {
-    let mut builder = SubtitleTrackMetaBuilder::new(_fbb);
+    args: &'args QueueInsertBatchArgs<'args>
+  ) -> ::flatbuffers::WIPOffset<QueueInsertBatch<'bldr>> {
+    let mut builder = QueueInsertBatchBuilder::new(_fbb);
+    if let Some(x) = args.position { builder.add_position(x); }
+    if let Some(x) = args.items { builder.add_items(x); }
+    builder.add_position_type(args.position_type);
     builder.finish()
   }
 
+
+  #[inline]
+  pub fn items(&self) -> ::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<QueueItem<'a>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<QueueItem>>>>(QueueInsertBatch::VT_ITEMS, None).unwrap()}
+  }
+  #[inline]
+  pub fn position_type(&self) -> QueuePosition {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<QueuePosition>(QueueInsertBatch::VT_POSITION_TYPE, Some(QueuePosition::NONE)).unwrap()}
+  }
+  #[inline]
+  pub fn position(&self) -> ::flatbuffers::Table<'a> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<::flatbuffers::Table<'a>>>(QueueInsertBatch::VT_POSITION, None).unwrap()}
+  }
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn position_as_index(&self) -> Option<QueueIndex<'a>> {
+    if self.position_type() == QueuePosition::Index {
+      let u = self.position();
+      // Safety:
+      // Created from a valid Table for this object
+      // Which contains a valid union in this slot
+      Some(unsafe { QueueIndex::init_from_table(u) })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn position_as_front(&self) -> Option<QueueMarkerFront<'a>> {
+    if self.position_type() == QueuePosition::Front {
+      let u = self.position();
+      // Safety:
+      // Created from a valid Table for this object
+      // Which contains a valid union in this slot
+      Some(unsafe { QueueMarkerFront::init_from_table(u) })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn position_as_back(&self) -> Option<QueueMarkerBack<'a>> {
+    if self.position_type() == QueuePosition::Back {
+      let u = self.position();
+      // Safety:
+      // Created from a valid Table for this object
+      // Which contains a valid union in this slot
+      Some(unsafe { QueueMarkerBack::init_from_table(u) })
+    } else {
+      None
+    }
+  }
+
 }
 
-impl ::flatbuffers::Verifiable for SubtitleTrackMeta<'_> {
+impl ::flatbuffers::Verifiable for QueueInsertBatch<'_> {
   #[inline]
   fn run_verifier(
     v: &mut ::flatbuffers::Verifier, pos: usize
   ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
     v.visit_table(pos)?
+     .visit_field::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'_, ::flatbuffers::ForwardsUOffset<QueueItem>>>>("items", Self::VT_ITEMS, true)?
+     .visit_union::<QueuePosition, _>("position_type", Self::VT_POSITION_TYPE, "position", Self::VT_POSITION, true, |key, v, pos| {
+        match key {
+          QueuePosition::Index => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<QueueIndex>>("QueuePosition::Index", pos),
+          QueuePosition::Front => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<QueueMarkerFront>>("QueuePosition::Front", pos),
+          QueuePosition::Back => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<QueueMarkerBack>>("QueuePosition::Back", pos),
+          _ => Ok(()),
+        }
+     })?
      .finish();
     Ok(())
   }
 }
-pub struct SubtitleTrackMetaArgs {
+pub struct QueueInsertBatchArgs<'a> {
+    pub items: Option<::flatbuffers::WIPOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<QueueItem<'a>>>>>,
+    pub position_type: QueuePosition,
+    pub position: Option<::flatbuffers::WIPOffset<::flatbuffers::UnionWIPOffset>>,
 }
-impl<'a> Default for SubtitleTrackMetaArgs {
+impl<'a> Default for QueueInsertBatchArgs<'a> {
   #[inline]
   fn default() -> Self {
-    SubtitleTrackMetaArgs {
+    QueueInsertBatchArgs {
+      items: None, // required field
+      position_type: QueuePosition::NONE,
+      position: None, // required field
     }
   }
 }
 
-pub struct SubtitleTrackMetaBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
+pub struct QueueInsertBatchBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
   fbb_: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>,
   start_: ::flatbuffers::WIPOffset<::flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> SubtitleTrackMetaBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> QueueInsertBatchBuilder<'a, 'b, A> {
   #[inline]
-  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> SubtitleTrackMetaBuilder<'a, 'b, A> {
+  pub fn add_items(&mut self, items: ::flatbuffers::WIPOffset<::flatbuffers::Vector<'b , ::flatbuffers::ForwardsUOffset<QueueItem<'b >>>>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(QueueInsertBatch::VT_ITEMS, items);
+  }
+  #[inline]
+  pub fn add_position_type(&mut self, position_type: QueuePosition) {
+    self.fbb_.push_slot::<QueuePosition>(QueueInsertBatch::VT_POSITION_TYPE, position_type, QueuePosition::NONE);
+  }
+  #[inline]
+  pub fn add_position(&mut self, position: ::flatbuffers::WIPOffset<::flatbuffers::UnionWIPOffset>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(QueueInsertBatch::VT_POSITION, position);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> QueueInsertBatchBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    SubtitleTrackMetaBuilder {
+    QueueInsertBatchBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> ::flatbuffers::WIPOffset<SubtitleTrackMeta<'a>> {
+  pub fn finish(self) -> ::flatbuffers::WIPOffset<QueueInsertBatch<'a>> {
     let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, QueueInsertBatch::VT_ITEMS,"items");
+    self.fbb_.required(o, QueueInsertBatch::VT_POSITION,"position");
     ::flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl ::core::fmt::Debug for SubtitleTrackMeta<'_> {
+impl ::core::fmt::Debug for QueueInsertBatch<'_> {
   fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-    let mut ds = f.debug_struct("SubtitleTrackMeta");
+    let mut ds = f.debug_struct("QueueInsertBatch");
+      ds.field("items", &self.items());
+      ds.field("position_type", &self.position_type());
+      match self.position_type() {
+        QueuePosition::Index => {
+          if let Some(x) = self.position_as_index() {
+            ds.field("position", &x)
+          } else {
+            ds.field("position", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        QueuePosition::Front => {
+          if let Some(x) = self.position_as_front() {
+            ds.field("position", &x)
+          } else {
+            ds.field("position", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        QueuePosition::Back => {
+          if let Some(x) = self.position_as_back() {
+            ds.field("position", &x)
+          } else {
+            ds.field("position", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        _ => {
+          let x: Option<()> = None;
+          ds.field("position", &x)
+        },
+      };
       ds.finish()
   }
 }
-pub enum DeviceInfoOffset {}
+pub enum SleepTimerChangedOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct DeviceInfo<'a> {
+pub struct SleepTimerChanged<'a> {
   pub _tab: ::flatbuffers::Table<'a>,
 }
 
-impl<'a> ::flatbuffers::Follow<'a> for DeviceInfo<'a> {
-  type Inner = DeviceInfo<'a>;
+impl<'a> ::flatbuffers::Follow<'a> for SleepTimerChanged<'a> {
+  type Inner = SleepTimerChanged<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: unsafe { ::flatbuffers::Table::new(buf, loc) } }
   }
 }
 
-impl<'a> DeviceInfo<'a> {
-  pub const VT_DISPLAY_NAME: ::flatbuffers::VOffsetT = 4;
-  pub const VT_APP_NAME: ::flatbuffers::VOffsetT = 6;
-  pub const VT_APP_VERSION: ::flatbuffers::VOffsetT = 8;
+impl<'a> SleepTimerChanged<'a> {
+  pub const VT_MODE: ::flatbuffers::VOffsetT = 4;
+  pub const VT_REMAINING: ::flatbuffers::VOffsetT = 6;
+  pub const VT_FADE: ::flatbuffers::VOffsetT = 8;
 
   #[inline]
   pub unsafe fn init_from_table(table: ::flatbuffers::Table<'a>) -> Self {
-    DeviceInfo { _tab: table }
+    SleepTimerChanged { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: ::flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut ::flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args DeviceInfoArgs<'args>
-  ) -> ::flatbuffers::WIPOffset<DeviceInfo<'bldr>> {
-    let mut builder = DeviceInfoBuilder::new(_fbb);
-    if let Some(x) = args.app_version { builder.add_app_version(x); }
-    if let Some(x) = args.app_name { builder.add_app_name(x); }
-    if let Some(x) = args.display_name { builder.add_display_name(x); }
+    args: &'args SleepTimerChangedArgs<'args>
+  ) -> ::flatbuffers::WIPOffset<SleepTimerChanged<'bldr>> {
+    let mut builder = SleepTimerChangedBuilder::new(_fbb);
+    if let Some(x) = args.fade { builder.add_fade(x); }
+    if let Some(x) = args.remaining { builder.add_remaining(x); }
+    builder.add_mode(args.mode);
     builder.finish()
   }
 
 
   #[inline]
-  pub fn display_name(&self) -> Option<&'a str> {
+  pub fn mode(&self) -> SleepTimerMode {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<&str>>(DeviceInfo::VT_DISPLAY_NAME, None)}
+    unsafe { self._tab.get::<SleepTimerMode>(SleepTimerChanged::VT_MODE, Some(SleepTimerMode::Off)).unwrap()}
   }
   #[inline]
-  pub fn app_name(&self) -> Option<&'a str> {
+  pub fn remaining(&self) -> Option<&'a Time> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<&str>>(DeviceInfo::VT_APP_NAME, None)}
+    unsafe { self._tab.get::<Time>(SleepTimerChanged::VT_REMAINING, None)}
   }
   #[inline]
-  pub fn app_version(&self) -> Option<&'a str> {
+  pub fn fade(&self) -> Option<&'a Time> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<&str>>(DeviceInfo::VT_APP_VERSION, None)}
+    unsafe { self._tab.get::<Time>(SleepTimerChanged::VT_FADE, None)}
   }
 }
 
-impl ::flatbuffers::Verifiable for DeviceInfo<'_> {
+impl ::flatbuffers::Verifiable for SleepTimerChanged<'_> {
   #[inline]
   fn run_verifier(
     v: &mut ::flatbuffers::Verifier, pos: usize
   ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
     v.visit_table(pos)?
-     .visit_field::<::flatbuffers::ForwardsUOffset<&str>>("display_name", Self::VT_DISPLAY_NAME, false)?
-     .visit_field::<::flatbuffers::ForwardsUOffset<&str>>("app_name", Self::VT_APP_NAME, false)?
-     .visit_field::<::flatbuffers::ForwardsUOffset<&str>>("app_version", Self::VT_APP_VERSION, false)?
+     .visit_field::<SleepTimerMode>("mode", Self::VT_MODE, false)?
+     .visit_field::<Time>("remaining", Self::VT_REMAINING, false)?
+     .visit_field::<Time>("fade", Self::VT_FADE, false)?
      .finish();
     Ok(())
   }
 }
-pub struct DeviceInfoArgs<'a> {
-    pub display_name: Option<::flatbuffers::WIPOffset<&'a str>>,
-    pub app_name: Option<::flatbuffers::WIPOffset<&'a str>>,
-    pub app_version: Option<::flatbuffers::WIPOffset<&'a str>>,
+pub struct SleepTimerChangedArgs<'a> {
+    pub mode: SleepTimerMode,
+    pub remaining: Option<&'a Time>,
+    pub fade: Option<&'a Time>,
 }
-impl<'a> Default for DeviceInfoArgs<'a> {
+impl<'a> Default for SleepTimerChangedArgs<'a> {
   #[inline]
   fn default() -> Self {
-    DeviceInfoArgs {
-      display_name: None,
-      app_name: None,
-      app_version: None,
+    SleepTimerChangedArgs {
+      mode: SleepTimerMode::Off,
+      remaining: None,
+      fade: None,
     }
   }
 }
 
-pub struct DeviceInfoBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
+pub struct SleepTimerChangedBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
   fbb_: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>,
   start_: ::flatbuffers::WIPOffset<::flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> DeviceInfoBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> SleepTimerChangedBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_display_name(&mut self, display_name: ::flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(DeviceInfo::VT_DISPLAY_NAME, display_name);
+  pub fn add_mode(&mut self, mode: SleepTimerMode) {
+    self.fbb_.push_slot::<SleepTimerMode>(SleepTimerChanged::VT_MODE, mode, SleepTimerMode::Off);
   }
   #[inline]
-  pub fn add_app_name(&mut self, app_name: ::flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(DeviceInfo::VT_APP_NAME, app_name);
+  pub fn add_remaining(&mut self, remaining: &Time) {
+    self.fbb_.push_slot_always::<&Time>(SleepTimerChanged::VT_REMAINING, remaining);
   }
   #[inline]
-  pub fn add_app_version(&mut self, app_version: ::flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(DeviceInfo::VT_APP_VERSION, app_version);
+  pub fn add_fade(&mut self, fade: &Time) {
+    self.fbb_.push_slot_always::<&Time>(SleepTimerChanged::VT_FADE, fade);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> DeviceInfoBuilder<'a, 'b, A> {
+  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> SleepTimerChangedBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    DeviceInfoBuilder {
+    SleepTimerChangedBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> ::flatbuffers::WIPOffset<DeviceInfo<'a>> {
+  pub fn finish(self) -> ::flatbuffers::WIPOffset<SleepTimerChanged<'a>> {
     let o = self.fbb_.end_table(self.start_);
     ::flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl ::core::fmt::Debug for DeviceInfo<'_> {
+impl ::core::fmt::Debug for SleepTimerChanged<'_> {
   fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-    let mut ds = f.debug_struct("DeviceInfo");
-      ds.field("display_name", &self.display_name());
-      ds.field("app_name", &self.app_name());
-      ds.field("app_version", &self.app_version());
+    let mut ds = f.debug_struct("SleepTimerChanged");
+      ds.field("mode", &self.mode());
+      ds.field("remaining", &self.remaining());
+      ds.field("fade", &self.fade());
       ds.finish()
   }
 }
-pub enum DisplayCapabilitiesOffset {}
+pub enum PlaybackHistoryRequestOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct DisplayCapabilities<'a> {
+pub struct PlaybackHistoryRequest<'a> {
   pub _tab: ::flatbuffers::Table<'a>,
 }
 
-impl<'a> ::flatbuffers::Follow<'a> for DisplayCapabilities<'a> {
-  type Inner = DisplayCapabilities<'a>;
+impl<'a> ::flatbuffers::Follow<'a> for PlaybackHistoryRequest<'a> {
+  type Inner = PlaybackHistoryRequest<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: unsafe { ::flatbuffers::Table::new(buf, loc) } }
   }
 }
 
-impl<'a> DisplayCapabilities<'a> {
-  pub const VT_RESOLUTION: ::flatbuffers::VOffsetT = 4;
+impl<'a> PlaybackHistoryRequest<'a> {
+  pub const VT_REQUEST_ID: ::flatbuffers::VOffsetT = 4;
+  pub const VT_LIMIT: ::flatbuffers::VOffsetT = 6;
 
   #[inline]
   pub unsafe fn init_from_table(table: ::flatbuffers::Table<'a>) -> Self {
-    DisplayCapabilities { _tab: table }
+    PlaybackHistoryRequest { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: ::flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut ::flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args DisplayCapabilitiesArgs<'args>
-  ) -> ::flatbuffers::WIPOffset<DisplayCapabilities<'bldr>> {
-    let mut builder = DisplayCapabilitiesBuilder::new(_fbb);
-    if let Some(x) = args.resolution { builder.add_resolution(x); }
+    args: &'args PlaybackHistoryRequestArgs
+  ) -> ::flatbuffers::WIPOffset<PlaybackHistoryRequest<'bldr>> {
+    let mut builder = PlaybackHistoryRequestBuilder::new(_fbb);
+    builder.add_limit(args.limit);
+    builder.add_request_id(args.request_id);
     builder.finish()
   }
 
 
   #[inline]
-  pub fn resolution(&self) -> Option<&'a VideoResolution> {
+  pub fn request_id(&self) -> u32 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<VideoResolution>(DisplayCapabilities::VT_RESOLUTION, None)}
+    unsafe { self._tab.get::<u32>(PlaybackHistoryRequest::VT_REQUEST_ID, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn limit(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(PlaybackHistoryRequest::VT_LIMIT, Some(0)).unwrap()}
   }
 }
 
-impl ::flatbuffers::Verifiable for DisplayCapabilities<'_> {
+impl ::flatbuffers::Verifiable for PlaybackHistoryRequest<'_> {
   #[inline]
   fn run_verifier(
     v: &mut ::flatbuffers::Verifier, pos: usize
   ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
     v.visit_table(pos)?
-     .visit_field::<VideoResolution>("resolution", Self::VT_RESOLUTION, false)?
+     .visit_field::<u32>("request_id", Self::VT_REQUEST_ID, false)?
+     .visit_field::<u32>("limit", Self::VT_LIMIT, false)?
      .finish();
     Ok(())
   }
 }
-pub struct DisplayCapabilitiesArgs<'a> {
-    pub resolution: Option<&'a VideoResolution>,
+pub struct PlaybackHistoryRequestArgs {
+    pub request_id: u32,
+    pub limit: u32,
 }
-impl<'a> Default for DisplayCapabilitiesArgs<'a> {
+impl<'a> Default for PlaybackHistoryRequestArgs {
   #[inline]
   fn default() -> Self {
-    DisplayCapabilitiesArgs {
-      resolution: None,
+    PlaybackHistoryRequestArgs {
+      request_id: 0,
+      limit: 0,
     }
   }
 }
 
-pub struct DisplayCapabilitiesBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
+pub struct PlaybackHistoryRequestBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
   fbb_: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>,
   start_: ::flatbuffers::WIPOffset<::flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> DisplayCapabilitiesBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> PlaybackHistoryRequestBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_resolution(&mut self, resolution: &VideoResolution) {
-    self.fbb_.push_slot_always::<&VideoResolution>(DisplayCapabilities::VT_RESOLUTION, resolution);
+  pub fn add_request_id(&mut self, request_id: u32) {
+    self.fbb_.push_slot::<u32>(PlaybackHistoryRequest::VT_REQUEST_ID, request_id, 0);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> DisplayCapabilitiesBuilder<'a, 'b, A> {
+  pub fn add_limit(&mut self, limit: u32) {
+    self.fbb_.push_slot::<u32>(PlaybackHistoryRequest::VT_LIMIT, limit, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> PlaybackHistoryRequestBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    DisplayCapabilitiesBuilder {
+    PlaybackHistoryRequestBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> ::flatbuffers::WIPOffset<DisplayCapabilities<'a>> {
+  pub fn finish(self) -> ::flatbuffers::WIPOffset<PlaybackHistoryRequest<'a>> {
     let o = self.fbb_.end_table(self.start_);
     ::flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl ::core::fmt::Debug for DisplayCapabilities<'_> {
+impl ::core::fmt::Debug for PlaybackHistoryRequest<'_> {
   fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-    let mut ds = f.debug_struct("DisplayCapabilities");
-      ds.field("resolution", &self.resolution());
+    let mut ds = f.debug_struct("PlaybackHistoryRequest");
+      ds.field("request_id", &self.request_id());
+      ds.field("limit", &self.limit());
       ds.finish()
   }
 }
-pub enum MediaCapabilitiesOffset {}
+pub enum HistoryEntryOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct MediaCapabilities<'a> {
+pub struct HistoryEntry<'a> {
   pub _tab: ::flatbuffers::Table<'a>,
 }
 
-impl<'a> ::flatbuffers::Follow<'a> for MediaCapabilities<'a> {
-  type Inner = MediaCapabilities<'a>;
+impl<'a> ::flatbuffers::Follow<'a> for HistoryEntry<'a> {
+  type Inner = HistoryEntry<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: unsafe { ::flatbuffers::Table::new(buf, loc) } }
   }
 }
 
-impl<'a> MediaCapabilities<'a> {
-  pub const VT_PROTOCOLS: ::flatbuffers::VOffsetT = 4;
-  pub const VT_CONTAINERS: ::flatbuffers::VOffsetT = 6;
-  pub const VT_VIDEO_FORMATS: ::flatbuffers::VOffsetT = 8;
-  pub const VT_AUDIO_FORMATS: ::flatbuffers::VOffsetT = 10;
-  pub const VT_SUBTITLE_FORMATS: ::flatbuffers::VOffsetT = 12;
-  pub const VT_HDR_FORMATS: ::flatbuffers::VOffsetT = 14;
-  pub const VT_IMAGE_FORMATS: ::flatbuffers::VOffsetT = 16;
-  pub const VT_EXTERNAL_SUBTITLES: ::flatbuffers::VOffsetT = 18;
-  pub const VT_MIRRORING: ::flatbuffers::VOffsetT = 20;
+impl<'a> HistoryEntry<'a> {
+  pub const VT_URL: ::flatbuffers::VOffsetT = 4;
+  pub const VT_CONTAINER: ::flatbuffers::VOffsetT = 6;
+  pub const VT_TITLE: ::flatbuffers::VOffsetT = 8;
+  pub const VT_THUMBNAIL_URL: ::flatbuffers::VOffsetT = 10;
+  pub const VT_WATCHED: ::flatbuffers::VOffsetT = 12;
+  pub const VT_DURATION: ::flatbuffers::VOffsetT = 14;
+  pub const VT_PLAYED_AT: ::flatbuffers::VOffsetT = 16;
+  pub const VT_SENDER_NAME: ::flatbuffers::VOffsetT = 18;
 
   #[inline]
   pub unsafe fn init_from_table(table: ::flatbuffers::Table<'a>) -> Self {
-    MediaCapabilities { _tab: table }
+    HistoryEntry { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: ::flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut ::flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args MediaCapabilitiesArgs<'args>
-  ) -> ::flatbuffers::WIPOffset<MediaCapabilities<'bldr>> {
-    let mut builder = MediaCapabilitiesBuilder::new(_fbb);
-    if let Some(x) = args.image_formats { builder.add_image_formats(x); }
-    if let Some(x) = args.hdr_formats { builder.add_hdr_formats(x); }
-    if let Some(x) = args.subtitle_formats { builder.add_subtitle_formats(x); }
-    if let Some(x) = args.audio_formats { builder.add_audio_formats(x); }
-    if let Some(x) = args.video_formats { builder.add_video_formats(x); }
-    if let Some(x) = args.containers { builder.add_containers(x); }
-    if let Some(x) = args.protocols { builder.add_protocols(x); }
-    builder.add_mirroring(args.mirroring);
-    builder.add_external_subtitles(args.external_subtitles);
+    args: &'args HistoryEntryArgs<'args>
+  ) -> ::flatbuffers::WIPOffset<HistoryEntry<'bldr>> {
+    let mut builder = HistoryEntryBuilder::new(_fbb);
+    builder.add_played_at(args.played_at);
+    if let Some(x) = args.sender_name { builder.add_sender_name(x); }
+    if let Some(x) = args.duration { builder.add_duration(x); }
+    if let Some(x) = args.watched { builder.add_watched(x); }
+    if let Some(x) = args.thumbnail_url { builder.add_thumbnail_url(x); }
+    if let Some(x) = args.title { builder.add_title(x); }
+    if let Some(x) = args.container { builder.add_container(x); }
+    if let Some(x) = args.url { builder.add_url(x); }
     builder.finish()
   }
 
 
   #[inline]
-  pub fn protocols(&self) -> Option<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<&'a str>>> {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<&'a str>>>>(MediaCapabilities::VT_PROTOCOLS, None)}
-  }
-  #[inline]
-  pub fn containers(&self) -> Option<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<&'a str>>> {
+  pub fn url(&self) -> &'a str {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<&'a str>>>>(MediaCapabilities::VT_CONTAINERS, None)}
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<&str>>(HistoryEntry::VT_URL, None).unwrap()}
   }
   #[inline]
-  pub fn video_formats(&self) -> Option<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<&'a str>>> {
+  pub fn container(&self) -> &'a str {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<&'a str>>>>(MediaCapabilities::VT_VIDEO_FORMATS, None)}
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<&str>>(HistoryEntry::VT_CONTAINER, None).unwrap()}
   }
   #[inline]
-  pub fn audio_formats(&self) -> Option<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<&'a str>>> {
+  pub fn title(&self) -> Option<&'a str> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<&'a str>>>>(MediaCapabilities::VT_AUDIO_FORMATS, None)}
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<&str>>(HistoryEntry::VT_TITLE, None)}
   }
   #[inline]
-  pub fn subtitle_formats(&self) -> Option<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<&'a str>>> {
+  pub fn thumbnail_url(&self) -> Option<&'a str> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<&'a str>>>>(MediaCapabilities::VT_SUBTITLE_FORMATS, None)}
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<&str>>(HistoryEntry::VT_THUMBNAIL_URL, None)}
   }
   #[inline]
-  pub fn hdr_formats(&self) -> Option<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<&'a str>>> {
+  pub fn watched(&self) -> Option<&'a Time> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<&'a str>>>>(MediaCapabilities::VT_HDR_FORMATS, None)}
+    unsafe { self._tab.get::<Time>(HistoryEntry::VT_WATCHED, None)}
   }
   #[inline]
-  pub fn image_formats(&self) -> Option<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<&'a str>>> {
+  pub fn duration(&self) -> Option<&'a Time> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<&'a str>>>>(MediaCapabilities::VT_IMAGE_FORMATS, None)}
+    unsafe { self._tab.get::<Time>(HistoryEntry::VT_DURATION, None)}
   }
   #[inline]
-  pub fn external_subtitles(&self) -> bool {
+  pub fn played_at(&self) -> u64 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<bool>(MediaCapabilities::VT_EXTERNAL_SUBTITLES, Some(false)).unwrap()}
+    unsafe { self._tab.get::<u64>(HistoryEntry::VT_PLAYED_AT, Some(0)).unwrap()}
   }
   #[inline]
-  pub fn mirroring(&self) -> bool {
+  pub fn sender_name(&self) -> Option<&'a str> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<bool>(MediaCapabilities::VT_MIRRORING, Some(false)).unwrap()}
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<&str>>(HistoryEntry::VT_SENDER_NAME, None)}
   }
 }
 
-impl ::flatbuffers::Verifiable for MediaCapabilities<'_> {
+impl ::flatbuffers::Verifiable for HistoryEntry<'_> {
   #[inline]
   fn run_verifier(
     v: &mut ::flatbuffers::Verifier, pos: usize
   ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
     v.visit_table(pos)?
-     .visit_field::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'_, ::flatbuffers::ForwardsUOffset<&'_ str>>>>("protocols", Self::VT_PROTOCOLS, false)?
-     .visit_field::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'_, ::flatbuffers::ForwardsUOffset<&'_ str>>>>("containers", Self::VT_CONTAINERS, false)?
-     .visit_field::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'_, ::flatbuffers::ForwardsUOffset<&'_ str>>>>("video_formats", Self::VT_VIDEO_FORMATS, false)?
-     .visit_field::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'_, ::flatbuffers::ForwardsUOffset<&'_ str>>>>("audio_formats", Self::VT_AUDIO_FORMATS, false)?
-     .visit_field::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'_, ::flatbuffers::ForwardsUOffset<&'_ str>>>>("subtitle_formats", Self::VT_SUBTITLE_FORMATS, false)?
-     .visit_field::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'_, ::flatbuffers::ForwardsUOffset<&'_ str>>>>("hdr_formats", Self::VT_HDR_FORMATS, false)?
-     .visit_field::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'_, ::flatbuffers::ForwardsUOffset<&'_ str>>>>("image_formats", Self::VT_IMAGE_FORMATS, false)?
-     .visit_field::<bool>("external_subtitles", Self::VT_EXTERNAL_SUBTITLES, false)?
-     .visit_field::<bool>("mirroring", Self::VT_MIRRORING, false)?
+     .visit_field::<::flatbuffers::ForwardsUOffset<&str>>("url", Self::VT_URL, true)?
+     .visit_field::<::flatbuffers::ForwardsUOffset<&str>>("container", Self::VT_CONTAINER, true)?
+     .visit_field::<::flatbuffers::ForwardsUOffset<&str>>("title", Self::VT_TITLE, false)?
+     .visit_field::<::flatbuffers::ForwardsUOffset<&str>>("thumbnail_url", Self::VT_THUMBNAIL_URL, false)?
+     .visit_field::<Time>("watched", Self::VT_WATCHED, false)?
+     .visit_field::<Time>("duration", Self::VT_DURATION, false)?
+     .visit_field::<u64>("played_at", Self::VT_PLAYED_AT, false)?
+     .visit_field::<::flatbuffers::ForwardsUOffset<&str>>("sender_name", Self::VT_SENDER_NAME, false)?
      .finish();
     Ok(())
   }
 }
-pub struct MediaCapabilitiesArgs<'a> {
-    pub protocols: Option<::flatbuffers::WIPOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<&'a str>>>>,
-    pub containers: Option<::flatbuffers::WIPOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<&'a str>>>>,
-    pub video_formats: Option<::flatbuffers::WIPOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<&'a str>>>>,
-    pub audio_formats: Option<::flatbuffers::WIPOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<&'a str>>>>,
-    pub subtitle_formats: Option<::flatbuffers::WIPOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<&'a str>>>>,
-    pub hdr_formats: Option<::flatbuffers::WIPOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<&'a str>>>>,
-    pub image_formats: Option<::flatbuffers::WIPOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<&'a str>>>>,
-    pub external_subtitles: bool,
-    pub mirroring: bool,
+pub struct HistoryEntryArgs<'a> {
+    pub url: Option<::flatbuffers::WIPOffset<&'a str>>,
+    pub container: Option<::flatbuffers::WIPOffset<&'a str>>,
+    pub title: Option<::flatbuffers::WIPOffset<&'a str>>,
+    pub thumbnail_url: Option<::flatbuffers::WIPOffset<&'a str>>,
+    pub watched: Option<&'a Time>,
+    pub duration: Option<&'a Time>,
+    pub played_at: u64,
+    pub sender_name: Option<::flatbuffers::WIPOffset<&'a str>>,
 }
-impl<'a> Default for MediaCapabilitiesArgs<'a> {
+impl<'a> Default for HistoryEntryArgs<'a> {
   #[inline]
   fn default() -> Self {
-    MediaCapabilitiesArgs {
-      protocols: None,
-      containers: None,
-      video_formats: None,
-      audio_formats: None,
-      subtitle_formats: None,
-      hdr_formats: None,
-      image_formats: None,
-      external_subtitles: false,
-      mirroring: false,
+    HistoryEntryArgs {
+      url: None, // required field
+      container: None, // required field
+      title: None,
+      thumbnail_url: None,
+      watched: None,
+      duration: None,
+      played_at: 0,
+      sender_name: None,
     }
   }
 }
 
-pub struct MediaCapabilitiesBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
+pub struct HistoryEntryBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
   fbb_: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>,
   start_: ::flatbuffers::WIPOffset<::flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> MediaCapabilitiesBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> HistoryEntryBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_protocols(&mut self, protocols: ::flatbuffers::WIPOffset<::flatbuffers::Vector<'b , ::flatbuffers::ForwardsUOffset<&'b  str>>>) {
-    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(MediaCapabilities::VT_PROTOCOLS, protocols);
+  pub fn add_url(&mut self, url: ::flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(HistoryEntry::VT_URL, url);
   }
   #[inline]
-  pub fn add_containers(&mut self, containers: ::flatbuffers::WIPOffset<::flatbuffers::Vector<'b , ::flatbuffers::ForwardsUOffset<&'b  str>>>) {
-    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(MediaCapabilities::VT_CONTAINERS, containers);
+  pub fn add_container(&mut self, container: ::flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(HistoryEntry::VT_CONTAINER, container);
   }
   #[inline]
-  pub fn add_video_formats(&mut self, video_formats: ::flatbuffers::WIPOffset<::flatbuffers::Vector<'b , ::flatbuffers::ForwardsUOffset<&'b  str>>>) {
-    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(MediaCapabilities::VT_VIDEO_FORMATS, video_formats);
+  pub fn add_title(&mut self, title: ::flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(HistoryEntry::VT_TITLE, title);
   }
   #[inline]
-  pub fn add_audio_formats(&mut self, audio_formats: ::flatbuffers::WIPOffset<::flatbuffers::Vector<'b , ::flatbuffers::ForwardsUOffset<&'b  str>>>) {
-    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(MediaCapabilities::VT_AUDIO_FORMATS, audio_formats);
+  pub fn add_thumbnail_url(&mut self, thumbnail_url: ::flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(HistoryEntry::VT_THUMBNAIL_URL, thumbnail_url);
   }
   #[inline]
-  pub fn add_subtitle_formats(&mut self, subtitle_formats: ::flatbuffers::WIPOffset<::flatbuffers::Vector<'b , ::flatbuffers::ForwardsUOffset<&'b  str>>>) {
-    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(MediaCapabilities::VT_SUBTITLE_FORMATS, subtitle_formats);
+  pub fn add_watched(&mut self, watched: &Time) {
+    self.fbb_.push_slot_always::<&Time>(HistoryEntry::VT_WATCHED, watched);
+  }
+  #[inline]
+  pub fn add_duration(&mut self, duration: &Time) {
+    self.fbb_.push_slot_always::<&Time>(HistoryEntry::VT_DURATION, duration);
+  }
+  #[inline]
+  pub fn add_played_at(&mut self, played_at: u64) {
+    self.fbb_.push_slot::<u64>(HistoryEntry::VT_PLAYED_AT, played_at, 0);
+  }
+  #[inline]
+  pub fn add_sender_name(&mut self, sender_name: ::flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(HistoryEntry::VT_SENDER_NAME, sender_name);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> HistoryEntryBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    HistoryEntryBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> ::flatbuffers::WIPOffset<HistoryEntry<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, HistoryEntry::VT_URL,"url");
+    self.fbb_.required(o, HistoryEntry::VT_CONTAINER,"container");
+    ::flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl ::core::fmt::Debug for HistoryEntry<'_> {
+  fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+    let mut ds = f.debug_struct("HistoryEntry");
+      ds.field("url", &self.url());
+      ds.field("container", &self.container());
+      ds.field("title", &self.title());
+      ds.field("thumbnail_url", &self.thumbnail_url());
+      ds.field("watched", &self.watched());
+      ds.field("duration", &self.duration());
+      ds.field("played_at", &self.played_at());
+      ds.field("sender_name", &self.sender_name());
+      ds.finish()
+  }
+}
+pub enum PlaybackHistoryOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct PlaybackHistory<'a> {
+  pub _tab: ::flatbuffers::Table<'a>,
+}
+
+impl<'a> ::flatbuffers::Follow<'a> for PlaybackHistory<'a> {
+  type Inner = PlaybackHistory<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { ::flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> PlaybackHistory<'a> {
+  pub const VT_REQUEST_ID: ::flatbuffers::VOffsetT = 4;
+  pub const VT_ENTRIES: ::flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: ::flatbuffers::Table<'a>) -> Self {
+    PlaybackHistory { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: ::flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut ::flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args PlaybackHistoryArgs<'args>
+  ) -> ::flatbuffers::WIPOffset<PlaybackHistory<'bldr>> {
+    let mut builder = PlaybackHistoryBuilder::new(_fbb);
+    if let Some(x) = args.entries { builder.add_entries(x); }
+    builder.add_request_id(args.request_id);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn request_id(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(PlaybackHistory::VT_REQUEST_ID, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn entries(&self) -> Option<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<HistoryEntry<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<HistoryEntry>>>>(PlaybackHistory::VT_ENTRIES, None)}
   }
+}
+
+impl ::flatbuffers::Verifiable for PlaybackHistory<'_> {
   #[inline]
-  pub fn add_hdr_formats(&mut self, hdr_formats: ::flatbuffers::WIPOffset<::flatbuffers::Vector<'b , ::flatbuffers::ForwardsUOffset<&'b  str>>>) {
-    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(MediaCapabilities::VT_HDR_FORMATS, hdr_formats);
+  fn run_verifier(
+    v: &mut ::flatbuffers::Verifier, pos: usize
+  ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
+    v.visit_table(pos)?
+     .visit_field::<u32>("request_id", Self::VT_REQUEST_ID, false)?
+     .visit_field::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'_, ::flatbuffers::ForwardsUOffset<HistoryEntry>>>>("entries", Self::VT_ENTRIES, false)?
+     .finish();
+    Ok(())
   }
+}
+pub struct PlaybackHistoryArgs<'a> {
+    pub request_id: u32,
+    pub entries: Option<::flatbuffers::WIPOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<HistoryEntry<'a>>>>>,
+}
+impl<'a> Default for PlaybackHistoryArgs<'a> {
   #[inline]
-  pub fn add_image_formats(&mut self, image_formats: ::flatbuffers::WIPOffset<::flatbuffers::Vector<'b , ::flatbuffers::ForwardsUOffset<&'b  str>>>) {
-    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(MediaCapabilities::VT_IMAGE_FORMATS, image_formats);
+  fn default() -> Self {
+    PlaybackHistoryArgs {
+      request_id: 0,
+      entries: None,
+    }
   }
+}
+
+pub struct PlaybackHistoryBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: ::flatbuffers::WIPOffset<::flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> PlaybackHistoryBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_external_subtitles(&mut self, external_subtitles: bool) {
-    self.fbb_.push_slot::<bool>(MediaCapabilities::VT_EXTERNAL_SUBTITLES, external_subtitles, false);
+  pub fn add_request_id(&mut self, request_id: u32) {
+    self.fbb_.push_slot::<u32>(PlaybackHistory::VT_REQUEST_ID, request_id, 0);
   }
   #[inline]
-  pub fn add_mirroring(&mut self, mirroring: bool) {
-    self.fbb_.push_slot::<bool>(MediaCapabilities::VT_MIRRORING, mirroring, false);
+  pub fn add_entries(&mut self, entries: ::flatbuffers::WIPOffset<::flatbuffers::Vector<'b , ::flatbuffers::ForwardsUOffset<HistoryEntry<'b >>>>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(PlaybackHistory::VT_ENTRIES, entries);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> MediaCapabilitiesBuilder<'a, 'b, A> {
+  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> PlaybackHistoryBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    MediaCapabilitiesBuilder {
+    PlaybackHistoryBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> ::flatbuffers::WIPOffset<MediaCapabilities<'a>> {
+  pub fn finish(self) -> ::flatbuffers::WIPOffset<PlaybackHistory<'a>> {
     let o = self.fbb_.end_table(self.start_);
     ::flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl ::core::fmt::Debug for MediaCapabilities<'_> {
+impl ::core::fmt::Debug for PlaybackHistory<'_> {
   fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-    let mut ds = f.debug_struct("MediaCapabilities");
-      ds.field("protocols", &self.protocols());
-      ds.field("containers", &self.containers());
-      ds.field("video_formats", &self.video_formats());
-      ds.field("audio_formats", &self.audio_formats());
-      ds.field("subtitle_formats", &self.subtitle_formats());
-      ds.field("hdr_formats", &self.hdr_formats());
-      ds.field("image_formats", &self.image_formats());
-      ds.field("external_subtitles", &self.external_subtitles());
-      ds.field("mirroring", &self.mirroring());
+    let mut ds = f.debug_struct("PlaybackHistory");
+      ds.field("request_id", &self.request_id());
+      ds.field("entries", &self.entries());
       ds.finish()
   }
 }
-pub enum AudioCapabilitiesOffset {}
+pub enum SubtitleStyleChangedOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct AudioCapabilities<'a> {
+pub struct SubtitleStyleChanged<'a> {
   pub _tab: ::flatbuffers::Table<'a>,
 }
 
-impl<'a> ::flatbuffers::Follow<'a> for AudioCapabilities<'a> {
-  type Inner = AudioCapabilities<'a>;
+impl<'a> ::flatbuffers::Follow<'a> for SubtitleStyleChanged<'a> {
+  type Inner = SubtitleStyleChanged<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: unsafe { ::flatbuffers::Table::new(buf, loc) } }
   }
 }
 
-impl<'a> AudioCapabilities<'a> {
-  pub const VT_VOLUME_STEP_INTERVAL: ::flatbuffers::VOffsetT = 4;
+impl<'a> SubtitleStyleChanged<'a> {
+  pub const VT_SIZE: ::flatbuffers::VOffsetT = 4;
+  pub const VT_POSITION: ::flatbuffers::VOffsetT = 6;
+  pub const VT_BACKGROUND: ::flatbuffers::VOffsetT = 8;
 
   #[inline]
   pub unsafe fn init_from_table(table: ::flatbuffers::Table<'a>) -> Self {
-    AudioCapabilities { _tab: table }
+    SubtitleStyleChanged { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: ::flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut ::flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args AudioCapabilitiesArgs
-  ) -> ::flatbuffers::WIPOffset<AudioCapabilities<'bldr>> {
-    let mut builder = AudioCapabilitiesBuilder::new(_fbb);
-    builder.add_volume_step_interval(args.volume_step_interval);
+    args: &'args SubtitleStyleChangedArgs
+  ) -> ::flatbuffers::WIPOffset<SubtitleStyleChanged<'bldr>> {
+    let mut builder = SubtitleStyleChangedBuilder::new(_fbb);
+    if let Some(x) = args.position { builder.add_position(x); }
+    if let Some(x) = args.size { builder.add_size(x); }
+    if let Some(x) = args.background { builder.add_background(x); }
     builder.finish()
   }
 
 
   #[inline]
-  pub fn volume_step_interval(&self) -> f32 {
+  pub fn size(&self) -> Option<f32> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<f32>(AudioCapabilities::VT_VOLUME_STEP_INTERVAL, Some(0.0)).unwrap()}
+    unsafe { self._tab.get::<f32>(SubtitleStyleChanged::VT_SIZE, None)}
+  }
+  #[inline]
+  pub fn position(&self) -> Option<f32> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(SubtitleStyleChanged::VT_POSITION, None)}
+  }
+  #[inline]
+  pub fn background(&self) -> Option<bool> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(SubtitleStyleChanged::VT_BACKGROUND, None)}
   }
 }
 
-impl ::flatbuffers::Verifiable for AudioCapabilities<'_> {
+impl ::flatbuffers::Verifiable for SubtitleStyleChanged<'_> {
   #[inline]
   fn run_verifier(
     v: &mut ::flatbuffers::Verifier, pos: usize
   ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
     v.visit_table(pos)?
-     .visit_field::<f32>("volume_step_interval", Self::VT_VOLUME_STEP_INTERVAL, false)?
+     .visit_field::<f32>("size", Self::VT_SIZE, false)?
+     .visit_field::<f32>("position", Self::VT_POSITION, false)?
+     .visit_field::<bool>("background", Self::VT_BACKGROUND, false)?
      .finish();
     Ok(())
   }
 }
-pub struct AudioCapabilitiesArgs {
-    pub volume_step_interval: f32,
+pub struct SubtitleStyleChangedArgs {
+    pub size: Option<f32>,
+    pub position: Option<f32>,
+    pub background: Option<bool>,
 }
-impl<'a> Default for AudioCapabilitiesArgs {
+impl<'a> Default for SubtitleStyleChangedArgs {
   #[inline]
   fn default() -> Self {
-    AudioCapabilitiesArgs {
-      volume_step_interval: 0.0,
+    SubtitleStyleChangedArgs {
+      size: None,
+      position: None,
+      background: None,
     }
   }
 }
 
-pub struct AudioCapabilitiesBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
+pub struct SubtitleStyleChangedBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
   fbb_: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>,
   start_: ::flatbuffers::WIPOffset<::flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> AudioCapabilitiesBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> SubtitleStyleChangedBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_volume_step_interval(&mut self, volume_step_interval: f32) {
-    self.fbb_.push_slot::<f32>(AudioCapabilities::VT_VOLUME_STEP_INTERVAL, volume_step_interval, 0.0);
+  pub fn add_size(&mut self, size: f32) {
+    self.fbb_.push_slot_always::<f32>(SubtitleStyleChanged::VT_SIZE, size);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> AudioCapabilitiesBuilder<'a, 'b, A> {
+  pub fn add_position(&mut self, position: f32) {
+    self.fbb_.push_slot_always::<f32>(SubtitleStyleChanged::VT_POSITION, position);
+  }
+  #[inline]
+  pub fn add_background(&mut self, background: bool) {
+    self.fbb_.push_slot_always::<bool>(SubtitleStyleChanged::VT_BACKGROUND, background);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> SubtitleStyleChangedBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    AudioCapabilitiesBuilder {
+    SubtitleStyleChangedBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> ::flatbuffers::WIPOffset<AudioCapabilities<'a>> {
+  pub fn finish(self) -> ::flatbuffers::WIPOffset<SubtitleStyleChanged<'a>> {
     let o = self.fbb_.end_table(self.start_);
     ::flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl ::core::fmt::Debug for AudioCapabilities<'_> {
+impl ::core::fmt::Debug for SubtitleStyleChanged<'_> {
   fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-    let mut ds = f.debug_struct("AudioCapabilities");
-      ds.field("volume_step_interval", &self.volume_step_interval());
+    let mut ds = f.debug_struct("SubtitleStyleChanged");
+      ds.field("size", &self.size());
+      ds.field("position", &self.position());
+      ds.field("background", &self.background());
       ds.finish()
   }
 }
-pub enum ReceiverCapabilitiesOffset {}
+pub enum DelayChangedOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct ReceiverCapabilities<'a> {
+pub struct DelayChanged<'a> {
   pub _tab: ::flatbuffers::Table<'a>,
 }
 
-impl<'a> ::flatbuffers::Follow<'a> for ReceiverCapabilities<'a> {
-  type Inner = ReceiverCapabilities<'a>;
+impl<'a> ::flatbuffers::Follow<'a> for DelayChanged<'a> {
+  type Inner = DelayChanged<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: unsafe { ::flatbuffers::Table::new(buf, loc) } }
   }
 }
 
-impl<'a> ReceiverCapabilities<'a> {
-  pub const VT_MEDIA: ::flatbuffers::VOffsetT = 4;
-  pub const VT_DISPLAY: ::flatbuffers::VOffsetT = 6;
-  pub const VT_AUDIO: ::flatbuffers::VOffsetT = 8;
+impl<'a> DelayChanged<'a> {
+  pub const VT_SUBTITLE_MICROS: ::flatbuffers::VOffsetT = 4;
+  pub const VT_AUDIO_MICROS: ::flatbuffers::VOffsetT = 6;
 
   #[inline]
   pub unsafe fn init_from_table(table: ::flatbuffers::Table<'a>) -> Self {
-    ReceiverCapabilities { _tab: table }
+    DelayChanged { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: ::flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut ::flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args ReceiverCapabilitiesArgs<'args>
-  ) -> ::flatbuffers::WIPOffset<ReceiverCapabilities<'bldr>> {
-    let mut builder = ReceiverCapabilitiesBuilder::new(_fbb);
-    if let Some(x) = args.audio { builder.add_audio(x); }
-    if let Some(x) = args.display { builder.add_display(x); }
-    if let Some(x) = args.media { builder.add_media(x); }
+    args: &'args DelayChangedArgs
+  ) -> ::flatbuffers::WIPOffset<DelayChanged<'bldr>> {
+    let mut builder = DelayChangedBuilder::new(_fbb);
+    if let Some(x) = args.audio_micros { builder.add_audio_micros(x); }
+    if let Some(x) = args.subtitle_micros { builder.add_subtitle_micros(x); }
     builder.finish()
   }
 
 
   #[inline]
-  pub fn media(&self) -> Option<MediaCapabilities<'a>> {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<MediaCapabilities>>(ReceiverCapabilities::VT_MEDIA, None)}
-  }
-  #[inline]
-  pub fn display(&self) -> Option<DisplayCapabilities<'a>> {
+  pub fn subtitle_micros(&self) -> Option<i64> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<DisplayCapabilities>>(ReceiverCapabilities::VT_DISPLAY, None)}
+    unsafe { self._tab.get::<i64>(DelayChanged::VT_SUBTITLE_MICROS, None)}
   }
   #[inline]
-  pub fn audio(&self) -> Option<AudioCapabilities<'a>> {
+  pub fn audio_micros(&self) -> Option<i64> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<AudioCapabilities>>(ReceiverCapabilities::VT_AUDIO, None)}
+    unsafe { self._tab.get::<i64>(DelayChanged::VT_AUDIO_MICROS, None)}
   }
 }
 
-impl ::flatbuffers::Verifiable for ReceiverCapabilities<'_> {
+impl ::flatbuffers::Verifiable for DelayChanged<'_> {
   #[inline]
   fn run_verifier(
     v: &mut ::flatbuffers::Verifier, pos: usize
   ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
     v.visit_table(pos)?
-     .visit_field::<::flatbuffers::ForwardsUOffset<MediaCapabilities>>("media", Self::VT_MEDIA, false)?
-     .visit_field::<::flatbuffers::ForwardsUOffset<DisplayCapabilities>>("display", Self::VT_DISPLAY, false)?
-     .visit_field::<::flatbuffers::ForwardsUOffset<AudioCapabilities>>("audio", Self::VT_AUDIO, false)?
+     .visit_field::<i64>("subtitle_micros", Self::VT_SUBTITLE_MICROS, false)?
+     .visit_field::<i64>("audio_micros", Self::VT_AUDIO_MICROS, false)?
      .finish();
     Ok(())
   }
 }
-pub struct ReceiverCapabilitiesArgs<'a> {
-    pub media: Option<::flatbuffers::WIPOffset<MediaCapabilities<'a>>>,
-    pub display: Option<::flatbuffers::WIPOffset<DisplayCapabilities<'a>>>,
-    pub audio: Option<::flatbuffers::WIPOffset<AudioCapabilities<'a>>>,
+pub struct DelayChangedArgs {
+    pub subtitle_micros: Option<i64>,
+    pub audio_micros: Option<i64>,
 }
-impl<'a> Default for ReceiverCapabilitiesArgs<'a> {
+impl<'a> Default for DelayChangedArgs {
   #[inline]
   fn default() -> Self {
-    ReceiverCapabilitiesArgs {
-      media: None,
-      display: None,
-      audio: None,
+    DelayChangedArgs {
+      subtitle_micros: None,
+      audio_micros: None,
     }
   }
 }
 
-pub struct ReceiverCapabilitiesBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
+pub struct DelayChangedBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
   fbb_: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>,
   start_: ::flatbuffers::WIPOffset<::flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> ReceiverCapabilitiesBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> DelayChangedBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_media(&mut self, media: ::flatbuffers::WIPOffset<MediaCapabilities<'b >>) {
-    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<MediaCapabilities>>(ReceiverCapabilities::VT_MEDIA, media);
-  }
-  #[inline]
-  pub fn add_display(&mut self, display: ::flatbuffers::WIPOffset<DisplayCapabilities<'b >>) {
-    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<DisplayCapabilities>>(ReceiverCapabilities::VT_DISPLAY, display);
+  pub fn add_subtitle_micros(&mut self, subtitle_micros: i64) {
+    self.fbb_.push_slot_always::<i64>(DelayChanged::VT_SUBTITLE_MICROS, subtitle_micros);
   }
   #[inline]
-  pub fn add_audio(&mut self, audio: ::flatbuffers::WIPOffset<AudioCapabilities<'b >>) {
-    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<AudioCapabilities>>(ReceiverCapabilities::VT_AUDIO, audio);
+  pub fn add_audio_micros(&mut self, audio_micros: i64) {
+    self.fbb_.push_slot_always::<i64>(DelayChanged::VT_AUDIO_MICROS, audio_micros);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> ReceiverCapabilitiesBuilder<'a, 'b, A> {
+  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> DelayChangedBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    ReceiverCapabilitiesBuilder {
+    DelayChangedBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> ::flatbuffers::WIPOffset<ReceiverCapabilities<'a>> {
+  pub fn finish(self) -> ::flatbuffers::WIPOffset<DelayChanged<'a>> {
     let o = self.fbb_.end_table(self.start_);
     ::flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl ::core::fmt::Debug for ReceiverCapabilities<'_> {
+impl ::core::fmt::Debug for DelayChanged<'_> {
   fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-    let mut ds = f.debug_struct("ReceiverCapabilities");
-      ds.field("media", &self.media());
-      ds.field("display", &self.display());
-      ds.field("audio", &self.audio());
+    let mut ds = f.debug_struct("DelayChanged");
+      ds.field("subtitle_micros", &self.subtitle_micros());
+      ds.field("audio_micros", &self.audio_micros());
       ds.finish()
   }
 }
-pub enum MediaTrackOffset {}
+pub enum QueueItemSelectedOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct MediaTrack<'a> {
+pub struct QueueItemSelected<'a> {
   pub _tab: ::flatbuffers::Table<'a>,
 }
 
-impl<'a> ::flatbuffers::Follow<'a> for MediaTrack<'a> {
-  type Inner = MediaTrack<'a>;
+impl<'a> ::flatbuffers::Follow<'a> for QueueItemSelected<'a> {
+  type Inner = QueueItemSelected<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: unsafe { ::flatbuffers::Table::new(buf, loc) } }
   }
 }
 
-impl<'a> MediaTrack<'a> {
-  pub const VT_ID: ::flatbuffers::VOffsetT = 4;
-  pub const VT_ISO_639: ::flatbuffers::VOffsetT = 6;
-  pub const VT_TITLE: ::flatbuffers::VOffsetT = 8;
-  pub const VT_METADATA_TYPE: ::flatbuffers::VOffsetT = 10;
-  pub const VT_METADATA: ::flatbuffers::VOffsetT = 12;
+impl<'a> QueueItemSelected<'a> {
+  pub const VT_POSITION_TYPE: ::flatbuffers::VOffsetT = 4;
+  pub const VT_POSITION: ::flatbuffers::VOffsetT = 6;
 
   #[inline]
   pub unsafe fn init_from_table(table: ::flatbuffers::Table<'a>) -> Self {
-    MediaTrack { _tab: table }
+    QueueItemSelected { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: ::flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut ::flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args MediaTrackArgs<'args>
-  ) -> ::flatbuffers::WIPOffset<MediaTrack<'bldr>> {
-    let mut builder = MediaTrackBuilder::new(_fbb);
-    if let Some(x) = args.metadata { builder.add_metadata(x); }
-    if let Some(x) = args.title { builder.add_title(x); }
-    if let Some(x) = args.iso_639 { builder.add_iso_639(x); }
-    builder.add_id(args.id);
-    builder.add_metadata_type(args.metadata_type);
+    args: &'args QueueItemSelectedArgs
+  ) -> ::flatbuffers::WIPOffset<QueueItemSelected<'bldr>> {
+    let mut builder = QueueItemSelectedBuilder::new(_fbb);
+    if let Some(x) = args.position { builder.add_position(x); }
+    builder.add_position_type(args.position_type);
     builder.finish()
   }
 
 
   #[inline]
-  pub fn id(&self) -> u32 {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<u32>(MediaTrack::VT_ID, Some(0)).unwrap()}
-  }
-  #[inline]
-  pub fn iso_639(&self) -> &'a str {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<&str>>(MediaTrack::VT_ISO_639, None).unwrap()}
-  }
-  #[inline]
-  pub fn title(&self) -> Option<&'a str> {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<&str>>(MediaTrack::VT_TITLE, None)}
-  }
-  #[inline]
-  pub fn metadata_type(&self) -> MediaTrackMetadata {
+  pub fn position_type(&self) -> QueuePosition {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<MediaTrackMetadata>(MediaTrack::VT_METADATA_TYPE, Some(MediaTrackMetadata::NONE)).unwrap()}
+    unsafe { self._tab.get::<QueuePosition>(QueueItemSelected::VT_POSITION_TYPE, Some(QueuePosition::NONE)).unwrap()}
   }
   #[inline]
-  pub fn metadata(&self) -> Option<::flatbuffers::Table<'a>> {
+  pub fn position(&self) -> ::flatbuffers::Table<'a> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<::flatbuffers::Table<'a>>>(MediaTrack::VT_METADATA, None)}
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<::flatbuffers::Table<'a>>>(QueueItemSelected::VT_POSITION, None).unwrap()}
   }
   #[inline]
   #[allow(non_snake_case)]
-  pub fn metadata_as_video(&self) -> Option<VideoTrackMeta<'a>> {
-    if self.metadata_type() == MediaTrackMetadata::Video {
-      self.metadata().map(|t| {
-       // Safety:
-       // Created from a valid Table for this object
-       // Which contains a valid union in this slot
-       unsafe { VideoTrackMeta::init_from_table(t) }
-     })
+  pub fn position_as_index(&self) -> Option<QueueIndex<'a>> {
+    if self.position_type() == QueuePosition::Index {
+      let u = self.position();
+      // Safety:
+      // Created from a valid Table for this object
+      // Which contains a valid union in this slot
+      Some(unsafe { QueueIndex::init_from_table(u) })
     } else {
       None
     }
@@ -5266,29 +8370,27 @@ impl<'a> MediaTrack<'a> {
 
   #[inline]
   #[allow(non_snake_case)]
-  pub fn metadata_as_audio(&self) -> Option<AudioTrackMeta<'a>> {
-    if self.metadata_type() == MediaTrackMetadata::Audio {
-      self.metadata().map(|t| {
-       // Safety:
-       // Created from a valid Table for this object
-       // Which contains a valid union in this slot
-       unsafe { AudioTrackMeta::init_from_table(t) }
-     })
+  pub fn position_as_front(&self) -> Option<QueueMarkerFront<'a>> {
+    if self.position_type() == QueuePosition::Front {
+      let u = self.position();
+      // Safety:
+      // Created from a valid Table for this object
+      // Which contains a valid union in this slot
+      Some(unsafe { QueueMarkerFront::init_from_table(u) })
     } else {
       None
-    }
-  }
-
-  #[inline]
-  #[allow(non_snake_case)]
-  pub fn metadata_as_subtitle(&self) -> Option<SubtitleTrackMeta<'a>> {
-    if self.metadata_type() == MediaTrackMetadata::Subtitle {
-      self.metadata().map(|t| {
-       // Safety:
-       // Created from a valid Table for this object
-       // Which contains a valid union in this slot
-       unsafe { SubtitleTrackMeta::init_from_table(t) }
-     })
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn position_as_back(&self) -> Option<QueueMarkerBack<'a>> {
+    if self.position_type() == QueuePosition::Back {
+      let u = self.position();
+      // Safety:
+      // Created from a valid Table for this object
+      // Which contains a valid union in this slot
+      Some(unsafe { QueueMarkerBack::init_from_table(u) })
     } else {
       None
     }
@@ -5296,20 +8398,17 @@ impl<'a> MediaTrack<'a> {
 
 }
 
-impl ::flatbuffers::Verifiable for MediaTrack<'_> {
+impl ::flatbuffers::Verifiable for QueueItemSelected<'_> {
   #[inline]
   fn run_verifier(
     v: &mut ::flatbuffers::Verifier, pos: usize
   ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
     v.visit_table(pos)?
-     .visit_field::<u32>("id", Self::VT_ID, false)?
-     .visit_field::<::flatbuffers::ForwardsUOffset<&str>>("iso_639", Self::VT_ISO_639, true)?
-     .visit_field::<::flatbuffers::ForwardsUOffset<&str>>("title", Self::VT_TITLE, false)?
-     .visit_union::<MediaTrackMetadata, _>("metadata_type", Self::VT_METADATA_TYPE, "metadata", Self::VT_METADATA, false, |key, v, pos| {
+     .visit_union::<QueuePosition, _>("position_type", Self::VT_POSITION_TYPE, "position", Self::VT_POSITION, true, |key, v, pos| {
         match key {
-          MediaTrackMetadata::Video => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<VideoTrackMeta>>("MediaTrackMetadata::Video", pos),
-          MediaTrackMetadata::Audio => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<AudioTrackMeta>>("MediaTrackMetadata::Audio", pos),
-          MediaTrackMetadata::Subtitle => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<SubtitleTrackMeta>>("MediaTrackMetadata::Subtitle", pos),
+          QueuePosition::Index => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<QueueIndex>>("QueuePosition::Index", pos),
+          QueuePosition::Front => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<QueueMarkerFront>>("QueuePosition::Front", pos),
+          QueuePosition::Back => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<QueueMarkerBack>>("QueuePosition::Back", pos),
           _ => Ok(()),
         }
      })?
@@ -5317,1382 +8416,1146 @@ impl ::flatbuffers::Verifiable for MediaTrack<'_> {
     Ok(())
   }
 }
-pub struct MediaTrackArgs<'a> {
-    pub id: u32,
-    pub iso_639: Option<::flatbuffers::WIPOffset<&'a str>>,
-    pub title: Option<::flatbuffers::WIPOffset<&'a str>>,
-    pub metadata_type: MediaTrackMetadata,
-    pub metadata: Option<::flatbuffers::WIPOffset<::flatbuffers::UnionWIPOffset>>,
+pub struct QueueItemSelectedArgs {
+    pub position_type: QueuePosition,
+    pub position: Option<::flatbuffers::WIPOffset<::flatbuffers::UnionWIPOffset>>,
 }
-impl<'a> Default for MediaTrackArgs<'a> {
+impl<'a> Default for QueueItemSelectedArgs {
   #[inline]
   fn default() -> Self {
-    MediaTrackArgs {
-      id: 0,
-      iso_639: None, // required field
-      title: None,
-      metadata_type: MediaTrackMetadata::NONE,
-      metadata: None,
+    QueueItemSelectedArgs {
+      position_type: QueuePosition::NONE,
+      position: None, // required field
     }
   }
 }
 
-pub struct MediaTrackBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
+pub struct QueueItemSelectedBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
   fbb_: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>,
   start_: ::flatbuffers::WIPOffset<::flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> MediaTrackBuilder<'a, 'b, A> {
-  #[inline]
-  pub fn add_id(&mut self, id: u32) {
-    self.fbb_.push_slot::<u32>(MediaTrack::VT_ID, id, 0);
-  }
-  #[inline]
-  pub fn add_iso_639(&mut self, iso_639: ::flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(MediaTrack::VT_ISO_639, iso_639);
-  }
-  #[inline]
-  pub fn add_title(&mut self, title: ::flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(MediaTrack::VT_TITLE, title);
-  }
+impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> QueueItemSelectedBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_metadata_type(&mut self, metadata_type: MediaTrackMetadata) {
-    self.fbb_.push_slot::<MediaTrackMetadata>(MediaTrack::VT_METADATA_TYPE, metadata_type, MediaTrackMetadata::NONE);
+  pub fn add_position_type(&mut self, position_type: QueuePosition) {
+    self.fbb_.push_slot::<QueuePosition>(QueueItemSelected::VT_POSITION_TYPE, position_type, QueuePosition::NONE);
   }
   #[inline]
-  pub fn add_metadata(&mut self, metadata: ::flatbuffers::WIPOffset<::flatbuffers::UnionWIPOffset>) {
-    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(MediaTrack::VT_METADATA, metadata);
+  pub fn add_position(&mut self, position: ::flatbuffers::WIPOffset<::flatbuffers::UnionWIPOffset>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(QueueItemSelected::VT_POSITION, position);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> MediaTrackBuilder<'a, 'b, A> {
+  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> QueueItemSelectedBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    MediaTrackBuilder {
+    QueueItemSelectedBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> ::flatbuffers::WIPOffset<MediaTrack<'a>> {
+  pub fn finish(self) -> ::flatbuffers::WIPOffset<QueueItemSelected<'a>> {
     let o = self.fbb_.end_table(self.start_);
-    self.fbb_.required(o, MediaTrack::VT_ISO_639,"iso_639");
+    self.fbb_.required(o, QueueItemSelected::VT_POSITION,"position");
     ::flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl ::core::fmt::Debug for MediaTrack<'_> {
+impl ::core::fmt::Debug for QueueItemSelected<'_> {
   fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-    let mut ds = f.debug_struct("MediaTrack");
-      ds.field("id", &self.id());
-      ds.field("iso_639", &self.iso_639());
-      ds.field("title", &self.title());
-      ds.field("metadata_type", &self.metadata_type());
-      match self.metadata_type() {
-        MediaTrackMetadata::Video => {
-          if let Some(x) = self.metadata_as_video() {
-            ds.field("metadata", &x)
+    let mut ds = f.debug_struct("QueueItemSelected");
+      ds.field("position_type", &self.position_type());
+      match self.position_type() {
+        QueuePosition::Index => {
+          if let Some(x) = self.position_as_index() {
+            ds.field("position", &x)
           } else {
-            ds.field("metadata", &"InvalidFlatbuffer: Union discriminant does not match value.")
+            ds.field("position", &"InvalidFlatbuffer: Union discriminant does not match value.")
           }
         },
-        MediaTrackMetadata::Audio => {
-          if let Some(x) = self.metadata_as_audio() {
-            ds.field("metadata", &x)
+        QueuePosition::Front => {
+          if let Some(x) = self.position_as_front() {
+            ds.field("position", &x)
           } else {
-            ds.field("metadata", &"InvalidFlatbuffer: Union discriminant does not match value.")
+            ds.field("position", &"InvalidFlatbuffer: Union discriminant does not match value.")
           }
         },
-        MediaTrackMetadata::Subtitle => {
-          if let Some(x) = self.metadata_as_subtitle() {
-            ds.field("metadata", &x)
+        QueuePosition::Back => {
+          if let Some(x) = self.position_as_back() {
+            ds.field("position", &x)
           } else {
-            ds.field("metadata", &"InvalidFlatbuffer: Union discriminant does not match value.")
+            ds.field("position", &"InvalidFlatbuffer: Union discriminant does not match value.")
           }
         },
         _ => {
           let x: Option<()> = None;
-          ds.field("metadata", &x)
+          ds.field("position", &x)
         },
       };
       ds.finish()
   }
 }
-pub enum ProgressChangedOffset {}
+pub enum TracksAvailableOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct ProgressChanged<'a> {
+pub struct TracksAvailable<'a> {
   pub _tab: ::flatbuffers::Table<'a>,
 }
 
-impl<'a> ::flatbuffers::Follow<'a> for ProgressChanged<'a> {
-  type Inner = ProgressChanged<'a>;
+impl<'a> ::flatbuffers::Follow<'a> for TracksAvailable<'a> {
+  type Inner = TracksAvailable<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: unsafe { ::flatbuffers::Table::new(buf, loc) } }
   }
 }
 
-impl<'a> ProgressChanged<'a> {
-  pub const VT_POSITION: ::flatbuffers::VOffsetT = 4;
-  pub const VT_DURATION: ::flatbuffers::VOffsetT = 6;
+impl<'a> TracksAvailable<'a> {
+  pub const VT_TRACKS: ::flatbuffers::VOffsetT = 4;
 
   #[inline]
   pub unsafe fn init_from_table(table: ::flatbuffers::Table<'a>) -> Self {
-    ProgressChanged { _tab: table }
+    TracksAvailable { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: ::flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut ::flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args ProgressChangedArgs<'args>
-  ) -> ::flatbuffers::WIPOffset<ProgressChanged<'bldr>> {
-    let mut builder = ProgressChangedBuilder::new(_fbb);
-    if let Some(x) = args.duration { builder.add_duration(x); }
-    if let Some(x) = args.position { builder.add_position(x); }
+    args: &'args TracksAvailableArgs<'args>
+  ) -> ::flatbuffers::WIPOffset<TracksAvailable<'bldr>> {
+    let mut builder = TracksAvailableBuilder::new(_fbb);
+    if let Some(x) = args.tracks { builder.add_tracks(x); }
     builder.finish()
   }
 
 
   #[inline]
-  pub fn position(&self) -> Option<&'a Time> {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<Time>(ProgressChanged::VT_POSITION, None)}
-  }
-  #[inline]
-  pub fn duration(&self) -> Option<&'a Time> {
+  pub fn tracks(&self) -> Option<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<MediaTrack<'a>>>> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<Time>(ProgressChanged::VT_DURATION, None)}
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<MediaTrack>>>>(TracksAvailable::VT_TRACKS, None)}
   }
 }
 
-impl ::flatbuffers::Verifiable for ProgressChanged<'_> {
+impl ::flatbuffers::Verifiable for TracksAvailable<'_> {
   #[inline]
   fn run_verifier(
     v: &mut ::flatbuffers::Verifier, pos: usize
   ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
     v.visit_table(pos)?
-     .visit_field::<Time>("position", Self::VT_POSITION, false)?
-     .visit_field::<Time>("duration", Self::VT_DURATION, false)?
+     .visit_field::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'_, ::flatbuffers::ForwardsUOffset<MediaTrack>>>>("tracks", Self::VT_TRACKS, false)?
      .finish();
     Ok(())
   }
 }
-pub struct ProgressChangedArgs<'a> {
-    pub position: Option<&'a Time>,
-    pub duration: Option<&'a Time>,
+pub struct TracksAvailableArgs<'a> {
+    pub tracks: Option<::flatbuffers::WIPOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<MediaTrack<'a>>>>>,
 }
-impl<'a> Default for ProgressChangedArgs<'a> {
+impl<'a> Default for TracksAvailableArgs<'a> {
   #[inline]
   fn default() -> Self {
-    ProgressChangedArgs {
-      position: None,
-      duration: None,
+    TracksAvailableArgs {
+      tracks: None,
     }
   }
 }
 
-pub struct ProgressChangedBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
+pub struct TracksAvailableBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
   fbb_: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>,
   start_: ::flatbuffers::WIPOffset<::flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> ProgressChangedBuilder<'a, 'b, A> {
-  #[inline]
-  pub fn add_position(&mut self, position: &Time) {
-    self.fbb_.push_slot_always::<&Time>(ProgressChanged::VT_POSITION, position);
-  }
+impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> TracksAvailableBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_duration(&mut self, duration: &Time) {
-    self.fbb_.push_slot_always::<&Time>(ProgressChanged::VT_DURATION, duration);
+  pub fn add_tracks(&mut self, tracks: ::flatbuffers::WIPOffset<::flatbuffers::Vector<'b , ::flatbuffers::ForwardsUOffset<MediaTrack<'b >>>>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(TracksAvailable::VT_TRACKS, tracks);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> ProgressChangedBuilder<'a, 'b, A> {
+  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> TracksAvailableBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    ProgressChangedBuilder {
+    TracksAvailableBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> ::flatbuffers::WIPOffset<ProgressChanged<'a>> {
+  pub fn finish(self) -> ::flatbuffers::WIPOffset<TracksAvailable<'a>> {
     let o = self.fbb_.end_table(self.start_);
     ::flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl ::core::fmt::Debug for ProgressChanged<'_> {
+impl ::core::fmt::Debug for TracksAvailable<'_> {
   fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-    let mut ds = f.debug_struct("ProgressChanged");
-      ds.field("position", &self.position());
-      ds.field("duration", &self.duration());
+    let mut ds = f.debug_struct("TracksAvailable");
+      ds.field("tracks", &self.tracks());
       ds.finish()
   }
 }
-pub enum LoadOffset {}
+pub enum ChangeTrackOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct Load<'a> {
+pub struct ChangeTrack<'a> {
   pub _tab: ::flatbuffers::Table<'a>,
 }
 
-impl<'a> ::flatbuffers::Follow<'a> for Load<'a> {
-  type Inner = Load<'a>;
+impl<'a> ::flatbuffers::Follow<'a> for ChangeTrack<'a> {
+  type Inner = ChangeTrack<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: unsafe { ::flatbuffers::Table::new(buf, loc) } }
   }
 }
 
-impl<'a> Load<'a> {
-  pub const VT_SOURCE_TYPE: ::flatbuffers::VOffsetT = 4;
-  pub const VT_SOURCE: ::flatbuffers::VOffsetT = 6;
+impl<'a> ChangeTrack<'a> {
+  pub const VT_ID: ::flatbuffers::VOffsetT = 4;
+  pub const VT_TRACK_TYPE: ::flatbuffers::VOffsetT = 6;
 
   #[inline]
   pub unsafe fn init_from_table(table: ::flatbuffers::Table<'a>) -> Self {
-    Load { _tab: table }
+    ChangeTrack { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: ::flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut ::flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args LoadArgs
-  ) -> ::flatbuffers::WIPOffset<Load<'bldr>> {
-    let mut builder = LoadBuilder::new(_fbb);
-    if let Some(x) = args.source { builder.add_source(x); }
-    builder.add_source_type(args.source_type);
+    args: &'args ChangeTrackArgs
+  ) -> ::flatbuffers::WIPOffset<ChangeTrack<'bldr>> {
+    let mut builder = ChangeTrackBuilder::new(_fbb);
+    if let Some(x) = args.id { builder.add_id(x); }
+    builder.add_track_type(args.track_type);
     builder.finish()
   }
 
 
   #[inline]
-  pub fn source_type(&self) -> MediaSource {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<MediaSource>(Load::VT_SOURCE_TYPE, Some(MediaSource::NONE)).unwrap()}
-  }
-  #[inline]
-  pub fn source(&self) -> ::flatbuffers::Table<'a> {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<::flatbuffers::Table<'a>>>(Load::VT_SOURCE, None).unwrap()}
-  }
-  #[inline]
-  #[allow(non_snake_case)]
-  pub fn source_as_single(&self) -> Option<MediaItem<'a>> {
-    if self.source_type() == MediaSource::Single {
-      let u = self.source();
-      // Safety:
-      // Created from a valid Table for this object
-      // Which contains a valid union in this slot
-      Some(unsafe { MediaItem::init_from_table(u) })
-    } else {
-      None
-    }
-  }
-
-  #[inline]
-  #[allow(non_snake_case)]
-  pub fn source_as_queue(&self) -> Option<Queue<'a>> {
-    if self.source_type() == MediaSource::Queue {
-      let u = self.source();
-      // Safety:
-      // Created from a valid Table for this object
-      // Which contains a valid union in this slot
-      Some(unsafe { Queue::init_from_table(u) })
-    } else {
-      None
-    }
+  pub fn id(&self) -> Option<u32> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(ChangeTrack::VT_ID, None)}
+  }
+  #[inline]
+  pub fn track_type(&self) -> MediaTrackType {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<MediaTrackType>(ChangeTrack::VT_TRACK_TYPE, Some(MediaTrackType::Video)).unwrap()}
   }
-
 }
 
-impl ::flatbuffers::Verifiable for Load<'_> {
+impl ::flatbuffers::Verifiable for ChangeTrack<'_> {
   #[inline]
   fn run_verifier(
     v: &mut ::flatbuffers::Verifier, pos: usize
   ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
     v.visit_table(pos)?
-     .visit_union::<MediaSource, _>("source_type", Self::VT_SOURCE_TYPE, "source", Self::VT_SOURCE, true, |key, v, pos| {
-        match key {
-          MediaSource::Single => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<MediaItem>>("MediaSource::Single", pos),
-          MediaSource::Queue => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<Queue>>("MediaSource::Queue", pos),
-          _ => Ok(()),
-        }
-     })?
+     .visit_field::<u32>("id", Self::VT_ID, false)?
+     .visit_field::<MediaTrackType>("track_type", Self::VT_TRACK_TYPE, false)?
      .finish();
     Ok(())
   }
 }
-pub struct LoadArgs {
-    pub source_type: MediaSource,
-    pub source: Option<::flatbuffers::WIPOffset<::flatbuffers::UnionWIPOffset>>,
+pub struct ChangeTrackArgs {
+    pub id: Option<u32>,
+    pub track_type: MediaTrackType,
 }
-impl<'a> Default for LoadArgs {
+impl<'a> Default for ChangeTrackArgs {
   #[inline]
   fn default() -> Self {
-    LoadArgs {
-      source_type: MediaSource::NONE,
-      source: None, // required field
+    ChangeTrackArgs {
+      id: None,
+      track_type: MediaTrackType::Video,
     }
   }
 }
 
-pub struct LoadBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
+pub struct ChangeTrackBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
   fbb_: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>,
   start_: ::flatbuffers::WIPOffset<::flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> LoadBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> ChangeTrackBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_source_type(&mut self, source_type: MediaSource) {
-    self.fbb_.push_slot::<MediaSource>(Load::VT_SOURCE_TYPE, source_type, MediaSource::NONE);
+  pub fn add_id(&mut self, id: u32) {
+    self.fbb_.push_slot_always::<u32>(ChangeTrack::VT_ID, id);
   }
   #[inline]
-  pub fn add_source(&mut self, source: ::flatbuffers::WIPOffset<::flatbuffers::UnionWIPOffset>) {
-    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(Load::VT_SOURCE, source);
+  pub fn add_track_type(&mut self, track_type: MediaTrackType) {
+    self.fbb_.push_slot::<MediaTrackType>(ChangeTrack::VT_TRACK_TYPE, track_type, MediaTrackType::Video);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> LoadBuilder<'a, 'b, A> {
+  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> ChangeTrackBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    LoadBuilder {
+    ChangeTrackBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> ::flatbuffers::WIPOffset<Load<'a>> {
+  pub fn finish(self) -> ::flatbuffers::WIPOffset<ChangeTrack<'a>> {
     let o = self.fbb_.end_table(self.start_);
-    self.fbb_.required(o, Load::VT_SOURCE,"source");
     ::flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl ::core::fmt::Debug for Load<'_> {
+impl ::core::fmt::Debug for ChangeTrack<'_> {
   fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-    let mut ds = f.debug_struct("Load");
-      ds.field("source_type", &self.source_type());
-      match self.source_type() {
-        MediaSource::Single => {
-          if let Some(x) = self.source_as_single() {
-            ds.field("source", &x)
-          } else {
-            ds.field("source", &"InvalidFlatbuffer: Union discriminant does not match value.")
-          }
-        },
-        MediaSource::Queue => {
-          if let Some(x) = self.source_as_queue() {
-            ds.field("source", &x)
-          } else {
-            ds.field("source", &"InvalidFlatbuffer: Union discriminant does not match value.")
-          }
-        },
-        _ => {
-          let x: Option<()> = None;
-          ds.field("source", &x)
-        },
-      };
+    let mut ds = f.debug_struct("ChangeTrack");
+      ds.field("id", &self.id());
+      ds.field("track_type", &self.track_type());
       ds.finish()
   }
 }
-pub enum QueueInsertOffset {}
+pub enum SenderIntroductionOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct QueueInsert<'a> {
+pub struct SenderIntroduction<'a> {
   pub _tab: ::flatbuffers::Table<'a>,
 }
 
-impl<'a> ::flatbuffers::Follow<'a> for QueueInsert<'a> {
-  type Inner = QueueInsert<'a>;
+impl<'a> ::flatbuffers::Follow<'a> for SenderIntroduction<'a> {
+  type Inner = SenderIntroduction<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: unsafe { ::flatbuffers::Table::new(buf, loc) } }
   }
 }
 
-impl<'a> QueueInsert<'a> {
-  pub const VT_ITEM: ::flatbuffers::VOffsetT = 4;
-  pub const VT_POSITION_TYPE: ::flatbuffers::VOffsetT = 6;
-  pub const VT_POSITION: ::flatbuffers::VOffsetT = 8;
+impl<'a> SenderIntroduction<'a> {
+  pub const VT_DEVICE_INFO: ::flatbuffers::VOffsetT = 4;
+  pub const VT_PAIRING_TOKEN: ::flatbuffers::VOffsetT = 6;
 
   #[inline]
   pub unsafe fn init_from_table(table: ::flatbuffers::Table<'a>) -> Self {
-    QueueInsert { _tab: table }
+    SenderIntroduction { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: ::flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut ::flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args QueueInsertArgs<'args>
-  ) -> ::flatbuffers::WIPOffset<QueueInsert<'bldr>> {
-    let mut builder = QueueInsertBuilder::new(_fbb);
-    if let Some(x) = args.position { builder.add_position(x); }
-    if let Some(x) = args.item { builder.add_item(x); }
-    builder.add_position_type(args.position_type);
+    args: &'args SenderIntroductionArgs<'args>
+  ) -> ::flatbuffers::WIPOffset<SenderIntroduction<'bldr>> {
+    let mut builder = SenderIntroductionBuilder::new(_fbb);
+    if let Some(x) = args.pairing_token { builder.add_pairing_token(x); }
+    if let Some(x) = args.device_info { builder.add_device_info(x); }
     builder.finish()
   }
 
 
   #[inline]
-  pub fn item(&self) -> QueueItem<'a> {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<QueueItem>>(QueueInsert::VT_ITEM, None).unwrap()}
-  }
-  #[inline]
-  pub fn position_type(&self) -> QueuePosition {
+  pub fn device_info(&self) -> DeviceInfo<'a> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<QueuePosition>(QueueInsert::VT_POSITION_TYPE, Some(QueuePosition::NONE)).unwrap()}
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<DeviceInfo>>(SenderIntroduction::VT_DEVICE_INFO, None).unwrap()}
   }
   #[inline]
-  pub fn position(&self) -> ::flatbuffers::Table<'a> {
+  pub fn pairing_token(&self) -> Option<&'a str> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<::flatbuffers::Table<'a>>>(QueueInsert::VT_POSITION, None).unwrap()}
-  }
-  #[inline]
-  #[allow(non_snake_case)]
-  pub fn position_as_index(&self) -> Option<QueueIndex<'a>> {
-    if self.position_type() == QueuePosition::Index {
-      let u = self.position();
-      // Safety:
-      // Created from a valid Table for this object
-      // Which contains a valid union in this slot
-      Some(unsafe { QueueIndex::init_from_table(u) })
-    } else {
-      None
-    }
-  }
-
-  #[inline]
-  #[allow(non_snake_case)]
-  pub fn position_as_front(&self) -> Option<QueueMarkerFront<'a>> {
-    if self.position_type() == QueuePosition::Front {
-      let u = self.position();
-      // Safety:
-      // Created from a valid Table for this object
-      // Which contains a valid union in this slot
-      Some(unsafe { QueueMarkerFront::init_from_table(u) })
-    } else {
-      None
-    }
-  }
-
-  #[inline]
-  #[allow(non_snake_case)]
-  pub fn position_as_back(&self) -> Option<QueueMarkerBack<'a>> {
-    if self.position_type() == QueuePosition::Back {
-      let u = self.position();
-      // Safety:
-      // Created from a valid Table for this object
-      // Which contains a valid union in this slot
-      Some(unsafe { QueueMarkerBack::init_from_table(u) })
-    } else {
-      None
-    }
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<&str>>(SenderIntroduction::VT_PAIRING_TOKEN, None)}
   }
-
 }
 
-impl ::flatbuffers::Verifiable for QueueInsert<'_> {
+impl ::flatbuffers::Verifiable for SenderIntroduction<'_> {
   #[inline]
   fn run_verifier(
     v: &mut ::flatbuffers::Verifier, pos: usize
   ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
     v.visit_table(pos)?
-     .visit_field::<::flatbuffers::ForwardsUOffset<QueueItem>>("item", Self::VT_ITEM, true)?
-     .visit_union::<QueuePosition, _>("position_type", Self::VT_POSITION_TYPE, "position", Self::VT_POSITION, true, |key, v, pos| {
-        match key {
-          QueuePosition::Index => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<QueueIndex>>("QueuePosition::Index", pos),
-          QueuePosition::Front => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<QueueMarkerFront>>("QueuePosition::Front", pos),
-          QueuePosition::Back => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<QueueMarkerBack>>("QueuePosition::Back", pos),
-          _ => Ok(()),
-        }
-     })?
+     .visit_field::<::flatbuffers::ForwardsUOffset<DeviceInfo>>("device_info", Self::VT_DEVICE_INFO, true)?
+     .visit_field::<::flatbuffers::ForwardsUOffset<&str>>("pairing_token", Self::VT_PAIRING_TOKEN, false)?
      .finish();
     Ok(())
   }
 }
-pub struct QueueInsertArgs<'a> {
-    pub item: Option<::flatbuffers::WIPOffset<QueueItem<'a>>>,
-    pub position_type: QueuePosition,
-    pub position: Option<::flatbuffers::WIPOffset<::flatbuffers::UnionWIPOffset>>,
+pub struct SenderIntroductionArgs<'a> {
+    pub device_info: Option<::flatbuffers::WIPOffset<DeviceInfo<'a>>>,
+    pub pairing_token: Option<::flatbuffers::WIPOffset<&'a str>>,
 }
-impl<'a> Default for QueueInsertArgs<'a> {
+impl<'a> Default for SenderIntroductionArgs<'a> {
   #[inline]
   fn default() -> Self {
-    QueueInsertArgs {
-      item: None, // required field
-      position_type: QueuePosition::NONE,
-      position: None, // required field
+    SenderIntroductionArgs {
+      device_info: None, // required field
+      pairing_token: None,
     }
   }
 }
 
-pub struct QueueInsertBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
+pub struct SenderIntroductionBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
   fbb_: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>,
   start_: ::flatbuffers::WIPOffset<::flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> QueueInsertBuilder<'a, 'b, A> {
-  #[inline]
-  pub fn add_item(&mut self, item: ::flatbuffers::WIPOffset<QueueItem<'b >>) {
-    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<QueueItem>>(QueueInsert::VT_ITEM, item);
-  }
-  #[inline]
-  pub fn add_position_type(&mut self, position_type: QueuePosition) {
-    self.fbb_.push_slot::<QueuePosition>(QueueInsert::VT_POSITION_TYPE, position_type, QueuePosition::NONE);
-  }
-  #[inline]
-  pub fn add_position(&mut self, position: ::flatbuffers::WIPOffset<::flatbuffers::UnionWIPOffset>) {
-    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(QueueInsert::VT_POSITION, position);
-  }
-  #[inline]
-  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> QueueInsertBuilder<'a, 'b, A> {
-    let start = _fbb.start_table();
-    QueueInsertBuilder {
-      fbb_: _fbb,
-      start_: start,
-    }
-  }
-  #[inline]
-  pub fn finish(self) -> ::flatbuffers::WIPOffset<QueueInsert<'a>> {
-    let o = self.fbb_.end_table(self.start_);
-    self.fbb_.required(o, QueueInsert::VT_ITEM,"item");
-    self.fbb_.required(o, QueueInsert::VT_POSITION,"position");
-    ::flatbuffers::WIPOffset::new(o.value())
-  }
-}
-
-impl ::core::fmt::Debug for QueueInsert<'_> {
-  fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-    let mut ds = f.debug_struct("QueueInsert");
-      ds.field("item", &self.item());
-      ds.field("position_type", &self.position_type());
-      match self.position_type() {
-        QueuePosition::Index => {
-          if let Some(x) = self.position_as_index() {
-            ds.field("position", &x)
-          } else {
-            ds.field("position", &"InvalidFlatbuffer: Union discriminant does not match value.")
-          }
-        },
-        QueuePosition::Front => {
-          if let Some(x) = self.position_as_front() {
-            ds.field("position", &x)
-          } else {
-            ds.field("position", &"InvalidFlatbuffer: Union discriminant does not match value.")
-          }
-        },
-        QueuePosition::Back => {
-          if let Some(x) = self.position_as_back() {
-            ds.field("position", &x)
-          } else {
-            ds.field("position", &"InvalidFlatbuffer: Union discriminant does not match value.")
-          }
-        },
-        _ => {
-          let x: Option<()> = None;
-          ds.field("position", &x)
-        },
-      };
+impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> SenderIntroductionBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_device_info(&mut self, device_info: ::flatbuffers::WIPOffset<DeviceInfo<'b >>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<DeviceInfo>>(SenderIntroduction::VT_DEVICE_INFO, device_info);
+  }
+  #[inline]
+  pub fn add_pairing_token(&mut self, pairing_token: ::flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(SenderIntroduction::VT_PAIRING_TOKEN, pairing_token);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> SenderIntroductionBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    SenderIntroductionBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> ::flatbuffers::WIPOffset<SenderIntroduction<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, SenderIntroduction::VT_DEVICE_INFO,"device_info");
+    ::flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl ::core::fmt::Debug for SenderIntroduction<'_> {
+  fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+    let mut ds = f.debug_struct("SenderIntroduction");
+      ds.field("device_info", &self.device_info());
+      ds.field("pairing_token", &self.pairing_token());
       ds.finish()
   }
 }
-pub enum QueueRemoveOffset {}
+pub enum ReceiverIntroductionOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct QueueRemove<'a> {
+pub struct ReceiverIntroduction<'a> {
   pub _tab: ::flatbuffers::Table<'a>,
 }
 
-impl<'a> ::flatbuffers::Follow<'a> for QueueRemove<'a> {
-  type Inner = QueueRemove<'a>;
+impl<'a> ::flatbuffers::Follow<'a> for ReceiverIntroduction<'a> {
+  type Inner = ReceiverIntroduction<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: unsafe { ::flatbuffers::Table::new(buf, loc) } }
   }
 }
 
-impl<'a> QueueRemove<'a> {
-  pub const VT_POSITION_TYPE: ::flatbuffers::VOffsetT = 4;
-  pub const VT_POSITION: ::flatbuffers::VOffsetT = 6;
+impl<'a> ReceiverIntroduction<'a> {
+  pub const VT_DEVICE_INFO: ::flatbuffers::VOffsetT = 4;
+  pub const VT_CAPABILITIES: ::flatbuffers::VOffsetT = 6;
+  pub const VT_PAIRING_REQUIRED: ::flatbuffers::VOffsetT = 8;
+  pub const VT_MAX_QUEUE_ITEMS: ::flatbuffers::VOffsetT = 10;
 
   #[inline]
   pub unsafe fn init_from_table(table: ::flatbuffers::Table<'a>) -> Self {
-    QueueRemove { _tab: table }
+    ReceiverIntroduction { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: ::flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut ::flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args QueueRemoveArgs
-  ) -> ::flatbuffers::WIPOffset<QueueRemove<'bldr>> {
-    let mut builder = QueueRemoveBuilder::new(_fbb);
-    if let Some(x) = args.position { builder.add_position(x); }
-    builder.add_position_type(args.position_type);
+    args: &'args ReceiverIntroductionArgs<'args>
+  ) -> ::flatbuffers::WIPOffset<ReceiverIntroduction<'bldr>> {
+    let mut builder = ReceiverIntroductionBuilder::new(_fbb);
+    if let Some(x) = args.max_queue_items { builder.add_max_queue_items(x); }
+    if let Some(x) = args.capabilities { builder.add_capabilities(x); }
+    if let Some(x) = args.device_info { builder.add_device_info(x); }
+    builder.add_pairing_required(args.pairing_required);
     builder.finish()
   }
 
 
   #[inline]
-  pub fn position_type(&self) -> QueuePosition {
+  pub fn device_info(&self) -> DeviceInfo<'a> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<QueuePosition>(QueueRemove::VT_POSITION_TYPE, Some(QueuePosition::NONE)).unwrap()}
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<DeviceInfo>>(ReceiverIntroduction::VT_DEVICE_INFO, None).unwrap()}
   }
   #[inline]
-  pub fn position(&self) -> ::flatbuffers::Table<'a> {
+  pub fn capabilities(&self) -> Option<ReceiverCapabilities<'a>> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<::flatbuffers::Table<'a>>>(QueueRemove::VT_POSITION, None).unwrap()}
-  }
-  #[inline]
-  #[allow(non_snake_case)]
-  pub fn position_as_index(&self) -> Option<QueueIndex<'a>> {
-    if self.position_type() == QueuePosition::Index {
-      let u = self.position();
-      // Safety:
-      // Created from a valid Table for this object
-      // Which contains a valid union in this slot
-      Some(unsafe { QueueIndex::init_from_table(u) })
-    } else {
-      None
-    }
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<ReceiverCapabilities>>(ReceiverIntroduction::VT_CAPABILITIES, None)}
   }
-
   #[inline]
-  #[allow(non_snake_case)]
-  pub fn position_as_front(&self) -> Option<QueueMarkerFront<'a>> {
-    if self.position_type() == QueuePosition::Front {
-      let u = self.position();
-      // Safety:
-      // Created from a valid Table for this object
-      // Which contains a valid union in this slot
-      Some(unsafe { QueueMarkerFront::init_from_table(u) })
-    } else {
-      None
-    }
+  pub fn pairing_required(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(ReceiverIntroduction::VT_PAIRING_REQUIRED, Some(false)).unwrap()}
   }
-
   #[inline]
-  #[allow(non_snake_case)]
-  pub fn position_as_back(&self) -> Option<QueueMarkerBack<'a>> {
-    if self.position_type() == QueuePosition::Back {
-      let u = self.position();
-      // Safety:
-      // Created from a valid Table for this object
-      // Which contains a valid union in this slot
-      Some(unsafe { QueueMarkerBack::init_from_table(u) })
-    } else {
-      None
-    }
+  pub fn max_queue_items(&self) -> Option<u32> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(ReceiverIntroduction::VT_MAX_QUEUE_ITEMS, None)}
   }
-
 }
 
-impl ::flatbuffers::Verifiable for QueueRemove<'_> {
+impl ::flatbuffers::Verifiable for ReceiverIntroduction<'_> {
   #[inline]
   fn run_verifier(
     v: &mut ::flatbuffers::Verifier, pos: usize
   ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
     v.visit_table(pos)?
-     .visit_union::<QueuePosition, _>("position_type", Self::VT_POSITION_TYPE, "position", Self::VT_POSITION, true, |key, v, pos| {
-        match key {
-          QueuePosition::Index => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<QueueIndex>>("QueuePosition::Index", pos),
-          QueuePosition::Front => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<QueueMarkerFront>>("QueuePosition::Front", pos),
-          QueuePosition::Back => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<QueueMarkerBack>>("QueuePosition::Back", pos),
-          _ => Ok(()),
-        }
-     })?
+     .visit_field::<::flatbuffers::ForwardsUOffset<DeviceInfo>>("device_info", Self::VT_DEVICE_INFO, true)?
+     .visit_field::<::flatbuffers::ForwardsUOffset<ReceiverCapabilities>>("capabilities", Self::VT_CAPABILITIES, false)?
+     .visit_field::<bool>("pairing_required", Self::VT_PAIRING_REQUIRED, false)?
+     .visit_field::<u32>("max_queue_items", Self::VT_MAX_QUEUE_ITEMS, false)?
      .finish();
     Ok(())
   }
 }
-pub struct QueueRemoveArgs {
-    pub position_type: QueuePosition,
-    pub position: Option<::flatbuffers::WIPOffset<::flatbuffers::UnionWIPOffset>>,
+pub struct ReceiverIntroductionArgs<'a> {
+    pub device_info: Option<::flatbuffers::WIPOffset<DeviceInfo<'a>>>,
+    pub capabilities: Option<::flatbuffers::WIPOffset<ReceiverCapabilities<'a>>>,
+    pub pairing_required: bool,
+    pub max_queue_items: Option<u32>,
 }
-impl<'a> Default for QueueRemoveArgs {
+impl<'a> Default for ReceiverIntroductionArgs<'a> {
   #[inline]
   fn default() -> Self {
-    QueueRemoveArgs {
-      position_type: QueuePosition::NONE,
-      position: None, // required field
+    ReceiverIntroductionArgs {
+      device_info: None, // required field
+      capabilities: None,
+      pairing_required: false,
+      max_queue_items: None,
     }
   }
 }
 
-pub struct QueueRemoveBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
+pub struct ReceiverIntroductionBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
   fbb_: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>,
   start_: ::flatbuffers::WIPOffset<::flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> QueueRemoveBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> ReceiverIntroductionBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_position_type(&mut self, position_type: QueuePosition) {
-    self.fbb_.push_slot::<QueuePosition>(QueueRemove::VT_POSITION_TYPE, position_type, QueuePosition::NONE);
+  pub fn add_device_info(&mut self, device_info: ::flatbuffers::WIPOffset<DeviceInfo<'b >>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<DeviceInfo>>(ReceiverIntroduction::VT_DEVICE_INFO, device_info);
   }
   #[inline]
-  pub fn add_position(&mut self, position: ::flatbuffers::WIPOffset<::flatbuffers::UnionWIPOffset>) {
-    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(QueueRemove::VT_POSITION, position);
+  pub fn add_capabilities(&mut self, capabilities: ::flatbuffers::WIPOffset<ReceiverCapabilities<'b >>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<ReceiverCapabilities>>(ReceiverIntroduction::VT_CAPABILITIES, capabilities);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> QueueRemoveBuilder<'a, 'b, A> {
+  pub fn add_pairing_required(&mut self, pairing_required: bool) {
+    self.fbb_.push_slot::<bool>(ReceiverIntroduction::VT_PAIRING_REQUIRED, pairing_required, false);
+  }
+  #[inline]
+  pub fn add_max_queue_items(&mut self, max_queue_items: u32) {
+    self.fbb_.push_slot_always::<u32>(ReceiverIntroduction::VT_MAX_QUEUE_ITEMS, max_queue_items);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> ReceiverIntroductionBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    QueueRemoveBuilder {
+    ReceiverIntroductionBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> ::flatbuffers::WIPOffset<QueueRemove<'a>> {
+  pub fn finish(self) -> ::flatbuffers::WIPOffset<ReceiverIntroduction<'a>> {
     let o = self.fbb_.end_table(self.start_);
-    self.fbb_.required(o, QueueRemove::VT_POSITION,"position");
+    self.fbb_.required(o, ReceiverIntroduction::VT_DEVICE_INFO,"device_info");
     ::flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl ::core::fmt::Debug for QueueRemove<'_> {
+impl ::core::fmt::Debug for ReceiverIntroduction<'_> {
   fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-    let mut ds = f.debug_struct("QueueRemove");
-      ds.field("position_type", &self.position_type());
-      match self.position_type() {
-        QueuePosition::Index => {
-          if let Some(x) = self.position_as_index() {
-            ds.field("position", &x)
-          } else {
-            ds.field("position", &"InvalidFlatbuffer: Union discriminant does not match value.")
-          }
-        },
-        QueuePosition::Front => {
-          if let Some(x) = self.position_as_front() {
-            ds.field("position", &x)
-          } else {
-            ds.field("position", &"InvalidFlatbuffer: Union discriminant does not match value.")
-          }
-        },
-        QueuePosition::Back => {
-          if let Some(x) = self.position_as_back() {
-            ds.field("position", &x)
-          } else {
-            ds.field("position", &"InvalidFlatbuffer: Union discriminant does not match value.")
-          }
-        },
-        _ => {
-          let x: Option<()> = None;
-          ds.field("position", &x)
-        },
-      };
+    let mut ds = f.debug_struct("ReceiverIntroduction");
+      ds.field("device_info", &self.device_info());
+      ds.field("capabilities", &self.capabilities());
+      ds.field("pairing_required", &self.pairing_required());
+      ds.field("max_queue_items", &self.max_queue_items());
       ds.finish()
   }
 }
-pub enum QueueItemSelectedOffset {}
+pub enum PairingRequestOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct QueueItemSelected<'a> {
+pub struct PairingRequest<'a> {
   pub _tab: ::flatbuffers::Table<'a>,
 }
 
-impl<'a> ::flatbuffers::Follow<'a> for QueueItemSelected<'a> {
-  type Inner = QueueItemSelected<'a>;
+impl<'a> ::flatbuffers::Follow<'a> for PairingRequest<'a> {
+  type Inner = PairingRequest<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: unsafe { ::flatbuffers::Table::new(buf, loc) } }
   }
 }
 
-impl<'a> QueueItemSelected<'a> {
-  pub const VT_POSITION_TYPE: ::flatbuffers::VOffsetT = 4;
-  pub const VT_POSITION: ::flatbuffers::VOffsetT = 6;
+impl<'a> PairingRequest<'a> {
+  pub const VT_PIN: ::flatbuffers::VOffsetT = 4;
 
   #[inline]
   pub unsafe fn init_from_table(table: ::flatbuffers::Table<'a>) -> Self {
-    QueueItemSelected { _tab: table }
+    PairingRequest { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: ::flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut ::flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args QueueItemSelectedArgs
-  ) -> ::flatbuffers::WIPOffset<QueueItemSelected<'bldr>> {
-    let mut builder = QueueItemSelectedBuilder::new(_fbb);
-    if let Some(x) = args.position { builder.add_position(x); }
-    builder.add_position_type(args.position_type);
+    args: &'args PairingRequestArgs<'args>
+  ) -> ::flatbuffers::WIPOffset<PairingRequest<'bldr>> {
+    let mut builder = PairingRequestBuilder::new(_fbb);
+    if let Some(x) = args.pin { builder.add_pin(x); }
     builder.finish()
   }
 
 
   #[inline]
-  pub fn position_type(&self) -> QueuePosition {
+  pub fn pin(&self) -> &'a str {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<QueuePosition>(QueueItemSelected::VT_POSITION_TYPE, Some(QueuePosition::NONE)).unwrap()}
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<&str>>(PairingRequest::VT_PIN, None).unwrap()}
   }
+}
+
+impl ::flatbuffers::Verifiable for PairingRequest<'_> {
   #[inline]
-  pub fn position(&self) -> ::flatbuffers::Table<'a> {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<::flatbuffers::Table<'a>>>(QueueItemSelected::VT_POSITION, None).unwrap()}
+  fn run_verifier(
+    v: &mut ::flatbuffers::Verifier, pos: usize
+  ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
+    v.visit_table(pos)?
+     .visit_field::<::flatbuffers::ForwardsUOffset<&str>>("pin", Self::VT_PIN, true)?
+     .finish();
+    Ok(())
   }
+}
+pub struct PairingRequestArgs<'a> {
+    pub pin: Option<::flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for PairingRequestArgs<'a> {
   #[inline]
-  #[allow(non_snake_case)]
-  pub fn position_as_index(&self) -> Option<QueueIndex<'a>> {
-    if self.position_type() == QueuePosition::Index {
-      let u = self.position();
-      // Safety:
-      // Created from a valid Table for this object
-      // Which contains a valid union in this slot
-      Some(unsafe { QueueIndex::init_from_table(u) })
-    } else {
-      None
+  fn default() -> Self {
+    PairingRequestArgs {
+      pin: None, // required field
+    }
+  }
+}
+
+pub struct PairingRequestBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: ::flatbuffers::WIPOffset<::flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> PairingRequestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_pin(&mut self, pin: ::flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(PairingRequest::VT_PIN, pin);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> PairingRequestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    PairingRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
     }
   }
+  #[inline]
+  pub fn finish(self) -> ::flatbuffers::WIPOffset<PairingRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, PairingRequest::VT_PIN,"pin");
+    ::flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl ::core::fmt::Debug for PairingRequest<'_> {
+  fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+    let mut ds = f.debug_struct("PairingRequest");
+      ds.field("pin", &self.pin());
+      ds.finish()
+  }
+}
+pub enum PairingResultOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct PairingResult<'a> {
+  pub _tab: ::flatbuffers::Table<'a>,
+}
 
+impl<'a> ::flatbuffers::Follow<'a> for PairingResult<'a> {
+  type Inner = PairingResult<'a>;
   #[inline]
-  #[allow(non_snake_case)]
-  pub fn position_as_front(&self) -> Option<QueueMarkerFront<'a>> {
-    if self.position_type() == QueuePosition::Front {
-      let u = self.position();
-      // Safety:
-      // Created from a valid Table for this object
-      // Which contains a valid union in this slot
-      Some(unsafe { QueueMarkerFront::init_from_table(u) })
-    } else {
-      None
-    }
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { ::flatbuffers::Table::new(buf, loc) } }
   }
+}
+
+impl<'a> PairingResult<'a> {
+  pub const VT_TOKEN: ::flatbuffers::VOffsetT = 4;
 
   #[inline]
-  #[allow(non_snake_case)]
-  pub fn position_as_back(&self) -> Option<QueueMarkerBack<'a>> {
-    if self.position_type() == QueuePosition::Back {
-      let u = self.position();
-      // Safety:
-      // Created from a valid Table for this object
-      // Which contains a valid union in this slot
-      Some(unsafe { QueueMarkerBack::init_from_table(u) })
-    } else {
-      None
-    }
+  pub unsafe fn init_from_table(table: ::flatbuffers::Table<'a>) -> Self {
+    PairingResult { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: ::flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut ::flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args PairingResultArgs<'args>
+  ) -> ::flatbuffers::WIPOffset<PairingResult<'bldr>> {
+    let mut builder = PairingResultBuilder::new(_fbb);
+    if let Some(x) = args.token { builder.add_token(x); }
+    builder.finish()
   }
 
+
+  #[inline]
+  pub fn token(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<&str>>(PairingResult::VT_TOKEN, None).unwrap()}
+  }
 }
 
-impl ::flatbuffers::Verifiable for QueueItemSelected<'_> {
+impl ::flatbuffers::Verifiable for PairingResult<'_> {
   #[inline]
   fn run_verifier(
     v: &mut ::flatbuffers::Verifier, pos: usize
   ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
     v.visit_table(pos)?
-     .visit_union::<QueuePosition, _>("position_type", Self::VT_POSITION_TYPE, "position", Self::VT_POSITION, true, |key, v, pos| {
-        match key {
-          QueuePosition::Index => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<QueueIndex>>("QueuePosition::Index", pos),
-          QueuePosition::Front => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<QueueMarkerFront>>("QueuePosition::Front", pos),
-          QueuePosition::Back => v.verify_union_variant::<::flatbuffers::ForwardsUOffset<QueueMarkerBack>>("QueuePosition::Back", pos),
-          _ => Ok(()),
-        }
-     })?
+     .visit_field::<::flatbuffers::ForwardsUOffset<&str>>("token", Self::VT_TOKEN, true)?
      .finish();
     Ok(())
   }
 }
-pub struct QueueItemSelectedArgs {
-    pub position_type: QueuePosition,
-    pub position: Option<::flatbuffers::WIPOffset<::flatbuffers::UnionWIPOffset>>,
+pub struct PairingResultArgs<'a> {
+    pub token: Option<::flatbuffers::WIPOffset<&'a str>>,
 }
-impl<'a> Default for QueueItemSelectedArgs {
+impl<'a> Default for PairingResultArgs<'a> {
   #[inline]
   fn default() -> Self {
-    QueueItemSelectedArgs {
-      position_type: QueuePosition::NONE,
-      position: None, // required field
+    PairingResultArgs {
+      token: None, // required field
     }
   }
 }
 
-pub struct QueueItemSelectedBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
+pub struct PairingResultBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
   fbb_: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>,
   start_: ::flatbuffers::WIPOffset<::flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> QueueItemSelectedBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> PairingResultBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_position_type(&mut self, position_type: QueuePosition) {
-    self.fbb_.push_slot::<QueuePosition>(QueueItemSelected::VT_POSITION_TYPE, position_type, QueuePosition::NONE);
-  }
-  #[inline]
-  pub fn add_position(&mut self, position: ::flatbuffers::WIPOffset<::flatbuffers::UnionWIPOffset>) {
-    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(QueueItemSelected::VT_POSITION, position);
+  pub fn add_token(&mut self, token: ::flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(PairingResult::VT_TOKEN, token);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> QueueItemSelectedBuilder<'a, 'b, A> {
+  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> PairingResultBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    QueueItemSelectedBuilder {
+    PairingResultBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> ::flatbuffers::WIPOffset<QueueItemSelected<'a>> {
+  pub fn finish(self) -> ::flatbuffers::WIPOffset<PairingResult<'a>> {
     let o = self.fbb_.end_table(self.start_);
-    self.fbb_.required(o, QueueItemSelected::VT_POSITION,"position");
+    self.fbb_.required(o, PairingResult::VT_TOKEN,"token");
     ::flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl ::core::fmt::Debug for QueueItemSelected<'_> {
+impl ::core::fmt::Debug for PairingResult<'_> {
   fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-    let mut ds = f.debug_struct("QueueItemSelected");
-      ds.field("position_type", &self.position_type());
-      match self.position_type() {
-        QueuePosition::Index => {
-          if let Some(x) = self.position_as_index() {
-            ds.field("position", &x)
-          } else {
-            ds.field("position", &"InvalidFlatbuffer: Union discriminant does not match value.")
-          }
-        },
-        QueuePosition::Front => {
-          if let Some(x) = self.position_as_front() {
-            ds.field("position", &x)
-          } else {
-            ds.field("position", &"InvalidFlatbuffer: Union discriminant does not match value.")
-          }
-        },
-        QueuePosition::Back => {
-          if let Some(x) = self.position_as_back() {
-            ds.field("position", &x)
-          } else {
-            ds.field("position", &"InvalidFlatbuffer: Union discriminant does not match value.")
-          }
-        },
-        _ => {
-          let x: Option<()> = None;
-          ds.field("position", &x)
-        },
-      };
+    let mut ds = f.debug_struct("PairingResult");
+      ds.field("token", &self.token());
       ds.finish()
   }
 }
-pub enum TracksAvailableOffset {}
+pub enum SeekRelativeOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct TracksAvailable<'a> {
+pub struct SeekRelative<'a> {
   pub _tab: ::flatbuffers::Table<'a>,
 }
 
-impl<'a> ::flatbuffers::Follow<'a> for TracksAvailable<'a> {
-  type Inner = TracksAvailable<'a>;
+impl<'a> ::flatbuffers::Follow<'a> for SeekRelative<'a> {
+  type Inner = SeekRelative<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: unsafe { ::flatbuffers::Table::new(buf, loc) } }
   }
 }
 
-impl<'a> TracksAvailable<'a> {
-  pub const VT_TRACKS: ::flatbuffers::VOffsetT = 4;
+impl<'a> SeekRelative<'a> {
+  pub const VT_OFFSET_MICROS: ::flatbuffers::VOffsetT = 4;
 
   #[inline]
   pub unsafe fn init_from_table(table: ::flatbuffers::Table<'a>) -> Self {
-    TracksAvailable { _tab: table }
+    SeekRelative { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: ::flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut ::flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args TracksAvailableArgs<'args>
-  ) -> ::flatbuffers::WIPOffset<TracksAvailable<'bldr>> {
-    let mut builder = TracksAvailableBuilder::new(_fbb);
-    if let Some(x) = args.tracks { builder.add_tracks(x); }
+    args: &'args SeekRelativeArgs
+  ) -> ::flatbuffers::WIPOffset<SeekRelative<'bldr>> {
+    let mut builder = SeekRelativeBuilder::new(_fbb);
+    builder.add_offset_micros(args.offset_micros);
     builder.finish()
   }
 
 
   #[inline]
-  pub fn tracks(&self) -> Option<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<MediaTrack<'a>>>> {
+  pub fn offset_micros(&self) -> i64 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<MediaTrack>>>>(TracksAvailable::VT_TRACKS, None)}
+    unsafe { self._tab.get::<i64>(SeekRelative::VT_OFFSET_MICROS, Some(0)).unwrap()}
   }
 }
 
-impl ::flatbuffers::Verifiable for TracksAvailable<'_> {
+impl ::flatbuffers::Verifiable for SeekRelative<'_> {
   #[inline]
   fn run_verifier(
     v: &mut ::flatbuffers::Verifier, pos: usize
   ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
     v.visit_table(pos)?
-     .visit_field::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'_, ::flatbuffers::ForwardsUOffset<MediaTrack>>>>("tracks", Self::VT_TRACKS, false)?
+     .visit_field::<i64>("offset_micros", Self::VT_OFFSET_MICROS, false)?
      .finish();
     Ok(())
   }
 }
-pub struct TracksAvailableArgs<'a> {
-    pub tracks: Option<::flatbuffers::WIPOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<MediaTrack<'a>>>>>,
+pub struct SeekRelativeArgs {
+    pub offset_micros: i64,
 }
-impl<'a> Default for TracksAvailableArgs<'a> {
+impl<'a> Default for SeekRelativeArgs {
   #[inline]
   fn default() -> Self {
-    TracksAvailableArgs {
-      tracks: None,
+    SeekRelativeArgs {
+      offset_micros: 0,
     }
   }
 }
 
-pub struct TracksAvailableBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
+pub struct SeekRelativeBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
   fbb_: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>,
   start_: ::flatbuffers::WIPOffset<::flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> TracksAvailableBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> SeekRelativeBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_tracks(&mut self, tracks: ::flatbuffers::WIPOffset<::flatbuffers::Vector<'b , ::flatbuffers::ForwardsUOffset<MediaTrack<'b >>>>) {
-    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(TracksAvailable::VT_TRACKS, tracks);
+  pub fn add_offset_micros(&mut self, offset_micros: i64) {
+    self.fbb_.push_slot::<i64>(SeekRelative::VT_OFFSET_MICROS, offset_micros, 0);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> TracksAvailableBuilder<'a, 'b, A> {
+  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> SeekRelativeBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    TracksAvailableBuilder {
+    SeekRelativeBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> ::flatbuffers::WIPOffset<TracksAvailable<'a>> {
+  pub fn finish(self) -> ::flatbuffers::WIPOffset<SeekRelative<'a>> {
     let o = self.fbb_.end_table(self.start_);
     ::flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl ::core::fmt::Debug for TracksAvailable<'_> {
+impl ::core::fmt::Debug for SeekRelative<'_> {
   fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-    let mut ds = f.debug_struct("TracksAvailable");
-      ds.field("tracks", &self.tracks());
+    let mut ds = f.debug_struct("SeekRelative");
+      ds.field("offset_micros", &self.offset_micros());
       ds.finish()
   }
 }
-pub enum ChangeTrackOffset {}
+pub enum FrameStepOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct ChangeTrack<'a> {
+pub struct FrameStep<'a> {
   pub _tab: ::flatbuffers::Table<'a>,
 }
 
-impl<'a> ::flatbuffers::Follow<'a> for ChangeTrack<'a> {
-  type Inner = ChangeTrack<'a>;
+impl<'a> ::flatbuffers::Follow<'a> for FrameStep<'a> {
+  type Inner = FrameStep<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: unsafe { ::flatbuffers::Table::new(buf, loc) } }
   }
 }
 
-impl<'a> ChangeTrack<'a> {
-  pub const VT_ID: ::flatbuffers::VOffsetT = 4;
-  pub const VT_TRACK_TYPE: ::flatbuffers::VOffsetT = 6;
+impl<'a> FrameStep<'a> {
+  pub const VT_DIRECTION: ::flatbuffers::VOffsetT = 4;
 
   #[inline]
   pub unsafe fn init_from_table(table: ::flatbuffers::Table<'a>) -> Self {
-    ChangeTrack { _tab: table }
+    FrameStep { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: ::flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut ::flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args ChangeTrackArgs
-  ) -> ::flatbuffers::WIPOffset<ChangeTrack<'bldr>> {
-    let mut builder = ChangeTrackBuilder::new(_fbb);
-    if let Some(x) = args.id { builder.add_id(x); }
-    builder.add_track_type(args.track_type);
+    args: &'args FrameStepArgs
+  ) -> ::flatbuffers::WIPOffset<FrameStep<'bldr>> {
+    let mut builder = FrameStepBuilder::new(_fbb);
+    builder.add_direction(args.direction);
     builder.finish()
   }
 
 
   #[inline]
-  pub fn id(&self) -> Option<u32> {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<u32>(ChangeTrack::VT_ID, None)}
-  }
-  #[inline]
-  pub fn track_type(&self) -> MediaTrackType {
+  pub fn direction(&self) -> FrameStepDirection {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<MediaTrackType>(ChangeTrack::VT_TRACK_TYPE, Some(MediaTrackType::Video)).unwrap()}
+    unsafe { self._tab.get::<FrameStepDirection>(FrameStep::VT_DIRECTION, Some(FrameStepDirection::Forward)).unwrap()}
   }
 }
 
-impl ::flatbuffers::Verifiable for ChangeTrack<'_> {
+impl ::flatbuffers::Verifiable for FrameStep<'_> {
   #[inline]
   fn run_verifier(
     v: &mut ::flatbuffers::Verifier, pos: usize
   ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
     v.visit_table(pos)?
-     .visit_field::<u32>("id", Self::VT_ID, false)?
-     .visit_field::<MediaTrackType>("track_type", Self::VT_TRACK_TYPE, false)?
+     .visit_field::<FrameStepDirection>("direction", Self::VT_DIRECTION, false)?
      .finish();
     Ok(())
   }
 }
-pub struct ChangeTrackArgs {
-    pub id: Option<u32>,
-    pub track_type: MediaTrackType,
+pub struct FrameStepArgs {
+    pub direction: FrameStepDirection,
 }
-impl<'a> Default for ChangeTrackArgs {
+impl<'a> Default for FrameStepArgs {
   #[inline]
   fn default() -> Self {
-    ChangeTrackArgs {
-      id: None,
-      track_type: MediaTrackType::Video,
+    FrameStepArgs {
+      direction: FrameStepDirection::Forward,
     }
   }
 }
 
-pub struct ChangeTrackBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
+pub struct FrameStepBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
   fbb_: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>,
   start_: ::flatbuffers::WIPOffset<::flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> ChangeTrackBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> FrameStepBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_id(&mut self, id: u32) {
-    self.fbb_.push_slot_always::<u32>(ChangeTrack::VT_ID, id);
-  }
-  #[inline]
-  pub fn add_track_type(&mut self, track_type: MediaTrackType) {
-    self.fbb_.push_slot::<MediaTrackType>(ChangeTrack::VT_TRACK_TYPE, track_type, MediaTrackType::Video);
+  pub fn add_direction(&mut self, direction: FrameStepDirection) {
+    self.fbb_.push_slot::<FrameStepDirection>(FrameStep::VT_DIRECTION, direction, FrameStepDirection::Forward);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> ChangeTrackBuilder<'a, 'b, A> {
+  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> FrameStepBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    ChangeTrackBuilder {
+    FrameStepBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> ::flatbuffers::WIPOffset<ChangeTrack<'a>> {
+  pub fn finish(self) -> ::flatbuffers::WIPOffset<FrameStep<'a>> {
     let o = self.fbb_.end_table(self.start_);
     ::flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl ::core::fmt::Debug for ChangeTrack<'_> {
+impl ::core::fmt::Debug for FrameStep<'_> {
   fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-    let mut ds = f.debug_struct("ChangeTrack");
-      ds.field("id", &self.id());
-      ds.field("track_type", &self.track_type());
+    let mut ds = f.debug_struct("FrameStep");
+      ds.field("direction", &self.direction());
       ds.finish()
   }
 }
-pub enum SenderIntroductionOffset {}
+pub enum ChaptersAvailableOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct SenderIntroduction<'a> {
+pub struct ChaptersAvailable<'a> {
   pub _tab: ::flatbuffers::Table<'a>,
 }
 
-impl<'a> ::flatbuffers::Follow<'a> for SenderIntroduction<'a> {
-  type Inner = SenderIntroduction<'a>;
+impl<'a> ::flatbuffers::Follow<'a> for ChaptersAvailable<'a> {
+  type Inner = ChaptersAvailable<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: unsafe { ::flatbuffers::Table::new(buf, loc) } }
   }
 }
 
-impl<'a> SenderIntroduction<'a> {
-  pub const VT_DEVICE_INFO: ::flatbuffers::VOffsetT = 4;
+impl<'a> ChaptersAvailable<'a> {
+  pub const VT_CHAPTERS: ::flatbuffers::VOffsetT = 4;
 
   #[inline]
   pub unsafe fn init_from_table(table: ::flatbuffers::Table<'a>) -> Self {
-    SenderIntroduction { _tab: table }
+    ChaptersAvailable { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: ::flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut ::flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args SenderIntroductionArgs<'args>
-  ) -> ::flatbuffers::WIPOffset<SenderIntroduction<'bldr>> {
-    let mut builder = SenderIntroductionBuilder::new(_fbb);
-    if let Some(x) = args.device_info { builder.add_device_info(x); }
+    args: &'args ChaptersAvailableArgs<'args>
+  ) -> ::flatbuffers::WIPOffset<ChaptersAvailable<'bldr>> {
+    let mut builder = ChaptersAvailableBuilder::new(_fbb);
+    if let Some(x) = args.chapters { builder.add_chapters(x); }
     builder.finish()
   }
 
 
   #[inline]
-  pub fn device_info(&self) -> DeviceInfo<'a> {
+  pub fn chapters(&self) -> Option<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<Chapter<'a>>>> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<DeviceInfo>>(SenderIntroduction::VT_DEVICE_INFO, None).unwrap()}
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<Chapter>>>>(ChaptersAvailable::VT_CHAPTERS, None)}
   }
 }
 
-impl ::flatbuffers::Verifiable for SenderIntroduction<'_> {
+impl ::flatbuffers::Verifiable for ChaptersAvailable<'_> {
   #[inline]
   fn run_verifier(
     v: &mut ::flatbuffers::Verifier, pos: usize
   ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
     v.visit_table(pos)?
-     .visit_field::<::flatbuffers::ForwardsUOffset<DeviceInfo>>("device_info", Self::VT_DEVICE_INFO, true)?
+     .visit_field::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'_, ::flatbuffers::ForwardsUOffset<Chapter>>>>("chapters", Self::VT_CHAPTERS, false)?
      .finish();
     Ok(())
   }
 }
-pub struct SenderIntroductionArgs<'a> {
-    pub device_info: Option<::flatbuffers::WIPOffset<DeviceInfo<'a>>>,
+pub struct ChaptersAvailableArgs<'a> {
+    pub chapters: Option<::flatbuffers::WIPOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<Chapter<'a>>>>>,
 }
-impl<'a> Default for SenderIntroductionArgs<'a> {
+impl<'a> Default for ChaptersAvailableArgs<'a> {
   #[inline]
   fn default() -> Self {
-    SenderIntroductionArgs {
-      device_info: None, // required field
+    ChaptersAvailableArgs {
+      chapters: None,
     }
   }
 }
 
-pub struct SenderIntroductionBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
+pub struct ChaptersAvailableBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
   fbb_: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>,
   start_: ::flatbuffers::WIPOffset<::flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> SenderIntroductionBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> ChaptersAvailableBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_device_info(&mut self, device_info: ::flatbuffers::WIPOffset<DeviceInfo<'b >>) {
-    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<DeviceInfo>>(SenderIntroduction::VT_DEVICE_INFO, device_info);
+  pub fn add_chapters(&mut self, chapters: ::flatbuffers::WIPOffset<::flatbuffers::Vector<'b , ::flatbuffers::ForwardsUOffset<Chapter<'b >>>>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(ChaptersAvailable::VT_CHAPTERS, chapters);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> SenderIntroductionBuilder<'a, 'b, A> {
+  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> ChaptersAvailableBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    SenderIntroductionBuilder {
+    ChaptersAvailableBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> ::flatbuffers::WIPOffset<SenderIntroduction<'a>> {
+  pub fn finish(self) -> ::flatbuffers::WIPOffset<ChaptersAvailable<'a>> {
     let o = self.fbb_.end_table(self.start_);
-    self.fbb_.required(o, SenderIntroduction::VT_DEVICE_INFO,"device_info");
     ::flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl ::core::fmt::Debug for SenderIntroduction<'_> {
+impl ::core::fmt::Debug for ChaptersAvailable<'_> {
   fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-    let mut ds = f.debug_struct("SenderIntroduction");
-      ds.field("device_info", &self.device_info());
+    let mut ds = f.debug_struct("ChaptersAvailable");
+      ds.field("chapters", &self.chapters());
       ds.finish()
   }
 }
-pub enum ReceiverIntroductionOffset {}
+pub enum SeekToChapterOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct ReceiverIntroduction<'a> {
+pub struct SeekToChapter<'a> {
   pub _tab: ::flatbuffers::Table<'a>,
 }
 
-impl<'a> ::flatbuffers::Follow<'a> for ReceiverIntroduction<'a> {
-  type Inner = ReceiverIntroduction<'a>;
+impl<'a> ::flatbuffers::Follow<'a> for SeekToChapter<'a> {
+  type Inner = SeekToChapter<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: unsafe { ::flatbuffers::Table::new(buf, loc) } }
   }
 }
 
-impl<'a> ReceiverIntroduction<'a> {
-  pub const VT_DEVICE_INFO: ::flatbuffers::VOffsetT = 4;
-  pub const VT_CAPABILITIES: ::flatbuffers::VOffsetT = 6;
+impl<'a> SeekToChapter<'a> {
+  pub const VT_TARGET: ::flatbuffers::VOffsetT = 4;
+  pub const VT_INDEX: ::flatbuffers::VOffsetT = 6;
 
   #[inline]
   pub unsafe fn init_from_table(table: ::flatbuffers::Table<'a>) -> Self {
-    ReceiverIntroduction { _tab: table }
+    SeekToChapter { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: ::flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut ::flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args ReceiverIntroductionArgs<'args>
-  ) -> ::flatbuffers::WIPOffset<ReceiverIntroduction<'bldr>> {
-    let mut builder = ReceiverIntroductionBuilder::new(_fbb);
-    if let Some(x) = args.capabilities { builder.add_capabilities(x); }
-    if let Some(x) = args.device_info { builder.add_device_info(x); }
+    args: &'args SeekToChapterArgs
+  ) -> ::flatbuffers::WIPOffset<SeekToChapter<'bldr>> {
+    let mut builder = SeekToChapterBuilder::new(_fbb);
+    builder.add_index(args.index);
+    builder.add_target(args.target);
     builder.finish()
   }
 
 
   #[inline]
-  pub fn device_info(&self) -> DeviceInfo<'a> {
+  pub fn target(&self) -> ChapterTarget {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<DeviceInfo>>(ReceiverIntroduction::VT_DEVICE_INFO, None).unwrap()}
+    unsafe { self._tab.get::<ChapterTarget>(SeekToChapter::VT_TARGET, Some(ChapterTarget::Index)).unwrap()}
   }
   #[inline]
-  pub fn capabilities(&self) -> Option<ReceiverCapabilities<'a>> {
+  pub fn index(&self) -> u32 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<ReceiverCapabilities>>(ReceiverIntroduction::VT_CAPABILITIES, None)}
+    unsafe { self._tab.get::<u32>(SeekToChapter::VT_INDEX, Some(0)).unwrap()}
   }
 }
 
-impl ::flatbuffers::Verifiable for ReceiverIntroduction<'_> {
+impl ::flatbuffers::Verifiable for SeekToChapter<'_> {
   #[inline]
   fn run_verifier(
     v: &mut ::flatbuffers::Verifier, pos: usize
   ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
     v.visit_table(pos)?
-     .visit_field::<::flatbuffers::ForwardsUOffset<DeviceInfo>>("device_info", Self::VT_DEVICE_INFO, true)?
-     .visit_field::<::flatbuffers::ForwardsUOffset<ReceiverCapabilities>>("capabilities", Self::VT_CAPABILITIES, false)?
+     .visit_field::<ChapterTarget>("target", Self::VT_TARGET, false)?
+     .visit_field::<u32>("index", Self::VT_INDEX, false)?
      .finish();
     Ok(())
   }
 }
-pub struct ReceiverIntroductionArgs<'a> {
-    pub device_info: Option<::flatbuffers::WIPOffset<DeviceInfo<'a>>>,
-    pub capabilities: Option<::flatbuffers::WIPOffset<ReceiverCapabilities<'a>>>,
+pub struct SeekToChapterArgs {
+    pub target: ChapterTarget,
+    pub index: u32,
 }
-impl<'a> Default for ReceiverIntroductionArgs<'a> {
+impl<'a> Default for SeekToChapterArgs {
   #[inline]
   fn default() -> Self {
-    ReceiverIntroductionArgs {
-      device_info: None, // required field
-      capabilities: None,
+    SeekToChapterArgs {
+      target: ChapterTarget::Index,
+      index: 0,
     }
   }
 }
 
-pub struct ReceiverIntroductionBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
+pub struct SeekToChapterBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
   fbb_: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>,
   start_: ::flatbuffers::WIPOffset<::flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> ReceiverIntroductionBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> SeekToChapterBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_device_info(&mut self, device_info: ::flatbuffers::WIPOffset<DeviceInfo<'b >>) {
-    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<DeviceInfo>>(ReceiverIntroduction::VT_DEVICE_INFO, device_info);
+  pub fn add_target(&mut self, target: ChapterTarget) {
+    self.fbb_.push_slot::<ChapterTarget>(SeekToChapter::VT_TARGET, target, ChapterTarget::Index);
   }
   #[inline]
-  pub fn add_capabilities(&mut self, capabilities: ::flatbuffers::WIPOffset<ReceiverCapabilities<'b >>) {
-    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<ReceiverCapabilities>>(ReceiverIntroduction::VT_CAPABILITIES, capabilities);
+  pub fn add_index(&mut self, index: u32) {
+    self.fbb_.push_slot::<u32>(SeekToChapter::VT_INDEX, index, 0);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> ReceiverIntroductionBuilder<'a, 'b, A> {
+  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> SeekToChapterBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    ReceiverIntroductionBuilder {
+    SeekToChapterBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> ::flatbuffers::WIPOffset<ReceiverIntroduction<'a>> {
+  pub fn finish(self) -> ::flatbuffers::WIPOffset<SeekToChapter<'a>> {
     let o = self.fbb_.end_table(self.start_);
-    self.fbb_.required(o, ReceiverIntroduction::VT_DEVICE_INFO,"device_info");
     ::flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl ::core::fmt::Debug for ReceiverIntroduction<'_> {
+impl ::core::fmt::Debug for SeekToChapter<'_> {
   fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-    let mut ds = f.debug_struct("ReceiverIntroduction");
-      ds.field("device_info", &self.device_info());
-      ds.field("capabilities", &self.capabilities());
+    let mut ds = f.debug_struct("SeekToChapter");
+      ds.field("target", &self.target());
+      ds.field("index", &self.index());
       ds.finish()
   }
 }
@@ -1330,7 +1330,8 @@ impl CueEngine {
 /// and a later one that would land on top of it moves up until it does not.
 /// That is bottom-up stacking for the ordinary case and browser-like for the
 /// positioned case: the WebVTT rendering algorithm also moves a cue box that
-/// would overlap an existing one.
+/// would overlap an existing one. Under [`CueStyle::top_anchored`] the stack
+/// grows down from the top instead.
 ///
 /// Two known limits, both deliberate:
 ///
@@ -1345,6 +1346,7 @@ fn active_overlays(state: &State) -> SmallVec<[Overlay; 1]> {
     let mut overlays = SmallVec::new();
     // What has been placed, in placement order: (x, y, width, height).
     let mut placed: SmallVec<[(i32, i32, u32, u32); 2]> = SmallVec::new();
+    let downward = state.style.top_anchored;
     for active in state.active.iter() {
         // `Stale` counts: its pixels are a previous step or style of the same
         // cue in the same place, which beats blanking the line while the
@@ -1353,7 +1355,7 @@ fn active_overlays(state: &State) -> SmallVec<[Overlay; 1]> {
             continue;
         };
         let mut overlay = raster.to_overlay();
-        overlay.y = stacked_y(&placed, &overlay);
+        overlay.y = stacked_y(&placed, &overlay, downward);
         placed.push((overlay.x, overlay.y, overlay.width, overlay.height));
         overlays.push(overlay);
     }
@@ -1369,12 +1371,13 @@ fn active_overlays(state: &State) -> SmallVec<[Overlay; 1]> {
 }
 
 /// Where an overlay ends up once it has moved out of the way of the cues
-/// already placed: its own `y`, or far enough above whatever it collided with.
+/// already placed: its own `y`, or far enough above (`downward`: below)
+/// whatever it collided with.
 ///
 /// Rectangles, not lines: two cues at different horizontal positions do not
 /// collide and neither moves. The loop is bounded by the number of cues that
 /// can be placed, since each pass either settles or clears one more rectangle.
-fn stacked_y(placed: &[(i32, i32, u32, u32)], overlay: &Overlay) -> i32 {
+fn stacked_y(placed: &[(i32, i32, u32, u32)], overlay: &Overlay, downward: bool) -> i32 {
     let (x, w, h) = (overlay.x, overlay.width as i32, overlay.height as i32);
     let mut y = overlay.y;
     for _ in 0..MAX_ACTIVE_CUES {
@@ -1383,7 +1386,7 @@ fn stacked_y(placed: &[(i32, i32, u32, u32)], overlay: &Overlay) -> i32 {
             let overlaps_x = x < px + pw as i32 && px < x + w;
             let overlaps_y = y < py + ph as i32 && py < y + h;
             if overlaps_x && overlaps_y {
-                y = py - h;
+                y = if downward { py + ph as i32 } else { py - h };
                 moved = true;
             }
         }
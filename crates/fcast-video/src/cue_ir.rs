@@ -106,6 +106,11 @@ pub struct CueStyle {
     pub wrap_width_fraction: f32,
     /// Distance from the bottom edge, as a fraction of canvas height.
     pub bottom_margin_fraction: f32,
+    /// Put default-placed cues at the top instead, `bottom_margin_fraction`
+    /// then measuring from the top edge. This is the secondary subtitle's
+    /// region, so two tracks can be shown at once without overlapping. Cues
+    /// the file places itself go where the file says.
+    pub top_anchored: bool,
     /// Whether *default-placed* subtitles (no positioning in the file) may sit
     /// in the window's letterbox bars instead of covering the picture (mpv's
     /// `sub-use-margins`, and what the pango arm effectively does). Cues the
@@ -197,6 +202,7 @@ impl Default for CueStyle {
             min_font_px: 12.0,
             wrap_width_fraction: 0.90,
             bottom_margin_fraction: 0.04,
+            top_anchored: false,
             use_window_margins: true,
             outline: Some(DEFAULT_OUTLINE),
             background: Some(DEFAULT_BACKGROUND),
@@ -1115,7 +1121,8 @@ struct Frame {
 /// picture): an explicit SSA origin (`\pos`) pins the anchor point exactly,
 /// then WebVTT `position`/`line` percentages, then the anchor's own frame
 /// region with the IR margins. A cue the file says nothing about is house
-/// policy: bottom-center of the frame, or of the *window* when
+/// policy: bottom-center of the frame (top-center under
+/// [`CueStyle::top_anchored`]), or of the *window* when
 /// [`CueStyle::use_window_margins`] is set, letting default subtitles sit in
 /// the letterbox bars instead of covering the picture (the margin itself stays
 /// proportional to the picture so the look does not change with the bars).
@@ -1127,7 +1134,11 @@ fn place(
     w: f32,
     h: f32,
 ) -> (i32, i32) {
-    let anchor = ir.layout.anchor.unwrap_or(ir::Anchor::BottomCenter);
+    let anchor = ir.layout.anchor.unwrap_or(if house.top_anchored {
+        ir::Anchor::TopCenter
+    } else {
+        ir::Anchor::BottomCenter
+    });
     let (col, row) = anchor_cell(anchor);
     let l = &ir.layout;
     // "The file said nothing": no explicit placement of any kind. Note the
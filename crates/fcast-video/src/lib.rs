@@ -2,6 +2,7 @@ pub mod cue;
 pub mod cue_ir;
 pub mod render_latency;
 pub mod render_options;
+pub mod secondary;
 pub mod subpic;
 pub mod video;
 
@@ -0,0 +1,195 @@
+//! The secondary subtitle: a second track shown at the same time as the
+//! primary one, drawn by a [`CueEngine`] of its own so it can have its own
+//! region and style (normally [`crate::cue_ir::CueStyle::top_anchored`]).
+//!
+//! The primary track reaches the sink as a stream of cues already resolved to
+//! running time. The secondary one has no stream behind it: its producer
+//! parses a whole subtitle file up front and hands every cue over at once, in
+//! stream time. They are kept here and rescheduled onto the engine whenever
+//! the video segment changes, so a seek, a rate change or a delay needs
+//! nothing from the producer.
+
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+
+use crate::cue::{CueEngine, CueInput, TextFormat};
+
+/// One cue of a parsed file, in stream time.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimedCue {
+    pub format: TextFormat,
+    pub text: String,
+    pub start: gst::ClockTime,
+    /// `None` means open-ended, as for [`CueInput::end_rt`].
+    pub end: Option<gst::ClockTime>,
+}
+
+#[derive(Default)]
+struct State {
+    cues: Vec<TimedCue>,
+    /// Added to every cue's times, positive for later.
+    delay_ns: i64,
+    /// The sink's current video segment. `None` between a flush and the next
+    /// segment, when nothing can be scheduled.
+    segment: Option<gst::FormattedSegment<gst::ClockTime>>,
+}
+
+/// The secondary track's cues and their engine. Cheap to clone, like
+/// [`CueEngine`].
+#[derive(Clone, Default)]
+pub struct SecondaryCues {
+    engine: CueEngine,
+    state: Arc<Mutex<State>>,
+}
+
+impl SecondaryCues {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The engine the track is drawn by. The sink evaluates it per frame
+    /// beside its own; the style is the caller's to set.
+    pub fn engine(&self) -> &CueEngine {
+        &self.engine
+    }
+
+    /// Show `cues` from now on, in place of whatever was shown before.
+    pub fn set_cues(&self, cues: Vec<TimedCue>) {
+        let mut state = self.state.lock();
+        state.cues = cues;
+        self.reschedule(&state);
+    }
+
+    /// Stop showing the secondary track.
+    pub fn clear(&self) {
+        self.set_cues(Vec::new());
+    }
+
+    /// Shift every cue by `delay`, positive for later.
+    pub fn set_delay(&self, delay: gst::Signed<gst::ClockTime>) {
+        let delay_ns = match delay {
+            gst::Signed::Positive(t) => t.nseconds() as i64,
+            gst::Signed::Negative(t) => -(t.nseconds() as i64),
+        };
+        let mut state = self.state.lock();
+        if state.delay_ns != delay_ns {
+            state.delay_ns = delay_ns;
+            self.reschedule(&state);
+        }
+    }
+
+    /// The sink's SEGMENT event: the stream-time cues are rescheduled against
+    /// the running time it defines.
+    pub fn set_video_segment(&self, segment: &gst::Segment) {
+        self.engine.set_video_segment(segment);
+        let mut state = self.state.lock();
+        state.segment = segment.downcast_ref::<gst::ClockTime>().cloned();
+        self.reschedule(&state);
+    }
+
+    /// FLUSH_STOP. The cues are kept, the next segment schedules them again.
+    pub fn flush(&self) {
+        self.engine.flush();
+        self.state.lock().segment = None;
+    }
+
+    /// STREAM_START. The cues are kept: they belong to the item, and the
+    /// producer clears them when the item changes.
+    pub fn reset_timeline(&self) {
+        self.engine.reset_timeline();
+        self.state.lock().segment = None;
+    }
+
+    fn reschedule(&self, state: &State) {
+        self.engine.clear();
+        let Some(segment) = state.segment.as_ref() else {
+            return;
+        };
+        for cue in &state.cues {
+            if let Some(input) = schedule(cue, segment, state.delay_ns) {
+                self.engine.submit(input);
+            }
+        }
+    }
+}
+
+/// `cue` in `segment`'s running time, clipped to the segment the way the
+/// primary track's cues are. `None` for a cue wholly outside it.
+fn schedule(
+    cue: &TimedCue,
+    segment: &gst::FormattedSegment<gst::ClockTime>,
+    delay_ns: i64,
+) -> Option<CueInput> {
+    let shift = |t: gst::ClockTime| {
+        let ns = (t.nseconds() as i64).saturating_add(delay_ns);
+        gst::ClockTime::from_nseconds(ns.max(0) as u64)
+    };
+    let start = segment.position_from_stream_time(shift(cue.start))?;
+    let end = match cue.end {
+        Some(end) => Some(segment.position_from_stream_time(shift(end))?),
+        None => None,
+    };
+    let (start, stop) = segment.clip(start, end)?;
+    let start = start?;
+    if stop == Some(start) {
+        return None;
+    }
+    let start_rt = segment.to_running_time(start)?;
+    let end_rt = stop.and_then(|stop| segment.to_running_time(stop));
+    // A reverse segment measures from `stop`, which inverts the pair.
+    let (start_rt, end_rt) = match end_rt {
+        Some(end_rt) if end_rt < start_rt => (end_rt, Some(start_rt)),
+        end_rt => (start_rt, end_rt),
+    };
+    Some(CueInput {
+        format: cue.format.clone(),
+        text: cue.text.clone(),
+        start_rt,
+        end_rt,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cue(text: &str, start_ms: u64, end_ms: u64) -> TimedCue {
+        TimedCue {
+            format: TextFormat::Utf8,
+            text: text.to_owned(),
+            start: gst::ClockTime::from_mseconds(start_ms),
+            end: Some(gst::ClockTime::from_mseconds(end_ms)),
+        }
+    }
+
+    fn segment(start_ms: u64) -> gst::FormattedSegment<gst::ClockTime> {
+        let mut segment = gst::FormattedSegment::<gst::ClockTime>::new();
+        segment.set_start(gst::ClockTime::from_mseconds(start_ms));
+        segment.set_time(gst::ClockTime::from_mseconds(start_ms));
+        segment
+    }
+
+    #[test]
+    fn cues_follow_the_segment_and_the_delay() {
+        gst::init().unwrap();
+        let ms = gst::ClockTime::from_mseconds;
+
+        // A seek to 5s: running time restarts there, an earlier cue is gone
+        // and one the seek landed inside starts at once.
+        let seeked = segment(5_000);
+        assert_eq!(schedule(&cue("gone", 1_000, 2_000), &seeked, 0), None);
+        let inside = schedule(&cue("inside", 4_000, 6_000), &seeked, 0).unwrap();
+        assert_eq!((inside.start_rt, inside.end_rt), (ms(0), Some(ms(1_000))));
+        let later = schedule(&cue("later", 7_000, 8_000), &seeked, 0).unwrap();
+        assert_eq!((later.start_rt, later.end_rt), (ms(2_000), Some(ms(3_000))));
+
+        // Half a second earlier, a cue that ended just after the seek target
+        // ends before it. Half a second later, a cue moves along with it.
+        assert!(schedule(&cue("inside", 1_000, 5_400), &seeked, 0).is_some());
+        let earlier = schedule(&cue("inside", 1_000, 5_400), &seeked, -500_000_000);
+        assert_eq!(earlier, None);
+        let delayed = schedule(&cue("later", 7_000, 8_000), &seeked, 500_000_000).unwrap();
+        assert_eq!(delayed.start_rt, ms(2_500));
+    }
+}
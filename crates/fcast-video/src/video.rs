@@ -189,6 +189,7 @@ pub mod imp {
 
     use crate::{
         cue::CueEngine,
+        secondary::SecondaryCues,
         video::{Overlay, OverlaySpace},
     };
 
@@ -389,6 +390,10 @@ pub mod imp {
         /// overlay-composition meta a bitmap source attached upstream, and a
         /// repaint with no new frame reads `current_overlays()`.
         pub(super) engine: CueEngine,
+        /// The secondary subtitle track, drawn by its own engine on top of
+        /// the first one's overlays. Fed whole files by the receiver, not by
+        /// the pipeline (see [`crate::secondary`]).
+        pub(super) secondary: SecondaryCues,
     }
 
     #[glib::object_subclass]
@@ -445,6 +450,9 @@ pub mod imp {
                     // because no upstream renderer negotiates window space any
                     // more.
                     self.engine.set_canvas(resolution.width, resolution.height);
+                    self.secondary
+                        .engine()
+                        .set_canvas(resolution.width, resolution.height);
                 }
                 _ => unreachable!(),
             }
@@ -646,9 +654,16 @@ pub mod imp {
                 gst::EventView::Segment(ev) => {
                     gst::debug!(CAT, imp = self, "video segment: {:?}", ev.segment());
                     self.engine.set_video_segment(ev.segment());
+                    self.secondary.set_video_segment(ev.segment());
+                }
+                gst::EventView::FlushStop(_) => {
+                    self.engine.flush();
+                    self.secondary.flush();
+                }
+                gst::EventView::StreamStart(_) => {
+                    self.engine.reset_timeline();
+                    self.secondary.reset_timeline();
                 }
-                gst::EventView::FlushStop(_) => self.engine.flush(),
-                gst::EventView::StreamStart(_) => self.engine.reset_timeline(),
                 _ => (),
             }
 
@@ -737,6 +752,7 @@ pub mod imp {
             // and the meta path is kept for a bitmap renderer that no current
             // pipeline autoplugs.
             overlays.extend(self.engine.overlays_for(frame_running_time));
+            overlays.extend(self.secondary.engine().overlays_for(frame_running_time));
 
             let config = self.config.lock();
             let mdi = config.mastering_display_info;
@@ -798,12 +814,14 @@ impl FSink {
         // The engine reports every change that is not carried by a frame; the
         // sink republishes it as a signal, beside "frame-available". Weak, so
         // the callback never keeps the sink alive.
-        let weak = sink.downgrade();
-        sink.imp().engine.set_on_change(move || {
-            if let Some(sink) = weak.upgrade() {
-                sink.emit_by_name::<()>("overlays-changed", &[]);
-            }
-        });
+        for engine in [&sink.imp().engine, sink.imp().secondary.engine()] {
+            let weak = sink.downgrade();
+            engine.set_on_change(move || {
+                if let Some(sink) = weak.upgrade() {
+                    sink.emit_by_name::<()>("overlays-changed", &[]);
+                }
+            });
+        }
 
         // Pay the fontconfig/fontmap first-use cost here, on the raster thread,
         // instead of inside the first cue.
@@ -817,4 +835,10 @@ impl FSink {
     pub fn cue_engine(&self) -> crate::cue::CueEngine {
         self.imp().engine.clone()
     }
+
+    /// Handle on the secondary subtitle track, for whoever sets its cues and
+    /// style. Repaints go through the same `overlays-changed` signal.
+    pub fn secondary_cues(&self) -> crate::secondary::SecondaryCues {
+        self.imp().secondary.clone()
+    }
 }
@@ -277,6 +277,10 @@ pub enum PlaybinEvent {
         live: bool,
     },
     Tags(gst::TagList),
+    /// The table of contents of the current item (MKV/MP4 chapters and
+    /// editions). A gapless item's TOC is held back like its collection and
+    /// delivered after [`PlaybinEvent::PreparedActivated`].
+    Toc(gst::Toc),
     /// The volume changed, a deterministic `notify::volume` from the
     /// dedicated volume element (see
     /// [`FcastPlaybin::set_volume`](crate::FcastPlaybin::set_volume)). Also
@@ -385,6 +389,14 @@ pub enum PlaybinEvent {
     SourceBackoff {
         remaining_ms: u64,
     },
+    /// The main input's source lost its connection mid-stream and is
+    /// reconnecting (posted by `fcasthttpsrc` as `reconnect` progress).
+    /// `false` once it resumed or gave up; giving up also posts the error.
+    /// The caller shows "reconnecting" instead of treating the stall as a
+    /// failure.
+    SourceReconnecting {
+        reconnecting: bool,
+    },
     /// A prepared next input
     /// ([`FcastPlaybin::prepare_next_async`](crate::FcastPlaybin::prepare_next_async))
     /// went live: the current item drained and decodebin3 switched to the
@@ -272,6 +272,20 @@ impl Inner {
                 debug: warning.debug().map(|debug| debug.to_string()),
             },
             MessageView::Tag(tag) => PlaybinEvent::Tags(tag.tags()),
+            MessageView::Toc(toc) => {
+                let (toc, _updated) = toc.toc();
+                // Demuxers post their TOC as they start, so one arriving with a
+                // prepared next input belongs to that item: the current one
+                // posted its own long before the pre-arm. decodebin3 posts on
+                // behalf of the prepared input's parsebin, so ancestry cannot
+                // tell the two apart.
+                if let Some(prepared) = self.prepared.lock().as_mut() {
+                    debug!("holding the prepared next input's table of contents");
+                    prepared.pending_toc = Some(toc);
+                    return None;
+                }
+                PlaybinEvent::Toc(toc)
+            }
             MessageView::Buffering(buffering) => {
                 // The prepared next input buffers ahead while the CURRENT
                 // item plays; its levels must not drive the caller's
@@ -375,6 +389,23 @@ impl Inner {
                     _ => return None,
                 }
             }
+            MessageView::Progress(progress) => {
+                let (type_, code, _) = progress.get();
+                // fcasthttpsrc reconnecting after a dropped connection. Only
+                // the live main input stalls playback: a prepared next item
+                // or an external subtitle file recovers on its own.
+                if code != "reconnect"
+                    || !matches!(self.classify_error_src(msg.src()), ErrorSource::Main)
+                {
+                    return None;
+                }
+                PlaybinEvent::SourceReconnecting {
+                    reconnecting: matches!(
+                        type_,
+                        gst::ProgressType::Start | gst::ProgressType::Continue
+                    ),
+                }
+            }
             _ => return None,
         };
         Some(event)
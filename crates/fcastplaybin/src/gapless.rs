@@ -33,6 +33,8 @@ pub(crate) struct PreparedNext {
     /// with the new generation: the same collection-then-selected order a
     /// fresh load produces.
     pub(crate) pending_collection: Option<gst::StreamCollection>,
+    /// The next item's table of contents, held back with the collection.
+    pub(crate) pending_toc: Option<gst::Toc>,
 }
 
 impl PreparedNext {
@@ -407,6 +409,7 @@ pub(crate) struct HeldActivation {
     /// [`PlaybinEvent::PreparedActivated`] (the fresh-load ordering the caller
     /// relies on).
     collection: Option<gst::StreamCollection>,
+    toc: Option<gst::Toc>,
 }
 
 impl Inner {
@@ -764,6 +767,7 @@ impl Inner {
         self.arm_or_emit_activation(
             HeldActivation {
                 collection: prepared.pending_collection,
+                toc: prepared.pending_toc,
             },
             &anchor,
             prepared.generation,
@@ -867,12 +871,15 @@ impl Inner {
     /// Emit a gapless activation's user-facing events in the canonical
     /// fresh-load order: [`PlaybinEvent::PreparedActivated`] (which the caller
     /// uses to adopt the new generation, letting the collection past its
-    /// supersession guard) followed by the new item's collection.
+    /// supersession guard) followed by the new item's collection and TOC.
     pub(crate) fn emit_held(&self, held: HeldActivation) {
         self.emit(PlaybinEvent::PreparedActivated);
         if let Some(collection) = held.collection {
             self.emit(PlaybinEvent::StreamCollection(collection));
         }
+        if let Some(toc) = held.toc {
+            self.emit(PlaybinEvent::Toc(toc));
+        }
     }
 
     /// Release a held gapless activation, if one is waiting. Called from the
@@ -1587,6 +1594,7 @@ mod tests {
             element: bin.upcast(),
             generation: 1,
             pending_collection: None,
+            pending_toc: None,
         };
         assert_eq!(prepared.stream_ids(), ids(&["prep-sid"]));
     }
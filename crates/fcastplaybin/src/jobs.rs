@@ -103,6 +103,10 @@ pub(crate) enum Job {
         generation: u64,
     },
     Seek(Seek),
+    /// Step one frame, see [`FcastPlaybin::step_frame_async`].
+    StepFrame {
+        forward: bool,
+    },
     RefreshSeek {
         seqnum: gst::Seqnum,
     },
@@ -434,6 +438,10 @@ pub(crate) fn stale_policy(job: &Job) -> StalePolicy {
         // no caller is stranded, and a fresh load elects a clock at preroll
         // anyway.
         Job::RecoverClock => StalePolicy::Drop,
+        // Relative to the item it was asked for. Run after a load it moves the
+        // new item off its start point by a frame nobody asked for.
+        // Fire-and-forget, so no caller is stranded.
+        Job::StepFrame { .. } => StalePolicy::Drop,
         // An attach that outlives its item hangs the previous item's subtitle
         // URL onto the new one, a cross-item ghost external, which suppresses
         // refresh seeks, refuses prepares and can wedge selection. The attach
@@ -656,6 +664,7 @@ pub(crate) fn poison_refusal(job: Job) -> Refusal {
         // work whose input is gone with the pipeline, and internal hygiene
         // that re-derives everything at execution.
         Job::SetState { .. }
+        | Job::StepFrame { .. }
         | Job::RecoverClock
         | Job::RecalculateLatency
         | Job::DetachSub { .. }
@@ -1470,6 +1479,14 @@ impl FcastPlaybin {
         self.queue_job(Job::Seek(seek));
     }
 
+    /// Queue a single frame step, forward or backward, for a pipeline settled
+    /// at PAUSED. Nothing is reported back: a step that cannot run (not
+    /// settled, no video framerate, reverse playback) is dropped, and one that
+    /// runs shows up as the next position the caller polls.
+    pub fn step_frame_async(&self, forward: bool) {
+        self.queue_job(Job::StepFrame { forward });
+    }
+
     /// Queue a flushing seek to the CURRENT position that keeps the pipeline
     /// in its current state, stamped with `seqnum` (failures come back as
     /// [`PlaybinEvent::RefreshSeekFailed`] with that seqnum). Used to force a
@@ -1621,6 +1638,7 @@ impl FcastPlaybin {
                 generation,
             } => self.run_load(input, start, generation),
             Job::Seek(seek) => self.run_seek(seek),
+            Job::StepFrame { forward } => self.run_step_frame(forward),
             Job::RefreshSeek { seqnum } => self.run_refresh_seek(seqnum),
             Job::RecoverClock => self.run_recover_clock(),
             Job::RecalculateLatency => self.run_recalculate_latency(),
@@ -1857,6 +1875,60 @@ impl FcastPlaybin {
         }
     }
 
+    /// Worker side of [`Job::StepFrame`]. Both directions are an ACCURATE
+    /// flushing seek one frame away, performed in place at PAUSED like
+    /// [`Job::RefreshSeek`]. A `GST_EVENT_STEP` would only advance the video
+    /// sink, leaving the audio sink a frame behind, and cannot go backwards.
+    fn run_step_frame(&self, forward: bool) {
+        let inner = &self.inner;
+        let (_, current, pending) = inner.pipeline.state(gst::ClockTime::ZERO);
+        if current != gst::State::Paused || pending != gst::State::VoidPending {
+            debug!(
+                ?current,
+                ?pending,
+                "dropping a frame step outside a settled PAUSED"
+            );
+            return;
+        }
+        let Some(frame) = self.frame_duration() else {
+            debug!("dropping a frame step: no fixed video framerate");
+            return;
+        };
+        let rate = inner.intended_timeline.lock().0;
+        if rate < 0.0 {
+            // The End-anchored reverse segment has no "one frame later" start to seek to.
+            debug!(rate, "dropping a frame step during reverse playback");
+            return;
+        }
+        let Some(position) = inner.pipeline.query_position::<gst::ClockTime>() else {
+            debug!("dropping a frame step: no position");
+            return;
+        };
+        let target = if forward {
+            position.saturating_add(frame)
+        } else {
+            position.saturating_sub(frame)
+        };
+
+        debug!(?position, ?target, ?frame, "Frame step");
+        let event = gst::event::Seek::new(
+            rate,
+            gst::SeekFlags::FLUSH | gst::SeekFlags::ACCURATE,
+            gst::SeekType::Set,
+            target,
+            gst::SeekType::None,
+            gst::ClockTime::NONE,
+        );
+        if !inner.pipeline.send_event(event) {
+            warn!(?target, "Frame step seek failed");
+            return;
+        }
+        // Same bookkeeping as a `Job::Seek` that reached the pipeline.
+        inner.clear_passing_eos_after_flush();
+        *inner.intended_timeline.lock() = (rate, target);
+        inner.forward_seek_to_live_externals(rate, target);
+    }
+
     /// Worker side of [`Job::RefreshSeek`].
     fn run_refresh_seek(&self, seqnum: gst::Seqnum) {
         let inner = &self.inner;
@@ -2096,6 +2168,7 @@ impl FcastPlaybin {
                     element,
                     generation,
                     pending_collection: None,
+                    pending_toc: None,
                 });
             }
             Err(err) => {
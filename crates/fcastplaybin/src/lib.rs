@@ -32,7 +32,7 @@ use std::{
     collections::VecDeque,
     sync::{
         Arc, OnceLock,
-        atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering},
+        atomic::{AtomicBool, AtomicI64, AtomicU32, AtomicU64, Ordering},
         mpsc,
     },
     thread::ThreadId,
@@ -338,6 +338,10 @@ struct Inner {
     /// `None` between the load reset and the first audio route, or for a
     /// video-only item.
     audio_sink: Mutex<Option<gst::Element>>,
+    /// The `ts-offset` (nanoseconds) the audio sink plays with, kept here so
+    /// each load's fresh sink is built with it. See
+    /// [`FcastPlaybin::set_audio_delay`].
+    audio_delay_ns: AtomicI64,
     /// The caller's event handler (see [`FcastPlaybin::set_event_handler`]).
     /// Events are silently dropped until one is installed.
     events: Mutex<Option<EventCallback>>,
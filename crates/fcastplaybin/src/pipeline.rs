@@ -3,7 +3,7 @@
 
 use std::sync::{
     Arc, OnceLock,
-    atomic::{AtomicBool, AtomicU64, Ordering},
+    atomic::{AtomicBool, AtomicI64, AtomicU64, Ordering},
     mpsc,
 };
 
@@ -107,6 +107,16 @@ pub(crate) fn send_rate_seek(
     }
 }
 
+/// Set an audio sink's `ts-offset`. autoaudiosink forwards it to the sink it
+/// resolves; a caller's factory sink without the property plays undelayed.
+fn apply_audio_delay(sink: &gst::Element, delay_ns: i64) {
+    if sink.find_property("ts-offset").is_some() {
+        sink.set_property("ts-offset", delay_ns);
+    } else if delay_ns != 0 {
+        warn!(element = %sink.name(), "audio sink has no ts-offset, ignoring the audio delay");
+    }
+}
+
 impl Inner {
     /// Build and install a fresh dynamic core (see `Core`): decodebin3 +
     /// streamsynchronizer, added to the pipeline at its current state, with
@@ -206,6 +216,7 @@ impl Inner {
                 .context("creating autoaudiosink")?,
             AudioSink::Factory(factory) => factory().context("building the audio sink")?,
         };
+        apply_audio_delay(&sink, self.audio_delay_ns.load(Ordering::Relaxed));
         self.pipeline.add(&sink).context("adding the audio sink")?;
         self.volume
             .link(&sink)
@@ -782,6 +793,7 @@ impl FcastPlaybin {
             video_sink,
             audio: sinks.audio,
             audio_sink: Mutex::default(),
+            audio_delay_ns: AtomicI64::default(),
             pipeline,
             core: Mutex::default(),
             token_src,
@@ -1211,6 +1223,25 @@ impl FcastPlaybin {
         self.inner.volume.set_property("volume", target);
     }
 
+    /// Play audio `delay` later than the video, or earlier for a negative
+    /// one, to make up for an output with its own lag (a Bluetooth speaker).
+    /// Applies to the current item's sink at once and to every later load's.
+    ///
+    /// Set as the audio sink's `ts-offset`, so the stream itself is untouched
+    /// and the pipeline clock keeps running from the same sink.
+    pub fn set_audio_delay(&self, delay: gst::Signed<gst::ClockTime>) {
+        let delay_ns = match delay {
+            gst::Signed::Positive(delay) => delay.nseconds() as i64,
+            gst::Signed::Negative(delay) => -(delay.nseconds() as i64),
+        };
+        if self.inner.audio_delay_ns.swap(delay_ns, Ordering::Relaxed) == delay_ns {
+            return;
+        }
+        if let Some(sink) = self.inner.audio_sink.lock().as_ref() {
+            apply_audio_delay(sink, delay_ns);
+        }
+    }
+
     /// The current volume (`0.0..=1.0`).
     pub fn volume(&self) -> f64 {
         self.inner.volume.property("volume")
@@ -1247,6 +1278,22 @@ impl FcastPlaybin {
         self.query_timeline(|element| element.query_duration::<gst::ClockTime>())
     }
 
+    /// One frame at the video sink's negotiated framerate. `None` for an
+    /// audio-only item (no video chain in the pipeline) and for a variable
+    /// framerate stream, which negotiates `0/1`.
+    pub(crate) fn frame_duration(&self) -> Option<gst::ClockTime> {
+        let sink = &self.inner.video_sink;
+        if sink.parent().is_none() {
+            return None;
+        }
+        let caps = sink.static_pad("sink")?.current_caps()?;
+        let framerate = caps.structure(0)?.get::<gst::Fraction>("framerate").ok()?;
+        if framerate.numer() <= 0 || framerate.denom() <= 0 {
+            return None;
+        }
+        gst::ClockTime::SECOND.mul_div_round(framerate.denom() as u64, framerate.numer() as u64)
+    }
+
     /// Run a timeline query against the authoritative element: the per-load
     /// audio sink (the pipeline clock and the held-activation anchor), else
     /// the video sink, else the pipeline as a whole. One helper so
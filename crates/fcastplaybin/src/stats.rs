@@ -4,7 +4,8 @@
 //! invariant: a text track that is alive but undeliverable, a descent that
 //! leaked its thread, a flush pair that latched a multiqueue slot. None of them
 //! change behaviour, all of them answer "did the shape happen", and the tests
-//! are the only readers.
+//! are the main readers. The receiver's metrics endpoint exports them as they
+//! are, through [`Stats::counters`].
 //!
 //! # Why a snapshot instead of one accessor per counter
 //!
@@ -73,6 +74,12 @@ macro_rules! counters {
                 }
             }
 
+            /// Every counter as a `(name, value)` pair, in declaration order.
+            #[doc(hidden)]
+            pub fn counters(&self) -> Vec<(&'static str, u64)> {
+                vec![$( (stringify!($field), self.$field), )*]
+            }
+
             /// Counters declared `zero` that moved since `before`, as
             /// `(name, delta)` pairs in declaration order.
             ///
@@ -258,6 +265,18 @@ mod tests {
         assert_eq!(moved, vec![("teardown_descent_stuck", 3)]);
     }
 
+    #[test]
+    fn counters_list_every_field_by_name() {
+        let snapshot = GlobalStats {
+            slot_unlatches: 7,
+            ..GlobalStats::default()
+        };
+        let counters = snapshot.counters();
+        assert_eq!(counters.first(), Some(&("text_drain_interlocks", 0)));
+        assert!(counters.contains(&("slot_unlatches", 7)));
+        assert_eq!(counters.last(), Some(&("slot_seed_refusals", 0)));
+    }
+
     #[test]
     fn a_flat_snapshot_pair_reports_nothing() {
         let snapshot = GlobalStats {
@@ -29,7 +29,7 @@ fn ids(names: &[&str]) -> Vec<String> {
 /// families), is idempotent against whatever world it finds, or has a
 /// caller blocked on its completion.
 #[test]
-fn stale_policy_drop_set_is_exactly_load_recoverclock_attachsub_dispatchselection() {
+fn stale_policy_drop_set_is_exactly_load_recoverclock_stepframe_attachsub_dispatchselection() {
     // Only for `gst::Seqnum::next` below; no pipeline is built here.
     gst::init().unwrap();
     let id = ExternalSubId(1);
@@ -39,7 +39,7 @@ fn stale_policy_drop_set_is_exactly_load_recoverclock_attachsub_dispatchselectio
     };
 
     // Supersession by a later load or stop is precisely what makes these
-    // four wrong to carry out. Nothing else may join them without a
+    // five wrong to carry out. Nothing else may join them without a
     // named field bug it prevents and a nobody-is-stranded argument.
     let load = Job::Load {
         input: uri(),
@@ -48,14 +48,15 @@ fn stale_policy_drop_set_is_exactly_load_recoverclock_attachsub_dispatchselectio
     };
     pinned(load, StalePolicy::Drop);
     pinned(Job::RecoverClock, StalePolicy::Drop);
+    pinned(Job::StepFrame { forward: true }, StalePolicy::Drop);
     let attach = Job::AttachSub {
         id,
         url: "file:///subs.srt".to_string(),
     };
     pinned(attach, StalePolicy::Drop);
-    // The fourth: a selection names the stream ids of the collection it
+    // The fifth: a selection names the stream ids of the collection it
     // was formed against, and it does the outgoing item's text-branch
-    // surgery on the way out. Unlike the three above, this drop is
+    // surgery on the way out. Unlike the four above, this drop is
     // REPORTED (`dispatch_failed` in `run_job`'s drop path), because the
     // engine recorded the wait before the job was ever queued.
     let dispatch = Job::DispatchSelection {
@@ -319,6 +320,7 @@ fn the_poison_gate_settles_every_job_a_caller_waits_on() {
         },
         "nothing",
     );
+    settles(Job::StepFrame { forward: false }, "nothing");
     settles(Job::RecoverClock, "nothing");
     settles(Job::RecalculateLatency, "nothing");
     settles(Job::DetachSub { id }, "nothing");
@@ -152,6 +152,59 @@ impl<'de> Deserialize<'de> for Metadata {
     }
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrackType {
+    #[serde(rename = "TEXT")]
+    Text,
+    #[serde(rename = "AUDIO")]
+    Audio,
+    #[serde(rename = "VIDEO")]
+    Video,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextTrackType {
+    #[serde(rename = "SUBTITLES")]
+    Subtitles,
+    #[serde(rename = "CAPTIONS")]
+    Captions,
+    #[serde(rename = "DESCRIPTIONS")]
+    Descriptions,
+    #[serde(rename = "CHAPTERS")]
+    Chapters,
+    #[serde(rename = "METADATA")]
+    Metadata,
+}
+
+/// Describes a track of the media, embedded or side-loaded by the sender.
+///
+/// <https://developers.google.com/cast/docs/media/messages#Track>
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Track {
+    /// Unique ID of the track within the context of a MediaInformation
+    /// object.
+    #[serde(rename = "trackId")]
+    pub track_id: u32,
+    #[serde(rename = "type")]
+    pub track_type: TrackType,
+    /// optional The URL of a side-loaded track, absent for tracks embedded in
+    /// the media.
+    #[serde(rename = "trackContentId", skip_serializing_if = "Option::is_none")]
+    pub track_content_id: Option<String>,
+    /// optional MIME type of the side-loaded track.
+    #[serde(rename = "trackContentType", skip_serializing_if = "Option::is_none")]
+    pub track_content_type: Option<String>,
+    /// optional The kind of text track, only for `TEXT` tracks.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subtype: Option<TextTrackType>,
+    /// optional Human readable name of the track.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// optional RFC 5646 language tag of the track.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+}
+
 /// <https://developers.google.com/cast/docs/media/messages#MediaInformation>
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct MediaInformation {
@@ -170,6 +223,9 @@ pub struct MediaInformation {
     pub metadata: Option<Metadata>,
     /// Duration of the currently playing stream in seconds
     pub duration: Option<f64>,
+    /// optional The tracks of the media.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tracks: Option<Vec<Track>>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
@@ -248,6 +304,12 @@ pub struct MediaStatus {
     /// * 8  Stream mute
     /// * 16  Skip forward
     /// * 32  Skip backward
+    /// * 64  Queue next
+    /// * 128  Queue previous
+    /// * 256  Queue shuffle
+    /// * 1024  Queue repeat all
+    /// * 2048  Queue repeat one
+    /// * 4096  Edit tracks
     ///
     /// Combinations are summations, for example
     /// Pause+Seek+StreamVolume+Mute == 15.
@@ -255,21 +317,39 @@ pub struct MediaStatus {
     pub supported_media_commands: u64,
     /// Stream volume
     pub volume: Volume,
+    /// optional The items of the queue being played, absent when the media
+    /// was loaded on its own.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub items: Option<Vec<QueueItem>>,
+    /// optional The `itemId` of the queue item being played.
+    #[serde(rename = "currentItemId", skip_serializing_if = "Option::is_none")]
+    pub current_item_id: Option<u32>,
+    /// optional How the queue is walked.
+    #[serde(rename = "repeatMode", skip_serializing_if = "Option::is_none")]
+    pub repeat_mode: Option<QueueRepeatMode>,
+    /// optional The `trackId`s of the tracks that are playing.
+    #[serde(rename = "activeTrackIds", skip_serializing_if = "Option::is_none")]
+    pub active_track_ids: Option<Vec<u32>>,
 }
 
 /// <https://developers.google.com/cast/docs/reference/web_sender/chrome.cast.media.QueueItem>
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct QueueItem {
-    /// Whether the media will automatically play.
-    pub autoplay: bool,
+    /// Unique identifier of the item in the queue, assigned by the receiver.
+    /// Absent in items a sender is adding.
+    #[serde(rename = "itemId", skip_serializing_if = "Option::is_none")]
+    pub item_id: Option<u32>,
+    /// Whether the media will automatically play. Default is true.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub autoplay: Option<bool>,
     pub media: MediaInformation,
     /// Playback duration of the item in seconds. If it is larger than the
     /// actual duration - startTime it will be limited to the actual
     /// duration - startTime. It can be negative, in such case the duration will
     /// be the actual item duration minus the duration provided. A duration
     /// of value zero effectively means that the item will not be played.
-    #[serde(rename = "playbackDuration")]
-    pub playback_duration: i32,
+    #[serde(rename = "playbackDuration", skip_serializing_if = "Option::is_none")]
+    pub playback_duration: Option<f64>,
     // This parameter is a hint for the receiver to preload this media item before it is played. It
     // allows for a smooth transition between items played from the queue.
     //
@@ -284,8 +364,8 @@ pub struct QueueItem {
     // currentItem, the preload will just happen as soon as possible. #[serde(rename =
     // "preloadTime")] pub preload_time: f64,
     /// Seconds from the beginning of the media to start playback.
-    #[serde(rename = "startTime")]
-    pub start_time: f64,
+    #[serde(rename = "startTime", skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<f64>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -318,7 +398,7 @@ pub struct Application {
     pub universal_app_id: Option<String>,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
 pub enum QueueRepeatMode {
     /// Items are played in order, and when the queue is completed (the last
     /// item has ended) the media session is terminated.
@@ -487,6 +567,9 @@ pub mod namespaces {
             /// The media playback rate.
             #[serde(rename = "playbackRate", skip_serializing_if = "Option::is_none")]
             playback_rate: Option<f64>,
+            /// The `trackId`s of the tracks to start with.
+            #[serde(rename = "activeTrackIds", skip_serializing_if = "Option::is_none")]
+            active_track_ids: Option<Vec<u32>>,
         },
         /// Sets the current position in the stream. Triggers a STATUS event
         /// notification to all sender applications. If the position
@@ -598,8 +681,9 @@ pub mod namespaces {
             /// Array of items to load. It is sorted (first element will be
             /// played first). Must not be null or empty.
             items: Vec<QueueItem>,
+            /// Default is REPEAT_OFF.
             #[serde(rename = "repeatMode")]
-            repeat_mode: QueueRepeatMode,
+            repeat_mode: Option<QueueRepeatMode>,
             /// The index of the item in the items array that must be the first
             /// currentItem (the item that will be played first).
             /// Note this is the index of the array (starts at 0) and not the
@@ -612,18 +696,94 @@ pub mod namespaces {
             /// decides to cast. In this way the sender app does not need to map
             /// between the local and remote queue positions or
             /// saves one extra request to update the queue.
+            /// Default is 0.
             #[serde(rename = "startIndex")]
-            start_index: u32,
+            start_index: Option<u32>,
             #[serde(rename = "queueType")]
             queue_type: Option<String>,
         },
+        /// Inserts items into the queue.
+        ///
+        /// <https://developers.google.com/cast/docs/reference/web_receiver/cast.framework.messages.QueueInsertRequestData>
+        #[serde(rename = "QUEUE_INSERT")]
+        QueueInsert {
+            #[serde(rename = "requestId")]
+            request_id: u64,
+            #[serde(rename = "mediaSessionId")]
+            media_session_id: serde_json::Value,
+            /// Items to insert, in order.
+            items: Vec<QueueItem>,
+            /// ID of the item that will be located immediately after the
+            /// inserted list. If absent the list is appended to the end of the
+            /// queue.
+            #[serde(rename = "insertBefore")]
+            insert_before: Option<u32>,
+        },
+        /// Updates the current item, the repeat mode or jumps in the queue.
+        ///
+        /// <https://developers.google.com/cast/docs/reference/web_receiver/cast.framework.messages.QueueUpdateRequestData>
         #[serde(rename = "QUEUE_UPDATE")]
         QueueUpdate {
             #[serde(rename = "requestId")]
             request_id: u64,
             #[serde(rename = "mediaSessionId")]
-            media_session_id: String,
+            media_session_id: serde_json::Value,
+            /// ID of the item to play.
+            #[serde(rename = "currentItemId", skip_serializing_if = "Option::is_none")]
+            current_item_id: Option<u32>,
+            /// Skip or go back this many items from the current one.
             jump: Option<i32>,
+            #[serde(rename = "repeatMode", skip_serializing_if = "Option::is_none")]
+            repeat_mode: Option<QueueRepeatMode>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            shuffle: Option<bool>,
+        },
+        /// Removes items from the queue. The current item moves to the one
+        /// after it if it is removed.
+        ///
+        /// <https://developers.google.com/cast/docs/reference/web_receiver/cast.framework.messages.QueueRemoveRequestData>
+        #[serde(rename = "QUEUE_REMOVE")]
+        QueueRemove {
+            #[serde(rename = "requestId")]
+            request_id: u64,
+            #[serde(rename = "mediaSessionId")]
+            media_session_id: serde_json::Value,
+            #[serde(rename = "itemIds")]
+            item_ids: Vec<u32>,
+        },
+        /// Moves items to just before another one, keeping their order.
+        ///
+        /// <https://developers.google.com/cast/docs/reference/web_receiver/cast.framework.messages.QueueReorderRequestData>
+        #[serde(rename = "QUEUE_REORDER")]
+        QueueReorder {
+            #[serde(rename = "requestId")]
+            request_id: u64,
+            #[serde(rename = "mediaSessionId")]
+            media_session_id: serde_json::Value,
+            /// IDs of the items to move, in the order they end up in.
+            #[serde(rename = "itemIds")]
+            item_ids: Vec<u32>,
+            /// ID of the item that will be located immediately after the moved
+            /// items. If absent they are moved to the end of the queue.
+            #[serde(rename = "insertBefore")]
+            insert_before: Option<u32>,
+        },
+        /// Changes which tracks are playing.
+        ///
+        /// <https://developers.google.com/cast/docs/reference/web_receiver/cast.framework.messages.EditTracksInfoRequestData>
+        #[serde(rename = "EDIT_TRACKS_INFO")]
+        EditTracksInfo {
+            #[serde(rename = "requestId")]
+            request_id: u64,
+            #[serde(rename = "mediaSessionId")]
+            media_session_id: Option<serde_json::Value>,
+            /// The `trackId`s of the tracks to play, tracks left out are turned
+            /// off.
+            #[serde(rename = "activeTrackIds", skip_serializing_if = "Option::is_none")]
+            active_track_ids: Option<Vec<u32>>,
+            /// Turns the text tracks on or off.
+            #[serde(rename = "enableTextTracks", skip_serializing_if = "Option::is_none")]
+            enable_text_tracks: Option<bool>,
         },
         /// <https://developers.google.com/cast/docs/media/messages#InvalidPlayerState>
         #[serde(rename = "INVALID_PLAYER_STATE")]
@@ -706,4 +866,30 @@ mod tests {
             meta,
         );
     }
+
+    #[test]
+    fn deserialize_queue_load_with_defaults() {
+        let msg = r#"{
+            "type": "QUEUE_LOAD",
+            "requestId": 7,
+            "items": [
+                {"media": {"contentId": "http://a/1.mp4", "streamType": "BUFFERED", "contentType": "video/mp4"}},
+                {"media": {"contentId": "http://a/2.mp4", "streamType": "BUFFERED", "contentType": "video/mp4"}, "autoplay": false, "startTime": 12.5}
+            ]
+        }"#;
+        let namespaces::Media::QueueLoad {
+            items,
+            repeat_mode,
+            start_index,
+            ..
+        } = serde_json::from_str::<namespaces::Media>(msg).unwrap()
+        else {
+            panic!("not a QUEUE_LOAD");
+        };
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].autoplay, None);
+        assert_eq!(items[1].start_time, Some(12.5));
+        assert_eq!(repeat_mode, None);
+        assert_eq!(start_index, None);
+    }
 }
@@ -390,4 +390,16 @@ impl device::DeviceEventHandler for DeviceHandler {
     fn queue_changed(&self, _queue: device::QueueState) {}
 
     fn command_error(&self, _error: device::ReceiverError) {}
+
+    fn paired(&self, _token: String) {}
+
+    fn chapters_available(&self, _chapters: Vec<device::Chapter>) {}
+
+    fn sleep_timer_changed(&self, _timer: device::SleepTimer) {}
+
+    fn playback_history(&self, _entries: Vec<device::HistoryEntry>) {}
+
+    fn subtitle_style_changed(&self, _style: device::SubtitleStyle) {}
+
+    fn delay_changed(&self, _delay: device::MediaDelay) {}
 }
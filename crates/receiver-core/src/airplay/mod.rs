@@ -1,14 +1,15 @@
-//! AirPlay screen-mirroring receiver: `_airplay._tcp`, served over a hybrid
-//! HTTP/1.1 + RTSP/1.0 connection (see [`http`]). Distinct from the legacy
-//! RAOP/AirTunes audio receiver in [`crate::raop`].
+//! AirPlay screen-mirroring, URL-casting and photo receiver: `_airplay._tcp`,
+//! served over a hybrid HTTP/1.1 + RTSP/1.0 connection (see [`crate::http`]).
+//! Distinct from the legacy RAOP/AirTunes audio receiver in [`crate::raop`].
 
 mod audio;
 mod crypto;
 mod h264;
-mod http;
 mod ntp;
+mod photo;
 pub(crate) mod source;
 mod stream;
+pub(crate) mod video;
 
 pub(crate) use source::AirPlayContext;
 
@@ -20,24 +21,33 @@ use sha2::{Digest, Sha256};
 use tokio::net::TcpListener;
 use tracing::{debug, instrument, warn};
 
-use crate::MessageSender;
+use crate::{
+    MessageSender,
+    application::PacketOrigin,
+    fcast::FlatLoadMessage,
+    http::{Connection, Request, Response},
+    rtsp_auth::DigestAuth,
+};
 use apple_fairplay::FairPlay;
 use crypto::MirrorCipher;
-use http::{Connection, Request, Response};
 
 /// Apple's default AirPlay port; iOS expects the HTTP/RTSP server here.
 pub const AIRPLAY_TCP_PORT: u16 = 7000;
 
-/// TXT-record features bitmask, low/high 32-bit words. Bit 27 ("legacy
-/// pairing") is deliberately off, so the client skips the `/pair-setup`
-/// ed25519/x25519 handshake and goes straight to `/fp-setup`, whose recovered
-/// AES key is used directly (no ECDH hashing).
-const FEATURES_LO: u32 = 0x527F_FEE6;
+/// TXT-record features bitmask, low/high 32-bit words. Bit 0 ("video")
+/// offers URL casting (`/play`). Bit 27 ("legacy pairing") is deliberately
+/// off, so the client skips the `/pair-setup` ed25519/x25519 handshake and
+/// goes straight to `/fp-setup`, whose recovered AES key is used directly (no
+/// ECDH hashing).
+const FEATURES_LO: u32 = 0x527F_FEE7;
 const FEATURES_HI: u32 = 0x0;
 
 /// Combined 64-bit features bitmask, reported in the `GET /info` plist.
 const FEATURES: u64 = ((FEATURES_HI as u64) << 32) | (FEATURES_LO as u64);
 
+/// Largest request body accepted: `PUT /photo` carries a whole JPEG.
+const MAX_BODY_BYTES: usize = 32 * 1024 * 1024;
+
 /// Mirroring geometry in `GET /info`; iOS picks the streamed resolution from
 /// it.
 const DISPLAY_WIDTH: u64 = 1920;
@@ -48,6 +58,13 @@ const DISPLAY_HEIGHT: u64 = 1080;
 const MODEL: &str = "AppleTV3,2";
 const SOURCE_VERSION: &str = "220.68";
 
+/// How long `/playback-info` reports a `/play` as loading before giving up
+/// on it.
+const PLAY_LOAD_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(30);
+
+/// `statusFlags` (and TXT `flags`) bit asking senders for a password.
+const STATUS_PASSWORD_REQUIRED: u64 = 0x80;
+
 /// Stable AirPlay "pairing identifier" advertised in the `pi` TXT record.
 const PAIRING_ID: &str = "2e388006-13ba-4041-9a67-25dd4a43d536";
 
@@ -58,9 +75,20 @@ pub struct Configuration {
     pub hw_addr: [u8; 6],
     /// Public key advertised in the `pk` TXT record (hex).
     pub pk: String,
+    /// Password senders must authenticate with, if any.
+    pub password: Option<String>,
 }
 
 impl Configuration {
+    /// `flags` with the password bit set when one is configured.
+    fn status_flags(&self, flags: u64) -> u64 {
+        if self.password.is_some() {
+            flags | STATUS_PASSWORD_REQUIRED
+        } else {
+            flags
+        }
+    }
+
     /// `deviceid` in AirPlay MAC form, e.g. `aa:bb:cc:dd:ee:ff`.
     fn device_id(&self) -> String {
         self.hw_addr
@@ -106,7 +134,10 @@ impl Configuration {
         d.insert("name".into(), Value::String(self.device_name.clone()));
         d.insert("pi".into(), Value::String(PAIRING_ID.to_owned()));
         d.insert("vv".into(), Value::Integer(2i64.into()));
-        d.insert("statusFlags".into(), Value::Integer(68i64.into()));
+        d.insert(
+            "statusFlags".into(),
+            Value::Integer((self.status_flags(68) as i64).into()),
+        );
         d.insert("keepAliveLowPower".into(), Value::Integer(1i64.into()));
         d.insert(
             "sourceVersion".into(),
@@ -223,7 +254,10 @@ fn audio_array(formats: u64) -> Vec<plist::Value> {
 }
 
 /// Build the `_airplay._tcp` mDNS service and its connection-handler config.
-pub fn service_info(device_name: String) -> Result<(ServiceInfo, Configuration)> {
+pub fn service_info(
+    device_name: String,
+    password: Option<String>,
+) -> Result<(ServiceInfo, Configuration)> {
     let hw_addr = crate::raop::device_name_hash(&device_name);
 
     // Discovery only needs a stable 64-char hex string here.
@@ -236,6 +270,7 @@ pub fn service_info(device_name: String) -> Result<(ServiceInfo, Configuration)>
         device_name: device_name.clone(),
         hw_addr,
         pk,
+        password,
     };
 
     let host_name = format!("{device_name}.local.");
@@ -261,13 +296,16 @@ fn txt_properties(config: &Configuration) -> HashMap<String, String> {
             "features".to_owned(),
             format!("0x{FEATURES_LO:X},0x{FEATURES_HI:X}"),
         ),
-        ("flags".to_owned(), "0x4".to_owned()),
+        (
+            "flags".to_owned(),
+            format!("0x{:X}", config.status_flags(0x4)),
+        ),
         ("model".to_owned(), MODEL.to_owned()),
         ("pk".to_owned(), config.pk.clone()),
         ("pi".to_owned(), PAIRING_ID.to_owned()),
         ("srcvers".to_owned(), SOURCE_VERSION.to_owned()),
         ("vv".to_owned(), "2".to_owned()),
-        ("pw".to_owned(), "false".to_owned()),
+        ("pw".to_owned(), config.password.is_some().to_string()),
     ])
 }
 
@@ -278,24 +316,7 @@ pub async fn handle_sender(
     msg_tx: MessageSender,
     airplay_context: AirPlayContext,
 ) {
-    let peer_ip = stream
-        .peer_addr()
-        .map(|addr| addr.ip())
-        .unwrap_or(std::net::IpAddr::V4(std::net::Ipv4Addr::LOCALHOST));
-    let mut handler = Handler {
-        config,
-        connection: Connection::new(stream),
-        fairplay: FairPlay::new(),
-        aeskey: None,
-        aesiv: None,
-        msg_tx,
-        airplay_context,
-        peer_ip,
-        mirror_session: None,
-        tasks: Vec::new(),
-        audio_task: None,
-        ntp_clock: ntp::NtpClock::new(),
-    };
+    let mut handler = Handler::new(stream, config, msg_tx, airplay_context);
     if let Err(err) = handler.run().await {
         tracing::error!(cause = ?err, "airplay connection error");
     }
@@ -325,6 +346,16 @@ struct Handler {
     audio_task: Option<tokio::task::AbortHandle>,
     /// Drift-corrected remote↔local clock maintained by the NTP timing client.
     ntp_clock: ntp::NtpClock,
+    /// A `/play` start given as a fraction of the duration, sought to once the
+    /// duration is known.
+    pending_start_fraction: Option<f64>,
+    /// When this connection's last `/play` was, until its playback shows up.
+    play_requested_at: Option<std::time::Instant>,
+    /// Photos sent ahead with `cacheOnly`, keyed by asset key.
+    photo_cache: photo::PhotoCache,
+    /// The password challenge while the sender still has to answer it; `None`
+    /// once it has, or when no password is set.
+    auth: Option<DigestAuth>,
 }
 
 impl Drop for Handler {
@@ -335,6 +366,40 @@ impl Drop for Handler {
 }
 
 impl Handler {
+    fn new(
+        stream: tokio::net::TcpStream,
+        config: Configuration,
+        msg_tx: MessageSender,
+        airplay_context: AirPlayContext,
+    ) -> Self {
+        let peer_ip = stream
+            .peer_addr()
+            .map(|addr| addr.ip())
+            .unwrap_or(std::net::IpAddr::V4(std::net::Ipv4Addr::LOCALHOST));
+        let auth = config
+            .password
+            .clone()
+            .map(|password| DigestAuth::new("airplay", password));
+        Self {
+            config,
+            connection: Connection::new(stream).with_max_body(MAX_BODY_BYTES),
+            fairplay: FairPlay::new(),
+            aeskey: None,
+            aesiv: None,
+            msg_tx,
+            airplay_context,
+            peer_ip,
+            mirror_session: None,
+            tasks: Vec::new(),
+            audio_task: None,
+            ntp_clock: ntp::NtpClock::new(),
+            pending_start_fraction: None,
+            play_requested_at: None,
+            photo_cache: photo::PhotoCache::default(),
+            auth,
+        }
+    }
+
     #[instrument(skip(self), fields(device = %self.config.device_name))]
     async fn run(&mut self) -> Result<()> {
         while let Some(request) = self.connection.read_request().await? {
@@ -351,7 +416,47 @@ impl Handler {
     }
 
     async fn respond(&mut self, request: &Request) -> Response {
-        let mut response = match (request.method.as_str(), request.path()) {
+        let mut response = match self.unauthorized(request) {
+            Some(response) => response,
+            None => self.route(request).await,
+        };
+
+        // Default headers, matching UxPlay.
+        response = response.header("Server", &format!("AirTunes/{SOURCE_VERSION}"));
+        if let Some(cseq) = request.header("CSeq") {
+            response = response.header("CSeq", cseq);
+        }
+        response
+    }
+
+    /// The `401` for a request that needs the password and doesn't carry it.
+    /// `OPTIONS` and `GET /info` stay open: the sender reads the password
+    /// flag from `/info` before it asks the user.
+    fn unauthorized(&mut self, request: &Request) -> Option<Response> {
+        let auth = self.auth.as_ref()?;
+        let open = matches!(
+            (request.method.as_str(), request.path()),
+            ("OPTIONS", _) | ("GET", "/info")
+        );
+        if open {
+            return None;
+        }
+        if auth.verify(
+            &request.method,
+            &request.url,
+            request.header("Authorization"),
+        ) {
+            self.auth = None;
+            return None;
+        }
+        Some(
+            Response::new(&request.protocol, 401, "Unauthorized")
+                .header("WWW-Authenticate", &auth.challenge()),
+        )
+    }
+
+    async fn route(&mut self, request: &Request) -> Response {
+        match (request.method.as_str(), request.path()) {
             ("GET", "/info") => self.info(request),
             ("OPTIONS", _) => Response::new(&request.protocol, 200, "OK").header(
                 "Public",
@@ -363,19 +468,29 @@ impl Handler {
             ("SETUP", _) => self.setup(request).await,
             ("SET_PARAMETER", _) => self.set_parameter(request),
             ("TEARDOWN", _) => self.teardown(request),
+            ("POST", "/play") => self.play(request),
+            ("PUT", "/photo") => self.photo(request),
+            ("GET", "/slideshow-features") => {
+                self.binary_plist_response(request, photo::slideshow_features())
+            }
+            ("PUT", path) if path.starts_with("/slideshows/") => self.slideshow(request),
+            ("GET", "/scrub") => self.get_scrub(request),
+            ("POST", "/scrub") => self.scrub(request),
+            ("POST", "/rate") => self.rate(request),
+            ("POST", "/stop") => {
+                self.pending_start_fraction = None;
+                self.play_requested_at = None;
+                self.msg_tx
+                    .operation(PacketOrigin::AirPlay, crate::Operation::Stop);
+                Response::new(&request.protocol, 200, "OK")
+            }
+            ("GET", "/playback-info") => self.playback_info(request),
             _ => {
                 // Permissive default (as in UxPlay) keeps the connection healthy.
                 debug!(method = %request.method, url = %request.url, "unhandled airplay request");
                 Response::new(&request.protocol, 200, "OK")
             }
-        };
-
-        // Default headers, matching UxPlay.
-        response = response.header("Server", &format!("AirTunes/{SOURCE_VERSION}"));
-        if let Some(cseq) = request.header("CSeq") {
-            response = response.header("CSeq", cseq);
         }
-        response
     }
 
     /// `TEARDOWN`: `streams: [{type: 96}]` alone stops *just* audio (the client
@@ -493,6 +608,169 @@ impl Handler {
         }
     }
 
+    /// `POST /play`: load the URL in the body like an FCast `Load`.
+    fn play(&mut self, request: &Request) -> Response {
+        let Some(play) = video::PlayRequest::parse(request.header("Content-Type"), &request.body)
+        else {
+            warn!("unparseable /play body");
+            return Response::new(&request.protocol, 400, "Bad Request");
+        };
+        debug!(url = %play.url, start = ?play.start, "airplay play");
+        if !self.load(play.to_v4()) {
+            return Response::new(&request.protocol, 500, "Internal Server Error");
+        }
+        self.play_requested_at = Some(std::time::Instant::now());
+        self.pending_start_fraction = match play.start {
+            video::StartPosition::Fraction(fraction) => Some(fraction),
+            video::StartPosition::Seconds(_) => None,
+        };
+        Response::new(&request.protocol, 200, "OK")
+    }
+
+    /// Hand `item` to the application as a single-item load.
+    fn load(&self, item: fcast_protocol::v4::MediaItem) -> bool {
+        let msg = fcast_protocol::v4::MessageBuilder::new().load_single(item);
+        match FlatLoadMessage::from_packet(msg.to_vec()) {
+            Ok(load) => {
+                self.msg_tx.operation(
+                    PacketOrigin::AirPlay,
+                    crate::Operation::PlayNew(crate::fcast::WrappedPlayMessage::V4(load)),
+                );
+                true
+            }
+            Err(err) => {
+                warn!(?err, "failed to build a load");
+                false
+            }
+        }
+    }
+
+    /// `PUT /photo`: show or cache a photo, see [`photo`]. The requested
+    /// `X-Apple-Transition` isn't drawn, photos replace each other.
+    fn photo(&mut self, request: &Request) -> Response {
+        let action = photo::PhotoAction::from_header(request.header("X-Apple-AssetAction"));
+        let key = request.header("X-Apple-AssetKey");
+        debug!(
+            ?action,
+            key,
+            transition = request.header("X-Apple-Transition"),
+            len = request.body.len(),
+            "airplay photo"
+        );
+        let shown = match (action, key) {
+            (photo::PhotoAction::CacheOnly, Some(key)) => {
+                self.photo_cache
+                    .insert(key.to_owned(), request.body.clone());
+                return Response::new(&request.protocol, 200, "OK");
+            }
+            (photo::PhotoAction::DisplayCached, Some(key)) => {
+                let Some(cached) = self.photo_cache.get(key) else {
+                    // Tells the sender to send the photo itself.
+                    return Response::new(&request.protocol, 412, "Precondition Failed");
+                };
+                self.load(photo::photo_load(cached))
+            }
+            (photo::PhotoAction::Display, key) if !request.body.is_empty() => {
+                if let Some(key) = key {
+                    self.photo_cache
+                        .insert(key.to_owned(), request.body.clone());
+                }
+                self.load(photo::photo_load(&request.body))
+            }
+            _ => return Response::new(&request.protocol, 400, "Bad Request"),
+        };
+        if !shown {
+            return Response::new(&request.protocol, 500, "Internal Server Error");
+        }
+        self.play_requested_at = None;
+        self.pending_start_fraction = None;
+        Response::new(&request.protocol, 200, "OK")
+    }
+
+    /// `PUT /slideshows/<id>`: only stopping is acted on. A playing slideshow
+    /// pulls its photos over a reverse connection, which isn't served, so
+    /// senders fall back to pushing them with `PUT /photo`.
+    fn slideshow(&mut self, request: &Request) -> Response {
+        if photo::slideshow_stopped(&request.body) {
+            debug!("airplay slideshow stopped");
+            self.msg_tx
+                .operation(PacketOrigin::AirPlay, crate::Operation::Stop);
+        }
+        self.binary_plist_response(request, plist::Dictionary::new())
+    }
+
+    /// `GET /scrub`: the position as `text/parameters`.
+    fn get_scrub(&self, request: &Request) -> Response {
+        let info = self.airplay_context.playback().unwrap_or_default();
+        Response::new(&request.protocol, 200, "OK")
+            .body("text/parameters", info.scrub_text().into_bytes())
+    }
+
+    /// `POST /scrub?position=<secs>`: seek.
+    fn scrub(&mut self, request: &Request) -> Response {
+        let Some(position) = video::query_f64(&request.url, "position") else {
+            return Response::new(&request.protocol, 400, "Bad Request");
+        };
+        match gst::ClockTime::try_from_seconds_f64(position) {
+            Ok(time) => {
+                self.pending_start_fraction = None;
+                self.msg_tx
+                    .operation(PacketOrigin::AirPlay, crate::Operation::Seek(time));
+                Response::new(&request.protocol, 200, "OK")
+            }
+            Err(err) => {
+                warn!(position, ?err, "invalid /scrub position");
+                Response::new(&request.protocol, 400, "Bad Request")
+            }
+        }
+    }
+
+    /// `POST /rate?value=<rate>`: senders only send `0` (pause) and `1`
+    /// (play).
+    fn rate(&mut self, request: &Request) -> Response {
+        let Some(rate) = video::query_f64(&request.url, "value") else {
+            return Response::new(&request.protocol, 400, "Bad Request");
+        };
+        let op = if rate == 0.0 {
+            crate::Operation::Pause
+        } else {
+            crate::Operation::Resume
+        };
+        self.msg_tx.operation(PacketOrigin::AirPlay, op);
+        Response::new(&request.protocol, 200, "OK")
+    }
+
+    /// `GET /playback-info`: polled by the sender about once a second. A
+    /// load in progress is reported as not ready yet; an empty dictionary
+    /// when nothing is playing for it ends the sender's session.
+    fn playback_info(&mut self, request: &Request) -> Response {
+        let Some(info) = self.airplay_context.playback() else {
+            let loading = self
+                .play_requested_at
+                .is_some_and(|at| at.elapsed() < PLAY_LOAD_TIMEOUT);
+            let dict = if loading {
+                video::PlaybackInfo::loading_plist()
+            } else {
+                plist::Dictionary::new()
+            };
+            return self.binary_plist_response(request, dict);
+        };
+        self.play_requested_at = None;
+        if info.duration > 0.0
+            && let Some(fraction) = self.pending_start_fraction.take()
+            && let Ok(time) = gst::ClockTime::try_from_seconds_f64(fraction * info.duration)
+        {
+            debug!(
+                fraction,
+                ?time,
+                "airplay seeking to the /play start position"
+            );
+            self.msg_tx
+                .operation(PacketOrigin::AirPlay, crate::Operation::Seek(time));
+        }
+        self.binary_plist_response(request, info.to_plist())
+    }
+
     /// `SET_PARAMETER`: maps the `text/parameters` `volume:` dB value onto
     /// linear volume; other parameters are accepted and ignored.
     fn set_parameter(&mut self, request: &Request) -> Response {
@@ -780,6 +1058,7 @@ mod tests {
             device_name: "FCast-test".to_owned(),
             hw_addr: [0x01, 0x23, 0x45, 0x67, 0x89, 0xab],
             pk: "deadbeef".to_owned(),
+            password: None,
         }
     }
 
@@ -869,43 +1148,33 @@ mod tests {
         assert!(entries.iter().any(|e| e == "model=AppleTV3,2"));
     }
 
-    #[tokio::test]
-    async fn serves_info_over_tcp() {
-        use tokio::io::{AsyncReadExt, AsyncWriteExt};
-
+    /// Serve one connection with `config` and connect to it.
+    async fn serve(config: Configuration) -> tokio::net::TcpStream {
         let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
         let addr = listener.local_addr().unwrap();
 
-        let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
         let msg_tx = MessageSender::new(tx);
         tokio::spawn(async move {
+            // Operations go nowhere, but the channel stays open.
+            let _rx = rx;
             let (stream, _) = listener.accept().await.unwrap();
-            let mut handler = Handler {
-                config: test_config(),
-                connection: Connection::new(stream),
-                fairplay: FairPlay::new(),
-                aeskey: None,
-                aesiv: None,
-                msg_tx,
-                airplay_context: AirPlayContext::new(),
-                peer_ip: std::net::IpAddr::V4(std::net::Ipv4Addr::LOCALHOST),
-                mirror_session: None,
-                tasks: Vec::new(),
-                audio_task: None,
-                ntp_clock: ntp::NtpClock::new(),
-            };
+            let mut handler = Handler::new(stream, config, msg_tx, AirPlayContext::new());
             let _ = handler.run().await;
         });
 
-        let mut client = tokio::net::TcpStream::connect(addr).await.unwrap();
-        client
-            .write_all(b"GET /info HTTP/1.1\r\nCSeq: 7\r\n\r\n")
-            .await
-            .unwrap();
+        tokio::net::TcpStream::connect(addr).await.unwrap()
+    }
+
+    /// Send `request` and read the response head and body.
+    async fn exchange(client: &mut tokio::net::TcpStream, request: &str) -> (String, Vec<u8>) {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        client.write_all(request.as_bytes()).await.unwrap();
 
         let mut buf = Vec::new();
         let mut tmp = [0u8; 1024];
-        let (status_line, body) = loop {
+        loop {
             let n = client.read(&mut tmp).await.unwrap();
             assert_ne!(n, 0, "connection closed before full response");
             buf.extend_from_slice(&tmp[..n]);
@@ -919,19 +1188,21 @@ mod tests {
                     .unwrap();
                 let body_start = idx + 4;
                 if buf.len() >= body_start + content_length {
-                    let status = head.lines().next().unwrap().to_owned();
-                    break (
-                        status,
+                    return (
+                        head.to_owned(),
                         buf[body_start..body_start + content_length].to_vec(),
                     );
                 }
             }
-        };
+        }
+    }
 
-        assert!(
-            status_line.starts_with("HTTP/1.1 200"),
-            "got: {status_line}"
-        );
+    #[tokio::test]
+    async fn serves_info_over_tcp() {
+        let mut client = serve(test_config()).await;
+        let (head, body) = exchange(&mut client, "GET /info HTTP/1.1\r\nCSeq: 7\r\n\r\n").await;
+
+        assert!(head.starts_with("HTTP/1.1 200"), "got: {head}");
         let value: plist::Value = plist::from_bytes(&body).expect("valid plist body");
         assert_eq!(
             value
@@ -941,4 +1212,51 @@ mod tests {
             Some(MODEL)
         );
     }
+
+    #[tokio::test]
+    async fn password_protected_receiver_challenges_until_authorized() {
+        let config = Configuration {
+            password: Some("hunter2".to_owned()),
+            ..test_config()
+        };
+        assert!(
+            txt_properties(&config)
+                .get("pw")
+                .is_some_and(|pw| pw == "true")
+        );
+        let mut client = serve(config).await;
+
+        // `/info` stays open and carries the password flag.
+        let (head, body) = exchange(&mut client, "GET /info HTTP/1.1\r\nCSeq: 1\r\n\r\n").await;
+        assert!(head.starts_with("HTTP/1.1 200"), "got: {head}");
+        let info: plist::Value = plist::from_bytes(&body).unwrap();
+        let status_flags = info
+            .as_dictionary()
+            .and_then(|d| d.get("statusFlags"))
+            .and_then(|v| v.as_unsigned_integer())
+            .unwrap();
+        assert_ne!(status_flags & STATUS_PASSWORD_REQUIRED, 0);
+
+        let (head, _) = exchange(
+            &mut client,
+            "POST /rate?value=0 HTTP/1.1\r\nCSeq: 2\r\n\r\n",
+        )
+        .await;
+        assert!(head.starts_with("HTTP/1.1 401"), "got: {head}");
+        let challenge = head
+            .lines()
+            .find_map(|l| l.strip_prefix("WWW-Authenticate: "))
+            .unwrap();
+
+        let authorization =
+            crate::rtsp_auth::sender_authorization(challenge, "hunter2", "POST", "/rate?value=0");
+        let (head, _) = exchange(
+            &mut client,
+            &format!(
+                "POST /rate?value=0 HTTP/1.1\r\nCSeq: 3\r\nAuthorization: {authorization}\r\n\r\n"
+            ),
+        )
+        .await;
+        assert!(head.starts_with("HTTP/1.1 200"), "got: {head}");
+    }
 }
@@ -0,0 +1,156 @@
+//! AirPlay photo sharing: `PUT /photo` carries a JPEG to show now, or one to
+//! keep for later (`X-Apple-AssetAction: cacheOnly`) that a body-less
+//! `displayCached` request then shows by its `X-Apple-AssetKey`. Photos are
+//! loaded as `data:` URL images, so they take the same pipeline path
+//! (`imagetypefind`, `imagedec`) as FCast image loads.
+
+use std::collections::VecDeque;
+
+use base64::Engine as _;
+use fcast_protocol::v4;
+
+/// Photos kept for `displayCached`. iOS caches the neighbours of the photo on
+/// screen, a few either side.
+const MAX_CACHED_PHOTOS: usize = 8;
+/// And their total size, which a sender could otherwise run up with large
+/// bodies.
+const MAX_CACHED_BYTES: usize = 32 * 1024 * 1024;
+
+/// What a `PUT /photo` asks for, from its `X-Apple-AssetAction`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PhotoAction {
+    Display,
+    CacheOnly,
+    DisplayCached,
+}
+
+impl PhotoAction {
+    pub fn from_header(value: Option<&str>) -> Self {
+        match value {
+            Some("cacheOnly") => Self::CacheOnly,
+            Some("displayCached") => Self::DisplayCached,
+            _ => Self::Display,
+        }
+    }
+}
+
+/// Least recently stored first.
+#[derive(Debug, Default)]
+pub struct PhotoCache {
+    photos: VecDeque<(String, Vec<u8>)>,
+}
+
+impl PhotoCache {
+    /// A photo larger than the whole cache isn't kept.
+    pub fn insert(&mut self, key: String, photo: Vec<u8>) {
+        self.photos.retain(|(old, _)| *old != key);
+        if photo.len() > MAX_CACHED_BYTES {
+            return;
+        }
+        self.photos.push_back((key, photo));
+        while self.photos.len() > MAX_CACHED_PHOTOS || self.bytes() > MAX_CACHED_BYTES {
+            self.photos.pop_front();
+        }
+    }
+
+    fn bytes(&self) -> usize {
+        self.photos.iter().map(|(_, photo)| photo.len()).sum()
+    }
+
+    pub fn get(&self, key: &str) -> Option<&[u8]> {
+        self.photos
+            .iter()
+            .find(|(cached, _)| cached == key)
+            .map(|(_, photo)| photo.as_slice())
+    }
+}
+
+/// The load for a photo. iOS only sends JPEG, and `imagetypefind` checks
+/// what the bytes are regardless.
+pub fn photo_load(photo: &[u8]) -> v4::MediaItem {
+    let encoded = base64::engine::general_purpose::STANDARD.encode(photo);
+    v4::MediaItem {
+        container: "image/jpeg".to_owned(),
+        source_url: format!("data:image/jpeg;base64,{encoded}"),
+        start_time: None,
+        volume: None,
+        speed: None,
+        headers: None,
+        title: None,
+        thumbnail_url: None,
+        metadata: None,
+        extra_metadata: None,
+        resume: false,
+    }
+}
+
+/// The `GET /slideshow-features` plist. Transitions aren't drawn, so only
+/// the plain theme is offered.
+pub fn slideshow_features() -> plist::Dictionary {
+    use plist::Value;
+    let mut theme = plist::Dictionary::new();
+    theme.insert("key".into(), Value::String("Classic".to_owned()));
+    theme.insert("name".into(), Value::String("Classic".to_owned()));
+    let mut d = plist::Dictionary::new();
+    d.insert(
+        "themes".into(),
+        Value::Array(vec![Value::Dictionary(theme)]),
+    );
+    d
+}
+
+/// Whether a `PUT /slideshows/<id>` body stops the slideshow.
+pub fn slideshow_stopped(body: &[u8]) -> bool {
+    plist::from_bytes::<plist::Value>(body)
+        .ok()
+        .as_ref()
+        .and_then(|root| root.as_dictionary())
+        .and_then(|dict| dict.get("state"))
+        .and_then(|state| state.as_string())
+        == Some("stopped")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cache_keeps_the_most_recent_photos() {
+        let mut cache = PhotoCache::default();
+        for n in 0..=MAX_CACHED_PHOTOS {
+            cache.insert(format!("key-{n}"), vec![n as u8]);
+        }
+        assert_eq!(cache.get("key-0"), None);
+        assert_eq!(cache.get("key-1"), Some([1].as_slice()));
+        cache.insert("key-1".to_owned(), vec![42]);
+        assert_eq!(cache.get("key-1"), Some([42].as_slice()));
+        assert_eq!(cache.photos.len(), MAX_CACHED_PHOTOS);
+    }
+
+    #[test]
+    fn cache_stays_within_its_byte_budget() {
+        let mut cache = PhotoCache::default();
+        let large = MAX_CACHED_BYTES / 3 + 1;
+        for n in 0..3 {
+            cache.insert(format!("key-{n}"), vec![0; large]);
+        }
+        assert!(cache.bytes() <= MAX_CACHED_BYTES);
+        assert!(cache.get("key-0").is_none());
+        assert!(cache.get("key-2").is_some());
+
+        cache.insert("huge".to_owned(), vec![0; MAX_CACHED_BYTES + 1]);
+        assert!(cache.get("huge").is_none());
+        assert!(cache.get("key-2").is_some());
+    }
+
+    #[test]
+    fn photo_loads_are_data_urls() {
+        let load = photo_load(&[0xff, 0xd8, 0xff]);
+        assert_eq!(load.source_url, "data:image/jpeg;base64,/9j/");
+        assert_eq!(
+            PhotoAction::from_header(Some("displayCached")),
+            PhotoAction::DisplayCached
+        );
+        assert_eq!(PhotoAction::from_header(None), PhotoAction::Display);
+    }
+}
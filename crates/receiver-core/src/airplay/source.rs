@@ -4,6 +4,8 @@ use gst::{glib, prelude::*};
 use parking_lot::Mutex;
 use url::Url;
 
+use super::video::PlaybackInfo;
+
 /// One Annex-B H.264 access unit handed from the stream reader to the source.
 pub struct AccessUnit {
     /// Annex-B byte-stream (start-code-prefixed NALs).
@@ -40,6 +42,8 @@ struct SessionEntry {
 #[derive(Clone, Default)]
 pub struct AirPlayContext {
     sessions: Arc<Mutex<HashMap<u64, SessionEntry>>>,
+    /// Progress of the URL playing for a `/play`, `None` when there is none.
+    playback: Arc<Mutex<Option<PlaybackInfo>>>,
 }
 
 impl std::fmt::Debug for AirPlayContext {
@@ -104,6 +108,15 @@ impl AirPlayContext {
         }
     }
 
+    /// Set by the application on each progress tick of a `/play` load.
+    pub fn set_playback(&self, info: Option<PlaybackInfo>) {
+        *self.playback.lock() = info;
+    }
+
+    pub fn playback(&self) -> Option<PlaybackInfo> {
+        *self.playback.lock()
+    }
+
     fn take_video(
         &self,
         stream_connection_id: u64,
@@ -0,0 +1,210 @@
+//! Classic AirPlay video: the sender hands over a URL (`POST /play`) and
+//! drives playback with `/scrub`, `/rate` and `/stop`, polling
+//! `/playback-info` for progress. Playback itself goes through the
+//! application like any other load; the progress it reports back is kept in
+//! the [`AirPlayContext`](super::AirPlayContext).
+
+use fcast_protocol::v4;
+
+use super::plist_u64;
+
+/// Where a `/play` asks playback to start.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StartPosition {
+    Seconds(f64),
+    /// Fraction of the duration, which isn't known before the load.
+    Fraction(f64),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlayRequest {
+    pub url: String,
+    pub start: StartPosition,
+}
+
+impl PlayRequest {
+    /// Parse a `/play` body, either `text/parameters` lines or a binary plist
+    /// (newer iOS).
+    pub fn parse(content_type: Option<&str>, body: &[u8]) -> Option<Self> {
+        if content_type.is_some_and(|ct| ct.contains("text/parameters")) {
+            return Self::from_parameters(&String::from_utf8_lossy(body));
+        }
+        let root = plist::from_bytes::<plist::Value>(body).ok()?;
+        let dict = root.as_dictionary()?;
+        let url = dict.get("Content-Location")?.as_string()?.to_owned();
+        let seconds = dict.get("Start-Position-Seconds").and_then(plist_f64);
+        let fraction = dict.get("Start-Position").and_then(plist_f64);
+        Some(Self {
+            url,
+            start: start_position(seconds, fraction),
+        })
+    }
+
+    fn from_parameters(body: &str) -> Option<Self> {
+        let mut url = None;
+        let mut fraction = None;
+        for line in body.lines() {
+            let Some((key, value)) = line.split_once(':') else {
+                continue;
+            };
+            match key.trim() {
+                "Content-Location" => url = Some(value.trim().to_owned()),
+                "Start-Position" => fraction = value.trim().parse().ok(),
+                _ => (),
+            }
+        }
+        Some(Self {
+            url: url?,
+            start: start_position(None, fraction),
+        })
+    }
+
+    /// The load for the application. A fractional start needs the duration,
+    /// it's sought to once the first progress comes back.
+    pub fn to_v4(&self) -> v4::MediaItem {
+        let start_time = match self.start {
+            StartPosition::Seconds(secs) if secs > 0.0 => Some(secs),
+            _ => None,
+        };
+        v4::MediaItem {
+            container: container_for(&self.url).to_owned(),
+            source_url: self.url.clone(),
+            start_time,
+            volume: None,
+            speed: None,
+            headers: None,
+            title: None,
+            thumbnail_url: None,
+            metadata: None,
+            extra_metadata: None,
+            resume: false,
+        }
+    }
+}
+
+fn start_position(seconds: Option<f64>, fraction: Option<f64>) -> StartPosition {
+    match (seconds, fraction) {
+        (Some(secs), _) => StartPosition::Seconds(secs),
+        (None, Some(fraction)) if fraction > 0.0 => StartPosition::Fraction(fraction),
+        _ => StartPosition::Seconds(0.0),
+    }
+}
+
+fn plist_f64(v: &plist::Value) -> Option<f64> {
+    v.as_real().or_else(|| plist_u64(v).map(|i| i as f64))
+}
+
+/// Senders don't say what they hand over, it's HLS or a progressive file.
+fn container_for(url: &str) -> &'static str {
+    let path = url.split(['?', '#']).next().unwrap_or(url);
+    if path.to_ascii_lowercase().ends_with(".m3u8") {
+        "application/vnd.apple.mpegurl"
+    } else {
+        "video/mp4"
+    }
+}
+
+/// A `?name=value` query parameter as a number, as in `/scrub?position=12.5`.
+pub fn query_f64(url: &str, name: &str) -> Option<f64> {
+    let (_, query) = url.split_once('?')?;
+    query
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(key, _)| *key == name)
+        .and_then(|(_, value)| value.parse().ok())
+}
+
+/// Progress of the URL the application is playing for an AirPlay sender.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct PlaybackInfo {
+    pub position: f64,
+    /// Zero until known, and for live streams.
+    pub duration: f64,
+    /// Zero while paused.
+    pub rate: f64,
+}
+
+impl PlaybackInfo {
+    /// The `GET /scrub` body.
+    pub fn scrub_text(&self) -> String {
+        format!(
+            "duration: {:.6}\nposition: {:.6}\n",
+            self.duration, self.position
+        )
+    }
+
+    /// The `GET /playback-info` plist. Everything is reported as loaded, the
+    /// sender only uses the ranges to draw its scrubber.
+    pub fn to_plist(self) -> plist::Dictionary {
+        use plist::Value;
+        let range = || {
+            let mut range = plist::Dictionary::new();
+            range.insert("start".into(), Value::Real(0.0));
+            range.insert("duration".into(), Value::Real(self.duration));
+            Value::Array(vec![Value::Dictionary(range)])
+        };
+        let mut d = plist::Dictionary::new();
+        d.insert("duration".into(), Value::Real(self.duration));
+        d.insert("position".into(), Value::Real(self.position));
+        d.insert("rate".into(), Value::Real(self.rate));
+        d.insert("readyToPlay".into(), Value::Boolean(true));
+        d.insert("playbackBufferEmpty".into(), Value::Boolean(false));
+        d.insert("playbackBufferFull".into(), Value::Boolean(true));
+        d.insert("playbackLikelyToKeepUp".into(), Value::Boolean(true));
+        d.insert("loadedTimeRanges".into(), range());
+        d.insert("seekableTimeRanges".into(), range());
+        d
+    }
+
+    /// The `GET /playback-info` plist while a `/play` is still loading.
+    pub fn loading_plist() -> plist::Dictionary {
+        let mut d = plist::Dictionary::new();
+        d.insert("readyToPlay".into(), plist::Value::Boolean(false));
+        d.insert("playbackBufferEmpty".into(), plist::Value::Boolean(true));
+        d
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_text_and_plist_play_bodies() {
+        let text = b"Content-Location: http://a/v.m3u8?t=1\nStart-Position: 0.25\n";
+        assert_eq!(
+            PlayRequest::parse(Some("text/parameters"), text),
+            Some(PlayRequest {
+                url: "http://a/v.m3u8?t=1".to_owned(),
+                start: StartPosition::Fraction(0.25),
+            })
+        );
+
+        let mut dict = plist::Dictionary::new();
+        dict.insert(
+            "Content-Location".into(),
+            plist::Value::String("http://a/v.mp4".to_owned()),
+        );
+        dict.insert("Start-Position".into(), plist::Value::Real(0.5));
+        dict.insert("Start-Position-Seconds".into(), plist::Value::Real(42.0));
+        let mut body = Vec::new();
+        plist::to_writer_binary(&mut body, &plist::Value::Dictionary(dict)).unwrap();
+        let play = PlayRequest::parse(Some("application/x-apple-binary-plist"), &body).unwrap();
+        assert_eq!(play.start, StartPosition::Seconds(42.0));
+        assert_eq!(play.to_v4().start_time, Some(42.0));
+        assert_eq!(play.to_v4().container, "video/mp4");
+
+        assert_eq!(PlayRequest::parse(None, b"not a plist"), None);
+    }
+
+    #[test]
+    fn reads_numeric_query_parameters() {
+        assert_eq!(
+            query_f64("/scrub?position=20.097", "position"),
+            Some(20.097)
+        );
+        assert_eq!(query_f64("/rate?value=0.000000", "value"), Some(0.0));
+        assert_eq!(query_f64("/rate?other=1", "value"), None);
+        assert_eq!(query_f64("/scrub", "position"), None);
+    }
+}
@@ -13,7 +13,6 @@ use fcast_protocol::{
     v4::{self, flat::ErrorKind},
 };
 use gst::{glib::object::Cast, prelude::*};
-use rcgen::PublicKeyData;
 use smallvec::SmallVec;
 use smol_str::SmolStr;
 use tokio::{
@@ -28,7 +27,8 @@ use tracing::{debug, error, info, warn};
 #[cfg(any(target_os = "macos", target_os = "windows"))]
 use crate::message;
 use crate::{
-    FCAST_TCP_PORT, GCastUpdateSender, MediaItemId, MessageSender, SenderId,
+    DlnaUpdateSender, FCAST_TCP_PORT, GCastUpdateSender, MediaItemId, MessageSender,
+    MprisUpdateSender, SenderId, av_delay, control_api, dlna,
     external_subtitles::{self, ExternalSubtitle, is_external_track_id},
     fcast::{
         self, CompanionContext, InitialV4State, Operation, ReceiverToSenderMessage, SessionDriver,
@@ -38,12 +38,22 @@ use crate::{
     freeze_watchdog::{self, FreezeAction, FreezeSample},
     fwebrtcsrc, gcast,
     gui::{self, GuiController},
+    history::{self, PlaybackHistory},
     image,
     media_formats::SupportedFormats,
     media_source,
     message::{Mdns, Message, Raop, ReceiverToFCastSender},
+    metrics, mpris,
+    pairing::Pairing,
     player::{self, PlayerState},
-    queue_cache, raop,
+    queue_cache,
+    queue_order::{PlayOrder, index_after_queue_move},
+    raop,
+    resume::{ResumeMode, ResumeStore},
+    secondary_subtitles,
+    sleep_timer::{self, SleepTimer},
+    subtitle_style,
+    tls_identity::TlsIdentity,
     ui_types::{AppState, GuiPlaybackState, UiMediaTrack, UiPlayerVariant, UiToastKind},
     utils::{current_time_millis, map_to_header_map},
 };
@@ -58,6 +68,8 @@ const SENDER_UPDATE_INTERVAL: Duration = Duration::from_millis(500);
 const SEEK_QUIET_DEBOUNCE: Duration = Duration::from_millis(500);
 const DEFAULT_PROGRESS_INTERVAL: Duration = Duration::from_millis(500);
 const PROGRESS_TICK_INTERVAL: Duration = Duration::from_millis(100);
+/// History entries listed on the idle screen.
+const IDLE_HISTORY_ENTRIES: usize = 5;
 /// Deliberately far above the progress tick: the stream-mode nub walks the
 /// pipeline.
 const BUFFERED_RANGES_INTERVAL: Duration = Duration::from_millis(1000);
@@ -110,6 +122,9 @@ struct AirPlayServer {
 
 #[derive(Clone, Debug)]
 struct QueueItem {
+    /// Stays with the item as the queue is edited; Cast senders address items
+    /// by it.
+    id: u32,
     content_type: String,
     url: String,
     time: Option<f64>,
@@ -119,6 +134,9 @@ struct QueueItem {
     headers: Option<HashMap<String, String>>,
     title: Option<String>,
     thumbnail_url: Option<String>,
+    chapters: Vec<v4::Chapter>,
+    /// Start where an earlier play of `url` left off.
+    resume: bool,
 }
 
 impl QueueItem {
@@ -130,7 +148,9 @@ impl QueueItem {
                 .map(|h| (h.key().to_owned(), h.value().to_owned()))
                 .collect()
         });
+        static NEXT_ID: std::sync::atomic::AtomicU32 = std::sync::atomic::AtomicU32::new(1);
         Self {
+            id: NEXT_ID.fetch_add(1, std::sync::atomic::Ordering::Relaxed),
             content_type: media_item.container().to_owned(),
             url: media_item.source_url().to_owned(),
             time: media_item
@@ -144,6 +164,8 @@ impl QueueItem {
             headers,
             title: media_item.title().map(ToOwned::to_owned),
             thumbnail_url: media_item.thumbnail_url().map(ToOwned::to_owned),
+            chapters: v4::read_media_item_chapters(&media_item),
+            resume: media_item.resume(),
         }
     }
 
@@ -173,10 +195,12 @@ impl QueueItem {
 
 struct QueueState {
     items: Vec<QueueItem>,
-    current_idx: u8,
+    current_idx: usize,
     /// Spec'd Queue.autoplay: the receiver advances by itself when an item
     /// finishes.
     autoplay: bool,
+    /// The order autoplay walks `items` in while shuffle is on.
+    order: PlayOrder,
 }
 
 /// A gapless pre-arm in flight: the next queue item is prepared on the live
@@ -275,6 +299,70 @@ fn parked_op_action(kind: GaplessParkedOpKind, outcome: GaplessOutcome) -> Parke
     }
 }
 
+/// How far into a chapter "previous" still goes to the chapter before it
+/// rather than restarting the current one, like a previous-track button.
+const CHAPTER_RESTART_GRACE: gst::ClockTime = gst::ClockTime::from_seconds(3);
+
+/// The start of the chapter `target` names, seen from `position`. `None` when
+/// the item has no such chapter: an index past the end, nothing after the
+/// last chapter, or nothing before a position ahead of the first. Indices
+/// count in start order, whatever order `chapters` is in.
+fn chapter_start(
+    chapters: &[v4::Chapter],
+    position: gst::ClockTime,
+    target: v4::ChapterTarget,
+) -> Option<gst::ClockTime> {
+    let mut starts: Vec<gst::ClockTime> = chapters
+        .iter()
+        .filter_map(|c| gst::ClockTime::try_from_seconds_f64(c.start).ok())
+        .collect();
+    starts.sort();
+    match target {
+        v4::ChapterTarget::Index(idx) => starts.get(idx as usize).copied(),
+        v4::ChapterTarget::Next => starts.into_iter().find(|start| *start > position),
+        v4::ChapterTarget::Previous => {
+            let current = starts.iter().rposition(|start| *start <= position)?;
+            if position - starts[current] > CHAPTER_RESTART_GRACE || current == 0 {
+                Some(starts[current])
+            } else {
+                Some(starts[current - 1])
+            }
+        }
+    }
+}
+
+/// Where a `QueueInsert` or `QueueInsertBatch` lands in a queue of `len`
+/// items.
+fn queue_insert_index(
+    len: usize,
+    position_type: v4::flat::QueuePosition,
+    position: Option<v4::flat::QueueIndex>,
+) -> Result<usize, ErrorKind> {
+    let idx = match position_type {
+        v4::flat::QueuePosition::Back => len,
+        v4::flat::QueuePosition::Front => 0,
+        v4::flat::QueuePosition::Index => {
+            let Some(idx) = position else {
+                error!("Queue insert position is missing its index");
+                return Err(ErrorKind::MalformedBody);
+            };
+
+            v4::read_queue_index(&idx) as usize
+        }
+        _ => {
+            error!(?position_type, "Invalid queue position");
+            return Err(ErrorKind::MalformedBody);
+        }
+    };
+
+    if len == 0 || idx > len {
+        error!(idx, "Invalid index");
+        return Err(ErrorKind::QueuePositionOutOfRange);
+    }
+
+    Ok(idx)
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 enum StaleEventAction {
     /// Describes the one real pipeline, not the item it is playing: only the
@@ -499,16 +587,18 @@ fn format_recent_warnings(ring: &RecentWarnings, now: Instant) -> String {
 }
 
 fn issue_tracker_qr() -> Option<crate::ui_types::QrCode> {
-    let qrcode = fast_qr::QRBuilder::new(ISSUE_TRACKER_URL.as_bytes())
-        .build()
-        .ok()?;
+    encode_qr(ISSUE_TRACKER_URL.as_bytes()).ok()
+}
+
+fn encode_qr(data: &[u8]) -> Result<crate::ui_types::QrCode> {
+    let qrcode = fast_qr::QRBuilder::new(data).build()?;
     let dims = qrcode.size as u32;
     let module_count = (dims * dims) as usize;
     let dark = qrcode.data[0..module_count]
         .iter()
         .map(|module| *module != fast_qr::Module::LIGHT)
         .collect();
-    Some(crate::ui_types::QrCode { size: dims, dark })
+    Ok(crate::ui_types::QrCode { size: dims, dark })
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -559,6 +649,12 @@ pub enum PacketOrigin {
     Raop,
     #[cfg_attr(not(feature = "airplay"), allow(dead_code))]
     AirPlay,
+    /// The desktop's media controls. Relayed to senders like the GUI.
+    Mpris,
+    /// A DLNA/UPnP control point.
+    Dlna,
+    /// A client of the local control API. Relayed to senders like the GUI.
+    ControlApi,
 }
 
 impl PacketOrigin {
@@ -598,6 +694,9 @@ struct MediaSourceState {
     pending_thumbnail_download: Option<image::ImageDownloadId>,
     /// Owns the STABLE advertised track ids for the current item's externals.
     externals: external_subtitles::Catalog,
+    /// Who cast the source, for the history. Looked up once, the sender may be
+    /// gone by the time a later queue item plays.
+    sender_name: Option<String>,
 }
 
 impl MediaSourceState {
@@ -609,6 +708,7 @@ impl MediaSourceState {
             pending_thumbnail: None,
             pending_thumbnail_download: None,
             externals: external_subtitles::Catalog::default(),
+            sender_name: None,
         }
     }
 
@@ -623,6 +723,8 @@ struct FCastSenderHandle {
     msg_tx: mpsc::UnboundedSender<ReceiverToFCastSender>,
     progress_interval: Duration,
     last_progress_update: Instant,
+    /// From the sender's introduction.
+    name: Option<String>,
 }
 
 impl FCastSenderHandle {
@@ -631,6 +733,7 @@ impl FCastSenderHandle {
             msg_tx,
             progress_interval: DEFAULT_PROGRESS_INTERVAL,
             last_progress_update: Instant::now(),
+            name: None,
         }
     }
 }
@@ -646,6 +749,11 @@ pub struct Application {
     debug_mode: bool,
     player: player::Player,
     current_duration: Option<gst::ClockTime>,
+    resume_positions: ResumeStore,
+    /// The playing item's key in `resume_positions`, `None` when it has none to
+    /// remember.
+    resume_key: Option<String>,
+    playback_history: PlaybackHistory,
     pending_subtitle_adds: Vec<PendingSubtitleAdd>,
     pending_subtitle_add_epoch: u64,
     last_progress_broadcast: Option<Instant>,
@@ -658,6 +766,33 @@ pub struct Application {
     /// v1-v3 gets nothing.
     seek_quiet: bool,
     seek_quiet_epoch: u64,
+    /// The latest seek target. While `seek_quiet` is armed the seek has not
+    /// settled and this, not the playhead, is what a relative seek builds on.
+    last_seek_target: Option<gst::ClockTime>,
+    /// Chapters of the current item, advertised to senders and marked on the
+    /// scrubber.
+    chapters: Vec<v4::Chapter>,
+    /// `chapters` came with the sender's load, so the container's own TOC
+    /// does not replace them.
+    chapters_from_sender: bool,
+    /// Repeat and shuffle, set by senders. Outlives any one queue.
+    queue_mode: fcast::QueueMode,
+    sleep_timer: Option<SleepTimer>,
+    /// Invalidates the ticks of a replaced or cancelled sleep timer.
+    sleep_timer_epoch: u64,
+    /// How subtitles look, from `[subtitles]` and adjusted by senders.
+    subtitles: crate::config::SubtitlesConfig,
+    /// Where `subtitles` is applied; `None` when headless.
+    cue_engine: Option<fcast_video::cue::CueEngine>,
+    /// The sink's second cue engine, drawing the secondary subtitle track;
+    /// `None` when headless.
+    secondary_cues: Option<fcast_video::secondary::SecondaryCues>,
+    /// The external subtitle shown as the secondary track, from the moment
+    /// it is asked for. A parse finishing for any other id is stale.
+    secondary_subtitle: Option<u32>,
+    /// The current item's subtitle and audio delays. Each load starts them
+    /// over from `[playback]`.
+    delays: av_delay::Delays,
     /// Pins the slider thumb at the seek target so a stale position tick can't
     /// spring it back.
     gui_seek_hold: Option<GuiSeekHold>,
@@ -668,9 +803,14 @@ pub struct Application {
     source_backoff: Option<(Instant, u64)>,
     /// Bumped per backoff change/clear so a stale `SourceBackoffTick` no-ops.
     source_backoff_epoch: u64,
+    /// The media source is reconnecting a dropped connection, shown by the
+    /// GUI as "reconnecting" instead of an error.
+    source_reconnecting: bool,
     /// Detects a silently wedged pipeline and drives recovery. Lever:
     /// `FCAST_NO_FREEZE_WATCHDOG`.
     freeze_watchdog: freeze_watchdog::FreezeWatchdog,
+    /// Counters for the metrics endpoint, kept whether or not it is served.
+    metrics: metrics::Metrics,
     current_image_id: image::ImageId,
     current_image_download_id: image::ImageDownloadId,
     /// True while the load is an image routed through the player pipeline
@@ -712,6 +852,8 @@ pub struct Application {
     #[cfg(any(target_os = "macos", target_os = "windows"))]
     update: Option<app_updater::Release>,
     gcast_tx: GCastUpdateSender,
+    mpris_tx: MprisUpdateSender,
+    dlna_tx: DlnaUpdateSender,
     #[cfg(not(target_os = "android"))]
     settings: Settings,
     window_visible_before_playing: Option<bool>,
@@ -757,6 +899,9 @@ pub struct Application {
     receiver_info: Arc<crate::ReceiverInfo>,
     fcast_txt_records: HashMap<String, String>,
     fcast_senders: HashMap<SenderId, FCastSenderHandle>,
+    pairing: Arc<Pairing>,
+    /// The sender whose PIN is on screen.
+    pairing_shown_for: Option<SenderId>,
     inspector_bitrates: InspectorBitrates,
     /// Gates all inspector work so nothing is computed or sent while it is
     /// closed.
@@ -808,6 +953,7 @@ impl Application {
         video_sink: Option<gst::Element>,
         // The video sink's subtitle cue state; `None` when headless.
         cue_engine: Option<fcast_video::cue::CueEngine>,
+        secondary_cues: Option<fcast_video::secondary::SecondaryCues>,
         msg_tx: MessageSender,
         #[cfg(not(target_os = "android"))] settings: Settings,
     ) -> Result<Self> {
@@ -843,9 +989,32 @@ impl Application {
         let companion_ctx = CompanionContext::new();
         #[cfg(feature = "airplay")]
         let airplay_context = airplay::AirPlayContext::new();
+        #[cfg(target_os = "android")]
+        let subtitles = crate::config::SubtitlesConfig::default();
+        #[cfg(not(target_os = "android"))]
+        let subtitles = settings.config.get().subtitles.clone();
+        if let Some(engine) = cue_engine.as_ref() {
+            engine.set_style(subtitle_style::cue_style(&subtitles));
+        }
+        if let Some(secondary) = secondary_cues.as_ref() {
+            secondary
+                .engine()
+                .set_style(subtitle_style::secondary_cue_style(&subtitles));
+        }
+        let metrics = metrics::Metrics::new(
+            cue_engine
+                .iter()
+                .map(|engine| ("primary", engine.clone()))
+                .chain(
+                    secondary_cues
+                        .iter()
+                        .map(|secondary| ("secondary", secondary.engine().clone())),
+                )
+                .collect(),
+        );
         let player = player::Player::new(
             video_sink,
-            cue_engine,
+            cue_engine.clone(),
             msg_tx.clone(),
             fcompsrc::imp::CompContext(companion_ctx.clone()),
             #[cfg(feature = "airplay")]
@@ -854,35 +1023,47 @@ impl Application {
 
         let (updates_tx, _) = broadcast::channel(10);
 
-        let (acceptor, fingerprint) = {
-            use rcgen::{CertificateParams, DistinguishedName, KeyPair, date_time_ymd};
-            use tokio_rustls::{TlsAcceptor, rustls};
-
-            let mut params: CertificateParams = Default::default();
-            params.not_before = date_time_ymd(1975, 1, 1);
-            params.not_after = date_time_ymd(4096, 1, 1);
-            params.distinguished_name = DistinguishedName::new();
-            let key_pair = KeyPair::generate()?;
-            let cert = params.self_signed(&key_pair)?;
-            let spki = key_pair.subject_public_key_info();
-            use sha2::Digest;
-            let digest = sha2::Sha256::digest(&spki);
-            let fingerprint = base64::engine::general_purpose::STANDARD.encode(digest);
-
-            let config =
-                rustls::ServerConfig::builder_with_protocol_versions(&[&rustls::version::TLS13])
-                    .with_no_client_auth()
-                    .with_single_cert(vec![cert.der().to_owned()], key_pair.into())?;
-            (TlsAcceptor::from(Arc::new(config)), fingerprint)
+        // Android has no config location, so it keeps a per-launch identity.
+        #[cfg(target_os = "android")]
+        let tls_identity = TlsIdentity::generate()?;
+        #[cfg(not(target_os = "android"))]
+        let tls_identity = if settings.rotate_tls_identity() {
+            TlsIdentity::rotate(settings.config.state_dir())?
+        } else {
+            TlsIdentity::load_or_create(settings.config.state_dir())?
         };
+        let acceptor = tls_identity.acceptor()?;
+
+        #[cfg(target_os = "android")]
+        let resume_positions = ResumeStore::load(None);
+        #[cfg(not(target_os = "android"))]
+        let resume_positions = ResumeStore::load(settings.config.state_dir());
+        #[cfg(target_os = "android")]
+        let playback_history = PlaybackHistory::load(None);
+        #[cfg(not(target_os = "android"))]
+        let playback_history = PlaybackHistory::load(settings.config.state_dir());
 
         let fcast_txt_records = HashMap::from([
-            ("fp".to_owned(), fingerprint),
+            ("fp".to_owned(), tls_identity.fingerprint().to_owned()),
             ("v".to_owned(), "4".to_owned()),
         ]);
         #[cfg(not(target_os = "android"))]
         let mdns = mdns::start_daemon(&msg_tx, &settings)?;
 
+        #[cfg(target_os = "android")]
+        let pairing = Pairing::default();
+        #[cfg(not(target_os = "android"))]
+        let pairing = {
+            let fcast = &settings.config.get().fcast;
+            Pairing::new(
+                fcast.require_pairing,
+                fcast
+                    .paired_senders
+                    .iter()
+                    .map(|sender| sender.token_sha256.clone()),
+            )
+        };
+
         let run_gcast = if cfg!(not(target_os = "android")) {
             settings.google_cast_enabled()
         } else {
@@ -905,6 +1086,74 @@ impl Application {
             GCastUpdateSender(None)
         };
 
+        #[cfg(target_os = "linux")]
+        let mpris_tx = if settings.mpris_enabled() {
+            let (mpris_tx, mpris_rx) = mpsc::unbounded_channel::<mpris::StatusUpdate>();
+            tokio::spawn({
+                let msg_tx = msg_tx.clone();
+                async move {
+                    // No session bus is no reason to stop; the media keys just don't work.
+                    if let Err(err) = mpris::run_server(msg_tx, mpris_rx).await {
+                        warn!(?err, "MPRIS server stopped");
+                    }
+                }
+            });
+            MprisUpdateSender(Some(mpris_tx))
+        } else {
+            MprisUpdateSender(None)
+        };
+        #[cfg(not(target_os = "linux"))]
+        let mpris_tx = MprisUpdateSender(None);
+
+        let dlna_tx = if cfg!(not(target_os = "android")) && settings.dlna_enabled() {
+            let (dlna_tx, dlna_rx) = mpsc::unbounded_channel::<dlna::StatusUpdate>();
+            tokio::spawn({
+                let msg_tx = msg_tx.clone();
+                let name = settings.dlna_name();
+                let exclude_interfaces = settings.exclude_interfaces().map(str::to_owned);
+                async move {
+                    if let Err(err) =
+                        dlna::run_server(msg_tx, name, exclude_interfaces, dlna_rx).await
+                    {
+                        warn!(?err, "DLNA server stopped (port 49494 may be in use)");
+                    }
+                }
+            });
+            DlnaUpdateSender(Some(dlna_tx))
+        } else {
+            DlnaUpdateSender(None)
+        };
+
+        if cfg!(not(target_os = "android")) && settings.control_api_enabled() {
+            tokio::spawn({
+                let msg_tx = msg_tx.clone();
+                let updates_tx = updates_tx.clone();
+                let access = control_api::Access {
+                    token: settings.control_api_token(),
+                    allowed_origins: settings.control_api_allowed_origins(),
+                    state_dir: settings.config.state_dir().map(ToOwned::to_owned),
+                };
+                async move {
+                    if let Err(err) = control_api::run_server(msg_tx, updates_tx, access).await {
+                        warn!(?err, "Control API stopped (port 46898 may be in use)");
+                    }
+                }
+            });
+        }
+
+        if cfg!(not(target_os = "android")) && settings.metrics_enabled() {
+            tokio::spawn({
+                let metrics = metrics.clone();
+                let msg_tx = msg_tx.clone();
+                let public = settings.metrics_public();
+                async move {
+                    if let Err(err) = metrics::run_server(metrics, msg_tx, public).await {
+                        warn!(?err, "Metrics endpoint stopped (port 46897 may be in use)");
+                    }
+                }
+            });
+        }
+
         #[cfg(any(target_os = "macos", target_os = "windows"))]
         tokio::spawn({
             use tracing::Instrument;
@@ -962,6 +1211,9 @@ impl Application {
             debug_mode: false,
             player,
             current_duration: None,
+            resume_positions,
+            resume_key: None,
+            playback_history,
             pending_subtitle_adds: Vec::new(),
             pending_subtitle_add_epoch: 0,
             last_progress_broadcast: None,
@@ -971,11 +1223,24 @@ impl Application {
             pending_seek_epoch: 0,
             seek_quiet: false,
             seek_quiet_epoch: 0,
+            last_seek_target: None,
+            chapters: Vec::new(),
+            chapters_from_sender: false,
+            queue_mode: fcast::QueueMode::default(),
+            sleep_timer: None,
+            sleep_timer_epoch: 0,
+            subtitles,
+            cue_engine,
+            secondary_cues,
+            secondary_subtitle: None,
+            delays: av_delay::Delays::default(),
             gui_seek_hold: None,
             load_watchdog_epoch: 0,
             source_backoff: None,
             source_backoff_epoch: 0,
+            source_reconnecting: false,
             freeze_watchdog: freeze_watchdog::FreezeWatchdog::new(),
+            metrics,
             current_image_id: 0,
             image_via_player: false,
             have_audio_track_cover: false,
@@ -1007,6 +1272,8 @@ impl Application {
             #[cfg(any(target_os = "macos", target_os = "windows"))]
             update: None,
             gcast_tx,
+            mpris_tx,
+            dlna_tx,
             #[cfg(not(target_os = "android"))]
             settings,
             window_visible_before_playing: None,
@@ -1033,6 +1300,8 @@ impl Application {
             receiver_info,
             fcast_txt_records,
             fcast_senders: HashMap::new(),
+            pairing: Arc::new(pairing),
+            pairing_shown_for: None,
         })
     }
 
@@ -1062,11 +1331,17 @@ impl Application {
 
         self.gcast_tx
             .send(gcast::StatusUpdate::Volume(volume as f64));
+        self.mpris_tx
+            .send(mpris::StatusUpdate::Volume(volume as f64));
+        self.dlna_tx.send(dlna::StatusUpdate::Volume(volume as f64));
     }
 
     /// Relay a playback rate to all senders.
     fn broadcast_rate(&mut self, rate: f32) -> Result<()> {
         self.notify_updates(true)?;
+        self.gcast_tx
+            .send(gcast::StatusUpdate::PlaybackRate(rate as f64));
+        self.mpris_tx.send(mpris::StatusUpdate::Rate(rate as f64));
         if self.updates_tx.strong_count() > 0 {
             let _ = self.updates_tx.send(Arc::new(ReceiverToSenderMessage::V4(
                 fcast::V4Message::PlaybackRateChanged(rate),
@@ -1095,7 +1370,7 @@ impl Application {
         serialized_msg: fcast_protocol::v4::ConstructedMessage<'static>,
     ) {
         let sender_id = match origin {
-            PacketOrigin::Gui => Some(0),
+            PacketOrigin::Gui | PacketOrigin::Mpris | PacketOrigin::ControlApi => Some(0),
             PacketOrigin::FCast { sender_id, .. } => Some(sender_id),
             _ => None,
         };
@@ -1217,6 +1492,8 @@ impl Application {
 
     fn playback_state_changed(&mut self, state: fcast_protocol::v4::PlaybackState) {
         use fcast_protocol::v4::PlaybackState as S;
+        // Counted before the debounce: a seek's rebuffering is still time spent.
+        self.metrics.buffering(matches!(state, S::Buffering));
         // Seek debounce: transients stay quiet, a settled state ends the window.
         match state {
             S::Idle | S::Buffering if self.seek_quiet => return,
@@ -1230,6 +1507,15 @@ impl Application {
         }
     }
 
+    /// The current item failed to load or play: count it and tell whoever
+    /// asked for it.
+    fn load_failed(&self, error: fcast_protocol::v4::flat::ErrorKind) {
+        self.metrics.load_error(error);
+        if let Some(origin) = self.current_media.as_ref().map(|m| m.origin) {
+            self.send_error(origin, error);
+        }
+    }
+
     fn send_error(&self, origin: PacketOrigin, error: fcast_protocol::v4::flat::ErrorKind) {
         error!(?origin, ?error, "An error occured");
 
@@ -1237,7 +1523,10 @@ impl Application {
             PacketOrigin::Gui
             | PacketOrigin::AutoPlay
             | PacketOrigin::Raop
-            | PacketOrigin::AirPlay => (),
+            | PacketOrigin::AirPlay
+            | PacketOrigin::Mpris
+            | PacketOrigin::Dlna
+            | PacketOrigin::ControlApi => (),
             PacketOrigin::FCast {
                 sender_id,
                 packet_num,
@@ -1309,8 +1598,33 @@ impl Application {
 
         self.gcast_tx.send(gcast::StatusUpdate::Duration(duration));
         self.gcast_tx.send(gcast::StatusUpdate::Position(position));
+        self.mpris_tx.send(mpris::StatusUpdate::Duration(duration));
+        self.mpris_tx.send(mpris::StatusUpdate::Position(position));
+        self.dlna_tx.send(dlna::StatusUpdate::Duration(duration));
+        self.dlna_tx.send(dlna::StatusUpdate::Position(position));
+        #[cfg(feature = "airplay")]
+        self.sync_airplay_playback(position, duration);
 
         let is_live = self.player.is_live();
+        self.resume_positions.set_mode(self.resume_mode());
+        if let Some(key) = self.resume_key.as_deref()
+            && !is_live
+        {
+            self.resume_positions.update(key, position, duration);
+        }
+        if self.resume_key.is_some() {
+            self.playback_history.progress(position, duration);
+        }
+        if self
+            .sleep_timer
+            .as_ref()
+            .is_some_and(SleepTimer::ends_with_item)
+            && !is_live
+            && duration > 0.0
+        {
+            let left = Duration::from_secs_f64((duration - position).max(0.0));
+            self.fade_for_sleep_timer(left);
+        }
         let playback_state = {
             match self.player.player_state() {
                 PlayerState::Stopped | PlayerState::Buffering => GuiPlaybackState::Loading,
@@ -1360,6 +1674,13 @@ impl Application {
         preserve_playlist: PreservePlaylist,
     ) {
         self.current_duration = None;
+        // The last progress tick already recorded where the item stopped.
+        if self.resume_key.take().is_some() {
+            self.resume_positions.save();
+            self.playback_history.stopped();
+            self.playback_history.save();
+            self.push_history_to_gui();
+        }
         // Playback is stopping or being replaced: a real Idle must go out.
         self.seek_quiet = false;
         self.gapless_prearm = None;
@@ -1367,14 +1688,19 @@ impl Application {
         self.held_prearm_events.clear();
         self.gapless_parked_op = None;
         self.reject_pending_subtitle_adds();
+        self.clear_secondary_subtitle();
         self.drop_pending_seek();
         if self.gui_seek_hold.take().is_some() {
             self.gui.set_seek_pending(false);
         }
+        #[cfg(feature = "airplay")]
+        self.airplay_context.set_playback(None);
         self.have_audio_track_cover = false;
         self.have_media_info = false;
         self.have_media_title = false;
         self.last_artist_name = None;
+        self.chapters.clear();
+        self.chapters_from_sender = false;
         self.last_position_updated = -1.0;
         self.image_via_player = false;
         self.gui.set_image_via_player(false);
@@ -1390,8 +1716,11 @@ impl Application {
         self.current_image_id += 1;
         self.current_image_download_id += 1;
         self.clear_source_backoff();
+        self.set_source_reconnecting(false);
 
         if continue_to_play == ContinueToPlay::No {
+            self.mpris_tx.send(mpris::StatusUpdate::Stopped);
+            self.dlna_tx.send(dlna::StatusUpdate::Stopped);
             self.gui.set_media_title("".to_owned());
             self.gui.set_artist_name("".to_owned());
             self.gui.clear_images();
@@ -1506,7 +1835,7 @@ impl Application {
                 if queue.autoplay
                     && let Some(show_duration) = queue
                         .items
-                        .get(queue.current_idx as usize)
+                        .get(queue.current_idx)
                         .and_then(|item| item.show_duration)
                 {
                     self.arm_show_duration(show_duration, self.current_media_item_id);
@@ -1530,10 +1859,14 @@ impl Application {
         }
 
         error!(?kind, msg = diagnostic, "Media error");
+        self.gcast_tx.send(gcast::StatusUpdate::IdleReason(
+            google_cast_protocol::IdleReason::Error,
+        ));
 
         self.cleanup_playback_data(ContinueToPlay::No, PreservePlaylist::No);
         self.current_media = None;
         self.queue_cache.clear();
+        self.sync_queue_status();
 
         if self.should_broadcast() {
             let update = v3::PlaybackUpdateMessage {
@@ -1619,6 +1952,13 @@ impl Application {
     fn media_ended(&mut self) {
         info!("Media finished");
 
+        if let Some(key) = self.resume_key.as_deref() {
+            self.resume_positions.forget(key);
+            self.resume_positions.save();
+            self.playback_history.stopped();
+            self.playback_history.save();
+        }
+
         #[cfg(target_os = "android")]
         {
             let android_app = self.android_app.clone();
@@ -1632,9 +1972,16 @@ impl Application {
 
         // An autoplay queue with a next item is exempt: the receiver-side advance must
         // keep working after the last sender disconnects.
-        if self.updates_tx.receiver_count() == 0 && self.autoplay_next_index().is_none() {
+        if self
+            .sleep_timer
+            .as_ref()
+            .is_some_and(SleepTimer::ends_with_item)
+        {
+            self.sleep_timer_ran_out();
+        } else if self.updates_tx.receiver_count() == 0 && self.autoplay_next_index().is_none() {
             self.cleanup_playback_data(ContinueToPlay::No, PreservePlaylist::Yes);
             self.current_media = None;
+            self.sync_queue_status();
         }
 
         self.screensaver_inhibitor.un_inhibit();
@@ -1654,9 +2001,7 @@ impl Application {
         self.inspector_image = String::new();
         if let Err(err) = self.load_current_media_item() {
             error!(?err, "Failed to load media");
-            if let Some(origin) = self.current_media.as_ref().map(|m| m.origin) {
-                self.send_error(origin, load_media_error_kind(&err));
-            }
+            self.load_failed(load_media_error_kind(&err));
         }
         self.sync_queue_cache();
     }
@@ -1734,6 +2079,20 @@ impl Application {
         // LATER load.
         let start_override = self.load_start_override.take();
         let current_media = self.current_media.as_ref().ok_or(LoadMediaError::NoItem)?;
+        let resume_requested = match &current_media.source {
+            MediaSource::Single(play_data) => match play_data.as_ref() {
+                fcast::WrappedPlayMessage::V4(packet) => packet
+                    .borrow_dependent()
+                    .source_as_single()
+                    .is_some_and(|single| single.resume()),
+                _ => false,
+            },
+            MediaSource::Queue(queue) => queue
+                .items
+                .get(queue.current_idx)
+                .is_some_and(|item| item.resume),
+            _ => false,
+        };
         // TODO: this shouldn't be v3 item
         let item = match &current_media.source {
             MediaSource::Single(play_data) => match play_data.as_ref() {
@@ -1770,7 +2129,7 @@ impl Application {
                 .clone(),
             MediaSource::Queue(queue) => queue
                 .items
-                .get(queue.current_idx as usize)
+                .get(queue.current_idx)
                 .ok_or(LoadMediaError::IndexOutOfBounds)?
                 .to_media_item(),
             MediaSource::Raop => {
@@ -1784,6 +2143,31 @@ impl Application {
             }
         };
 
+        // The v3 item of a v4 single carries no metadata, its labels are read from the
+        // packet.
+        let (history_title, history_thumbnail) = match &item.metadata {
+            Some(v3::MetadataObject::Generic {
+                title,
+                thumbnail_url,
+                ..
+            }) => (title.clone(), thumbnail_url.clone()),
+            None => match &current_media.source {
+                MediaSource::Single(play_data) => match play_data.as_ref() {
+                    fcast::WrappedPlayMessage::V4(packet) => packet
+                        .borrow_dependent()
+                        .source_as_single()
+                        .map(|single| {
+                            (
+                                single.title().map(ToOwned::to_owned),
+                                single.thumbnail_url().map(ToOwned::to_owned),
+                            )
+                        })
+                        .unwrap_or_default(),
+                    _ => (None, None),
+                },
+                _ => (None, None),
+            },
+        };
         let container = item.container;
         let url = match item.url {
             Some(url) => url,
@@ -1803,13 +2187,50 @@ impl Application {
             }
         };
         let volume = item.volume.map(|v| v as f32);
+        // Inline content and still images have no position worth coming back to.
+        let resume_key = (!url.starts_with("data:") && !container.starts_with("image/"))
+            .then(|| strip_uri_query(&url).to_owned());
+        // Cast senders follow what plays whoever cast it. Inline content would not fit
+        // in a Cast message.
+        if !url.starts_with("data:") {
+            let media = gcast::MediaDescription {
+                container: container.clone(),
+                url: url.clone(),
+                title: history_title.clone(),
+                thumbnail_url: history_thumbnail.clone(),
+            };
+            if self.dlna_tx.is_enabled() {
+                self.dlna_tx.send(dlna::StatusUpdate::Media(media.clone()));
+            }
+            self.gcast_tx.send(gcast::StatusUpdate::Media(media));
+        }
+        self.mpris_tx
+            .send(mpris::StatusUpdate::Media(mpris::MediaDescription {
+                url: (!url.starts_with("data:")).then(|| url.clone()),
+                title: history_title.clone(),
+                art_url: history_thumbnail.clone(),
+            }));
+        let resume_mode = self.resume_mode();
+        self.resume_positions.set_mode(resume_mode);
+        let wants_resume = match resume_mode {
+            ResumeMode::Off => false,
+            ResumeMode::OnRequest => resume_requested,
+            ResumeMode::Always => resume_requested || item.time.is_none_or(|t| t <= 0.0),
+        };
+        let resume_from = resume_key
+            .as_deref()
+            .filter(|_| wants_resume && start_override.is_none())
+            .and_then(|key| self.resume_positions.position(key))
+            .and_then(|s| gst::ClockTime::try_from_seconds_f64(s).ok());
         // An override stands for the operation this load replaces, so it wins over the
-        // item's own start point.
+        // item's own start point, as does a remembered position.
         let start_position = match start_override {
             Some(start) => start.position,
-            None => item
-                .time
-                .and_then(|s| gst::ClockTime::try_from_seconds_f64(s).ok())
+            None => resume_from
+                .or_else(|| {
+                    item.time
+                        .and_then(|s| gst::ClockTime::try_from_seconds_f64(s).ok())
+                })
                 .unwrap_or(gst::ClockTime::ZERO),
         };
         let playback_rate = match start_override {
@@ -1924,7 +2345,21 @@ impl Application {
                 rate: playback_rate,
             });
             let source = self.build_media_source(&container, url, headers.clone());
+            self.reset_delays();
             self.player.load(source, start);
+            if let Some(position) = resume_from {
+                info!(?position, "Resuming where the item was left off");
+                // Senders otherwise learn the start only from the first progress tick,
+                // and show the item's own start time until then.
+                if self.should_broadcast() {
+                    self.broadcast_update(ReceiverToSenderMessage::V4(
+                        fcast::V4Message::ProgressUpdated {
+                            pos: position,
+                            dur: gst::ClockTime::ZERO,
+                        },
+                    ));
+                }
+            }
             if let Some(volume) = volume {
                 // Stamp the echo window so stale read-back notifies aren't relayed as
                 // external changes; the confirm comes from the Load relay itself.
@@ -1933,7 +2368,14 @@ impl Application {
             }
         }
 
+        if let Some(key) = resume_key.clone() {
+            self.playback_history_started(key, container, history_title, history_thumbnail);
+        }
+        self.resume_key = resume_key;
         self.have_media_title = media_title.is_some();
+        let chapters = self.current_item_chapters();
+        self.chapters_from_sender = !chapters.is_empty();
+        self.set_chapters(chapters);
 
         self.gui.set_player_type(player_variant);
         if !is_image {
@@ -1945,6 +2387,7 @@ impl Application {
 
         self.current_media_item_id += 1;
         self.recent_warnings.clear();
+        self.clear_secondary_subtitle();
 
         if is_image {
             tokio::spawn({
@@ -1958,6 +2401,7 @@ impl Application {
         }
         self.is_loading_media = true;
         self.clear_source_backoff();
+        self.set_source_reconnecting(false);
 
         // A pipeline load should reach a steady PAUSED quickly; dump diagnostics if
         // not.
@@ -2054,11 +2498,15 @@ impl Application {
     fn stop_playback(&mut self) {
         tracing::info!(is_playing = self.is_playing());
         if self.is_playing() {
+            self.gcast_tx.send(gcast::StatusUpdate::IdleReason(
+                google_cast_protocol::IdleReason::Cancelled,
+            ));
             self.player.stop();
             self.gui.set_app_state(AppState::Idle);
             self.cleanup_playback_data(ContinueToPlay::No, PreservePlaylist::No);
             self.current_media = None;
             self.queue_cache.clear();
+            self.sync_queue_status();
             self.screensaver_inhibitor.un_inhibit();
         }
     }
@@ -2074,12 +2522,12 @@ impl Application {
         };
 
         let index = match position {
-            v4::QueuePosition::Index(idx) => idx,
+            v4::QueuePosition::Index(idx) => idx as usize,
             v4::QueuePosition::Front => 0,
-            v4::QueuePosition::Back => queue.items.len().saturating_sub(1) as u8,
+            v4::QueuePosition::Back => queue.items.len().saturating_sub(1),
         };
 
-        if queue.items.is_empty() || index as usize >= queue.items.len() {
+        if queue.items.is_empty() || index >= queue.items.len() {
             error!(index, "Requested queue item index does not exist");
             self.send_error(origin, ErrorKind::QueuePositionOutOfRange);
             return;
@@ -2108,6 +2556,14 @@ impl Application {
     /// `media_ended` teardown, so an unattended autoplay queue is not wiped
     /// between items.
     fn autoplay_next_index(&self) -> Option<usize> {
+        // Also keeps a gapless pre-arm from running past the end of the item.
+        if self
+            .sleep_timer
+            .as_ref()
+            .is_some_and(SleepTimer::ends_with_item)
+        {
+            return None;
+        }
         let media = self.current_media.as_ref()?;
         let MediaSource::Queue(queue) = &media.source else {
             return None;
@@ -2115,8 +2571,9 @@ impl Application {
         if !queue.autoplay {
             return None;
         }
-        let next = queue.current_idx as usize + 1;
-        (next < queue.items.len()).then_some(next)
+        queue
+            .order
+            .next(queue.items.len(), queue.current_idx, self.queue_mode.repeat)
     }
 
     fn maybe_autoplay_advance(&mut self) {
@@ -2128,12 +2585,12 @@ impl Application {
         };
 
         info!(next, "Autoplay: advancing to the next queue item");
-        self.play_queue_item(origin, v4::QueuePosition::Index(next as u8), false);
+        self.play_queue_item(origin, v4::QueuePosition::Index(next as u32), false);
 
         if self.should_broadcast() {
             self.broadcast_update(ReceiverToSenderMessage::V4(fcast::V4Message::Broadcast {
                 serialized_msg: fcast_protocol::v4::MessageBuilder::new()
-                    .queue_select(v4::QueuePosition::Index(next as u8)),
+                    .queue_select(v4::QueuePosition::Index(next as u32)),
             }));
         }
     }
@@ -2183,7 +2640,7 @@ impl Application {
             else {
                 return;
             };
-            let Some(current) = queue.items.get(queue.current_idx as usize) else {
+            let Some(current) = queue.items.get(queue.current_idx) else {
                 return;
             };
             let Some(next_item) = queue.items.get(next) else {
@@ -2286,12 +2743,14 @@ impl Application {
                 || self.gui_seek_hold.is_some()
                 || self.pending_seek_op.is_some()
                 || self.gapless_parked_op.is_some(),
+            source_reconnecting: self.source_reconnecting,
             rate: self.player.rate(),
             position: playing.then(|| self.player.get_position()).flatten(),
             duration: self.current_duration,
         };
 
         let action = self.freeze_watchdog.poll(&sample);
+        self.metrics.freeze_recovery(action);
         let pinned_for = match action {
             FreezeAction::None => return Ok(()),
             FreezeAction::Seek { pinned_for }
@@ -2510,6 +2969,132 @@ impl Application {
         }
     }
 
+    fn seek_to(&mut self, origin: PacketOrigin, time: gst::ClockTime) {
+        // Range-check first so a park cannot delay the sender's error reply.
+        let time = self.clamp_seek_target(origin, time);
+        self.last_seek_target = Some(time);
+        self.arm_seek_quiet();
+        // A flushing seek must not reach the pipeline before a pre-arm
+        // cancellation is confirmed.
+        self.park_or_apply_gapless_op(GaplessParkedOp::Seek { origin, time });
+    }
+
+    /// Resolve a relative seek against where playback is headed: the target
+    /// of a seek that has not settled yet, else the playhead. Building on the
+    /// playhead alone would fold a quick burst of skips into one. Going
+    /// before the start clamps to it and is reported like an overshoot.
+    fn relative_seek_target(
+        &mut self,
+        origin: PacketOrigin,
+        offset: gst::Signed<gst::ClockTime>,
+    ) -> Option<gst::ClockTime> {
+        let base = self.seek_base()?;
+        Some(match offset {
+            gst::Signed::Positive(offset) => base.saturating_add(offset),
+            gst::Signed::Negative(offset) => {
+                if offset > base {
+                    self.send_error(origin, ErrorKind::SeekOutOfRange);
+                }
+                base.saturating_sub(offset)
+            }
+        })
+    }
+
+    /// Where playback is headed, see
+    /// [`relative_seek_target`](Self::relative_seek_target).
+    fn seek_base(&self) -> Option<gst::ClockTime> {
+        match self.last_seek_target {
+            Some(target) if self.seek_quiet => Some(target),
+            _ => self.player.get_position(),
+        }
+    }
+
+    fn seek_to_chapter(&mut self, origin: PacketOrigin, target: v4::ChapterTarget) {
+        let Some(base) = self.seek_base() else {
+            self.send_error(origin, ErrorKind::InvalidState);
+            return;
+        };
+        match chapter_start(&self.chapters, base, target) {
+            Some(start) => self.seek_to(origin, start),
+            None => {
+                debug!(
+                    ?target,
+                    chapters = self.chapters.len(),
+                    "No chapter to seek to"
+                );
+                self.send_error(origin, ErrorKind::InvalidState);
+            }
+        }
+    }
+
+    /// The chapters the sender attached to the current item, if any.
+    fn current_item_chapters(&self) -> Vec<v4::Chapter> {
+        match self.current_media.as_ref().map(|m| &m.source) {
+            Some(MediaSource::Single(play_data)) => match play_data.as_ref() {
+                fcast::WrappedPlayMessage::V4(packet) => packet
+                    .borrow_dependent()
+                    .source_as_single()
+                    .map(|item| v4::read_media_item_chapters(&item))
+                    .unwrap_or_default(),
+                _ => Vec::new(),
+            },
+            Some(MediaSource::Queue(queue)) => queue
+                .items
+                .get(queue.current_idx)
+                .map(|item| item.chapters.clone())
+                .unwrap_or_default(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// `[playback] resume`.
+    #[cfg(not(target_os = "android"))]
+    fn resume_mode(&self) -> ResumeMode {
+        self.settings.resume_mode()
+    }
+
+    /// Android has no config file and keeps the default.
+    #[cfg(target_os = "android")]
+    fn resume_mode(&self) -> ResumeMode {
+        ResumeMode::default()
+    }
+
+    /// Adopt the current item's chapters: mark them on the scrubber and
+    /// advertise them to senders. Also sent when the list is empty, so a
+    /// sender drops the previous item's chapters. Senders needn't list them
+    /// in order; they are kept and advertised sorted by start.
+    fn set_chapters(&mut self, mut chapters: Vec<v4::Chapter>) {
+        chapters.sort_by(|a, b| a.start.total_cmp(&b.start));
+        self.gui
+            .set_chapter_marks(chapters.iter().map(|c| c.start as f32).collect());
+        if self.should_broadcast() {
+            let serialized_msg = v4::MessageBuilder::new().chapters_available(&chapters);
+            self.broadcast_update(ReceiverToSenderMessage::V4(
+                fcast::V4Message::ChaptersAvailable { serialized_msg },
+            ));
+        }
+        self.chapters = chapters;
+    }
+
+    fn step_frame(&mut self, origin: PacketOrigin, forward: bool) {
+        if !self.is_playing()
+            || self.player.player_state() != PlayerState::Paused
+            || !self.player.seekable
+        {
+            self.send_error(origin, ErrorKind::InvalidState);
+            return;
+        }
+        // A step is a flushing seek too, so it may not run while a pre-arm is
+        // pending (see `park_or_apply_gapless_op`). Not worth a parked kind of
+        // its own: refuse, the sender can retry once the item has moved on.
+        if self.gapless_prearm.is_some() {
+            debug!("Refusing a frame step while the next item is pre-armed");
+            self.send_error(origin, ErrorKind::InvalidState);
+            return;
+        }
+        self.player.step_frame(forward);
+    }
+
     /// Send an already-clamped seek to the pipeline, or park it until the
     /// seekability query resolves; the player would silently drop a seek
     /// issued in that window.
@@ -2658,31 +3243,63 @@ impl Application {
         if let Some(media) = self.current_media.as_mut() {
             media.clear_external_subtitles();
         }
-        let (title, thumbnail_url, headers) = match self.queue_mut() {
+        self.reset_delays();
+        // The retired item played through; the new one starts from zero and is
+        // remembered under its own key from the next tick.
+        if let Some(key) = self.resume_key.take() {
+            self.resume_positions.forget(&key);
+            self.resume_positions.save();
+        }
+        let (title, thumbnail_url, headers, chapters) = match self.queue_mut() {
             Some(queue) => {
-                queue.current_idx = next_index as u8;
+                queue.current_idx = next_index;
                 match queue.items.get(next_index) {
                     Some(item) => (
                         item.title.clone(),
                         item.thumbnail_url.clone(),
                         item.headers.clone(),
+                        item.chapters.clone(),
                     ),
-                    None => (None, None, None),
+                    None => (None, None, None, Vec::new()),
                 }
             }
-            None => (None, None, None),
+            None => (None, None, None, Vec::new()),
         };
+        self.resume_key = self
+            .queue_mut()
+            .and_then(|queue| queue.items.get(next_index))
+            .filter(|item| !item.content_type.starts_with("image/"))
+            .map(|item| strip_uri_query(&item.url).to_owned());
+        if let Some(key) = self.resume_key.clone()
+            && let Some(container) = self
+                .queue_mut()
+                .and_then(|queue| queue.items.get(next_index))
+                .map(|item| item.content_type.clone())
+        {
+            self.playback_history_started(key, container, title.clone(), thumbnail_url.clone());
+        }
 
         // Per-item view state rolls like a fresh load; the new item's collection
         // follows and re-runs media_loaded_successfully through the
         // have_media_info gate.
         self.current_media_item_id += 1;
         self.recent_warnings.clear();
+        self.clear_secondary_subtitle();
         self.have_media_info = false;
         self.current_duration = None;
         self.inspector_container = None;
         self.inspector_image = String::new();
         self.have_media_title = title.is_some();
+        self.mpris_tx
+            .send(mpris::StatusUpdate::Media(mpris::MediaDescription {
+                url: self
+                    .queue_mut()
+                    .and_then(|queue| queue.items.get(next_index))
+                    .map(|item| item.url.clone())
+                    .filter(|url| !url.starts_with("data:")),
+                title: title.clone(),
+                art_url: thumbnail_url.clone(),
+            }));
         // The gapless path skips cleanup_playback_data, so the labels roll
         // here too: a titleless item must not keep the retired item's title,
         // and the artist only ever comes from Tags, so it clears either way
@@ -2690,6 +3307,9 @@ impl Application {
         self.gui.set_media_title(title.unwrap_or_default());
         self.last_artist_name = None;
         self.gui.set_artist_name(String::new());
+        // The held TOC follows the activation, like the collection.
+        self.chapters_from_sender = !chapters.is_empty();
+        self.set_chapters(chapters);
 
         // The gapless path bypasses the normal load, so refresh the audio cover here or
         // the previous track's thumbnail lingers and the Tags handler ignores new art.
@@ -2716,7 +3336,7 @@ impl Application {
         if self.should_broadcast() {
             self.broadcast_update(ReceiverToSenderMessage::V4(fcast::V4Message::Broadcast {
                 serialized_msg: fcast_protocol::v4::MessageBuilder::new()
-                    .queue_select(v4::QueuePosition::Index(next_index as u8)),
+                    .queue_select(v4::QueuePosition::Index(next_index as u32)),
             }));
         }
         self.sync_queue_cache();
@@ -2750,22 +3370,23 @@ impl Application {
     fn sync_queue_cache(&mut self) {
         let (desired, retain) = match self.current_media.as_ref().map(|m| &m.source) {
             Some(MediaSource::Queue(queue)) => {
-                let desired =
-                    queue_cache::window_indices(queue.items.len(), queue.current_idx as usize)
-                        .into_iter()
-                        .filter_map(|idx| queue.items.get(idx))
-                        // Adaptive manifests must stream live: never prefetch them.
-                        .filter(|item| queue_cache::cacheable_container(&item.content_type))
-                        .map(|item| queue_cache::PrefetchSpec {
-                            url: item.url.clone(),
-                            headers: item.headers.clone(),
-                        })
-                        .collect();
+                let desired = queue
+                    .order
+                    .window(queue.items.len(), queue.current_idx, self.queue_mode.repeat)
+                    .into_iter()
+                    .filter_map(|idx| queue.items.get(idx))
+                    // Adaptive manifests must stream live: never prefetch them.
+                    .filter(|item| queue_cache::cacheable_container(&item.content_type))
+                    .map(|item| queue_cache::PrefetchSpec {
+                        url: item.url.clone(),
+                        headers: item.headers.clone(),
+                    })
+                    .collect();
                 // Retained but never fetched, so flipping to a neighbor and back does not
                 // re-download it.
                 let retain = queue
                     .items
-                    .get(queue.current_idx as usize)
+                    .get(queue.current_idx)
                     .map(|item| item.url.clone())
                     .into_iter()
                     .collect::<Vec<_>>();
@@ -2777,46 +3398,487 @@ impl Application {
         self.queue_cache.sync(desired, &retain, |spec, epoch| {
             prefetcher.fetch(spec, epoch)
         });
+        self.sync_queue_status();
+    }
+
+    /// Apply a Cast queue edit to the queue as it is now, refusing one that
+    /// names an item it doesn't have.
+    fn handle_gcast_queue_edit(
+        &mut self,
+        origin: PacketOrigin,
+        edit: gcast::QueueEdit,
+    ) -> Result<bool> {
+        let ops = match self.current_media.as_ref().map(|m| &m.source) {
+            Some(MediaSource::Queue(queue)) => {
+                let ids = queue.items.iter().map(|item| item.id).collect::<Vec<_>>();
+                edit.operations(
+                    &ids,
+                    Some(queue.current_idx),
+                    &queue.order,
+                    self.queue_mode.repeat,
+                )
+            }
+            _ => None,
+        };
+        let Some(ops) = ops else {
+            warn!("Cast queue edit names an item not in the queue");
+            self.send_error(origin, ErrorKind::QueuePositionOutOfRange);
+            return Ok(false);
+        };
+        for op in ops {
+            if self.handle_operation(op, origin)? {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// Report the queue to Cast senders and MPRIS. Both address items by id,
+    /// so this follows every edit, selection and mode change.
+    fn sync_queue_status(&mut self) {
+        if !self.gcast_tx.is_enabled() && !self.mpris_tx.is_enabled() {
+            return;
+        }
+        let (items, current_item_id) = match self.current_media.as_ref().map(|m| &m.source) {
+            Some(MediaSource::Queue(queue)) => (
+                queue
+                    .items
+                    .iter()
+                    .map(|item| gcast::QueueStatusItem {
+                        id: item.id,
+                        media: gcast::MediaDescription {
+                            container: item.content_type.clone(),
+                            url: item.url.clone(),
+                            title: item.title.clone(),
+                            thumbnail_url: item.thumbnail_url.clone(),
+                        },
+                        autoplay: queue.autoplay,
+                    })
+                    .collect(),
+                queue.items.get(queue.current_idx).map(|item| item.id),
+            ),
+            _ => (Vec::new(), None),
+        };
+        if self.mpris_tx.is_enabled() {
+            self.mpris_tx.send(mpris::StatusUpdate::Queue {
+                items: items
+                    .iter()
+                    .map(|item| mpris::QueueItem {
+                        id: item.id,
+                        media: mpris::MediaDescription {
+                            url: Some(item.media.url.clone()),
+                            title: item.media.title.clone(),
+                            art_url: item.media.thumbnail_url.clone(),
+                        },
+                    })
+                    .collect(),
+                current_item_id,
+                mode: self.queue_mode,
+            });
+        }
+        self.gcast_tx.send(gcast::StatusUpdate::Queue {
+            items,
+            current_item_id,
+            mode: self.queue_mode,
+        });
+    }
+
+    #[tracing::instrument(skip_all)]
+    fn remove_queue_item(&mut self, origin: PacketOrigin, position: v4::QueuePosition) {
+        let Some(queue) = self.queue_mut() else {
+            error!("Cannot play a queue item when there's no active queue");
+            self.send_error(origin, ErrorKind::InvalidState);
+            return;
+        };
+
+        let idx = match position {
+            v4::QueuePosition::Index(idx) => idx as usize,
+            v4::QueuePosition::Front => 0,
+            v4::QueuePosition::Back => queue.items.len().saturating_sub(1),
+        };
+
+        if queue.items.is_empty() || idx >= queue.items.len() {
+            error!(idx, "Invalid index");
+            self.send_error(origin, ErrorKind::QueuePositionOutOfRange);
+            return;
+        }
+
+        if idx == queue.current_idx {
+            error!(idx, "Cannot remove the currently playing item");
+            self.send_error(origin, ErrorKind::QueueRemovePlayingItem);
+            return;
+        }
+
+        if idx <= queue.current_idx {
+            queue.current_idx = queue.current_idx.saturating_sub(1);
+        }
+
+        queue.items.remove(idx);
+        queue.order.removed(idx);
+
+        // Indices shifted (and the pre-armed item itself may be gone).
+        self.cancel_gapless_prearm(player::AfterCancel::Nothing);
+
+        self.relay_to_other_senders(
+            origin,
+            fcast_protocol::v4::MessageBuilder::new().queue_remove(position),
+        );
+
+        self.sync_queue_cache();
+    }
+
+    /// Apply a sender's repeat/shuffle change and confirm it to every sender.
+    /// Turning shuffle on draws a fresh order starting at the current item.
+    fn set_queue_mode(&mut self, mode: fcast::QueueMode) {
+        debug!(?mode, "Setting the queue mode");
+        let next_before = self.autoplay_next_index();
+        let reshuffle = mode.shuffle != self.queue_mode.shuffle;
+        self.queue_mode = mode;
+        if reshuffle && let Some(queue) = self.queue_mut() {
+            queue.order = if mode.shuffle {
+                PlayOrder::shuffled(queue.items.len(), queue.current_idx)
+            } else {
+                PlayOrder::default()
+            };
+        }
+
+        // A pre-armed item that is no longer the next one must not play.
+        if self.autoplay_next_index() != next_before {
+            self.cancel_gapless_prearm(player::AfterCancel::Nothing);
+        }
+
+        if self.should_broadcast() {
+            self.broadcast_update(ReceiverToSenderMessage::V4(fcast::V4Message::Broadcast {
+                serialized_msg: v4::MessageBuilder::new()
+                    .queue_mode_changed(mode.repeat, mode.shuffle),
+            }));
+        }
+
+        self.sync_queue_cache();
+    }
+
+    /// Arm, replace or cancel the sleep timer and confirm it to every sender.
+    fn set_sleep_timer(&mut self, setting: fcast::SleepTimerSetting) {
+        debug!(?setting, "Setting the sleep timer");
+        let next_before = self.autoplay_next_index();
+        // A replaced timer's fade must not outlive it.
+        if let Some(volume) = self
+            .sleep_timer
+            .take()
+            .and_then(|timer| timer.volume_before_fade)
+        {
+            self.set_volume_cmd(volume);
+        }
+        self.sleep_timer_epoch += 1;
+        self.sleep_timer = SleepTimer::new(setting, Instant::now());
+
+        // Ending with the item rules out an advance that was already pre-armed.
+        if self.autoplay_next_index() != next_before {
+            self.cancel_gapless_prearm(player::AfterCancel::Nothing);
+        }
+
+        if let Some(timer) = self.sleep_timer.as_ref()
+            && let sleep_timer::Stop::At(deadline) = timer.stop
+        {
+            let fade_start = deadline.checked_sub(timer.fade).unwrap_or(deadline);
+            let epoch = self.sleep_timer_epoch;
+            let msg_tx = self.msg_tx.clone();
+            tokio::spawn(async move {
+                loop {
+                    // Stale ticks are dropped by the epoch check, so the task never has
+                    // to learn that its timer was replaced.
+                    msg_tx.send(Message::SleepTimerTick { epoch });
+                    let now = Instant::now();
+                    if now >= deadline {
+                        break;
+                    }
+                    let step = if now >= fade_start {
+                        sleep_timer::FADE_TICK
+                    } else {
+                        sleep_timer::TICK
+                    };
+                    tokio::time::sleep(step.min(deadline - now)).await;
+                }
+            });
+        }
+
+        self.sleep_timer_changed();
+    }
+
+    fn sleep_timer_tick(&mut self, epoch: u64) {
+        if epoch != self.sleep_timer_epoch {
+            return;
+        }
+        let Some(left) = self
+            .sleep_timer
+            .as_ref()
+            .and_then(|timer| timer.remaining(Instant::now()))
+        else {
+            return;
+        };
+        if left.is_zero() {
+            self.sleep_timer_ran_out();
+            return;
+        }
+        self.fade_for_sleep_timer(left);
+        self.push_sleep_timer_to_gui();
+    }
+
+    /// Step the volume along the sleep timer's fade, `left` before it runs out.
+    /// Goes through the ordinary volume path, so senders follow the fade.
+    fn fade_for_sleep_timer(&mut self, left: Duration) {
+        let Some(timer) = self.sleep_timer.as_mut() else {
+            return;
+        };
+        let gain = sleep_timer::fade_gain(timer.fade, left);
+        if gain >= 1.0 {
+            return;
+        }
+        let from = *timer
+            .volume_before_fade
+            .get_or_insert_with(|| self.player.volume());
+        let level = from * gain;
+        if (self.player.volume() - level).abs() >= 0.01 {
+            self.set_volume_cmd(level);
+        }
+    }
+
+    /// Stop playback on the sleep timer's behalf and put the volume back where
+    /// the fade found it.
+    fn sleep_timer_ran_out(&mut self) {
+        let Some(timer) = self.sleep_timer.take() else {
+            return;
+        };
+        info!("Sleep timer ran out, stopping playback");
+        self.sleep_timer_epoch += 1;
+        if self.is_playing() {
+            self.stop_playback();
+            if self.should_broadcast() {
+                self.broadcast_update(ReceiverToSenderMessage::V4(fcast::V4Message::Broadcast {
+                    serialized_msg: v4::MessageBuilder::new().stop_playback(),
+                }));
+            }
+        }
+        if let Some(volume) = timer.volume_before_fade {
+            self.set_volume_cmd(volume);
+        }
+        self.sleep_timer_changed();
+    }
+
+    fn sleep_timer_setting(&self) -> fcast::SleepTimerSetting {
+        self.sleep_timer
+            .as_ref()
+            .map_or(fcast::SleepTimerSetting::Off, |timer| {
+                timer.setting(Instant::now())
+            })
+    }
+
+    fn sleep_timer_changed(&mut self) {
+        if self.should_broadcast() {
+            self.broadcast_update(ReceiverToSenderMessage::V4(fcast::V4Message::Broadcast {
+                serialized_msg: self.sleep_timer_setting().message(),
+            }));
+        }
+        self.push_sleep_timer_to_gui();
+    }
+
+    /// Add the item starting to play to the history, credited to the sender
+    /// that cast it.
+    fn playback_history_started(
+        &mut self,
+        url: String,
+        container: String,
+        title: Option<String>,
+        thumbnail_url: Option<String>,
+    ) {
+        let sender_name = self.current_media.as_mut().and_then(|media| {
+            if media.sender_name.is_none()
+                && let PacketOrigin::FCast { sender_id, .. } = media.origin
+            {
+                media.sender_name = self
+                    .fcast_senders
+                    .get(&sender_id)
+                    .and_then(|handle| handle.name.clone());
+            }
+            media.sender_name.clone()
+        });
+        self.playback_history.started(history::Entry::new(
+            url,
+            container,
+            title,
+            thumbnail_url,
+            sender_name,
+        ));
+        self.playback_history.save();
+        self.push_history_to_gui();
+    }
+
+    fn push_history_to_gui(&self) {
+        let entries = self
+            .playback_history
+            .recent(IDLE_HISTORY_ENTRIES)
+            .map(|entry| crate::ui_types::UiHistoryEntry {
+                title: entry.title.clone().unwrap_or_else(|| {
+                    entry
+                        .url
+                        .rsplit('/')
+                        .find(|segment| !segment.is_empty())
+                        .unwrap_or(&entry.url)
+                        .to_owned()
+                }),
+                sender_name: entry.sender_name.clone().unwrap_or_default(),
+                watched_secs: entry.watched as i32,
+                duration_secs: entry.duration.unwrap_or_default() as i32,
+            })
+            .collect();
+        self.gui.set_playback_history(entries);
+    }
+
+    fn push_sleep_timer_to_gui(&self) {
+        let now = Instant::now();
+        match self.sleep_timer.as_ref() {
+            Some(timer) => self.gui.set_sleep_timer(timer.armed, timer.remaining(now)),
+            None => self
+                .gui
+                .set_sleep_timer(fcast::SleepTimerSetting::Off, None),
+        }
+    }
+
+    /// A sender's `SubtitleStyleChanged`. Persisted like a settings drawer
+    /// change, so it is the receiver's style from then on.
+    fn set_subtitle_style(&mut self, update: subtitle_style::StyleUpdate) {
+        subtitle_style::apply(&mut self.subtitles, update);
+        #[cfg(not(target_os = "android"))]
+        {
+            let subtitles = self.subtitles.clone();
+            if let Err(err) = self
+                .settings
+                .config
+                .update(|config| config.subtitles = subtitles)
+            {
+                error!(?err, "Failed to persist subtitle style");
+            }
+            self.push_settings_to_ui();
+        }
+        self.subtitle_style_changed();
+    }
+
+    /// Restyle the cues on screen and confirm the style to senders.
+    fn subtitle_style_changed(&mut self) {
+        if let Some(engine) = self.cue_engine.as_ref() {
+            engine.set_style(subtitle_style::cue_style(&self.subtitles));
+        }
+        if let Some(secondary) = self.secondary_cues.as_ref() {
+            secondary
+                .engine()
+                .set_style(subtitle_style::secondary_cue_style(&self.subtitles));
+        }
+        if self.should_broadcast() {
+            self.broadcast_update(ReceiverToSenderMessage::V4(fcast::V4Message::Broadcast {
+                serialized_msg: subtitle_style::message(&self.subtitles),
+            }));
+        }
+    }
+
+    /// A sender's or the player menu's `DelayChanged`, for the current item
+    /// only. Confirmed even when nothing changed, like a volume change.
+    fn set_delay(&mut self, update: av_delay::DelayUpdate) {
+        self.delays.apply(update);
+        self.player.set_subtitle_delay(self.delays.subtitle());
+        self.player.set_audio_delay(self.delays.audio());
+        if let Some(secondary) = self.secondary_cues.as_ref() {
+            secondary.set_delay(self.delays.subtitle());
+        }
+        self.delays_changed();
+    }
+
+    /// Start a new item from the configured delays. Runs before the item
+    /// loads, so none of it is delivered under the last item's.
+    fn reset_delays(&mut self) {
+        #[cfg(target_os = "android")]
+        let defaults = av_delay::Delays::default();
+        #[cfg(not(target_os = "android"))]
+        let defaults = av_delay::Delays::defaults(&self.settings.config.get().playback);
+        self.player
+            .set_item_delays(defaults.subtitle(), defaults.audio());
+        if let Some(secondary) = self.secondary_cues.as_ref() {
+            secondary.set_delay(defaults.subtitle());
+        }
+        if std::mem::replace(&mut self.delays, defaults) != defaults {
+            self.delays_changed();
+        }
+    }
+
+    fn delays_changed(&mut self) {
+        self.gui.set_delays(self.delays);
+        if self.should_broadcast() {
+            self.broadcast_update(ReceiverToSenderMessage::V4(fcast::V4Message::Broadcast {
+                serialized_msg: self.delays.message(),
+            }));
+        }
     }
 
+    /// Reorder the queue in place. Unlike a remove + insert, the current item
+    /// keeps playing, the pre-arm survives when its item is still the next one,
+    /// and the prefetch window only changes if its neighbours did.
     #[tracing::instrument(skip_all)]
-    fn remove_queue_item(&mut self, origin: PacketOrigin, position: v4::QueuePosition) {
+    fn move_queue_item(
+        &mut self,
+        origin: PacketOrigin,
+        from: v4::QueuePosition,
+        to: v4::QueuePosition,
+    ) {
         let Some(queue) = self.queue_mut() else {
-            error!("Cannot play a queue item when there's no active queue");
+            error!("Cannot move a queue item when there's no active queue");
             self.send_error(origin, ErrorKind::InvalidState);
             return;
         };
 
-        let idx = match position {
+        let resolve = |position| match position {
             v4::QueuePosition::Index(idx) => idx as usize,
             v4::QueuePosition::Front => 0,
             v4::QueuePosition::Back => queue.items.len().saturating_sub(1),
         };
+        let (from_idx, to_idx) = (resolve(from), resolve(to));
 
-        if queue.items.is_empty() || idx >= queue.items.len() {
-            error!(idx, "Invalid index");
+        if from_idx >= queue.items.len() || to_idx >= queue.items.len() {
+            error!(from_idx, to_idx, "Invalid index");
             self.send_error(origin, ErrorKind::QueuePositionOutOfRange);
             return;
         }
 
-        if idx == queue.current_idx as usize {
-            error!(idx, "Cannot remove the currently playing item");
-            self.send_error(origin, ErrorKind::QueueRemovePlayingItem);
+        if from_idx == to_idx {
             return;
         }
 
-        if idx <= queue.current_idx as usize {
-            queue.current_idx = queue.current_idx.saturating_sub(1);
-        }
-
-        queue.items.remove(idx);
+        let item = queue.items.remove(from_idx);
+        queue.items.insert(to_idx, item);
+        queue.order.moved(from_idx, to_idx);
+        queue.current_idx = index_after_queue_move(queue.current_idx, from_idx, to_idx);
 
-        // Indices shifted (and the pre-armed item itself may be gone).
-        self.cancel_gapless_prearm(player::AfterCancel::Nothing);
+        // Keep the pre-arm when its item is still the one autoplay advances to,
+        // only its index moved.
+        let next = self.autoplay_next_index();
+        let prearm = self
+            .gapless_prearm
+            .as_ref()
+            .filter(|prearm| !prearm.cancelling)
+            .map(|prearm| (prearm.next_index, prearm.url.clone()));
+        if let Some((armed_index, url)) = prearm {
+            let moved = index_after_queue_move(armed_index, from_idx, to_idx);
+            match self.prepared_queue_index(moved, &url) {
+                Some(idx) if Some(idx) == next => {
+                    if let Some(prearm) = self.gapless_prearm.as_mut() {
+                        prearm.next_index = idx;
+                    }
+                }
+                _ => self.cancel_gapless_prearm(player::AfterCancel::Nothing),
+            }
+        }
 
         self.relay_to_other_senders(
             origin,
-            fcast_protocol::v4::MessageBuilder::new().queue_remove(position),
+            fcast_protocol::v4::MessageBuilder::new().queue_move(from, to),
         );
 
         self.sync_queue_cache();
@@ -2830,45 +3892,33 @@ impl Application {
             return;
         };
 
-        if queue.items.len() >= u8::MAX as usize + 1 {
+        if queue.items.len() >= fcast::MAX_QUEUE_ITEMS as usize {
             error!("Cannot insert into the queue because it's full");
             self.send_error(origin, ErrorKind::QueueFull);
             return;
         }
 
         let insert = insert.borrow_dependent();
-        let idx = match insert.position_type() {
-            v4::flat::QueuePosition::Back => queue.items.len(),
-            v4::flat::QueuePosition::Front => 0,
-            v4::flat::QueuePosition::Index => {
-                let Some(idx) = insert.position_as_index() else {
-                    error!("Queue insert position is missing its index");
-                    self.send_error(origin, ErrorKind::MalformedBody);
-                    return;
-                };
-
-                idx.index() as usize
-            }
-            _ => {
-                error!(position = ?insert.position_type(), "Invalid queue position");
-                self.send_error(origin, ErrorKind::MalformedBody);
+        let idx = match queue_insert_index(
+            queue.items.len(),
+            insert.position_type(),
+            insert.position_as_index(),
+        ) {
+            Ok(idx) => idx,
+            Err(kind) => {
+                self.send_error(origin, kind);
                 return;
             }
         };
 
-        if queue.items.is_empty() || idx > queue.items.len() {
-            error!(idx, "Invalid index");
-            self.send_error(origin, ErrorKind::QueuePositionOutOfRange);
-            return;
-        }
-
-        if idx <= queue.current_idx as usize {
+        if idx <= queue.current_idx {
             queue.current_idx += 1;
         }
 
         queue
             .items
             .insert(idx, QueueItem::from_flat(&insert.item()));
+        queue.order.inserted(idx, queue.current_idx);
 
         // Indices shifted; the pre-armed item may no longer be the next.
         self.cancel_gapless_prearm(player::AfterCancel::Nothing);
@@ -2882,6 +3932,63 @@ impl Application {
         self.sync_queue_cache();
     }
 
+    /// Insert a `QueueInsertBatch`. The batch is all or nothing: if it doesn't
+    /// fit, the queue is left as it was and the sender gets `QueueFull`.
+    #[tracing::instrument(skip_all)]
+    fn insert_queue_items(&mut self, origin: PacketOrigin, batch: fcast::QueueInsertBatchCell) {
+        let Some(queue) = self.queue_mut() else {
+            error!("Cannot insert into the queue when there's no active queue");
+            self.send_error(origin, ErrorKind::InvalidState);
+            return;
+        };
+
+        let batch = batch.borrow_dependent();
+        let items = batch.items();
+        if queue.items.len() + items.len() > fcast::MAX_QUEUE_ITEMS as usize {
+            error!(
+                len = queue.items.len(),
+                batch = items.len(),
+                "Cannot insert the batch because the queue would overflow"
+            );
+            self.send_error(origin, ErrorKind::QueueFull);
+            return;
+        }
+
+        let idx = match queue_insert_index(
+            queue.items.len(),
+            batch.position_type(),
+            batch.position_as_index(),
+        ) {
+            Ok(idx) => idx,
+            Err(kind) => {
+                self.send_error(origin, kind);
+                return;
+            }
+        };
+
+        if idx <= queue.current_idx {
+            queue.current_idx += items.len();
+        }
+
+        queue.items.splice(
+            idx..idx,
+            items.iter().map(|item| QueueItem::from_flat(&item)),
+        );
+        queue
+            .order
+            .inserted_many(idx, items.len(), queue.current_idx);
+
+        self.cancel_gapless_prearm(player::AfterCancel::Nothing);
+
+        if let Some(relay_msg) =
+            fcast_protocol::v4::MessageBuilder::new().from_queue_insert_batch_stripped(&batch)
+        {
+            self.relay_to_other_senders(origin, relay_msg);
+        }
+
+        self.sync_queue_cache();
+    }
+
     fn pause(&mut self) {
         // A pause landing mid-load is recorded as desired transport and committed at
         // preroll.
@@ -2934,10 +4041,8 @@ impl Application {
                             return;
                         };
                         let items = queue.items();
-                        // The spec caps a queue at 256 items: wire positions are ubytes,
-                        // and the u8 bookkeeping would wrap back to item 0.
-                        if items.len() > u8::MAX as usize + 1 {
-                            error!(len = items.len(), "Queue exceeds the spec's 256 item cap");
+                        if items.len() > fcast::MAX_QUEUE_ITEMS as usize {
+                            error!(len = items.len(), "Queue exceeds the advertised item cap");
                             self.send_error(origin, ErrorKind::MalformedBody);
                             return;
                         }
@@ -2945,16 +4050,22 @@ impl Application {
                         for item in items {
                             queue_items.push(QueueItem::from_flat(&item));
                         }
-                        let idx = queue.start_index().unwrap_or(0);
+                        let idx = v4::read_queue_start_index(&queue).unwrap_or(0) as usize;
+                        let order = if self.queue_mode.shuffle {
+                            PlayOrder::shuffled(queue_items.len(), idx)
+                        } else {
+                            PlayOrder::default()
+                        };
                         self.current_media = Some(MediaSourceState::new(
                             origin,
                             MediaSource::Queue(QueueState {
                                 items: queue_items,
                                 current_idx: idx,
                                 autoplay: queue.autoplay(),
+                                order,
                             }),
                         ));
-                        self.play_queue_item(origin, v4::QueuePosition::Index(idx), false);
+                        self.play_queue_item(origin, v4::QueuePosition::Index(idx as u32), false);
                     }
                     _ => {
                         error!(source_type = ?play.source_type(), "Got play message with invalid source type");
@@ -2962,25 +4073,23 @@ impl Application {
                     }
                 }
 
-                match origin {
-                    PacketOrigin::FCast {
-                        sender_id,
-                        packet_num: _,
-                    } => {
-                        if self.should_broadcast()
-                            && let Some(stripped) =
-                                fcast_protocol::v4::MessageBuilder::new().from_play_stripped(play)
-                        {
-                            debug!("Sending play message to active sesssions");
-                            self.broadcast_update(ReceiverToSenderMessage::V4(
-                                fcast::V4Message::Play {
-                                    initiator_session_id: sender_id,
-                                    serialized_msg: stripped,
-                                },
-                            ));
-                        }
-                    }
-                    _ => (),
+                // Loads from the control API reach senders as if the GUI made them.
+                let initiator = match origin {
+                    PacketOrigin::FCast { sender_id, .. } => Some(sender_id),
+                    PacketOrigin::ControlApi => Some(0),
+                    _ => None,
+                };
+                if let Some(sender_id) = initiator
+                    && self.should_broadcast()
+                    && let Some(stripped) =
+                        fcast_protocol::v4::MessageBuilder::new().from_play_stripped(play)
+                    && stripped.len() <= fcast_protocol::v4::MAX_PACKET_SIZE
+                {
+                    debug!("Sending play message to active sesssions");
+                    self.broadcast_update(ReceiverToSenderMessage::V4(fcast::V4Message::Play {
+                        initiator_session_id: sender_id,
+                        serialized_msg: stripped,
+                    }));
                 }
             }
             fcast::WrappedPlayMessage::Chromecast(_) => {
@@ -3006,12 +4115,21 @@ impl Application {
             }
             Operation::Seek(time) => {
                 if self.is_playing() {
-                    // Range-check first so a park cannot delay the sender's error reply.
-                    let time = self.clamp_seek_target(origin, time);
-                    self.arm_seek_quiet();
-                    // A flushing seek must not reach the pipeline before a pre-arm
-                    // cancellation is confirmed.
-                    self.park_or_apply_gapless_op(GaplessParkedOp::Seek { origin, time });
+                    self.seek_to(origin, time);
+                }
+            }
+            Operation::SeekRelative(offset) => {
+                if self.is_playing() {
+                    match self.relative_seek_target(origin, offset) {
+                        Some(time) => self.seek_to(origin, time),
+                        None => self.send_error(origin, ErrorKind::InvalidState),
+                    }
+                }
+            }
+            Operation::FrameStep { forward } => self.step_frame(origin, forward),
+            Operation::SeekToChapter(target) => {
+                if self.is_playing() {
+                    self.seek_to_chapter(origin, target);
                 }
             }
             Operation::SetSpeed(rate) => {
@@ -3104,11 +4222,16 @@ impl Application {
                     self.change_subtitle_track(origin, id);
                     return Ok(false);
                 }
+                if matches!(typ, v4::flat::MediaTrackType::SecondarySubtitle) {
+                    self.change_secondary_subtitle(origin, id);
+                    return Ok(false);
+                }
 
                 let stream_type = match typ {
                     v4::flat::MediaTrackType::Video => gst::StreamType::VIDEO,
                     v4::flat::MediaTrackType::Audio => gst::StreamType::AUDIO,
-                    v4::flat::MediaTrackType::Subtitle => unreachable!(),
+                    v4::flat::MediaTrackType::Subtitle
+                    | v4::flat::MediaTrackType::SecondarySubtitle => unreachable!(),
                     _ => {
                         error!(?typ, "Unknown track type");
                         self.send_error(origin, ErrorKind::MalformedBody);
@@ -3154,9 +4277,36 @@ impl Application {
             Operation::RemoveQueueItem(position) => {
                 self.remove_queue_item(origin, position);
             }
+            Operation::MoveQueueItem { from, to } => {
+                self.move_queue_item(origin, from, to);
+            }
+            Operation::SetQueueMode(mode) => {
+                self.set_queue_mode(mode);
+            }
+            Operation::SetSleepTimer(setting) => {
+                self.set_sleep_timer(setting);
+            }
+            Operation::SetSubtitleStyle(update) => {
+                self.set_subtitle_style(update);
+            }
+            Operation::SetDelay(update) => {
+                self.set_delay(update);
+            }
+            Operation::GetPlaybackHistory { request_id, limit } => {
+                if let PacketOrigin::FCast { sender_id, .. } = origin
+                    && let Some(handle) = self.fcast_senders.get(&sender_id)
+                {
+                    let _ = handle.msg_tx.send(ReceiverToFCastSender::Reply(
+                        self.playback_history.message(request_id, limit),
+                    ));
+                }
+            }
             Operation::InsertQueueItem(insert) => {
                 self.insert_queue_item(origin, insert);
             }
+            Operation::InsertQueueItems(batch) => {
+                self.insert_queue_items(origin, batch);
+            }
             Operation::SetProgressUpdateInterval(interval) => {
                 if let PacketOrigin::FCast { sender_id, .. } = origin
                     && let Some(handle) = self.fcast_senders.get_mut(&sender_id)
@@ -3214,6 +4364,20 @@ impl Application {
             return Ok(());
         }
 
+        // TODO: Reset QR when there is nothing to advertise
+        if let Some(net_config) = self.network_config() {
+            let ips_string = net_config.addresses.join(", ");
+            debug!(?net_config, "Network config for QR code created");
+            let qrcode = encode_qr(net_config.to_url()?.as_bytes())?;
+            self.gui.set_connection_details(qrcode, ips_string);
+        }
+
+        Ok(())
+    }
+
+    /// What the idle-screen QR code encodes; `None` without a name or a
+    /// routable address.
+    fn network_config(&self) -> Option<fcast_protocol::FCastNetworkConfig> {
         let addrs = self
             .current_addresses
             .iter()
@@ -3229,33 +4393,40 @@ impl Application {
             .collect::<SmallVec<[String; 5]>>();
 
         if addrs.is_empty() {
-            // TODO: Reset QR
-        } else if let Some(device_name) = self.device_name.clone() {
-            let ips_string = addrs.join(", ");
-            let net_config = fcast_protocol::FCastNetworkConfig {
-                name: device_name,
-                addresses: addrs.to_vec(),
-                services: vec![fcast_protocol::FCastService {
-                    port: self.fcast_port,
-                    r#type: 0,
-                }],
-                txt: Some(self.fcast_txt_records.clone()),
-            };
-            debug!(?net_config, "Network config for QR code created");
-            let device_url = net_config.to_url()?;
-            let qrcode = fast_qr::QRBuilder::new(device_url.as_bytes()).build()?;
-            let dims = qrcode.size as u32;
-            let module_count = (dims * dims) as usize;
-            let dark = qrcode.data[0..module_count]
-                .iter()
-                .map(|module| *module != fast_qr::Module::LIGHT)
-                .collect();
-
-            self.gui
-                .set_connection_details(crate::ui_types::QrCode { size: dims, dark }, ips_string);
+            return None;
         }
 
-        Ok(())
+        Some(fcast_protocol::FCastNetworkConfig {
+            name: self.device_name.clone()?,
+            addresses: addrs.to_vec(),
+            services: vec![fcast_protocol::FCastService {
+                port: self.fcast_port,
+                r#type: 0,
+            }],
+            txt: Some(self.fcast_txt_records.clone()),
+        })
+    }
+
+    /// Show the PIN an unpaired sender has to enter. The QR code is the
+    /// connection URL plus a `pin` TXT entry, so a sender that scans it pairs
+    /// without typing. The latest request replaces an earlier one.
+    fn show_pairing_pin(&mut self, sender: SenderId, sender_name: Option<String>, pin: String) {
+        let qr = self.network_config().and_then(|mut net_config| {
+            net_config
+                .txt
+                .get_or_insert_default()
+                .insert("pin".to_owned(), pin.clone());
+            encode_qr(net_config.to_url().ok()?.as_bytes()).ok()
+        });
+        self.pairing_shown_for = Some(sender);
+        self.gui.show_pairing(sender_name, pin, qr);
+    }
+
+    fn hide_pairing_pin(&mut self, sender: SenderId) {
+        if self.pairing_shown_for == Some(sender) {
+            self.pairing_shown_for = None;
+            self.gui.hide_pairing();
+        }
     }
 
     fn on_media_info_updated(&mut self) {
@@ -3466,6 +4637,13 @@ impl Application {
         self.gui.set_source_backoff(0, 0);
     }
 
+    /// Show or drop the "reconnecting" notice; the freeze watchdog stands
+    /// down meanwhile.
+    fn set_source_reconnecting(&mut self, reconnecting: bool) {
+        self.source_reconnecting = reconnecting;
+        self.gui.set_source_reconnecting(reconnecting);
+    }
+
     /// Whether a server-directed backoff deserves the "server busy" countdown:
     /// only when the pipeline is low on buffered media, so the wait may
     /// actually interrupt playback. Live sources at their edge are directed to
@@ -3566,6 +4744,102 @@ impl Application {
         }
     }
 
+    /// `ChangeTrack` for the secondary subtitle track. Only an external file
+    /// can be one (see `secondary_subtitles`), and it is shown once parsed.
+    fn change_secondary_subtitle(&mut self, origin: PacketOrigin, id: Option<u32>) {
+        if self.secondary_cues.is_none() {
+            error!("No video sink to show a secondary subtitle on");
+            self.send_error(origin, ErrorKind::InvalidState);
+            return;
+        }
+        let Some(id) = id else {
+            self.clear_secondary_subtitle();
+            self.secondary_subtitle_changed();
+            return;
+        };
+        if !is_external_track_id(id) {
+            error!(id, "Only an external subtitle can be the secondary track");
+            self.send_error(origin, ErrorKind::UnsupportedFormat);
+            return;
+        }
+        let Some(url) = self
+            .current_media
+            .as_ref()
+            .and_then(|m| m.externals.by_id(id))
+            .map(|s| s.url.clone())
+        else {
+            error!(id, "Unknown secondary subtitle id");
+            self.send_error(origin, ErrorKind::MalformedBody);
+            return;
+        };
+
+        // The old cues stay up until the new ones are ready.
+        debug!(id, url, "Parsing the secondary subtitle");
+        self.secondary_subtitle = Some(id);
+        let item = self.current_media_item_id;
+        let msg_tx = self.msg_tx.clone();
+        tokio::task::spawn_blocking(move || {
+            let cues = secondary_subtitles::parse(&url)
+                .inspect_err(|err| error!(?err, "Failed to parse the secondary subtitle"))
+                .ok();
+            msg_tx.send(Message::SecondarySubtitleParsed {
+                origin,
+                item,
+                id,
+                cues,
+            });
+        });
+    }
+
+    fn secondary_subtitle_parsed(
+        &mut self,
+        origin: PacketOrigin,
+        item: MediaItemId,
+        id: u32,
+        cues: Option<Vec<fcast_video::secondary::TimedCue>>,
+    ) {
+        if item != self.current_media_item_id || self.secondary_subtitle != Some(id) {
+            debug!(id, "Dropping a stale secondary subtitle parse");
+            return;
+        }
+        let Some(cues) = cues else {
+            self.clear_secondary_subtitle();
+            self.secondary_subtitle_changed();
+            self.send_error(origin, ErrorKind::UnsupportedFormat);
+            return;
+        };
+        info!(id, cues = cues.len(), "Showing the secondary subtitle");
+        if let Some(secondary) = self.secondary_cues.as_ref() {
+            secondary.set_cues(cues);
+        }
+        self.secondary_subtitle_changed();
+    }
+
+    /// Take the secondary track down. Senders are not told: every item starts
+    /// without one.
+    fn clear_secondary_subtitle(&mut self) {
+        self.secondary_subtitle = None;
+        if let Some(secondary) = self.secondary_cues.as_ref() {
+            secondary.clear();
+        }
+        self.gui.set_secondary_subtitle_track(-1);
+    }
+
+    fn secondary_subtitle_changed(&mut self) {
+        if let Some(id) = self.secondary_subtitle {
+            self.gui.set_secondary_subtitle_track(id as i32);
+        }
+        if self.updates_tx.strong_count() > 0 {
+            let msg = v4::MessageBuilder::new().change_track(
+                self.secondary_subtitle,
+                v4::flat::MediaTrackType::SecondarySubtitle,
+            );
+            let _ = self.updates_tx.send(Arc::new(ReceiverToSenderMessage::V4(
+                fcast::V4Message::TracksSelected(vec![msg]),
+            )));
+        }
+    }
+
     /// Apply a track change through TrackOps. Whether the switch's re-emit
     /// flush is safe is decided inside the player's pump, off the
     /// pipeline's own input state.
@@ -3639,7 +4913,8 @@ impl Application {
             .map(|m| m.externals.iter().map(|s| (s.id, s.name.clone())).collect())
             .unwrap_or_default();
 
-        if self.should_broadcast() {
+        let broadcast = self.should_broadcast();
+        if broadcast || self.gcast_tx.is_enabled() {
             let mut tracks: Vec<v4::MediaTrack> = self
                 .player
                 .streams
@@ -3691,10 +4966,19 @@ impl Application {
                 });
             }
 
-            let serialized_msg = v4::MessageBuilder::new().tracks_available(tracks.into_iter());
-            self.broadcast_update(ReceiverToSenderMessage::V4(
-                fcast::V4Message::TracksAvailable { serialized_msg },
+            self.gcast_tx.send(gcast::StatusUpdate::Tracks(
+                tracks
+                    .iter()
+                    .filter_map(gcast::MediaTrack::from_v4)
+                    .collect(),
             ));
+
+            if broadcast {
+                let serialized_msg = v4::MessageBuilder::new().tracks_available(tracks.into_iter());
+                self.broadcast_update(ReceiverToSenderMessage::V4(
+                    fcast::V4Message::TracksAvailable { serialized_msg },
+                ));
+            }
         }
 
         let mut videos = Vec::new();
@@ -3722,17 +5006,23 @@ impl Application {
                 });
             }
         }
+        let mut secondary_subtitles = Vec::new();
         for (id, name) in &externals {
-            subtitles.push(UiMediaTrack {
+            let track = UiMediaTrack {
                 id: *id as i32,
                 name: name
                     .as_ref()
                     .map(|n| n.to_string())
                     .unwrap_or_else(|| SmolStr::new_inline("External").to_string()),
-            });
+            };
+            // Only a file can be parsed again for the secondary track.
+            if self.secondary_cues.is_some() {
+                secondary_subtitles.push(track.clone());
+            }
+            subtitles.push(track);
         }
 
-        self.gui.set_tracks(videos, audios, subtitles);
+        self.gui.set_tracks(videos, audios, subtitles, secondary_subtitles);
     }
 
     /// Whether an event must be dropped when its generation is not the current
@@ -3860,6 +5150,16 @@ impl Application {
 
                 self.maybe_autoplay_advance();
             }
+            player::PlayerEvent::Chapters(chapters) => {
+                if self.chapters_from_sender {
+                    debug!(
+                        count = chapters.len(),
+                        "Keeping the sender's chapters over the container's"
+                    );
+                } else if chapters != self.chapters {
+                    self.set_chapters(chapters);
+                }
+            }
             player::PlayerEvent::Tags(tags) => {
                 if let Some(container) = tags.get::<gst::tags::ContainerFormat>() {
                     self.inspector_container = Some(container.get().to_string());
@@ -3907,6 +5207,7 @@ impl Application {
                 {
                     let name = artist.get().to_owned();
                     self.gui.set_artist_name(name.clone());
+                    self.mpris_tx.send(mpris::StatusUpdate::Artist(name.clone()));
                     self.last_artist_name = Some(name);
                 }
             }
@@ -4026,6 +5327,10 @@ impl Application {
 
                 self.gcast_tx
                     .send(gcast::StatusUpdate::PlayerState(self.player.player_state()));
+                self.mpris_tx
+                    .send(mpris::StatusUpdate::PlayerState(self.player.player_state()));
+                self.dlna_tx
+                    .send(dlna::StatusUpdate::PlayerState(self.player.player_state()));
 
                 if (old == gst::State::Ready
                     && current == gst::State::Paused
@@ -4095,6 +5400,12 @@ impl Application {
                     audio_id.map(|i| i as i32).unwrap_or(-1),
                     subtitle_id.map(|i| i as i32).unwrap_or(-1),
                 );
+                self.gcast_tx.send(gcast::StatusUpdate::ActiveTracks(
+                    [video_id, audio_id, subtitle_id]
+                        .into_iter()
+                        .flatten()
+                        .collect(),
+                ));
 
                 if video.is_some() {
                     self.video_stream_available()?;
@@ -4143,9 +5454,7 @@ impl Application {
                     }
                     fcastplaybin::ErrorOrigin::Main | fcastplaybin::ErrorOrigin::Unknown => {
                         self.player.stop();
-                        if let Some(origin) = self.current_media.as_ref().map(|m| m.origin) {
-                            self.send_error(origin, media_error_kind_to_error(kind));
-                        }
+                        self.load_failed(media_error_kind_to_error(kind));
                         let detail = match kind {
                             player::MediaErrorKind::NetworkFailure => failed_uri
                                 .as_deref()
@@ -4230,6 +5539,14 @@ impl Application {
                     });
                 }
             }
+            player::PlayerEvent::SourceReconnecting { reconnecting } => {
+                if reconnecting != self.source_reconnecting {
+                    info!(reconnecting, "Source connection state changed");
+                }
+                // Giving up posts the source's error right behind this, which
+                // takes the ordinary media error path.
+                self.set_source_reconnecting(reconnecting);
+            }
             player::PlayerEvent::ImageStream(info) => {
                 debug!(?info, "Image stream announced by fimagedec");
                 self.inspector_image = format!(
@@ -4471,6 +5788,10 @@ impl Application {
 
                 self.gui.set_app_state(AppState::Playing);
                 self.gui.set_player_type(UiPlayerVariant::Raop);
+                self.mpris_tx
+                    .send(mpris::StatusUpdate::Media(mpris::MediaDescription::default()));
+                self.mpris_tx
+                    .send(mpris::StatusUpdate::PlayerState(PlayerState::Playing));
             }
             Raop::SenderDisconnected => {
                 debug!("Session ended");
@@ -4478,8 +5799,13 @@ impl Application {
                 self.gui.set_app_state(AppState::Idle);
                 self.gui.set_player_type(UiPlayerVariant::Unknown);
                 self.gui.clear_common_playback_state();
+                self.mpris_tx.send(mpris::StatusUpdate::Stopped);
             }
             Raop::CoverArtSet(data) => {
+                if self.mpris_tx.is_enabled() {
+                    self.mpris_tx
+                        .send(mpris::StatusUpdate::CoverArt(data.clone()));
+                }
                 self.current_thumbnail_id += 1;
                 let this_id = self.current_thumbnail_id;
                 self.image_decoder.queue_job(
@@ -4496,21 +5822,31 @@ impl Application {
                     None => error!("Got CoverArtSet event but no media is currently loaded"),
                 }
             }
-            Raop::CoverArtRemoved => self.gui.clear_audio_covers(),
+            Raop::CoverArtRemoved => {
+                self.gui.clear_audio_covers();
+                self.mpris_tx.send(mpris::StatusUpdate::CoverArtRemoved);
+            }
             Raop::MetadataSet(metadata) => {
                 if let Some(title) = metadata.title {
+                    self.mpris_tx.send(mpris::StatusUpdate::Title(title.clone()));
                     self.gui.set_media_title(title);
                 }
                 if let Some(name) = metadata.artist {
+                    self.mpris_tx.send(mpris::StatusUpdate::Artist(name.clone()));
                     self.gui.set_artist_name(name);
                 }
             }
             Raop::ProgressUpdate {
                 position_sec,
                 duration_sec,
-            } => self
-                .gui
-                .update_playback_progress(position_sec as f32, duration_sec as f32),
+            } => {
+                self.mpris_tx
+                    .send(mpris::StatusUpdate::Duration(duration_sec as f64));
+                self.mpris_tx
+                    .send(mpris::StatusUpdate::Position(position_sec as f64));
+                self.gui
+                    .update_playback_progress(position_sec as f32, duration_sec as f32);
+            }
         }
 
         Ok(false)
@@ -4525,6 +5861,30 @@ impl Application {
         )
     }
 
+    /// Keep the progress an AirPlay `/play` sender polls for current. Mirrors
+    /// have none, and media cast from elsewhere isn't the sender's to see.
+    #[cfg(feature = "airplay")]
+    fn sync_airplay_playback(&self, position: f64, duration: f64) {
+        let playing_for_airplay = self.current_media.as_ref().is_some_and(|media| {
+            matches!(media.origin, PacketOrigin::AirPlay)
+                && !matches!(media.source, MediaSource::AirPlayMirror { .. })
+        });
+        if !playing_for_airplay {
+            return;
+        }
+        let rate = if self.player.player_state() == player::PlayerState::Playing {
+            self.player.rate()
+        } else {
+            0.0
+        };
+        self.airplay_context
+            .set_playback(Some(airplay::video::PlaybackInfo {
+                position,
+                duration,
+                rate,
+            }));
+    }
+
     #[cfg(feature = "airplay")]
     fn handle_airplay_event(&mut self, event: AirPlay) -> Result<bool> {
         match event {
@@ -4806,9 +6166,7 @@ impl Application {
                         );
                     }
                     Err(err) => {
-                        if let Some(origin) = self.current_media.as_ref().map(|m| m.origin) {
-                            self.send_error(origin, image_download_error_kind(&err));
-                        }
+                        self.load_failed(image_download_error_kind(&err));
                         self.media_error(
                             player::MediaErrorKind::ImageDownloadFailed,
                             None,
@@ -5175,6 +6533,10 @@ impl Application {
                 debug!(?origin, ?op, "Operation from sender");
                 return self.handle_operation(op, origin);
             }
+            Message::GCastQueueEdit { origin, edit } => {
+                debug!(?origin, ?edit, "Queue edit from Cast sender");
+                return self.handle_gcast_queue_edit(origin, edit);
+            }
             Message::Image(event) => return self.handle_image_event(event),
             Message::QueueCache(event) => self.queue_cache.on_event(event),
             Message::Mdns(event) => {
@@ -5222,6 +6584,16 @@ impl Application {
                 self.gui.update_playlist(start_idx as i32, length as i32);
             }
             Message::MediaItemFinish(id) => {
+                if id == self.current_media_item_id
+                    && self
+                        .sleep_timer
+                        .as_ref()
+                        .is_some_and(SleepTimer::ends_with_item)
+                {
+                    self.sleep_timer_ran_out();
+                    return Ok(false);
+                }
+
                 let Some(media) = &self.current_media else {
                     return Ok(false);
                 };
@@ -5272,6 +6644,16 @@ impl Application {
                 // Same gapless park as the protocol ChangeTrack above.
                 self.park_or_apply_gapless_op(GaplessParkedOp::TrackChange { kind, sid });
             }
+            Message::SelectSecondarySubtitle(id) => {
+                let wire_id = if id >= 0 { Some(id as u32) } else { None };
+                self.change_secondary_subtitle(PacketOrigin::Gui, wire_id);
+            }
+            Message::SecondarySubtitleParsed {
+                origin,
+                item,
+                id,
+                cues,
+            } => self.secondary_subtitle_parsed(origin, item, id, cues),
             Message::NewPlayerEvent { event, generation } => {
                 self.handle_player_event(event, generation)?;
             }
@@ -5298,6 +6680,7 @@ impl Application {
                     self.drop_pending_seek();
                 }
             }
+            Message::SleepTimerTick { epoch } => self.sleep_timer_tick(epoch),
             Message::SeekQuietTimeout { epoch } => {
                 // Still unsettled after the debounce: v4 gets Buffering,
                 // v1-v3 have no such state and stay silent.
@@ -5360,6 +6743,15 @@ impl Application {
             }
             Message::FCastSenderDisconnect(id) => {
                 self.fcast_senders.remove(&id);
+                self.hide_pairing_pin(id);
+            }
+            Message::MetricsSample(reply) => {
+                let _ = reply.send(metrics::PlayerSample {
+                    state: self.player.player_state(),
+                    fcast_sessions: self.fcast_senders.len(),
+                    playbin: self.player.playbin_stats(),
+                    streams: self.player.stream_io_stats(),
+                });
             }
             Message::SetConfigBool { key, value } => {
                 #[cfg(not(target_os = "android"))]
@@ -5370,6 +6762,14 @@ impl Application {
                         .config
                         .update(|config| known = config.set_bool(&key, value));
                     self.report_config_change(&key, known, res);
+                    // Unlike most settings this one applies to the next session.
+                    if key == "fcast.require_pairing" {
+                        self.pairing.set_required(value);
+                    }
+                    if key.starts_with("subtitles.") {
+                        self.subtitles = self.settings.config.get().subtitles.clone();
+                        self.subtitle_style_changed();
+                    }
                 }
                 #[cfg(target_os = "android")]
                 let _ = (key, value);
@@ -5383,10 +6783,58 @@ impl Application {
                         .config
                         .update(|config| known = config.set_string(&key, &value));
                     self.report_config_change(&key, known, res);
+                    if key.starts_with("subtitles.") {
+                        self.subtitles = self.settings.config.get().subtitles.clone();
+                        self.subtitle_style_changed();
+                    }
                 }
                 #[cfg(target_os = "android")]
                 let _ = (key, value);
             }
+            Message::RotateTlsIdentity => {
+                #[cfg(not(target_os = "android"))]
+                self.rotate_tls_identity()?;
+            }
+            Message::PairingRequested {
+                sender,
+                sender_name,
+                pin,
+            } => {
+                info!(sender, ?sender_name, "Sender has to pair");
+                self.show_pairing_pin(sender, sender_name, pin);
+            }
+            Message::SenderIntroduced {
+                sender,
+                sender_name,
+            } => {
+                if let Some(handle) = self.fcast_senders.get_mut(&sender) {
+                    handle.name = sender_name;
+                }
+            }
+            Message::SenderPaired { sender, paired } => {
+                info!(sender, name = ?paired.name, "Sender paired");
+                self.hide_pairing_pin(sender);
+                self.pairing.remember(paired.token_sha256.clone());
+                #[cfg(not(target_os = "android"))]
+                if let Err(err) = self
+                    .settings
+                    .config
+                    .update(|config| config.fcast.paired_senders.push(paired))
+                {
+                    error!(?err, "Failed to persist paired sender");
+                }
+            }
+            Message::ForgetPairedSenders => {
+                self.pairing.forget_all();
+                #[cfg(not(target_os = "android"))]
+                if let Err(err) = self
+                    .settings
+                    .config
+                    .update(|config| config.fcast.paired_senders.clear())
+                {
+                    error!(?err, "Failed to forget paired senders");
+                }
+            }
         }
 
         Ok(false)
@@ -5412,7 +6860,10 @@ impl Application {
     #[cfg(not(target_os = "android"))]
     fn report_config_change(&self, key: &str, known: bool, result: std::io::Result<()>) {
         if !known {
-            warn!(key, "Ignoring unknown setting from the settings UI");
+            warn!(
+                key,
+                "Ignoring an unknown or invalid setting from the settings UI"
+            );
         } else if let Err(err) = result {
             error!(?err, key, "Failed to persist settings change");
         } else {
@@ -5420,8 +6871,31 @@ impl Application {
         }
     }
 
+    /// Swap in a fresh TLS identity. Connected sessions keep the one they
+    /// upgraded with; new sessions, the mDNS record and the QR code get the
+    /// new fingerprint.
+    #[cfg(not(target_os = "android"))]
+    fn rotate_tls_identity(&mut self) -> Result<()> {
+        let identity = TlsIdentity::rotate(self.settings.config.state_dir())?;
+        self.tls_acceptor = identity.acceptor()?;
+        self.fcast_txt_records
+            .insert("fp".to_owned(), identity.fingerprint().to_owned());
+
+        if self.port_committed && self.settings.fcast_enabled() {
+            // Re-registering the same instance name replaces its TXT record.
+            mdns::register_fcast(
+                &self.mdns,
+                &self.settings.fcast_name(),
+                self.fcast_port,
+                &self.fcast_txt_records,
+            )?;
+        }
+        self.update_connection_details()
+    }
+
     fn handle_new_fcast_session(&mut self, stream: tokio::net::TcpStream, session_id: SenderId) {
         debug!("New connection id={session_id}");
+        self.metrics.fcast_session_started();
 
         let (recv_to_f_tx, recv_to_f_rx) = mpsc::unbounded_channel();
         let _ = self
@@ -5442,11 +6916,17 @@ impl Application {
                 Some(InitialV4State {
                     play_data: Arc::clone(play_data),
                     playback_state: self.player.player_state().as_fcast_v4(),
+                    chapters: self.chapters.clone(),
                 })
             } else {
                 None
             };
             let initial_volume = self.player.volume();
+            let initial_queue_mode = self.queue_mode;
+            let initial_sleep_timer = self.sleep_timer_setting();
+            let initial_subtitle_style = self.subtitles.clone();
+            let initial_delays = self.delays;
+            let pairing = Arc::clone(&self.pairing);
             async move {
                 if let Err(err) = SessionDriver::new(
                     stream,
@@ -5457,6 +6937,11 @@ impl Application {
                     receiver_info,
                     initial_v4_state,
                     initial_volume,
+                    initial_queue_mode,
+                    initial_sleep_timer,
+                    initial_subtitle_style,
+                    initial_delays,
+                    pairing,
                 )
                 .run(updates_rx, &msg_tx, comp_rx, recv_to_f_rx)
                 .await
@@ -5588,6 +7073,7 @@ impl Application {
         // Seed the settings drawer with the current persisted config.
         #[cfg(not(target_os = "android"))]
         self.push_settings_to_ui();
+        self.push_history_to_gui();
 
         // `None` means the user quit before anything was bound. When FCast is disabled
         // we commit with no listeners so the loop still serves
@@ -5724,6 +7210,52 @@ impl Application {
 mod tests {
     use super::*;
 
+    #[test]
+    fn chapter_targets_resolve_from_the_position() {
+        let chapters: Vec<v4::Chapter> = [0.0, 60.0, 120.0]
+            .into_iter()
+            .map(|start| v4::Chapter {
+                start,
+                length: None,
+                title: String::new(),
+                skip_label: None,
+            })
+            .collect();
+        let secs = gst::ClockTime::from_seconds;
+        let start = |position, target| chapter_start(&chapters, position, target);
+
+        assert_eq!(start(secs(70), v4::ChapterTarget::Next), Some(secs(120)));
+        assert_eq!(start(secs(130), v4::ChapterTarget::Next), None);
+        // Exactly on a chapter start (a just-settled chapter seek) moves on.
+        assert_eq!(start(secs(60), v4::ChapterTarget::Next), Some(secs(120)));
+
+        // Well into a chapter "previous" restarts it, right after its start it
+        // goes to the one before, and the first chapter only ever restarts.
+        assert_eq!(start(secs(70), v4::ChapterTarget::Previous), Some(secs(60)));
+        assert_eq!(start(secs(61), v4::ChapterTarget::Previous), Some(secs(0)));
+        assert_eq!(start(secs(1), v4::ChapterTarget::Previous), Some(secs(0)));
+
+        assert_eq!(start(secs(0), v4::ChapterTarget::Index(2)), Some(secs(120)));
+        assert_eq!(start(secs(0), v4::ChapterTarget::Index(3)), None);
+        assert_eq!(chapter_start(&[], secs(0), v4::ChapterTarget::Next), None);
+
+        // A sender's list out of order resolves the same.
+        let mut shuffled = chapters.clone();
+        shuffled.swap(0, 2);
+        for (position, target) in [
+            (secs(70), v4::ChapterTarget::Next),
+            (secs(70), v4::ChapterTarget::Previous),
+            (secs(61), v4::ChapterTarget::Previous),
+            (secs(0), v4::ChapterTarget::Index(2)),
+        ] {
+            assert_eq!(
+                chapter_start(&shuffled, position, target),
+                start(position, target),
+                "{target:?} from {position}"
+            );
+        }
+    }
+
     /// The two-masters window: a stale-looking generation matching the
     /// pending pre-arm is the pipeline's future and must be held, never
     /// dropped. Everything else stays a dropped straggler, including a stale
@@ -0,0 +1,115 @@
+//! Per-item subtitle and audio delays. Every item starts from the
+//! `[playback]` defaults; senders shift the one playing with `DelayChanged`,
+//! and the player menu does the same in milliseconds.
+//!
+//! Pure bookkeeping, like [`crate::subtitle_style`]. Shifting cues and
+//! offsetting the audio sink is the player's job.
+
+use fcast_protocol::v4;
+
+use crate::config::PlaybackConfig;
+
+/// Either delay goes no further than this, in microseconds.
+const MAX_DELAY_MICROS: i64 = 10_000_000;
+
+/// A sender's `DelayChanged`, in microseconds. Absent fields keep their
+/// value.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DelayUpdate {
+    pub subtitle_micros: Option<i64>,
+    pub audio_micros: Option<i64>,
+}
+
+/// The delays the item playing uses, in microseconds. Positive is later.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Delays {
+    pub subtitle_micros: i64,
+    pub audio_micros: i64,
+}
+
+impl Delays {
+    /// Where each new item starts.
+    pub fn defaults(config: &PlaybackConfig) -> Self {
+        Self {
+            subtitle_micros: clamp(config.subtitle_delay_ms.saturating_mul(1000)),
+            audio_micros: clamp(config.audio_delay_ms.saturating_mul(1000)),
+        }
+    }
+
+    /// Apply an update, returning whether anything changed.
+    pub fn apply(&mut self, update: DelayUpdate) -> bool {
+        let before = *self;
+        if let Some(micros) = update.subtitle_micros {
+            self.subtitle_micros = clamp(micros);
+        }
+        if let Some(micros) = update.audio_micros {
+            self.audio_micros = clamp(micros);
+        }
+        *self != before
+    }
+
+    pub fn subtitle(&self) -> gst::Signed<gst::ClockTime> {
+        signed(self.subtitle_micros)
+    }
+
+    pub fn audio(&self) -> gst::Signed<gst::ClockTime> {
+        signed(self.audio_micros)
+    }
+
+    /// The `DelayChanged` confirming these delays to senders.
+    pub fn message(&self) -> v4::ConstructedMessage<'static> {
+        v4::MessageBuilder::new().delay_changed(Some(self.subtitle_micros), Some(self.audio_micros))
+    }
+}
+
+fn clamp(micros: i64) -> i64 {
+    micros.clamp(-MAX_DELAY_MICROS, MAX_DELAY_MICROS)
+}
+
+fn signed(micros: i64) -> gst::Signed<gst::ClockTime> {
+    let magnitude = gst::ClockTime::from_useconds(micros.unsigned_abs());
+    if micros < 0 {
+        gst::Signed::Negative(magnitude)
+    } else {
+        gst::Signed::Positive(magnitude)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults_come_from_config_and_clamp() {
+        let config = PlaybackConfig {
+            subtitle_delay_ms: -250,
+            audio_delay_ms: 60_000,
+            ..PlaybackConfig::default()
+        };
+        let delays = Delays::defaults(&config);
+        assert_eq!(delays.subtitle_micros, -250_000);
+        assert_eq!(delays.audio_micros, MAX_DELAY_MICROS);
+        assert_eq!(
+            delays.subtitle(),
+            gst::Signed::Negative(gst::ClockTime::from_mseconds(250))
+        );
+    }
+
+    #[test]
+    fn updates_keep_absent_fields() {
+        let mut delays = Delays {
+            subtitle_micros: 100_000,
+            audio_micros: 0,
+        };
+        assert!(delays.apply(DelayUpdate {
+            audio_micros: Some(i64::MIN),
+            ..DelayUpdate::default()
+        }));
+        assert_eq!(delays.subtitle_micros, 100_000);
+        assert_eq!(delays.audio_micros, -MAX_DELAY_MICROS);
+        assert!(!delays.apply(DelayUpdate {
+            subtitle_micros: Some(100_000),
+            audio_micros: None,
+        }));
+    }
+}
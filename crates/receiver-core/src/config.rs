@@ -22,10 +22,20 @@ pub struct Config {
     pub chromecast: ChromecastConfig,
     /// `[airplay]` the AirPlay screen-mirroring receiver.
     pub airplay: AirplayConfig,
+    /// `[dlna]` the DLNA/UPnP media renderer.
+    pub dlna: DlnaConfig,
+    /// `[control_api]` the local HTTP/WebSocket control API.
+    pub control_api: ControlApiConfig,
+    /// `[metrics]` the Prometheus metrics endpoint.
+    pub metrics: MetricsConfig,
     /// `[interface]` window, tray and player presentation.
     pub interface: InterfaceConfig,
     /// `[video]` video output settings.
     pub video: VideoConfig,
+    /// `[playback]` playback behaviour.
+    pub playback: PlaybackConfig,
+    /// `[subtitles]` subtitle appearance.
+    pub subtitles: SubtitlesConfig,
     /// `[log]` logging settings.
     pub log: LogConfig,
 }
@@ -48,6 +58,13 @@ pub struct FcastConfig {
     /// Broadcast name; `{hostname}` expands. Defaults to `FCast-{hostname}`.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
+    /// Refuse control from v4 senders until they pair with the on-screen PIN,
+    /// and drop v1-v3 senders, which cannot pair.
+    pub require_pairing: bool,
+    /// Senders that paired, remembered by the hash of the token they were
+    /// issued. Managed by the receiver.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub paired_senders: Vec<PairedSender>,
 }
 
 impl Default for FcastConfig {
@@ -55,10 +72,23 @@ impl Default for FcastConfig {
         Self {
             enabled: true,
             name: None,
+            require_pairing: false,
+            paired_senders: Vec::new(),
         }
     }
 }
 
+/// One entry of `[fcast] paired_senders`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PairedSender {
+    /// The display name the sender introduced itself with, for the user's
+    /// benefit only.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Base64 SHA-256 of the pairing token.
+    pub token_sha256: String,
+}
+
 /// `[raop]` the AirPlay audio (RAOP) receiver.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
@@ -68,6 +98,9 @@ pub struct RaopConfig {
     /// Broadcast name; `{hostname}` expands. Defaults to `FCast-{hostname}`.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
+    /// Password senders must enter before streaming; absent lets anyone in.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
 }
 
 impl Default for RaopConfig {
@@ -75,6 +108,7 @@ impl Default for RaopConfig {
         Self {
             enabled: true,
             name: None,
+            password: None,
         }
     }
 }
@@ -100,6 +134,55 @@ impl Default for ChromecastConfig {
     }
 }
 
+/// `[dlna]` the DLNA/UPnP media renderer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DlnaConfig {
+    /// Whether to advertise over SSDP and serve the renderer. Off by default.
+    pub enabled: bool,
+    /// Friendly name control points list; `{hostname}` expands. Defaults to
+    /// `FCast-{hostname}`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+impl Default for DlnaConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            name: None,
+        }
+    }
+}
+
+/// `[control_api]` the local HTTP/WebSocket control API.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ControlApiConfig {
+    /// Whether to serve the API. Off by default.
+    pub enabled: bool,
+    /// Token clients must present. Without one the API only listens on
+    /// loopback, and wants a generated token kept in `control-api-token`
+    /// beside this file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    /// Web page origins (e.g. `http://homeassistant.local:8123`) allowed to
+    /// call the API from a browser. Requests from any other page are refused.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub allowed_origins: Vec<String>,
+}
+
+/// `[metrics]` the Prometheus metrics endpoint.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MetricsConfig {
+    /// Whether to serve `/metrics`. Off by default.
+    pub enabled: bool,
+    /// Listen on every interface rather than loopback only, for a scraper
+    /// elsewhere on the network.
+    pub public: bool,
+}
+
 /// `[airplay]` the AirPlay screen-mirroring receiver.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
@@ -107,11 +190,18 @@ pub struct AirplayConfig {
     /// Whether to advertise and serve AirPlay mirroring (needs the `airplay`
     /// feature).
     pub enabled: bool,
+    /// Password senders must enter before mirroring or casting; absent lets
+    /// anyone in.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
 }
 
 impl Default for AirplayConfig {
     fn default() -> Self {
-        Self { enabled: true }
+        Self {
+            enabled: true,
+            password: None,
+        }
     }
 }
 
@@ -129,6 +219,8 @@ pub struct InterfaceConfig {
     pub fullscreen_player: bool,
     /// Run without a GUI at all.
     pub headless: bool,
+    /// Publish playback to the desktop's media controls over MPRIS (Linux).
+    pub mpris: bool,
     /// How the GUI is scaled: `tv` (the default), `auto`, `desktop`, or a
     /// literal factor like `2.5`. Stored as a string so an unrecognised value
     /// warns instead of discarding the file. See [`crate::ui_scaling`].
@@ -144,6 +236,7 @@ impl Default for InterfaceConfig {
             start_fullscreen: false,
             fullscreen_player: true,
             headless: false,
+            mpris: true,
             ui_scale: None,
         }
     }
@@ -170,6 +263,72 @@ impl Default for VideoConfig {
     }
 }
 
+/// `[playback]` playback behaviour.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PlaybackConfig {
+    /// When a load starts from the position an earlier play was left at:
+    /// `off`, `on-request` (the sender asks for it) or `always`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resume: Option<String>,
+    /// Milliseconds each item's subtitles start out delayed by, negative for
+    /// earlier. Senders and the player menu adjust the item playing.
+    #[serde(skip_serializing_if = "is_zero")]
+    pub subtitle_delay_ms: i64,
+    /// Milliseconds each item's audio starts out delayed by, to make up for a
+    /// lagging output like a Bluetooth speaker.
+    #[serde(skip_serializing_if = "is_zero")]
+    pub audio_delay_ms: i64,
+}
+
+fn is_zero(value: &i64) -> bool {
+    *value == 0
+}
+
+/// `[subtitles]` how subtitles look wherever the subtitle file itself leaves
+/// it open. Applies immediately, also to the item playing.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SubtitlesConfig {
+    /// Text size: `small`, `normal`, `large` or `huge`, or a factor of the
+    /// normal size like `1.3`. Stored as a string so an unrecognised value
+    /// warns instead of discarding the file. See [`crate::subtitle_style`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<String>,
+    /// Height above the bottom edge: `bottom`, `raised` or `high`, or a
+    /// fraction of the picture height like `0.1`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<String>,
+    /// Draw a dark box behind the text.
+    pub background: bool,
+    /// Draw an outline around the letters.
+    pub outline: bool,
+    /// Bold text.
+    pub bold: bool,
+    /// Font family; absent uses the platform's sans-serif.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub font: Option<String>,
+    /// Text size of the secondary subtitle track, shown at the top of the
+    /// picture; the names and factors of `size`. It is otherwise drawn like
+    /// the main track.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secondary_size: Option<String>,
+}
+
+impl Default for SubtitlesConfig {
+    fn default() -> Self {
+        Self {
+            size: None,
+            position: None,
+            background: true,
+            outline: true,
+            bold: true,
+            font: None,
+            secondary_size: None,
+        }
+    }
+}
+
 /// `[log]` logging settings.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(default)]
@@ -185,15 +344,24 @@ impl Config {
     pub fn set_bool(&mut self, key: &str, value: bool) -> bool {
         match key {
             "fcast.enabled" => self.fcast.enabled = value,
+            "fcast.require_pairing" => self.fcast.require_pairing = value,
             "raop.enabled" => self.raop.enabled = value,
             "chromecast.enabled" => self.chromecast.enabled = value,
             "airplay.enabled" => self.airplay.enabled = value,
+            "dlna.enabled" => self.dlna.enabled = value,
+            "control_api.enabled" => self.control_api.enabled = value,
+            "metrics.enabled" => self.metrics.enabled = value,
+            "metrics.public" => self.metrics.public = value,
             "interface.show_window" => self.interface.show_window = value,
             "interface.tray" => self.interface.tray = value,
             "interface.start_fullscreen" => self.interface.start_fullscreen = value,
             "interface.fullscreen_player" => self.interface.fullscreen_player = value,
             "interface.headless" => self.interface.headless = value,
+            "interface.mpris" => self.interface.mpris = value,
             "video.hdr_output" => self.video.hdr_output = value,
+            "subtitles.background" => self.subtitles.background = value,
+            "subtitles.outline" => self.subtitles.outline = value,
+            "subtitles.bold" => self.subtitles.bold = value,
             _ => return false,
         }
         true
@@ -201,7 +369,8 @@ impl Config {
 
     /// Apply a string setting by dotted `section.key`. An empty value clears
     /// it; dropdowns also treat `"Default"` as unset while free-text names
-    /// keep it literal. False for an unknown key.
+    /// keep it literal. False for an unknown key, or a delay that is not a
+    /// whole number of milliseconds.
     pub fn set_string(&mut self, key: &str, value: &str) -> bool {
         let trimmed = value.trim();
         let text = (!trimmed.is_empty()).then(|| trimmed.to_owned());
@@ -211,15 +380,37 @@ impl Config {
             "fcast.name" => self.fcast.name = text,
             "raop.name" => self.raop.name = text,
             "chromecast.name" => self.chromecast.name = text,
+            "dlna.name" => self.dlna.name = text,
             "video.render_profile" => self.video.render_profile = choice,
+            "playback.resume" => self.playback.resume = choice,
+            "playback.subtitle_delay_ms" => match parse_delay_ms(choice) {
+                Some(ms) => self.playback.subtitle_delay_ms = ms,
+                None => return false,
+            },
+            "playback.audio_delay_ms" => match parse_delay_ms(choice) {
+                Some(ms) => self.playback.audio_delay_ms = ms,
+                None => return false,
+            },
             "log.level" => self.log.level = choice,
             "interface.ui_scale" => self.interface.ui_scale = choice,
+            "subtitles.size" => self.subtitles.size = choice,
+            "subtitles.position" => self.subtitles.position = choice,
+            "subtitles.font" => self.subtitles.font = text,
+            "subtitles.secondary_size" => self.subtitles.secondary_size = choice,
             _ => return false,
         }
         true
     }
 }
 
+/// A delay setting: unset is none at all.
+fn parse_delay_ms(value: Option<String>) -> Option<i64> {
+    match value {
+        None => Some(0),
+        Some(value) => value.parse().ok(),
+    }
+}
+
 /// Owns the receiver's persisted [`Config`]. The in-memory copy is the source
 /// of truth for reads; on save it is merged into the parsed on-disk document so
 /// untouched keys, comments and formatting survive round-trips.
@@ -306,6 +497,12 @@ impl ConfigStore {
         self.path.as_deref()
     }
 
+    /// The directory holding the config file, where the receiver's other
+    /// persisted state (e.g. its TLS identity) lives too.
+    pub fn state_dir(&self) -> Option<&Path> {
+        self.path.as_deref().and_then(Path::parent)
+    }
+
     /// Apply an in-memory edit and persist it. The edit always applies; the
     /// `Result` only reflects the disk write, and no writable location is `Ok`.
     #[allow(dead_code)]
@@ -424,15 +621,24 @@ fn apply(doc: &DocumentMut, config: &Config) -> Result<DocumentMut, toml_edit::s
 }
 
 /// The optional settings that can be cleared: exactly the `Option` fields with
-/// `skip_serializing_if` handled by [`Config::set_string`]. Keep both in sync.
+/// `skip_serializing_if` handled by [`Config::set_string`], plus the lists
+/// skipped when empty and the delays skipped at zero. Keep them in sync.
 const CLEARABLE_KEYS: &[&[&str]] = &[
     &["discovery", "exclude_interfaces"],
     &["fcast", "name"],
+    &["fcast", "paired_senders"],
     &["raop", "name"],
     &["chromecast", "name"],
+    &["dlna", "name"],
     &["video", "render_profile"],
+    &["playback", "subtitle_delay_ms"],
+    &["playback", "audio_delay_ms"],
     &["log", "level"],
     &["interface", "ui_scale"],
+    &["subtitles", "size"],
+    &["subtitles", "position"],
+    &["subtitles", "font"],
+    &["subtitles", "secondary_size"],
 ];
 
 /// Remove from `dst` every [`CLEARABLE_KEYS`] path absent from `src`; foreign
@@ -527,12 +733,37 @@ fn replace_preserving_decor(dst: &mut Item, src: &Item) {
 
 /// Write `bytes` to `path` atomically (temp file plus rename), so readers never
 /// observe a partial file.
-fn write_atomic(path: &Path, bytes: &[u8]) -> std::io::Result<()> {
+pub(crate) fn write_atomic(path: &Path, bytes: &[u8]) -> std::io::Result<()> {
+    write_atomic_with(path, bytes, false)
+}
+
+/// [`write_atomic`] for secrets: the file is created readable by its owner
+/// only before anything is written to it, so the secret is never exposed to
+/// whatever the umask allows.
+pub(crate) fn write_atomic_private(path: &Path, bytes: &[u8]) -> std::io::Result<()> {
+    write_atomic_with(path, bytes, true)
+}
+
+fn write_atomic_with(path: &Path, bytes: &[u8], private: bool) -> std::io::Result<()> {
+    use std::io::Write;
+
     if let Some(parent) = path.parent() {
         std::fs::create_dir_all(parent)?;
     }
     let tmp = tmp_path(path);
-    std::fs::write(&tmp, bytes)?;
+    // The mode only applies to a newly created file, so never reuse a temp
+    // file a crashed write left behind.
+    let _ = std::fs::remove_file(&tmp);
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    if private {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    #[cfg(not(unix))]
+    let _ = private;
+    options.open(&tmp)?.write_all(bytes)?;
     match std::fs::rename(&tmp, path) {
         Ok(()) => Ok(()),
         Err(err) => {
@@ -542,6 +773,56 @@ fn write_atomic(path: &Path, bytes: &[u8]) -> std::io::Result<()> {
     }
 }
 
+/// Read a JSON state file kept beside the config, `what` naming it in logs. A
+/// missing file is `None`; so is an unreadable or unusable one, with a warning,
+/// and the caller starts over.
+pub(crate) fn read_json_state<T: serde::de::DeserializeOwned>(
+    path: &Path,
+    what: &str,
+) -> Option<T> {
+    let bytes = match std::fs::read(path) {
+        Ok(bytes) => bytes,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return None,
+        Err(err) => {
+            warn!(?err, ?path, "Failed to read {what}");
+            return None;
+        }
+    };
+    match serde_json::from_slice(&bytes) {
+        Ok(value) => Some(value),
+        Err(err) => {
+            warn!(?err, ?path, "Stored {what} is unusable, starting over");
+            None
+        }
+    }
+}
+
+/// Write a JSON state file beside the config atomically, `what` naming it in
+/// logs. Failures are logged, the state stays in memory.
+pub(crate) fn write_json_state<T: Serialize + ?Sized>(path: &Path, value: &T, what: &str) {
+    let result = serde_json::to_vec(value)
+        .map_err(std::io::Error::other)
+        .and_then(|bytes| write_atomic(path, &bytes));
+    match result {
+        Ok(()) => debug!(?path, "Saved {what}"),
+        Err(err) => warn!(?err, ?path, "Failed to persist {what}"),
+    }
+}
+
+/// A path in the temp directory no other test uses, for tests that persist
+/// state. Nothing is created.
+#[cfg(test)]
+pub(crate) fn unique_temp_path(name: &str) -> PathBuf {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+    let n = COUNTER.fetch_add(1, Ordering::Relaxed);
+    std::env::temp_dir().join(format!(
+        "fcast-receiver-test-{}-{}-{name}",
+        std::process::id(),
+        n
+    ))
+}
+
 fn tmp_path(path: &Path) -> PathBuf {
     let mut name = path
         .file_name()
@@ -588,11 +869,16 @@ mod tests {
         assert!(config.raop.enabled);
         assert!(!config.chromecast.enabled);
         assert!(config.airplay.enabled);
+        assert!(!config.dlna.enabled);
+        assert!(!config.control_api.enabled);
+        assert!(!config.metrics.enabled);
+        assert!(!config.metrics.public);
         assert!(config.interface.show_window);
         assert!(config.interface.tray);
         assert!(!config.interface.start_fullscreen);
         assert!(config.interface.fullscreen_player);
         assert!(!config.interface.headless);
+        assert!(config.interface.mpris);
         assert!(config.video.hdr_output);
     }
 
@@ -655,6 +941,18 @@ mod tests {
         assert!(config.interface.ui_scale.is_none());
     }
 
+    #[test]
+    fn set_string_delays() {
+        let mut config = Config::default();
+
+        assert!(config.set_string("playback.audio_delay_ms", "-120"));
+        assert_eq!(config.playback.audio_delay_ms, -120);
+        assert!(!config.set_string("playback.audio_delay_ms", "soon"));
+        assert_eq!(config.playback.audio_delay_ms, -120);
+        assert!(config.set_string("playback.audio_delay_ms", ""));
+        assert_eq!(config.playback.audio_delay_ms, 0);
+    }
+
     #[test]
     fn unknown_keys_are_ignored() {
         let config =
@@ -755,7 +1053,7 @@ exclude_interfaces = \"old\" # trailing note
 
     #[test]
     fn round_trip_through_disk() {
-        let path = unique_temp_path();
+        let path = unique_temp_path("config.toml");
         let mut store = ConfigStore::open(path.clone());
         store
             .update(|config| {
@@ -776,9 +1074,43 @@ exclude_interfaces = \"old\" # trailing note
         let _ = std::fs::remove_file(&path);
     }
 
+    #[test]
+    fn forgetting_paired_senders_removes_them_from_disk() {
+        let path = unique_temp_path("config.toml");
+        let mut store = ConfigStore::open(path.clone());
+        store
+            .update(|config| {
+                config.fcast.require_pairing = true;
+                config.fcast.paired_senders.push(PairedSender {
+                    name: Some("Phone".to_owned()),
+                    token_sha256: "abc=".to_owned(),
+                });
+            })
+            .expect("persist");
+
+        let mut reloaded = ConfigStore::open(path.clone());
+        assert!(reloaded.get().fcast.require_pairing);
+        assert_eq!(
+            reloaded.get().fcast.paired_senders,
+            vec![PairedSender {
+                name: Some("Phone".to_owned()),
+                token_sha256: "abc=".to_owned(),
+            }]
+        );
+
+        reloaded
+            .update(|config| config.fcast.paired_senders.clear())
+            .expect("persist");
+        let text = std::fs::read_to_string(&path).expect("read");
+        assert!(!text.contains("paired_senders"), "list removed: {text}");
+        assert!(ConfigStore::open(path.clone()).get().fcast.require_pairing);
+
+        let _ = std::fs::remove_file(&path);
+    }
+
     #[test]
     fn malformed_file_falls_back_to_default() {
-        let path = unique_temp_path();
+        let path = unique_temp_path("config.toml");
         std::fs::write(&path, b"this is = = not valid toml\n").expect("write");
 
         // Loading a broken document for editing yields defaults, not a panic.
@@ -788,15 +1120,4 @@ exclude_interfaces = \"old\" # trailing note
 
         let _ = std::fs::remove_file(&path);
     }
-
-    fn unique_temp_path() -> PathBuf {
-        use std::sync::atomic::{AtomicUsize, Ordering};
-        static COUNTER: AtomicUsize = AtomicUsize::new(0);
-        let n = COUNTER.fetch_add(1, Ordering::Relaxed);
-        std::env::temp_dir().join(format!(
-            "fcast-receiver-config-test-{}-{}.toml",
-            std::process::id(),
-            n
-        ))
-    }
 }
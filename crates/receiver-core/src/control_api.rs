@@ -0,0 +1,950 @@
+//! Local control API for home automation and scripts that can't speak the
+//! FCast framing: JSON commands, POSTed to `/api/v1/command` or sent over the
+//! WebSocket at `/api/v1/events`, become the same [`crate::Operation`]s a
+//! sender's packets do, and the WebSocket streams the state changes senders
+//! are sent, as the JSON bodies v3 senders receive.
+//!
+//! Commands look like `{"command": "seek", "time": 30.5}`. Events look like
+//! `{"event": "playbackUpdate", "data": {...}}`, with `playbackUpdate`,
+//! `volumeUpdate`, `playUpdate`, `playbackError` and `event` (media item and
+//! key events) as names.
+//!
+//! Every client presents a token, as `Authorization: Bearer <token>` or, for
+//! WebSocket clients that cannot set headers, a `token` query parameter. With
+//! a token configured the API listens on every interface. Without one it
+//! listens on loopback only and generates a token, kept in `control-api-token`
+//! beside `config.toml`; requests there must also name a loopback `Host`, so a
+//! rebound DNS name doesn't reach it.
+//!
+//! Browsers send any page's requests along, so a request with an `Origin` is
+//! refused unless that origin is allowed in the config, and commands must be
+//! `application/json`, which a page can't POST without asking first.
+
+mod websocket;
+
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use anyhow::Result;
+use fcast_protocol::v4;
+use serde::Deserialize;
+use tokio::{
+    io::AsyncWriteExt,
+    net::{TcpListener, TcpStream},
+    sync::broadcast::{self, error::RecvError},
+};
+use tracing::{debug, info, warn};
+
+use crate::{
+    MessageSender,
+    application::PacketOrigin,
+    fcast::{
+        FlatLoadMessage, QueueInsertCell, QueueMode, ReceiverToSenderMessage, TranslatableMessage,
+        WrappedPlayMessage,
+    },
+    http::{Connection, Request, Response},
+};
+
+pub const CONTROL_API_TCP_PORT: u16 = 46898;
+
+const TOKEN_FILE_NAME: &str = "control-api-token";
+
+/// Who may use the API, from `[control_api]`.
+pub struct Access {
+    /// The configured token; `None` keeps the API on loopback with a
+    /// generated one.
+    pub token: Option<String>,
+    pub allowed_origins: Vec<String>,
+    /// Where the generated token is kept.
+    pub state_dir: Option<PathBuf>,
+}
+
+/// The token persisted in `dir`, or a fresh one persisted there. `None` (no
+/// writable config location) gives one that lasts this launch only.
+fn load_or_create_token(dir: Option<&Path>) -> String {
+    let Some(path) = dir.map(|dir| dir.join(TOKEN_FILE_NAME)) else {
+        warn!("No writable config location, control API token will change on every launch");
+        return crate::pairing::generate_token();
+    };
+    match std::fs::read_to_string(&path) {
+        Ok(token) if !token.trim().is_empty() => return token.trim().to_owned(),
+        Ok(_) => (),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => (),
+        Err(err) => warn!(?err, ?path, "Failed to read the control API token"),
+    }
+    let token = crate::pairing::generate_token();
+    match crate::config::write_atomic_private(&path, token.as_bytes()) {
+        Ok(()) => info!(?path, "Generated a control API token"),
+        Err(err) => warn!(?err, ?path, "Failed to persist the control API token"),
+    }
+    token
+}
+
+/// `localhost`, `127.0.0.1` or `[::1]`, with or without a port.
+fn is_loopback_host(host: &str) -> bool {
+    let name = match host.strip_prefix('[') {
+        Some(bracketed) => bracketed
+            .split_once(']')
+            .map_or(bracketed, |(name, _)| name),
+        None => host.split_once(':').map_or(host, |(name, _)| name),
+    };
+    name.eq_ignore_ascii_case("localhost") || name == "127.0.0.1" || name == "::1"
+}
+
+fn is_json(request: &Request) -> bool {
+    request
+        .header("Content-Type")
+        .and_then(|value| value.split(';').next())
+        .is_some_and(|mime| mime.trim().eq_ignore_ascii_case("application/json"))
+}
+
+/// A media item as commands carry it.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Item {
+    /// The MIME type.
+    container: String,
+    url: String,
+    title: Option<String>,
+    thumbnail_url: Option<String>,
+    /// Where to start, in seconds.
+    time: Option<f64>,
+    volume: Option<f32>,
+    speed: Option<f32>,
+    headers: Option<HashMap<String, String>>,
+    /// Continue from the remembered position.
+    #[serde(default)]
+    resume: bool,
+}
+
+impl From<Item> for v4::MediaItem {
+    fn from(item: Item) -> Self {
+        Self {
+            container: item.container,
+            source_url: item.url,
+            start_time: item.time,
+            volume: item.volume,
+            speed: item.speed,
+            headers: item.headers,
+            title: item.title,
+            thumbnail_url: item.thumbnail_url,
+            metadata: None,
+            extra_metadata: None,
+            resume: item.resume,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct QueueEntry {
+    #[serde(flatten)]
+    item: Item,
+    /// Seconds to play it for; to its end when absent.
+    duration: Option<f64>,
+}
+
+/// An index into the queue, or `"front"` or `"back"`.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Position {
+    Index(u32),
+    Marker(Marker),
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum Marker {
+    Front,
+    Back,
+}
+
+impl From<Position> for v4::QueuePosition {
+    fn from(position: Position) -> Self {
+        match position {
+            Position::Index(index) => Self::Index(index),
+            Position::Marker(Marker::Front) => Self::Front,
+            Position::Marker(Marker::Back) => Self::Back,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum Repeat {
+    Off,
+    One,
+    All,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+enum TrackType {
+    Video,
+    Audio,
+    Subtitle,
+    SecondarySubtitle,
+}
+
+fn default_autoplay() -> bool {
+    true
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(
+    tag = "command",
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase"
+)]
+enum Command {
+    Load(Item),
+    LoadQueue {
+        items: Vec<QueueEntry>,
+        start_index: Option<u32>,
+        #[serde(default = "default_autoplay")]
+        autoplay: bool,
+    },
+    Pause,
+    Resume,
+    Stop,
+    Seek {
+        time: f64,
+    },
+    SetVolume {
+        volume: f32,
+    },
+    SetSpeed {
+        speed: f32,
+    },
+    SelectQueueItem {
+        position: Position,
+    },
+    InsertQueueItem {
+        item: QueueEntry,
+        position: Position,
+    },
+    RemoveQueueItem {
+        position: Position,
+    },
+    MoveQueueItem {
+        from: Position,
+        to: Position,
+    },
+    SetQueueMode {
+        repeat: Repeat,
+        #[serde(default)]
+        shuffle: bool,
+    },
+    ChangeTrack {
+        track_type: TrackType,
+        /// The track to switch to; none turns subtitles off.
+        id: Option<u32>,
+    },
+}
+
+impl Command {
+    fn parse(json: &[u8]) -> Result<Self, String> {
+        serde_json::from_slice(json).map_err(|err| err.to_string())
+    }
+
+    fn into_operation(self) -> Result<crate::Operation, String> {
+        let load = |msg: v4::ConstructedMessage| {
+            FlatLoadMessage::from_packet(msg.to_vec())
+                .map(|load| crate::Operation::PlayNew(WrappedPlayMessage::V4(load)))
+                .map_err(|err| err.to_string())
+        };
+        Ok(match self {
+            Self::Load(item) => load(v4::MessageBuilder::new().load_single(item.into()))?,
+            Self::LoadQueue {
+                items,
+                start_index,
+                autoplay,
+            } => {
+                if items.is_empty() {
+                    return Err("the queue is empty".to_owned());
+                }
+                load(
+                    v4::MessageBuilder::new().load_queue(
+                        items
+                            .into_iter()
+                            .map(|entry| (entry.item.into(), entry.duration)),
+                        start_index,
+                        autoplay,
+                    ),
+                )?
+            }
+            Self::Pause => crate::Operation::Pause,
+            Self::Resume => crate::Operation::Resume,
+            Self::Stop => crate::Operation::Stop,
+            Self::Seek { time } => crate::Operation::Seek(
+                gst::ClockTime::try_from_seconds_f64(time)
+                    .map_err(|_| format!("{time} is not a position"))?,
+            ),
+            Self::SetVolume { volume } => crate::Operation::SetVolume(volume),
+            Self::SetSpeed { speed } => crate::Operation::SetSpeed(speed),
+            Self::SelectQueueItem { position } => {
+                crate::Operation::SelectQueueItem(position.into())
+            }
+            Self::InsertQueueItem { item, position } => {
+                let msg = v4::MessageBuilder::new().queue_insert(
+                    item.item.into(),
+                    item.duration,
+                    position.into(),
+                );
+                crate::Operation::InsertQueueItem(
+                    QueueInsertCell::from_packet(msg.to_vec()).map_err(|err| err.to_string())?,
+                )
+            }
+            Self::RemoveQueueItem { position } => {
+                crate::Operation::RemoveQueueItem(position.into())
+            }
+            Self::MoveQueueItem { from, to } => crate::Operation::MoveQueueItem {
+                from: from.into(),
+                to: to.into(),
+            },
+            Self::SetQueueMode { repeat, shuffle } => crate::Operation::SetQueueMode(QueueMode {
+                repeat: match repeat {
+                    Repeat::Off => v4::flat::RepeatMode::Off,
+                    Repeat::One => v4::flat::RepeatMode::One,
+                    Repeat::All => v4::flat::RepeatMode::All,
+                },
+                shuffle,
+            }),
+            Self::ChangeTrack { track_type, id } => crate::Operation::ChangeTrack {
+                id,
+                typ: match track_type {
+                    TrackType::Video => v4::flat::MediaTrackType::Video,
+                    TrackType::Audio => v4::flat::MediaTrackType::Audio,
+                    TrackType::Subtitle => v4::flat::MediaTrackType::Subtitle,
+                    TrackType::SecondarySubtitle => v4::flat::MediaTrackType::SecondarySubtitle,
+                },
+            },
+        })
+    }
+}
+
+/// The event a sender update becomes, `None` for the ones with no JSON form.
+fn event(msg: &ReceiverToSenderMessage) -> Option<String> {
+    let (name, data) = match msg {
+        ReceiverToSenderMessage::LegacyTranslatable { msg, .. } => match msg {
+            TranslatableMessage::PlaybackUpdate(update) => {
+                ("playbackUpdate", serde_json::to_value(update))
+            }
+            TranslatableMessage::VolumeUpdate(update) => {
+                ("volumeUpdate", serde_json::to_value(update))
+            }
+        },
+        ReceiverToSenderMessage::Error(error) => ("playbackError", serde_json::to_value(error)),
+        ReceiverToSenderMessage::PlayUpdate { msg } => ("playUpdate", serde_json::to_value(msg)),
+        ReceiverToSenderMessage::Event { msg } => ("event", serde_json::to_value(msg)),
+        // The v4 messages repeat the above as flatbuffers, or only concern v4
+        // sessions.
+        ReceiverToSenderMessage::V4(_) => return None,
+    };
+    let data = data.ok()?;
+    Some(serde_json::json!({ "event": name, "data": data }).to_string())
+}
+
+fn json_error(protocol: &str, status: u16, reason: &str, message: &str) -> Response {
+    Response::new(protocol, status, reason).body(
+        "application/json",
+        serde_json::json!({ "error": message })
+            .to_string()
+            .into_bytes(),
+    )
+}
+
+#[derive(Clone)]
+struct Context {
+    msg_tx: MessageSender,
+    updates_tx: broadcast::Sender<Arc<ReceiverToSenderMessage>>,
+    /// The token, hashed like pairing tokens so checking a guess doesn't leak
+    /// how much of it was right through timing.
+    token_hash: String,
+    /// Listening on every interface, so any `Host` is expected.
+    public: bool,
+    allowed_origins: Arc<[String]>,
+}
+
+impl Context {
+    fn new(
+        msg_tx: MessageSender,
+        updates_tx: broadcast::Sender<Arc<ReceiverToSenderMessage>>,
+        token: &str,
+        public: bool,
+        allowed_origins: Vec<String>,
+    ) -> Self {
+        Self {
+            msg_tx,
+            updates_tx,
+            token_hash: crate::pairing::hash_token(token),
+            public,
+            allowed_origins: allowed_origins.into(),
+        }
+    }
+
+    /// Why a request from a browser page, or one that reached a loopback
+    /// listener under another name, is turned away.
+    fn refused(&self, request: &Request) -> Option<&'static str> {
+        if let Some(origin) = request.header("Origin")
+            && !self
+                .allowed_origins
+                .iter()
+                .any(|allowed| allowed.eq_ignore_ascii_case(origin))
+        {
+            return Some("origin not allowed");
+        }
+        if !self.public && !request.header("Host").is_some_and(is_loopback_host) {
+            return Some("host not allowed");
+        }
+        None
+    }
+
+    fn authorized(&self, request: &Request) -> bool {
+        let bearer = request
+            .header("Authorization")
+            .and_then(|value| value.strip_prefix("Bearer "));
+        let query = request
+            .url
+            .split_once('?')
+            .and_then(|(_, query)| {
+                url::form_urlencoded::parse(query.as_bytes()).find(|(name, _)| name == "token")
+            })
+            .map(|(_, token)| token.into_owned());
+        let given = bearer.map(str::to_owned).or(query);
+        given.is_some_and(|token| crate::pairing::hash_token(&token) == self.token_hash)
+    }
+
+    fn command(&self, json: &[u8]) -> Result<(), String> {
+        let op = Command::parse(json)?.into_operation()?;
+        self.msg_tx.operation(PacketOrigin::ControlApi, op);
+        Ok(())
+    }
+
+    async fn serve(&self, stream: TcpStream) -> Result<()> {
+        let mut connection = Connection::new(stream);
+        while let Some(request) = connection.read_request().await? {
+            let protocol = request.protocol.clone();
+            if let Some(reason) = self.refused(&request) {
+                connection
+                    .write_response(&json_error(&protocol, 403, "Forbidden", reason))
+                    .await?;
+                continue;
+            }
+            if !self.authorized(&request) {
+                let response = json_error(&protocol, 401, "Unauthorized", "missing or wrong token")
+                    .header("WWW-Authenticate", "Bearer");
+                connection.write_response(&response).await?;
+                continue;
+            }
+            let response = match (request.method.as_str(), request.path()) {
+                ("POST", "/api/v1/command") if !is_json(&request) => json_error(
+                    &protocol,
+                    415,
+                    "Unsupported Media Type",
+                    "expected application/json",
+                ),
+                ("POST", "/api/v1/command") => match self.command(&request.body) {
+                    Ok(()) => Response::new(&protocol, 204, "No Content"),
+                    Err(err) => json_error(&protocol, 400, "Bad Request", &err),
+                },
+                ("GET", "/api/v1/events") => {
+                    let key = request
+                        .header("Upgrade")
+                        .filter(|upgrade| upgrade.eq_ignore_ascii_case("websocket"))
+                        .and_then(|_| request.header("Sec-WebSocket-Key"));
+                    let Some(key) = key else {
+                        let response =
+                            json_error(&protocol, 426, "Upgrade Required", "expected a WebSocket");
+                        connection.write_response(&response).await?;
+                        continue;
+                    };
+                    let accept = websocket::accept_key(key);
+                    // Subscribed before the upgrade goes out, so no update the
+                    // client could expect is missed.
+                    let updates_rx = self.updates_tx.subscribe();
+                    let (mut stream, buffer) = connection.into_parts();
+                    stream
+                        .write_all(
+                            format!(
+                                "HTTP/1.1 101 Switching Protocols\r\n\
+                                 Upgrade: websocket\r\n\
+                                 Connection: Upgrade\r\n\
+                                 Sec-WebSocket-Accept: {accept}\r\n\r\n"
+                            )
+                            .as_bytes(),
+                        )
+                        .await?;
+                    return self
+                        .serve_websocket(websocket::WebSocket::new(stream, buffer), updates_rx)
+                        .await;
+                }
+                (_, "/api/v1/command" | "/api/v1/events") => {
+                    json_error(&protocol, 405, "Method Not Allowed", "wrong method")
+                }
+                _ => json_error(&protocol, 404, "Not Found", "no such endpoint"),
+            };
+            connection.write_response(&response).await?;
+        }
+        Ok(())
+    }
+
+    async fn serve_websocket(
+        &self,
+        mut ws: websocket::WebSocket,
+        mut updates_rx: broadcast::Receiver<Arc<ReceiverToSenderMessage>>,
+    ) -> Result<()> {
+        loop {
+            while let Some(message) = ws.next_message().await? {
+                match message {
+                    websocket::Message::Text(text) => {
+                        if let Err(err) = self.command(text.as_bytes()) {
+                            ws.write_text(&serde_json::json!({ "error": err }).to_string())
+                                .await?;
+                        }
+                    }
+                    websocket::Message::Close => return Ok(()),
+                }
+            }
+            tokio::select! {
+                res = ws.read_more() => {
+                    if !res? {
+                        return Ok(());
+                    }
+                }
+                update = updates_rx.recv() => match update {
+                    Ok(update) => {
+                        if let Some(event) = event(&update) {
+                            ws.write_text(&event).await?;
+                        }
+                    }
+                    // The next update carries the current state anyway.
+                    Err(RecvError::Lagged(skipped)) => {
+                        debug!(skipped, "Control API client fell behind");
+                    }
+                    Err(RecvError::Closed) => return Ok(()),
+                },
+            }
+        }
+    }
+}
+
+pub async fn run_server(
+    msg_tx: MessageSender,
+    updates_tx: broadcast::Sender<Arc<ReceiverToSenderMessage>>,
+    access: Access,
+) -> Result<()> {
+    let public = access.token.is_some();
+    let ip = if public {
+        std::net::Ipv4Addr::UNSPECIFIED
+    } else {
+        std::net::Ipv4Addr::LOCALHOST
+    };
+    let token = access
+        .token
+        .unwrap_or_else(|| load_or_create_token(access.state_dir.as_deref()));
+    let listener = TcpListener::bind((ip, CONTROL_API_TCP_PORT)).await?;
+    let context = Context::new(msg_tx, updates_tx, &token, public, access.allowed_origins);
+
+    loop {
+        // A failed accept is per-connection and must not end the API.
+        let (stream, _addr) = match listener.accept().await {
+            Ok(accepted) => accepted,
+            Err(err) => {
+                warn!(?err, "Failed to accept a control API connection");
+                tokio::time::sleep(crate::application::ACCEPT_ERROR_BACKOFF).await;
+                continue;
+            }
+        };
+        let context = context.clone();
+        tokio::spawn(async move {
+            if let Err(err) = context.serve(stream).await {
+                debug!(?err, "Control API connection ended with error");
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use tokio::io::AsyncReadExt;
+
+    use super::*;
+    use crate::message::Message;
+
+    fn operation(json: &str) -> crate::Operation {
+        Command::parse(json.as_bytes())
+            .and_then(Command::into_operation)
+            .unwrap()
+    }
+
+    struct Client {
+        updates_tx: broadcast::Sender<Arc<ReceiverToSenderMessage>>,
+        stream: TcpStream,
+        msg_rx: tokio::sync::mpsc::UnboundedReceiver<Message>,
+    }
+
+    const ALLOWED_ORIGIN: &str = "http://homeassistant.local:8123";
+
+    impl Client {
+        /// A client of an API that listens on every interface when `public`.
+        async fn connect(token: &str, public: bool) -> Self {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let addr = listener.local_addr().unwrap();
+            let (client, accepted) = tokio::join!(TcpStream::connect(addr), listener.accept());
+            let (server, _) = accepted.unwrap();
+            let (msg_tx, msg_rx) = tokio::sync::mpsc::unbounded_channel();
+            let (updates_tx, _) = broadcast::channel(10);
+            let context = Context::new(
+                MessageSender::new(msg_tx),
+                updates_tx.clone(),
+                token,
+                public,
+                vec![ALLOWED_ORIGIN.to_owned()],
+            );
+            tokio::spawn(async move { context.serve(server).await });
+            Self {
+                updates_tx,
+                stream: client.unwrap(),
+                msg_rx,
+            }
+        }
+
+        async fn read(&mut self, done: impl Fn(&[u8]) -> bool) -> Vec<u8> {
+            let read = async {
+                let mut buf = Vec::new();
+                while !done(&buf) {
+                    let mut chunk = [0u8; 4096];
+                    let n = self.stream.read(&mut chunk).await.unwrap();
+                    assert_ne!(n, 0, "connection closed early");
+                    buf.extend_from_slice(&chunk[..n]);
+                }
+                buf
+            };
+            tokio::time::timeout(Duration::from_secs(5), read)
+                .await
+                .expect("timed out waiting for the server")
+        }
+
+        /// Send a request and return the response's status and body.
+        async fn request(&mut self, raw: String) -> (u16, String) {
+            self.stream.write_all(raw.as_bytes()).await.unwrap();
+            let response = self
+                .read(|buf| {
+                    let text = String::from_utf8_lossy(buf);
+                    let Some((head, body)) = text.split_once("\r\n\r\n") else {
+                        return false;
+                    };
+                    let length: usize = head
+                        .lines()
+                        .find_map(|line| line.strip_prefix("Content-Length: "))
+                        .map_or(0, |length| length.parse().unwrap());
+                    body.len() >= length
+                })
+                .await;
+            let text = String::from_utf8(response).unwrap();
+            let (head, body) = text.split_once("\r\n\r\n").unwrap();
+            (head[9..12].parse().unwrap(), body.to_owned())
+        }
+
+        /// POST a command as a script on the same machine would.
+        async fn post(&mut self, headers: &str, json: &str) -> (u16, String) {
+            self.request(format!(
+                "POST /api/v1/command HTTP/1.1\r\n\
+                 Host: 127.0.0.1:46898\r\n\
+                 Content-Type: application/json\r\n\
+                 {headers}Content-Length: {}\r\n\r\n{json}",
+                json.len()
+            ))
+            .await
+        }
+
+        /// Read one unmasked text frame from the server.
+        async fn read_text_frame(&mut self) -> String {
+            let frame = self
+                .read(|buf| buf.len() >= 2 && buf.len() >= 2 + (buf[1] & 0x7F) as usize)
+                .await;
+            assert_eq!(frame[0], 0x81, "expected a final text frame");
+            String::from_utf8(frame[2..].to_vec()).unwrap()
+        }
+
+        async fn next_operation(&mut self) -> crate::Operation {
+            loop {
+                let msg = tokio::time::timeout(Duration::from_secs(5), self.msg_rx.recv())
+                    .await
+                    .expect("timed out waiting for an operation")
+                    .unwrap();
+                if let Message::Op { op, .. } = msg {
+                    return op;
+                }
+            }
+        }
+
+        fn operations(&mut self) -> Vec<crate::Operation> {
+            std::iter::from_fn(|| self.msg_rx.try_recv().ok())
+                .filter_map(|msg| match msg {
+                    Message::Op { op, .. } => Some(op),
+                    _ => None,
+                })
+                .collect()
+        }
+    }
+
+    #[test]
+    fn commands_map_onto_operations() {
+        assert_eq!(
+            operation(r#"{"command": "seek", "time": 90.5}"#),
+            crate::Operation::Seek(gst::ClockTime::from_mseconds(90_500))
+        );
+        assert_eq!(
+            operation(r#"{"command": "setVolume", "volume": 0.25}"#),
+            crate::Operation::SetVolume(0.25)
+        );
+        assert_eq!(
+            operation(r#"{"command": "pause"}"#),
+            crate::Operation::Pause
+        );
+        assert_eq!(
+            operation(r#"{"command": "selectQueueItem", "position": 3}"#),
+            crate::Operation::SelectQueueItem(v4::QueuePosition::Index(3))
+        );
+        assert_eq!(
+            operation(r#"{"command": "moveQueueItem", "from": "front", "to": "back"}"#),
+            crate::Operation::MoveQueueItem {
+                from: v4::QueuePosition::Front,
+                to: v4::QueuePosition::Back,
+            }
+        );
+        assert_eq!(
+            operation(r#"{"command": "setQueueMode", "repeat": "all"}"#),
+            crate::Operation::SetQueueMode(QueueMode {
+                repeat: v4::flat::RepeatMode::All,
+                shuffle: false,
+            })
+        );
+        assert_eq!(
+            operation(r#"{"command": "changeTrack", "trackType": "subtitle", "id": null}"#),
+            crate::Operation::ChangeTrack {
+                id: None,
+                typ: v4::flat::MediaTrackType::Subtitle,
+            }
+        );
+    }
+
+    #[test]
+    fn loads_become_v4_loads() {
+        let crate::Operation::PlayNew(WrappedPlayMessage::V4(load)) = operation(
+            r#"{"command": "load", "container": "video/mp4", "url": "http://nas/a.mp4",
+                "title": "A", "time": 12}"#,
+        ) else {
+            panic!("expected a load");
+        };
+        let single = load.borrow_dependent().source_as_single().unwrap();
+        assert_eq!(single.source_url(), "http://nas/a.mp4");
+        assert_eq!(single.container(), "video/mp4");
+        assert_eq!(single.title(), Some("A"));
+
+        let crate::Operation::PlayNew(WrappedPlayMessage::V4(load)) = operation(
+            r#"{"command": "loadQueue", "startIndex": 1, "items": [
+                {"container": "audio/mpeg", "url": "http://nas/1.mp3"},
+                {"container": "audio/mpeg", "url": "http://nas/2.mp3", "duration": 30}
+            ]}"#,
+        ) else {
+            panic!("expected a load");
+        };
+        let queue = load.borrow_dependent().source_as_queue().unwrap();
+        assert_eq!(queue.items().unwrap().len(), 2);
+        assert_eq!(v4::read_queue_start_index(&queue), Some(1));
+        assert!(queue.autoplay());
+
+        let Err(err) = Command::parse(br#"{"command": "loadQueue", "items": []}"#)
+            .map(Command::into_operation)
+        else {
+            panic!("the command should parse");
+        };
+        assert_eq!(err, "the queue is empty");
+    }
+
+    #[test]
+    fn only_json_bodies_become_events() {
+        let volume = ReceiverToSenderMessage::LegacyTranslatable {
+            op: fcast_protocol::Opcode::VolumeUpdate,
+            msg: TranslatableMessage::VolumeUpdate(fcast_protocol::v3::VolumeUpdateMessage {
+                generation_time: 7,
+                volume: 0.5,
+            }),
+        };
+        let event: serde_json::Value = serde_json::from_str(&event(&volume).unwrap()).unwrap();
+        assert_eq!(
+            event,
+            serde_json::json!({
+                "event": "volumeUpdate",
+                "data": { "generationTime": 7, "volume": 0.5 },
+            })
+        );
+        assert_eq!(
+            super::event(&ReceiverToSenderMessage::V4(
+                crate::fcast::V4Message::VolumeChanged(0.5)
+            )),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn commands_need_the_token_when_one_is_set() {
+        let mut client = Client::connect("s3cret", true).await;
+        let pause = r#"{"command": "pause"}"#;
+
+        let (status, _) = client.post("", pause).await;
+        assert_eq!(status, 401);
+        let (status, _) = client.post("Authorization: Bearer wrong\r\n", pause).await;
+        assert_eq!(status, 401);
+        assert!(client.operations().is_empty());
+
+        let (status, _) = client.post("Authorization: Bearer s3cret\r\n", pause).await;
+        assert_eq!(status, 204);
+        assert_eq!(client.operations(), [crate::Operation::Pause]);
+
+        let (status, body) = client
+            .post(
+                "Authorization: Bearer s3cret\r\n",
+                r#"{"command": "explode"}"#,
+            )
+            .await;
+        assert_eq!(status, 400);
+        assert!(body.contains("error"), "{body}");
+    }
+
+    #[tokio::test]
+    async fn the_websocket_takes_commands_and_streams_events() {
+        let mut client = Client::connect("s3cret", true).await;
+        let (status, _) = client
+            .request(
+                "GET /api/v1/events?token=s3cret HTTP/1.1\r\n\
+                 Host: 192.168.1.20:46898\r\n\
+                 Upgrade: websocket\r\n\
+                 Connection: Upgrade\r\n\
+                 Sec-WebSocket-Key: dGhlIHNhbXBsZSBub25jZQ==\r\n\
+                 Sec-WebSocket-Version: 13\r\n\r\n"
+                    .to_owned(),
+            )
+            .await;
+        assert_eq!(status, 101);
+
+        client
+            .stream
+            .write_all(&websocket::client_text_frame(
+                br#"{"command": "setSpeed", "speed": 1.5}"#,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(
+            client.next_operation().await,
+            crate::Operation::SetSpeed(1.5)
+        );
+
+        client
+            .updates_tx
+            .send(Arc::new(ReceiverToSenderMessage::Error(
+                fcast_protocol::PlaybackErrorMessage {
+                    message: "gone".to_owned(),
+                },
+            )))
+            .unwrap();
+        let event: serde_json::Value =
+            serde_json::from_str(&client.read_text_frame().await).unwrap();
+        assert_eq!(event["event"], "playbackError");
+        assert_eq!(event["data"]["message"], "gone");
+    }
+
+    #[tokio::test]
+    async fn browser_pages_and_rebound_names_are_refused() {
+        let mut client = Client::connect("s3cret", false).await;
+        let bearer = "Authorization: Bearer s3cret\r\n";
+        let pause = r#"{"command": "pause"}"#;
+
+        let (status, body) = client
+            .post(&format!("{bearer}Origin: http://evil.example\r\n"), pause)
+            .await;
+        assert_eq!(status, 403, "{body}");
+        let (status, _) = client
+            .post(&format!("{bearer}Origin: {ALLOWED_ORIGIN}\r\n"), pause)
+            .await;
+        assert_eq!(status, 204);
+
+        // The simple request a page can send without a preflight.
+        let (status, _) = client
+            .request(format!(
+                "POST /api/v1/command HTTP/1.1\r\n\
+                 Host: localhost:46898\r\n\
+                 Content-Type: text/plain\r\n\
+                 {bearer}Content-Length: {}\r\n\r\n{pause}",
+                pause.len()
+            ))
+            .await;
+        assert_eq!(status, 415);
+
+        // A name rebound to 127.0.0.1 still says so in `Host`.
+        let (status, _) = client
+            .request(format!(
+                "POST /api/v1/command HTTP/1.1\r\n\
+                 Host: evil.example:46898\r\n\
+                 Content-Type: application/json\r\n\
+                 {bearer}Content-Length: {}\r\n\r\n{pause}",
+                pause.len()
+            ))
+            .await;
+        assert_eq!(status, 403);
+
+        // Cross-site WebSocket hijacking: the token in the URL is no help.
+        let (status, _) = client
+            .request(
+                "GET /api/v1/events?token=s3cret HTTP/1.1\r\n\
+                 Host: [::1]:46898\r\n\
+                 Origin: http://evil.example\r\n\
+                 Upgrade: websocket\r\n\
+                 Connection: Upgrade\r\n\
+                 Sec-WebSocket-Key: dGhlIHNhbXBsZSBub25jZQ==\r\n\
+                 Sec-WebSocket-Version: 13\r\n\r\n"
+                    .to_owned(),
+            )
+            .await;
+        assert_eq!(status, 403);
+        assert_eq!(client.operations(), [crate::Operation::Pause]);
+    }
+
+    #[test]
+    fn loopback_hosts_with_and_without_ports() {
+        for host in [
+            "localhost",
+            "LOCALHOST:46898",
+            "127.0.0.1:46898",
+            "[::1]",
+            "[::1]:46898",
+        ] {
+            assert!(is_loopback_host(host), "{host}");
+        }
+        for host in [
+            "127.0.0.1.evil.example",
+            "localhost.evil.example:46898",
+            "[::2]",
+            "",
+        ] {
+            assert!(!is_loopback_host(host), "{host}");
+        }
+    }
+
+    #[test]
+    fn the_generated_token_is_kept() {
+        let dir = crate::config::unique_temp_path("control-api");
+        let token = load_or_create_token(Some(&dir));
+        assert_eq!(load_or_create_token(Some(&dir)), token);
+        assert_ne!(load_or_create_token(None), token);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
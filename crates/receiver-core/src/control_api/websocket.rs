@@ -0,0 +1,248 @@
+//! The server side of RFC 6455, as much as the control API needs: the
+//! upgrade handshake and unfragmented or fragmented text frames in, text
+//! frames out, with pings answered and closes echoed.
+
+use anyhow::{Result, bail};
+use base64::Engine;
+use bytes::{Buf, BytesMut};
+use sha1::{Digest, Sha1};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpStream,
+};
+
+const ACCEPT_GUID: &str = "258EAFA5-E914-47DA-95CA-C5AB0DC85B11";
+/// Commands are a few hundred bytes; anything this big is not a client of
+/// ours.
+const MAX_MESSAGE_LEN: usize = 1 << 20;
+
+const OPCODE_CONTINUATION: u8 = 0x0;
+const OPCODE_TEXT: u8 = 0x1;
+const OPCODE_BINARY: u8 = 0x2;
+const OPCODE_CLOSE: u8 = 0x8;
+const OPCODE_PING: u8 = 0x9;
+const OPCODE_PONG: u8 = 0xA;
+
+/// The `Sec-WebSocket-Accept` answer to a `Sec-WebSocket-Key`.
+pub fn accept_key(key: &str) -> String {
+    let mut hasher = Sha1::new();
+    hasher.update(key.trim().as_bytes());
+    hasher.update(ACCEPT_GUID.as_bytes());
+    base64::engine::general_purpose::STANDARD.encode(hasher.finalize())
+}
+
+#[derive(Debug, PartialEq)]
+struct Frame {
+    fin: bool,
+    opcode: u8,
+    payload: Vec<u8>,
+}
+
+/// Parse one frame off the front of `buf`, `None` until it is complete.
+fn parse_frame(buf: &mut BytesMut) -> Result<Option<Frame>> {
+    if buf.len() < 2 {
+        return Ok(None);
+    }
+    let fin = buf[0] & 0x80 != 0;
+    let opcode = buf[0] & 0x0F;
+    let masked = buf[1] & 0x80 != 0;
+    if !masked {
+        bail!("client frames must be masked");
+    }
+    let (len, mut offset) = match buf[1] & 0x7F {
+        126 => {
+            if buf.len() < 4 {
+                return Ok(None);
+            }
+            (u16::from_be_bytes([buf[2], buf[3]]) as usize, 4)
+        }
+        127 => {
+            if buf.len() < 10 {
+                return Ok(None);
+            }
+            let mut len = [0u8; 8];
+            len.copy_from_slice(&buf[2..10]);
+            (usize::try_from(u64::from_be_bytes(len))?, 10)
+        }
+        len => (len as usize, 2),
+    };
+    if len > MAX_MESSAGE_LEN {
+        bail!("frame of {len} bytes is too large");
+    }
+    if buf.len() < offset + 4 + len {
+        return Ok(None);
+    }
+    let mut mask = [0u8; 4];
+    mask.copy_from_slice(&buf[offset..offset + 4]);
+    offset += 4;
+    let payload = buf[offset..offset + len]
+        .iter()
+        .enumerate()
+        .map(|(i, byte)| byte ^ mask[i % 4])
+        .collect();
+    buf.advance(offset + len);
+    Ok(Some(Frame {
+        fin,
+        opcode,
+        payload,
+    }))
+}
+
+fn encode_frame(opcode: u8, payload: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(payload.len() + 10);
+    out.push(0x80 | opcode);
+    match payload.len() {
+        len @ 0..=125 => out.push(len as u8),
+        len @ 126..=0xFFFF => {
+            out.push(126);
+            out.extend_from_slice(&(len as u16).to_be_bytes());
+        }
+        len => {
+            out.push(127);
+            out.extend_from_slice(&(len as u64).to_be_bytes());
+        }
+    }
+    out.extend_from_slice(payload);
+    out
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Message {
+    Text(String),
+    /// The client closed the connection; the close was echoed.
+    Close,
+}
+
+/// An upgraded connection.
+pub struct WebSocket {
+    stream: TcpStream,
+    buffer: BytesMut,
+    /// A text message being reassembled from continuation frames.
+    partial: Option<Vec<u8>>,
+}
+
+impl WebSocket {
+    /// Take over `stream` after the `101` went out, `buffer` holding what the
+    /// client sent past its upgrade request.
+    pub fn new(stream: TcpStream, buffer: BytesMut) -> Self {
+        Self {
+            stream,
+            buffer,
+            partial: None,
+        }
+    }
+
+    /// Read more of what the client sent, false once it hung up. Cancel safe,
+    /// so it can race the events in a `select!`.
+    pub async fn read_more(&mut self) -> Result<bool> {
+        Ok(self.stream.read_buf(&mut self.buffer).await? != 0)
+    }
+
+    /// The next message among what was read so far, answering pings on the
+    /// way. `None` until one is complete.
+    pub async fn next_message(&mut self) -> Result<Option<Message>> {
+        while let Some(frame) = parse_frame(&mut self.buffer)? {
+            match frame.opcode {
+                OPCODE_PING => self.write_frame(OPCODE_PONG, &frame.payload).await?,
+                OPCODE_PONG => (),
+                OPCODE_CLOSE => {
+                    // Echo the status code back, as the closing handshake asks.
+                    let code = frame.payload.get(..2).unwrap_or_default();
+                    self.write_frame(OPCODE_CLOSE, code).await?;
+                    return Ok(Some(Message::Close));
+                }
+                OPCODE_TEXT | OPCODE_BINARY if self.partial.is_none() => {
+                    if frame.fin {
+                        return Ok(Some(Message::Text(String::from_utf8(frame.payload)?)));
+                    }
+                    self.partial = Some(frame.payload);
+                }
+                OPCODE_CONTINUATION if self.partial.is_some() => {
+                    let Some(partial) = self.partial.as_mut() else {
+                        continue;
+                    };
+                    partial.extend_from_slice(&frame.payload);
+                    if partial.len() > MAX_MESSAGE_LEN {
+                        bail!("message is too large");
+                    }
+                    if frame.fin
+                        && let Some(message) = self.partial.take()
+                    {
+                        return Ok(Some(Message::Text(String::from_utf8(message)?)));
+                    }
+                }
+                opcode => bail!("unexpected opcode {opcode:#x}"),
+            }
+        }
+        Ok(None)
+    }
+
+    pub async fn write_text(&mut self, text: &str) -> Result<()> {
+        self.write_frame(OPCODE_TEXT, text.as_bytes()).await
+    }
+
+    async fn write_frame(&mut self, opcode: u8, payload: &[u8]) -> Result<()> {
+        self.stream
+            .write_all(&encode_frame(opcode, payload))
+            .await?;
+        Ok(())
+    }
+}
+
+/// A client text frame, masked as clients must.
+#[cfg(test)]
+pub(super) fn client_text_frame(payload: &[u8]) -> Vec<u8> {
+    let mask = [0x37, 0xfa, 0x21, 0x3d];
+    let mut out = encode_frame(OPCODE_TEXT, payload);
+    let header_len = out.len() - payload.len();
+    out[1] |= 0x80;
+    let masked: Vec<u8> = payload
+        .iter()
+        .enumerate()
+        .map(|(i, byte)| byte ^ mask[i % 4])
+        .collect();
+    out.truncate(header_len);
+    out.extend_from_slice(&mask);
+    out.extend_from_slice(&masked);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_handshake_answers_the_rfc_example() {
+        assert_eq!(
+            accept_key("dGhlIHNhbXBsZSBub25jZQ=="),
+            "s3pPLMBiTxaQ9kYGzzhZxOo="
+        );
+    }
+
+    #[test]
+    fn masked_frames_parse_once_complete() {
+        let frame = client_text_frame(b"Hello");
+        let mut buf = BytesMut::from(&frame[..4]);
+        assert_eq!(parse_frame(&mut buf).unwrap(), None);
+        buf.extend_from_slice(&frame[4..]);
+        assert_eq!(
+            parse_frame(&mut buf).unwrap(),
+            Some(Frame {
+                fin: true,
+                opcode: OPCODE_TEXT,
+                payload: b"Hello".to_vec(),
+            })
+        );
+        assert!(buf.is_empty());
+
+        let long = vec![b'x'; 300];
+        let mut buf = BytesMut::from(&client_text_frame(&long)[..]);
+        assert_eq!(parse_frame(&mut buf).unwrap().unwrap().payload, long);
+    }
+
+    #[test]
+    fn unmasked_client_frames_are_refused() {
+        let mut buf = BytesMut::from(&encode_frame(OPCODE_TEXT, b"Hello")[..]);
+        assert!(parse_frame(&mut buf).is_err());
+    }
+}
@@ -0,0 +1,1951 @@
+//! DLNA/UPnP AV MediaRenderer: a `MediaRenderer:1` device with the
+//! AVTransport, RenderingControl and ConnectionManager services, found over
+//! [`ssdp`] and served over [`crate::http`]. Control points (BubbleUPnP,
+//! Jellyfin, Kodi, TVs and NAS apps) drive it with SOAP actions, which map
+//! onto the same [`crate::Operation`]s as the other senders, and follow it
+//! through GENA `LastChange` events.
+
+mod ssdp;
+mod xml;
+
+use std::{
+    net::IpAddr,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use anyhow::Result;
+use fcast_protocol::v4;
+use parking_lot::{Mutex, RwLock};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+    sync::mpsc::UnboundedReceiver,
+};
+use tracing::{debug, warn};
+
+use crate::{
+    MessageSender,
+    application::PacketOrigin,
+    fcast::FlatLoadMessage,
+    http::{Connection, Request, Response},
+};
+
+/// Where the description, control and event URLs are served.
+pub const DLNA_TCP_PORT: u16 = 49494;
+
+const DEVICE_TYPE: &str = "urn:schemas-upnp-org:device:MediaRenderer:1";
+/// Subscriptions last this long unless renewed.
+const SUBSCRIPTION_TIMEOUT_SECS: u64 = 1800;
+/// Live subscriptions across the services; a control point or two each
+/// subscribing to a few services stays well under it.
+const MAX_SUBSCRIPTIONS: usize = 32;
+const EVENT_DELIVERY_TIMEOUT: Duration = Duration::from_secs(5);
+/// What the renderer accepts, as advertised by `GetProtocolInfo`.
+const SINK_MIME_TYPES: &[&str] = &[
+    "video/mp4",
+    "video/x-matroska",
+    "video/webm",
+    "video/quicktime",
+    "video/mpeg",
+    "video/mp2t",
+    "video/x-msvideo",
+    "video/x-flv",
+    "video/ogg",
+    "audio/mpeg",
+    "audio/mp4",
+    "audio/aac",
+    "audio/flac",
+    "audio/x-flac",
+    "audio/ogg",
+    "audio/opus",
+    "audio/wav",
+    "audio/x-wav",
+    "image/jpeg",
+    "image/png",
+    "image/gif",
+    "image/webp",
+    "application/vnd.apple.mpegurl",
+    "application/x-mpegurl",
+    "application/dash+xml",
+];
+
+#[derive(Debug)]
+pub enum StatusUpdate {
+    /// An item started loading, whoever cast it.
+    Media(crate::gcast::MediaDescription),
+    /// Playback ended and nothing replaced it.
+    Stopped,
+    PlayerState(crate::PlayerState),
+    Position(f64),
+    Duration(f64),
+    Volume(f64),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Service {
+    AvTransport,
+    RenderingControl,
+    ConnectionManager,
+}
+
+impl Service {
+    const ALL: [Self; 3] = [
+        Self::AvTransport,
+        Self::RenderingControl,
+        Self::ConnectionManager,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::AvTransport => "AVTransport",
+            Self::RenderingControl => "RenderingControl",
+            Self::ConnectionManager => "ConnectionManager",
+        }
+    }
+
+    fn service_type(self) -> &'static str {
+        match self {
+            Self::AvTransport => "urn:schemas-upnp-org:service:AVTransport:1",
+            Self::RenderingControl => "urn:schemas-upnp-org:service:RenderingControl:1",
+            Self::ConnectionManager => "urn:schemas-upnp-org:service:ConnectionManager:1",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|service| service.name() == name)
+    }
+
+    fn actions(self) -> &'static [Action] {
+        match self {
+            Self::AvTransport => AV_TRANSPORT_ACTIONS,
+            Self::RenderingControl => RENDERING_CONTROL_ACTIONS,
+            Self::ConnectionManager => CONNECTION_MANAGER_ACTIONS,
+        }
+    }
+
+    fn variables(self) -> &'static [Variable] {
+        match self {
+            Self::AvTransport => AV_TRANSPORT_VARIABLES,
+            Self::RenderingControl => RENDERING_CONTROL_VARIABLES,
+            Self::ConnectionManager => CONNECTION_MANAGER_VARIABLES,
+        }
+    }
+}
+
+fn server_header() -> String {
+    format!(
+        "{}/1.0 UPnP/1.0 FCastReceiver/{}",
+        std::env::consts::OS,
+        env!("CARGO_PKG_VERSION")
+    )
+}
+
+/// The device UUID, derived from the name so control points recognise the
+/// renderer across restarts.
+fn device_uuid(name: &str) -> String {
+    use md5::Digest;
+    let digest = md5::Md5::digest(name.as_bytes());
+    let mut bytes = [0u8; 16];
+    bytes.copy_from_slice(&digest);
+    uuid::Builder::from_md5_bytes(bytes)
+        .into_uuid()
+        .hyphenated()
+        .to_string()
+}
+
+fn description(name: &str, uuid: &str) -> String {
+    let services: String = Service::ALL
+        .into_iter()
+        .map(|service| {
+            let name = service.name();
+            format!(
+                "<service>\
+                 <serviceType>{}</serviceType>\
+                 <serviceId>urn:upnp-org:serviceId:{name}</serviceId>\
+                 <SCPDURL>/{name}/scpd.xml</SCPDURL>\
+                 <controlURL>/{name}/control</controlURL>\
+                 <eventSubURL>/{name}/event</eventSubURL>\
+                 </service>",
+                service.service_type(),
+            )
+        })
+        .collect();
+    format!(
+        "<?xml version=\"1.0\" encoding=\"utf-8\"?>\
+         <root xmlns=\"urn:schemas-upnp-org:device-1-0\" \
+         xmlns:dlna=\"urn:schemas-dlna-org:device-1-0\">\
+         <specVersion><major>1</major><minor>0</minor></specVersion>\
+         <device>\
+         <deviceType>{DEVICE_TYPE}</deviceType>\
+         <friendlyName>{}</friendlyName>\
+         <manufacturer>FUTO</manufacturer>\
+         <manufacturerURL>https://fcast.org/</manufacturerURL>\
+         <modelName>FCast Receiver</modelName>\
+         <modelNumber>{}</modelNumber>\
+         <UDN>uuid:{uuid}</UDN>\
+         <dlna:X_DLNADOC>DMR-1.50</dlna:X_DLNADOC>\
+         <serviceList>{services}</serviceList>\
+         </device>\
+         </root>",
+        xml::escape(name),
+        env!("CARGO_PKG_VERSION"),
+    )
+}
+
+/// An action argument, `out` for the ones in the response.
+struct Argument {
+    name: &'static str,
+    out: bool,
+    variable: &'static str,
+}
+
+const fn input(name: &'static str, variable: &'static str) -> Argument {
+    Argument {
+        name,
+        out: false,
+        variable,
+    }
+}
+
+const fn output(name: &'static str, variable: &'static str) -> Argument {
+    Argument {
+        name,
+        out: true,
+        variable,
+    }
+}
+
+const INSTANCE_ID: Argument = input("InstanceID", "A_ARG_TYPE_InstanceID");
+const CHANNEL: Argument = input("Channel", "A_ARG_TYPE_Channel");
+
+struct Action {
+    name: &'static str,
+    arguments: &'static [Argument],
+}
+
+struct Variable {
+    name: &'static str,
+    data_type: &'static str,
+    evented: bool,
+    allowed: &'static [&'static str],
+}
+
+const fn variable(name: &'static str, data_type: &'static str) -> Variable {
+    Variable {
+        name,
+        data_type,
+        evented: false,
+        allowed: &[],
+    }
+}
+
+const fn evented(name: &'static str) -> Variable {
+    Variable {
+        name,
+        data_type: "string",
+        evented: true,
+        allowed: &[],
+    }
+}
+
+const fn choice(name: &'static str, allowed: &'static [&'static str]) -> Variable {
+    Variable {
+        name,
+        data_type: "string",
+        evented: false,
+        allowed,
+    }
+}
+
+/// The optional `Next`/`Previous`/`SetNextAVTransportURI` and recording are
+/// left out: control points fall back to loading items one by one.
+const AV_TRANSPORT_ACTIONS: &[Action] = &[
+    Action {
+        name: "SetAVTransportURI",
+        arguments: &[
+            INSTANCE_ID,
+            input("CurrentURI", "AVTransportURI"),
+            input("CurrentURIMetaData", "AVTransportURIMetaData"),
+        ],
+    },
+    Action {
+        name: "GetMediaInfo",
+        arguments: &[
+            INSTANCE_ID,
+            output("NrTracks", "NumberOfTracks"),
+            output("MediaDuration", "CurrentMediaDuration"),
+            output("CurrentURI", "AVTransportURI"),
+            output("CurrentURIMetaData", "AVTransportURIMetaData"),
+            output("NextURI", "NextAVTransportURI"),
+            output("NextURIMetaData", "NextAVTransportURIMetaData"),
+            output("PlayMedium", "PlaybackStorageMedium"),
+            output("RecordMedium", "RecordStorageMedium"),
+            output("WriteStatus", "RecordMediumWriteStatus"),
+        ],
+    },
+    Action {
+        name: "GetTransportInfo",
+        arguments: &[
+            INSTANCE_ID,
+            output("CurrentTransportState", "TransportState"),
+            output("CurrentTransportStatus", "TransportStatus"),
+            output("CurrentSpeed", "TransportPlaySpeed"),
+        ],
+    },
+    Action {
+        name: "GetPositionInfo",
+        arguments: &[
+            INSTANCE_ID,
+            output("Track", "CurrentTrack"),
+            output("TrackDuration", "CurrentTrackDuration"),
+            output("TrackMetaData", "CurrentTrackMetaData"),
+            output("TrackURI", "CurrentTrackURI"),
+            output("RelTime", "RelativeTimePosition"),
+            output("AbsTime", "AbsoluteTimePosition"),
+            output("RelCount", "RelativeCounterPosition"),
+            output("AbsCount", "AbsoluteCounterPosition"),
+        ],
+    },
+    Action {
+        name: "GetDeviceCapabilities",
+        arguments: &[
+            INSTANCE_ID,
+            output("PlayMedia", "PossiblePlaybackStorageMedia"),
+            output("RecMedia", "PossibleRecordStorageMedia"),
+            output("RecQualityModes", "PossibleRecordQualityModes"),
+        ],
+    },
+    Action {
+        name: "GetTransportSettings",
+        arguments: &[
+            INSTANCE_ID,
+            output("PlayMode", "CurrentPlayMode"),
+            output("RecQualityMode", "CurrentRecordQualityMode"),
+        ],
+    },
+    Action {
+        name: "GetCurrentTransportActions",
+        arguments: &[INSTANCE_ID, output("Actions", "CurrentTransportActions")],
+    },
+    Action {
+        name: "Stop",
+        arguments: &[INSTANCE_ID],
+    },
+    Action {
+        name: "Play",
+        arguments: &[INSTANCE_ID, input("Speed", "TransportPlaySpeed")],
+    },
+    Action {
+        name: "Pause",
+        arguments: &[INSTANCE_ID],
+    },
+    Action {
+        name: "Seek",
+        arguments: &[
+            INSTANCE_ID,
+            input("Unit", "A_ARG_TYPE_SeekMode"),
+            input("Target", "A_ARG_TYPE_SeekTarget"),
+        ],
+    },
+];
+
+const AV_TRANSPORT_VARIABLES: &[Variable] = &[
+    choice(
+        "TransportState",
+        &[
+            "STOPPED",
+            "PLAYING",
+            "PAUSED_PLAYBACK",
+            "TRANSITIONING",
+            "NO_MEDIA_PRESENT",
+        ],
+    ),
+    choice("TransportStatus", &["OK", "ERROR_OCCURRED"]),
+    choice("PlaybackStorageMedium", &["NONE", "NETWORK"]),
+    choice("RecordStorageMedium", &["NOT_IMPLEMENTED"]),
+    variable("PossiblePlaybackStorageMedia", "string"),
+    variable("PossibleRecordStorageMedia", "string"),
+    choice("CurrentPlayMode", &["NORMAL"]),
+    choice("TransportPlaySpeed", &["1"]),
+    choice("RecordMediumWriteStatus", &["NOT_IMPLEMENTED"]),
+    choice("CurrentRecordQualityMode", &["NOT_IMPLEMENTED"]),
+    variable("PossibleRecordQualityModes", "string"),
+    variable("NumberOfTracks", "ui4"),
+    variable("CurrentTrack", "ui4"),
+    variable("CurrentTrackDuration", "string"),
+    variable("CurrentMediaDuration", "string"),
+    variable("CurrentTrackMetaData", "string"),
+    variable("CurrentTrackURI", "string"),
+    variable("AVTransportURI", "string"),
+    variable("AVTransportURIMetaData", "string"),
+    variable("NextAVTransportURI", "string"),
+    variable("NextAVTransportURIMetaData", "string"),
+    variable("RelativeTimePosition", "string"),
+    variable("AbsoluteTimePosition", "string"),
+    variable("RelativeCounterPosition", "i4"),
+    variable("AbsoluteCounterPosition", "i4"),
+    variable("CurrentTransportActions", "string"),
+    evented("LastChange"),
+    choice("A_ARG_TYPE_SeekMode", &["REL_TIME", "ABS_TIME"]),
+    variable("A_ARG_TYPE_SeekTarget", "string"),
+    variable("A_ARG_TYPE_InstanceID", "ui4"),
+];
+
+const RENDERING_CONTROL_ACTIONS: &[Action] = &[
+    Action {
+        name: "ListPresets",
+        arguments: &[
+            INSTANCE_ID,
+            output("CurrentPresetNameList", "PresetNameList"),
+        ],
+    },
+    Action {
+        name: "SelectPreset",
+        arguments: &[INSTANCE_ID, input("PresetName", "A_ARG_TYPE_PresetName")],
+    },
+    Action {
+        name: "GetMute",
+        arguments: &[INSTANCE_ID, CHANNEL, output("CurrentMute", "Mute")],
+    },
+    Action {
+        name: "SetMute",
+        arguments: &[INSTANCE_ID, CHANNEL, input("DesiredMute", "Mute")],
+    },
+    Action {
+        name: "GetVolume",
+        arguments: &[INSTANCE_ID, CHANNEL, output("CurrentVolume", "Volume")],
+    },
+    Action {
+        name: "SetVolume",
+        arguments: &[INSTANCE_ID, CHANNEL, input("DesiredVolume", "Volume")],
+    },
+];
+
+const RENDERING_CONTROL_VARIABLES: &[Variable] = &[
+    variable("PresetNameList", "string"),
+    evented("LastChange"),
+    variable("Mute", "boolean"),
+    // Control points read the range for their slider; 0 to 100 is assumed.
+    variable("Volume", "ui2"),
+    choice("A_ARG_TYPE_Channel", &["Master"]),
+    variable("A_ARG_TYPE_InstanceID", "ui4"),
+    choice("A_ARG_TYPE_PresetName", &["FactoryDefaults"]),
+];
+
+const CONNECTION_MANAGER_ACTIONS: &[Action] = &[
+    Action {
+        name: "GetProtocolInfo",
+        arguments: &[
+            output("Source", "SourceProtocolInfo"),
+            output("Sink", "SinkProtocolInfo"),
+        ],
+    },
+    Action {
+        name: "GetCurrentConnectionIDs",
+        arguments: &[output("ConnectionIDs", "CurrentConnectionIDs")],
+    },
+    Action {
+        name: "GetCurrentConnectionInfo",
+        arguments: &[
+            input("ConnectionID", "A_ARG_TYPE_ConnectionID"),
+            output("RcsID", "A_ARG_TYPE_RcsID"),
+            output("AVTransportID", "A_ARG_TYPE_AVTransportID"),
+            output("ProtocolInfo", "A_ARG_TYPE_ProtocolInfo"),
+            output("PeerConnectionManager", "A_ARG_TYPE_ConnectionManager"),
+            output("PeerConnectionID", "A_ARG_TYPE_ConnectionID"),
+            output("Direction", "A_ARG_TYPE_Direction"),
+            output("Status", "A_ARG_TYPE_ConnectionStatus"),
+        ],
+    },
+];
+
+const CONNECTION_MANAGER_VARIABLES: &[Variable] = &[
+    evented("SourceProtocolInfo"),
+    evented("SinkProtocolInfo"),
+    evented("CurrentConnectionIDs"),
+    choice(
+        "A_ARG_TYPE_ConnectionStatus",
+        &[
+            "OK",
+            "ContentFormatMismatch",
+            "InsufficientBandwidth",
+            "UnreliableChannel",
+            "Unknown",
+        ],
+    ),
+    variable("A_ARG_TYPE_ConnectionManager", "string"),
+    choice("A_ARG_TYPE_Direction", &["Input", "Output"]),
+    variable("A_ARG_TYPE_ProtocolInfo", "string"),
+    variable("A_ARG_TYPE_ConnectionID", "i4"),
+    variable("A_ARG_TYPE_AVTransportID", "i4"),
+    variable("A_ARG_TYPE_RcsID", "i4"),
+];
+
+/// The service description control points read the actions from.
+fn scpd(service: Service) -> String {
+    let mut out = String::from(
+        "<?xml version=\"1.0\" encoding=\"utf-8\"?>\
+         <scpd xmlns=\"urn:schemas-upnp-org:service-1-0\">\
+         <specVersion><major>1</major><minor>0</minor></specVersion>\
+         <actionList>",
+    );
+    for action in service.actions() {
+        out.push_str("<action><name>");
+        out.push_str(action.name);
+        out.push_str("</name><argumentList>");
+        for argument in action.arguments {
+            out.push_str(&format!(
+                "<argument><name>{}</name><direction>{}</direction>\
+                 <relatedStateVariable>{}</relatedStateVariable></argument>",
+                argument.name,
+                if argument.out { "out" } else { "in" },
+                argument.variable,
+            ));
+        }
+        out.push_str("</argumentList></action>");
+    }
+    out.push_str("</actionList><serviceStateTable>");
+    for variable in service.variables() {
+        out.push_str(&format!(
+            "<stateVariable sendEvents=\"{}\"><name>{}</name><dataType>{}</dataType>",
+            if variable.evented { "yes" } else { "no" },
+            variable.name,
+            variable.data_type,
+        ));
+        if !variable.allowed.is_empty() {
+            out.push_str("<allowedValueList>");
+            for value in variable.allowed {
+                out.push_str(&format!("<allowedValue>{value}</allowedValue>"));
+            }
+            out.push_str("</allowedValueList>");
+        }
+        if variable.name == "Volume" {
+            out.push_str(
+                "<allowedValueRange><minimum>0</minimum><maximum>100</maximum>\
+                 <step>1</step></allowedValueRange>",
+            );
+        }
+        out.push_str("</stateVariable>");
+    }
+    out.push_str("</serviceStateTable></scpd>");
+    out
+}
+
+fn sink_protocol_info() -> String {
+    SINK_MIME_TYPES
+        .iter()
+        .map(|mime| format!("http-get:*:{mime}:*"))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// A UPnP error, reported in a SOAP fault.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum UpnpError {
+    InvalidAction,
+    InvalidArgs,
+    TransitionNotAvailable,
+    SeekModeNotSupported,
+    IllegalSeekTarget,
+    InvalidInstanceId,
+}
+
+impl UpnpError {
+    fn code(self) -> u16 {
+        match self {
+            Self::InvalidAction => 401,
+            Self::InvalidArgs => 402,
+            Self::TransitionNotAvailable => 701,
+            Self::SeekModeNotSupported => 710,
+            Self::IllegalSeekTarget => 711,
+            Self::InvalidInstanceId => 718,
+        }
+    }
+
+    fn description(self) -> &'static str {
+        match self {
+            Self::InvalidAction => "Invalid Action",
+            Self::InvalidArgs => "Invalid Args",
+            Self::TransitionNotAvailable => "Transition not available",
+            Self::SeekModeNotSupported => "Seek mode not supported",
+            Self::IllegalSeekTarget => "Illegal seek target",
+            Self::InvalidInstanceId => "Invalid InstanceID",
+        }
+    }
+}
+
+fn soap_envelope(body: &str) -> String {
+    format!(
+        "<?xml version=\"1.0\" encoding=\"utf-8\"?>\
+         <s:Envelope xmlns:s=\"http://schemas.xmlsoap.org/soap/envelope/\" \
+         s:encodingStyle=\"http://schemas.xmlsoap.org/soap/encoding/\">\
+         <s:Body>{body}</s:Body></s:Envelope>"
+    )
+}
+
+fn soap_response(service: Service, action: &str, arguments: &[(&str, String)]) -> String {
+    let arguments: String = arguments
+        .iter()
+        .map(|(name, value)| format!("<{name}>{}</{name}>", xml::escape(value)))
+        .collect();
+    soap_envelope(&format!(
+        "<u:{action}Response xmlns:u=\"{}\">{arguments}</u:{action}Response>",
+        service.service_type(),
+    ))
+}
+
+fn soap_fault(error: UpnpError) -> String {
+    soap_envelope(&format!(
+        "<s:Fault><faultcode>s:Client</faultcode><faultstring>UPnPError</faultstring>\
+         <detail><UPnPError xmlns=\"urn:schemas-upnp-org:control-1-0\">\
+         <errorCode>{}</errorCode><errorDescription>{}</errorDescription>\
+         </UPnPError></detail></s:Fault>",
+        error.code(),
+        error.description(),
+    ))
+}
+
+/// A UPnP duration or position, `H+:MM:SS[.F+]` or `H+:MM:SS[.F0/F1]`.
+fn parse_time(text: &str) -> Option<f64> {
+    let mut parts = text.trim().splitn(3, ':');
+    let hours: u64 = parts.next()?.parse().ok()?;
+    let minutes: u64 = parts.next()?.parse().ok()?;
+    let seconds = parts.next()?;
+    let seconds = match seconds.split_once('.') {
+        Some((whole, fraction)) if fraction.contains('/') => {
+            let (numerator, denominator) = fraction.split_once('/')?;
+            let denominator = denominator.parse::<f64>().ok().filter(|d| *d > 0.0)?;
+            whole.parse::<f64>().ok()? + numerator.parse::<f64>().ok()? / denominator
+        }
+        _ => seconds.parse::<f64>().ok()?,
+    };
+    if minutes >= 60 || !(0.0..60.0).contains(&seconds) {
+        return None;
+    }
+    // The hours come straight from a control point's SOAP argument.
+    let whole = hours.checked_mul(3600)?.checked_add(minutes * 60)?;
+    Some(whole as f64 + seconds).filter(|secs| secs.is_finite())
+}
+
+fn format_time(secs: f64) -> String {
+    let total = secs.max(0.0) as u64;
+    format!("{}:{:02}:{:02}", total / 3600, total / 60 % 60, total % 60)
+}
+
+/// What a DIDL-Lite `CurrentURIMetaData` says about the item.
+#[derive(Debug, Default, PartialEq)]
+struct DidlItem {
+    title: Option<String>,
+    art_url: Option<String>,
+    /// From the `res` element's protocol info, `http-get:*:<mime>:*`.
+    mime: Option<String>,
+}
+
+impl DidlItem {
+    fn parse(metadata: &str) -> Self {
+        let non_empty = |text: String| (!text.trim().is_empty()).then_some(text);
+        Self {
+            title: xml::element_text(metadata, "title").and_then(non_empty),
+            art_url: xml::element_text(metadata, "albumArtURI").and_then(non_empty),
+            mime: xml::element_attribute(metadata, "res", "protocolInfo")
+                .and_then(|info| info.split(':').nth(2).map(str::to_owned))
+                .filter(|mime| mime.contains('/')),
+        }
+    }
+}
+
+/// Metadata for an item another sender cast, so control points show it.
+fn didl_for(media: &crate::gcast::MediaDescription) -> String {
+    let class = if media.container.starts_with("image/") {
+        "object.item.imageItem"
+    } else if media.container.starts_with("audio/") {
+        "object.item.audioItem"
+    } else {
+        "object.item.videoItem"
+    };
+    let title = media.title.as_deref().unwrap_or_default();
+    let art = media
+        .thumbnail_url
+        .as_deref()
+        .map(|url| format!("<upnp:albumArtURI>{}</upnp:albumArtURI>", xml::escape(url)))
+        .unwrap_or_default();
+    format!(
+        "<DIDL-Lite xmlns=\"urn:schemas-upnp-org:metadata-1-0/DIDL-Lite/\" \
+         xmlns:dc=\"http://purl.org/dc/elements/1.1/\" \
+         xmlns:upnp=\"urn:schemas-upnp-org:metadata-1-0/upnp/\">\
+         <item id=\"0\" parentID=\"-1\" restricted=\"1\">\
+         <dc:title>{}</dc:title><upnp:class>{class}</upnp:class>{art}\
+         <res protocolInfo=\"http-get:*:{}:*\">{}</res>\
+         </item></DIDL-Lite>",
+        xml::escape(title),
+        xml::escape(&media.container),
+        xml::escape(&media.url),
+    )
+}
+
+/// A container for a URL that came without a usable protocol info.
+fn container_for(url: &str) -> &'static str {
+    let path = url.split(['?', '#']).next().unwrap_or(url);
+    let extension = path
+        .rsplit_once('.')
+        .map(|(_, extension)| extension.to_ascii_lowercase());
+    match extension.as_deref() {
+        Some("m3u8") => "application/vnd.apple.mpegurl",
+        Some("mpd") => "application/dash+xml",
+        Some("mp3") => "audio/mpeg",
+        Some("m4a" | "aac") => "audio/mp4",
+        Some("flac") => "audio/flac",
+        Some("ogg" | "oga" | "opus") => "audio/ogg",
+        Some("wav") => "audio/wav",
+        Some("jpg" | "jpeg") => "image/jpeg",
+        Some("png") => "image/png",
+        Some("gif") => "image/gif",
+        Some("webp") => "image/webp",
+        Some("mkv") => "video/x-matroska",
+        Some("webm") => "video/webm",
+        _ => "video/mp4",
+    }
+}
+
+fn load_message(uri: &str, metadata: &str) -> Option<FlatLoadMessage> {
+    let didl = DidlItem::parse(metadata);
+    let item = v4::MediaItem {
+        container: didl.mime.unwrap_or_else(|| container_for(uri).to_owned()),
+        source_url: uri.to_owned(),
+        start_time: None,
+        volume: None,
+        speed: None,
+        headers: None,
+        title: didl.title,
+        thumbnail_url: didl.art_url,
+        metadata: None,
+        extra_metadata: None,
+        resume: false,
+    };
+    let msg = v4::MessageBuilder::new().load_single(item);
+    match FlatLoadMessage::from_packet(msg.to_vec()) {
+        Ok(load) => Some(load),
+        Err(err) => {
+            warn!(?err, "Failed to build a load");
+            None
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum TransportState {
+    NoMediaPresent,
+    Stopped,
+    Playing,
+    PausedPlayback,
+    Transitioning,
+}
+
+impl TransportState {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::NoMediaPresent => "NO_MEDIA_PRESENT",
+            Self::Stopped => "STOPPED",
+            Self::Playing => "PLAYING",
+            Self::PausedPlayback => "PAUSED_PLAYBACK",
+            Self::Transitioning => "TRANSITIONING",
+        }
+    }
+
+    fn actions(self) -> &'static str {
+        match self {
+            Self::NoMediaPresent => "",
+            Self::Stopped => "Play",
+            Self::Playing => "Pause,Stop,Seek",
+            Self::PausedPlayback => "Play,Stop,Seek",
+            Self::Transitioning => "Stop",
+        }
+    }
+}
+
+/// The renderer as control points see it.
+#[derive(Debug)]
+struct Renderer {
+    transport_state: TransportState,
+    uri: String,
+    metadata: String,
+    /// Whether the application has `uri`. `SetAVTransportURI` only stages it,
+    /// `Play` hands it over.
+    loaded: bool,
+    position: f64,
+    duration: f64,
+    volume: f64,
+    /// The volume to go back to, while muted. Mute is volume zero to the
+    /// application.
+    muted_volume: Option<f64>,
+}
+
+impl Default for Renderer {
+    fn default() -> Self {
+        Self {
+            transport_state: TransportState::NoMediaPresent,
+            uri: String::new(),
+            metadata: String::new(),
+            loaded: false,
+            position: 0.0,
+            duration: 0.0,
+            volume: 1.0,
+            muted_volume: None,
+        }
+    }
+}
+
+impl Renderer {
+    fn stopped_state(&self) -> TransportState {
+        if self.uri.is_empty() {
+            TransportState::NoMediaPresent
+        } else {
+            TransportState::Stopped
+        }
+    }
+
+    /// Apply an application update, returning the service whose evented state
+    /// changed.
+    fn apply(&mut self, update: StatusUpdate) -> Option<Service> {
+        match update {
+            StatusUpdate::Media(media) => {
+                // Keep the control point's own metadata for what it cast.
+                if media.url != self.uri {
+                    self.metadata = didl_for(&media);
+                    self.uri = media.url;
+                }
+                self.loaded = true;
+                self.position = 0.0;
+                self.duration = 0.0;
+                self.transport_state = TransportState::Transitioning;
+                Some(Service::AvTransport)
+            }
+            StatusUpdate::Stopped => {
+                self.loaded = false;
+                self.position = 0.0;
+                self.set_transport_state(self.stopped_state())
+            }
+            StatusUpdate::PlayerState(state) => {
+                let state = match state {
+                    crate::PlayerState::Playing => TransportState::Playing,
+                    crate::PlayerState::Paused => TransportState::PausedPlayback,
+                    crate::PlayerState::Buffering => TransportState::Transitioning,
+                    crate::PlayerState::Stopped => self.stopped_state(),
+                };
+                self.set_transport_state(state)
+            }
+            StatusUpdate::Position(position) => {
+                self.position = position;
+                None
+            }
+            StatusUpdate::Duration(duration) => {
+                let changed = format_time(duration) != format_time(self.duration);
+                self.duration = duration;
+                changed.then_some(Service::AvTransport)
+            }
+            StatusUpdate::Volume(volume) => {
+                let before = (self.volume_percent(), self.muted_volume.is_some());
+                self.volume = volume;
+                if volume > 0.0 {
+                    self.muted_volume = None;
+                }
+                (before != (self.volume_percent(), self.muted_volume.is_some()))
+                    .then_some(Service::RenderingControl)
+            }
+        }
+    }
+
+    fn set_transport_state(&mut self, state: TransportState) -> Option<Service> {
+        if self.transport_state == state {
+            return None;
+        }
+        self.transport_state = state;
+        Some(Service::AvTransport)
+    }
+
+    fn volume_percent(&self) -> u32 {
+        (self.volume.clamp(0.0, 1.0) * 100.0).round() as u32
+    }
+
+    fn track_count(&self) -> &'static str {
+        if self.uri.is_empty() { "0" } else { "1" }
+    }
+
+    fn av_transport_last_change(&self) -> String {
+        let duration = format_time(self.duration);
+        last_change(
+            "urn:schemas-upnp-org:metadata-1-0/AVT/",
+            None,
+            &[
+                ("TransportState", self.transport_state.as_str()),
+                ("TransportStatus", "OK"),
+                ("TransportPlaySpeed", "1"),
+                ("CurrentPlayMode", "NORMAL"),
+                ("NumberOfTracks", self.track_count()),
+                ("CurrentTrack", self.track_count()),
+                ("CurrentTrackDuration", &duration),
+                ("CurrentMediaDuration", &duration),
+                ("AVTransportURI", &self.uri),
+                ("AVTransportURIMetaData", &self.metadata),
+                ("CurrentTrackURI", &self.uri),
+                ("CurrentTrackMetaData", &self.metadata),
+                ("CurrentTransportActions", self.transport_state.actions()),
+            ],
+        )
+    }
+
+    fn rendering_control_last_change(&self) -> String {
+        let volume = self.volume_percent().to_string();
+        let mute = if self.muted_volume.is_some() {
+            "1"
+        } else {
+            "0"
+        };
+        last_change(
+            "urn:schemas-upnp-org:metadata-1-0/RCS/",
+            Some("Master"),
+            &[("Volume", &volume), ("Mute", mute)],
+        )
+    }
+
+    /// The body of an event carrying everything `service` events.
+    fn event_body(&self, service: Service) -> String {
+        match service {
+            Service::AvTransport => {
+                property_set(&[("LastChange", self.av_transport_last_change())])
+            }
+            Service::RenderingControl => {
+                property_set(&[("LastChange", self.rendering_control_last_change())])
+            }
+            Service::ConnectionManager => property_set(&[
+                ("SourceProtocolInfo", String::new()),
+                ("SinkProtocolInfo", sink_protocol_info()),
+                ("CurrentConnectionIDs", "0".to_owned()),
+            ]),
+        }
+    }
+}
+
+fn last_change(namespace: &str, channel: Option<&str>, values: &[(&str, &str)]) -> String {
+    let channel = channel
+        .map(|channel| format!(" channel=\"{channel}\""))
+        .unwrap_or_default();
+    let values: String = values
+        .iter()
+        .map(|(name, value)| format!("<{name}{channel} val=\"{}\"/>", xml::escape(value)))
+        .collect();
+    format!("<Event xmlns=\"{namespace}\"><InstanceID val=\"0\">{values}</InstanceID></Event>")
+}
+
+fn property_set(properties: &[(&str, String)]) -> String {
+    let properties: String = properties
+        .iter()
+        .map(|(name, value)| {
+            format!(
+                "<e:property><{name}>{}</{name}></e:property>",
+                xml::escape(value)
+            )
+        })
+        .collect();
+    format!(
+        "<?xml version=\"1.0\" encoding=\"utf-8\"?>\
+         <e:propertyset xmlns:e=\"urn:schemas-upnp-org:event-1-0\">{properties}</e:propertyset>"
+    )
+}
+
+/// A GENA subscription to one service's events.
+#[derive(Debug)]
+struct Subscription {
+    sid: String,
+    service: Service,
+    callbacks: Vec<String>,
+    expires: Instant,
+    seq: u32,
+}
+
+impl Subscription {
+    /// The next event's sequence number. Zero is the initial event's only, so
+    /// it wraps to one.
+    fn next_seq(&mut self) -> u32 {
+        let seq = self.seq;
+        self.seq = self.seq.checked_add(1).unwrap_or(1);
+        seq
+    }
+}
+
+/// The `<url>` list of a `CALLBACK` header, keeping only URLs on `peer`, the
+/// subscriber itself. Events sent anywhere else would make the renderer a
+/// request relay for whoever subscribes (CallStranger, CVE-2020-12695).
+fn parse_callbacks(header: &str, peer: IpAddr) -> Vec<String> {
+    header
+        .split('<')
+        .filter_map(|part| part.split_once('>'))
+        .map(|(url, _)| url.trim())
+        .filter(|url| callback_ip(url) == Some(peer.to_canonical()))
+        .map(str::to_owned)
+        .collect()
+}
+
+/// The IP address an `http://` callback URL names, if it names one.
+fn callback_ip(url: &str) -> Option<IpAddr> {
+    let url = url::Url::parse(url)
+        .ok()
+        .filter(|url| url.scheme() == "http")?;
+    match url.host()? {
+        url::Host::Ipv4(ip) => Some(IpAddr::V4(ip)),
+        url::Host::Ipv6(ip) => Some(IpAddr::V6(ip).to_canonical()),
+        url::Host::Domain(_) => None,
+    }
+}
+
+/// Send one event, trying each callback URL until one takes it.
+async fn deliver_event(callbacks: Vec<String>, sid: String, seq: u32, body: String) {
+    for callback in callbacks {
+        let send = async {
+            let url = url::Url::parse(&callback)?;
+            let host = url.host_str().unwrap_or_default();
+            let port = url.port_or_known_default().unwrap_or(80);
+            let mut stream = TcpStream::connect((host, port)).await?;
+            let request = format!(
+                "NOTIFY {} HTTP/1.1\r\n\
+                 HOST: {host}:{port}\r\n\
+                 CONTENT-TYPE: text/xml; charset=\"utf-8\"\r\n\
+                 NT: upnp:event\r\n\
+                 NTS: upnp:propchange\r\n\
+                 SID: {sid}\r\n\
+                 SEQ: {seq}\r\n\
+                 Connection: close\r\n\
+                 Content-Length: {}\r\n\r\n{body}",
+                &url[url::Position::BeforePath..],
+                body.len(),
+            );
+            stream.write_all(request.as_bytes()).await?;
+            let mut status = [0u8; 12];
+            stream.read_exact(&mut status).await?;
+            anyhow::ensure!(status.starts_with(b"HTTP/1.1 2") || status.starts_with(b"HTTP/1.0 2"));
+            anyhow::Ok(())
+        };
+        match tokio::time::timeout(EVENT_DELIVERY_TIMEOUT, send).await {
+            Ok(Ok(())) => return,
+            Ok(Err(err)) => debug!(?err, callback, "Event delivery failed"),
+            Err(_) => debug!(callback, "Event delivery timed out"),
+        }
+    }
+}
+
+/// What every connection shares: the renderer's state and who to tell about
+/// changes to it.
+#[derive(Clone)]
+struct Context {
+    msg_tx: MessageSender,
+    description: Arc<str>,
+    renderer: Arc<RwLock<Renderer>>,
+    subscriptions: Arc<Mutex<Vec<Subscription>>>,
+}
+
+impl Context {
+    fn new(msg_tx: MessageSender, name: &str, uuid: &str) -> Self {
+        Self {
+            msg_tx,
+            description: description(name, uuid).into(),
+            renderer: Arc::default(),
+            subscriptions: Arc::default(),
+        }
+    }
+
+    fn operation(&self, op: crate::Operation) {
+        self.msg_tx.operation(PacketOrigin::Dlna, op);
+    }
+
+    /// Event the current state of `service` to its subscribers.
+    fn notify(&self, service: Service) {
+        let body = self.renderer.read().event_body(service);
+        let now = Instant::now();
+        let mut subscriptions = self.subscriptions.lock();
+        subscriptions.retain(|subscription| subscription.expires > now);
+        for subscription in subscriptions
+            .iter_mut()
+            .filter(|subscription| subscription.service == service)
+        {
+            tokio::spawn(deliver_event(
+                subscription.callbacks.clone(),
+                subscription.sid.clone(),
+                subscription.next_seq(),
+                body.clone(),
+            ));
+        }
+    }
+
+    async fn serve(&self, stream: TcpStream) -> Result<()> {
+        let peer = stream.peer_addr()?.ip();
+        let mut connection = Connection::new(stream);
+        while let Some(request) = connection.read_request().await? {
+            let (response, subscribed) = self.respond(&request, peer);
+            connection.write_response(&response).await?;
+            // The initial event goes out once the subscriber has its SID.
+            if let Some(sid) = subscribed {
+                self.send_initial_event(&sid);
+            }
+        }
+        Ok(())
+    }
+
+    fn respond(&self, request: &Request, peer: IpAddr) -> (Response, Option<String>) {
+        let path = request.path().trim_start_matches('/');
+        if path == "description.xml" {
+            return (
+                self.xml_response(request, self.description.to_string()),
+                None,
+            );
+        }
+        let Some((service, endpoint)) = path
+            .split_once('/')
+            .and_then(|(name, endpoint)| Some((Service::from_name(name)?, endpoint)))
+        else {
+            return (Response::new(&request.protocol, 404, "Not Found"), None);
+        };
+        match (request.method.as_str(), endpoint) {
+            ("GET", "scpd.xml") => (self.xml_response(request, scpd(service)), None),
+            ("POST", "control") => (self.control(service, request), None),
+            ("SUBSCRIBE", "event") => self.subscribe(service, request, peer),
+            ("UNSUBSCRIBE", "event") => (self.unsubscribe(service, request), None),
+            (_, "scpd.xml" | "control" | "event") => (
+                Response::new(&request.protocol, 405, "Method Not Allowed"),
+                None,
+            ),
+            _ => (Response::new(&request.protocol, 404, "Not Found"), None),
+        }
+    }
+
+    fn xml_response(&self, request: &Request, body: String) -> Response {
+        Response::new(&request.protocol, 200, "OK")
+            .header("Server", &server_header())
+            .body("text/xml; charset=\"utf-8\"", body.into_bytes())
+    }
+
+    fn control(&self, service: Service, request: &Request) -> Response {
+        let Some(action) = request
+            .header("SOAPACTION")
+            .and_then(|action| action.trim_matches('"').rsplit_once('#'))
+            .map(|(_, action)| action.to_owned())
+        else {
+            return Response::new(&request.protocol, 400, "Bad Request");
+        };
+        let body = String::from_utf8_lossy(&request.body);
+        debug!(service = service.name(), action, "DLNA action");
+        match self.action(service, &action, &body) {
+            Ok(arguments) => Response::new(&request.protocol, 200, "OK")
+                .header("Server", &server_header())
+                .header("EXT", "")
+                .body(
+                    "text/xml; charset=\"utf-8\"",
+                    soap_response(service, &action, &arguments).into_bytes(),
+                ),
+            Err(err) => {
+                debug!(?err, action, "DLNA action failed");
+                Response::new(&request.protocol, 500, "Internal Server Error")
+                    .header("Server", &server_header())
+                    .body("text/xml; charset=\"utf-8\"", soap_fault(err).into_bytes())
+            }
+        }
+    }
+
+    fn action(
+        &self,
+        service: Service,
+        action: &str,
+        body: &str,
+    ) -> Result<Vec<(&'static str, String)>, UpnpError> {
+        let argument = |name: &str| xml::element_text(body, name).ok_or(UpnpError::InvalidArgs);
+        if service != Service::ConnectionManager && argument("InstanceID")?.trim() != "0" {
+            return Err(UpnpError::InvalidInstanceId);
+        }
+        match (service, action) {
+            (Service::AvTransport, "SetAVTransportURI") => {
+                let uri = argument("CurrentURI")?.trim().to_owned();
+                let metadata = xml::element_text(body, "CurrentURIMetaData").unwrap_or_default();
+                self.set_uri(uri, metadata);
+                Ok(Vec::new())
+            }
+            (Service::AvTransport, "Play") => {
+                let mut renderer = self.renderer.write();
+                if renderer.uri.is_empty() {
+                    return Err(UpnpError::TransitionNotAvailable);
+                }
+                if renderer.loaded {
+                    self.operation(crate::Operation::Resume);
+                } else if let Some(load) = load_message(&renderer.uri, &renderer.metadata) {
+                    renderer.loaded = true;
+                    renderer.transport_state = TransportState::Transitioning;
+                    self.operation(crate::Operation::PlayNew(
+                        crate::fcast::WrappedPlayMessage::V4(load),
+                    ));
+                } else {
+                    return Err(UpnpError::InvalidArgs);
+                }
+                Ok(Vec::new())
+            }
+            (Service::AvTransport, "Pause") => {
+                if !self.renderer.read().loaded {
+                    return Err(UpnpError::TransitionNotAvailable);
+                }
+                self.operation(crate::Operation::Pause);
+                Ok(Vec::new())
+            }
+            (Service::AvTransport, "Stop") => {
+                let mut renderer = self.renderer.write();
+                if renderer.loaded {
+                    self.operation(crate::Operation::Stop);
+                }
+                renderer.loaded = false;
+                renderer.transport_state = renderer.stopped_state();
+                Ok(Vec::new())
+            }
+            (Service::AvTransport, "Seek") => {
+                let unit = argument("Unit")?;
+                if unit != "REL_TIME" && unit != "ABS_TIME" {
+                    return Err(UpnpError::SeekModeNotSupported);
+                }
+                let time = parse_time(&argument("Target")?)
+                    .and_then(|secs| gst::ClockTime::try_from_seconds_f64(secs).ok())
+                    .ok_or(UpnpError::IllegalSeekTarget)?;
+                if !self.renderer.read().loaded {
+                    return Err(UpnpError::TransitionNotAvailable);
+                }
+                self.operation(crate::Operation::Seek(time));
+                Ok(Vec::new())
+            }
+            (Service::AvTransport, "GetMediaInfo") => {
+                let renderer = self.renderer.read();
+                Ok(vec![
+                    ("NrTracks", renderer.track_count().to_owned()),
+                    ("MediaDuration", format_time(renderer.duration)),
+                    ("CurrentURI", renderer.uri.clone()),
+                    ("CurrentURIMetaData", renderer.metadata.clone()),
+                    ("NextURI", String::new()),
+                    ("NextURIMetaData", String::new()),
+                    ("PlayMedium", "NETWORK".to_owned()),
+                    ("RecordMedium", "NOT_IMPLEMENTED".to_owned()),
+                    ("WriteStatus", "NOT_IMPLEMENTED".to_owned()),
+                ])
+            }
+            (Service::AvTransport, "GetTransportInfo") => Ok(vec![
+                (
+                    "CurrentTransportState",
+                    self.renderer.read().transport_state.as_str().to_owned(),
+                ),
+                ("CurrentTransportStatus", "OK".to_owned()),
+                ("CurrentSpeed", "1".to_owned()),
+            ]),
+            (Service::AvTransport, "GetPositionInfo") => {
+                let renderer = self.renderer.read();
+                let position = format_time(renderer.position);
+                Ok(vec![
+                    ("Track", renderer.track_count().to_owned()),
+                    ("TrackDuration", format_time(renderer.duration)),
+                    ("TrackMetaData", renderer.metadata.clone()),
+                    ("TrackURI", renderer.uri.clone()),
+                    ("RelTime", position.clone()),
+                    ("AbsTime", position),
+                    ("RelCount", i32::MAX.to_string()),
+                    ("AbsCount", i32::MAX.to_string()),
+                ])
+            }
+            (Service::AvTransport, "GetDeviceCapabilities") => Ok(vec![
+                ("PlayMedia", "NETWORK".to_owned()),
+                ("RecMedia", "NOT_IMPLEMENTED".to_owned()),
+                ("RecQualityModes", "NOT_IMPLEMENTED".to_owned()),
+            ]),
+            (Service::AvTransport, "GetTransportSettings") => Ok(vec![
+                ("PlayMode", "NORMAL".to_owned()),
+                ("RecQualityMode", "NOT_IMPLEMENTED".to_owned()),
+            ]),
+            (Service::AvTransport, "GetCurrentTransportActions") => Ok(vec![(
+                "Actions",
+                self.renderer.read().transport_state.actions().to_owned(),
+            )]),
+            (Service::RenderingControl, "ListPresets") => Ok(vec![(
+                "CurrentPresetNameList",
+                "FactoryDefaults".to_owned(),
+            )]),
+            (Service::RenderingControl, "SelectPreset") => {
+                if argument("PresetName")? != "FactoryDefaults" {
+                    return Err(UpnpError::InvalidArgs);
+                }
+                Ok(Vec::new())
+            }
+            (Service::RenderingControl, "GetVolume") => Ok(vec![(
+                "CurrentVolume",
+                self.renderer.read().volume_percent().to_string(),
+            )]),
+            (Service::RenderingControl, "SetVolume") => {
+                let percent = argument("DesiredVolume")?
+                    .trim()
+                    .parse::<u32>()
+                    .map_err(|_| UpnpError::InvalidArgs)?
+                    .min(100);
+                let volume = percent as f64 / 100.0;
+                {
+                    let mut renderer = self.renderer.write();
+                    renderer.volume = volume;
+                    renderer.muted_volume = None;
+                }
+                self.operation(crate::Operation::SetVolume(volume as f32));
+                self.notify(Service::RenderingControl);
+                Ok(Vec::new())
+            }
+            (Service::RenderingControl, "GetMute") => {
+                let muted = self.renderer.read().muted_volume.is_some();
+                Ok(vec![(
+                    "CurrentMute",
+                    if muted { "1" } else { "0" }.to_owned(),
+                )])
+            }
+            (Service::RenderingControl, "SetMute") => {
+                let mute = match argument("DesiredMute")?.trim() {
+                    "1" | "true" | "True" | "yes" => true,
+                    "0" | "false" | "False" | "no" => false,
+                    _ => return Err(UpnpError::InvalidArgs),
+                };
+                let volume = {
+                    let mut renderer = self.renderer.write();
+                    match (mute, renderer.muted_volume) {
+                        (true, None) => {
+                            renderer.muted_volume = Some(renderer.volume);
+                            renderer.volume = 0.0;
+                            Some(0.0)
+                        }
+                        (false, Some(volume)) => {
+                            renderer.muted_volume = None;
+                            renderer.volume = volume;
+                            Some(volume)
+                        }
+                        _ => None,
+                    }
+                };
+                if let Some(volume) = volume {
+                    self.operation(crate::Operation::SetVolume(volume as f32));
+                    self.notify(Service::RenderingControl);
+                }
+                Ok(Vec::new())
+            }
+            (Service::ConnectionManager, "GetProtocolInfo") => Ok(vec![
+                ("Source", String::new()),
+                ("Sink", sink_protocol_info()),
+            ]),
+            (Service::ConnectionManager, "GetCurrentConnectionIDs") => {
+                Ok(vec![("ConnectionIDs", "0".to_owned())])
+            }
+            (Service::ConnectionManager, "GetCurrentConnectionInfo") => {
+                if argument("ConnectionID")?.trim() != "0" {
+                    return Err(UpnpError::InvalidArgs);
+                }
+                Ok(vec![
+                    ("RcsID", "0".to_owned()),
+                    ("AVTransportID", "0".to_owned()),
+                    ("ProtocolInfo", String::new()),
+                    ("PeerConnectionManager", String::new()),
+                    ("PeerConnectionID", "-1".to_owned()),
+                    ("Direction", "Input".to_owned()),
+                    ("Status", "OK".to_owned()),
+                ])
+            }
+            _ => Err(UpnpError::InvalidAction),
+        }
+    }
+
+    /// `SetAVTransportURI`: stage `uri` for `Play`, or switch to it straight
+    /// away when something is playing, as control points expect. An empty URI
+    /// clears the transport.
+    fn set_uri(&self, uri: String, metadata: String) {
+        {
+            let mut renderer = self.renderer.write();
+            let playing = renderer.loaded
+                && matches!(
+                    renderer.transport_state,
+                    TransportState::Playing | TransportState::Transitioning
+                );
+            if uri.is_empty() && renderer.loaded {
+                self.operation(crate::Operation::Stop);
+            }
+            renderer.uri = uri;
+            renderer.metadata = metadata;
+            renderer.loaded = false;
+            renderer.position = 0.0;
+            renderer.duration = 0.0;
+            renderer.transport_state = renderer.stopped_state();
+            if playing && let Some(load) = load_message(&renderer.uri, &renderer.metadata) {
+                renderer.loaded = true;
+                renderer.transport_state = TransportState::Transitioning;
+                self.operation(crate::Operation::PlayNew(
+                    crate::fcast::WrappedPlayMessage::V4(load),
+                ));
+            }
+        }
+        self.notify(Service::AvTransport);
+    }
+
+    fn subscribe(
+        &self,
+        service: Service,
+        request: &Request,
+        peer: IpAddr,
+    ) -> (Response, Option<String>) {
+        let ok = |sid: &str| {
+            Response::new(&request.protocol, 200, "OK")
+                .header("Server", &server_header())
+                .header("SID", sid)
+                .header("TIMEOUT", &format!("Second-{SUBSCRIPTION_TIMEOUT_SECS}"))
+        };
+        let precondition_failed = || Response::new(&request.protocol, 412, "Precondition Failed");
+        let now = Instant::now();
+        let expires = now + Duration::from_secs(SUBSCRIPTION_TIMEOUT_SECS);
+        let mut subscriptions = self.subscriptions.lock();
+        subscriptions.retain(|subscription| subscription.expires > now);
+
+        if let Some(sid) = request.header("SID") {
+            // A renewal.
+            return match subscriptions
+                .iter_mut()
+                .find(|subscription| subscription.sid == sid && subscription.service == service)
+            {
+                Some(subscription) => {
+                    subscription.expires = expires;
+                    (ok(sid), None)
+                }
+                None => (precondition_failed(), None),
+            };
+        }
+
+        let callbacks = parse_callbacks(request.header("CALLBACK").unwrap_or_default(), peer);
+        if callbacks.is_empty() || request.header("NT") != Some("upnp:event") {
+            return (precondition_failed(), None);
+        }
+        if subscriptions.len() >= MAX_SUBSCRIPTIONS {
+            warn!("Too many DLNA event subscriptions, refusing another");
+            return (
+                Response::new(&request.protocol, 503, "Service Unavailable"),
+                None,
+            );
+        }
+        let sid = format!("uuid:{}", uuid::Uuid::new_v4());
+        subscriptions.push(Subscription {
+            sid: sid.clone(),
+            service,
+            callbacks,
+            expires,
+            seq: 0,
+        });
+        (ok(&sid), Some(sid))
+    }
+
+    fn unsubscribe(&self, service: Service, request: &Request) -> Response {
+        let mut subscriptions = self.subscriptions.lock();
+        let before = subscriptions.len();
+        if let Some(sid) = request.header("SID") {
+            subscriptions
+                .retain(|subscription| subscription.sid != sid || subscription.service != service);
+        }
+        if subscriptions.len() == before {
+            return Response::new(&request.protocol, 412, "Precondition Failed");
+        }
+        Response::new(&request.protocol, 200, "OK")
+    }
+
+    fn send_initial_event(&self, sid: &str) {
+        let mut subscriptions = self.subscriptions.lock();
+        let Some(subscription) = subscriptions
+            .iter_mut()
+            .find(|subscription| subscription.sid == sid)
+        else {
+            return;
+        };
+        let body = self.renderer.read().event_body(subscription.service);
+        tokio::spawn(deliver_event(
+            subscription.callbacks.clone(),
+            subscription.sid.clone(),
+            subscription.next_seq(),
+            body,
+        ));
+    }
+}
+
+pub async fn run_server(
+    msg_tx: MessageSender,
+    name: String,
+    exclude_interfaces: Option<String>,
+    mut status_rx: UnboundedReceiver<StatusUpdate>,
+) -> Result<()> {
+    let listener = TcpListener::bind((std::net::Ipv4Addr::UNSPECIFIED, DLNA_TCP_PORT)).await?;
+    let uuid = device_uuid(&name);
+    let context = Context::new(msg_tx, &name, &uuid);
+
+    tokio::spawn(async move {
+        // Without SSDP the renderer can still be added by its description URL.
+        if let Err(err) = ssdp::run(uuid, DLNA_TCP_PORT, exclude_interfaces).await {
+            warn!(?err, "SSDP stopped (port 1900 may be unavailable)");
+        }
+    });
+
+    loop {
+        tokio::select! {
+            res = listener.accept() => {
+                // A failed accept is per-connection and must not end the renderer.
+                let (stream, _addr) = match res {
+                    Ok(accepted) => accepted,
+                    Err(err) => {
+                        warn!(?err, "Failed to accept a DLNA connection");
+                        tokio::time::sleep(crate::application::ACCEPT_ERROR_BACKOFF).await;
+                        continue;
+                    }
+                };
+                let context = context.clone();
+                tokio::spawn(async move {
+                    if let Err(err) = context.serve(stream).await {
+                        debug!(?err, "DLNA connection ended with error");
+                    }
+                });
+            }
+            status_update = status_rx.recv() => {
+                let Some(update) = status_update else {
+                    break;
+                };
+                let changed = context.renderer.write().apply(update);
+                if let Some(service) = changed {
+                    context.notify(service);
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::message::Message;
+
+    /// A control point stand-in: speaks SOAP to a served connection and
+    /// collects the operations it caused.
+    struct ControlPoint {
+        context: Context,
+        client: TcpStream,
+        msg_rx: tokio::sync::mpsc::UnboundedReceiver<Message>,
+    }
+
+    impl ControlPoint {
+        async fn spawn() -> Self {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let addr = listener.local_addr().unwrap();
+            let (client, accepted) = tokio::join!(TcpStream::connect(addr), listener.accept());
+            let (server, _) = accepted.unwrap();
+            let (msg_tx, msg_rx) = tokio::sync::mpsc::unbounded_channel();
+            let context = Context::new(MessageSender::new(msg_tx), "Living Room", "abc");
+            tokio::spawn({
+                let context = context.clone();
+                async move { context.serve(server).await }
+            });
+            Self {
+                context,
+                client: client.unwrap(),
+                msg_rx,
+            }
+        }
+
+        async fn request(&mut self, raw: String) -> (u16, String) {
+            self.client.write_all(raw.as_bytes()).await.unwrap();
+            let read = async {
+                let mut buf = Vec::new();
+                loop {
+                    let mut chunk = [0u8; 4096];
+                    let n = self.client.read(&mut chunk).await.unwrap();
+                    assert_ne!(n, 0, "connection closed before a response arrived");
+                    buf.extend_from_slice(&chunk[..n]);
+                    let text = String::from_utf8_lossy(&buf).into_owned();
+                    let Some((head, body)) = text.split_once("\r\n\r\n") else {
+                        continue;
+                    };
+                    let length: usize = head
+                        .lines()
+                        .find_map(|line| line.strip_prefix("Content-Length: "))
+                        .map_or(0, |length| length.parse().unwrap());
+                    if body.len() >= length {
+                        let status = head[9..12].parse().unwrap();
+                        return (status, body.to_owned());
+                    }
+                }
+            };
+            tokio::time::timeout(Duration::from_secs(5), read)
+                .await
+                .expect("timed out waiting for a response")
+        }
+
+        async fn call(&mut self, service: Service, action: &str, arguments: &str) -> (u16, String) {
+            let body = soap_envelope(&format!(
+                "<u:{action} xmlns:u=\"{}\">{arguments}</u:{action}>",
+                service.service_type()
+            ));
+            self.request(format!(
+                "POST /{}/control HTTP/1.1\r\n\
+                 Content-Type: text/xml; charset=\"utf-8\"\r\n\
+                 SOAPACTION: \"{}#{action}\"\r\n\
+                 Content-Length: {}\r\n\r\n{body}",
+                service.name(),
+                service.service_type(),
+                body.len(),
+            ))
+            .await
+        }
+
+        fn operations(&mut self) -> Vec<crate::Operation> {
+            std::iter::from_fn(|| self.msg_rx.try_recv().ok())
+                .filter_map(|msg| match msg {
+                    Message::Op { op, .. } => Some(op),
+                    _ => None,
+                })
+                .collect()
+        }
+    }
+
+    const INSTANCE: &str = "<InstanceID>0</InstanceID>";
+
+    #[tokio::test]
+    async fn the_description_lists_every_service() {
+        let mut control_point = ControlPoint::spawn().await;
+        let (status, body) = control_point
+            .request("GET /description.xml HTTP/1.1\r\nHost: x\r\n\r\n".to_owned())
+            .await;
+        assert_eq!(status, 200);
+        assert_eq!(
+            xml::element_text(&body, "friendlyName").as_deref(),
+            Some("Living Room")
+        );
+        assert_eq!(xml::element_text(&body, "UDN").as_deref(), Some("uuid:abc"));
+        for service in Service::ALL {
+            assert!(body.contains(&format!(
+                "<controlURL>/{}/control</controlURL>",
+                service.name()
+            )));
+        }
+
+        let (status, body) = control_point
+            .request("GET /AVTransport/scpd.xml HTTP/1.1\r\n\r\n".to_owned())
+            .await;
+        assert_eq!(status, 200);
+        assert!(body.contains("<name>SetAVTransportURI</name>"));
+        let (status, _) = control_point
+            .request("GET /Nothing/scpd.xml HTTP/1.1\r\n\r\n".to_owned())
+            .await;
+        assert_eq!(status, 404);
+    }
+
+    #[tokio::test]
+    async fn set_uri_then_play_loads_the_item() {
+        let mut control_point = ControlPoint::spawn().await;
+        let metadata = xml::escape(
+            "<DIDL-Lite><item><dc:title>Big Buck Bunny</dc:title>\
+             <res protocolInfo=\"http-get:*:video/x-matroska:*\">http://nas/bbb.mkv</res>\
+             </item></DIDL-Lite>",
+        );
+        let (status, _) = control_point
+            .call(
+                Service::AvTransport,
+                "SetAVTransportURI",
+                &format!(
+                    "{INSTANCE}<CurrentURI>http://nas/bbb.mkv</CurrentURI>\
+                     <CurrentURIMetaData>{metadata}</CurrentURIMetaData>"
+                ),
+            )
+            .await;
+        assert_eq!(status, 200);
+        // Staged, not played.
+        assert!(control_point.operations().is_empty());
+        let (_, body) = control_point
+            .call(Service::AvTransport, "GetTransportInfo", INSTANCE)
+            .await;
+        assert_eq!(
+            xml::element_text(&body, "CurrentTransportState").as_deref(),
+            Some("STOPPED")
+        );
+
+        let (status, _) = control_point
+            .call(
+                Service::AvTransport,
+                "Play",
+                &format!("{INSTANCE}<Speed>1</Speed>"),
+            )
+            .await;
+        assert_eq!(status, 200);
+        let operations = control_point.operations();
+        let [crate::Operation::PlayNew(crate::fcast::WrappedPlayMessage::V4(load))] =
+            operations.as_slice()
+        else {
+            panic!("expected a load, got {operations:?}");
+        };
+        let single = load.borrow_dependent().source_as_single().unwrap();
+        assert_eq!(single.source_url(), "http://nas/bbb.mkv");
+        assert_eq!(single.container(), "video/x-matroska");
+        assert_eq!(single.title(), Some("Big Buck Bunny"));
+
+        // Loaded, so another Play resumes.
+        control_point
+            .call(
+                Service::AvTransport,
+                "Play",
+                &format!("{INSTANCE}<Speed>1</Speed>"),
+            )
+            .await;
+        assert_eq!(control_point.operations(), [crate::Operation::Resume]);
+    }
+
+    #[tokio::test]
+    async fn transport_actions_map_onto_operations() {
+        let mut control_point = ControlPoint::spawn().await;
+        control_point
+            .context
+            .renderer
+            .write()
+            .apply(StatusUpdate::Media(crate::gcast::MediaDescription {
+                container: "video/mp4".to_owned(),
+                url: "http://host/a.mp4".to_owned(),
+                title: None,
+                thumbnail_url: None,
+            }));
+
+        control_point
+            .call(Service::AvTransport, "Pause", INSTANCE)
+            .await;
+        let (status, _) = control_point
+            .call(
+                Service::AvTransport,
+                "Seek",
+                &format!("{INSTANCE}<Unit>REL_TIME</Unit><Target>0:01:30.500</Target>"),
+            )
+            .await;
+        assert_eq!(status, 200);
+        control_point
+            .call(
+                Service::RenderingControl,
+                "SetVolume",
+                &format!("{INSTANCE}<Channel>Master</Channel><DesiredVolume>40</DesiredVolume>"),
+            )
+            .await;
+        control_point
+            .call(Service::AvTransport, "Stop", INSTANCE)
+            .await;
+        assert_eq!(
+            control_point.operations(),
+            [
+                crate::Operation::Pause,
+                crate::Operation::Seek(gst::ClockTime::from_mseconds(90_500)),
+                crate::Operation::SetVolume(0.4),
+                crate::Operation::Stop,
+            ]
+        );
+
+        let (_, body) = control_point
+            .call(
+                Service::RenderingControl,
+                "GetVolume",
+                &format!("{INSTANCE}<Channel>Master</Channel>"),
+            )
+            .await;
+        assert_eq!(
+            xml::element_text(&body, "CurrentVolume").as_deref(),
+            Some("40")
+        );
+    }
+
+    #[tokio::test]
+    async fn bad_requests_get_upnp_errors() {
+        let mut control_point = ControlPoint::spawn().await;
+        let error_code = |body: &str| xml::element_text(body, "errorCode");
+
+        // Nothing to play yet.
+        let (status, body) = control_point
+            .call(Service::AvTransport, "Play", INSTANCE)
+            .await;
+        assert_eq!(status, 500);
+        assert_eq!(error_code(&body).as_deref(), Some("701"));
+
+        let (_, body) = control_point
+            .call(Service::AvTransport, "Pause", "<InstanceID>3</InstanceID>")
+            .await;
+        assert_eq!(error_code(&body).as_deref(), Some("718"));
+
+        let (_, body) = control_point
+            .call(
+                Service::AvTransport,
+                "Seek",
+                &format!("{INSTANCE}<Unit>TRACK_NR</Unit><Target>2</Target>"),
+            )
+            .await;
+        assert_eq!(error_code(&body).as_deref(), Some("710"));
+
+        let (_, body) = control_point
+            .call(Service::AvTransport, "Record", INSTANCE)
+            .await;
+        assert_eq!(error_code(&body).as_deref(), Some("401"));
+        assert!(control_point.operations().is_empty());
+    }
+
+    #[tokio::test]
+    async fn mute_remembers_the_volume() {
+        let mut control_point = ControlPoint::spawn().await;
+        control_point
+            .context
+            .renderer
+            .write()
+            .apply(StatusUpdate::Volume(0.7));
+        let set_mute =
+            |mute| format!("{INSTANCE}<Channel>Master</Channel><DesiredMute>{mute}</DesiredMute>");
+        control_point
+            .call(Service::RenderingControl, "SetMute", &set_mute(1))
+            .await;
+        control_point
+            .call(Service::RenderingControl, "SetMute", &set_mute(0))
+            .await;
+        assert_eq!(
+            control_point.operations(),
+            [
+                crate::Operation::SetVolume(0.0),
+                crate::Operation::SetVolume(0.7),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn subscriptions_need_a_callback_and_can_be_cancelled() {
+        let mut control_point = ControlPoint::spawn().await;
+        let (status, _) = control_point
+            .request("SUBSCRIBE /AVTransport/event HTTP/1.1\r\nNT: upnp:event\r\n\r\n".to_owned())
+            .await;
+        assert_eq!(status, 412);
+
+        let (status, _) = control_point
+            .request(
+                "SUBSCRIBE /AVTransport/event HTTP/1.1\r\n\
+                 CALLBACK: <http://127.0.0.1:9/events>\r\n\
+                 NT: upnp:event\r\n\
+                 TIMEOUT: Second-300\r\n\r\n"
+                    .to_owned(),
+            )
+            .await;
+        assert_eq!(status, 200);
+        let sid = control_point.context.subscriptions.lock()[0].sid.clone();
+
+        let (status, _) = control_point
+            .request(format!(
+                "UNSUBSCRIBE /AVTransport/event HTTP/1.1\r\nSID: {sid}\r\n\r\n"
+            ))
+            .await;
+        assert_eq!(status, 200);
+        assert!(control_point.context.subscriptions.lock().is_empty());
+    }
+
+    #[tokio::test]
+    async fn events_only_go_back_to_the_subscriber() {
+        let mut control_point = ControlPoint::spawn().await;
+        let subscribe = |callback: &str| {
+            format!(
+                "SUBSCRIBE /AVTransport/event HTTP/1.1\r\n\
+                 CALLBACK: <{callback}>\r\n\
+                 NT: upnp:event\r\n\r\n"
+            )
+        };
+        // Somebody else's server, by address or by name.
+        for callback in ["http://192.0.2.7/events", "http://localhost:9/events"] {
+            let (status, _) = control_point.request(subscribe(callback)).await;
+            assert_eq!(status, 412, "{callback}");
+        }
+        assert!(control_point.context.subscriptions.lock().is_empty());
+
+        for _ in 0..MAX_SUBSCRIPTIONS {
+            let (status, _) = control_point
+                .request(subscribe("http://127.0.0.1:9/events"))
+                .await;
+            assert_eq!(status, 200);
+        }
+        let (status, _) = control_point
+            .request(subscribe("http://127.0.0.1:9/events"))
+            .await;
+        assert_eq!(status, 503);
+
+        // Expired ones make room.
+        control_point.context.subscriptions.lock()[0].expires = Instant::now();
+        let (status, _) = control_point
+            .request(subscribe("http://127.0.0.1:9/events"))
+            .await;
+        assert_eq!(status, 200);
+        assert_eq!(
+            control_point.context.subscriptions.lock().len(),
+            MAX_SUBSCRIPTIONS
+        );
+    }
+
+    #[test]
+    fn callbacks_must_name_the_peer() {
+        let peer: IpAddr = "192.168.1.5".parse().unwrap();
+        assert_eq!(
+            parse_callbacks(
+                "<http://192.168.1.5:8080/a><http://192.168.1.6/b><https://192.168.1.5/c>",
+                peer
+            ),
+            ["http://192.168.1.5:8080/a"]
+        );
+        // A v4 peer accepted on a dual-stack socket.
+        let mapped: IpAddr = "::ffff:192.168.1.5".parse().unwrap();
+        assert_eq!(
+            parse_callbacks("<http://192.168.1.5/a>", mapped),
+            ["http://192.168.1.5/a"]
+        );
+    }
+
+    #[test]
+    fn application_updates_drive_the_transport_state() {
+        let mut renderer = Renderer::default();
+        assert_eq!(
+            renderer.apply(StatusUpdate::Media(crate::gcast::MediaDescription {
+                container: "audio/mpeg".to_owned(),
+                url: "http://host/a.mp3".to_owned(),
+                title: Some("Song".to_owned()),
+                thumbnail_url: None,
+            })),
+            Some(Service::AvTransport)
+        );
+        assert_eq!(
+            DidlItem::parse(&renderer.metadata).title.as_deref(),
+            Some("Song")
+        );
+        renderer.apply(StatusUpdate::PlayerState(crate::PlayerState::Playing));
+        assert_eq!(renderer.transport_state, TransportState::Playing);
+        assert_eq!(renderer.apply(StatusUpdate::Position(12.0)), None);
+        assert_eq!(
+            renderer.apply(StatusUpdate::PlayerState(crate::PlayerState::Playing)),
+            None
+        );
+        renderer.apply(StatusUpdate::Stopped);
+        assert_eq!(renderer.transport_state, TransportState::Stopped);
+        assert!(!renderer.loaded);
+        assert!(
+            renderer
+                .av_transport_last_change()
+                .contains("<TransportState val=\"STOPPED\"/>")
+        );
+    }
+
+    #[test]
+    fn times_parse_and_format() {
+        assert_eq!(parse_time("0:01:30"), Some(90.0));
+        assert_eq!(parse_time("1:00:00.5"), Some(3600.5));
+        assert_eq!(parse_time("0:00:10.1/2"), Some(10.5));
+        assert_eq!(parse_time("0:61:00"), None);
+        assert_eq!(parse_time("90"), None);
+        assert_eq!(parse_time(&format!("{}:00:00", u64::MAX)), None);
+        assert_eq!(parse_time("0:00:NaN"), None);
+        assert_eq!(parse_time("0:00:1.inf/1"), None);
+        assert_eq!(format_time(3725.9), "1:02:05");
+    }
+}
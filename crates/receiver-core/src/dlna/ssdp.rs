@@ -0,0 +1,278 @@
+//! SSDP: answers `M-SEARCH` requests and multicasts `ssdp:alive`
+//! announcements so control points find the renderer's description.
+
+use std::{
+    net::{Ipv4Addr, SocketAddr, SocketAddrV4},
+    time::Duration,
+};
+
+use anyhow::Result;
+use socket2::{Domain, Protocol, Socket, Type};
+use tokio::net::UdpSocket;
+use tracing::{debug, warn};
+
+use super::{DEVICE_TYPE, Service, server_header};
+
+const MULTICAST_ADDR: Ipv4Addr = Ipv4Addr::new(239, 255, 255, 250);
+const PORT: u16 = 1900;
+/// How long control points may cache an announcement.
+const MAX_AGE_SECS: u64 = 1800;
+/// Announcements are repeated well within `MAX_AGE_SECS` so a lost datagram
+/// doesn't drop the renderer from control points' lists. Interfaces that
+/// came up since are picked up at the same time.
+const ANNOUNCE_INTERVAL: Duration = Duration::from_secs(300);
+
+/// An IPv4 interface the renderer is reachable on.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Interface {
+    ip: Ipv4Addr,
+    netmask: Ipv4Addr,
+}
+
+impl Interface {
+    fn contains(&self, peer: Ipv4Addr) -> bool {
+        let mask = u32::from(self.netmask);
+        u32::from(self.ip) & mask == u32::from(peer) & mask
+    }
+}
+
+fn interfaces(exclude: Option<&regex::Regex>) -> Vec<Interface> {
+    let ifaces = match if_addrs::get_if_addrs() {
+        Ok(ifaces) => ifaces,
+        Err(err) => {
+            warn!(?err, "Failed to list network interfaces");
+            return Vec::new();
+        }
+    };
+    ifaces
+        .into_iter()
+        .filter(|iface| !iface.is_loopback())
+        .filter(|iface| exclude.is_none_or(|re| !re.is_match(&iface.name)))
+        .filter_map(|iface| match iface.addr {
+            if_addrs::IfAddr::V4(addr) => Some(Interface {
+                ip: addr.ip,
+                netmask: addr.netmask,
+            }),
+            if_addrs::IfAddr::V6(_) => None,
+        })
+        .collect()
+}
+
+/// The `(NT or ST, USN)` pairs the renderer answers to: the root device, its
+/// UUID, its device type and each service.
+fn targets(uuid: &str) -> Vec<(String, String)> {
+    let udn = format!("uuid:{uuid}");
+    let mut targets = vec![
+        (
+            "upnp:rootdevice".to_owned(),
+            format!("{udn}::upnp:rootdevice"),
+        ),
+        (udn.clone(), udn.clone()),
+        (DEVICE_TYPE.to_owned(), format!("{udn}::{DEVICE_TYPE}")),
+    ];
+    for service in Service::ALL {
+        let service_type = service.service_type();
+        targets.push((service_type.to_owned(), format!("{udn}::{service_type}")));
+    }
+    targets
+}
+
+/// The search target of an `ssdp:discover` request, or `None` for any other
+/// datagram.
+fn parse_search(datagram: &[u8]) -> Option<String> {
+    let text = std::str::from_utf8(datagram).ok()?;
+    let mut lines = text.split("\r\n");
+    if !lines.next()?.starts_with("M-SEARCH * HTTP/1.1") {
+        return None;
+    }
+    let mut discover = false;
+    let mut search_target = None;
+    for line in lines {
+        let Some((name, value)) = line.split_once(':') else {
+            continue;
+        };
+        let value = value.trim();
+        if name.trim().eq_ignore_ascii_case("MAN") {
+            discover = value.trim_matches('"') == "ssdp:discover";
+        } else if name.trim().eq_ignore_ascii_case("ST") {
+            search_target = Some(value.to_owned());
+        }
+    }
+    search_target.filter(|_| discover)
+}
+
+fn location(ip: Ipv4Addr, port: u16) -> String {
+    format!("http://{ip}:{port}/description.xml")
+}
+
+fn search_response(search_target: &str, usn: &str, location: &str) -> String {
+    format!(
+        "HTTP/1.1 200 OK\r\n\
+         CACHE-CONTROL: max-age={MAX_AGE_SECS}\r\n\
+         EXT:\r\n\
+         LOCATION: {location}\r\n\
+         SERVER: {}\r\n\
+         ST: {search_target}\r\n\
+         USN: {usn}\r\n\
+         Content-Length: 0\r\n\r\n",
+        server_header(),
+    )
+}
+
+fn alive_notification(notification_type: &str, usn: &str, location: &str) -> String {
+    format!(
+        "NOTIFY * HTTP/1.1\r\n\
+         HOST: {MULTICAST_ADDR}:{PORT}\r\n\
+         CACHE-CONTROL: max-age={MAX_AGE_SECS}\r\n\
+         LOCATION: {location}\r\n\
+         NT: {notification_type}\r\n\
+         NTS: ssdp:alive\r\n\
+         SERVER: {}\r\n\
+         USN: {usn}\r\n\
+         Content-Length: 0\r\n\r\n",
+        server_header(),
+    )
+}
+
+/// The shared multicast socket. Other SSDP stacks on the host (the desktop's
+/// own media server, say) hold the port too, so it's bound reusable.
+fn bind_listener() -> Result<UdpSocket> {
+    let socket = Socket::new(Domain::IPV4, Type::DGRAM, Some(Protocol::UDP))?;
+    socket.set_reuse_address(true)?;
+    socket.set_nonblocking(true)?;
+    socket.bind(&SocketAddr::from((Ipv4Addr::UNSPECIFIED, PORT)).into())?;
+    Ok(UdpSocket::from_std(socket.into())?)
+}
+
+/// Multicast the announcements out of `iface`, from its own address so the
+/// advertised location is one the receiving network can reach.
+async fn announce(iface: Interface, uuid: &str, http_port: u16) -> Result<()> {
+    let socket = Socket::new(Domain::IPV4, Type::DGRAM, Some(Protocol::UDP))?;
+    socket.set_nonblocking(true)?;
+    socket.set_multicast_if_v4(&iface.ip)?;
+    socket.set_multicast_ttl_v4(2)?;
+    socket.bind(&SocketAddr::from((iface.ip, 0)).into())?;
+    let socket = UdpSocket::from_std(socket.into())?;
+    let location = location(iface.ip, http_port);
+    for (notification_type, usn) in targets(uuid) {
+        let message = alive_notification(&notification_type, &usn, &location);
+        socket
+            .send_to(message.as_bytes(), SocketAddrV4::new(MULTICAST_ADDR, PORT))
+            .await?;
+    }
+    Ok(())
+}
+
+pub async fn run(uuid: String, http_port: u16, exclude_interfaces: Option<String>) -> Result<()> {
+    let exclude = exclude_interfaces.and_then(|pattern| match regex::Regex::new(&pattern) {
+        Ok(re) => Some(re),
+        Err(err) => {
+            warn!(
+                ?err,
+                "Invalid exclude_interfaces, advertising DLNA everywhere"
+            );
+            None
+        }
+    });
+    let listener = bind_listener()?;
+    let mut joined: Vec<Ipv4Addr> = Vec::new();
+    let mut ifaces = Vec::new();
+    let mut announce_timer = tokio::time::interval(ANNOUNCE_INTERVAL);
+    let mut buf = [0u8; 2048];
+
+    loop {
+        tokio::select! {
+            _ = announce_timer.tick() => {
+                ifaces = interfaces(exclude.as_ref());
+                for iface in &ifaces {
+                    if !joined.contains(&iface.ip) {
+                        match listener.join_multicast_v4(MULTICAST_ADDR, iface.ip) {
+                            Ok(()) => joined.push(iface.ip),
+                            Err(err) => debug!(?err, ip = %iface.ip, "Failed to join the SSDP group"),
+                        }
+                    }
+                    if let Err(err) = announce(*iface, &uuid, http_port).await {
+                        debug!(?err, ip = %iface.ip, "Failed to announce over SSDP");
+                    }
+                }
+            }
+            res = listener.recv_from(&mut buf) => {
+                let (len, peer) = match res {
+                    Ok(received) => received,
+                    Err(err) => {
+                        debug!(?err, "Failed to receive an SSDP datagram");
+                        continue;
+                    }
+                };
+                let SocketAddr::V4(peer) = peer else {
+                    continue;
+                };
+                let Some(search_target) = parse_search(&buf[..len]) else {
+                    continue;
+                };
+                // Answer with the address on the searcher's network.
+                let Some(iface) = ifaces
+                    .iter()
+                    .find(|iface| iface.contains(*peer.ip()))
+                    .or(ifaces.first())
+                else {
+                    continue;
+                };
+                let location = location(iface.ip, http_port);
+                for (target, usn) in targets(&uuid) {
+                    if search_target != "ssdp:all" && search_target != target {
+                        continue;
+                    }
+                    let response = search_response(&target, &usn, &location);
+                    if let Err(err) = listener.send_to(response.as_bytes(), peer).await {
+                        debug!(?err, %peer, "Failed to answer an SSDP search");
+                        break;
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_discover_searches_are_answered() {
+        let search = b"M-SEARCH * HTTP/1.1\r\n\
+            HOST: 239.255.255.250:1900\r\n\
+            MAN: \"ssdp:discover\"\r\n\
+            MX: 2\r\n\
+            ST: urn:schemas-upnp-org:device:MediaRenderer:1\r\n\r\n";
+        assert_eq!(parse_search(search).as_deref(), Some(DEVICE_TYPE));
+        let notify = b"NOTIFY * HTTP/1.1\r\nNT: upnp:rootdevice\r\nNTS: ssdp:alive\r\n\r\n";
+        assert_eq!(parse_search(notify), None);
+        let no_man = b"M-SEARCH * HTTP/1.1\r\nST: ssdp:all\r\n\r\n";
+        assert_eq!(parse_search(no_man), None);
+    }
+
+    #[test]
+    fn every_service_is_advertised_under_the_device() {
+        let targets = targets("abc");
+        assert_eq!(targets.len(), 3 + Service::ALL.len());
+        assert!(targets.contains(&(
+            "upnp:rootdevice".to_owned(),
+            "uuid:abc::upnp:rootdevice".to_owned()
+        )));
+        assert!(targets.contains(&(
+            "urn:schemas-upnp-org:service:AVTransport:1".to_owned(),
+            "uuid:abc::urn:schemas-upnp-org:service:AVTransport:1".to_owned()
+        )));
+    }
+
+    #[test]
+    fn searches_are_answered_from_the_searchers_network() {
+        let iface = Interface {
+            ip: Ipv4Addr::new(192, 168, 1, 20),
+            netmask: Ipv4Addr::new(255, 255, 255, 0),
+        };
+        assert!(iface.contains(Ipv4Addr::new(192, 168, 1, 77)));
+        assert!(!iface.contains(Ipv4Addr::new(10, 0, 0, 77)));
+    }
+}
@@ -0,0 +1,199 @@
+//! Just enough XML for UPnP: SOAP arguments and DIDL-Lite fields are looked
+//! up by element name, ignoring namespace prefixes, and what the renderer
+//! writes is built from escaped strings. Control points send flat, small
+//! documents, so there is no tree to build.
+
+/// Escape `text` for an element body or a quoted attribute value.
+pub fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Resolve the predefined entities and character references. An unknown
+/// entity is kept as written.
+pub fn unescape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let Some(end) = rest.find(';') else {
+            break;
+        };
+        let entity = &rest[1..end];
+        let resolved = match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ => entity
+                .strip_prefix("#x")
+                .or_else(|| entity.strip_prefix("#X"))
+                .map(|hex| u32::from_str_radix(hex, 16))
+                .or_else(|| entity.strip_prefix('#').map(str::parse))
+                .and_then(Result::ok)
+                .and_then(char::from_u32),
+        };
+        match resolved {
+            Some(c) => {
+                out.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// A start tag found by [`find_element`].
+struct StartTag<'a> {
+    /// The name as written, prefix included, to find the matching end tag.
+    qualified_name: &'a str,
+    attributes: &'a str,
+    self_closing: bool,
+    /// Where the element's content starts.
+    content_start: usize,
+}
+
+/// The first start tag whose local name is `name`.
+fn find_element<'a>(xml: &'a str, name: &str) -> Option<StartTag<'a>> {
+    let mut offset = 0;
+    while let Some(found) = xml[offset..].find('<') {
+        let tag_start = offset + found + 1;
+        let tag_end = tag_start + xml[tag_start..].find('>')?;
+        offset = tag_end + 1;
+        let tag = &xml[tag_start..tag_end];
+        if tag.starts_with(['/', '?', '!']) {
+            continue;
+        }
+        let (tag, self_closing) = match tag.strip_suffix('/') {
+            Some(tag) => (tag, true),
+            None => (tag, false),
+        };
+        let name_end = tag.find(char::is_whitespace).unwrap_or(tag.len());
+        let qualified_name = &tag[..name_end];
+        if local_name(qualified_name) == name {
+            return Some(StartTag {
+                qualified_name,
+                attributes: &tag[name_end..],
+                self_closing,
+                content_start: offset,
+            });
+        }
+    }
+    None
+}
+
+fn local_name(qualified_name: &str) -> &str {
+    qualified_name
+        .rsplit_once(':')
+        .map_or(qualified_name, |(_, local)| local)
+}
+
+/// The text of the first element named `name`, unescaped. Empty for an empty
+/// element, `None` when there is no such element.
+pub fn element_text(xml: &str, name: &str) -> Option<String> {
+    let tag = find_element(xml, name)?;
+    if tag.self_closing {
+        return Some(String::new());
+    }
+    let end_tag = format!("</{}", tag.qualified_name);
+    let content = &xml[tag.content_start..];
+    let content = &content[..content.find(&end_tag)?];
+    match content
+        .trim()
+        .strip_prefix("<![CDATA[")
+        .and_then(|cdata| cdata.strip_suffix("]]>"))
+    {
+        Some(cdata) => Some(cdata.to_owned()),
+        None => Some(unescape(content)),
+    }
+}
+
+/// The value of `attribute` on the first element named `element`, unescaped.
+pub fn element_attribute(xml: &str, element: &str, attribute: &str) -> Option<String> {
+    let tag = find_element(xml, element)?;
+    let mut rest = tag.attributes;
+    while let Some((key, value)) = rest.split_once('=') {
+        let value = value.trim_start();
+        let quote = value.chars().next()?;
+        if quote != '"' && quote != '\'' {
+            return None;
+        }
+        let value = &value[1..];
+        let end = value.find(quote)?;
+        if local_name(key.trim()) == attribute {
+            return Some(unescape(&value[..end]));
+        }
+        rest = &value[end + 1..];
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escaping_round_trips() {
+        let text = r#"Tom & Jerry <"live"> 'n' stuff"#;
+        assert_eq!(unescape(&escape(text)), text);
+        assert_eq!(
+            unescape("&#65;&#x42;&unknown; & done"),
+            "AB&unknown; & done"
+        );
+    }
+
+    #[test]
+    fn elements_are_found_whatever_their_prefix() {
+        let body = r#"<?xml version="1.0"?>
+            <s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/">
+              <s:Body>
+                <u:Seek xmlns:u="urn:schemas-upnp-org:service:AVTransport:1">
+                  <InstanceID>0</InstanceID>
+                  <Unit>REL_TIME</Unit>
+                  <Target>0:01:30</Target>
+                  <Empty/>
+                </u:Seek>
+              </s:Body>
+            </s:Envelope>"#;
+        assert_eq!(element_text(body, "Unit").as_deref(), Some("REL_TIME"));
+        assert_eq!(element_text(body, "Target").as_deref(), Some("0:01:30"));
+        assert_eq!(element_text(body, "Empty").as_deref(), Some(""));
+        assert_eq!(element_text(body, "Speed"), None);
+    }
+
+    #[test]
+    fn didl_fields_are_read_from_escaped_metadata() {
+        let didl = unescape(
+            "&lt;DIDL-Lite&gt;&lt;item id=&quot;1&quot;&gt;\
+             &lt;dc:title&gt;Fish &amp;amp; Chips&lt;/dc:title&gt;\
+             &lt;res protocolInfo=&quot;http-get:*:video/mp4:*&quot; size=&quot;10&quot;&gt;\
+             http://host/a.mp4&lt;/res&gt;&lt;/item&gt;&lt;/DIDL-Lite&gt;",
+        );
+        assert_eq!(
+            element_text(&didl, "title").as_deref(),
+            Some("Fish & Chips")
+        );
+        assert_eq!(
+            element_attribute(&didl, "res", "protocolInfo").as_deref(),
+            Some("http-get:*:video/mp4:*")
+        );
+        assert_eq!(element_attribute(&didl, "res", "duration"), None);
+    }
+}
@@ -1,8 +1,12 @@
 use std::{collections::HashMap, sync::Arc, time::Duration};
 
 use crate::{
-    MessageSender, ReceiverInfo, SenderId, application::PacketOrigin,
-    message::ReceiverToFCastSender, utils::current_time_millis,
+    MessageSender, ReceiverInfo, SenderId,
+    application::PacketOrigin,
+    config::PairedSender,
+    message::{Message, ReceiverToFCastSender},
+    pairing::{self, Pairing},
+    utils::current_time_millis,
 };
 use anyhow::{Context, bail};
 use bitflags::bitflags;
@@ -28,6 +32,11 @@ const TICKS_BEFORE_PING: u32 = 3;
 
 const TLS_UPGRADE_TIMEOUT: Duration = Duration::from_secs(5);
 
+/// The most items a queue can hold, advertised to v4 senders in the
+/// `ReceiverIntroduction`. Senders that don't read it stay within the legacy
+/// 256 item cap.
+pub const MAX_QUEUE_ITEMS: u32 = 1 << 16;
+
 #[derive(Debug, PartialEq)]
 pub struct Header {
     pub size: u32,
@@ -260,6 +269,9 @@ pub enum V4Message {
     TracksAvailable {
         serialized_msg: fcast_protocol::v4::ConstructedMessage<'static>,
     },
+    ChaptersAvailable {
+        serialized_msg: fcast_protocol::v4::ConstructedMessage<'static>,
+    },
     TracksSelected(Vec<fcast_protocol::v4::ConstructedMessage<'static>>),
 }
 
@@ -281,7 +293,7 @@ pub enum ReceiverToSenderMessage {
 }
 
 #[derive(Debug, thiserror::Error, PartialEq)]
-enum StateError {
+pub(crate) enum StateError {
     #[error("body is not valid UTF-8")]
     BodyIsNotUtf8,
     #[error("invalid json")]
@@ -346,6 +358,16 @@ impl std::fmt::Debug for FlatLoadMessage {
     }
 }
 
+impl FlatLoadMessage {
+    /// Wrap a serialized `Load` packet.
+    pub(crate) fn from_packet(body: Vec<u8>) -> Result<Self, StateError> {
+        Self::try_new(body, |buf| {
+            let packet = v4::flat::root_as_packet(buf)?;
+            packet.payload_as_load().ok_or(StateError::InvalidUnionType)
+        })
+    }
+}
+
 #[allow(clippy::large_enum_variant)]
 #[derive(Debug, PartialEq)]
 pub enum WrappedPlayMessage {
@@ -362,6 +384,14 @@ pub enum Operation {
     Stop,
     PlayNew(WrappedPlayMessage),
     Seek(gst::ClockTime),
+    /// Seek by an offset from the current position.
+    SeekRelative(gst::Signed<gst::ClockTime>),
+    /// Step one frame while paused.
+    FrameStep {
+        forward: bool,
+    },
+    /// Jump to a chapter of the current item.
+    SeekToChapter(v4::ChapterTarget),
     SetSpeed(f32),
     SetPlaylistItem(v3::SetPlaylistItemMessage),
     SetVolume(f32),
@@ -381,11 +411,66 @@ pub enum Operation {
     },
     SelectQueueItem(v4::QueuePosition),
     RemoveQueueItem(v4::QueuePosition),
+    MoveQueueItem {
+        from: v4::QueuePosition,
+        to: v4::QueuePosition,
+    },
     InsertQueueItem(QueueInsertCell),
+    InsertQueueItems(QueueInsertBatchCell),
+    SetQueueMode(QueueMode),
+    SetSleepTimer(SleepTimerSetting),
+    SetSubtitleStyle(crate::subtitle_style::StyleUpdate),
+    SetDelay(crate::av_delay::DelayUpdate),
+    /// Answer the sender with at most `limit` history entries, all of them
+    /// when zero.
+    GetPlaybackHistory {
+        request_id: u32,
+        limit: u32,
+    },
     ResumeOrPause,
     SetProgressUpdateInterval(Duration),
 }
 
+/// How autoplay walks a queue (`QueueModeChanged`).
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct QueueMode {
+    pub repeat: v4::flat::RepeatMode,
+    pub shuffle: bool,
+}
+
+/// A sleep timer as carried by `SleepTimerChanged`.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum SleepTimerSetting {
+    #[default]
+    Off,
+    EndOfItem {
+        fade: Duration,
+    },
+    Deadline {
+        /// Until playback stops, counted from when the setting was made.
+        remaining: Duration,
+        fade: Duration,
+    },
+}
+
+impl SleepTimerSetting {
+    pub fn message(self) -> v4::ConstructedMessage<'static> {
+        let time = |duration: Duration| Some(v4::flat::Time::new(duration.as_micros() as u64));
+        let builder = v4::MessageBuilder::new();
+        match self {
+            Self::Off => builder.sleep_timer_changed(v4::flat::SleepTimerMode::Off, None, None),
+            Self::EndOfItem { fade } => {
+                builder.sleep_timer_changed(v4::flat::SleepTimerMode::EndOfItem, None, time(fade))
+            }
+            Self::Deadline { remaining, fade } => builder.sleep_timer_changed(
+                v4::flat::SleepTimerMode::Deadline,
+                time(remaining),
+                time(fade),
+            ),
+        }
+    }
+}
+
 fn round_progress_interval(micros: u64) -> Duration {
     const STEP_MICROS: u64 = 100_000;
     let steps = ((micros + STEP_MICROS / 2) / STEP_MICROS).max(1);
@@ -404,6 +489,42 @@ self_cell::self_cell!(
     impl {Debug, PartialEq}
 );
 
+impl QueueInsertCell {
+    /// Wrap a serialized `QueueInsert` packet.
+    pub(crate) fn from_packet(body: Vec<u8>) -> Result<Self, StateError> {
+        Self::try_new(body, |buf| {
+            let packet = v4::flat::root_as_packet(buf)?;
+            packet
+                .payload_as_queue_insert()
+                .ok_or(StateError::InvalidUnionType)
+        })
+    }
+}
+
+use v4::flat::QueueInsertBatch as FlatQueueInsertBatch;
+
+self_cell::self_cell!(
+    pub struct QueueInsertBatchCell {
+        owner: Vec<u8>,
+        #[covariant]
+        dependent: FlatQueueInsertBatch,
+    }
+
+    impl {Debug, PartialEq}
+);
+
+impl QueueInsertBatchCell {
+    /// Wrap a serialized `QueueInsertBatch` packet.
+    pub(crate) fn from_packet(body: Vec<u8>) -> Result<Self, StateError> {
+        Self::try_new(body, |buf| {
+            let packet = v4::flat::root_as_packet(buf)?;
+            packet
+                .payload_as_queue_insert_batch()
+                .ok_or(StateError::InvalidUnionType)
+        })
+    }
+}
+
 #[derive(Debug, PartialEq)]
 enum CompanionResponse {
     ResourceInfo(ResourceInfoResponseCell),
@@ -442,6 +563,32 @@ enum Action {
     Error {
         kind: v4::flat::ErrorKind,
     },
+    /// Show `pin` on the receiver and tell the sender it has to pair.
+    RequestPairing {
+        sender_name: Option<String>,
+        pin: String,
+    },
+    /// The sender is paired: hand it `token` and catch it up on the receiver's
+    /// state. `remember` is set when the token was just issued.
+    Paired {
+        token: String,
+        remember: Option<PairedSender>,
+    },
+    /// An authorized sender introduced itself.
+    Introduced,
+}
+
+/// Where a v4 session is in pairing. Sessions on a receiver that does not
+/// require pairing start out `Paired`.
+#[derive(Debug, PartialEq)]
+enum Authorization {
+    Paired,
+    Unpaired {
+        sender_name: Option<String>,
+        /// The PIN on screen for this sender, once it has introduced itself.
+        pin: Option<String>,
+        failed_attempts: u8,
+    },
 }
 
 #[derive(Debug, PartialEq)]
@@ -551,6 +698,10 @@ struct State {
     media_item_events: MediaItemEventFlags,
     key_name_events_down: KeyEventFlags,
     key_name_events_up: KeyEventFlags,
+    pairing: Arc<Pairing>,
+    authorization: Authorization,
+    /// The display name from the sender's introduction.
+    sender_name: Option<String>,
 }
 
 macro_rules! stringify {
@@ -567,6 +718,10 @@ macro_rules! stringify {
 
 impl State {
     pub fn new() -> Self {
+        Self::with_pairing(Arc::default())
+    }
+
+    pub fn with_pairing(pairing: Arc<Pairing>) -> Self {
         Self {
             time: 0,
             last_packet_received: 0,
@@ -575,9 +730,16 @@ impl State {
             media_item_events: MediaItemEventFlags::empty(),
             key_name_events_down: KeyEventFlags::empty(),
             key_name_events_up: KeyEventFlags::empty(),
+            pairing,
+            authorization: Authorization::Paired,
+            sender_name: None,
         }
     }
 
+    fn is_authorized(&self) -> bool {
+        self.authorization == Authorization::Paired
+    }
+
     fn handle_packet_uninit(
         &mut self,
         opcode: Opcode,
@@ -591,6 +753,10 @@ impl State {
             | Opcode::Stop
             | Opcode::Seek
             | Opcode::SetVolume => {
+                if self.pairing.required() {
+                    warn!("Refusing v1 sender, pairing is required");
+                    return Ok(Action::EndSession);
+                }
                 self.variant = StateVariant::Active {
                     version: SessionVersion::V1,
                 };
@@ -614,6 +780,10 @@ impl State {
                         return Ok(Action::UpgradeToTls);
                     }
                 };
+                if self.pairing.required() {
+                    warn!(?version, "Refusing legacy sender, pairing is required");
+                    return Ok(Action::EndSession);
+                }
                 self.variant = StateVariant::Active { version };
                 if version == SessionVersion::V3 {
                     Action::SendInitial
@@ -780,12 +950,15 @@ impl State {
 
         macro_rules! get_queue_position {
             ($msg:expr) => {
-                match $msg.position_type() {
+                get_queue_position!($msg, position_type, position_as_index)
+            };
+            ($msg:expr, $typ:ident, $as_index:ident) => {
+                match $msg.$typ() {
                     v4::flat::QueuePosition::Back => v4::QueuePosition::Back,
                     v4::flat::QueuePosition::Front => v4::QueuePosition::Front,
                     v4::flat::QueuePosition::Index => {
-                        let pos = union!($msg.position_as_index());
-                        v4::QueuePosition::Index(pos.index())
+                        let pos = union!($msg.$as_index());
+                        v4::QueuePosition::Index(v4::read_queue_index(&pos))
                     }
                     _ => return Err(StateError::InvalidBody),
                 }
@@ -793,6 +966,18 @@ impl State {
         }
 
         let packet = v4::flat::root_as_packet(body)?;
+        if !self.is_authorized()
+            && !matches!(
+                packet.payload_type(),
+                v4::flat::Message::SenderIntroduction | v4::flat::Message::PairingRequest
+            )
+        {
+            debug!(payload_type = ?packet.payload_type(), "Refusing message from unpaired sender");
+            return Ok(Action::Error {
+                kind: v4::flat::ErrorKind::Unauthorized,
+            });
+        }
+
         let action = match packet.payload_type() {
             v4::flat::Message::ProgressChanged => {
                 if let Some(pos) = union!(packet.payload_as_progress_changed()).position() {
@@ -821,18 +1006,18 @@ impl State {
                 union!(packet.payload_as_speed_changed()).speed(),
             )),
             v4::flat::Message::SenderIntroduction => {
-                // TODO: do something with the payload
-                debug!("Got inital sender message");
-                Action::None
+                let msg = union!(packet.payload_as_sender_introduction());
+                debug!(device_info = ?msg.device_info(), "Got sender introduction");
+                self.on_sender_introduction(
+                    msg.device_info().display_name().map(str::to_owned),
+                    msg.pairing_token(),
+                )
+            }
+            v4::flat::Message::PairingRequest => {
+                self.on_pairing_request(union!(packet.payload_as_pairing_request()).pin())
             }
             v4::flat::Message::Load => Action::Op(Operation::PlayNew(WrappedPlayMessage::V4(
-                FlatLoadMessage::try_new(body.to_owned(), |buf| {
-                    let packet = v4::flat::root_as_packet(&buf)?;
-                    match packet.payload_as_load() {
-                        Some(p) => Ok(p),
-                        None => Err(StateError::InvalidUnionType),
-                    }
-                })?,
+                FlatLoadMessage::from_packet(body.to_owned())?,
             ))),
             v4::flat::Message::StartMirroringSession => {
                 let msg = union!(packet.payload_as_start_mirroring_session());
@@ -924,11 +1109,143 @@ impl State {
                 })?;
                 Action::Op(Operation::InsertQueueItem(insert))
             }
+            v4::flat::Message::QueueInsertBatch => {
+                let batch = QueueInsertBatchCell::try_new(body.to_owned(), |buf| {
+                    let packet = v4::flat::root_as_packet(&buf)?;
+                    match packet.payload_as_queue_insert_batch() {
+                        Some(p) => Ok(p),
+                        None => Err(StateError::InvalidUnionType),
+                    }
+                })?;
+                Action::Op(Operation::InsertQueueItems(batch))
+            }
             v4::flat::Message::QueueRemove => {
                 let msg = union!(packet.payload_as_queue_remove());
                 let position = get_queue_position!(msg);
                 Action::Op(Operation::RemoveQueueItem(position))
             }
+            v4::flat::Message::QueueModeChanged => {
+                let msg = union!(packet.payload_as_queue_mode_changed());
+                match msg.repeat() {
+                    repeat @ (v4::flat::RepeatMode::Off
+                    | v4::flat::RepeatMode::One
+                    | v4::flat::RepeatMode::All) => {
+                        Action::Op(Operation::SetQueueMode(QueueMode {
+                            repeat,
+                            shuffle: msg.shuffle(),
+                        }))
+                    }
+                    _ => Action::Error {
+                        kind: v4::flat::ErrorKind::MalformedBody,
+                    },
+                }
+            }
+            v4::flat::Message::SleepTimerChanged => {
+                let msg = union!(packet.payload_as_sleep_timer_changed());
+                let fade = msg
+                    .fade()
+                    .map_or(Duration::ZERO, |fade| Duration::from_micros(fade.micros()));
+                match (msg.mode(), msg.remaining()) {
+                    (v4::flat::SleepTimerMode::Off, _) => {
+                        Action::Op(Operation::SetSleepTimer(SleepTimerSetting::Off))
+                    }
+                    (v4::flat::SleepTimerMode::EndOfItem, _) => {
+                        Action::Op(Operation::SetSleepTimer(SleepTimerSetting::EndOfItem {
+                            fade,
+                        }))
+                    }
+                    (v4::flat::SleepTimerMode::Deadline, Some(remaining)) => {
+                        Action::Op(Operation::SetSleepTimer(SleepTimerSetting::Deadline {
+                            remaining: Duration::from_micros(remaining.micros()),
+                            fade,
+                        }))
+                    }
+                    _ => Action::Error {
+                        kind: v4::flat::ErrorKind::MalformedBody,
+                    },
+                }
+            }
+            v4::flat::Message::SubtitleStyleChanged => {
+                let msg = union!(packet.payload_as_subtitle_style_changed());
+                let update = crate::subtitle_style::StyleUpdate {
+                    size: msg.size(),
+                    position: msg.position(),
+                    background: msg.background(),
+                };
+                if update.size.is_some_and(|v| !v.is_finite())
+                    || update.position.is_some_and(|v| !v.is_finite())
+                {
+                    Action::Error {
+                        kind: v4::flat::ErrorKind::MalformedBody,
+                    }
+                } else {
+                    Action::Op(Operation::SetSubtitleStyle(update))
+                }
+            }
+            v4::flat::Message::DelayChanged => {
+                let msg = union!(packet.payload_as_delay_changed());
+                Action::Op(Operation::SetDelay(crate::av_delay::DelayUpdate {
+                    subtitle_micros: msg.subtitle_micros(),
+                    audio_micros: msg.audio_micros(),
+                }))
+            }
+            v4::flat::Message::PlaybackHistoryRequest => {
+                let msg = union!(packet.payload_as_playback_history_request());
+                Action::Op(Operation::GetPlaybackHistory {
+                    request_id: msg.request_id(),
+                    limit: msg.limit(),
+                })
+            }
+            v4::flat::Message::QueueMove => {
+                let msg = union!(packet.payload_as_queue_move());
+                let from = get_queue_position!(msg, from_type, from_as_index);
+                let to = get_queue_position!(msg, to_type, to_as_index);
+                Action::Op(Operation::MoveQueueItem { from, to })
+            }
+            v4::flat::Message::SeekRelative => {
+                let offset = union!(packet.payload_as_seek_relative()).offset_micros();
+                // Same overflow guard as `ProgressChanged`, on the magnitude.
+                match offset.unsigned_abs().checked_mul(1000) {
+                    Some(nanos) => {
+                        let magnitude = gst::ClockTime::from_nseconds(nanos);
+                        Action::Op(Operation::SeekRelative(if offset < 0 {
+                            gst::Signed::Negative(magnitude)
+                        } else {
+                            gst::Signed::Positive(magnitude)
+                        }))
+                    }
+                    None => Action::Error {
+                        kind: v4::flat::ErrorKind::MalformedBody,
+                    },
+                }
+            }
+            v4::flat::Message::FrameStep => {
+                match union!(packet.payload_as_frame_step()).direction() {
+                    v4::flat::FrameStepDirection::Forward => {
+                        Action::Op(Operation::FrameStep { forward: true })
+                    }
+                    v4::flat::FrameStepDirection::Backward => {
+                        Action::Op(Operation::FrameStep { forward: false })
+                    }
+                    _ => Action::Error {
+                        kind: v4::flat::ErrorKind::MalformedBody,
+                    },
+                }
+            }
+            v4::flat::Message::SeekToChapter => {
+                let msg = union!(packet.payload_as_seek_to_chapter());
+                let target = match msg.target() {
+                    v4::flat::ChapterTarget::Index => v4::ChapterTarget::Index(msg.index()),
+                    v4::flat::ChapterTarget::Next => v4::ChapterTarget::Next,
+                    v4::flat::ChapterTarget::Previous => v4::ChapterTarget::Previous,
+                    _ => {
+                        return Ok(Action::Error {
+                            kind: v4::flat::ErrorKind::MalformedBody,
+                        });
+                    }
+                };
+                Action::Op(Operation::SeekToChapter(target))
+            }
             v4::flat::Message::SetProgressUpdateInterval => {
                 match union!(packet.payload_as_set_progress_update_interval()).interval() {
                     Some(interval) => Action::Op(Operation::SetProgressUpdateInterval(
@@ -950,6 +1267,96 @@ impl State {
         Ok(action)
     }
 
+    fn on_sender_introduction(
+        &mut self,
+        sender_name: Option<String>,
+        token: Option<&str>,
+    ) -> Action {
+        self.sender_name.clone_from(&sender_name);
+        if self.is_authorized() {
+            return Action::Introduced;
+        }
+
+        if let Some(token) = token
+            && self.pairing.is_paired(token)
+        {
+            debug!("Sender presented a remembered pairing token");
+            self.authorization = Authorization::Paired;
+            return Action::Paired {
+                token: token.to_owned(),
+                remember: None,
+            };
+        }
+
+        // A repeated introduction gets a fresh PIN but keeps the attempt count.
+        let pin = pairing::generate_pin();
+        if let Authorization::Unpaired {
+            sender_name: name,
+            pin: shown,
+            ..
+        } = &mut self.authorization
+        {
+            *name = sender_name.clone();
+            *shown = Some(pin.clone());
+        }
+        Action::RequestPairing { sender_name, pin }
+    }
+
+    fn on_pairing_request(&mut self, attempt: &str) -> Action {
+        let Authorization::Unpaired {
+            sender_name,
+            pin,
+            failed_attempts,
+        } = &mut self.authorization
+        else {
+            return Action::None;
+        };
+
+        let Some(pin) = pin.as_deref() else {
+            // No PIN is shown before the sender introduces itself.
+            return Action::Error {
+                kind: v4::flat::ErrorKind::InvalidState,
+            };
+        };
+
+        // Guessing across reconnects trips the receiver-wide lockout; even the
+        // right PIN is refused until it lifts.
+        if self.pairing.locked_out() {
+            warn!("Refusing a pairing attempt during the lockout");
+            return Action::Error {
+                kind: v4::flat::ErrorKind::Unauthorized,
+            };
+        }
+
+        if attempt != pin {
+            self.pairing.note_failure();
+            *failed_attempts += 1;
+            warn!(
+                failed_attempts = *failed_attempts,
+                "Sender entered a wrong pairing PIN"
+            );
+            return if *failed_attempts >= pairing::MAX_PIN_ATTEMPTS {
+                Action::EndSession
+            } else {
+                Action::Error {
+                    kind: v4::flat::ErrorKind::Unauthorized,
+                }
+            };
+        }
+
+        self.pairing.note_success();
+        let token = pairing::generate_token();
+        let remember = PairedSender {
+            name: sender_name.take(),
+            token_sha256: pairing::hash_token(&token),
+        };
+        self.authorization = Authorization::Paired;
+        Action::Paired {
+            token,
+            remember: Some(remember),
+        }
+    }
+
     #[instrument(skip_all)]
     fn handle_packet_v4(
         &mut self,
@@ -979,6 +1386,13 @@ impl State {
                 mirroring_session_id: None,
             },
         };
+        if self.pairing.required() {
+            self.authorization = Authorization::Unpaired {
+                sender_name: None,
+                pin: None,
+                failed_attempts: 0,
+            };
+        }
     }
 
     #[instrument(skip_all)]
@@ -1106,12 +1520,15 @@ impl State {
                     }
                 }
                 ReceiverToSenderMessage::V4(_) => {
-                    if matches!(
-                        self.variant,
-                        StateVariant::Active {
-                            version: SessionVersion::V4 { .. },
-                        }
-                    ) {
+                    // Unpaired senders don't get to watch either.
+                    if self.is_authorized()
+                        && matches!(
+                            self.variant,
+                            StateVariant::Active {
+                                version: SessionVersion::V4 { .. },
+                            }
+                        )
+                    {
                         Action::Forward {
                             session_version: None,
                             msg,
@@ -1155,6 +1572,7 @@ pub use fcast_gst_elements::fwebrtcsrc::{InternalMessage, MirroringOfferRx};
 pub struct InitialV4State {
     pub play_data: Arc<WrappedPlayMessage>,
     pub playback_state: v4::PlaybackState,
+    pub chapters: Vec<v4::Chapter>,
 }
 
 pub struct SessionDriver {
@@ -1174,6 +1592,14 @@ pub struct SessionDriver {
     /// Volume at accept time, sent once the session activates. Same staleness
     /// caveat as `initial_v4_state`.
     initial_volume: f32,
+    /// Queue mode at accept time, same caveat.
+    initial_queue_mode: QueueMode,
+    /// Sleep timer at accept time, same caveat.
+    initial_sleep_timer: SleepTimerSetting,
+    /// Subtitle style at accept time, same caveat.
+    initial_subtitle_style: crate::config::SubtitlesConfig,
+    /// The playing item's delays at accept time, same caveat.
+    initial_delays: crate::av_delay::Delays,
     pending_tls_upgrade: bool,
 }
 
@@ -1187,11 +1613,16 @@ impl SessionDriver {
         receiver_info: Arc<ReceiverInfo>,
         initial_v4_state: Option<InitialV4State>,
         initial_volume: f32,
+        initial_queue_mode: QueueMode,
+        initial_sleep_timer: SleepTimerSetting,
+        initial_subtitle_style: crate::config::SubtitlesConfig,
+        initial_delays: crate::av_delay::Delays,
+        pairing: Arc<Pairing>,
     ) -> Self {
         Self {
             stream: NetworkStream::new(stream),
             id,
-            state: State::new(),
+            state: State::with_pairing(pairing),
             tls_acceptor,
             companion_ctx,
             internal_companion_tx,
@@ -1201,6 +1632,10 @@ impl SessionDriver {
             receiver_info,
             initial_v4_state,
             initial_volume,
+            initial_queue_mode,
+            initial_sleep_timer,
+            initial_subtitle_style,
+            initial_delays,
             pending_tls_upgrade: false,
         }
     }
@@ -1280,6 +1715,9 @@ impl SessionDriver {
             V4Message::TracksAvailable { serialized_msg } => {
                 return self.send_bin_msg(Opcode::Flatbuf, serialized_msg).await;
             }
+            V4Message::ChaptersAvailable { serialized_msg } => {
+                return self.send_bin_msg(Opcode::Flatbuf, serialized_msg).await;
+            }
             V4Message::Broadcast { serialized_msg } => {
                 return self.send_bin_msg(Opcode::Flatbuf, serialized_msg).await;
             }
@@ -1325,24 +1763,56 @@ impl SessionDriver {
             true,
             true,
             0.01,
+            !self.state.is_authorized(),
+            Some(MAX_QUEUE_ITEMS),
         );
 
         self.send_bin_msg(Opcode::Flatbuf, &msg).await?;
 
+        if self.state.is_authorized() {
+            self.send_initial_v4_state().await?;
+        }
+
+        Ok(())
+    }
+
+    /// Catch a v4 sender up on the receiver's state. Deferred until it has
+    /// paired when pairing is required.
+    async fn send_initial_v4_state(&mut self) -> anyhow::Result<()> {
         // Volume seed: it only broadcasts on change, so a fresh sender would start with
         // a stale level.
         let volume_msg = v4::MessageBuilder::new().volume_changed(self.initial_volume);
         self.send_bin_msg(Opcode::Flatbuf, &volume_msg).await?;
+        let mode_msg = v4::MessageBuilder::new().queue_mode_changed(
+            self.initial_queue_mode.repeat,
+            self.initial_queue_mode.shuffle,
+        );
+        self.send_bin_msg(Opcode::Flatbuf, &mode_msg).await?;
+        let sleep_msg = self.initial_sleep_timer.message();
+        self.send_bin_msg(Opcode::Flatbuf, &sleep_msg).await?;
+        let style_msg = crate::subtitle_style::message(&self.initial_subtitle_style);
+        self.send_bin_msg(Opcode::Flatbuf, &style_msg).await?;
+        let delay_msg = self.initial_delays.message();
+        self.send_bin_msg(Opcode::Flatbuf, &delay_msg).await?;
 
         if let Some(initial) = self.initial_v4_state.take()
             && let WrappedPlayMessage::V4(play_msg) = initial.play_data.as_ref()
         {
             let load = play_msg.borrow_dependent();
-            if let Some(load_msg) = v4::MessageBuilder::new().from_play_stripped(&load) {
+            // An inline source, like an AirPlay photo, can outgrow a packet. The
+            // sender then only hears of the next load.
+            if let Some(load_msg) = v4::MessageBuilder::new().from_play_stripped(&load)
+                && load_msg.len() <= v4::MAX_PACKET_SIZE
+            {
                 self.send_bin_msg(Opcode::Flatbuf, &load_msg).await?;
                 let state_msg =
                     v4::MessageBuilder::new().playback_state_changed(initial.playback_state);
                 self.send_bin_msg(Opcode::Flatbuf, &state_msg).await?;
+                if !initial.chapters.is_empty() {
+                    let chapters_msg =
+                        v4::MessageBuilder::new().chapters_available(&initial.chapters);
+                    self.send_bin_msg(Opcode::Flatbuf, &chapters_msg).await?;
+                }
             }
         }
 
@@ -1350,7 +1820,7 @@ impl SessionDriver {
     }
 
     /// Seed a v1/v2/v3 session with the current volume (v4 gets it in
-    /// `finish_tls_upgrade`); volume only broadcasts on change.
+    /// `send_initial_v4_state`); volume only broadcasts on change.
     async fn send_connect_volume(&mut self) -> anyhow::Result<()> {
         let StateVariant::Active { version } = &self.state.variant else {
             return Ok(());
@@ -1547,6 +2017,36 @@ impl SessionDriver {
                         self.send_v4_error(packet_num, kind).await?;
                     }
                 }
+                Action::RequestPairing { sender_name, pin } => {
+                    msg_tx.send(Message::PairingRequested {
+                        sender: self.id,
+                        sender_name,
+                        pin,
+                    });
+                    if let PacketOrigin::FCast { packet_num, .. } = origin {
+                        self.send_v4_error(packet_num, v4::flat::ErrorKind::Unauthorized)
+                            .await?;
+                    }
+                }
+                Action::Paired { token, remember } => {
+                    let msg = v4::MessageBuilder::new().pairing_result(&token);
+                    self.send_bin_msg(Opcode::Flatbuf, &msg).await?;
+                    if let Some(paired) = remember {
+                        msg_tx.send(Message::SenderPaired {
+                            sender: self.id,
+                            paired,
+                        });
+                    }
+                    msg_tx.send(Message::SenderIntroduced {
+                        sender: self.id,
+                        sender_name: self.state.sender_name.clone(),
+                    });
+                    self.send_initial_v4_state().await?;
+                }
+                Action::Introduced => msg_tx.send(Message::SenderIntroduced {
+                    sender: self.id,
+                    sender_name: self.state.sender_name.clone(),
+                }),
             },
             Err(err) => {
                 error!(?err, "Error occured when advancing state");
@@ -1578,10 +2078,16 @@ impl SessionDriver {
             ReceiverToFCastSender::Error { kind, packet_num } => {
                 self.send_v4_error(packet_num, kind).await?;
             }
+            ReceiverToFCastSender::Reply(msg) => {
+                if self.state.is_authorized() {
+                    self.send_bin_msg(Opcode::Flatbuf, &msg).await?;
+                }
+            }
             ReceiverToFCastSender::ProgressUpdate { pos, dur } => {
                 if let StateVariant::Active {
                     version: SessionVersion::V4 { .. },
                 } = &self.state.variant
+                    && self.state.is_authorized()
                 {
                     self.send_v4_message(&V4Message::ProgressUpdated { pos, dur })
                         .await?;
@@ -1997,6 +2503,163 @@ mod tests {
         );
     }
 
+    fn v4_state_with_pairing(pairing: Arc<Pairing>) -> State {
+        let mut state = State::with_pairing(pairing);
+        state.tls_success();
+        state
+    }
+
+    fn sender_introduction(token: Option<&str>) -> v4::ConstructedMessage<'static> {
+        let info = v4::DeviceInfo {
+            display_name: Some("Phone".to_owned()),
+            app_name: None,
+            app_version: None,
+        };
+        v4::MessageBuilder::new().sender_introduction(&info, token)
+    }
+
+    const UNAUTHORIZED: Result<Action, StateError> = Ok(Action::Error {
+        kind: v4::flat::ErrorKind::Unauthorized,
+    });
+
+    #[test]
+    fn v4_unpaired_sender_is_refused_until_it_enters_the_pin() {
+        let mut state = v4_state_with_pairing(Arc::new(Pairing::new(true, [])));
+        let volume = v4::MessageBuilder::new().volume_changed(0.5);
+        assert_eq!(advance_flatbuf(&mut state, &volume), UNAUTHORIZED);
+
+        let Ok(Action::RequestPairing { sender_name, pin }) =
+            advance_flatbuf(&mut state, &sender_introduction(None))
+        else {
+            panic!("expected a pairing request");
+        };
+        assert_eq!(sender_name.as_deref(), Some("Phone"));
+
+        let wrong = if pin == "000000" { "111111" } else { "000000" };
+        let msg = v4::MessageBuilder::new().pairing_request(wrong);
+        assert_eq!(advance_flatbuf(&mut state, &msg), UNAUTHORIZED);
+
+        let msg = v4::MessageBuilder::new().pairing_request(&pin);
+        let Ok(Action::Paired {
+            token,
+            remember: Some(remember),
+        }) = advance_flatbuf(&mut state, &msg)
+        else {
+            panic!("expected the sender to pair");
+        };
+        assert_eq!(remember.name.as_deref(), Some("Phone"));
+        assert_eq!(remember.token_sha256, pairing::hash_token(&token));
+
+        assert_eq!(
+            advance_flatbuf(&mut state, &volume),
+            Ok(Action::Op(Operation::SetVolume(0.5)))
+        );
+    }
+
+    #[test]
+    fn v4_remembered_token_skips_the_pin() {
+        let token = pairing::generate_token();
+        let mut state =
+            v4_state_with_pairing(Arc::new(Pairing::new(true, [pairing::hash_token(&token)])));
+
+        assert_eq!(
+            advance_flatbuf(&mut state, &sender_introduction(Some(&token))),
+            Ok(Action::Paired {
+                token: token.clone(),
+                remember: None,
+            })
+        );
+
+        // A forgotten token falls back to the PIN.
+        let mut state = v4_state_with_pairing(Arc::new(Pairing::new(true, [])));
+        assert!(matches!(
+            advance_flatbuf(&mut state, &sender_introduction(Some(&token))),
+            Ok(Action::RequestPairing { .. })
+        ));
+    }
+
+    #[test]
+    fn v4_unpaired_sender_does_not_receive_updates() {
+        let mut state = v4_state_with_pairing(Arc::new(Pairing::new(true, [])));
+        let update = Arc::new(ReceiverToSenderMessage::V4(V4Message::VolumeChanged(0.5)));
+        assert_eq!(
+            state.advance(DriverEvent::ToSender(update)),
+            Ok(Action::None)
+        );
+    }
+
+    #[test]
+    fn v4_repeated_wrong_pins_end_the_session() {
+        let mut state = v4_state_with_pairing(Arc::new(Pairing::new(true, [])));
+        let msg = v4::MessageBuilder::new().pairing_request("123456");
+        // No PIN is on screen before the introduction.
+        assert_eq!(
+            advance_flatbuf(&mut state, &msg),
+            Ok(Action::Error {
+                kind: v4::flat::ErrorKind::InvalidState
+            })
+        );
+
+        let Ok(Action::RequestPairing { pin, .. }) =
+            advance_flatbuf(&mut state, &sender_introduction(None))
+        else {
+            panic!("expected a pairing request");
+        };
+        let wrong = if pin == "000000" { "111111" } else { "000000" };
+        let msg = v4::MessageBuilder::new().pairing_request(wrong);
+        for _ in 1..pairing::MAX_PIN_ATTEMPTS {
+            assert_eq!(advance_flatbuf(&mut state, &msg), UNAUTHORIZED);
+        }
+        assert_eq!(advance_flatbuf(&mut state, &msg), Ok(Action::EndSession));
+    }
+
+    #[test]
+    fn v4_wrong_pins_across_reconnects_lock_pairing() {
+        let pairing = Arc::new(Pairing::new(true, []));
+        // Each reconnect is a new session with a fresh PIN and attempt count.
+        for _ in 0..10 {
+            if pairing.locked_out() {
+                break;
+            }
+            let mut state = v4_state_with_pairing(pairing.clone());
+            let Ok(Action::RequestPairing { pin, .. }) =
+                advance_flatbuf(&mut state, &sender_introduction(None))
+            else {
+                panic!("expected a pairing request");
+            };
+            let wrong = if pin == "000000" { "111111" } else { "000000" };
+            let msg = v4::MessageBuilder::new().pairing_request(wrong);
+            assert_eq!(advance_flatbuf(&mut state, &msg), UNAUTHORIZED);
+        }
+        assert!(pairing.locked_out());
+
+        // Locked: the right PIN of a new session is refused too.
+        let mut state = v4_state_with_pairing(pairing);
+        let Ok(Action::RequestPairing { pin, .. }) =
+            advance_flatbuf(&mut state, &sender_introduction(None))
+        else {
+            panic!("expected a pairing request");
+        };
+        let msg = v4::MessageBuilder::new().pairing_request(&pin);
+        assert_eq!(advance_flatbuf(&mut state, &msg), UNAUTHORIZED);
+    }
+
+    #[test]
+    fn legacy_sender_is_refused_when_pairing_is_required() {
+        let v3_json = serde_json::to_string(&VersionMessage { version: 3 }).unwrap();
+        let mut state = State::with_pairing(Arc::new(Pairing::new(true, [])));
+        run_advancements(
+            &mut state,
+            vec![(
+                DriverEvent::Packet {
+                    opcode: Opcode::Version,
+                    body: Some(Bytes::copy_from_slice(v3_json.as_bytes())),
+                },
+                Ok(Action::EndSession),
+            )],
+        );
+    }
+
     #[test]
     fn v4_progress_changed_normal_micros_seeks() {
         let mut state = v4_state();
@@ -2014,4 +2677,193 @@ mod tests {
             ))))
         );
     }
+
+    #[test]
+    fn v4_seek_relative_keeps_the_sign() {
+        let mut state = v4_state();
+        for (micros, expected) in [
+            (
+                10_000_000,
+                gst::Signed::Positive(gst::ClockTime::from_seconds(10)),
+            ),
+            (
+                -10_000_000,
+                gst::Signed::Negative(gst::ClockTime::from_seconds(10)),
+            ),
+        ] {
+            let msg = v4::MessageBuilder::new().seek_relative(micros);
+            assert_eq!(
+                advance_flatbuf(&mut state, &msg),
+                Ok(Action::Op(Operation::SeekRelative(expected)))
+            );
+        }
+
+        let msg = v4::MessageBuilder::new().seek_relative(i64::MIN);
+        assert_eq!(
+            advance_flatbuf(&mut state, &msg),
+            Ok(Action::Error {
+                kind: v4::flat::ErrorKind::MalformedBody
+            })
+        );
+    }
+
+    #[test]
+    fn v4_frame_step_direction() {
+        let mut state = v4_state();
+        let msg = v4::MessageBuilder::new().frame_step(v4::flat::FrameStepDirection::Backward);
+        assert_eq!(
+            advance_flatbuf(&mut state, &msg),
+            Ok(Action::Op(Operation::FrameStep { forward: false }))
+        );
+    }
+
+    #[test]
+    fn v4_seek_to_chapter_targets() {
+        for target in [
+            v4::ChapterTarget::Index(3),
+            v4::ChapterTarget::Next,
+            v4::ChapterTarget::Previous,
+        ] {
+            let mut state = v4_state();
+            let msg = v4::MessageBuilder::new().seek_to_chapter(target);
+            assert_eq!(
+                advance_flatbuf(&mut state, &msg),
+                Ok(Action::Op(Operation::SeekToChapter(target)))
+            );
+        }
+    }
+
+    #[test]
+    fn v4_queue_mode_rejects_unknown_repeat_modes() {
+        let mut state = v4_state();
+        let msg = v4::MessageBuilder::new().queue_mode_changed(v4::flat::RepeatMode::All, true);
+        assert_eq!(
+            advance_flatbuf(&mut state, &msg),
+            Ok(Action::Op(Operation::SetQueueMode(QueueMode {
+                repeat: v4::flat::RepeatMode::All,
+                shuffle: true,
+            })))
+        );
+
+        let msg = v4::MessageBuilder::new().queue_mode_changed(v4::flat::RepeatMode(7), false);
+        assert_eq!(
+            advance_flatbuf(&mut state, &msg),
+            Ok(Action::Error {
+                kind: v4::flat::ErrorKind::MalformedBody
+            })
+        );
+    }
+
+    #[test]
+    fn v4_sleep_timer_deadline_needs_remaining() {
+        let mut state = v4_state();
+        let setting = SleepTimerSetting::Deadline {
+            remaining: Duration::from_secs(1800),
+            fade: Duration::from_secs(30),
+        };
+        assert_eq!(
+            advance_flatbuf(&mut state, &setting.message()),
+            Ok(Action::Op(Operation::SetSleepTimer(setting)))
+        );
+
+        let msg = v4::MessageBuilder::new().sleep_timer_changed(
+            v4::flat::SleepTimerMode::Deadline,
+            None,
+            None,
+        );
+        assert_eq!(
+            advance_flatbuf(&mut state, &msg),
+            Ok(Action::Error {
+                kind: v4::flat::ErrorKind::MalformedBody
+            })
+        );
+    }
+
+    #[test]
+    fn v4_subtitle_style_keeps_absent_fields_absent() {
+        let mut state = v4_state();
+        let msg = v4::MessageBuilder::new().subtitle_style_changed(Some(1.5), None, Some(false));
+        assert_eq!(
+            advance_flatbuf(&mut state, &msg),
+            Ok(Action::Op(Operation::SetSubtitleStyle(
+                crate::subtitle_style::StyleUpdate {
+                    size: Some(1.5),
+                    position: None,
+                    background: Some(false),
+                }
+            )))
+        );
+
+        let msg = v4::MessageBuilder::new().subtitle_style_changed(None, Some(f32::NAN), None);
+        assert_eq!(
+            advance_flatbuf(&mut state, &msg),
+            Ok(Action::Error {
+                kind: v4::flat::ErrorKind::MalformedBody
+            })
+        );
+    }
+
+    #[test]
+    fn v4_delay_keeps_absent_fields_absent() {
+        let mut state = v4_state();
+        let msg = v4::MessageBuilder::new().delay_changed(None, Some(-150_000));
+        assert_eq!(
+            advance_flatbuf(&mut state, &msg),
+            Ok(Action::Op(Operation::SetDelay(
+                crate::av_delay::DelayUpdate {
+                    subtitle_micros: None,
+                    audio_micros: Some(-150_000),
+                }
+            )))
+        );
+    }
+
+    #[test]
+    fn v4_history_request_carries_the_senders_name() {
+        let mut state = v4_state();
+        assert_eq!(
+            advance_flatbuf(&mut state, &sender_introduction(None)),
+            Ok(Action::Introduced)
+        );
+        assert_eq!(state.sender_name.as_deref(), Some("Phone"));
+
+        let msg = v4::MessageBuilder::new().playback_history_request(3, 20);
+        assert_eq!(
+            advance_flatbuf(&mut state, &msg),
+            Ok(Action::Op(Operation::GetPlaybackHistory {
+                request_id: 3,
+                limit: 20
+            }))
+        );
+    }
+
+    #[test]
+    fn v4_queue_move_reads_both_positions() {
+        let mut state = v4_state();
+        let msg = v4::MessageBuilder::new()
+            .queue_move(v4::QueuePosition::Index(4), v4::QueuePosition::Front);
+        assert_eq!(
+            advance_flatbuf(&mut state, &msg),
+            Ok(Action::Op(Operation::MoveQueueItem {
+                from: v4::QueuePosition::Index(4),
+                to: v4::QueuePosition::Front,
+            }))
+        );
+    }
+
+    #[test]
+    fn v4_queue_positions_read_wide_indexes() {
+        let mut state = v4_state();
+        let msg = v4::MessageBuilder::new().queue_move(
+            v4::QueuePosition::Index(4_000),
+            v4::QueuePosition::Index(12),
+        );
+        assert_eq!(
+            advance_flatbuf(&mut state, &msg),
+            Ok(Action::Op(Operation::MoveQueueItem {
+                from: v4::QueuePosition::Index(4_000),
+                to: v4::QueuePosition::Index(12),
+            }))
+        );
+    }
 }
@@ -48,6 +48,9 @@ pub(crate) struct FreezeSample {
     pub seekable: bool,
     /// Any seek-shaped operation the application is waiting on.
     pub seek_pending: bool,
+    /// The source is reconnecting a dropped connection and resumes on its
+    /// own; a recovery seek would only restart its attempts.
+    pub source_reconnecting: bool,
     /// Current playback rate. Reverse playback counts down, so a pinned
     /// position means something else there.
     pub rate: f64,
@@ -192,7 +195,12 @@ impl FreezeWatchdog {
         if !sample.playing || !sample.have_media_info {
             return None;
         }
-        if sample.loading || sample.image || sample.live || sample.seek_pending {
+        if sample.loading
+            || sample.image
+            || sample.live
+            || sample.seek_pending
+            || sample.source_reconnecting
+        {
             return None;
         }
         // Reverse playback counts the position DOWN, and a legitimate pin at 0
@@ -265,6 +273,7 @@ mod tests {
             live: false,
             seekable: true,
             seek_pending: false,
+            source_reconnecting: false,
             rate: 1.0,
             position: Some(position),
             duration: Some(gst::ClockTime::from_seconds(600)),
@@ -425,13 +434,14 @@ mod tests {
     /// flipped must produce nothing.
     #[test]
     fn excluded_states_never_fire() {
-        let cases: [(&str, fn(&mut FreezeSample)); 9] = [
+        let cases: [(&str, fn(&mut FreezeSample)); 10] = [
             ("paused or transitioning", |s| s.playing = false),
             ("no media info", |s| s.have_media_info = false),
             ("load in flight", |s| s.loading = true),
             ("pipeline image", |s| s.image = true),
             ("live source", |s| s.live = true),
             ("seek pending", |s| s.seek_pending = true),
+            ("source reconnecting", |s| s.source_reconnecting = true),
             ("unsettled pipeline", |s| s.pipeline_settled = false),
             ("reverse playback", |s| s.rate = -1.0),
             ("position unknown", |s| s.position = None),
@@ -1,10 +1,11 @@
-use std::{net::IpAddr, sync::Arc};
+use std::{net::IpAddr, sync::Arc, time::Instant};
 
 use anyhow::{Result, bail};
+use fcast_protocol::v4;
 use futures::StreamExt;
 use google_cast_protocol::{
-    Application, CONNECTION_NAMESPACE, HEARTBEAT_NAMESPACE, MEDIA_NAMESPACE, RECEIVER_NAMESPACE,
-    VolumeStatus, namespaces, prost::Message, protos,
+    Application, CONNECTION_NAMESPACE, HEARTBEAT_NAMESPACE, MEDIA_NAMESPACE, QueueRepeatMode,
+    RECEIVER_NAMESPACE, Track, TrackType, VolumeStatus, namespaces, prost::Message, protos,
 };
 use parking_lot::RwLock;
 use rcgen::{CertificateParams, DistinguishedName, KeyPair, date_time_ymd};
@@ -17,11 +18,23 @@ use tokio::{
 use tokio_rustls::{TlsAcceptor, rustls, server::TlsStream};
 use tracing::{debug, error, instrument, warn};
 
-use crate::{MessageSender, application::PacketOrigin};
+use crate::{
+    MessageSender, SenderId,
+    application::PacketOrigin,
+    fcast::{FlatLoadMessage, QueueInsertBatchCell, QueueMode},
+    message::Message as AppMessage,
+    queue_order::PlayOrder,
+};
 
 const MAX_MSG_SIZE: usize = 1000 * 64;
 const MEDIA_ID: &str = "aaaaaaaa-aaaa-aaaa-aaaa-aaaaaaaaaaaa";
 const RECEIVER_ID: &str = "CC1AD845";
+/// Pause, seek, stream volume and mute, queue next and previous, shuffle, both
+/// repeat modes and track changes.
+const SUPPORTED_MEDIA_COMMANDS: u64 = 0b1_1101_1100_1111;
+/// Senders extrapolate the position from the last status, so only a position
+/// this far off that is worth a status of its own.
+const POSITION_JUMP_SECS: f64 = 2.0;
 
 use google_cast_protocol::MediaStatus;
 
@@ -53,7 +66,372 @@ pub enum StatusUpdate {
     Volume(f64),
     Position(f64),
     Duration(f64),
+    PlaybackRate(f64),
     PlayerState(crate::PlayerState),
+    /// Why the player is about to go idle, reported when it does.
+    IdleReason(google_cast_protocol::IdleReason),
+    /// An item started loading, whoever cast it.
+    Media(MediaDescription),
+    /// The queue being played, `items` is empty when the media isn't a queue.
+    Queue {
+        items: Vec<QueueStatusItem>,
+        current_item_id: Option<u32>,
+        mode: QueueMode,
+    },
+    /// The tracks of the media playing, as advertised to FCast senders.
+    Tracks(Vec<MediaTrack>),
+    /// The ids of the selected tracks.
+    ActiveTracks(Vec<u32>),
+}
+
+/// A queue edit as a Cast sender asked for it, by `itemId`. The event loop
+/// resolves it against the live queue, which the status a session caches can
+/// trail by any number of edits.
+#[derive(Debug)]
+pub enum QueueEdit {
+    Insert {
+        items: Vec<(v4::MediaItem, Option<f64>)>,
+        insert_before: Option<u32>,
+    },
+    Select(u32),
+    /// Move this many items along the play order, backwards when negative.
+    Jump(i32),
+    Remove(Vec<u32>),
+    Reorder {
+        item_ids: Vec<u32>,
+        insert_before: Option<u32>,
+    },
+}
+
+impl QueueEdit {
+    /// The operations this edit comes to, `ids` being the queue's item ids in
+    /// queue order and `current` the playing item's index. `None` when it
+    /// names an item the queue doesn't have, or jumps off its end.
+    pub(crate) fn operations(
+        self,
+        ids: &[u32],
+        current: Option<usize>,
+        order: &PlayOrder,
+        repeat: v4::flat::RepeatMode,
+    ) -> Option<Vec<crate::Operation>> {
+        match self {
+            QueueEdit::Insert {
+                items,
+                insert_before,
+            } => {
+                let position = match insert_before {
+                    Some(id) => queue_position(ids, id)?,
+                    None => v4::QueuePosition::Back,
+                };
+                let msg = v4::MessageBuilder::new().queue_insert_batch(items.into_iter(), position);
+                match QueueInsertBatchCell::from_packet(msg.to_vec()) {
+                    Ok(batch) => Some(vec![crate::Operation::InsertQueueItems(batch)]),
+                    Err(err) => {
+                        error!(?err, "Failed to build a queue insert");
+                        Some(Vec::new())
+                    }
+                }
+            }
+            QueueEdit::Select(id) => {
+                let position = queue_position(ids, id)?;
+                Some(vec![crate::Operation::SelectQueueItem(position)])
+            }
+            QueueEdit::Jump(jump) => {
+                let target = order.step(ids.len(), current?, jump.into(), repeat)?;
+                Some(vec![crate::Operation::SelectQueueItem(
+                    v4::QueuePosition::Index(target as u32),
+                )])
+            }
+            QueueEdit::Remove(item_ids) => queue_remove_operations(
+                ids,
+                current.and_then(|idx| ids.get(idx).copied()),
+                &item_ids,
+            ),
+            QueueEdit::Reorder {
+                item_ids,
+                insert_before,
+            } => queue_reorder_operations(ids, &item_ids, insert_before),
+        }
+    }
+}
+
+/// A track as Cast senders see it. `id` is the one FCast senders see, so a
+/// `trackId` maps straight onto `ChangeTrack`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MediaTrack {
+    pub id: u32,
+    pub kind: v4::flat::MediaTrackType,
+    pub name: Option<String>,
+    pub language: Option<String>,
+}
+
+impl MediaTrack {
+    pub fn from_v4(track: &v4::MediaTrack) -> Option<Self> {
+        let kind = match track.metadata.as_ref()? {
+            v4::MediaTrackMetadata::Video => v4::flat::MediaTrackType::Video,
+            v4::MediaTrackMetadata::Audio => v4::flat::MediaTrackType::Audio,
+            v4::MediaTrackMetadata::Subtitle => v4::flat::MediaTrackType::Subtitle,
+        };
+        Some(Self {
+            id: track.id,
+            kind,
+            name: track.title.as_ref().map(ToString::to_string),
+            language: (track.iso_639 != "und").then(|| track.iso_639.to_string()),
+        })
+    }
+
+    fn to_cast(&self) -> Track {
+        let (track_type, subtype) = match self.kind {
+            v4::flat::MediaTrackType::Video => (TrackType::Video, None),
+            v4::flat::MediaTrackType::Audio => (TrackType::Audio, None),
+            _ => (
+                TrackType::Text,
+                Some(google_cast_protocol::TextTrackType::Subtitles),
+            ),
+        };
+        Track {
+            track_id: self.id,
+            track_type,
+            track_content_id: None,
+            track_content_type: None,
+            subtype,
+            name: self.name.clone(),
+            language: self.language.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MediaDescription {
+    pub container: String,
+    pub url: String,
+    pub title: Option<String>,
+    pub thumbnail_url: Option<String>,
+}
+
+/// A queue item as Cast senders see it. `id` stays with the item while the
+/// queue is edited, it's the `itemId` senders address it by.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueueStatusItem {
+    pub id: u32,
+    pub media: MediaDescription,
+    pub autoplay: bool,
+}
+
+impl QueueStatusItem {
+    fn to_cast(&self) -> google_cast_protocol::QueueItem {
+        google_cast_protocol::QueueItem {
+            item_id: Some(self.id),
+            autoplay: Some(self.autoplay),
+            media: self.media.to_cast(),
+            playback_duration: None,
+            start_time: None,
+        }
+    }
+}
+
+impl MediaDescription {
+    fn to_cast(&self) -> google_cast_protocol::MediaInformation {
+        let metadata = (self.title.is_some() || self.thumbnail_url.is_some()).then(|| {
+            google_cast_protocol::Metadata::Generic {
+                title: self.title.clone(),
+                subtitle: None,
+                images: self
+                    .thumbnail_url
+                    .clone()
+                    .map(|url| vec![google_cast_protocol::Image { url }]),
+                release_date: None,
+            }
+        });
+        google_cast_protocol::MediaInformation {
+            content_id: self.url.clone(),
+            stream_type: google_cast_protocol::StreamType::Buffered,
+            content_type: self.container.clone(),
+            metadata,
+            duration: None,
+            tracks: None,
+        }
+    }
+}
+
+/// What made the media status worth re-sending to the senders.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StatusChange {
+    PlayerState,
+    Queue,
+    Tracks,
+    Media,
+    Volume,
+    PlaybackRate,
+    Position,
+}
+
+fn queue_mode_from_cast(mode: QueueRepeatMode) -> QueueMode {
+    let (repeat, shuffle) = match mode {
+        QueueRepeatMode::Off => (v4::flat::RepeatMode::Off, false),
+        QueueRepeatMode::All => (v4::flat::RepeatMode::All, false),
+        QueueRepeatMode::Single => (v4::flat::RepeatMode::One, false),
+        QueueRepeatMode::AllAndShuffle => (v4::flat::RepeatMode::All, true),
+    };
+    QueueMode { repeat, shuffle }
+}
+
+/// Cast has no shuffle without repeat, shuffle is only reported along with
+/// repeat all.
+fn queue_mode_to_cast(mode: QueueMode) -> QueueRepeatMode {
+    match (mode.repeat, mode.shuffle) {
+        (v4::flat::RepeatMode::All, true) => QueueRepeatMode::AllAndShuffle,
+        (v4::flat::RepeatMode::All, false) => QueueRepeatMode::All,
+        (v4::flat::RepeatMode::One, _) => QueueRepeatMode::Single,
+        _ => QueueRepeatMode::Off,
+    }
+}
+
+fn queue_item_to_v4(item: google_cast_protocol::QueueItem) -> (v4::MediaItem, Option<f64>) {
+    let (title, thumbnail_url) = match item.media.metadata {
+        Some(google_cast_protocol::Metadata::Generic { title, images, .. }) => (
+            title,
+            images.and_then(|images| images.into_iter().next().map(|image| image.url)),
+        ),
+        None => (None, None),
+    };
+    let media_item = v4::MediaItem {
+        container: item.media.content_type,
+        source_url: item.media.content_id,
+        start_time: item.start_time,
+        volume: None,
+        speed: None,
+        headers: None,
+        title,
+        thumbnail_url,
+        metadata: None,
+        extra_metadata: None,
+        resume: false,
+    };
+    // Negative durations are relative to the item's end, which isn't known
+    // yet: play those to the end.
+    let playback_duration = item.playback_duration.filter(|secs| *secs >= 0.0);
+    (media_item, playback_duration)
+}
+
+fn queue_position(ids: &[u32], id: u32) -> Option<v4::QueuePosition> {
+    ids.iter()
+        .position(|other| *other == id)
+        .map(|idx| v4::QueuePosition::Index(idx as u32))
+}
+
+/// Remove `remove` from the queue. Cast moves on to the next item left when
+/// the playing one goes, the queue operations refuse that, so select it first.
+/// `None` when any of `remove` isn't in the queue.
+fn queue_remove_operations(
+    ids: &[u32],
+    current: Option<u32>,
+    remove: &[u32],
+) -> Option<Vec<crate::Operation>> {
+    let mut indexes = remove
+        .iter()
+        .map(|id| ids.iter().position(|other| other == id))
+        .collect::<Option<Vec<_>>>()?;
+    // Back to front so the indexes stay valid.
+    indexes.sort_unstable_by(|a, b| b.cmp(a));
+    indexes.dedup();
+    if indexes.is_empty() {
+        return Some(Vec::new());
+    }
+    if indexes.len() == ids.len() {
+        return Some(vec![crate::Operation::Stop]);
+    }
+
+    let mut ops = Vec::new();
+    if let Some(current_idx) = current.and_then(|id| ids.iter().position(|other| *other == id))
+        && indexes.contains(&current_idx)
+    {
+        let kept = |idx: &usize| !indexes.contains(idx);
+        let next = (current_idx..ids.len())
+            .find(kept)
+            .or_else(|| (0..current_idx).rev().find(kept));
+        if let Some(next) = next {
+            ops.push(crate::Operation::SelectQueueItem(v4::QueuePosition::Index(
+                next as u32,
+            )));
+        }
+    }
+    ops.extend(
+        indexes
+            .into_iter()
+            .map(|idx| crate::Operation::RemoveQueueItem(v4::QueuePosition::Index(idx as u32))),
+    );
+    Some(ops)
+}
+
+/// Turn a sender's `activeTrackIds` into track changes, one per kind whose
+/// selection differs from `active`. Cast can't leave out the audio or video,
+/// doing so keeps what plays; a missing text track turns the subtitles off.
+fn track_change_operations(
+    tracks: &[Track],
+    active: &[u32],
+    requested: &[u32],
+) -> Vec<crate::Operation> {
+    let of_type = |ids: &[u32], track_type| {
+        ids.iter().copied().find(|id| {
+            tracks
+                .iter()
+                .any(|track| track.track_id == *id && track.track_type == track_type)
+        })
+    };
+    [
+        (TrackType::Video, v4::flat::MediaTrackType::Video),
+        (TrackType::Audio, v4::flat::MediaTrackType::Audio),
+        (TrackType::Text, v4::flat::MediaTrackType::Subtitle),
+    ]
+    .into_iter()
+    .filter_map(|(track_type, typ)| {
+        let want = of_type(requested, track_type);
+        if want == of_type(active, track_type) || (want.is_none() && track_type != TrackType::Text)
+        {
+            return None;
+        }
+        Some(crate::Operation::ChangeTrack { id: want, typ })
+    })
+    .collect()
+}
+
+/// Move `moving` to just before `insert_before`, or to the end, keeping the
+/// order they're listed in. `None` when any of the ids isn't in the queue.
+fn queue_reorder_operations(
+    ids: &[u32],
+    moving: &[u32],
+    insert_before: Option<u32>,
+) -> Option<Vec<crate::Operation>> {
+    if moving
+        .iter()
+        .chain(&insert_before)
+        .any(|id| !ids.contains(id))
+    {
+        return None;
+    }
+    let mut order = ids.to_vec();
+    let mut ops = Vec::new();
+    for id in moving {
+        if Some(*id) == insert_before {
+            continue;
+        }
+        let Some(from) = order.iter().position(|other| other == id) else {
+            continue;
+        };
+        order.remove(from);
+        let to = insert_before
+            .and_then(|before| order.iter().position(|other| *other == before))
+            .unwrap_or(order.len());
+        order.insert(to, *id);
+        if from != to {
+            ops.push(crate::Operation::MoveQueueItem {
+                from: v4::QueuePosition::Index(from as u32),
+                to: v4::QueuePosition::Index(to as u32),
+            });
+        }
+    }
+    Some(ops)
 }
 
 #[derive(Debug, PartialEq)]
@@ -66,20 +444,35 @@ pub struct ChromecastLoadItem {
 
 struct State {
     pub msg_tx: MessageSender,
+    /// Identifies this connection's operations to the application.
+    pub origin: PacketOrigin,
     pub has_launched: bool,
     pub media_status: Arc<RwLock<MediaStatus>>,
+    /// The player state this sender was last told about.
     pub player_state: google_cast_protocol::PlayerState,
 }
 
 impl State {
-    pub fn new(msg_tx: MessageSender, media_status: Arc<RwLock<MediaStatus>>) -> Self {
+    pub fn new(
+        msg_tx: MessageSender,
+        sender_id: SenderId,
+        media_status: Arc<RwLock<MediaStatus>>,
+    ) -> Self {
         Self {
             msg_tx,
+            origin: PacketOrigin::gcast(sender_id),
             has_launched: false,
             media_status,
             player_state: google_cast_protocol::PlayerState::Idle,
         }
     }
+
+    fn queue_edit(&self, edit: QueueEdit) {
+        self.msg_tx.send(AppMessage::GCastQueueEdit {
+            origin: self.origin,
+            edit,
+        });
+    }
 }
 
 async fn write_channel_message<T>(
@@ -200,7 +593,7 @@ async fn handle_message(
         bail!("Received message with unsupported payload type");
     }
 
-    let origin = PacketOrigin::gcast(0);
+    let origin = state.origin;
     let json_payload = message.payload_utf8();
     match message.namespace.as_str() {
         HEARTBEAT_NAMESPACE => {}
@@ -246,6 +639,7 @@ async fn handle_message(
                     media,
                     current_time,
                     playback_rate,
+                    active_track_ids,
                     ..
                 } => {
                     state.msg_tx.operation(
@@ -259,6 +653,24 @@ async fn handle_message(
                             },
                         )),
                     );
+                    // Side-loaded subtitles become external subtitle sources. They
+                    // are reported back under the receiver's own track ids.
+                    let active_track_ids = active_track_ids.unwrap_or_default();
+                    for track in media.tracks.iter().flatten() {
+                        let (TrackType::Text, Some(url)) =
+                            (track.track_type, track.track_content_id.as_ref())
+                        else {
+                            continue;
+                        };
+                        state.msg_tx.operation(
+                            origin,
+                            crate::Operation::AddSubtitleSource {
+                                url: url.clone(),
+                                select: active_track_ids.contains(&track.track_id),
+                                name: track.name.as_deref().map(smol_str::SmolStr::new),
+                            },
+                        );
+                    }
                     let mut status = state.media_status.write();
                     status.media = Some(media);
                 }
@@ -300,7 +712,117 @@ async fn handle_message(
                         .msg_tx
                         .operation(origin, crate::Operation::SetSpeed(playback_rate as f32));
                 }
-                // TODO: implement the queue namespace (QueueLoad / QueueUpdate)
+                namespaces::Media::EditTracksInfo {
+                    active_track_ids,
+                    enable_text_tracks,
+                    ..
+                } => {
+                    let ops = {
+                        let status = state.media_status.read();
+                        let tracks = status
+                            .media
+                            .as_ref()
+                            .and_then(|media| media.tracks.as_deref())
+                            .unwrap_or_default();
+                        let active = status.active_track_ids.as_deref().unwrap_or_default();
+                        let requested = match (active_track_ids, enable_text_tracks) {
+                            (Some(ids), _) => ids,
+                            // Everything but the text tracks keeps playing.
+                            (None, Some(false)) => active
+                                .iter()
+                                .copied()
+                                .filter(|id| {
+                                    !tracks.iter().any(|track| {
+                                        track.track_id == *id && track.track_type == TrackType::Text
+                                    })
+                                })
+                                .collect(),
+                            (None, _) => active.to_vec(),
+                        };
+                        track_change_operations(tracks, active, &requested)
+                    };
+                    for op in ops {
+                        state.msg_tx.operation(origin, op);
+                    }
+                }
+                namespaces::Media::QueueLoad {
+                    items,
+                    repeat_mode,
+                    start_index,
+                    ..
+                } => {
+                    // The mode goes first, the queue's play order is drawn at load.
+                    state.msg_tx.operation(
+                        origin,
+                        crate::Operation::SetQueueMode(queue_mode_from_cast(
+                            repeat_mode.unwrap_or(QueueRepeatMode::Off),
+                        )),
+                    );
+                    // The queue has one autoplay flag where Cast has one per item.
+                    let autoplay = items.iter().all(|item| item.autoplay.unwrap_or(true));
+                    let msg = v4::MessageBuilder::new().load_queue(
+                        items.into_iter().map(queue_item_to_v4),
+                        start_index,
+                        autoplay,
+                    );
+                    match FlatLoadMessage::from_packet(msg.to_vec()) {
+                        Ok(load) => state.msg_tx.operation(
+                            origin,
+                            crate::Operation::PlayNew(crate::fcast::WrappedPlayMessage::V4(load)),
+                        ),
+                        Err(err) => error!(?err, "Failed to build a queue load"),
+                    }
+                }
+                namespaces::Media::QueueInsert {
+                    items,
+                    insert_before,
+                    ..
+                } => state.queue_edit(QueueEdit::Insert {
+                    items: items.into_iter().map(queue_item_to_v4).collect(),
+                    insert_before,
+                }),
+                namespaces::Media::QueueUpdate {
+                    current_item_id,
+                    jump,
+                    repeat_mode,
+                    shuffle,
+                    ..
+                } => {
+                    if repeat_mode.is_some() || shuffle.is_some() {
+                        let mode = repeat_mode.unwrap_or_else(|| {
+                            state
+                                .media_status
+                                .read()
+                                .repeat_mode
+                                .unwrap_or(QueueRepeatMode::Off)
+                        });
+                        let mut mode = queue_mode_from_cast(mode);
+                        if let Some(shuffle) = shuffle {
+                            mode.shuffle = shuffle;
+                        }
+                        state
+                            .msg_tx
+                            .operation(origin, crate::Operation::SetQueueMode(mode));
+                    }
+
+                    // After the mode change, so a jump follows the new order.
+                    match (current_item_id, jump) {
+                        (Some(id), _) => state.queue_edit(QueueEdit::Select(id)),
+                        (None, Some(jump)) => state.queue_edit(QueueEdit::Jump(jump)),
+                        (None, None) => (),
+                    }
+                }
+                namespaces::Media::QueueRemove { item_ids, .. } => {
+                    state.queue_edit(QueueEdit::Remove(item_ids));
+                }
+                namespaces::Media::QueueReorder {
+                    item_ids,
+                    insert_before,
+                    ..
+                } => state.queue_edit(QueueEdit::Reorder {
+                    item_ids,
+                    insert_before,
+                }),
                 _ => (),
             }
         }
@@ -325,12 +847,13 @@ async fn handle_message(
     Ok(EndSession::No)
 }
 
-#[instrument(skip_all, name = "gcast_session")]
+#[instrument(skip_all, name = "gcast_session", fields(sender_id = sender_id))]
 async fn run_session(
     msg_tx: MessageSender,
+    sender_id: SenderId,
     media_status: Arc<RwLock<MediaStatus>>,
     stream: TlsStream<TcpStream>,
-    mut state_change_rx: broadcast::Receiver<()>,
+    mut state_change_rx: broadcast::Receiver<StatusChange>,
 ) -> Result<()> {
     let (reader, mut writer) = tokio::io::split(stream);
 
@@ -371,7 +894,7 @@ async fn run_session(
 
     tokio::pin!(packet_stream);
 
-    let mut state = State::new(msg_tx, media_status);
+    let mut state = State::new(msg_tx, sender_id, media_status);
 
     loop {
         tokio::select! {
@@ -386,10 +909,17 @@ async fn run_session(
                     break;
                 }
             }
-            _ = state_change_rx.recv() => {
-                let new_state = state.media_status.read().player_state;
-                if new_state != state.player_state {
-                    let status = state.media_status.read().clone();
+            change = state_change_rx.recv() => {
+                let change = match change {
+                    Ok(change) => Some(change),
+                    Err(broadcast::error::RecvError::Lagged(_)) => None,
+                    Err(broadcast::error::RecvError::Closed) => break,
+                };
+                let status = state.media_status.read().clone();
+                let player_state_changed = status.player_state != state.player_state;
+                state.player_state = status.player_state;
+                // A lagged receiver missed something, it gets the latest status.
+                if change != Some(StatusChange::PlayerState) || player_state_changed {
                     write_channel_message(
                         &mut writer,
                         MEDIA_ID,
@@ -408,6 +938,168 @@ async fn run_session(
     Ok(())
 }
 
+/// Applies the application's updates to the shared media status and decides
+/// which are worth a status to the senders.
+#[derive(Default)]
+struct StatusTracker {
+    last_queue: Option<(Vec<QueueStatusItem>, Option<u32>, QueueMode)>,
+    /// The last reported position and when it was.
+    position_at: Option<(f64, Instant)>,
+    idle_reason: Option<google_cast_protocol::IdleReason>,
+}
+
+impl StatusTracker {
+    fn apply(&mut self, status: &mut MediaStatus, update: StatusUpdate) -> Option<StatusChange> {
+        match update {
+            StatusUpdate::Volume(vol) => {
+                if status.volume.level == Some(vol) {
+                    return None;
+                }
+                status.volume.level = Some(vol);
+                Some(StatusChange::Volume)
+            }
+            StatusUpdate::Position(pos) => {
+                let rate = if status.player_state == google_cast_protocol::PlayerState::Playing {
+                    status.playback_rate
+                } else {
+                    0.0
+                };
+                let expected = self
+                    .position_at
+                    .replace((pos, Instant::now()))
+                    .map(|(last, at)| last + at.elapsed().as_secs_f64() * rate);
+                status.current_time = pos;
+                expected
+                    .is_some_and(|expected| (pos - expected).abs() > POSITION_JUMP_SECS)
+                    .then_some(StatusChange::Position)
+            }
+            StatusUpdate::Duration(dur) => {
+                if let Some(info) = status.media.as_mut() {
+                    info.duration = Some(dur);
+                }
+                None
+            }
+            StatusUpdate::PlaybackRate(rate) => {
+                if status.playback_rate == rate {
+                    return None;
+                }
+                status.playback_rate = rate;
+                Some(StatusChange::PlaybackRate)
+            }
+            StatusUpdate::PlayerState(state) => {
+                let (state, idle_reason) = match state {
+                    crate::player::PlayerState::Paused => {
+                        (google_cast_protocol::PlayerState::Paused, None)
+                    }
+                    crate::player::PlayerState::Playing => {
+                        (google_cast_protocol::PlayerState::Playing, None)
+                    }
+                    crate::player::PlayerState::Buffering => {
+                        (google_cast_protocol::PlayerState::Buffering, None)
+                    }
+                    crate::player::PlayerState::Stopped => (
+                        google_cast_protocol::PlayerState::Idle,
+                        Some(
+                            self.idle_reason
+                                .take()
+                                .unwrap_or(google_cast_protocol::IdleReason::Finished),
+                        ),
+                    ),
+                };
+                status.player_state = state;
+                status.idle_reason = idle_reason;
+                Some(StatusChange::PlayerState)
+            }
+            StatusUpdate::IdleReason(reason) => {
+                self.idle_reason = Some(reason);
+                None
+            }
+            StatusUpdate::Media(media) => {
+                // A reason left over from the item before isn't this one's.
+                self.idle_reason = None;
+                // A Cast sender's own LOAD already described it, with more detail.
+                if status
+                    .media
+                    .as_ref()
+                    .is_some_and(|current| current.content_id == media.url)
+                {
+                    return None;
+                }
+                status.media = Some(media.to_cast());
+                status.active_track_ids = None;
+                Some(StatusChange::Media)
+            }
+            StatusUpdate::Queue {
+                items,
+                current_item_id,
+                mode,
+            } => {
+                let queue = (items, current_item_id, mode);
+                if self.last_queue.as_ref() == Some(&queue) {
+                    return None;
+                }
+                let (items, current_item_id, mode) = &queue;
+                if items.is_empty() {
+                    status.items = None;
+                    status.current_item_id = None;
+                    status.repeat_mode = None;
+                } else {
+                    let current = items.iter().find(|item| Some(item.id) == *current_item_id);
+                    if let Some(current) = current {
+                        // Keep what the player reported when the item stays the same.
+                        let mut media = current.media.to_cast();
+                        let previous = status
+                            .media
+                            .take()
+                            .filter(|media| media.content_id == current.media.url);
+                        if let Some(previous) = previous {
+                            media.duration = previous.duration;
+                            media.tracks = previous.tracks;
+                        }
+                        status.media = Some(media);
+                    }
+                    status.items = Some(items.iter().map(QueueStatusItem::to_cast).collect());
+                    status.current_item_id = *current_item_id;
+                    status.repeat_mode = Some(queue_mode_to_cast(*mode));
+                }
+                self.last_queue = Some(queue);
+                Some(StatusChange::Queue)
+            }
+            StatusUpdate::Tracks(tracks) => {
+                if let Some(media) = status.media.as_mut() {
+                    media.tracks = Some(tracks.iter().map(MediaTrack::to_cast).collect());
+                }
+                Some(StatusChange::Tracks)
+            }
+            StatusUpdate::ActiveTracks(ids) => {
+                status.active_track_ids = Some(ids);
+                Some(StatusChange::Tracks)
+            }
+        }
+    }
+}
+
+/// What senders see before anything has played.
+fn initial_media_status() -> MediaStatus {
+    google_cast_protocol::MediaStatus {
+        media_session_id: 1337,
+        media: None,
+        playback_rate: 1.0,
+        player_state: google_cast_protocol::PlayerState::Idle,
+        idle_reason: None,
+        current_time: 0.0,
+        supported_media_commands: SUPPORTED_MEDIA_COMMANDS,
+        volume: google_cast_protocol::Volume {
+            level: Some(1.0),
+            muted: None,
+        },
+        items: None,
+        current_item_id: None,
+        repeat_mode: None,
+        active_track_ids: None,
+    }
+}
+
 pub async fn run_server(
     msg_tx: MessageSender,
     mut status_rx: UnboundedReceiver<StatusUpdate>,
@@ -448,21 +1140,11 @@ pub async fn run_server(
         .with_no_client_auth()
         .with_single_cert(vec![cert.der().to_owned()], key_pair.into())?;
     let acceptor = TlsAcceptor::from(Arc::new(config));
-    let (state_change_tx, _) = broadcast::channel::<()>(8);
+    let (state_change_tx, _) = broadcast::channel::<StatusChange>(8);
 
-    let media_status = Arc::new(RwLock::new(google_cast_protocol::MediaStatus {
-        media_session_id: 1337,
-        media: None,
-        playback_rate: 1.0,
-        player_state: google_cast_protocol::PlayerState::Idle,
-        idle_reason: None,
-        current_time: 0.0,
-        supported_media_commands: 0b001111,
-        volume: google_cast_protocol::Volume {
-            level: Some(1.0),
-            muted: None,
-        },
-    }));
+    let media_status = Arc::new(RwLock::new(initial_media_status()));
+    let mut tracker = StatusTracker::default();
+    let mut next_sender_id: SenderId = 0;
 
     loop {
         tokio::select! {
@@ -478,12 +1160,14 @@ pub async fn run_server(
                 };
                 let acceptor = acceptor.clone();
                 let msg_tx = msg_tx.clone();
+                let sender_id = next_sender_id;
+                next_sender_id = next_sender_id.wrapping_add(1);
                 let media_status = Arc::clone(&media_status);
                 let state_change_rx = state_change_tx.subscribe();
                 tokio::spawn(async move {
                     match acceptor.accept(stream).await {
                         Ok(stream) => {
-                            if let Err(err) = run_session(msg_tx, media_status, stream, state_change_rx).await {
+                            if let Err(err) = run_session(msg_tx, sender_id, media_status, stream, state_change_rx).await {
                                 debug!(?err, "Session ended with error");
                             }
                         }
@@ -495,29 +1179,11 @@ pub async fn run_server(
                 let Some(update) = status_update else {
                     break;
                 };
-                let mut status = media_status.write();
-                match update {
-                    StatusUpdate::Volume(vol) => status.volume.level = Some(vol),
-                    StatusUpdate::Position(pos) => status.current_time = pos,
-                    StatusUpdate::Duration(dur) => if let Some(info) = status.media.as_mut() {
-                        info.duration = Some(dur);
-                    },
-                    StatusUpdate::PlayerState(state) => {
-                        let (state, idle_reason) = match state {
-                            crate::player::PlayerState::Paused => (google_cast_protocol::PlayerState::Paused, None),
-                            crate::player::PlayerState::Playing => (google_cast_protocol::PlayerState::Playing, None),
-                            crate::player::PlayerState::Buffering => (google_cast_protocol::PlayerState::Buffering, None),
-                            crate::player::PlayerState::Stopped => (
-                                google_cast_protocol::PlayerState::Idle,
-                                Some(google_cast_protocol::IdleReason::Finished)
-                            ),
-                        };
-                        status.player_state = state;
-                        status.idle_reason = idle_reason;
-                        if state_change_tx.receiver_count() > 0 {
-                            let _ = state_change_tx.send(());
-                        }
-                    }
+                let change = tracker.apply(&mut media_status.write(), update);
+                if let Some(change) = change
+                    && state_change_tx.receiver_count() > 0
+                {
+                    let _ = state_change_tx.send(change);
                 }
             }
         }
@@ -525,3 +1191,233 @@ pub async fn run_server(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use v4::flat::RepeatMode;
+
+    use super::*;
+
+    fn index(idx: u32) -> v4::QueuePosition {
+        v4::QueuePosition::Index(idx)
+    }
+
+    #[test]
+    fn removing_the_playing_item_selects_the_next_one_first() {
+        let ids = [10, 11, 12, 13];
+        assert_eq!(
+            queue_remove_operations(&ids, Some(11), &[11, 12]).unwrap(),
+            [
+                crate::Operation::SelectQueueItem(index(3)),
+                crate::Operation::RemoveQueueItem(index(2)),
+                crate::Operation::RemoveQueueItem(index(1)),
+            ]
+        );
+        // Nothing after it is left, fall back to the one before.
+        assert_eq!(
+            queue_remove_operations(&ids, Some(13), &[13]).unwrap(),
+            [
+                crate::Operation::SelectQueueItem(index(2)),
+                crate::Operation::RemoveQueueItem(index(3)),
+            ]
+        );
+        assert_eq!(
+            queue_remove_operations(&ids, Some(10), &[13, 12, 11, 10]).unwrap(),
+            [crate::Operation::Stop]
+        );
+        // An id the queue doesn't have refuses the whole removal.
+        assert!(queue_remove_operations(&ids, Some(10), &[11, 99]).is_none());
+    }
+
+    #[test]
+    fn edit_tracks_changes_only_what_differs() {
+        let track = |track_id, track_type| Track {
+            track_id,
+            track_type,
+            track_content_id: None,
+            track_content_type: None,
+            subtype: None,
+            name: None,
+            language: None,
+        };
+        let tracks = [
+            track(0, TrackType::Video),
+            track(1, TrackType::Audio),
+            track(2, TrackType::Audio),
+            track(3, TrackType::Text),
+        ];
+        assert_eq!(
+            track_change_operations(&tracks, &[0, 1], &[0, 2, 3]),
+            [
+                crate::Operation::ChangeTrack {
+                    id: Some(2),
+                    typ: v4::flat::MediaTrackType::Audio,
+                },
+                crate::Operation::ChangeTrack {
+                    id: Some(3),
+                    typ: v4::flat::MediaTrackType::Subtitle,
+                },
+            ]
+        );
+        // Leaving out the audio keeps it, leaving out the text turns it off.
+        assert_eq!(
+            track_change_operations(&tracks, &[0, 1, 3], &[0]),
+            [crate::Operation::ChangeTrack {
+                id: None,
+                typ: v4::flat::MediaTrackType::Subtitle,
+            }]
+        );
+    }
+
+    #[test]
+    fn reorder_keeps_the_listed_order() {
+        let ids = [10, 11, 12, 13];
+        // [10, 11, 12, 13] -> [10, 13, 12, 11]
+        assert_eq!(
+            queue_reorder_operations(&ids, &[13, 12], Some(11)).unwrap(),
+            [
+                crate::Operation::MoveQueueItem {
+                    from: index(3),
+                    to: index(1),
+                },
+                crate::Operation::MoveQueueItem {
+                    from: index(3),
+                    to: index(2),
+                },
+            ]
+        );
+        // [10, 11, 12, 13] -> [12, 13, 10, 11]
+        assert_eq!(
+            queue_reorder_operations(&ids, &[10, 11], None).unwrap(),
+            [
+                crate::Operation::MoveQueueItem {
+                    from: index(0),
+                    to: index(3),
+                },
+                crate::Operation::MoveQueueItem {
+                    from: index(0),
+                    to: index(3),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn queue_edits_name_items_of_the_live_queue() {
+        let ids = [10, 11, 12, 13];
+        let order = PlayOrder::default();
+        let edit = |edit: QueueEdit| edit.operations(&ids, Some(1), &order, RepeatMode::Off);
+        assert_eq!(
+            edit(QueueEdit::Select(12)).unwrap(),
+            [crate::Operation::SelectQueueItem(index(2))]
+        );
+        assert!(edit(QueueEdit::Select(99)).is_none());
+        assert!(
+            edit(QueueEdit::Reorder {
+                item_ids: vec![12],
+                insert_before: Some(99),
+            })
+            .is_none()
+        );
+        assert!(
+            edit(QueueEdit::Insert {
+                items: Vec::new(),
+                insert_before: Some(99),
+            })
+            .is_none()
+        );
+    }
+
+    #[test]
+    fn jumps_follow_the_play_order() {
+        let ids = [10, 11, 12, 13, 14];
+        let order = PlayOrder::shuffled(ids.len(), 2);
+        let jump = |jump, repeat| QueueEdit::Jump(jump).operations(&ids, Some(2), &order, repeat);
+        let next = order.next(ids.len(), 2, RepeatMode::Off).unwrap();
+        assert_eq!(
+            jump(1, RepeatMode::Off),
+            Some(vec![crate::Operation::SelectQueueItem(index(next as u32))])
+        );
+        // The playing item starts the shuffled order, so only a repeating
+        // queue has anything before it.
+        assert_eq!(jump(-1, RepeatMode::Off), None);
+        let last = order.step(ids.len(), 2, -1, RepeatMode::All).unwrap();
+        assert_eq!(order.next(ids.len(), last, RepeatMode::All), Some(2));
+        assert_eq!(
+            jump(-1, RepeatMode::All),
+            Some(vec![crate::Operation::SelectQueueItem(index(last as u32))])
+        );
+    }
+
+    #[test]
+    fn stopping_reports_the_pending_idle_reason_once() {
+        let mut tracker = StatusTracker::default();
+        let mut status = initial_media_status();
+        tracker.apply(
+            &mut status,
+            StatusUpdate::IdleReason(google_cast_protocol::IdleReason::Cancelled),
+        );
+        let stopped = || StatusUpdate::PlayerState(crate::player::PlayerState::Stopped);
+        assert_eq!(
+            tracker.apply(&mut status, stopped()),
+            Some(StatusChange::PlayerState)
+        );
+        assert_eq!(
+            status.idle_reason,
+            Some(google_cast_protocol::IdleReason::Cancelled)
+        );
+        tracker.apply(&mut status, stopped());
+        assert_eq!(
+            status.idle_reason,
+            Some(google_cast_protocol::IdleReason::Finished)
+        );
+    }
+
+    #[test]
+    fn loading_clears_the_reason_of_an_earlier_error() {
+        let mut tracker = StatusTracker::default();
+        let mut status = initial_media_status();
+        tracker.apply(
+            &mut status,
+            StatusUpdate::IdleReason(google_cast_protocol::IdleReason::Error),
+        );
+        tracker.apply(
+            &mut status,
+            StatusUpdate::Media(MediaDescription {
+                container: "video/mp4".to_owned(),
+                url: "http://example.com/next.mp4".to_owned(),
+                title: None,
+                thumbnail_url: None,
+            }),
+        );
+        tracker.apply(
+            &mut status,
+            StatusUpdate::PlayerState(crate::player::PlayerState::Stopped),
+        );
+        assert_eq!(
+            status.idle_reason,
+            Some(google_cast_protocol::IdleReason::Finished)
+        );
+    }
+
+    #[test]
+    fn only_position_jumps_are_reported() {
+        let mut tracker = StatusTracker::default();
+        let mut status = initial_media_status();
+        assert_eq!(
+            tracker.apply(&mut status, StatusUpdate::Position(10.0)),
+            None
+        );
+        // Paused, so the position is expected to stay put.
+        assert_eq!(
+            tracker.apply(&mut status, StatusUpdate::Position(10.5)),
+            None
+        );
+        assert_eq!(
+            tracker.apply(&mut status, StatusUpdate::Position(60.0)),
+            Some(StatusChange::Position)
+        );
+        assert_eq!(status.current_time, 60.0);
+        assert_eq!(tracker.apply(&mut status, StatusUpdate::Volume(1.0)), None);
+    }
+}
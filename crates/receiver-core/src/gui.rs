@@ -4,13 +4,17 @@
 //! Applying a command (which needs the generated slint types) is the UI
 //! layer's job; see `receiver-ui`'s module of the same name.
 
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
 #[cfg(any(target_os = "macos", target_os = "windows"))]
 use crate::ui_types::UiUpdaterState;
 use crate::{
+    fcast::SleepTimerSetting,
     image::DecodedImage,
-    ui_types::{AppState, GuiPlaybackState, QrCode, UiMediaTrack, UiPlayerVariant, UiToastKind},
+    ui_types::{
+        AppState, GuiPlaybackState, QrCode, UiHistoryEntry, UiMediaTrack, UiPlayerVariant,
+        UiToastKind,
+    },
 };
 use parking_lot::{Condvar, Mutex};
 use tokio::sync::mpsc::UnboundedSender;
@@ -111,6 +115,16 @@ pub enum UpdateGuiCommand {
         duration_s: Seconds,
     },
     SetBufferedRanges(Vec<(f32, f32)>),
+    SetChapterMarks(Vec<f32>),
+    SetSleepTimer {
+        armed: SleepTimerSetting,
+        remaining: Option<Duration>,
+    },
+    SetDelays {
+        audio_ms: i32,
+        subtitle_ms: i32,
+    },
+    SetPlaybackHistory(Vec<UiHistoryEntry>),
     SetMediaTitle(String),
     SetArtistName(String),
     ClearAudioCovers,
@@ -120,12 +134,14 @@ pub enum UpdateGuiCommand {
         videos: Option<Vec<UiMediaTrack>>,
         audios: Option<Vec<UiMediaTrack>>,
         subtitles: Option<Vec<UiMediaTrack>>,
+        secondary_subtitles: Option<Vec<UiMediaTrack>>,
     },
     SetTrackIds {
         video: i32,
         audio: i32,
         subtitle: i32,
     },
+    SetSecondarySubtitleTrack(i32),
     ClearVideoOverlays,
     SetConnectionDetails {
         qr_code: IgnoredDebug<QrCode>,
@@ -152,6 +168,15 @@ pub enum UpdateGuiCommand {
     /// Dismisses the report-bug popup so a stale one never sits over the
     /// next item. Sent on every new load.
     HideBugReport,
+    /// The pairing popup for an unpaired FCast sender. `qr` encodes the
+    /// connection URL with the PIN, so scanning it pairs too.
+    ShowPairing {
+        sender_name: Option<String>,
+        pin: String,
+        qr: Option<IgnoredDebug<QrCode>>,
+    },
+    /// The sender paired or went away.
+    HidePairing,
     SetPlaybackState(GuiPlaybackState),
     ClearImageState,
     SetImageViaPlayer(bool),
@@ -163,6 +188,8 @@ pub enum UpdateGuiCommand {
         remaining_ms: u64,
         total_ms: u64,
     },
+    /// The media source is reconnecting after a dropped connection.
+    SetSourceReconnecting(bool),
     SetPlaybackRate(f32),
     #[cfg(any(target_os = "macos", target_os = "windows"))]
     SetUpdateState(UiUpdaterState),
@@ -239,6 +266,7 @@ pub struct GuiController {
     playback_rate: f32,
     is_live: bool,
     backoff_active: bool,
+    reconnecting: bool,
     is_visible: GuiIsVisible,
 }
 
@@ -250,6 +278,7 @@ impl GuiController {
             playback_rate: -1.0,
             is_live: false,
             backoff_active: false,
+            reconnecting: false,
             is_visible,
         }
     }
@@ -340,6 +369,30 @@ impl GuiController {
         self.send(UpdateGuiCommand::SetBufferedRanges(ranges));
     }
 
+    /// Push the chapter starts (seconds) the scrubber marks.
+    pub fn set_chapter_marks(&self, starts: Vec<f32>) {
+        self.send(UpdateGuiCommand::SetChapterMarks(starts));
+    }
+
+    /// Show the sleep timer: what it was set to, and for a deadline the time
+    /// left.
+    pub fn set_sleep_timer(&self, armed: SleepTimerSetting, remaining: Option<Duration>) {
+        self.send(UpdateGuiCommand::SetSleepTimer { armed, remaining });
+    }
+
+    /// Show the current item's audio and subtitle delays.
+    pub fn set_delays(&self, delays: crate::av_delay::Delays) {
+        self.send(UpdateGuiCommand::SetDelays {
+            audio_ms: (delays.audio_micros / 1000) as i32,
+            subtitle_ms: (delays.subtitle_micros / 1000) as i32,
+        });
+    }
+
+    /// Show the most recently played items on the idle screen.
+    pub fn set_playback_history(&self, entries: Vec<UiHistoryEntry>) {
+        self.send(UpdateGuiCommand::SetPlaybackHistory(entries));
+    }
+
     pub fn set_media_title(&self, title: String) {
         self.send(UpdateGuiCommand::SetMediaTitle(title));
     }
@@ -365,11 +418,13 @@ impl GuiController {
         videos: Vec<UiMediaTrack>,
         audios: Vec<UiMediaTrack>,
         subtitles: Vec<UiMediaTrack>,
+        secondary_subtitles: Vec<UiMediaTrack>,
     ) {
         self.send(UpdateGuiCommand::SetTracks {
             videos: Some(videos),
             audios: Some(audios),
             subtitles: Some(subtitles),
+            secondary_subtitles: Some(secondary_subtitles),
         });
     }
 
@@ -378,6 +433,7 @@ impl GuiController {
             videos: None,
             audios: None,
             subtitles: None,
+            secondary_subtitles: None,
         });
     }
 
@@ -389,6 +445,11 @@ impl GuiController {
         });
     }
 
+    /// -1 for none.
+    pub fn set_secondary_subtitle_track(&self, id: i32) {
+        self.send(UpdateGuiCommand::SetSecondarySubtitleTrack(id));
+    }
+
     pub fn clear_video_overlays(&self) {
         self.send(UpdateGuiCommand::ClearVideoOverlays);
     }
@@ -428,6 +489,18 @@ impl GuiController {
         self.send(UpdateGuiCommand::HideBugReport);
     }
 
+    pub fn show_pairing(&self, sender_name: Option<String>, pin: String, qr: Option<QrCode>) {
+        self.send(UpdateGuiCommand::ShowPairing {
+            sender_name,
+            pin,
+            qr: qr.map(IgnoredDebug),
+        });
+    }
+
+    pub fn hide_pairing(&self) {
+        self.send(UpdateGuiCommand::HidePairing);
+    }
+
     pub fn set_playback_state(&mut self, state: GuiPlaybackState) {
         if state != self.playback_state {
             self.send(UpdateGuiCommand::SetPlaybackState(state));
@@ -474,6 +547,15 @@ impl GuiController {
         });
     }
 
+    /// Show or hide the "reconnecting" notice. Unchanged values are
+    /// swallowed so stop/load paths can clear blindly.
+    pub fn set_source_reconnecting(&mut self, reconnecting: bool) {
+        if reconnecting != self.reconnecting {
+            self.send(UpdateGuiCommand::SetSourceReconnecting(reconnecting));
+            self.reconnecting = reconnecting;
+        }
+    }
+
     pub fn set_seek_pending(&self, pending: bool) {
         self.send(UpdateGuiCommand::SetSeekPending(pending));
     }
@@ -0,0 +1,277 @@
+//! Playback history: what was cast recently, by whom, and how much of it
+//! played. Persisted beside `config.toml` like the resume positions, bounded
+//! to the most recently played items, and handed to senders on request
+//! (`PlaybackHistoryRequest`) so they can cast an item again.
+
+use std::{
+    collections::VecDeque,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use fcast_protocol::v4;
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+use crate::config::{read_json_state, write_json_state};
+
+const FILE_NAME: &str = "playback-history.json";
+/// Items remembered, the least recently played is forgotten first.
+const MAX_ENTRIES: usize = 100;
+/// Position steps longer than this between two progress ticks are seeks, not
+/// playback.
+const MAX_WATCHED_STEP_SECS: f64 = 5.0;
+/// Budget for the entries of one `PlaybackHistory` reply, leaving the rest of
+/// the packet for framing. Older entries past it are left out.
+const MAX_REPLY_BYTES: usize = v4::MAX_PACKET_SIZE / 2;
+/// Rough flatbuffer cost of an entry besides its strings.
+const ENTRY_OVERHEAD_BYTES: usize = 96;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+    /// Without its query, like the resume positions' keys.
+    pub url: String,
+    pub container: String,
+    pub title: Option<String>,
+    pub thumbnail_url: Option<String>,
+    /// Seconds played.
+    pub watched: f64,
+    /// `None` for live streams.
+    pub duration: Option<f64>,
+    /// Unix seconds playback started at.
+    pub played_at: u64,
+    pub sender_name: Option<String>,
+}
+
+impl Entry {
+    /// An inline (`data:`) thumbnail is dropped, it can run to megabytes and
+    /// would be kept and handed out with every history reply.
+    pub fn new(
+        url: String,
+        container: String,
+        title: Option<String>,
+        thumbnail_url: Option<String>,
+        sender_name: Option<String>,
+    ) -> Self {
+        let thumbnail_url = thumbnail_url.filter(|url| !url.starts_with("data:"));
+        let played_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |since| since.as_secs());
+        Self {
+            url,
+            container,
+            title,
+            thumbnail_url,
+            watched: 0.0,
+            duration: None,
+            played_at,
+            sender_name,
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        [
+            Some(&self.url),
+            Some(&self.container),
+            self.title.as_ref(),
+            self.thumbnail_url.as_ref(),
+            self.sender_name.as_ref(),
+        ]
+        .into_iter()
+        .flatten()
+        .map(String::len)
+        .sum::<usize>()
+            + ENTRY_OVERHEAD_BYTES
+    }
+
+    fn to_v4(&self) -> v4::HistoryEntry {
+        v4::HistoryEntry {
+            url: self.url.clone(),
+            container: self.container.clone(),
+            title: self.title.clone(),
+            thumbnail_url: self.thumbnail_url.clone(),
+            watched: self.watched,
+            duration: self.duration,
+            played_at: self.played_at,
+            sender_name: self.sender_name.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct PlaybackHistory {
+    /// `None` keeps the history for this launch only.
+    path: Option<PathBuf>,
+    /// Least recently played first.
+    entries: VecDeque<Entry>,
+    /// Where the playing item was at the last progress tick.
+    last_position: Option<f64>,
+    dirty: bool,
+}
+
+impl PlaybackHistory {
+    /// Load the history persisted in `dir`. A missing or unreadable file
+    /// starts empty.
+    pub fn load(dir: Option<&Path>) -> Self {
+        let path = dir.map(|dir| dir.join(FILE_NAME));
+        let entries: VecDeque<Entry> = path
+            .as_deref()
+            .and_then(|path| read_json_state(path, "playback history"))
+            .unwrap_or_default();
+        debug!(count = entries.len(), "Loaded playback history");
+        Self {
+            path,
+            entries,
+            last_position: None,
+            dirty: false,
+        }
+    }
+
+    /// Record that `entry` started playing. An earlier play of the same URL
+    /// is replaced.
+    pub fn started(&mut self, entry: Entry) {
+        self.entries.retain(|old| old.url != entry.url);
+        self.entries.push_back(entry);
+        while self.entries.len() > MAX_ENTRIES {
+            self.entries.pop_front();
+        }
+        self.last_position = None;
+        self.dirty = true;
+    }
+
+    /// Count the playback since the last tick towards the item playing, which
+    /// is the newest entry. `duration` is zero for live streams.
+    pub fn progress(&mut self, position: f64, duration: f64) {
+        let Some(entry) = self.entries.back_mut() else {
+            return;
+        };
+        if let Some(last) = self.last_position.replace(position) {
+            let step = position - last;
+            if step > 0.0 && step <= MAX_WATCHED_STEP_SECS {
+                entry.watched += step;
+                self.dirty = true;
+            }
+        }
+        let duration = (duration > 0.0).then_some(duration);
+        if entry.duration != duration {
+            entry.duration = duration;
+            self.dirty = true;
+        }
+    }
+
+    /// The playing item stopped, the next tick isn't a continuation of the
+    /// last one.
+    pub fn stopped(&mut self) {
+        self.last_position = None;
+    }
+
+    /// Most recently played first, `limit` of them, or all when zero.
+    pub fn recent(&self, limit: usize) -> impl Iterator<Item = &Entry> {
+        let limit = if limit == 0 { usize::MAX } else { limit };
+        self.entries.iter().rev().take(limit)
+    }
+
+    /// Build the answer to a sender's `PlaybackHistoryRequest`, as many of
+    /// the most recent entries as fit in one packet.
+    pub fn message(&self, request_id: u32, limit: u32) -> v4::ConstructedMessage<'static> {
+        let mut budget = MAX_REPLY_BYTES;
+        let entries = self
+            .recent(limit as usize)
+            .take_while(|entry| match budget.checked_sub(entry.encoded_len()) {
+                Some(left) => {
+                    budget = left;
+                    true
+                }
+                None => false,
+            })
+            .map(Entry::to_v4)
+            .collect::<Vec<_>>();
+        v4::MessageBuilder::new().playback_history(request_id, &entries)
+    }
+
+    /// Write the history out if it changed since the last save.
+    pub fn save(&mut self) {
+        if !self.dirty {
+            return;
+        }
+        self.dirty = false;
+        if let Some(path) = self.path.as_deref() {
+            write_json_state(path, &self.entries, "playback history");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(url: &str) -> Entry {
+        Entry::new(url.to_owned(), "video/mp4".to_owned(), None, None, None)
+    }
+
+    #[test]
+    fn seeks_do_not_count_as_watched() {
+        let mut history = PlaybackHistory::default();
+        history.started(entry("http://a/v.mp4"));
+        for position in [0.0, 1.0, 2.0, 3.0] {
+            history.progress(position, 600.0);
+        }
+        // A seek ahead, then playback resumes from there.
+        history.progress(300.0, 600.0);
+        history.progress(301.0, 600.0);
+        let played = history.recent(1).next().unwrap();
+        assert_eq!(played.watched, 4.0);
+        assert_eq!(played.duration, Some(600.0));
+    }
+
+    #[test]
+    fn playing_again_moves_an_item_to_the_front() {
+        let mut history = PlaybackHistory::default();
+        history.started(entry("http://a/1.mp4"));
+        history.started(entry("http://a/2.mp4"));
+        history.started(entry("http://a/1.mp4"));
+        let urls = history
+            .recent(0)
+            .map(|entry| entry.url.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(urls, ["http://a/1.mp4", "http://a/2.mp4"]);
+
+        for n in 0..MAX_ENTRIES {
+            history.started(entry(&format!("http://a/{n}.mp4")));
+        }
+        assert_eq!(history.entries.len(), MAX_ENTRIES);
+        assert_eq!(history.recent(2).count(), 2);
+    }
+
+    #[test]
+    fn the_reply_fits_in_one_packet() {
+        let mut history = PlaybackHistory::default();
+        let inline = format!("data:image/jpeg;base64,{}", "A".repeat(64 * 1024));
+        history.started(Entry::new(
+            "http://a/inline.mp4".to_owned(),
+            "video/mp4".to_owned(),
+            None,
+            Some(inline),
+            None,
+        ));
+        assert_eq!(history.recent(1).next().unwrap().thumbnail_url, None);
+
+        // Long titles add up past the packet size over a full history.
+        for n in 0..MAX_ENTRIES {
+            let mut entry = entry(&format!("http://a/{n}.mp4"));
+            entry.title = Some("t".repeat(16 * 1024));
+            history.started(entry);
+        }
+        let msg = history.message(1, 0);
+        assert!(msg.len() < v4::MAX_PACKET_SIZE);
+        let reply = v4::flat::root_as_packet(&msg)
+            .unwrap()
+            .payload_as_playback_history()
+            .unwrap();
+        let returned = v4::read_history(&reply);
+        assert!(!returned.is_empty() && returned.len() < MAX_ENTRIES);
+        // The most recent come first.
+        let newest = format!("http://a/{}.mp4", MAX_ENTRIES - 1);
+        assert_eq!(returned[0].url, newest);
+    }
+}
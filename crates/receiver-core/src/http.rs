@@ -6,6 +6,9 @@
 //! - with identical request-line + headers + Content-Length framing.
 //!   Hand-rolled
 //! because `rtsp-types` (used by RAOP) rejects the `HTTP/1.1` version token.
+//! The DLNA renderer serves its description, SOAP control and GENA
+//! subscriptions over the same framing, the control API its JSON commands and
+//! WebSocket upgrade, and the metrics endpoint its scrapes.
 
 use std::fmt::Write as _;
 
@@ -16,6 +19,35 @@ use tokio::{
     net::TcpStream,
 };
 
+/// Longest request line and headers accepted.
+const MAX_HEAD_BYTES: usize = 16 * 1024;
+/// Largest body accepted unless the server raises it with
+/// [`Connection::with_max_body`].
+const DEFAULT_MAX_BODY_BYTES: usize = 1024 * 1024;
+
+/// A request over the size limits. The connection answers it and closes.
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum TooLarge {
+    #[error("request head over {MAX_HEAD_BYTES} bytes")]
+    Head,
+    #[error("request body of {length} bytes over the {limit} byte limit")]
+    Body {
+        protocol: String,
+        length: usize,
+        limit: usize,
+    },
+}
+
+impl TooLarge {
+    fn response(&self) -> Response {
+        match self {
+            // Without a complete request line the protocol is a guess.
+            Self::Head => Response::new("HTTP/1.1", 431, "Request Header Fields Too Large"),
+            Self::Body { protocol, .. } => Response::new(protocol, 413, "Payload Too Large"),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct Request {
     pub method: String,
@@ -94,6 +126,7 @@ impl Response {
 pub struct Connection {
     stream: BufWriter<TcpStream>,
     buffer: BytesMut,
+    max_body: usize,
 }
 
 impl Connection {
@@ -101,14 +134,30 @@ impl Connection {
         Self {
             stream: BufWriter::new(stream),
             buffer: BytesMut::with_capacity(2048),
+            max_body: DEFAULT_MAX_BODY_BYTES,
         }
     }
 
-    /// Read the next complete request, or `None` on a clean EOF.
+    /// Accept bodies up to `max_body` bytes instead of the default.
+    pub fn with_max_body(mut self, max_body: usize) -> Self {
+        self.max_body = max_body;
+        self
+    }
+
+    /// Read the next complete request, or `None` on a clean EOF. A request
+    /// over the size limits is answered with a `431` or `413` and fails with
+    /// [`TooLarge`].
     pub async fn read_request(&mut self) -> Result<Option<Request>> {
         loop {
-            if let Some(req) = parse_request(&mut self.buffer)? {
-                return Ok(Some(req));
+            match parse_request(&mut self.buffer, self.max_body) {
+                Ok(Some(req)) => return Ok(Some(req)),
+                Ok(None) => (),
+                Err(err) => {
+                    if let Some(too_large) = err.downcast_ref::<TooLarge>() {
+                        self.write_response(&too_large.response()).await?;
+                    }
+                    return Err(err);
+                }
             }
             if 0 == self.stream.read_buf(&mut self.buffer).await? {
                 if self.buffer.is_empty() {
@@ -124,14 +173,27 @@ impl Connection {
         self.stream.flush().await?;
         Ok(())
     }
+
+    /// Give up the stream after a protocol upgrade, with whatever the peer
+    /// already sent past the last request.
+    pub fn into_parts(self) -> (TcpStream, BytesMut) {
+        (self.stream.into_inner(), self.buffer)
+    }
 }
 
 /// Attempt to parse a single request from `buf`. On success consumes the
-/// request bytes from `buf`. Returns `Ok(None)` when more data is needed.
-fn parse_request(buf: &mut BytesMut) -> Result<Option<Request>> {
+/// request bytes from `buf`. Returns `Ok(None)` when more data is needed, and
+/// fails with [`TooLarge`] rather than buffering past the limits.
+fn parse_request(buf: &mut BytesMut, max_body: usize) -> Result<Option<Request>> {
     let Some(header_end) = find_subslice(buf, b"\r\n\r\n") else {
+        if buf.len() > MAX_HEAD_BYTES {
+            return Err(TooLarge::Head.into());
+        }
         return Ok(None);
     };
+    if header_end > MAX_HEAD_BYTES {
+        return Err(TooLarge::Head.into());
+    }
     let head = std::str::from_utf8(&buf[..header_end])?;
     let mut lines = head.split("\r\n");
 
@@ -160,6 +222,14 @@ fn parse_request(buf: &mut BytesMut) -> Result<Option<Request>> {
         }
         headers.push((name, value));
     }
+    if content_length > max_body {
+        return Err(TooLarge::Body {
+            protocol,
+            length: content_length,
+            limit: max_body,
+        }
+        .into());
+    }
 
     let body_start = header_end + 4;
     if buf.len() < body_start + content_length {
@@ -193,7 +263,9 @@ mod tests {
         let mut buf = BytesMut::from(
             &b"GET /info?txtAirPlay HTTP/1.1\r\nCSeq: 0\r\nUser-Agent: AirPlay/x\r\n\r\n"[..],
         );
-        let req = parse_request(&mut buf).unwrap().unwrap();
+        let req = parse_request(&mut buf, DEFAULT_MAX_BODY_BYTES)
+            .unwrap()
+            .unwrap();
         assert_eq!(req.method, "GET");
         assert_eq!(req.url, "/info?txtAirPlay");
         assert_eq!(req.path(), "/info");
@@ -207,10 +279,41 @@ mod tests {
     fn waits_for_full_body() {
         let mut buf =
             BytesMut::from(&b"POST /fp-setup RTSP/1.0\r\nContent-Length: 4\r\n\r\nAB"[..]);
-        assert!(parse_request(&mut buf).unwrap().is_none());
+        assert!(parse_request(&mut buf, 4).unwrap().is_none());
         buf.extend_from_slice(b"CD");
-        let req = parse_request(&mut buf).unwrap().unwrap();
+        let req = parse_request(&mut buf, 4).unwrap().unwrap();
         assert_eq!(req.body, b"ABCD");
         assert_eq!(req.protocol, "RTSP/1.0");
     }
+
+    #[test]
+    fn refuses_oversized_heads_and_bodies() {
+        let too_large = |buf: &mut BytesMut| {
+            parse_request(buf, 4)
+                .unwrap_err()
+                .downcast::<TooLarge>()
+                .unwrap()
+        };
+
+        // Headers that never end stop being buffered.
+        let mut buf = BytesMut::from(&b"GET / HTTP/1.1\r\n"[..]);
+        buf.extend_from_slice(&b"X-Filler: a\r\n".repeat(MAX_HEAD_BYTES / 8));
+        assert_eq!(too_large(&mut buf), TooLarge::Head);
+        assert_eq!(TooLarge::Head.response().status, 431);
+
+        // A body is refused from its Content-Length, before it arrives.
+        let mut buf = BytesMut::from(&b"POST /x RTSP/1.0\r\nContent-Length: 5\r\n\r\n"[..]);
+        let body = too_large(&mut buf);
+        assert_eq!(
+            body,
+            TooLarge::Body {
+                protocol: "RTSP/1.0".to_owned(),
+                length: 5,
+                limit: 4,
+            }
+        );
+        let response = body.response();
+        assert_eq!(response.status, 413);
+        assert_eq!(response.protocol, "RTSP/1.0");
+    }
 }
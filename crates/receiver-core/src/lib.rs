@@ -9,13 +9,18 @@ pub use tracing;
 #[cfg(feature = "airplay")]
 mod airplay;
 pub mod application;
+pub mod av_delay;
 pub mod config;
+mod control_api;
+mod dlna;
 mod external_subtitles;
 pub mod fcast;
 mod freeze_watchdog;
 mod gcast;
 pub mod gstreamer;
 pub mod gui;
+mod history;
+mod http;
 pub mod image;
 pub mod inspector_graph;
 pub mod logging;
@@ -24,9 +29,19 @@ mod mdns;
 pub mod media_formats;
 mod media_source;
 pub mod message;
+pub mod metrics;
+mod mpris;
+pub mod pairing;
 pub mod player;
 mod queue_cache;
+mod queue_order;
 mod raop;
+mod resume;
+mod rtsp_auth;
+mod secondary_subtitles;
+mod sleep_timer;
+mod subtitle_style;
+mod tls_identity;
 pub mod ui_scaling;
 pub mod ui_types;
 mod user_agent;
@@ -95,6 +110,10 @@ pub use fcast_runtime::RUNTIME;
 struct GCastUpdateSender(Option<UnboundedSender<gcast::StatusUpdate>>);
 
 impl GCastUpdateSender {
+    fn is_enabled(&self) -> bool {
+        self.0.is_some()
+    }
+
     fn send(&mut self, update: gcast::StatusUpdate) {
         let Some(tx) = self.0.as_ref() else {
             return;
@@ -108,6 +127,44 @@ impl GCastUpdateSender {
     }
 }
 
+struct MprisUpdateSender(Option<UnboundedSender<mpris::StatusUpdate>>);
+
+impl MprisUpdateSender {
+    fn is_enabled(&self) -> bool {
+        self.0.is_some()
+    }
+
+    fn send(&mut self, update: mpris::StatusUpdate) {
+        let Some(tx) = self.0.as_ref() else {
+            return;
+        };
+        if tx.send(update).is_err() {
+            // No session bus, or the name was taken.
+            debug!("MPRIS server not running, disabling status updates");
+            self.0 = None;
+        }
+    }
+}
+
+struct DlnaUpdateSender(Option<UnboundedSender<dlna::StatusUpdate>>);
+
+impl DlnaUpdateSender {
+    fn is_enabled(&self) -> bool {
+        self.0.is_some()
+    }
+
+    fn send(&mut self, update: dlna::StatusUpdate) {
+        let Some(tx) = self.0.as_ref() else {
+            return;
+        };
+        if tx.send(update).is_err() {
+            // The renderer's port was taken.
+            debug!("DLNA server not running, disabling status updates");
+            self.0 = None;
+        }
+    }
+}
+
 #[cfg(not(target_os = "android"))]
 #[derive(clap::Parser)]
 #[command(name = "FCast Receiver")]
@@ -141,6 +198,12 @@ pub struct CliArgs {
     /// Disable the FCast receiver
     #[arg(long, default_value_t = false)]
     no_fcast: bool,
+    /// Disable the DLNA media renderer
+    #[arg(long, default_value_t = false)]
+    no_dlna: bool,
+    /// Don't publish playback to the desktop's media controls (MPRIS)
+    #[arg(long, default_value_t = false)]
+    no_mpris: bool,
     /// Change what video frame render profile should be used
     #[arg(long, value_enum)]
     render_profile: Option<RenderProfile>,
@@ -163,6 +226,10 @@ pub struct CliArgs {
     /// Force HDR content to be tone-mapped to SDR.
     #[arg(long, default_value_t = false)]
     pub disable_hdr_output: bool,
+    /// Replace the persisted TLS identity with a new one. Senders that pinned
+    /// the old fingerprint have to reconnect from scratch
+    #[arg(long, default_value_t = false)]
+    rotate_tls_identity: bool,
 }
 
 /// The receiver's effective settings: parsed CLI flags plus the persisted
@@ -237,6 +304,37 @@ impl Settings {
         !self.cli.no_google_cast && self.config.get().chromecast.enabled
     }
 
+    pub fn dlna_enabled(&self) -> bool {
+        !self.cli.no_dlna && self.config.get().dlna.enabled
+    }
+
+    pub fn control_api_enabled(&self) -> bool {
+        self.config.get().control_api.enabled
+    }
+
+    /// Token control API clients must present, if one is configured.
+    pub fn control_api_token(&self) -> Option<String> {
+        self.config.get().control_api.token.clone()
+    }
+
+    /// Browser origins allowed to call the control API.
+    pub fn control_api_allowed_origins(&self) -> Vec<String> {
+        self.config.get().control_api.allowed_origins.clone()
+    }
+
+    pub fn metrics_enabled(&self) -> bool {
+        self.config.get().metrics.enabled
+    }
+
+    /// Whether the metrics endpoint listens beyond loopback.
+    pub fn metrics_public(&self) -> bool {
+        self.config.get().metrics.public
+    }
+
+    pub fn mpris_enabled(&self) -> bool {
+        !self.cli.no_mpris && self.config.get().interface.mpris
+    }
+
     #[cfg(feature = "airplay")]
     pub fn airplay_enabled(&self) -> bool {
         !self.cli.no_airplay && self.config.get().airplay.enabled
@@ -264,6 +362,17 @@ impl Settings {
             .unwrap_or_else(mdns::fcast_device_name)
     }
 
+    /// Password RAOP senders must enter, if any.
+    pub fn raop_password(&self) -> Option<String> {
+        self.config.get().raop.password.clone()
+    }
+
+    /// Password AirPlay senders must enter, if any.
+    #[cfg(feature = "airplay")]
+    pub fn airplay_password(&self) -> Option<String> {
+        self.config.get().airplay.password.clone()
+    }
+
     /// Broadcast name for Google Cast. Defaults to `Chromecast-<hostname>`.
     pub fn chromecast_name(&self) -> String {
         self.config
@@ -275,6 +384,17 @@ impl Settings {
             .unwrap_or_else(mdns::chromecast_device_name)
     }
 
+    /// Friendly name of the DLNA renderer. Defaults to `FCast-<hostname>`.
+    pub fn dlna_name(&self) -> String {
+        self.config
+            .get()
+            .dlna
+            .name
+            .as_deref()
+            .map(expand_name_vars)
+            .unwrap_or_else(mdns::fcast_device_name)
+    }
+
     pub fn headless(&self) -> bool {
         self.cli.headless || self.config.get().interface.headless
     }
@@ -295,6 +415,21 @@ impl Settings {
         self.cli.no_fullscreen_player || !self.config.get().interface.fullscreen_player
     }
 
+    pub fn rotate_tls_identity(&self) -> bool {
+        self.cli.rotate_tls_identity
+    }
+
+    /// When loads resume from a remembered position, from `[playback] resume`.
+    pub fn resume_mode(&self) -> resume::ResumeMode {
+        self.config
+            .get()
+            .playback
+            .resume
+            .as_deref()
+            .and_then(resume::ResumeMode::parse)
+            .unwrap_or_default()
+    }
+
     pub fn disable_hdr_output(&self) -> bool {
         self.cli.disable_hdr_output || !self.config.get().video.hdr_output
     }
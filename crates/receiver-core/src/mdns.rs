@@ -126,14 +126,16 @@ pub fn start_daemon(
     }
 
     if settings.raop_enabled() {
-        let (raop_service, raop_config) = raop::service_info(raop_name).unwrap();
+        let (raop_service, raop_config) =
+            raop::service_info(raop_name, settings.raop_password()).unwrap();
         daemon.register(raop_service).unwrap();
         msg_tx.raop(Raop::ConfigAvailable(raop_config));
     }
 
     #[cfg(feature = "airplay")]
     if settings.airplay_enabled() {
-        let (airplay_service, airplay_config) = airplay::service_info(fcast_name).unwrap();
+        let (airplay_service, airplay_config) =
+            airplay::service_info(fcast_name, settings.airplay_password()).unwrap();
         daemon.register(airplay_service).unwrap();
         msg_tx.airplay(AirPlay::ConfigAvailable(airplay_config));
     }
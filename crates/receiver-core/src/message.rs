@@ -153,6 +153,11 @@ pub enum Message {
         origin: PacketOrigin,
         op: crate::Operation,
     },
+    /// A Cast queue edit, resolved against the live queue.
+    GCastQueueEdit {
+        origin: PacketOrigin,
+        edit: crate::gcast::QueueEdit,
+    },
     Image(crate::image::Event),
     QueueCache(crate::queue_cache::Event),
     Mdns(Mdns),
@@ -164,6 +169,8 @@ pub enum Message {
         id: i32,
         variant: player::TrackKind,
     },
+    /// The player menu's secondary subtitle choice, -1 for none.
+    SelectSecondarySubtitle(i32),
     /// A boolean setting was toggled in the settings drawer; `key` is a dotted
     /// `section.key`.
     SetConfigBool {
@@ -176,6 +183,29 @@ pub enum Message {
         key: String,
         value: String,
     },
+    /// Replace the FCast TLS identity (settings drawer); applies to new
+    /// sessions immediately.
+    RotateTlsIdentity,
+    /// An unpaired v4 sender introduced itself; show `pin` until it pairs or
+    /// disconnects.
+    PairingRequested {
+        sender: SenderId,
+        sender_name: Option<String>,
+        pin: String,
+    },
+    /// A v4 sender introduced itself and may now control the receiver.
+    SenderIntroduced {
+        sender: SenderId,
+        sender_name: Option<String>,
+    },
+    /// A sender entered the right PIN; remember it and hide the PIN.
+    SenderPaired {
+        sender: SenderId,
+        paired: crate::config::PairedSender,
+    },
+    /// Forget every paired sender (settings drawer). Connected sessions stay
+    /// paired until they reconnect.
+    ForgetPairedSenders,
     ShouldSetLoadingStatus(MediaItemId),
     /// Bounded wait for `AddSubtitleSource` parked on an in-flight load or
     /// unresolved seekability; on expiry the parked adds are rejected with
@@ -189,6 +219,17 @@ pub enum Message {
     PendingSeekCheck {
         epoch: u64,
     },
+    /// A secondary subtitle file finished parsing, `None` if it failed.
+    SecondarySubtitleParsed {
+        origin: PacketOrigin,
+        item: MediaItemId,
+        id: u32,
+        cues: Option<Vec<fcast_video::secondary::TimedCue>>,
+    },
+    /// A deadline sleep timer's countdown or fade step is due.
+    SleepTimerTick {
+        epoch: u64,
+    },
     /// The seek broadcast debounce expired.
     SeekQuietTimeout {
         epoch: u64,
@@ -215,6 +256,8 @@ pub enum Message {
     #[cfg(any(target_os = "macos", target_os = "windows"))]
     AppUpdate(AppUpdate),
     GuiWindowClosed(oneshot::Sender<()>),
+    /// A metrics scrape asking for what only the event loop can read.
+    MetricsSample(oneshot::Sender<crate::metrics::PlayerSample>),
     FCastSenderDisconnect(SenderId),
 }
 
@@ -227,4 +270,6 @@ pub enum ReceiverToFCastSender {
         pos: gst::ClockTime,
         dur: gst::ClockTime,
     },
+    /// An answer to a request from this sender only.
+    Reply(fcast_protocol::v4::ConstructedMessage<'static>),
 }
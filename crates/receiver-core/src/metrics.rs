@@ -0,0 +1,659 @@
+//! Receiver health for Prometheus: `GET /metrics` on port 46897, off by
+//! default.
+//!
+//! Everything here already existed for the debug inspector or the tests; this
+//! module only counts the few things nobody kept (FCast sessions, load
+//! errors, buffering time, freeze recoveries) and writes the lot out in the
+//! text exposition format, or OpenMetrics when the scraper asks for it. The
+//! other protocols have no session table to count from, so the session
+//! metrics are FCast's alone.
+//!
+//! What belongs to the event loop (the pipeline's counters, its streams, the
+//! session table) is asked of it per scrape with [`Message::MetricsSample`].
+//! A loop that doesn't answer in time is itself the finding, so the scrape
+//! still succeeds and says so in `fcast_receiver_event_loop_up`. Render cost
+//! is measured on the render thread, which has no handle on any of this, so
+//! it goes into process-global atomics through [`record_render`].
+
+use std::{
+    collections::BTreeMap,
+    fmt::{Display, Write as _},
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::{Duration, Instant},
+};
+
+use anyhow::Result;
+use fcast_protocol::v4::flat::ErrorKind;
+use fcast_video::cue::CueEngine;
+use parking_lot::Mutex;
+use tokio::{
+    net::{TcpListener, TcpStream},
+    sync::oneshot,
+};
+use tracing::{debug, warn};
+
+use crate::{
+    freeze_watchdog::FreezeAction,
+    http::{Connection, Request, Response},
+    message::{Message, MessageSender},
+    player::PlayerState,
+};
+
+pub const METRICS_TCP_PORT: u16 = 46897;
+/// How long a scrape waits on the event loop before reporting it stuck.
+const SAMPLE_TIMEOUT: Duration = Duration::from_secs(2);
+
+const PROMETHEUS_CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+const OPENMETRICS_CONTENT_TYPE: &str = "application/openmetrics-text; version=1.0.0; charset=utf-8";
+
+/// Upper bounds of the render-cost histogram, in seconds. Dense around one
+/// frame at 60 and 30 fps, where a render starts costing frames.
+const RENDER_BUCKETS: [f64; 10] = [
+    0.001, 0.002, 0.004, 0.006, 0.008, 0.012, 0.016, 0.025, 0.033, 0.05,
+];
+
+/// Render costs, bucketed as they come in. Every field is an atomic so the
+/// render thread never waits on a scrape.
+struct RenderHistogram {
+    /// Renders per bucket, NOT cumulative: the exposition sums them.
+    buckets: [AtomicU64; RENDER_BUCKETS.len()],
+    count: AtomicU64,
+    sum_nanos: AtomicU64,
+    /// The `render-delay` applied to the sink after the latest render.
+    delay_nanos: AtomicU64,
+}
+
+impl RenderHistogram {
+    const fn new() -> Self {
+        Self {
+            buckets: [const { AtomicU64::new(0) }; RENDER_BUCKETS.len()],
+            count: AtomicU64::new(0),
+            sum_nanos: AtomicU64::new(0),
+            delay_nanos: AtomicU64::new(0),
+        }
+    }
+
+    fn record(&self, cost: Duration, applied_delay: Duration) {
+        let seconds = cost.as_secs_f64();
+        if let Some(bucket) = RENDER_BUCKETS.iter().position(|bound| seconds <= *bound) {
+            self.buckets[bucket].fetch_add(1, Ordering::Relaxed);
+        }
+        self.count.fetch_add(1, Ordering::Relaxed);
+        self.sum_nanos
+            .fetch_add(cost.as_nanos() as u64, Ordering::Relaxed);
+        self.delay_nanos
+            .store(applied_delay.as_nanos() as u64, Ordering::Relaxed);
+    }
+
+    fn encode(&self, out: &mut Encoder) {
+        out.family(
+            "fcast_video_render_seconds",
+            "histogram",
+            "What rendering a video frame cost the render thread.",
+        );
+        let mut cumulative = 0;
+        for (bound, bucket) in RENDER_BUCKETS.iter().zip(&self.buckets) {
+            cumulative += bucket.load(Ordering::Relaxed);
+            out.sample(
+                "fcast_video_render_seconds_bucket",
+                &[("le", &bound.to_string())],
+                cumulative,
+            );
+        }
+        let count = self.count.load(Ordering::Relaxed);
+        out.sample(
+            "fcast_video_render_seconds_bucket",
+            &[("le", "+Inf")],
+            count,
+        );
+        out.sample("fcast_video_render_seconds_count", &[], count);
+        out.sample(
+            "fcast_video_render_seconds_sum",
+            &[],
+            seconds(self.sum_nanos.load(Ordering::Relaxed)),
+        );
+        out.gauge(
+            "fcast_video_render_delay_seconds",
+            "The render-delay reserved on the video sink for the render cost.",
+            &[],
+            seconds(self.delay_nanos.load(Ordering::Relaxed)),
+        );
+    }
+}
+
+static RENDER: RenderHistogram = RenderHistogram::new();
+
+/// Count one rendered frame. Called by the render loop with what the frame
+/// cost and the sink `render-delay` in force after it.
+pub fn record_render(cost: Duration, applied_delay: Duration) {
+    RENDER.record(cost, applied_delay);
+}
+
+fn seconds(nanos: u64) -> f64 {
+    Duration::from_nanos(nanos).as_secs_f64()
+}
+
+/// What the event loop answers a [`Message::MetricsSample`] with.
+pub struct PlayerSample {
+    pub state: PlayerState,
+    pub fcast_sessions: usize,
+    pub playbin: fcastplaybin::Stats,
+    pub streams: Vec<fcastplaybin::StreamIoStats>,
+}
+
+/// Time spent in the Buffering state, the current stretch included.
+#[derive(Debug, Default)]
+struct BufferingClock {
+    since: Option<Instant>,
+    total: Duration,
+}
+
+impl BufferingClock {
+    fn set(&mut self, buffering: bool, now: Instant) {
+        match (buffering, self.since) {
+            (true, None) => self.since = Some(now),
+            (false, Some(since)) => {
+                self.total += now.saturating_duration_since(since);
+                self.since = None;
+            }
+            _ => (),
+        }
+    }
+
+    fn total(&self, now: Instant) -> Duration {
+        self.total
+            + self
+                .since
+                .map_or(Duration::ZERO, |since| now.saturating_duration_since(since))
+    }
+}
+
+struct Shared {
+    fcast_sessions: AtomicU64,
+    /// Keyed by the `ErrorKind` variant name; a scrape lists only kinds that
+    /// happened.
+    load_errors: Mutex<BTreeMap<&'static str, u64>>,
+    buffering: Mutex<BufferingClock>,
+    freeze_seeks: AtomicU64,
+    freeze_reloads: AtomicU64,
+    freeze_give_ups: AtomicU64,
+    /// The sink's cue engines, by track; empty when headless.
+    cue_engines: Vec<(&'static str, CueEngine)>,
+}
+
+/// The counters the event loop keeps for the endpoint. Cheap to clone, and
+/// kept whether or not the endpoint is served.
+#[derive(Clone)]
+pub(crate) struct Metrics(Arc<Shared>);
+
+impl Metrics {
+    pub(crate) fn new(cue_engines: Vec<(&'static str, CueEngine)>) -> Self {
+        Self(Arc::new(Shared {
+            fcast_sessions: AtomicU64::new(0),
+            load_errors: Mutex::new(BTreeMap::new()),
+            buffering: Mutex::new(BufferingClock::default()),
+            freeze_seeks: AtomicU64::new(0),
+            freeze_reloads: AtomicU64::new(0),
+            freeze_give_ups: AtomicU64::new(0),
+            cue_engines,
+        }))
+    }
+
+    pub(crate) fn fcast_session_started(&self) {
+        self.0.fcast_sessions.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn load_error(&self, kind: ErrorKind) {
+        let name = kind.variant_name().unwrap_or("Unknown");
+        *self.0.load_errors.lock().entry(name).or_default() += 1;
+    }
+
+    pub(crate) fn buffering(&self, buffering: bool) {
+        self.0.buffering.lock().set(buffering, Instant::now());
+    }
+
+    pub(crate) fn freeze_recovery(&self, action: FreezeAction) {
+        let counter = match action {
+            FreezeAction::None => return,
+            FreezeAction::Seek { .. } => &self.0.freeze_seeks,
+            FreezeAction::Reload { .. } => &self.0.freeze_reloads,
+            FreezeAction::GiveUp { .. } => &self.0.freeze_give_ups,
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// The whole exposition: the counters kept here, the loop's `sample` when
+    /// it answered, and the crate-wide globals.
+    fn encode(&self, sample: Option<&PlayerSample>, openmetrics: bool) -> String {
+        let mut out = Encoder::new(openmetrics);
+        let shared = &self.0;
+
+        out.gauge(
+            "fcast_receiver_event_loop_up",
+            "Whether the event loop answered this scrape in time.",
+            &[],
+            u8::from(sample.is_some()),
+        );
+        let total = out.counter(
+            "fcast_receiver_sessions",
+            "FCast sender sessions accepted since start. Cast, AirPlay, RAOP and DLNA \
+             senders are not counted.",
+        );
+        out.sample(&total, &[], shared.fcast_sessions.load(Ordering::Relaxed));
+        let total = out.counter(
+            "fcast_receiver_load_errors",
+            "Loads and playbacks that failed, by the ErrorKind reported to the sender.",
+        );
+        for (kind, count) in shared.load_errors.lock().iter() {
+            out.sample(&total, &[("kind", *kind)], count);
+        }
+        let total = out.counter(
+            "fcast_receiver_buffering_seconds",
+            "Time playback spent buffering.",
+        );
+        let buffering = shared.buffering.lock().total(Instant::now());
+        out.sample(&total, &[], buffering.as_secs_f64());
+        let total = out.counter(
+            "fcast_receiver_freeze_recoveries",
+            "Frozen playback the freeze watchdog caught, by the recovery it took.",
+        );
+        for (action, counter) in [
+            ("seek", &shared.freeze_seeks),
+            ("reload", &shared.freeze_reloads),
+            ("give_up", &shared.freeze_give_ups),
+        ] {
+            out.sample(
+                &total,
+                &[("action", action)],
+                counter.load(Ordering::Relaxed),
+            );
+        }
+
+        if let Some(sample) = sample {
+            encode_sample(&mut out, sample);
+        }
+
+        let total = out.counter(
+            "fcast_playbin_global_events",
+            "fcastplaybin's process-wide diagnostic counters, see GlobalStats.",
+        );
+        for (name, value) in fcastplaybin::FcastPlaybin::global_stats().counters() {
+            out.sample(&total, &[("counter", name)], value);
+        }
+
+        encode_cues(&mut out, &shared.cue_engines);
+        RENDER.encode(&mut out);
+        out.finish()
+    }
+}
+
+fn encode_sample(out: &mut Encoder, sample: &PlayerSample) {
+    let state = match sample.state {
+        PlayerState::Paused => "paused",
+        PlayerState::Playing => "playing",
+        PlayerState::Buffering => "buffering",
+        PlayerState::Stopped => "stopped",
+    };
+    out.gauge(
+        "fcast_receiver_player_state",
+        "The player's state, as a 1 on the current one.",
+        &[("state", state)],
+        1,
+    );
+    out.gauge(
+        "fcast_receiver_active_sessions",
+        "FCast sender sessions currently connected. Cast, AirPlay, RAOP and DLNA senders \
+         are not counted.",
+        &[],
+        sample.fcast_sessions,
+    );
+    let total = out.counter(
+        "fcast_playbin_events",
+        "The current pipeline's diagnostic counters, see Stats.",
+    );
+    for (name, value) in sample.playbin.counters() {
+        out.sample(&total, &[("counter", name)], value);
+    }
+
+    let mut bytes: BTreeMap<&str, u64> = BTreeMap::new();
+    for stream in &sample.streams {
+        let kind = if stream.external.is_some() {
+            "external_subtitle"
+        } else {
+            match stream
+                .caps
+                .as_ref()
+                .and_then(|caps| caps.structure(0))
+                .map(|structure| structure.name().as_str())
+            {
+                Some(name) if name.starts_with("video/") => "video",
+                Some(name) if name.starts_with("audio/") => "audio",
+                Some(name) if name.starts_with("text/") || name.starts_with("subpicture/") => {
+                    "subtitle"
+                }
+                _ => "other",
+            }
+        };
+        *bytes.entry(kind).or_default() += stream.bytes;
+    }
+    let total = out.counter(
+        "fcast_playbin_stream_bytes",
+        "Compressed bytes the current item's streams fed the decoders, by stream kind.",
+    );
+    for (kind, bytes) in bytes {
+        out.sample(&total, &[("kind", kind)], bytes);
+    }
+}
+
+fn encode_cues(out: &mut Encoder, engines: &[(&'static str, CueEngine)]) {
+    let counters: [(&str, &str, fn(&CueEngine) -> u64); 5] = [
+        (
+            "fcast_cue_dropped",
+            "Subtitle cues dropped because the pending list was full.",
+            CueEngine::dropped_cues,
+        ),
+        (
+            "fcast_cue_bitmap_sets_decoded",
+            "Bitmap subtitle display sets decoded.",
+            CueEngine::bitmap_sets_decoded,
+        ),
+        (
+            "fcast_cue_bitmap_dropped_sets",
+            "Decoded bitmap display sets given up because the pending store was full.",
+            CueEngine::bitmap_dropped_sets,
+        ),
+        (
+            "fcast_cue_bitmap_decode_errors",
+            "Bitmap subtitle packets the decoder could not take.",
+            CueEngine::bitmap_decode_errors,
+        ),
+        (
+            "fcast_cue_bitmap_overflow_resets",
+            "Times the bitmap decode inbox overflowed and reset the decoder.",
+            CueEngine::bitmap_overflow_resets,
+        ),
+    ];
+    for (name, help, read) in counters {
+        let total = out.counter(name, help);
+        for (track, engine) in engines {
+            out.sample(&total, &[("track", *track)], read(engine));
+        }
+    }
+
+    // The engine only keeps the recent packets, which makes for no cumulative
+    // `_sum`/`_count`, so each quantile is a gauge of its own, not a summary.
+    let latencies: Vec<_> = engines
+        .iter()
+        .map(|(track, engine)| {
+            let mut latencies = engine.bitmap_decode_latencies();
+            latencies.sort_unstable();
+            (*track, latencies)
+        })
+        .filter(|(_, latencies)| !latencies.is_empty())
+        .collect();
+    for (name, help, quantile) in [
+        (
+            "fcast_cue_bitmap_decode_p50_seconds",
+            "Median cost of decoding a bitmap subtitle packet, over the recent packets.",
+            0.5,
+        ),
+        (
+            "fcast_cue_bitmap_decode_p95_seconds",
+            "95th percentile cost of decoding a bitmap subtitle packet, over the recent packets.",
+            0.95,
+        ),
+        (
+            "fcast_cue_bitmap_decode_max_seconds",
+            "Highest cost of decoding a bitmap subtitle packet, over the recent packets.",
+            1.0,
+        ),
+    ] {
+        out.family(name, "gauge", help);
+        for (track, latencies) in &latencies {
+            let idx = ((latencies.len() as f64 * quantile).ceil() as usize)
+                .saturating_sub(1)
+                .min(latencies.len() - 1);
+            out.sample(name, &[("track", *track)], latencies[idx].as_secs_f64());
+        }
+    }
+}
+
+/// Writes metric families in the text exposition format, or in OpenMetrics,
+/// which differs in how a counter family is named and in the `# EOF` ending.
+struct Encoder {
+    out: String,
+    openmetrics: bool,
+}
+
+impl Encoder {
+    fn new(openmetrics: bool) -> Self {
+        Self {
+            out: String::new(),
+            openmetrics,
+        }
+    }
+
+    fn family(&mut self, name: &str, kind: &str, help: &str) {
+        let _ = writeln!(self.out, "# HELP {name} {help}");
+        let _ = writeln!(self.out, "# TYPE {name} {kind}");
+    }
+
+    fn sample(&mut self, name: &str, labels: &[(&str, &str)], value: impl Display) {
+        self.out.push_str(name);
+        if !labels.is_empty() {
+            self.out.push('{');
+            for (i, (label, value)) in labels.iter().enumerate() {
+                if i > 0 {
+                    self.out.push(',');
+                }
+                let value = value
+                    .replace('\\', "\\\\")
+                    .replace('"', "\\\"")
+                    .replace('\n', "\\n");
+                let _ = write!(self.out, "{label}=\"{value}\"");
+            }
+            self.out.push('}');
+        }
+        let _ = writeln!(self.out, " {value}");
+    }
+
+    /// Start a counter family and return the name its samples go under.
+    /// `name` is without the `_total` the samples carry, which the text
+    /// format wants on the family too and OpenMetrics doesn't.
+    fn counter(&mut self, name: &str, help: &str) -> String {
+        let total = format!("{name}_total");
+        let family = if self.openmetrics { name } else { &total };
+        self.family(family, "counter", help);
+        total
+    }
+
+    fn gauge(&mut self, name: &str, help: &str, labels: &[(&str, &str)], value: impl Display) {
+        self.family(name, "gauge", help);
+        self.sample(name, labels, value);
+    }
+
+    fn finish(mut self) -> String {
+        if self.openmetrics {
+            self.out.push_str("# EOF\n");
+        }
+        self.out
+    }
+}
+
+/// Ask the event loop for its share. `None` when it didn't answer in time.
+async fn sample(msg_tx: &MessageSender) -> Option<PlayerSample> {
+    let (tx, rx) = oneshot::channel();
+    msg_tx.send(Message::MetricsSample(tx));
+    tokio::time::timeout(SAMPLE_TIMEOUT, rx).await.ok()?.ok()
+}
+
+fn wants_openmetrics(request: &Request) -> bool {
+    request
+        .header("Accept")
+        .is_some_and(|accept| accept.contains("application/openmetrics-text"))
+}
+
+async fn serve(stream: TcpStream, metrics: &Metrics, msg_tx: &MessageSender) -> Result<()> {
+    let mut connection = Connection::new(stream);
+    while let Some(request) = connection.read_request().await? {
+        let protocol = request.protocol.clone();
+        let response = match (request.method.as_str(), request.path()) {
+            ("GET", "/metrics") => {
+                let openmetrics = wants_openmetrics(&request);
+                let sample = sample(msg_tx).await;
+                let body = metrics.encode(sample.as_ref(), openmetrics);
+                let content_type = if openmetrics {
+                    OPENMETRICS_CONTENT_TYPE
+                } else {
+                    PROMETHEUS_CONTENT_TYPE
+                };
+                Response::new(&protocol, 200, "OK").body(content_type, body.into_bytes())
+            }
+            (_, "/metrics") => Response::new(&protocol, 405, "Method Not Allowed"),
+            _ => Response::new(&protocol, 404, "Not Found"),
+        };
+        connection.write_response(&response).await?;
+    }
+    Ok(())
+}
+
+/// Serve the endpoint, on every interface when `public` so a scraper
+/// elsewhere on the network can reach it.
+pub(crate) async fn run_server(
+    metrics: Metrics,
+    msg_tx: MessageSender,
+    public: bool,
+) -> Result<()> {
+    let ip = if public {
+        std::net::Ipv4Addr::UNSPECIFIED
+    } else {
+        std::net::Ipv4Addr::LOCALHOST
+    };
+    let listener = TcpListener::bind((ip, METRICS_TCP_PORT)).await?;
+
+    loop {
+        // A failed accept is per-connection and must not end the endpoint.
+        let (stream, _addr) = match listener.accept().await {
+            Ok(accepted) => accepted,
+            Err(err) => {
+                warn!(?err, "Failed to accept a metrics connection");
+                tokio::time::sleep(crate::application::ACCEPT_ERROR_BACKOFF).await;
+                continue;
+            }
+        };
+        let metrics = metrics.clone();
+        let msg_tx = msg_tx.clone();
+        tokio::spawn(async move {
+            if let Err(err) = serve(stream, &metrics, &msg_tx).await {
+                debug!(?err, "Metrics connection ended with error");
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The lines of `exposition` that are samples of `name`.
+    fn samples<'a>(exposition: &'a str, name: &str) -> Vec<&'a str> {
+        exposition
+            .lines()
+            .filter(|line| {
+                line.strip_prefix(name)
+                    .is_some_and(|rest| rest.starts_with(['{', ' ']))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn render_buckets_are_cumulative() {
+        let histogram = RenderHistogram::new();
+        histogram.record(Duration::from_micros(500), Duration::ZERO);
+        histogram.record(Duration::from_millis(7), Duration::from_millis(8));
+        histogram.record(Duration::from_millis(200), Duration::from_millis(8));
+        let mut out = Encoder::new(false);
+        histogram.encode(&mut out);
+        let out = out.finish();
+
+        let buckets = samples(&out, "fcast_video_render_seconds_bucket");
+        assert_eq!(
+            buckets[0],
+            "fcast_video_render_seconds_bucket{le=\"0.001\"} 1"
+        );
+        assert_eq!(
+            buckets[4],
+            "fcast_video_render_seconds_bucket{le=\"0.008\"} 2"
+        );
+        assert_eq!(
+            buckets[9],
+            "fcast_video_render_seconds_bucket{le=\"0.05\"} 2"
+        );
+        assert_eq!(
+            buckets[10],
+            "fcast_video_render_seconds_bucket{le=\"+Inf\"} 3"
+        );
+        assert_eq!(
+            samples(&out, "fcast_video_render_delay_seconds"),
+            ["fcast_video_render_delay_seconds 0.008"]
+        );
+    }
+
+    #[test]
+    fn buffering_time_includes_the_current_stretch() {
+        let start = Instant::now();
+        let mut clock = BufferingClock::default();
+        clock.set(true, start);
+        clock.set(true, start + Duration::from_secs(1));
+        clock.set(false, start + Duration::from_secs(2));
+        clock.set(false, start + Duration::from_secs(5));
+        assert_eq!(
+            clock.total(start + Duration::from_secs(6)),
+            Duration::from_secs(2)
+        );
+
+        clock.set(true, start + Duration::from_secs(10));
+        assert_eq!(
+            clock.total(start + Duration::from_secs(13)),
+            Duration::from_secs(5)
+        );
+    }
+
+    #[test]
+    fn counters_are_named_for_the_format_asked_for() {
+        let metrics = Metrics::new(Vec::new());
+        metrics.fcast_session_started();
+        metrics.load_error(ErrorKind::UnsupportedFormat);
+        metrics.load_error(ErrorKind::UnsupportedFormat);
+        metrics.freeze_recovery(FreezeAction::Reload {
+            pinned_for: Duration::from_secs(3),
+        });
+
+        let text = metrics.encode(None, false);
+        assert!(text.contains("# TYPE fcast_receiver_sessions_total counter\n"));
+        assert_eq!(
+            samples(&text, "fcast_receiver_sessions_total"),
+            ["fcast_receiver_sessions_total 1"]
+        );
+        assert_eq!(
+            samples(&text, "fcast_receiver_load_errors_total"),
+            ["fcast_receiver_load_errors_total{kind=\"UnsupportedFormat\"} 2"]
+        );
+        assert!(
+            samples(&text, "fcast_receiver_freeze_recoveries_total")
+                .contains(&"fcast_receiver_freeze_recoveries_total{action=\"reload\"} 1")
+        );
+        assert_eq!(
+            samples(&text, "fcast_receiver_event_loop_up"),
+            ["fcast_receiver_event_loop_up 0"]
+        );
+        assert!(!text.contains("# EOF"));
+
+        let openmetrics = metrics.encode(None, true);
+        assert!(openmetrics.contains("# TYPE fcast_receiver_sessions counter\n"));
+        assert!(openmetrics.ends_with("# EOF\n"));
+    }
+}
@@ -0,0 +1,57 @@
+//! MPRIS (`org.mpris.MediaPlayer2`) on the session bus, so the desktop's media
+//! keys, lock-screen controls and `playerctl` see what is being cast and can
+//! drive it.
+//!
+//! Like the Cast server this is a front end of its own: the application
+//! pushes [`StatusUpdate`]s, and method calls and property writes come back
+//! as [`crate::Operation`]s from `PacketOrigin::Mpris`, which the application
+//! relays to senders like a change made in the GUI.
+//!
+//! The session bus only exists on Linux; elsewhere the application never
+//! starts the server and the updates go nowhere.
+
+use crate::{PlayerState, fcast::QueueMode};
+
+#[cfg(target_os = "linux")]
+mod server;
+
+#[cfg(target_os = "linux")]
+pub use server::run_server;
+
+/// An item that started loading, whoever cast it.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MediaDescription {
+    pub url: Option<String>,
+    pub title: Option<String>,
+    pub art_url: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueueItem {
+    pub id: u32,
+    pub media: MediaDescription,
+}
+
+#[derive(Debug)]
+pub enum StatusUpdate {
+    Media(MediaDescription),
+    /// Nothing is loaded any more.
+    Stopped,
+    /// A title learned after the load, e.g. from a RAOP sender.
+    Title(String),
+    Artist(String),
+    /// Encoded cover art sent along with the audio (RAOP).
+    CoverArt(Vec<u8>),
+    CoverArtRemoved,
+    PlayerState(PlayerState),
+    Position(f64),
+    Duration(f64),
+    Rate(f64),
+    Volume(f64),
+    /// The queue being played, `items` is empty when the media isn't a queue.
+    Queue {
+        items: Vec<QueueItem>,
+        current_item_id: Option<u32>,
+        mode: QueueMode,
+    },
+}
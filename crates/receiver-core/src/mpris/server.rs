@@ -0,0 +1,780 @@
+//! The MPRIS objects on the session bus, and the loop that keeps them in
+//! step with the application's [`StatusUpdate`]s.
+
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use anyhow::Result;
+use fcast_protocol::v4;
+use parking_lot::Mutex;
+use tokio::sync::mpsc::UnboundedReceiver;
+use tracing::{debug, warn};
+use zbus::{
+    fdo, interface,
+    object_server::{InterfaceRef, SignalEmitter},
+    zvariant::{ObjectPath, OwnedObjectPath, OwnedValue, Value},
+};
+
+use super::{MediaDescription, QueueItem, StatusUpdate};
+use crate::{MessageSender, Operation, PlayerState, application::PacketOrigin, fcast::QueueMode};
+
+const BUS_NAME: &str = "org.mpris.MediaPlayer2.fcast_receiver";
+const OBJECT_PATH: &str = "/org/mpris/MediaPlayer2";
+const TRACK_PATH: &str = "/org/fcast/Receiver/track";
+const NO_TRACK: &str = "/org/mpris/MediaPlayer2/TrackList/NoTrack";
+/// The player menu's range.
+const MINIMUM_RATE: f64 = 0.25;
+const MAXIMUM_RATE: f64 = 2.0;
+/// Clients extrapolate the position from the rate, so only a position this
+/// far from the extrapolation is a seek worth a `Seeked` signal.
+const POSITION_JUMP_SECS: f64 = 2.0;
+
+/// Which properties an update changed, so only those are signalled.
+#[derive(Debug, Default, PartialEq)]
+struct Changes {
+    status: bool,
+    metadata: bool,
+    rate: bool,
+    volume: bool,
+    mode: bool,
+    navigation: bool,
+    tracks: bool,
+    /// The position a seek landed on, in microseconds.
+    seeked: Option<i64>,
+}
+
+#[derive(Debug)]
+struct State {
+    media: Option<MediaDescription>,
+    artist: Option<String>,
+    /// The RAOP cover, which takes over from the item's own art.
+    cover_url: Option<String>,
+    player_state: PlayerState,
+    position: f64,
+    position_at: Instant,
+    duration: f64,
+    rate: f64,
+    volume: f64,
+    queue: Vec<QueueItem>,
+    current_item_id: Option<u32>,
+    mode: QueueMode,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            media: None,
+            artist: None,
+            cover_url: None,
+            player_state: PlayerState::Stopped,
+            position: 0.0,
+            position_at: Instant::now(),
+            duration: 0.0,
+            rate: 1.0,
+            volume: 1.0,
+            queue: Vec::new(),
+            current_item_id: None,
+            mode: QueueMode::default(),
+        }
+    }
+}
+
+impl State {
+    fn apply(&mut self, update: StatusUpdate, now: Instant) -> Changes {
+        let mut changes = Changes::default();
+        match update {
+            StatusUpdate::Media(media) => {
+                self.media = Some(media);
+                self.artist = None;
+                self.cover_url = None;
+                self.duration = 0.0;
+                self.set_position(0.0, now);
+                changes.metadata = true;
+                changes.navigation = true;
+            }
+            StatusUpdate::Stopped => {
+                self.media = None;
+                self.artist = None;
+                self.cover_url = None;
+                self.duration = 0.0;
+                self.player_state = PlayerState::Stopped;
+                self.set_position(0.0, now);
+                changes.metadata = true;
+                changes.status = true;
+                changes.navigation = true;
+            }
+            StatusUpdate::Title(title) => {
+                let media = self.media.get_or_insert_with(Default::default);
+                changes.metadata = media.title.as_ref() != Some(&title);
+                media.title = Some(title);
+            }
+            StatusUpdate::Artist(artist) => {
+                changes.metadata = self.artist.as_ref() != Some(&artist);
+                self.artist = Some(artist);
+            }
+            StatusUpdate::CoverArt(data) => {
+                self.cover_url = write_cover(&data);
+                changes.metadata = true;
+            }
+            StatusUpdate::CoverArtRemoved => {
+                changes.metadata = self.cover_url.take().is_some();
+            }
+            StatusUpdate::PlayerState(state) => {
+                // Re-anchor first, so a pause does not keep the position running.
+                let position = self.position_at(now);
+                self.set_position(position, now);
+                changes.status = self.player_state != state;
+                self.player_state = state;
+            }
+            StatusUpdate::Position(position) => {
+                if (position - self.position_at(now)).abs() > POSITION_JUMP_SECS {
+                    changes.seeked = Some(micros(position));
+                }
+                self.set_position(position, now);
+            }
+            StatusUpdate::Duration(duration) => {
+                changes.metadata = self.duration != duration;
+                self.duration = duration;
+            }
+            StatusUpdate::Rate(rate) => {
+                let position = self.position_at(now);
+                self.set_position(position, now);
+                changes.rate = self.rate != rate;
+                self.rate = rate;
+            }
+            StatusUpdate::Volume(volume) => {
+                changes.volume = self.volume != volume;
+                self.volume = volume;
+            }
+            StatusUpdate::Queue {
+                items,
+                current_item_id,
+                mode,
+            } => {
+                changes.tracks = self.queue != items;
+                changes.metadata = self.current_item_id != current_item_id;
+                changes.mode = self.mode != mode;
+                changes.navigation = true;
+                self.queue = items;
+                self.current_item_id = current_item_id;
+                self.mode = mode;
+            }
+        }
+        changes
+    }
+
+    fn set_position(&mut self, position: f64, now: Instant) {
+        self.position = position;
+        self.position_at = now;
+    }
+
+    /// The last reported position, run forward while playing.
+    fn position_at(&self, now: Instant) -> f64 {
+        match self.player_state {
+            PlayerState::Playing => {
+                self.position + now.duration_since(self.position_at).as_secs_f64() * self.rate
+            }
+            _ => self.position,
+        }
+    }
+
+    fn current_index(&self) -> Option<usize> {
+        let id = self.current_item_id?;
+        self.queue.iter().position(|item| item.id == id)
+    }
+
+    /// The queue index `jump` items away from the current one, wrapping
+    /// when the whole queue repeats.
+    fn queue_step(&self, jump: i64) -> Option<usize> {
+        let current = self.current_index()? as i64;
+        let len = self.queue.len() as i64;
+        let target = current + jump;
+        match self.mode.repeat {
+            v4::flat::RepeatMode::All => Some(target.rem_euclid(len) as usize),
+            _ => (0..len).contains(&target).then_some(target as usize),
+        }
+    }
+
+    fn current_track(&self) -> OwnedObjectPath {
+        match (self.current_item_id, &self.media) {
+            (Some(id), _) => track_path(id),
+            (None, Some(_)) => path(&format!("{TRACK_PATH}/current")),
+            (None, None) => path(NO_TRACK),
+        }
+    }
+
+    fn metadata(&self) -> HashMap<String, OwnedValue> {
+        let mut map = HashMap::new();
+        map.insert("mpris:trackid".to_owned(), owned(self.current_track()));
+        let Some(media) = self.media.as_ref() else {
+            return map;
+        };
+        if self.duration > 0.0 {
+            map.insert("mpris:length".to_owned(), owned(micros(self.duration)));
+        }
+        if let Some(title) = &media.title {
+            map.insert("xesam:title".to_owned(), owned(title.as_str()));
+        }
+        if let Some(artist) = &self.artist {
+            map.insert("xesam:artist".to_owned(), owned(vec![artist.as_str()]));
+        }
+        if let Some(art_url) = self.cover_url.as_ref().or(media.art_url.as_ref()) {
+            map.insert("mpris:artUrl".to_owned(), owned(art_url.as_str()));
+        }
+        if let Some(url) = &media.url {
+            map.insert("xesam:url".to_owned(), owned(url.as_str()));
+        }
+        map
+    }
+
+    /// A queue item's metadata, for `GetTracksMetadata`. The current one has
+    /// what was learned since it loaded.
+    fn item_metadata(&self, item: &QueueItem) -> HashMap<String, OwnedValue> {
+        if Some(item.id) == self.current_item_id {
+            return self.metadata();
+        }
+        let mut map = HashMap::new();
+        map.insert("mpris:trackid".to_owned(), owned(track_path(item.id)));
+        if let Some(title) = &item.media.title {
+            map.insert("xesam:title".to_owned(), owned(title.as_str()));
+        }
+        if let Some(art_url) = &item.media.art_url {
+            map.insert("mpris:artUrl".to_owned(), owned(art_url.as_str()));
+        }
+        if let Some(url) = &item.media.url {
+            map.insert("xesam:url".to_owned(), owned(url.as_str()));
+        }
+        map
+    }
+}
+
+fn micros(secs: f64) -> i64 {
+    (secs * 1_000_000.0) as i64
+}
+
+fn path(path: &str) -> OwnedObjectPath {
+    ObjectPath::try_from(path)
+        .expect("a valid object path")
+        .into()
+}
+
+fn track_path(id: u32) -> OwnedObjectPath {
+    path(&format!("{TRACK_PATH}/{id}"))
+}
+
+fn owned<'a>(value: impl Into<Value<'a>>) -> OwnedValue {
+    OwnedValue::try_from(value.into()).expect("no file descriptors to duplicate")
+}
+
+/// Where RAOP covers are written for `mpris:artUrl`, which takes URLs only.
+fn cover_dir() -> Option<PathBuf> {
+    directories::BaseDirs::new().map(|base| base.cache_dir().join("fcast-receiver"))
+}
+
+/// Each cover gets a new name, since clients cache art by URL.
+fn write_cover(data: &[u8]) -> Option<String> {
+    static COUNTER: std::sync::atomic::AtomicU32 = std::sync::atomic::AtomicU32::new(0);
+    let dir = cover_dir()?;
+    let n = COUNTER.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+    let file = dir.join(format!("mpris-cover-{n}"));
+    let written = std::fs::create_dir_all(&dir).and_then(|()| std::fs::write(&file, data));
+    if let Err(err) = written {
+        warn!(?err, ?file, "Failed to write the cover art");
+        return None;
+    }
+    if let Some(previous) = n.checked_sub(1) {
+        let _ = std::fs::remove_file(dir.join(format!("mpris-cover-{previous}")));
+    }
+    Some(format!("file://{}", file.display()))
+}
+
+struct Root;
+
+#[interface(name = "org.mpris.MediaPlayer2")]
+impl Root {
+    fn raise(&self) {}
+
+    fn quit(&self) {}
+
+    #[zbus(property)]
+    fn can_quit(&self) -> bool {
+        false
+    }
+
+    #[zbus(property)]
+    fn can_raise(&self) -> bool {
+        false
+    }
+
+    #[zbus(property)]
+    fn has_track_list(&self) -> bool {
+        true
+    }
+
+    #[zbus(property)]
+    fn identity(&self) -> &str {
+        "FCast Receiver"
+    }
+
+    #[zbus(property)]
+    fn desktop_entry(&self) -> &str {
+        "org.fcast.Receiver"
+    }
+
+    #[zbus(property)]
+    fn supported_uri_schemes(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    #[zbus(property)]
+    fn supported_mime_types(&self) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+struct Player {
+    msg_tx: MessageSender,
+    state: Arc<Mutex<State>>,
+}
+
+impl Player {
+    fn operation(&self, op: Operation) {
+        self.msg_tx.operation(PacketOrigin::Mpris, op);
+    }
+
+    fn step(&self, jump: i64) {
+        if let Some(idx) = self.state.lock().queue_step(jump) {
+            self.operation(Operation::SelectQueueItem(v4::QueuePosition::Index(
+                idx as u32,
+            )));
+        }
+    }
+}
+
+#[interface(name = "org.mpris.MediaPlayer2.Player")]
+impl Player {
+    fn next(&self) {
+        self.step(1);
+    }
+
+    fn previous(&self) {
+        self.step(-1);
+    }
+
+    fn pause(&self) {
+        self.operation(Operation::Pause);
+    }
+
+    fn play_pause(&self) {
+        self.operation(Operation::ResumeOrPause);
+    }
+
+    fn stop(&self) {
+        self.operation(Operation::Stop);
+    }
+
+    fn play(&self) {
+        self.operation(Operation::Resume);
+    }
+
+    fn seek(&self, offset: i64) {
+        // Any local client can call this; an offset past what a clock time
+        // holds saturates, and the seek lands on the end like any overshoot.
+        let magnitude = offset
+            .unsigned_abs()
+            .checked_mul(1000)
+            .map_or(gst::ClockTime::MAX, gst::ClockTime::from_nseconds);
+        let offset = if offset < 0 {
+            gst::Signed::Negative(magnitude)
+        } else {
+            gst::Signed::Positive(magnitude)
+        };
+        self.operation(Operation::SeekRelative(offset));
+    }
+
+    fn set_position(&self, track_id: ObjectPath<'_>, position: i64) {
+        let state = self.state.lock();
+        // A position for a track that has since been replaced is ignored.
+        if track_id.as_str() != state.current_track().as_str()
+            || position < 0
+            || position > micros(state.duration)
+        {
+            return;
+        }
+        drop(state);
+        self.operation(Operation::Seek(gst::ClockTime::from_useconds(
+            position as u64,
+        )));
+    }
+
+    fn open_uri(&self, _uri: &str) -> fdo::Result<()> {
+        Err(fdo::Error::NotSupported(
+            "Media is cast from a sender".to_owned(),
+        ))
+    }
+
+    #[zbus(signal)]
+    async fn seeked(emitter: &SignalEmitter<'_>, position: i64) -> zbus::Result<()>;
+
+    #[zbus(property)]
+    fn playback_status(&self) -> &str {
+        match self.state.lock().player_state {
+            PlayerState::Playing | PlayerState::Buffering => "Playing",
+            PlayerState::Paused => "Paused",
+            PlayerState::Stopped => "Stopped",
+        }
+    }
+
+    #[zbus(property)]
+    fn loop_status(&self) -> &str {
+        match self.state.lock().mode.repeat {
+            v4::flat::RepeatMode::One => "Track",
+            v4::flat::RepeatMode::All => "Playlist",
+            _ => "None",
+        }
+    }
+
+    #[zbus(property)]
+    fn set_loop_status(&mut self, status: &str) -> fdo::Result<()> {
+        let repeat = match status {
+            "None" => v4::flat::RepeatMode::Off,
+            "Track" => v4::flat::RepeatMode::One,
+            "Playlist" => v4::flat::RepeatMode::All,
+            _ => return Err(fdo::Error::InvalidArgs(status.to_owned())),
+        };
+        let shuffle = self.state.lock().mode.shuffle;
+        self.operation(Operation::SetQueueMode(QueueMode { repeat, shuffle }));
+        Ok(())
+    }
+
+    #[zbus(property)]
+    fn rate(&self) -> f64 {
+        self.state.lock().rate
+    }
+
+    #[zbus(property)]
+    fn set_rate(&mut self, rate: f64) {
+        // The spec's way of pausing.
+        if rate == 0.0 {
+            self.operation(Operation::Pause);
+            return;
+        }
+        let rate = rate.clamp(MINIMUM_RATE, MAXIMUM_RATE);
+        self.operation(Operation::SetSpeed(rate as f32));
+    }
+
+    #[zbus(property)]
+    fn shuffle(&self) -> bool {
+        self.state.lock().mode.shuffle
+    }
+
+    #[zbus(property)]
+    fn set_shuffle(&mut self, shuffle: bool) {
+        let repeat = self.state.lock().mode.repeat;
+        self.operation(Operation::SetQueueMode(QueueMode { repeat, shuffle }));
+    }
+
+    #[zbus(property)]
+    fn metadata(&self) -> HashMap<String, OwnedValue> {
+        self.state.lock().metadata()
+    }
+
+    #[zbus(property)]
+    fn volume(&self) -> f64 {
+        self.state.lock().volume
+    }
+
+    #[zbus(property)]
+    fn set_volume(&mut self, volume: f64) {
+        self.operation(Operation::SetVolume(volume.clamp(0.0, 1.0) as f32));
+    }
+
+    #[zbus(property(emits_changed_signal = "false"))]
+    fn position(&self) -> i64 {
+        micros(self.state.lock().position_at(Instant::now()))
+    }
+
+    #[zbus(property)]
+    fn minimum_rate(&self) -> f64 {
+        MINIMUM_RATE
+    }
+
+    #[zbus(property)]
+    fn maximum_rate(&self) -> f64 {
+        MAXIMUM_RATE
+    }
+
+    #[zbus(property)]
+    fn can_go_next(&self) -> bool {
+        self.state.lock().queue_step(1).is_some()
+    }
+
+    #[zbus(property)]
+    fn can_go_previous(&self) -> bool {
+        self.state.lock().queue_step(-1).is_some()
+    }
+
+    #[zbus(property)]
+    fn can_play(&self) -> bool {
+        self.state.lock().media.is_some()
+    }
+
+    #[zbus(property)]
+    fn can_pause(&self) -> bool {
+        self.state.lock().media.is_some()
+    }
+
+    #[zbus(property)]
+    fn can_seek(&self) -> bool {
+        let state = self.state.lock();
+        state.media.is_some() && state.duration > 0.0
+    }
+
+    #[zbus(property(emits_changed_signal = "const"))]
+    fn can_control(&self) -> bool {
+        true
+    }
+}
+
+struct TrackList {
+    msg_tx: MessageSender,
+    state: Arc<Mutex<State>>,
+}
+
+#[interface(name = "org.mpris.MediaPlayer2.TrackList")]
+impl TrackList {
+    fn get_tracks_metadata(
+        &self,
+        track_ids: Vec<OwnedObjectPath>,
+    ) -> Vec<HashMap<String, OwnedValue>> {
+        let state = self.state.lock();
+        track_ids
+            .iter()
+            .filter_map(|id| state.queue.iter().find(|item| track_path(item.id) == *id))
+            .map(|item| state.item_metadata(item))
+            .collect()
+    }
+
+    /// The queue is the senders' to edit: `CanEditTracks` is false.
+    fn add_track(&self, _uri: &str, _after_track: ObjectPath<'_>, _set_as_current: bool) {}
+
+    fn remove_track(&self, _track_id: ObjectPath<'_>) {}
+
+    fn go_to(&self, track_id: ObjectPath<'_>) {
+        let idx = self
+            .state
+            .lock()
+            .queue
+            .iter()
+            .position(|item| track_path(item.id).as_str() == track_id.as_str());
+        if let Some(idx) = idx {
+            self.msg_tx.operation(
+                PacketOrigin::Mpris,
+                Operation::SelectQueueItem(v4::QueuePosition::Index(idx as u32)),
+            );
+        }
+    }
+
+    #[zbus(signal)]
+    async fn track_list_replaced(
+        emitter: &SignalEmitter<'_>,
+        tracks: Vec<OwnedObjectPath>,
+        current_track: OwnedObjectPath,
+    ) -> zbus::Result<()>;
+
+    #[zbus(property(emits_changed_signal = "invalidates"))]
+    fn tracks(&self) -> Vec<OwnedObjectPath> {
+        let state = self.state.lock();
+        state.queue.iter().map(|item| track_path(item.id)).collect()
+    }
+
+    #[zbus(property(emits_changed_signal = "const"))]
+    fn can_edit_tracks(&self) -> bool {
+        false
+    }
+}
+
+/// Publish the player on the session bus and keep it in step with `updates`
+/// until the application drops its end.
+pub async fn run_server(
+    msg_tx: MessageSender,
+    mut updates: UnboundedReceiver<StatusUpdate>,
+) -> Result<()> {
+    let state = Arc::new(Mutex::new(State::default()));
+    let connection = zbus::connection::Builder::session()?
+        .name(BUS_NAME)?
+        .serve_at(OBJECT_PATH, Root)?
+        .serve_at(
+            OBJECT_PATH,
+            Player {
+                msg_tx: msg_tx.clone(),
+                state: Arc::clone(&state),
+            },
+        )?
+        .serve_at(
+            OBJECT_PATH,
+            TrackList {
+                msg_tx,
+                state: Arc::clone(&state),
+            },
+        )?
+        .build()
+        .await?;
+    debug!("Published MPRIS as {BUS_NAME}");
+
+    let server = connection.object_server();
+    let player = server.interface::<_, Player>(OBJECT_PATH).await?;
+    let track_list = server.interface::<_, TrackList>(OBJECT_PATH).await?;
+    while let Some(update) = updates.recv().await {
+        let (changes, tracks, current_track) = {
+            let mut state = state.lock();
+            let changes = state.apply(update, Instant::now());
+            let tracks = state.queue.iter().map(|item| track_path(item.id)).collect();
+            (changes, tracks, state.current_track())
+        };
+
+        // A failed signal only leaves clients a change behind; the next one
+        // carries the current state, so the server carries on.
+        if let Err(err) = signal(&player, &track_list, changes, tracks, current_track).await {
+            warn!(?err, "Failed to signal an MPRIS change");
+        }
+    }
+
+    Ok(())
+}
+
+/// Emit the property-changed and `Seeked` signals for `changes`.
+async fn signal(
+    player: &InterfaceRef<Player>,
+    track_list: &InterfaceRef<TrackList>,
+    changes: Changes,
+    tracks: Vec<OwnedObjectPath>,
+    current_track: OwnedObjectPath,
+) -> zbus::Result<()> {
+    let emitter = player.signal_emitter();
+    let iface = player.get().await;
+    if changes.status {
+        iface.playback_status_changed(emitter).await?;
+    }
+    if changes.metadata {
+        iface.metadata_changed(emitter).await?;
+    }
+    if changes.rate {
+        iface.rate_changed(emitter).await?;
+    }
+    if changes.volume {
+        iface.volume_changed(emitter).await?;
+    }
+    if changes.mode {
+        iface.loop_status_changed(emitter).await?;
+        iface.shuffle_changed(emitter).await?;
+    }
+    if changes.navigation || changes.tracks || changes.metadata {
+        iface.can_go_next_changed(emitter).await?;
+        iface.can_go_previous_changed(emitter).await?;
+        iface.can_play_changed(emitter).await?;
+        iface.can_pause_changed(emitter).await?;
+        iface.can_seek_changed(emitter).await?;
+    }
+    if let Some(position) = changes.seeked {
+        Player::seeked(emitter, position).await?;
+    }
+    drop(iface);
+    if changes.tracks {
+        TrackList::track_list_replaced(track_list.signal_emitter(), tracks, current_track).await?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn queue(ids: &[u32], current: u32, repeat: v4::flat::RepeatMode) -> StatusUpdate {
+        StatusUpdate::Queue {
+            items: ids
+                .iter()
+                .map(|id| QueueItem {
+                    id: *id,
+                    media: MediaDescription::default(),
+                })
+                .collect(),
+            current_item_id: Some(current),
+            mode: QueueMode {
+                repeat,
+                shuffle: false,
+            },
+        }
+    }
+
+    #[test]
+    fn next_and_previous_follow_the_repeat_mode() {
+        let now = Instant::now();
+        let mut state = State::default();
+        assert_eq!(state.queue_step(1), None);
+
+        state.apply(queue(&[7, 8, 9], 9, v4::flat::RepeatMode::Off), now);
+        assert_eq!(state.queue_step(1), None);
+        assert_eq!(state.queue_step(-1), Some(1));
+
+        state.apply(queue(&[7, 8, 9], 9, v4::flat::RepeatMode::All), now);
+        assert_eq!(state.queue_step(1), Some(0));
+    }
+
+    #[test]
+    fn a_position_off_the_extrapolation_is_a_seek() {
+        let now = Instant::now();
+        let mut state = State::default();
+        state.apply(StatusUpdate::PlayerState(PlayerState::Playing), now);
+        state.apply(StatusUpdate::Position(10.0), now);
+
+        // One second later at rate one, 11s is where it should be.
+        let later = now + Duration::from_secs(1);
+        assert_eq!(
+            state.apply(StatusUpdate::Position(11.0), later).seeked,
+            None
+        );
+        let seeked = state.apply(StatusUpdate::Position(60.0), later).seeked;
+        assert_eq!(seeked, Some(60_000_000));
+
+        // Paused, the position stands still.
+        state.apply(StatusUpdate::PlayerState(PlayerState::Paused), later);
+        assert_eq!(state.position_at(later + Duration::from_secs(30)), 60.0);
+    }
+
+    #[test]
+    fn the_metadata_names_the_current_item() {
+        let now = Instant::now();
+        let mut state = State::default();
+        assert_eq!(
+            state.metadata().get("mpris:trackid"),
+            Some(&owned(path(NO_TRACK)))
+        );
+
+        state.apply(
+            StatusUpdate::Media(MediaDescription {
+                url: Some("https://example.com/a.mp3".to_owned()),
+                title: Some("A".to_owned()),
+                art_url: Some("https://example.com/a.jpg".to_owned()),
+            }),
+            now,
+        );
+        state.apply(StatusUpdate::Artist("Someone".to_owned()), now);
+        state.apply(StatusUpdate::Duration(2.5), now);
+        state.apply(queue(&[4], 4, v4::flat::RepeatMode::Off), now);
+
+        let metadata = state.metadata();
+        assert_eq!(metadata.get("mpris:trackid"), Some(&owned(track_path(4))));
+        assert_eq!(metadata.get("xesam:title"), Some(&owned("A")));
+        assert_eq!(metadata.get("xesam:artist"), Some(&owned(vec!["Someone"])));
+        assert_eq!(metadata.get("mpris:length"), Some(&owned(2_500_000i64)));
+        assert_eq!(
+            metadata.get("mpris:artUrl"),
+            Some(&owned("https://example.com/a.jpg"))
+        );
+    }
+}
@@ -0,0 +1,177 @@
+//! Optional FCast sender pairing. When required, a v4 session stays
+//! unauthorized until the sender either presents a token from an earlier
+//! pairing or enters the PIN shown on the receiver's screen. Only SHA-256
+//! hashes of the issued tokens are kept (in `[fcast] paired_senders`).
+
+use std::{
+    collections::HashSet,
+    time::{Duration, Instant},
+};
+
+use base64::Engine;
+use parking_lot::RwLock;
+use tracing::warn;
+
+/// Wrong PINs a session may send before it is dropped.
+pub const MAX_PIN_ATTEMPTS: u8 = 3;
+/// Wrong PINs across all sessions before pairing locks for a while.
+/// Reconnecting gets a fresh session and PIN, so the per-session limit alone
+/// doesn't slow down guessing.
+const LOCKOUT_THRESHOLD: u32 = 5;
+/// The first lockout; each further one doubles, up to [`MAX_LOCKOUT`].
+const BASE_LOCKOUT: Duration = Duration::from_secs(30);
+const MAX_LOCKOUT: Duration = Duration::from_secs(60 * 60);
+
+/// Shared between the [`crate::application::Application`] and every
+/// [`crate::fcast::SessionDriver`]; changes apply to the next handshake.
+#[derive(Debug, Default)]
+pub struct Pairing {
+    inner: RwLock<Inner>,
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+    required: bool,
+    token_hashes: HashSet<String>,
+    /// Wrong PINs since the last successful pairing, on any session.
+    failures: u32,
+    locked_until: Option<Instant>,
+}
+
+impl Pairing {
+    pub fn new(required: bool, token_hashes: impl IntoIterator<Item = String>) -> Self {
+        Self {
+            inner: RwLock::new(Inner {
+                required,
+                token_hashes: token_hashes.into_iter().collect(),
+                ..Default::default()
+            }),
+        }
+    }
+
+    pub fn required(&self) -> bool {
+        self.inner.read().required
+    }
+
+    pub fn set_required(&self, required: bool) {
+        self.inner.write().required = required;
+    }
+
+    /// Whether `token` was issued to a sender that is still remembered.
+    pub fn is_paired(&self, token: &str) -> bool {
+        self.inner.read().token_hashes.contains(&hash_token(token))
+    }
+
+    pub fn remember(&self, token_sha256: String) {
+        self.inner.write().token_hashes.insert(token_sha256);
+    }
+
+    pub fn forget_all(&self) {
+        self.inner.write().token_hashes.clear();
+    }
+
+    /// Whether PINs are refused outright after too many wrong ones.
+    pub fn locked_out(&self) -> bool {
+        self.locked_out_at(Instant::now())
+    }
+
+    fn locked_out_at(&self, now: Instant) -> bool {
+        self.inner
+            .read()
+            .locked_until
+            .is_some_and(|until| now < until)
+    }
+
+    /// Count a wrong PIN. Every [`LOCKOUT_THRESHOLD`]th one locks pairing,
+    /// for twice as long as the lockout before.
+    pub fn note_failure(&self) {
+        self.note_failure_at(Instant::now());
+    }
+
+    fn note_failure_at(&self, now: Instant) {
+        let mut inner = self.inner.write();
+        inner.failures += 1;
+        if inner.failures % LOCKOUT_THRESHOLD == 0 {
+            let doublings = (inner.failures / LOCKOUT_THRESHOLD - 1).min(16);
+            let lockout = (BASE_LOCKOUT * 2u32.pow(doublings)).min(MAX_LOCKOUT);
+            warn!(
+                failures = inner.failures,
+                ?lockout,
+                "Too many wrong pairing PINs, refusing pairing for a while"
+            );
+            inner.locked_until = Some(now + lockout);
+        }
+    }
+
+    /// A sender paired; wrong PINs before it no longer count.
+    pub fn note_success(&self) {
+        let mut inner = self.inner.write();
+        inner.failures = 0;
+        inner.locked_until = None;
+    }
+}
+
+/// A six digit PIN, zero padded.
+pub fn generate_pin() -> String {
+    format!("{:06}", rand::random_range(0..1_000_000u32))
+}
+
+/// 256 random bits, URL-safe base64 so senders can store it anywhere.
+pub fn generate_token() -> String {
+    base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(rand::random::<[u8; 32]>())
+}
+
+/// The form a token is persisted in.
+pub fn hash_token(token: &str) -> String {
+    use sha2::Digest;
+    base64::engine::general_purpose::STANDARD.encode(sha2::Sha256::digest(token.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_remembered_tokens_are_paired() {
+        let token = generate_token();
+        let pairing = Pairing::new(true, [hash_token(&token)]);
+        assert!(pairing.is_paired(&token));
+        assert!(!pairing.is_paired(&generate_token()));
+
+        pairing.forget_all();
+        assert!(!pairing.is_paired(&token));
+    }
+
+    #[test]
+    fn repeated_failures_lock_pairing_for_longer_each_time() {
+        let pairing = Pairing::new(true, []);
+        let start = Instant::now();
+        for _ in 0..LOCKOUT_THRESHOLD - 1 {
+            pairing.note_failure_at(start);
+        }
+        assert!(!pairing.locked_out_at(start));
+
+        pairing.note_failure_at(start);
+        assert!(pairing.locked_out_at(start));
+        assert!(!pairing.locked_out_at(start + BASE_LOCKOUT));
+
+        let later = start + BASE_LOCKOUT;
+        for _ in 0..LOCKOUT_THRESHOLD {
+            pairing.note_failure_at(later);
+        }
+        assert!(pairing.locked_out_at(later + BASE_LOCKOUT));
+        assert!(!pairing.locked_out_at(later + BASE_LOCKOUT * 2));
+
+        pairing.note_success();
+        assert!(!pairing.locked_out_at(later));
+    }
+
+    #[test]
+    fn pins_are_six_digits() {
+        for _ in 0..100 {
+            let pin = generate_pin();
+            assert_eq!(pin.len(), 6);
+            assert!(pin.bytes().all(|b| b.is_ascii_digit()));
+        }
+    }
+}
@@ -1,10 +1,10 @@
 use std::{
-    sync::atomic::{AtomicBool, AtomicU64, Ordering},
+    sync::atomic::{AtomicBool, AtomicI64, AtomicU64, Ordering},
     time::{Duration, Instant},
 };
 
 use anyhow::Result;
-use fcast_protocol::PlaybackState;
+use fcast_protocol::{PlaybackState, v4::Chapter};
 use gst::{glib::object::ObjectExt, prelude::*};
 use tracing::{debug, error, info, instrument, warn};
 
@@ -208,6 +208,91 @@ impl SubtitleFlow {
     }
 }
 
+/// The subtitle delay in nanoseconds, shared with the subtitle consumer,
+/// which shifts every item's running times by it on the way to the engine.
+/// See [`Player::set_subtitle_delay`].
+#[derive(Default, Clone)]
+struct SubtitleDelay(std::sync::Arc<AtomicI64>);
+
+impl SubtitleDelay {
+    /// Store a new delay, returning whether it differs from the old one.
+    fn set(&self, delay_ns: i64) -> bool {
+        self.0.swap(delay_ns, Ordering::Relaxed) != delay_ns
+    }
+
+    /// Shift an item by the delay. `None` for an item a negative delay moves
+    /// wholly before running time zero: it would never show. One that only
+    /// starts before zero is cut to start there.
+    fn shift(
+        &self,
+        item: fcastplaybin::SubtitleFeedItem,
+    ) -> Option<fcastplaybin::SubtitleFeedItem> {
+        let delay_ns = self.0.load(Ordering::Relaxed);
+        if delay_ns == 0 {
+            return Some(item);
+        }
+        Some(match item {
+            fcastplaybin::SubtitleFeedItem::Cue {
+                format,
+                text,
+                start_rt,
+                end_rt,
+                origin,
+            } => fcastplaybin::SubtitleFeedItem::Cue {
+                format,
+                text,
+                start_rt: delayed(start_rt, delay_ns).unwrap_or(gst::ClockTime::ZERO),
+                end_rt: match end_rt {
+                    Some(end_rt) => Some(delayed(end_rt, delay_ns)?),
+                    None => None,
+                },
+                origin,
+            },
+            fcastplaybin::SubtitleFeedItem::Bitmap {
+                format,
+                data,
+                codec_data,
+                rt,
+                duration,
+            } => {
+                let (rt, duration) = match (delayed(rt, delay_ns), duration) {
+                    (Some(rt), duration) => (rt, duration),
+                    (None, Some(duration)) => (
+                        gst::ClockTime::ZERO,
+                        Some(delayed(rt.saturating_add(duration), delay_ns)?),
+                    ),
+                    (None, None) => (gst::ClockTime::ZERO, None),
+                };
+                fcastplaybin::SubtitleFeedItem::Bitmap {
+                    format,
+                    data,
+                    codec_data,
+                    rt,
+                    duration,
+                }
+            }
+            fcastplaybin::SubtitleFeedItem::Clear => fcastplaybin::SubtitleFeedItem::Clear,
+        })
+    }
+}
+
+fn signed_nseconds(delay: gst::Signed<gst::ClockTime>) -> i64 {
+    match delay {
+        gst::Signed::Positive(delay) => delay.nseconds() as i64,
+        gst::Signed::Negative(delay) => -(delay.nseconds() as i64),
+    }
+}
+
+/// `rt` moved by `delay_ns`, `None` if that lands before zero.
+fn delayed(rt: gst::ClockTime, delay_ns: i64) -> Option<gst::ClockTime> {
+    let delay = gst::ClockTime::from_nseconds(delay_ns.unsigned_abs());
+    if delay_ns >= 0 {
+        Some(rt.saturating_add(delay))
+    } else {
+        rt.checked_sub(delay)
+    }
+}
+
 /// Whether a teardown is in flight, shared with the bus hook.
 ///
 /// # Why the escalation needs to know
@@ -515,6 +600,8 @@ pub enum PlayerEvent {
     EndOfStream,
     UriLoaded,
     Tags(gst::TagList),
+    /// The container's chapters (see [`chapters_from_toc`]).
+    Chapters(Vec<Chapter>),
     VolumeChanged(f64),
     /// User must call Player::handle_stream_collection()
     StreamCollection(gst::StreamCollection),
@@ -611,6 +698,12 @@ pub enum PlayerEvent {
     SourceBackoff {
         remaining_ms: u64,
     },
+    /// The media source lost its connection and is reconnecting to resume
+    /// where it left off (fcasthttpsrc). `false` once it resumed or gave up.
+    /// Shown as "reconnecting" in the GUI.
+    SourceReconnecting {
+        reconnecting: bool,
+    },
     /// A pre-armed next item ([`Player::prepare_next`]) went live: the
     /// current item drained and the pipeline switched gaplessly. Stamped
     /// with the PREPARED generation; the application validates it against
@@ -681,6 +774,50 @@ pub fn stream_title(stream: &gst::Stream) -> String {
     res
 }
 
+/// Flatten a container's table of contents into the chapter list advertised
+/// to senders. Only the first edition is used and sub-chapters are skipped,
+/// so the list is flat and ordered by start. Entries without a start time
+/// are dropped, and untitled ones are numbered.
+pub fn chapters_from_toc(toc: &gst::TocRef) -> Vec<Chapter> {
+    let mut entries = toc.entries();
+    if entries
+        .first()
+        .is_some_and(|e| e.entry_type() == gst::TocEntryType::Edition)
+    {
+        entries = entries[0].sub_entries();
+    }
+
+    let mut chapters: Vec<(u64, Option<u64>, Option<String>)> = entries
+        .iter()
+        .filter(|e| e.entry_type() == gst::TocEntryType::Chapter)
+        .filter_map(|entry| {
+            // -1 marks an unset time.
+            let (start, stop) = entry.start_stop_times()?;
+            let start = u64::try_from(start).ok()?;
+            let length = u64::try_from(stop)
+                .ok()
+                .and_then(|stop| stop.checked_sub(start));
+            let title = entry
+                .tags()
+                .and_then(|tags| tags.get::<gst::tags::Title>().map(|t| t.get().to_owned()))
+                .filter(|title| !title.is_empty());
+            Some((start, length, title))
+        })
+        .collect();
+    chapters.sort_by_key(|(start, ..)| *start);
+
+    chapters
+        .into_iter()
+        .enumerate()
+        .map(|(idx, (start, length, title))| Chapter {
+            start: Duration::from_nanos(start).as_secs_f64(),
+            length: length.map(|l| Duration::from_nanos(l).as_secs_f64()),
+            title: title.unwrap_or_else(|| format!("Chapter {}", idx + 1)),
+            skip_label: None,
+        })
+        .collect()
+}
+
 pub struct Stream {
     pub inner: gst::Stream,
     pub title: String,
@@ -768,6 +905,7 @@ pub struct Player {
     /// Discards seen vs subtitle items delivered, the signal that catches a
     /// latched track the discard COUNT never can. See [`SubtitleFlow`].
     subtitle_flow: SubtitleFlow,
+    subtitle_delay: SubtitleDelay,
 }
 
 impl Player {
@@ -858,12 +996,15 @@ impl Player {
         // inline, nothing waits on the raster worker) and none of them can
         // panic on a caller's cue text.
         let subtitle_flow = SubtitleFlow::default();
+        let subtitle_delay = SubtitleDelay::default();
         if let Some(engine) = cue_engine {
             let flow = subtitle_flow.clone();
+            let delay = subtitle_delay.clone();
             // `tally` counts and hands the item straight back, so the delivery
-            // signal costs this match neither an arm nor an indent level.
-            fcast.set_subtitle_consumer(move |item| match flow.tally(item) {
-                fcastplaybin::SubtitleFeedItem::Cue {
+            // signal costs this match neither an arm nor an indent level. The
+            // delay is applied after it: a cue it drops was still delivered.
+            fcast.set_subtitle_consumer(move |item| match delay.shift(flow.tally(item)) {
+                Some(fcastplaybin::SubtitleFeedItem::Cue {
                     format,
                     text,
                     start_rt,
@@ -871,7 +1012,7 @@ impl Player {
                     // The delivery timeline's origin; the engine takes bounds
                     // already resolved to running time and needs no timeline.
                     origin: _,
-                } => engine.submit(fcast_video::cue::CueInput {
+                }) => engine.submit(fcast_video::cue::CueInput {
                     format: match format {
                         fcastplaybin::SubtitleTextFormat::Utf8 => {
                             fcast_video::cue::TextFormat::Utf8
@@ -897,20 +1038,21 @@ impl Player {
                 // it runs on. Live for all three formats, and the
                 // driver's caps gate decides which caps get here (see
                 // `fcastplaybin::bitmap_format_implemented`).
-                fcastplaybin::SubtitleFeedItem::Bitmap {
+                Some(fcastplaybin::SubtitleFeedItem::Bitmap {
                     format,
                     data,
                     codec_data,
                     rt,
                     duration,
-                } => engine.submit_bitmap(fcast_video::subpic::BitmapPacket {
+                }) => engine.submit_bitmap(fcast_video::subpic::BitmapPacket {
                     format: bitmap_format(format),
                     data,
                     codec_data,
                     rt,
                     duration,
                 }),
-                fcastplaybin::SubtitleFeedItem::Clear => engine.clear(),
+                Some(fcastplaybin::SubtitleFeedItem::Clear) => engine.clear(),
+                None => {}
             });
         }
 
@@ -1093,6 +1235,7 @@ impl Player {
             stream_collection_notify: None,
             teardown: teardown_flag,
             subtitle_flow,
+            subtitle_delay,
             streams: Vec::new(),
         })
     }
@@ -1117,6 +1260,7 @@ impl Player {
                 PlayerEvent::UriLoaded
             }
             E::Tags(tags) => PlayerEvent::Tags(tags),
+            E::Toc(toc) => PlayerEvent::Chapters(chapters_from_toc(&toc)),
             E::VolumeChanged(volume) => PlayerEvent::VolumeChanged(volume),
             E::StreamCollection(collection) => PlayerEvent::StreamCollection(collection),
             E::AsyncDone => PlayerEvent::AsyncDone,
@@ -1185,6 +1329,9 @@ impl Player {
                 animated: s.get::<bool>("animated").unwrap_or(false),
             }),
             E::SourceBackoff { remaining_ms } => PlayerEvent::SourceBackoff { remaining_ms },
+            E::SourceReconnecting { reconnecting } => {
+                PlayerEvent::SourceReconnecting { reconnecting }
+            }
             E::PreparedActivated => PlayerEvent::GaplessActivated,
             E::PreparedFailed { generation } => PlayerEvent::GaplessPrepareFailed { generation },
             E::PreparedCancelled { generation } => PlayerEvent::GaplessCancelled { generation },
@@ -1503,6 +1650,13 @@ impl Player {
         });
     }
 
+    /// Step one frame while paused. Fire-and-forget: the playbin drops a step
+    /// it cannot perform, and one it performs re-prerolls like any flushing
+    /// seek, which reports the new position through `AsyncDone`.
+    pub fn step_frame(&mut self, forward: bool) {
+        self.fcast.step_frame_async(forward);
+    }
+
     /// The freeze watchdog's recovery seek: a FLUSHING, ACCURATE seek to the
     /// pipeline's current position at the current rate, performed IN PLACE
     /// (no transport change). Returns the fresh seqnum it is stamped with, so
@@ -1660,6 +1814,44 @@ impl Player {
         self.volume_confirm_in_flight = true;
     }
 
+    /// Show subtitles `delay` later than their running times, or earlier
+    /// for a negative one.
+    ///
+    /// Cues are shifted as they are delivered, and a file's external track
+    /// delivers all of its cues at once, well ahead of playback. So a change
+    /// mid-item re-seeks to the current position, which has the track deliver
+    /// its cues again under the new delay.
+    pub fn set_subtitle_delay(&mut self, delay: gst::Signed<gst::ClockTime>) {
+        if !self.subtitle_delay.set(signed_nseconds(delay)) {
+            return;
+        }
+        if self.current_subtitle_sid().is_some()
+            && self.seekable
+            && let Some(position) = self.get_position()
+        {
+            debug!(%position, "Subtitle delay changed; re-seeking to re-deliver cues");
+            self.seek(position);
+        }
+    }
+
+    /// The delays a new item starts with, set before its load: nothing of it
+    /// has been delivered yet, so unlike [`Player::set_subtitle_delay`]
+    /// there is nothing to re-seek.
+    pub fn set_item_delays(
+        &mut self,
+        subtitle: gst::Signed<gst::ClockTime>,
+        audio: gst::Signed<gst::ClockTime>,
+    ) {
+        self.subtitle_delay.set(signed_nseconds(subtitle));
+        self.fcast.set_audio_delay(audio);
+    }
+
+    /// Play audio `delay` later than the video, or earlier for a negative
+    /// one. See [`fcastplaybin::FcastPlaybin::set_audio_delay`].
+    pub fn set_audio_delay(&self, delay: gst::Signed<gst::ClockTime>) {
+        self.fcast.set_audio_delay(delay);
+    }
+
     pub fn volume_changed(&mut self) {
         self.volume_confirm_in_flight = false;
         // Apply the newest request that arrived while the confirmation was
@@ -1814,6 +2006,11 @@ impl Player {
         self.fcast.stream_io_stats()
     }
 
+    /// Metrics: the pipeline's diagnostic counters (see `fcastplaybin::Stats`).
+    pub fn playbin_stats(&self) -> fcastplaybin::Stats {
+        self.fcast.stats()
+    }
+
     /// Inspector: every advertised stream plus whether it is currently
     /// selected, for the track table (`gst::Stream` clones are refcounted).
     pub fn stream_dbg_rows(&self) -> Vec<(gst::Stream, bool)> {
@@ -2526,6 +2723,39 @@ mod tests {
         assert_eq!(flow.0.delivered.load(Ordering::Relaxed), 0);
     }
 
+    #[test]
+    fn the_subtitle_delay_shifts_cues_and_drops_those_before_zero() {
+        let cue = |start: u64, end: Option<u64>| fcastplaybin::SubtitleFeedItem::Cue {
+            format: fcastplaybin::SubtitleTextFormat::Utf8,
+            text: "hi".to_owned(),
+            start_rt: gst::ClockTime::from_mseconds(start),
+            end_rt: end.map(gst::ClockTime::from_mseconds),
+            origin: gst::ClockTime::ZERO,
+        };
+        let bounds = |item: Option<fcastplaybin::SubtitleFeedItem>| match item {
+            Some(fcastplaybin::SubtitleFeedItem::Cue {
+                start_rt, end_rt, ..
+            }) => Some((start_rt.mseconds(), end_rt.map(|end| end.mseconds()))),
+            _ => None,
+        };
+        let delay = SubtitleDelay::default();
+        assert!(delay.set(500_000_000));
+        assert!(!delay.set(500_000_000));
+        assert_eq!(
+            bounds(delay.shift(cue(1000, Some(2000)))),
+            Some((1500, Some(2500)))
+        );
+
+        delay.set(-1_500_000_000);
+        assert_eq!(bounds(delay.shift(cue(3000, None))), Some((1500, None)));
+        // Cut to start at zero, or gone altogether.
+        assert_eq!(
+            bounds(delay.shift(cue(1000, Some(2000)))),
+            Some((0, Some(500)))
+        );
+        assert_eq!(bounds(delay.shift(cue(1000, Some(1200)))), None);
+    }
+
     /// The driver's caps gate and the engine's decoder table each write down
     /// which bitmap subtitle formats are implemented, and they cannot import
     /// each other, since the dependency runs one way. This crate depends on
@@ -3261,4 +3491,63 @@ mod tests {
         assert_eq!(kind, MediaWarningKind::Unknown);
         assert_eq!(detail, None);
     }
+
+    #[test]
+    fn toc_chapters_use_the_first_edition_in_start_order() {
+        crate::gstreamer::init_for_tests();
+
+        let chapter = |uid: &str, start: i64, stop: i64, title: Option<&str>| {
+            let mut entry = gst::TocEntry::new(gst::TocEntryType::Chapter, uid);
+            {
+                let entry = entry.get_mut().unwrap();
+                entry.set_start_stop_times(start, stop);
+                if let Some(title) = title {
+                    let mut tags = gst::TagList::new();
+                    tags.get_mut()
+                        .unwrap()
+                        .add::<gst::tags::Title>(&title, gst::TagMergeMode::Replace);
+                    entry.set_tags(tags);
+                }
+            }
+            entry
+        };
+
+        let mut edition = gst::TocEntry::new(gst::TocEntryType::Edition, "edition");
+        {
+            let edition = edition.get_mut().unwrap();
+            let mut second = chapter("2", 90_000_000_000, -1, None);
+            // A sub-chapter does not make it into the flat list.
+            let nested = chapter("2.1", 95_000_000_000, -1, Some("Nested"));
+            second.get_mut().unwrap().append_sub_entry(nested);
+            edition.append_sub_entry(second);
+            edition.append_sub_entry(chapter("1", 0, 90_000_000_000, Some("Intro")));
+            edition.append_sub_entry(chapter("x", -1, -1, Some("No start")));
+        }
+        let mut toc = gst::Toc::new(gst::TocScope::Global);
+        toc.get_mut().unwrap().append_entry(edition);
+        // Only the first edition counts.
+        toc.get_mut().unwrap().append_entry(gst::TocEntry::new(
+            gst::TocEntryType::Edition,
+            "other-edition",
+        ));
+
+        let chapters = chapters_from_toc(&toc);
+        assert_eq!(
+            chapters,
+            vec![
+                Chapter {
+                    start: 0.0,
+                    length: Some(90.0),
+                    title: "Intro".to_owned(),
+                    skip_label: None,
+                },
+                Chapter {
+                    start: 90.0,
+                    length: None,
+                    title: "Chapter 2".to_owned(),
+                    skip_label: None,
+                },
+            ]
+        );
+    }
 }
@@ -27,21 +27,30 @@ const TOTAL_MAX_BYTES: usize = 128 * 1024 * 1024;
 const BEHIND: usize = 1;
 const AHEAD: usize = 2;
 
-/// The queue-item indices worth prefetching for `current` in a queue of `len`
-/// items: the window around it, clamped to the bounds, excluding the current
-/// item, ordered nearest-first.
-pub fn window_indices(len: usize, current: usize) -> Vec<usize> {
+/// The positions worth prefetching for `current` in a queue of `len` items:
+/// the window around it, excluding the current item, ordered nearest-first.
+/// Clamped to the bounds, or wrapped around them when `wrap` (a repeating
+/// queue), in which case a short queue's window holds each position once.
+pub fn window_indices(len: usize, current: usize, wrap: bool) -> Vec<usize> {
     let mut out = Vec::new();
     for step in 1..=AHEAD.max(BEHIND) {
-        if step <= AHEAD && current + step < len {
-            out.push(current + step);
+        if step <= AHEAD {
+            if current + step < len {
+                out.push(current + step);
+            } else if wrap && step < len {
+                out.push((current + step) % len);
+            }
         }
-        if step <= BEHIND
-            && let Some(idx) = current.checked_sub(step)
-        {
-            out.push(idx);
+        if step <= BEHIND {
+            if let Some(idx) = current.checked_sub(step) {
+                out.push(idx);
+            } else if wrap && step < len {
+                out.push(current + len - step);
+            }
         }
     }
+    let mut seen = HashSet::new();
+    out.retain(|idx| seen.insert(*idx));
     out
 }
 
@@ -498,15 +507,25 @@ mod tests {
     #[test]
     fn window_shape() {
         // Middle of a long queue: next first, then prev, then next+2.
-        assert_eq!(window_indices(10, 5), vec![6, 4, 7]);
+        assert_eq!(window_indices(10, 5, false), vec![6, 4, 7]);
         // At the front: nothing behind.
-        assert_eq!(window_indices(10, 0), vec![1, 2]);
+        assert_eq!(window_indices(10, 0, false), vec![1, 2]);
         // At the back: nothing ahead.
-        assert_eq!(window_indices(10, 9), vec![8]);
+        assert_eq!(window_indices(10, 9, false), vec![8]);
         // Tiny queues.
-        assert_eq!(window_indices(1, 0), Vec::<usize>::new());
-        assert_eq!(window_indices(2, 0), vec![1]);
-        assert_eq!(window_indices(2, 1), vec![0]);
+        assert_eq!(window_indices(1, 0, false), Vec::<usize>::new());
+        assert_eq!(window_indices(2, 0, false), vec![1]);
+        assert_eq!(window_indices(2, 1, false), vec![0]);
+    }
+
+    #[test]
+    fn window_wraps_for_repeating_queues() {
+        assert_eq!(window_indices(10, 9, true), vec![0, 8, 1]);
+        assert_eq!(window_indices(10, 0, true), vec![1, 9, 2]);
+        // Each position once, never the current one.
+        assert_eq!(window_indices(2, 0, true), vec![1]);
+        assert_eq!(window_indices(3, 2, true), vec![0, 1]);
+        assert_eq!(window_indices(1, 0, true), Vec::<usize>::new());
     }
 
     #[test]
@@ -0,0 +1,274 @@
+//! The order autoplay walks a queue in: queue order, or a shuffled
+//! permutation of it that is kept in step with queue mutations, so a
+//! shuffled queue still plays every item once per pass.
+
+use fcast_protocol::v4::flat::RepeatMode;
+use rand::seq::SliceRandom;
+
+use crate::queue_cache;
+
+/// Where the item at `idx` sits after the item at `from` moved to `to`.
+pub fn index_after_queue_move(idx: usize, from: usize, to: usize) -> usize {
+    if idx == from {
+        to
+    } else if from < idx && idx <= to {
+        idx - 1
+    } else if to <= idx && idx < from {
+        idx + 1
+    } else {
+        idx
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct PlayOrder {
+    /// A permutation of the item indices while shuffled, `None` plays them in
+    /// queue order.
+    shuffled: Option<Vec<usize>>,
+}
+
+impl PlayOrder {
+    /// A fresh random order over `len` items that starts at `current`, so
+    /// turning shuffle on never replays what is playing.
+    pub fn shuffled(len: usize, current: usize) -> Self {
+        let mut rest: Vec<usize> = (0..len).filter(|idx| *idx != current).collect();
+        rest.shuffle(&mut rand::rng());
+        let mut order = Vec::with_capacity(len);
+        if current < len {
+            order.push(current);
+        }
+        order.extend(rest);
+        Self {
+            shuffled: Some(order),
+        }
+    }
+
+    pub fn is_shuffled(&self) -> bool {
+        self.shuffled.is_some()
+    }
+
+    fn position(&self, idx: usize) -> usize {
+        match &self.shuffled {
+            Some(order) => order.iter().position(|i| *i == idx).unwrap_or(idx),
+            None => idx,
+        }
+    }
+
+    fn item(&self, position: usize) -> usize {
+        match &self.shuffled {
+            Some(order) => order.get(position).copied().unwrap_or(position),
+            None => position,
+        }
+    }
+
+    /// The item autoplay goes to once `current` finishes, if any.
+    pub fn next(&self, len: usize, current: usize, repeat: RepeatMode) -> Option<usize> {
+        if current >= len {
+            return None;
+        }
+        if repeat == RepeatMode::One {
+            return Some(current);
+        }
+        let next = self.position(current) + 1;
+        if next < len {
+            Some(self.item(next))
+        } else if repeat == RepeatMode::All {
+            Some(self.item(0))
+        } else {
+            None
+        }
+    }
+
+    /// The item `steps` places from `current` along the play order, backwards
+    /// when negative. Wraps around the order when the whole queue repeats, and
+    /// is `None` past either end otherwise.
+    pub fn step(
+        &self,
+        len: usize,
+        current: usize,
+        steps: i64,
+        repeat: RepeatMode,
+    ) -> Option<usize> {
+        if current >= len {
+            return None;
+        }
+        let target = (self.position(current) as i64).checked_add(steps)?;
+        let len = len as i64;
+        let position = if repeat == RepeatMode::All {
+            target.rem_euclid(len)
+        } else {
+            (0..len).contains(&target).then_some(target)?
+        };
+        Some(self.item(position as usize))
+    }
+
+    /// The prefetch window around `current`, as item indices, nearest first.
+    /// Follows the play order and wraps around it when the whole queue
+    /// repeats.
+    pub fn window(&self, len: usize, current: usize, repeat: RepeatMode) -> Vec<usize> {
+        queue_cache::window_indices(len, self.position(current), repeat == RepeatMode::All)
+            .into_iter()
+            .map(|position| self.item(position))
+            .collect()
+    }
+
+    /// Account for an item inserted at `idx`, with `current` the playing
+    /// item's index after the insert. While shuffled the new item lands at a
+    /// random point after `current` in the order, so it still plays this
+    /// pass.
+    pub fn inserted(&mut self, idx: usize, current: usize) {
+        let Some(order) = self.shuffled.as_mut() else {
+            return;
+        };
+        for i in order.iter_mut() {
+            if *i >= idx {
+                *i += 1;
+            }
+        }
+        let after = order
+            .iter()
+            .position(|i| *i == current)
+            .map_or(0, |position| position + 1);
+        let at = rand::random_range(after..=order.len());
+        order.insert(at, idx);
+    }
+
+    /// Account for `count` items inserted from `idx` on, with `current` the
+    /// playing item's index after the whole insert.
+    pub fn inserted_many(&mut self, idx: usize, count: usize, current: usize) {
+        // Inserted one at a time, the playing item only moves past the items
+        // ahead of it so far.
+        let before = current >= idx + count;
+        for offset in 0..count {
+            let current = if before {
+                current - count + offset + 1
+            } else {
+                current
+            };
+            self.inserted(idx + offset, current);
+        }
+    }
+
+    /// Account for the item at `idx` having been removed.
+    pub fn removed(&mut self, idx: usize) {
+        let Some(order) = self.shuffled.as_mut() else {
+            return;
+        };
+        order.retain(|i| *i != idx);
+        for i in order.iter_mut() {
+            if *i > idx {
+                *i -= 1;
+            }
+        }
+    }
+
+    /// Account for the item at `from` having moved to `to`. The item keeps its
+    /// place in a shuffled order, only its index changes.
+    pub fn moved(&mut self, from: usize, to: usize) {
+        let Some(order) = self.shuffled.as_mut() else {
+            return;
+        };
+        for i in order.iter_mut() {
+            *i = index_after_queue_move(*i, from, to);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_permutation(order: &PlayOrder, len: usize) -> bool {
+        let mut items = order.shuffled.clone().unwrap();
+        items.sort_unstable();
+        items == (0..len).collect::<Vec<_>>()
+    }
+
+    #[test]
+    fn queue_move_shifts_the_items_between_the_two_positions() {
+        // Moving 1 to 3 in [a, b, c, d, e] gives [a, c, d, b, e].
+        let after: Vec<usize> = (0..5)
+            .map(|idx| index_after_queue_move(idx, 1, 3))
+            .collect();
+        assert_eq!(after, [0, 3, 1, 2, 4]);
+        // And back again.
+        let after: Vec<usize> = (0..5)
+            .map(|idx| index_after_queue_move(idx, 3, 1))
+            .collect();
+        assert_eq!(after, [0, 2, 3, 1, 4]);
+        assert_eq!(index_after_queue_move(2, 2, 2), 2);
+    }
+
+    #[test]
+    fn queue_order_follows_the_repeat_mode() {
+        let order = PlayOrder::default();
+        assert_eq!(order.next(3, 1, RepeatMode::Off), Some(2));
+        assert_eq!(order.next(3, 2, RepeatMode::Off), None);
+        assert_eq!(order.next(3, 2, RepeatMode::All), Some(0));
+        assert_eq!(order.next(3, 2, RepeatMode::One), Some(2));
+        assert_eq!(order.next(0, 0, RepeatMode::All), None);
+        assert_eq!(order.window(5, 4, RepeatMode::All), vec![0, 3, 1]);
+    }
+
+    #[test]
+    fn shuffled_order_visits_every_item_once_per_pass() {
+        let order = PlayOrder::shuffled(6, 3);
+        assert!(is_permutation(&order, 6));
+        assert_eq!(order.item(0), 3);
+
+        let mut seen = vec![3];
+        let mut current = 3;
+        while let Some(next) = order.next(6, current, RepeatMode::Off) {
+            seen.push(next);
+            current = next;
+        }
+        seen.sort_unstable();
+        assert_eq!(seen, (0..6).collect::<Vec<_>>());
+        assert_eq!(order.next(6, current, RepeatMode::All), Some(3));
+    }
+
+    #[test]
+    fn steps_follow_the_shuffled_order_both_ways() {
+        let order = PlayOrder {
+            shuffled: Some(vec![2, 0, 3, 1]),
+        };
+        assert_eq!(order.step(4, 0, 1, RepeatMode::Off), Some(3));
+        assert_eq!(order.step(4, 0, -1, RepeatMode::Off), Some(2));
+        assert_eq!(order.step(4, 2, -1, RepeatMode::Off), None);
+        assert_eq!(order.step(4, 2, -1, RepeatMode::All), Some(1));
+        assert_eq!(order.step(4, 1, 2, RepeatMode::All), Some(0));
+        assert_eq!(order.step(4, 3, 0, RepeatMode::Off), Some(3));
+        assert_eq!(order.step(0, 0, 1, RepeatMode::All), None);
+    }
+
+    #[test]
+    fn shuffled_order_tracks_queue_mutations() {
+        let mut order = PlayOrder {
+            shuffled: Some(vec![2, 0, 3, 1]),
+        };
+        // Removing item 0 shifts the indices above it down.
+        order.removed(0);
+        assert_eq!(order.shuffled, Some(vec![1, 2, 0]));
+        // Moving item 2 to the front keeps its place in the order.
+        order.moved(2, 0);
+        assert_eq!(order.shuffled, Some(vec![2, 0, 1]));
+        // An inserted item plays after the current one (item 2, shifted to 3).
+        order.inserted(1, 3);
+        assert!(is_permutation(&order, 4));
+        assert_eq!(order.item(0), 3);
+        assert!(order.position(1) > order.position(3));
+    }
+
+    #[test]
+    fn a_batch_inserted_before_the_current_item_plays_after_it() {
+        for _ in 0..20 {
+            // Item 1 plays; two items go in at the front and shift it to 3.
+            let mut order = PlayOrder::shuffled(4, 1);
+            order.inserted_many(0, 2, 3);
+            assert!(is_permutation(&order, 6));
+            assert_eq!(order.item(0), 3);
+            assert!(order.position(0) > order.position(3));
+            assert!(order.position(1) > order.position(3));
+        }
+    }
+}
@@ -1,6 +1,6 @@
 // Loosely based on [Airguitar](https://github.com/MSNexploder/airguitar)
-// TODO: do propper synchronization
-// TODO: notify sender about missing packets
+
+mod playout;
 
 use aes::{
     Aes128,
@@ -23,6 +23,7 @@ use nom::{
     combinator::{map_res, opt},
     sequence::{delimited, preceded, terminated, tuple},
 };
+use parking_lot::Mutex;
 use rsa::{RsaPrivateKey, pkcs1::DecodeRsaPrivateKey};
 use rtsp_types::{
     HeaderName, Message, Method, ParseError, Request, Response, ResponseBuilder, StatusCode,
@@ -51,7 +52,7 @@ use tokio::{
 };
 use tracing::{debug, error, instrument, trace, warn};
 
-use crate::MessageSender;
+use crate::{MessageSender, rtsp_auth::DigestAuth};
 
 lazy_static::lazy_static! {
     static ref PRIVATE_KEY: RsaPrivateKey = RsaPrivateKey::from_pkcs1_pem(
@@ -105,6 +106,10 @@ const RTP_TIMING_PAYLOAD_TYPE: u8 = 83;
 
 const SAMPLING_RATE: u64 = 44100;
 const LATENCY_IN_SAMPLES: u64 = SAMPLING_RATE;
+/// The jitter buffer holds packets this long for resends to fill gaps; audio
+/// is timestamped this much ahead of when it's due so it still plays on time.
+const JITTER_BUFFER_LATENCY: gst::ClockTime =
+    gst::ClockTime::from_mseconds(LATENCY_IN_SAMPLES * 1000 / SAMPLING_RATE);
 
 // NTP epoch - UNIX epoch
 const NTP_OFFSET: Duration = Duration::from_secs(2208988800);
@@ -640,11 +645,10 @@ impl NoSsrcRtpHeader {
     }
 }
 
-#[allow(unused)]
-#[derive(Debug)]
+/// Reads the sender's sync packets and the audio it resends on request.
 struct ControlReceiver {
-    player_tx: mpsc::Sender<Command>,
     socket: Arc<UdpSocket>,
+    audio: AudioInput,
     shutdown: Shutdown,
 }
 
@@ -680,8 +684,22 @@ impl ControlReceiver {
             let header = NoSsrcRtpHeader::parse(&buf[..length]);
 
             match header.payload_type {
-                RTP_SYNC_PAYLOAD_TYPE => {}
-                RTP_RESENT_DATA_PAYLOAD_TYPE => (),
+                // The timestamp in the header, the one `latency` samples behind the
+                // sender's current one, plays at the NTP time that follows it.
+                RTP_SYNC_PAYLOAD_TYPE => {
+                    let ntp = duration_from_ntp(&buf[8..16]);
+                    let current = u32::from_be_bytes([buf[16], buf[17], buf[18], buf[19]]);
+                    trace!(
+                        rtp = header.timestamp,
+                        latency = current.wrapping_sub(header.timestamp),
+                        "sync"
+                    );
+                    self.audio.playout.lock().sync(header.timestamp, ntp);
+                }
+                // The original audio packet follows a 4 byte header.
+                RTP_RESENT_DATA_PAYLOAD_TYPE => {
+                    self.audio.push(&buf[4..length]);
+                }
                 payload_type => trace!(payload_type, "received packet with unknown payload type"),
             }
         }
@@ -704,7 +722,9 @@ fn duration_from_ntp(buf: &[u8]) -> Duration {
         };
     }
 
-    Duration::new(u32_from_be!(buf, 0) as u64, u32_from_be!(buf, 4))
+    // The fraction counts 2^-32 seconds.
+    let nanos = (u32_from_be!(buf, 4) as u64 * 1_000_000_000) >> 32;
+    Duration::new(u32_from_be!(buf, 0) as u64, nanos as u32)
 }
 
 fn ntp_duration_now() -> Duration {
@@ -717,10 +737,11 @@ fn ntp_duration_now() -> Duration {
 fn ntp_time_now() -> [u8; 8] {
     let now = ntp_duration_now();
     let secs = (now.as_secs() as u32).to_be_bytes();
-    let nanos = now.subsec_nanos().to_be_bytes();
+    // The fraction counts 2^-32 seconds.
+    let frac = ((((now.subsec_nanos() as u64) << 32) / 1_000_000_000) as u32).to_be_bytes();
 
     [
-        secs[0], secs[1], secs[2], secs[3], nanos[0], nanos[1], nanos[2], nanos[3],
+        secs[0], secs[1], secs[2], secs[3], frac[0], frac[1], frac[2], frac[3],
     ]
 }
 
@@ -728,12 +749,81 @@ fn is_no_data_packet(payload: &[u8]) -> bool {
     payload.len() == 16 && payload[12..16] == [0x00, 0x68, 0x34, 0x00]
 }
 
-struct ServerReceiver {
-    player_tx: mpsc::Sender<Command>,
-    socket: Arc<UdpSocket>,
+/// Decrypts audio packets and hands them to the pipeline, timestamped for
+/// when they are due.
+#[derive(Clone)]
+struct AudioInput {
     appsrc: gst_app::AppSrc,
     cipher: Option<Aes128>,
     aesiv: Option<Vec<u8>>,
+    playout: Arc<Mutex<playout::Playout>>,
+}
+
+impl AudioInput {
+    /// Push one RTP packet, returning its RTP timestamp if it carried audio.
+    fn push(&self, packet: &[u8]) -> Option<u32> {
+        let rtp = match rtp_types::RtpPacket::parse(packet) {
+            Ok(rtp) => rtp,
+            Err(err) => {
+                debug!(?err);
+                return None;
+            }
+        };
+        let timestamp = rtp.timestamp();
+        let payload = rtp.payload();
+
+        if payload.len() <= 12 || is_no_data_packet(payload) {
+            return None;
+        }
+
+        let payload = match (&self.cipher, &self.aesiv) {
+            (Some(cipher), Some(aesiv)) => match decrypt_audio_packet(cipher, aesiv, payload) {
+                Ok(decrypted) => decrypted,
+                Err(err) => {
+                    warn!(?err, "Failed to decrypt audio packet");
+                    return None;
+                }
+            },
+            _ => payload.to_vec(),
+        };
+
+        let header_len = packet.len() - rtp.payload().len();
+        let mut data = Vec::with_capacity(header_len + payload.len());
+        data.extend_from_slice(&packet[..header_len]);
+        data.extend_from_slice(&payload);
+
+        let mut buffer = gst::Buffer::from_mut_slice(data);
+        // Without a sync packet yet, `appsrc` stamps the arrival time.
+        if let Some(due) = self.running_time_of(timestamp) {
+            let buffer = buffer.get_mut().expect("a new buffer is writable");
+            buffer.set_pts(due);
+            buffer.set_dts(due);
+        }
+        if let Err(err) = self.appsrc.push_buffer(buffer) {
+            debug!(?err, "Dropped audio buffer: pipeline not accepting");
+            return None;
+        }
+        Some(timestamp)
+    }
+
+    /// The pipeline running time to stamp `rtp` with, so that it leaves the
+    /// jitter buffer when it's due. The pipeline runs on the realtime clock,
+    /// which is the local NTP clock shifted to the UNIX epoch.
+    fn running_time_of(&self, rtp: u32) -> Option<gst::ClockTime> {
+        let due = self.playout.lock().local_time_of(rtp)?;
+        let due = due.checked_sub(NTP_OFFSET)?;
+        gst::ClockTime::from_nseconds(u64::try_from(due.as_nanos()).ok()?)
+            .checked_sub(self.appsrc.base_time()?)?
+            .checked_sub(JITTER_BUFFER_LATENCY)
+    }
+}
+
+struct ServerReceiver {
+    player_tx: mpsc::Sender<Command>,
+    socket: Arc<UdpSocket>,
+    /// Connected to the sender's control port, for resend requests.
+    control_socket: Arc<UdpSocket>,
+    audio: AudioInput,
     shutdown: Shutdown,
 }
 
@@ -762,57 +852,36 @@ impl ServerReceiver {
                 }
             };
 
-            match rtp_types::RtpPacket::parse(&buf[..length]) {
-                Ok(packet) => {
-                    let timestamp = packet.timestamp();
-                    let payload = packet.payload();
-
-                    if payload.len() <= 12 || is_no_data_packet(payload) {
-                        continue;
-                    }
-
-                    let payload = match (&self.cipher, &self.aesiv) {
-                        (Some(cipher), Some(aesiv)) => {
-                            match decrypt_audio_packet(cipher, aesiv, payload) {
-                                Ok(decrypted) => decrypted,
-                                Err(err) => {
-                                    warn!(?err, "Failed to decrypt audio packet");
-                                    continue;
-                                }
-                            }
-                        }
-                        _ => payload.to_vec(),
-                    };
-
-                    let header_len = length - packet.payload().len();
-                    let mut rtp = Vec::with_capacity(header_len + payload.len());
-                    rtp.extend_from_slice(&buf[..header_len]);
-                    rtp.extend_from_slice(&payload);
-
-                    if let Err(err) = self.appsrc.push_buffer(gst::Buffer::from_mut_slice(rtp)) {
-                        debug!(?err, "Dropped audio buffer: pipeline not accepting");
-                        continue;
+            // Silent packets count too, or they'd look lost.
+            if length >= NO_SSRC_HEADER_SIZE {
+                let seq = NoSsrcRtpHeader::parse(&buf[..length]).sequence_number;
+                let missing = self.audio.playout.lock().arrived(seq);
+                if let Some(missing) = missing {
+                    debug!(?missing, "Requesting lost audio packets");
+                    if let Err(err) = self.control_socket.send(&missing.request()).await {
+                        warn!(?err, "Failed to request lost audio packets");
                     }
-
-                    self.player_tx
-                        .send(Command::PutPacket { timestamp })
-                        .await?
-                }
-                Err(err) => {
-                    debug!(?err);
                 }
             }
+
+            let Some(timestamp) = self.audio.push(&buf[..length]) else {
+                continue;
+            };
+
+            self.player_tx
+                .send(Command::PutPacket { timestamp })
+                .await?
         }
 
         Ok(())
     }
 }
 
-#[allow(unused)]
-#[derive(Debug)]
+/// Polls the sender's clock over the timing port, keeping the offset to ours
+/// current for playout.
 struct TimingManager {
-    player_tx: mpsc::Sender<Command>,
     socket: Arc<UdpSocket>,
+    playout: Arc<Mutex<playout::Playout>>,
     shutdown: Shutdown,
 }
 
@@ -836,10 +905,11 @@ impl TimingManager {
 
                     let header = NoSsrcRtpHeader::parse(&recv_buf[..32]);
                     if header.payload_type == RTP_TIMING_PAYLOAD_TYPE {
-                        let _origin = duration_from_ntp(&recv_buf[8..16]);
-                        let _receive = duration_from_ntp(&recv_buf[16..24]);
-                        let _transmit = duration_from_ntp(&recv_buf[24..32]);
-                        let _now = ntp_duration_now();
+                        let origin = duration_from_ntp(&recv_buf[8..16]);
+                        let receive = duration_from_ntp(&recv_buf[16..24]);
+                        let transmit = duration_from_ntp(&recv_buf[24..32]);
+                        let now = ntp_duration_now();
+                        self.playout.lock().timing_reply(origin, receive, transmit, now);
                     }
                 }
                 _ = send_interval.tick() => {
@@ -967,7 +1037,14 @@ impl Player {
         let mut time_start = 0;
         let mut position = 0;
         let mut duration = 0;
+        let playout = Arc::new(Mutex::new(playout::Playout::default()));
         let pipeline = gst::Pipeline::new();
+        // Running times map onto wall-clock time, which the sender's sync
+        // packets are converted to.
+        let clock = glib::Object::builder::<gst::SystemClock>()
+            .property("clock-type", gst::ClockType::Realtime)
+            .build();
+        pipeline.use_clock(Some(&clock));
 
         let appsrc = gst_app::AppSrc::builder()
             .stream_type(gst_app::AppStreamType::Stream)
@@ -984,12 +1061,12 @@ impl Player {
             )
             .build();
 
+        // Synced: buffers leave at their own timestamps (plus the latency), no
+        // skew estimation against arrival times.
         let jitterbuffer = gst::ElementFactory::make("rtpjitterbuffer")
-            .property(
-                "latency",
-                (LATENCY_IN_SAMPLES * 1000 / SAMPLING_RATE) as u32,
-            )
+            .property("latency", JITTER_BUFFER_LATENCY.mseconds() as u32)
             .property("do-lost", true)
+            .property_from_str("mode", "synced")
             .build()?;
         let depay = FcRaopDepay::default();
         let alacdec = FcAlacDec::default();
@@ -1065,24 +1142,31 @@ impl Player {
                     let (notify_shutdown_sender, _) = broadcast::channel(1);
                     _notify_shutdown = Some(notify_shutdown_sender.clone());
 
+                    *playout.lock() = playout::Playout::default();
+                    let audio = AudioInput {
+                        appsrc: appsrc.clone(),
+                        cipher: cipher.clone(),
+                        aesiv: encryption.as_ref().map(|e| e.aesiv.clone()),
+                        playout: playout.clone(),
+                    };
+
                     let mut timing_server = TimingManager {
                         socket: timing_sock.clone(),
-                        player_tx: self.player_tx.clone(),
+                        playout: playout.clone(),
                         shutdown: Shutdown::new(notify_shutdown_sender.subscribe()),
                     };
 
                     let mut control_receiver = ControlReceiver {
                         socket: control_sock.clone(),
-                        player_tx: self.player_tx.clone(),
+                        audio: audio.clone(),
                         shutdown: Shutdown::new(notify_shutdown_sender.subscribe()),
                     };
 
                     let mut server_receiver = ServerReceiver {
                         socket: server_sock.clone(),
+                        control_socket: control_sock.clone(),
                         player_tx: self.player_tx.clone(),
-                        appsrc: appsrc.clone(),
-                        cipher: cipher.clone(),
-                        aesiv: encryption.as_ref().map(|e| e.aesiv.clone()),
+                        audio,
                         shutdown: Shutdown::new(notify_shutdown_sender.subscribe()),
                     };
 
@@ -1135,6 +1219,7 @@ impl Player {
                 }
                 Command::Flush { payload, resp } => {
                     debug!(?payload, "Flushing");
+                    playout.lock().flush();
                     let _ = appsrc.send_event(gst::event::FlushStart::new());
                     let _ = appsrc.send_event(gst::event::FlushStop::new(false));
                     let _ = resp.send(Ok(()));
@@ -1200,6 +1285,9 @@ struct Handler {
     shutdown: Shutdown,
     _shutdown_complete: mpsc::Sender<()>,
     msg_tx: MessageSender,
+    /// The password challenge while the sender still has to answer it; `None`
+    /// once it has, or when no password is set.
+    auth: Option<DigestAuth>,
 }
 
 impl Handler {
@@ -1236,6 +1324,30 @@ impl Handler {
     }
 
     async fn execute(&mut self, request: &Request<Vec<u8>>) -> Result<()> {
+        // Senders probe with `OPTIONS` before they know a password is needed.
+        if request.method() != &Method::Options
+            && let Some(auth) = &self.auth
+        {
+            let authorization = request.header(&headers::AUTHORIZATION);
+            let uri = request.request_uri().map_or("*", |uri| uri.as_str());
+            if !auth.verify(
+                request.method().into(),
+                uri,
+                authorization.map(|v| v.as_str()),
+            ) {
+                let challenge = auth.challenge();
+                let response_builder = Response::builder(Version::V1_0, StatusCode::Unauthorized);
+                let response = self
+                    .add_default_headers(request, response_builder)?
+                    .header(headers::WWW_AUTHENTICATE, challenge)
+                    .empty();
+
+                self.connection.write_response(&response).await?;
+                return Ok(());
+            }
+            self.auth = None;
+        }
+
         match request.method() {
             Method::Options => {
                 let response_builder = Response::builder(Version::V1_0, StatusCode::Ok);
@@ -1634,6 +1746,8 @@ impl Handler {
 #[derive(Debug, Clone)]
 pub struct Configuration {
     pub hw_addr: [u8; 6],
+    /// Password senders must authenticate with, if any.
+    pub password: Option<String>,
 }
 
 /// Convert an AirPlay-family `volume:` value (dB gain, nominal `[-30.0, 0.0]`,
@@ -1664,7 +1778,7 @@ pub fn hash_to_string(hash: &[u8; 6]) -> String {
         .collect::<String>()
 }
 
-pub fn txt_properties() -> HashMap<String, String> {
+pub fn txt_properties(password_required: bool) -> HashMap<String, String> {
     macro_rules! s {
         ($s:expr) => {
             $s.to_owned()
@@ -1685,7 +1799,7 @@ pub fn txt_properties() -> HashMap<String, String> {
         // 1 = ALAC
         (s!(TXT_AUDIO_CODECS), s!("1")),
         (s!(TXT_AUDIO_CHANNELS), s!("2")),
-        (s!(TXT_PASSWORD_REQUIRED), s!("false")),
+        (s!(TXT_PASSWORD_REQUIRED), password_required.to_string()),
         // Required fields with unknown description
         (s!("sf"), s!("0x4")),
         (s!("ek"), s!("1")),
@@ -1695,16 +1809,20 @@ pub fn txt_properties() -> HashMap<String, String> {
     ])
 }
 
-pub fn service_info(device_name: String) -> Result<(ServiceInfo, Configuration)> {
+pub fn service_info(
+    device_name: String,
+    password: Option<String>,
+) -> Result<(ServiceInfo, Configuration)> {
     let hash = device_name_hash(&device_name);
     let fmt_device_name = format!("{}@{device_name}", hash_to_string(&hash),);
     let host_name = format!("{fmt_device_name}.local.");
 
     let config = Configuration {
         hw_addr: [hash[0], hash[1], hash[2], hash[3], hash[4], hash[5]],
+        password,
     };
 
-    let props = txt_properties();
+    let props = txt_properties(config.password.is_some());
 
     let service = mdns_sd::ServiceInfo::new(
         "_raop._tcp.local.",
@@ -1742,6 +1860,10 @@ pub async fn handle_sender(
         player.run().await.unwrap();
     });
 
+    let auth = config
+        .password
+        .clone()
+        .map(|password| DigestAuth::new("raop", password));
     let mut handler = Handler {
         config: std::sync::Arc::new(config),
         connection: Connection::new(stream).unwrap(),
@@ -1749,6 +1871,7 @@ pub async fn handle_sender(
         shutdown: Shutdown::new(notify_shutdown.subscribe()),
         _shutdown_complete: shutdown_complete_tx.clone(),
         msg_tx,
+        auth,
     };
 
     if let Err(err) = handler.run().await {
@@ -1996,6 +2119,10 @@ mod tests {
 
     impl HandlerHarness {
         async fn spawn() -> Self {
+            Self::spawn_with_password(None).await
+        }
+
+        async fn spawn_with_password(password: Option<&str>) -> Self {
             let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
             let addr = listener.local_addr().unwrap();
             let (client, accepted) = tokio::join!(TcpStream::connect(addr), listener.accept());
@@ -2008,12 +2135,16 @@ mod tests {
             let (msg_tx, msg_rx) = tokio::sync::mpsc::unbounded_channel();
 
             let mut handler = Handler {
-                config: Arc::new(Configuration { hw_addr: [0; 6] }),
+                config: Arc::new(Configuration {
+                    hw_addr: [0; 6],
+                    password: password.map(str::to_owned),
+                }),
                 connection: Connection::new(server).unwrap(),
                 player_tx,
                 shutdown: Shutdown::new(notify_shutdown.subscribe()),
                 _shutdown_complete: shutdown_complete_tx,
                 msg_tx: MessageSender::new(msg_tx),
+                auth: password.map(|password| DigestAuth::new("raop", password.to_owned())),
             };
 
             Self {
@@ -2098,4 +2229,67 @@ mod tests {
             "handler exited instead of ignoring the stray response"
         );
     }
+
+    #[tokio::test]
+    async fn password_protected_sessions_need_digest_authorization() {
+        let mut harness = HandlerHarness::spawn_with_password(Some("hunter2")).await;
+
+        // `OPTIONS` stays open so the sender can find out a password is needed.
+        harness
+            .send("OPTIONS rtsp://127.0.0.1/ RTSP/1.0\r\nCSeq: 1\r\n\r\n")
+            .await;
+        assert_eq!(harness.read_response().await.status(), StatusCode::Ok);
+
+        harness
+            .send("DESCRIBE rtsp://127.0.0.1/1 RTSP/1.0\r\nCSeq: 2\r\n\r\n")
+            .await;
+        let response = harness.read_response().await;
+        assert_eq!(response.status(), StatusCode::Unauthorized);
+        let challenge = response
+            .header(&headers::WWW_AUTHENTICATE)
+            .unwrap()
+            .as_str()
+            .to_owned();
+
+        let wrong = crate::rtsp_auth::sender_authorization(
+            &challenge,
+            "guess",
+            "DESCRIBE",
+            "rtsp://127.0.0.1/1",
+        );
+        harness
+            .send(&format!(
+                "DESCRIBE rtsp://127.0.0.1/1 RTSP/1.0\r\nCSeq: 3\r\nAuthorization: {wrong}\r\n\r\n"
+            ))
+            .await;
+        assert_eq!(
+            harness.read_response().await.status(),
+            StatusCode::Unauthorized
+        );
+
+        let right = crate::rtsp_auth::sender_authorization(
+            &challenge,
+            "hunter2",
+            "DESCRIBE",
+            "rtsp://127.0.0.1/1",
+        );
+        harness
+            .send(&format!(
+                "DESCRIBE rtsp://127.0.0.1/1 RTSP/1.0\r\nCSeq: 4\r\nAuthorization: {right}\r\n\r\n"
+            ))
+            .await;
+        assert_eq!(
+            harness.read_response().await.status(),
+            StatusCode::MethodNotAllowed
+        );
+
+        // Authenticated once is enough for the rest of the session.
+        harness
+            .send("DESCRIBE rtsp://127.0.0.1/1 RTSP/1.0\r\nCSeq: 5\r\n\r\n")
+            .await;
+        assert_eq!(
+            harness.read_response().await.status(),
+            StatusCode::MethodNotAllowed
+        );
+    }
 }
@@ -0,0 +1,160 @@
+//! Playout bookkeeping for a RAOP session: which audio packets went missing
+//! and must be asked for again, and when each RTP timestamp is due on the
+//! local clock.
+//!
+//! The sender's sync packets (control port) say which RTP timestamp plays at
+//! which time on *its* NTP clock; the timing exchange (timing port) gives the
+//! offset between that clock and ours. Together they put every packet at a
+//! local wall-clock time, the same one every other speaker of a multi-room
+//! sender plays it at.
+
+use std::time::Duration;
+
+use super::SAMPLING_RATE;
+
+const RTP_RESEND_REQUEST_PAYLOAD_TYPE: u8 = 85;
+/// Gaps wider than this are an outage, not loss: by the time a resend arrived
+/// it would be too late to play.
+const MAX_RESEND_PACKETS: u16 = 256;
+/// Weight of a new timing sample in the smoothed clock offset.
+const OFFSET_SMOOTHING: f64 = 1.0 / 8.0;
+
+/// Packets to ask the sender for again.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Missing {
+    pub first: u16,
+    pub count: u16,
+}
+
+impl Missing {
+    /// The resend request for the control port.
+    pub fn request(&self) -> [u8; 8] {
+        let first = self.first.to_be_bytes();
+        let count = self.count.to_be_bytes();
+        [
+            0x80,
+            0x80 | RTP_RESEND_REQUEST_PAYLOAD_TYPE,
+            0x00,
+            0x01,
+            first[0],
+            first[1],
+            count[0],
+            count[1],
+        ]
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Playout {
+    /// The sequence number expected next.
+    next_seq: Option<u16>,
+    /// Sender NTP clock minus ours, in nanoseconds.
+    offset_ns: Option<f64>,
+    /// An RTP timestamp and when it plays on the sender's NTP clock.
+    anchor: Option<(u32, Duration)>,
+}
+
+impl Playout {
+    /// Record an audio packet's arrival, returning the packets skipped since
+    /// the last one. Late and resent packets are behind the expected sequence
+    /// number and report nothing.
+    pub fn arrived(&mut self, seq: u16) -> Option<Missing> {
+        let next = self.next_seq.replace(seq.wrapping_add(1))?;
+        let ahead = seq.wrapping_sub(next);
+        if ahead >= 0x8000 {
+            self.next_seq = Some(next);
+            return None;
+        }
+        (ahead > 0 && ahead <= MAX_RESEND_PACKETS).then_some(Missing {
+            first: next,
+            count: ahead,
+        })
+    }
+
+    /// The sender flushed, the next packet starts a new sequence.
+    pub fn flush(&mut self) {
+        self.next_seq = None;
+    }
+
+    /// A timing reply: our send time echoed back, the sender's receive and
+    /// transmit times, and when the reply got here. All are NTP times.
+    pub fn timing_reply(
+        &mut self,
+        origin: Duration,
+        receive: Duration,
+        transmit: Duration,
+        now: Duration,
+    ) {
+        let ns = |d: Duration| d.as_nanos() as f64;
+        let sample = ((ns(receive) - ns(origin)) + (ns(transmit) - ns(now))) / 2.0;
+        self.offset_ns = Some(match self.offset_ns {
+            Some(offset) => offset + (sample - offset) * OFFSET_SMOOTHING,
+            None => sample,
+        });
+    }
+
+    /// A sync packet: `rtp` plays at `ntp` on the sender's clock.
+    pub fn sync(&mut self, rtp: u32, ntp: Duration) {
+        self.anchor = Some((rtp, ntp));
+    }
+
+    /// When `rtp` is due as an NTP time on the local clock. `None` until the
+    /// first sync packet.
+    pub fn local_time_of(&self, rtp: u32) -> Option<Duration> {
+        let (anchor_rtp, anchor_ntp) = self.anchor?;
+        let samples = rtp.wrapping_sub(anchor_rtp) as i32 as f64;
+        let remote_ns = anchor_ntp.as_nanos() as f64 + samples * 1e9 / SAMPLING_RATE as f64;
+        let local_ns = remote_ns - self.offset_ns.unwrap_or(0.0);
+        (local_ns >= 0.0).then(|| Duration::from_nanos(local_ns as u64))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gaps_are_reported_once_and_late_packets_ignored() {
+        let mut playout = Playout::default();
+        assert_eq!(playout.arrived(65534), None);
+        assert_eq!(playout.arrived(65535), None);
+        // Wraps, with 0 and 1 lost.
+        assert_eq!(playout.arrived(2), Some(Missing { first: 0, count: 2 }));
+        // The resends come in behind.
+        assert_eq!(playout.arrived(0), None);
+        assert_eq!(playout.arrived(1), None);
+        assert_eq!(playout.arrived(3), None);
+        assert_eq!(playout.arrived(4 + MAX_RESEND_PACKETS + 1), None);
+        playout.flush();
+        assert_eq!(playout.arrived(9000), None);
+        assert_eq!(
+            Missing {
+                first: 0x1234,
+                count: 3
+            }
+            .request(),
+            [0x80, 0xd5, 0x00, 0x01, 0x12, 0x34, 0x00, 0x03]
+        );
+    }
+
+    #[test]
+    fn rtp_times_follow_the_sync_anchor_and_clock_offset() {
+        let mut playout = Playout::default();
+        assert_eq!(playout.local_time_of(0), None);
+        // The sender's clock runs 2s ahead of ours, 10ms each way.
+        let origin = Duration::from_secs(1000);
+        playout.timing_reply(
+            origin,
+            Duration::from_millis(1_002_010),
+            Duration::from_millis(1_002_010),
+            Duration::from_millis(1_000_020),
+        );
+        playout.sync(u32::MAX - 44099, Duration::from_secs(5000));
+        assert_eq!(
+            playout.local_time_of(u32::MAX - 44099),
+            Some(Duration::from_secs(4998))
+        );
+        // A second later, across the RTP timestamp wrap.
+        assert_eq!(playout.local_time_of(0), Some(Duration::from_secs(4999)));
+    }
+}
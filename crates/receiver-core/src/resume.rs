@@ -0,0 +1,223 @@
+//! Resume positions: where recently played items were left off, keyed on the
+//! source URL without its query, so a later load of the same item can pick up
+//! from there. Persisted beside `config.toml` and bounded to the most
+//! recently played items.
+
+use std::{
+    collections::VecDeque,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+use tracing::{debug, warn};
+
+use crate::config::{read_json_state, write_json_state};
+
+const FILE_NAME: &str = "resume-positions.json";
+/// Items remembered, the least recently played is forgotten first.
+const MAX_ENTRIES: usize = 200;
+/// Positions closer to the start than this aren't worth resuming.
+const MIN_POSITION_SECS: f64 = 10.0;
+/// Positions this close to the end count as played through.
+const END_MARGIN_SECS: f64 = 15.0;
+
+/// When a load resumes from a remembered position (`[playback] resume`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ResumeMode {
+    /// Nothing is remembered.
+    Off,
+    /// Only loads that ask for it (`MediaItem.resume`) resume.
+    #[default]
+    OnRequest,
+    /// Every load without a start time of its own resumes.
+    Always,
+}
+
+impl ResumeMode {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "off" => Some(Self::Off),
+            "on-request" => Some(Self::OnRequest),
+            "always" => Some(Self::Always),
+            _ => {
+                warn!(value, "Unknown resume mode in config, using default");
+                None
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Entry {
+    url: String,
+    position: f64,
+    duration: f64,
+    /// Unix seconds of the last update.
+    updated: u64,
+}
+
+#[derive(Debug, Default)]
+pub struct ResumeStore {
+    /// `None` keeps the positions for this launch only.
+    path: Option<PathBuf>,
+    /// Least recently played first.
+    entries: VecDeque<Entry>,
+    dirty: bool,
+    /// `Off` leaves the positions, and the file, untouched.
+    mode: ResumeMode,
+}
+
+impl ResumeStore {
+    /// Load the positions persisted in `dir`. A missing or unreadable file
+    /// starts empty.
+    pub fn load(dir: Option<&Path>) -> Self {
+        let path = dir.map(|dir| dir.join(FILE_NAME));
+        let entries: VecDeque<Entry> = path
+            .as_deref()
+            .and_then(|path| read_json_state(path, "resume positions"))
+            .unwrap_or_default();
+        debug!(count = entries.len(), "Loaded resume positions");
+        Self {
+            path,
+            entries,
+            dirty: false,
+            mode: ResumeMode::default(),
+        }
+    }
+
+    /// Follow `[playback] resume`, which can change while the receiver runs.
+    pub fn set_mode(&mut self, mode: ResumeMode) {
+        self.mode = mode;
+    }
+
+    /// The position to resume `url` from, in seconds.
+    pub fn position(&self, url: &str) -> Option<f64> {
+        self.entries
+            .iter()
+            .find(|entry| entry.url == url)
+            .map(|entry| entry.position)
+    }
+
+    /// Note how far `url` got. A position near the end forgets the item
+    /// instead; one near the start is ignored, as the load's own start seek
+    /// passes through there before reaching a resumed position.
+    pub fn update(&mut self, url: &str, position: f64, duration: f64) {
+        // No duration is a live stream, nothing to resume within.
+        if self.mode == ResumeMode::Off || position < MIN_POSITION_SECS || duration <= 0.0 {
+            return;
+        }
+        if position >= duration - END_MARGIN_SECS {
+            self.forget(url);
+            return;
+        }
+
+        let updated = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |since| since.as_secs());
+        match self.entries.iter().position(|entry| entry.url == url) {
+            Some(idx) if idx + 1 == self.entries.len() => {
+                let entry = &mut self.entries[idx];
+                self.dirty |= (entry.position - position).abs() >= 1.0;
+                entry.position = position;
+                entry.duration = duration;
+                entry.updated = updated;
+                return;
+            }
+            Some(idx) => {
+                self.entries.remove(idx);
+            }
+            None => (),
+        }
+        self.entries.push_back(Entry {
+            url: url.to_owned(),
+            position,
+            duration,
+            updated,
+        });
+        while self.entries.len() > MAX_ENTRIES {
+            self.entries.pop_front();
+        }
+        self.dirty = true;
+    }
+
+    /// Forget `url`, e.g. because it played to the end.
+    pub fn forget(&mut self, url: &str) {
+        let len = self.entries.len();
+        self.entries.retain(|entry| entry.url != url);
+        self.dirty |= self.entries.len() != len;
+    }
+
+    /// Write the positions out if they changed since the last save.
+    pub fn save(&mut self) {
+        if !self.dirty || self.mode == ResumeMode::Off {
+            return;
+        }
+        self.dirty = false;
+        if let Some(path) = self.path.as_deref() {
+            write_json_state(path, &self.entries, "resume positions");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn positions_near_the_end_are_forgotten() {
+        let mut store = ResumeStore::default();
+        store.update("http://a/v.mp4", 120.0, 600.0);
+        assert_eq!(store.position("http://a/v.mp4"), Some(120.0));
+
+        // The start seek passing through zero keeps the remembered position.
+        store.update("http://a/v.mp4", 0.0, 600.0);
+        assert_eq!(store.position("http://a/v.mp4"), Some(120.0));
+
+        store.update("http://a/v.mp4", 595.0, 600.0);
+        assert_eq!(store.position("http://a/v.mp4"), None);
+        // Live: no duration to resume within.
+        store.update("http://a/live.m3u8", 120.0, 0.0);
+        assert_eq!(store.position("http://a/live.m3u8"), None);
+    }
+
+    #[test]
+    fn the_least_recently_played_item_is_dropped_first() {
+        let mut store = ResumeStore::default();
+        for n in 0..MAX_ENTRIES {
+            store.update(&format!("http://a/{n}.mp4"), 60.0, 600.0);
+        }
+        // Playing the oldest again makes it the newest.
+        store.update("http://a/0.mp4", 90.0, 600.0);
+        store.update("http://a/new.mp4", 60.0, 600.0);
+        assert_eq!(store.entries.len(), MAX_ENTRIES);
+        assert_eq!(store.position("http://a/0.mp4"), Some(90.0));
+        assert_eq!(store.position("http://a/1.mp4"), None);
+    }
+
+    #[test]
+    fn positions_survive_a_restart() {
+        let dir = crate::config::unique_temp_path("resume");
+        let mut store = ResumeStore::load(Some(&dir));
+        store.update("http://a/v.mp4", 120.0, 600.0);
+        store.save();
+
+        let reloaded = ResumeStore::load(Some(&dir));
+        assert_eq!(reloaded.position("http://a/v.mp4"), Some(120.0));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn off_remembers_and_writes_nothing() {
+        let dir = crate::config::unique_temp_path("resume");
+        let mut store = ResumeStore::load(Some(&dir));
+        store.set_mode(ResumeMode::Off);
+        store.update("http://a/v.mp4", 120.0, 600.0);
+        store.save();
+        assert_eq!(store.position("http://a/v.mp4"), None);
+        assert!(!dir.join(FILE_NAME).exists());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
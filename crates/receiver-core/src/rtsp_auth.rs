@@ -0,0 +1,162 @@
+//! RTSP digest authentication (RFC 2069, the form AirPlay senders speak) for
+//! password-protected RAOP and AirPlay receivers. Senders pick their own user
+//! name (`iTunes`, `AirPlay`, ...), so only the password is checked.
+
+use md5::Digest;
+
+/// One connection's challenge. The nonce is fresh per connection, a sender
+/// that asks again after a `401` gets the same one.
+#[derive(Debug, Clone)]
+pub struct DigestAuth {
+    realm: &'static str,
+    password: String,
+    nonce: String,
+}
+
+impl DigestAuth {
+    pub fn new(realm: &'static str, password: String) -> Self {
+        Self {
+            realm,
+            password,
+            nonce: hex(&rand::random::<[u8; 16]>()),
+        }
+    }
+
+    /// The `WWW-Authenticate` value sent with a `401`.
+    pub fn challenge(&self) -> String {
+        format!("Digest realm=\"{}\", nonce=\"{}\"", self.realm, self.nonce)
+    }
+
+    /// Whether an `Authorization` header answers this challenge for a request
+    /// made with `method` to `uri`.
+    pub fn verify(&self, method: &str, uri: &str, authorization: Option<&str>) -> bool {
+        let Some(params) = authorization.and_then(|value| value.strip_prefix("Digest ")) else {
+            return false;
+        };
+        let param = |name: &str| digest_param(params, name);
+        let (Some(username), Some(realm), Some(nonce), Some(digest_uri), Some(response)) = (
+            param("username"),
+            param("realm"),
+            param("nonce"),
+            param("uri"),
+            param("response"),
+        ) else {
+            return false;
+        };
+        // A digest for another URI is a replayed one.
+        if realm != self.realm || nonce != self.nonce || !same_uri(digest_uri, uri) {
+            return false;
+        }
+        let ha1 = md5_hex(&format!("{username}:{}:{}", self.realm, self.password));
+        let ha2 = md5_hex(&format!("{method}:{digest_uri}"));
+        let expected = md5_hex(&format!("{ha1}:{}:{ha2}", self.nonce));
+        // iTunes sends the digest in upper case.
+        constant_time_eq(
+            response.to_ascii_lowercase().as_bytes(),
+            expected.as_bytes(),
+        )
+    }
+}
+
+/// Absolute RTSP URIs are compared parsed, as the request line's may have
+/// been normalized on the way in.
+fn same_uri(digest_uri: &str, request_uri: &str) -> bool {
+    digest_uri == request_uri
+        || matches!(
+            (url::Url::parse(digest_uri), url::Url::parse(request_uri)),
+            (Ok(a), Ok(b)) if a == b
+        )
+}
+
+/// Doesn't stop at the first difference, so the time taken says nothing
+/// about how much of a guess was right.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+/// A `name="value"` (or unquoted `name=value`) parameter of a digest header.
+fn digest_param<'a>(params: &'a str, name: &str) -> Option<&'a str> {
+    let mut rest = params;
+    loop {
+        rest = rest.trim_start_matches([' ', ',']);
+        let (key, after) = rest.split_once('=')?;
+        let (value, tail) = match after.strip_prefix('"') {
+            Some(quoted) => {
+                let end = quoted.find('"')?;
+                (&quoted[..end], &quoted[end + 1..])
+            }
+            None => after.split_once(',').unwrap_or((after, "")),
+        };
+        if key.trim().eq_ignore_ascii_case(name) {
+            return Some(value.trim());
+        }
+        rest = tail;
+    }
+}
+
+fn md5_hex(s: &str) -> String {
+    hex(&md5::Md5::digest(s.as_bytes()))
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+/// What a sender puts in `Authorization` to answer `challenge` (a
+/// `WWW-Authenticate` value) for `method` and `uri`.
+#[cfg(test)]
+pub fn sender_authorization(challenge: &str, password: &str, method: &str, uri: &str) -> String {
+    let challenge = challenge.strip_prefix("Digest ").unwrap();
+    let realm = digest_param(challenge, "realm").unwrap();
+    let nonce = digest_param(challenge, "nonce").unwrap();
+    let ha1 = md5_hex(&format!("iTunes:{realm}:{password}"));
+    let ha2 = md5_hex(&format!("{method}:{uri}"));
+    let response = md5_hex(&format!("{ha1}:{nonce}:{ha2}")).to_uppercase();
+    format!(
+        "Digest username=\"iTunes\", realm=\"{realm}\", nonce=\"{nonce}\", uri=\"{uri}\", response=\"{response}\""
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_only_the_right_password_and_nonce() {
+        let auth = DigestAuth::new("raop", "hunter2".to_owned());
+        let good = sender_authorization(
+            &auth.challenge(),
+            "hunter2",
+            "ANNOUNCE",
+            "rtsp://10.0.0.2/1234",
+        );
+        let uri = "rtsp://10.0.0.2/1234";
+        assert!(auth.verify("ANNOUNCE", uri, Some(&good)));
+        // Bound to the method and the URI.
+        assert!(!auth.verify("SETUP", uri, Some(&good)));
+        assert!(!auth.verify("ANNOUNCE", "rtsp://10.0.0.2/5678", Some(&good)));
+        assert!(!auth.verify(
+            "ANNOUNCE",
+            uri,
+            Some(&sender_authorization(
+                &auth.challenge(),
+                "wrong",
+                "ANNOUNCE",
+                "rtsp://10.0.0.2/1234"
+            ))
+        ));
+        let other = DigestAuth::new("raop", "hunter2".to_owned());
+        assert!(!other.verify("ANNOUNCE", uri, Some(&good)));
+        assert!(!auth.verify("ANNOUNCE", uri, None));
+        assert!(!auth.verify("ANNOUNCE", uri, Some("Basic aXR1bmVzOmh1bnRlcjI=")));
+    }
+
+    #[test]
+    fn parses_quoted_and_bare_parameters() {
+        let params = r#"username="AirPlay", realm="airplay", nc=00000001, uri="/play""#;
+        assert_eq!(digest_param(params, "username"), Some("AirPlay"));
+        assert_eq!(digest_param(params, "nc"), Some("00000001"));
+        assert_eq!(digest_param(params, "uri"), Some("/play"));
+        assert_eq!(digest_param(params, "response"), None);
+    }
+}
@@ -0,0 +1,147 @@
+//! The secondary subtitle track: a subtitle file parsed whole, away from the
+//! playback pipeline, for the video sink's second cue engine
+//! ([`fcast_video::secondary`]).
+//!
+//! The playback pipeline presents one text stream at a time, so the second
+//! track cannot come out of it. A file added as an external subtitle is
+//! fetched again here and run through the same parsers, in the same cue-IR
+//! mode, so it is styled the way it would be as the main track. Embedded
+//! tracks have no file to fetch and cannot be the secondary track.
+
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use anyhow::{Context, Result, anyhow};
+use fcast_video::{cue::TextFormat, secondary::TimedCue};
+use gst::prelude::*;
+
+/// Fetching and parsing a subtitle file takes a fraction of this; a server
+/// that stalls past it is given up on.
+const PARSE_TIMEOUT: Duration = Duration::from_secs(30);
+/// How long each wait for a cue lasts before the bus is checked for errors.
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Fetch and parse the subtitle file at `url`. Blocks until the whole file is
+/// parsed, so it belongs on a blocking thread.
+pub fn parse(url: &str) -> Result<Vec<TimedCue>> {
+    let pipeline = gst::Pipeline::new();
+    // Only text comes out: anything else in the file is a dead end.
+    let decode = gst::ElementFactory::make("uridecodebin")
+        .property("uri", url)
+        .property("caps", gst::Caps::new_empty_simple("text/x-raw"))
+        .build()
+        .context("creating uridecodebin")?;
+    let sink = gst_app::AppSink::builder().sync(false).build();
+    pipeline.add_many([&decode, sink.upcast_ref()])?;
+
+    // The cue-IR switch `Player::new` sets on the playback pipeline's parsers.
+    pipeline.connect_deep_element_added(|_, _, element| {
+        let Some(factory) = element.factory() else {
+            return;
+        };
+        if matches!(factory.name().as_str(), "rssubparse" | "rsssaparse") {
+            element.set_property_from_str("text-format", "cue-ir");
+        }
+    });
+    let sink_pad = sink.static_pad("sink").expect("appsink has a sink pad");
+    decode.connect_pad_added(move |_, pad| {
+        if !sink_pad.is_linked() {
+            let _ = pad.link(&sink_pad);
+        }
+    });
+
+    pipeline.set_state(gst::State::Playing)?;
+    let result = collect(&pipeline, &sink);
+    let _ = pipeline.set_state(gst::State::Null);
+    result
+}
+
+fn collect(pipeline: &gst::Pipeline, sink: &gst_app::AppSink) -> Result<Vec<TimedCue>> {
+    let bus = pipeline.bus().expect("a pipeline has a bus");
+    let deadline = Instant::now() + PARSE_TIMEOUT;
+    let mut cues = Vec::new();
+    loop {
+        if let Some(sample) = sink.try_pull_sample(POLL_INTERVAL) {
+            cues.extend(timed_cue(&sample));
+            continue;
+        }
+        if sink.is_eos() {
+            return Ok(cues);
+        }
+        if let Some(msg) = bus.pop_filtered(&[gst::MessageType::Error])
+            && let gst::MessageView::Error(err) = msg.view()
+        {
+            return Err(anyhow!("{}", err.error()));
+        }
+        if Instant::now() > deadline {
+            return Err(anyhow!("timed out after {} cues", cues.len()));
+        }
+    }
+}
+
+/// One parsed cue. The pipeline runs from zero at rate one, so the buffer
+/// times are the file's own, in stream time.
+fn timed_cue(sample: &gst::Sample) -> Option<TimedCue> {
+    let buffer = sample.buffer()?;
+    let start = buffer.pts()?;
+    let map = buffer.map_readable().ok()?;
+    let text = std::str::from_utf8(map.as_slice()).ok()?.to_owned();
+    let markup = sample
+        .caps()
+        .and_then(|caps| caps.structure(0))
+        .and_then(|structure| structure.get::<&str>("format").ok())
+        == Some("pango-markup");
+    let format = match buffer.meta::<gstrssubparse::cueir::CueIrMeta>() {
+        Some(meta) => TextFormat::CueIr {
+            ir: Arc::new(meta.ir().clone()),
+            pts_start: Some(start),
+        },
+        None if markup => TextFormat::PangoMarkup,
+        None => TextFormat::Utf8,
+    };
+    Some(TimedCue {
+        format,
+        text,
+        start,
+        end: buffer
+            .duration()
+            .and_then(|duration| start.checked_add(duration)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn samples_become_cues_in_file_time() {
+        gst::init().unwrap();
+        let mut buffer = gst::Buffer::from_slice(b"Hello");
+        {
+            let buffer = buffer.get_mut().unwrap();
+            buffer.set_pts(gst::ClockTime::from_seconds(3));
+            buffer.set_duration(gst::ClockTime::from_seconds(2));
+        }
+        let caps = gst::Caps::builder("text/x-raw")
+            .field("format", "pango-markup")
+            .build();
+        let sample = gst::Sample::builder().buffer(&buffer).caps(&caps).build();
+        assert_eq!(
+            timed_cue(&sample),
+            Some(TimedCue {
+                format: TextFormat::PangoMarkup,
+                text: "Hello".to_owned(),
+                start: gst::ClockTime::from_seconds(3),
+                end: Some(gst::ClockTime::from_seconds(5)),
+            })
+        );
+
+        // No pts, no place on the timeline.
+        let sample = gst::Sample::builder()
+            .buffer(&gst::Buffer::from_slice(b"Lost"))
+            .build();
+        assert_eq!(timed_cue(&sample), None);
+    }
+}
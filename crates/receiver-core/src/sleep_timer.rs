@@ -0,0 +1,120 @@
+//! The sleep timer: stops playback at the end of the current item or at a
+//! deadline, optionally fading the volume out on the way there.
+
+use std::time::{Duration, Instant};
+
+use crate::fcast::SleepTimerSetting;
+
+/// How often a deadline timer ticks, which is also how often the GUI's
+/// countdown refreshes.
+pub const TICK: Duration = Duration::from_secs(1);
+/// How often the volume steps down while fading.
+pub const FADE_TICK: Duration = Duration::from_millis(250);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Stop {
+    EndOfItem,
+    At(Instant),
+}
+
+#[derive(Debug)]
+pub struct SleepTimer {
+    /// What the timer was set to, for the GUI.
+    pub armed: SleepTimerSetting,
+    pub stop: Stop,
+    pub fade: Duration,
+    /// The volume when the fade began, restored once playback has stopped.
+    pub volume_before_fade: Option<f32>,
+}
+
+impl SleepTimer {
+    /// `None` for [`SleepTimerSetting::Off`].
+    pub fn new(setting: SleepTimerSetting, now: Instant) -> Option<Self> {
+        let (stop, fade) = match setting {
+            SleepTimerSetting::Off => return None,
+            SleepTimerSetting::EndOfItem { fade } => (Stop::EndOfItem, fade),
+            SleepTimerSetting::Deadline { remaining, fade } => (Stop::At(now + remaining), fade),
+        };
+        Some(Self {
+            armed: setting,
+            stop,
+            fade,
+            volume_before_fade: None,
+        })
+    }
+
+    /// The timer as senders see it at `now`.
+    pub fn setting(&self, now: Instant) -> SleepTimerSetting {
+        match self.stop {
+            Stop::EndOfItem => SleepTimerSetting::EndOfItem { fade: self.fade },
+            Stop::At(deadline) => SleepTimerSetting::Deadline {
+                remaining: deadline.saturating_duration_since(now),
+                fade: self.fade,
+            },
+        }
+    }
+
+    pub fn ends_with_item(&self) -> bool {
+        self.stop == Stop::EndOfItem
+    }
+
+    /// Time left of a deadline timer.
+    pub fn remaining(&self, now: Instant) -> Option<Duration> {
+        match self.stop {
+            Stop::EndOfItem => None,
+            Stop::At(deadline) => Some(deadline.saturating_duration_since(now)),
+        }
+    }
+}
+
+/// Volume gain with `left` to go until the stop: unity until the fade begins,
+/// then falling linearly to silence at the stop.
+pub fn fade_gain(fade: Duration, left: Duration) -> f32 {
+    if left >= fade {
+        1.0
+    } else {
+        left.as_secs_f32() / fade.as_secs_f32()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fade_falls_linearly_to_silence() {
+        let fade = Duration::from_secs(20);
+        assert_eq!(fade_gain(fade, Duration::from_secs(60)), 1.0);
+        assert_eq!(fade_gain(fade, Duration::from_secs(20)), 1.0);
+        assert_eq!(fade_gain(fade, Duration::from_secs(5)), 0.25);
+        assert_eq!(fade_gain(fade, Duration::ZERO), 0.0);
+        // No fade keeps the volume up to the stop.
+        assert_eq!(fade_gain(Duration::ZERO, Duration::ZERO), 1.0);
+    }
+
+    #[test]
+    fn deadline_reports_the_time_left() {
+        let now = Instant::now();
+        let timer = SleepTimer::new(
+            SleepTimerSetting::Deadline {
+                remaining: Duration::from_secs(600),
+                fade: Duration::from_secs(30),
+            },
+            now,
+        )
+        .unwrap();
+        assert!(!timer.ends_with_item());
+        assert_eq!(
+            timer.setting(now + Duration::from_secs(100)),
+            SleepTimerSetting::Deadline {
+                remaining: Duration::from_secs(500),
+                fade: Duration::from_secs(30),
+            }
+        );
+        assert_eq!(
+            timer.remaining(now + Duration::from_secs(700)),
+            Some(Duration::ZERO)
+        );
+        assert!(SleepTimer::new(SleepTimerSetting::Off, now).is_none());
+    }
+}
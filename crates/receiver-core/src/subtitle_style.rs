@@ -0,0 +1,215 @@
+//! The subtitle house style from `[subtitles]`. Named sizes and positions
+//! resolve to the fractions [`CueStyle`] is drawn with; senders adjust the
+//! same settings with `SubtitleStyleChanged`, in numbers rather than names.
+//!
+//! This module is pure policy. Handing the style to the cue engine is the
+//! application's job.
+
+use fcast_protocol::v4;
+use fcast_video::cue_ir::{CueStyle, DEFAULT_BACKGROUND, DEFAULT_OUTLINE};
+use tracing::warn;
+
+use crate::config::SubtitlesConfig;
+
+/// Size factors a sender may ask for.
+const MIN_SIZE: f32 = 0.5;
+const MAX_SIZE: f32 = 3.0;
+/// Positions above this put the text in the middle of the picture.
+const MAX_POSITION: f32 = 0.5;
+
+/// The settings drawer's sizes, as factors of [`CueStyle::default`]'s.
+const SIZES: &[(&str, f32)] = &[
+    ("small", 0.75),
+    ("normal", 1.0),
+    ("large", 1.35),
+    ("huge", 1.75),
+];
+
+/// The settings drawer's positions, as fractions of the picture height.
+/// `bottom` is [`CueStyle::default`]'s margin.
+const POSITIONS: &[(&str, f32)] = &[("bottom", 0.04), ("raised", 0.12), ("high", 0.25)];
+
+/// A sender's `SubtitleStyleChanged`. Absent fields keep their value.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct StyleUpdate {
+    pub size: Option<f32>,
+    pub position: Option<f32>,
+    pub background: Option<bool>,
+}
+
+/// A `size` setting as a factor of the normal size. `None` for anything
+/// unrecognised, so the caller can warn and fall back.
+pub fn parse_size(value: &str) -> Option<f32> {
+    parse(value, SIZES, MIN_SIZE, MAX_SIZE)
+}
+
+/// A `position` setting as a fraction of the picture height.
+pub fn parse_position(value: &str) -> Option<f32> {
+    parse(value, POSITIONS, 0.0, MAX_POSITION)
+}
+
+fn parse(value: &str, named: &[(&str, f32)], min: f32, max: f32) -> Option<f32> {
+    let value = value.trim();
+    if let Some((_, v)) = named
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(value))
+    {
+        return Some(*v);
+    }
+    value
+        .parse::<f32>()
+        .ok()
+        .filter(|v| v.is_finite())
+        .map(|v| v.clamp(min, max))
+}
+
+/// The name of a preset `value`, else the number itself, for storing a
+/// sender's choice where the drawer can show it.
+fn spell(value: f32, named: &[(&str, f32)]) -> String {
+    match named.iter().find(|(_, v)| (v - value).abs() < 1e-4) {
+        Some((name, _)) => (*name).to_owned(),
+        None => format!("{}", (value * 100.0).round() / 100.0),
+    }
+}
+
+fn size_of(value: Option<&str>) -> f32 {
+    match value {
+        None => 1.0,
+        Some(value) => parse_size(value).unwrap_or_else(|| {
+            warn!(value, "Unrecognised subtitle size, using the default");
+            1.0
+        }),
+    }
+}
+
+fn position_of(config: &SubtitlesConfig) -> f32 {
+    let default = CueStyle::default().bottom_margin_fraction;
+    match config.position.as_deref() {
+        None => default,
+        Some(value) => parse_position(value).unwrap_or_else(|| {
+            warn!(value, "Unrecognised subtitle position, using the default");
+            default
+        }),
+    }
+}
+
+/// The style cues are drawn with under `config`.
+pub fn cue_style(config: &SubtitlesConfig) -> CueStyle {
+    let default = CueStyle::default();
+    CueStyle {
+        font_family: config.font.clone(),
+        font_weight: if config.bold { 700.0 } else { 400.0 },
+        font_height_fraction: default.font_height_fraction * size_of(config.size.as_deref()),
+        bottom_margin_fraction: position_of(config),
+        outline: config.outline.then_some(DEFAULT_OUTLINE),
+        background: config.background.then_some(DEFAULT_BACKGROUND),
+        ..default
+    }
+}
+
+/// The style the secondary track is drawn with: the main track's, at the top
+/// of the picture and in its own size.
+pub fn secondary_cue_style(config: &SubtitlesConfig) -> CueStyle {
+    let default = CueStyle::default();
+    CueStyle {
+        font_height_fraction: default.font_height_fraction
+            * size_of(config.secondary_size.as_deref()),
+        bottom_margin_fraction: default.bottom_margin_fraction,
+        top_anchored: true,
+        ..cue_style(config)
+    }
+}
+
+/// Apply a sender's update to `config`.
+pub fn apply(config: &mut SubtitlesConfig, update: StyleUpdate) {
+    if let Some(size) = update.size {
+        config.size = Some(spell(size.clamp(MIN_SIZE, MAX_SIZE), SIZES));
+    }
+    if let Some(position) = update.position {
+        config.position = Some(spell(position.clamp(0.0, MAX_POSITION), POSITIONS));
+    }
+    if let Some(background) = update.background {
+        config.background = background;
+    }
+}
+
+/// The `SubtitleStyleChanged` confirming `config` to senders.
+pub fn message(config: &SubtitlesConfig) -> v4::ConstructedMessage<'static> {
+    v4::MessageBuilder::new().subtitle_style_changed(
+        Some(size_of(config.size.as_deref())),
+        Some(position_of(config)),
+        Some(config.background),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_and_numbers_resolve_and_clamp() {
+        assert_eq!(parse_size("Large"), Some(1.35));
+        assert_eq!(parse_size("1.2"), Some(1.2));
+        assert_eq!(parse_size("10"), Some(MAX_SIZE));
+        assert_eq!(parse_size("NaN"), None);
+        assert_eq!(parse_size("enormous"), None);
+        assert_eq!(parse_position("raised"), Some(0.12));
+        assert_eq!(parse_position("-1"), Some(0.0));
+    }
+
+    #[test]
+    fn default_config_draws_the_default_style() {
+        assert_eq!(cue_style(&SubtitlesConfig::default()), CueStyle::default());
+
+        let config = SubtitlesConfig {
+            size: Some("huge".to_owned()),
+            background: false,
+            bold: false,
+            ..SubtitlesConfig::default()
+        };
+        let style = cue_style(&config);
+        assert_eq!(style.background, None);
+        assert_eq!(style.font_weight, 400.0);
+        assert!(style.font_height_fraction > CueStyle::default().font_height_fraction);
+
+        let config = SubtitlesConfig {
+            position: Some("high".to_owned()),
+            secondary_size: Some("small".to_owned()),
+            ..config
+        };
+        let secondary = secondary_cue_style(&config);
+        assert!(secondary.top_anchored);
+        assert_eq!(
+            secondary.bottom_margin_fraction,
+            CueStyle::default().bottom_margin_fraction
+        );
+        assert!(secondary.font_height_fraction < CueStyle::default().font_height_fraction);
+        assert_eq!(secondary.background, None);
+    }
+
+    #[test]
+    fn sender_updates_keep_absent_fields_and_store_presets_by_name() {
+        let mut config = SubtitlesConfig::default();
+        apply(
+            &mut config,
+            StyleUpdate {
+                size: Some(1.35),
+                position: Some(0.3),
+                background: None,
+            },
+        );
+        assert_eq!(config.size.as_deref(), Some("large"));
+        assert_eq!(config.position.as_deref(), Some("0.3"));
+        assert!(config.background);
+
+        apply(
+            &mut config,
+            StyleUpdate {
+                background: Some(false),
+                ..StyleUpdate::default()
+            },
+        );
+        assert_eq!(config.size.as_deref(), Some("large"));
+        assert!(!config.background);
+    }
+}
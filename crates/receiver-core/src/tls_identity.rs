@@ -0,0 +1,223 @@
+//! The receiver's TLS identity: the self-signed certificate v4 sessions upgrade
+//! to, and the SPKI fingerprint advertised as the `fp` TXT record. Persisted
+//! beside `config.toml` so senders that pinned the fingerprint keep trusting
+//! the receiver across restarts.
+
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use anyhow::{Context, Result};
+use base64::Engine;
+use rcgen::{CertificateParams, DistinguishedName, KeyPair, PublicKeyData, date_time_ymd};
+use rustls_pki_types::{CertificateDer, PrivateKeyDer, pem::PemObject};
+use tokio_rustls::{TlsAcceptor, rustls};
+use tracing::{info, warn};
+
+const KEY_FILE_NAME: &str = "tls-key.pem";
+const CERT_FILE_NAME: &str = "tls-cert.pem";
+
+pub struct TlsIdentity {
+    key_pem: String,
+    cert_pem: String,
+    /// Base64 SHA-256 of the key's SubjectPublicKeyInfo.
+    fingerprint: String,
+}
+
+impl std::fmt::Debug for TlsIdentity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Never log the private key.
+        f.debug_struct("TlsIdentity")
+            .field("fingerprint", &self.fingerprint)
+            .finish_non_exhaustive()
+    }
+}
+
+impl TlsIdentity {
+    /// A fresh identity that lives only in memory.
+    pub fn generate() -> Result<Self> {
+        let mut params: CertificateParams = Default::default();
+        params.not_before = date_time_ymd(1975, 1, 1);
+        params.not_after = date_time_ymd(4096, 1, 1);
+        params.distinguished_name = DistinguishedName::new();
+        let key_pair = KeyPair::generate()?;
+        let cert = params.self_signed(&key_pair)?;
+        Ok(Self {
+            key_pem: key_pair.serialize_pem(),
+            cert_pem: cert.pem(),
+            fingerprint: fingerprint(&key_pair),
+        })
+    }
+
+    /// Reuse the identity persisted in `dir`, or generate one and persist it
+    /// there. A missing or unusable pair is replaced; `None` (no writable config
+    /// location) gives an identity that lasts this launch only.
+    pub fn load_or_create(dir: Option<&Path>) -> Result<Self> {
+        let Some(dir) = dir else {
+            warn!("No writable config location, TLS identity will change on every launch");
+            return Self::generate();
+        };
+
+        match Self::read(dir) {
+            Ok(Some(identity)) => {
+                info!(fingerprint = identity.fingerprint, "Loaded TLS identity");
+                return Ok(identity);
+            }
+            Ok(None) => info!(?dir, "No TLS identity yet, generating one"),
+            Err(err) => warn!(?err, ?dir, "Stored TLS identity is unusable, replacing it"),
+        }
+
+        Self::create(dir)
+    }
+
+    /// Replace the identity persisted in `dir` with a freshly generated one.
+    /// Senders that pinned the old fingerprint have to pair again.
+    pub fn rotate(dir: Option<&Path>) -> Result<Self> {
+        let identity = match dir {
+            Some(dir) => Self::create(dir)?,
+            None => Self::generate()?,
+        };
+        info!(fingerprint = identity.fingerprint, "Rotated TLS identity");
+        Ok(identity)
+    }
+
+    fn create(dir: &Path) -> Result<Self> {
+        let identity = Self::generate()?;
+        // A failed write still leaves a usable identity for this launch.
+        if let Err(err) = identity.write(dir) {
+            warn!(?err, ?dir, "Failed to persist TLS identity");
+        }
+        Ok(identity)
+    }
+
+    /// The pair persisted in `dir`; `Ok(None)` when neither file exists.
+    fn read(dir: &Path) -> Result<Option<Self>> {
+        let (key_path, cert_path) = paths(dir);
+        if !key_path.exists() && !cert_path.exists() {
+            return Ok(None);
+        }
+
+        let key_pem = std::fs::read_to_string(&key_path)
+            .with_context(|| format!("failed to read {key_path:?}"))?;
+        let cert_pem = std::fs::read_to_string(&cert_path)
+            .with_context(|| format!("failed to read {cert_path:?}"))?;
+        let key_pair = KeyPair::from_pem(&key_pem)?;
+
+        let identity = Self {
+            fingerprint: fingerprint(&key_pair),
+            key_pem,
+            cert_pem,
+        };
+        // Catches a certificate that doesn't belong to the key.
+        identity.acceptor()?;
+        Ok(Some(identity))
+    }
+
+    fn write(&self, dir: &Path) -> std::io::Result<()> {
+        let (key_path, cert_path) = paths(dir);
+        crate::config::write_atomic_private(&key_path, self.key_pem.as_bytes())?;
+        crate::config::write_atomic(&cert_path, self.cert_pem.as_bytes())?;
+        info!(?key_path, ?cert_path, "Saved TLS identity");
+        Ok(())
+    }
+
+    pub fn fingerprint(&self) -> &str {
+        &self.fingerprint
+    }
+
+    pub fn acceptor(&self) -> Result<TlsAcceptor> {
+        let cert = CertificateDer::from_pem_slice(self.cert_pem.as_bytes())?;
+        let key = PrivateKeyDer::from_pem_slice(self.key_pem.as_bytes())?;
+        let config =
+            rustls::ServerConfig::builder_with_protocol_versions(&[&rustls::version::TLS13])
+                .with_no_client_auth()
+                .with_single_cert(vec![cert], key)?;
+        Ok(TlsAcceptor::from(Arc::new(config)))
+    }
+}
+
+fn paths(dir: &Path) -> (PathBuf, PathBuf) {
+    (dir.join(KEY_FILE_NAME), dir.join(CERT_FILE_NAME))
+}
+
+fn fingerprint(key_pair: &KeyPair) -> String {
+    use sha2::Digest;
+    let digest = sha2::Sha256::digest(key_pair.subject_public_key_info());
+    base64::engine::general_purpose::STANDARD.encode(digest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::unique_temp_path;
+
+    #[test]
+    fn identity_survives_a_restart() {
+        let dir = unique_temp_path("tls");
+        let first = TlsIdentity::load_or_create(Some(&dir)).expect("create");
+        let second = TlsIdentity::load_or_create(Some(&dir)).expect("load");
+        assert_eq!(first.fingerprint(), second.fingerprint());
+        assert_eq!(first.cert_pem, second.cert_pem);
+        second.acceptor().expect("acceptor");
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn the_private_key_is_owner_only() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = unique_temp_path("tls");
+        TlsIdentity::load_or_create(Some(&dir)).expect("create");
+        let mode = std::fs::metadata(dir.join(KEY_FILE_NAME))
+            .expect("stat")
+            .permissions()
+            .mode();
+        assert_eq!(mode & 0o777, 0o600);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn rotation_replaces_the_persisted_identity() {
+        let dir = unique_temp_path("tls");
+        let old = TlsIdentity::load_or_create(Some(&dir)).expect("create");
+        let rotated = TlsIdentity::rotate(Some(&dir)).expect("rotate");
+        assert_ne!(old.fingerprint(), rotated.fingerprint());
+
+        let reloaded = TlsIdentity::load_or_create(Some(&dir)).expect("load");
+        assert_eq!(rotated.fingerprint(), reloaded.fingerprint());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn corrupt_identity_is_replaced() {
+        let dir = unique_temp_path("tls");
+        std::fs::create_dir_all(&dir).expect("mkdir");
+        std::fs::write(dir.join(KEY_FILE_NAME), b"not a key").expect("write");
+
+        let identity = TlsIdentity::load_or_create(Some(&dir)).expect("create");
+        let reloaded = TlsIdentity::load_or_create(Some(&dir)).expect("load");
+        assert_eq!(identity.fingerprint(), reloaded.fingerprint());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn mismatched_pair_is_replaced() {
+        let dir = unique_temp_path("tls");
+        let original = TlsIdentity::load_or_create(Some(&dir)).expect("create");
+        // Pair the stored certificate with somebody else's key.
+        let other = TlsIdentity::generate().expect("generate");
+        std::fs::write(dir.join(KEY_FILE_NAME), &other.key_pem).expect("write");
+
+        let replaced = TlsIdentity::load_or_create(Some(&dir)).expect("load");
+        assert_ne!(replaced.fingerprint(), original.fingerprint());
+        assert_ne!(replaced.fingerprint(), other.fingerprint());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
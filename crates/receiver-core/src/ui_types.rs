@@ -54,6 +54,17 @@ pub struct UiMediaTrack {
     pub name: String,
 }
 
+/// Mirrors the generated `UiHistoryEntry`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct UiHistoryEntry {
+    pub title: String,
+    /// Empty when the sender gave no name.
+    pub sender_name: String,
+    pub watched_secs: i32,
+    /// Zero for live streams.
+    pub duration_secs: i32,
+}
+
 /// Mirrors the generated `UiToastKind`. The wording lives in slint (`@tr`,
 /// so it localizes), severity (error vs warning styling) derives from the
 /// kind there too.
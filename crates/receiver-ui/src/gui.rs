@@ -4,17 +4,18 @@
 //! The command enum and the `GuiController` that produces them stay in
 //! `receiver-core`; see its module of the same name.
 
-use std::rc::Rc;
+use std::{rc::Rc, time::Duration};
 
 use slint::{ComponentHandle, SharedString, ToSharedString, VecModel};
 use tokio::sync::mpsc::UnboundedReceiver;
 use tracing::{debug, error};
 
-use fcast_protocol::v3;
+use fcast_protocol::{v3, v4};
 use receiver_core::{
     MessageSender,
     application::PacketOrigin,
-    fcast::Operation,
+    av_delay::DelayUpdate,
+    fcast::{Operation, SleepTimerSetting},
     image::DecodedImage,
     log_if_err,
     message::{Message, PortConflictChoice},
@@ -28,10 +29,13 @@ pub use receiver_core::gui::*;
 #[cfg(any(target_os = "macos", target_os = "windows"))]
 use crate::UiUpdaterState;
 use crate::{
-    AppState, Bridge, CompoundImage, GuiPlaybackState, MainWindow, UiMediaTrack, UiMediaTrackType,
-    UiPlayerVariant, UiToastKind,
+    AppState, Bridge, CompoundImage, GuiPlaybackState, MainWindow, UiHistoryEntry, UiMediaTrack,
+    UiMediaTrackType, UiPlayerVariant, UiToastKind,
 };
 
+/// Deadline sleep timers set from the player's menu fade out over this long.
+const MENU_SLEEP_TIMER_FADE: Duration = Duration::from_secs(30);
+
 /// A QR code as slint wants it.
 pub type QrCodeImage = slint::SharedPixelBuffer<slint::Rgb8Pixel>;
 
@@ -188,6 +192,40 @@ pub fn register_callbacks(ui: &MainWindow, msg_tx: MessageSender) {
         }
     });
 
+    bridge.on_seek_relative({
+        let msg_tx = msg_tx.clone();
+        move |secs| {
+            let Ok(offset) = gst::ClockTime::try_from_seconds_f64(secs.abs() as f64) else {
+                return;
+            };
+            let offset = if secs < 0.0 {
+                gst::Signed::Negative(offset)
+            } else {
+                gst::Signed::Positive(offset)
+            };
+            msg_tx.operation(PacketOrigin::Gui, Operation::SeekRelative(offset));
+        }
+    });
+
+    bridge.on_frame_step({
+        let msg_tx = msg_tx.clone();
+        move |forward| {
+            msg_tx.operation(PacketOrigin::Gui, Operation::FrameStep { forward });
+        }
+    });
+
+    bridge.on_chapter_step({
+        let msg_tx = msg_tx.clone();
+        move |next| {
+            let target = if next {
+                v4::ChapterTarget::Next
+            } else {
+                v4::ChapterTarget::Previous
+            };
+            msg_tx.operation(PacketOrigin::Gui, Operation::SeekToChapter(target));
+        }
+    });
+
     bridge.on_toggle_fullscreen({
         let ui_weak = ui.as_weak();
         move || {
@@ -246,6 +284,45 @@ pub fn register_callbacks(ui: &MainWindow, msg_tx: MessageSender) {
         }
     });
 
+    bridge.on_set_sleep_timer({
+        let msg_tx = msg_tx.clone();
+        move |minutes| {
+            let setting = match minutes {
+                ..0 => SleepTimerSetting::EndOfItem {
+                    fade: Duration::ZERO,
+                },
+                0 => SleepTimerSetting::Off,
+                minutes => SleepTimerSetting::Deadline {
+                    remaining: Duration::from_secs(minutes as u64 * 60),
+                    fade: MENU_SLEEP_TIMER_FADE,
+                },
+            };
+            msg_tx.operation(PacketOrigin::Gui, Operation::SetSleepTimer(setting));
+        }
+    });
+
+    bridge.on_set_audio_delay({
+        let msg_tx = msg_tx.clone();
+        move |ms| {
+            let update = DelayUpdate {
+                audio_micros: Some(ms as i64 * 1000),
+                ..DelayUpdate::default()
+            };
+            msg_tx.operation(PacketOrigin::Gui, Operation::SetDelay(update));
+        }
+    });
+
+    bridge.on_set_subtitle_delay({
+        let msg_tx = msg_tx.clone();
+        move |ms| {
+            let update = DelayUpdate {
+                subtitle_micros: Some(ms as i64 * 1000),
+                ..DelayUpdate::default()
+            };
+            msg_tx.operation(PacketOrigin::Gui, Operation::SetDelay(update));
+        }
+    });
+
     bridge.on_set_cursor_hidden({
         let ui_weak = ui.as_weak();
         move |hidden| {
@@ -291,6 +368,13 @@ pub fn register_callbacks(ui: &MainWindow, msg_tx: MessageSender) {
         }
     });
 
+    bridge.on_select_secondary_subtitle({
+        let msg_tx = msg_tx.clone();
+        move |id: i32| {
+            msg_tx.send(Message::SelectSecondarySubtitle(id));
+        }
+    });
+
     bridge.on_set_bool_setting({
         let msg_tx = msg_tx.clone();
         move |key: SharedString, value: bool| {
@@ -311,6 +395,20 @@ pub fn register_callbacks(ui: &MainWindow, msg_tx: MessageSender) {
         }
     });
 
+    bridge.on_rotate_tls_identity({
+        let msg_tx = msg_tx.clone();
+        move || {
+            msg_tx.send(Message::RotateTlsIdentity);
+        }
+    });
+
+    bridge.on_forget_paired_senders({
+        let msg_tx = msg_tx.clone();
+        move || {
+            msg_tx.send(Message::ForgetPairedSenders);
+        }
+    });
+
     bridge.on_select_playlist_item({
         let msg_tx = msg_tx.clone();
         move |idx: i32| {
@@ -392,6 +490,44 @@ fn set_buffered_ranges(bridge: &Bridge, ranges: Vec<(f32, f32)>) {
     bridge.set_buffered_ranges(Rc::new(VecModel::from(model)).into());
 }
 
+fn set_chapter_marks(bridge: &Bridge, starts: Vec<f32>) {
+    bridge.set_chapter_marks(Rc::new(VecModel::from(starts)).into());
+}
+
+fn set_playback_history(bridge: &Bridge, entries: Vec<ui_types::UiHistoryEntry>) {
+    let model: Vec<UiHistoryEntry> = entries
+        .into_iter()
+        .map(|entry| UiHistoryEntry {
+            title: entry.title.into(),
+            sender_name: entry.sender_name.into(),
+            watched_secs: entry.watched_secs,
+            duration_secs: entry.duration_secs,
+        })
+        .collect();
+    bridge.set_playback_history(Rc::new(VecModel::from(model)).into());
+}
+
+/// The menu marks the option a timer was set with: minutes for a deadline, -1
+/// for the end of the item and 0 for none.
+fn set_sleep_timer(bridge: &Bridge, armed: SleepTimerSetting, remaining: Option<Duration>) {
+    let choice = match armed {
+        SleepTimerSetting::Off => 0,
+        SleepTimerSetting::EndOfItem { .. } => -1,
+        SleepTimerSetting::Deadline { remaining, .. } => remaining.as_secs().div_ceil(60) as i32,
+    };
+    bridge.set_sleep_timer_choice(choice);
+    bridge.set_sleep_timer_remaining(remaining.map_or(-1, |left| left.as_secs() as i32));
+}
+
+/// A delay setting as the drawer shows it: blank for none.
+fn delay_ms_text(ms: i64) -> SharedString {
+    if ms == 0 {
+        SharedString::new()
+    } else {
+        ms.to_shared_string()
+    }
+}
+
 fn clear_audio_covers(bridge: &Bridge, renderer_tx: &RendererMsgSender) {
     bridge.set_audio_track_cover(CompoundImage::default());
     let _ = renderer_tx.send(RendererMessage::ClearBluredAudioTrackCover);
@@ -452,6 +588,18 @@ fn handle_command(ui: MainWindow, cmd: UpdateGuiCommand, renderer_tx: &RendererM
             set_playback_progress(&bridge, progress_s, duration_s);
         }
         UpdateGuiCommand::SetBufferedRanges(ranges) => set_buffered_ranges(&bridge, ranges),
+        UpdateGuiCommand::SetChapterMarks(starts) => set_chapter_marks(&bridge, starts),
+        UpdateGuiCommand::SetSleepTimer { armed, remaining } => {
+            set_sleep_timer(&bridge, armed, remaining)
+        }
+        UpdateGuiCommand::SetDelays {
+            audio_ms,
+            subtitle_ms,
+        } => {
+            bridge.set_audio_delay_ms(audio_ms);
+            bridge.set_subtitle_delay_ms(subtitle_ms);
+        }
+        UpdateGuiCommand::SetPlaybackHistory(entries) => set_playback_history(&bridge, entries),
         UpdateGuiCommand::SetMediaTitle(title) => bridge.set_media_title(title.to_shared_string()),
         UpdateGuiCommand::SetArtistName(name) => bridge.set_artist_name(name.to_shared_string()),
         UpdateGuiCommand::ClearAudioCovers => clear_audio_covers(&bridge, renderer_tx),
@@ -459,6 +607,7 @@ fn handle_command(ui: MainWindow, cmd: UpdateGuiCommand, renderer_tx: &RendererM
             clear_audio_covers(&bridge, renderer_tx);
             set_playback_progress(&bridge, 0.0, 0.0);
             set_buffered_ranges(&bridge, Vec::new());
+            set_chapter_marks(&bridge, Vec::new());
         }
         UpdateGuiCommand::SetPlayerType(typ) => {
             bridge.set_player_variant(typ.into());
@@ -468,6 +617,7 @@ fn handle_command(ui: MainWindow, cmd: UpdateGuiCommand, renderer_tx: &RendererM
             videos,
             audios,
             subtitles,
+            secondary_subtitles,
         } => {
             macro_rules! wrap_or_default {
                 ($tracks:expr) => {
@@ -478,6 +628,7 @@ fn handle_command(ui: MainWindow, cmd: UpdateGuiCommand, renderer_tx: &RendererM
             bridge.set_video_tracks(wrap_or_default!(videos));
             bridge.set_audio_tracks(wrap_or_default!(audios));
             bridge.set_subtitle_tracks(wrap_or_default!(subtitles));
+            bridge.set_secondary_subtitle_tracks(wrap_or_default!(secondary_subtitles));
         }
         UpdateGuiCommand::SetTrackIds {
             video,
@@ -488,6 +639,9 @@ fn handle_command(ui: MainWindow, cmd: UpdateGuiCommand, renderer_tx: &RendererM
             bridge.set_current_audio_track(audio);
             bridge.set_current_subtitle_track(subtitle);
         }
+        UpdateGuiCommand::SetSecondarySubtitleTrack(id) => {
+            bridge.set_current_secondary_subtitle_track(id);
+        }
         UpdateGuiCommand::ClearVideoOverlays => {
             let _ = renderer_tx.send(RendererMessage::ClearVideoOverlays);
             ui.window().request_redraw();
@@ -532,6 +686,20 @@ fn handle_command(ui: MainWindow, cmd: UpdateGuiCommand, renderer_tx: &RendererM
             bridge.set_bug_report_visible(true);
         }
         UpdateGuiCommand::HideBugReport => bridge.set_bug_report_visible(false),
+        UpdateGuiCommand::ShowPairing {
+            sender_name,
+            pin,
+            qr,
+        } => {
+            bridge.set_pairing_sender_name(sender_name.unwrap_or_default().to_shared_string());
+            bridge.set_pairing_pin(pin.to_shared_string());
+            bridge.set_pairing_qr(match qr {
+                Some(qr) => slint::Image::from_rgb8(qr_pixbuf(&qr.0)),
+                None => slint::Image::default(),
+            });
+            bridge.set_pairing_visible(true);
+        }
+        UpdateGuiCommand::HidePairing => bridge.set_pairing_visible(false),
         UpdateGuiCommand::SetPlaybackState(state) => bridge.set_playback_state(state.into()),
         UpdateGuiCommand::ClearImageState => {
             bridge.set_image_preview(CompoundImage::default());
@@ -547,6 +715,9 @@ fn handle_command(ui: MainWindow, cmd: UpdateGuiCommand, renderer_tx: &RendererM
             bridge.set_source_backoff_remaining_ms(remaining_ms.min(i32::MAX as u64) as i32);
             bridge.set_source_backoff_total_ms(total_ms.min(i32::MAX as u64) as i32);
         }
+        UpdateGuiCommand::SetSourceReconnecting(reconnecting) => {
+            bridge.set_source_reconnecting(reconnecting)
+        }
         UpdateGuiCommand::SetPlaybackRate(rate) => bridge.set_playback_rate(rate),
         #[cfg(any(target_os = "macos", target_os = "windows"))]
         UpdateGuiCommand::SetUpdateState(state) => bridge.set_updater_state(state),
@@ -597,17 +768,21 @@ fn handle_command(ui: MainWindow, cmd: UpdateGuiCommand, renderer_tx: &RendererM
             let bridge = ui.global::<Bridge>();
             bridge.set_cfg_fcast_enabled(config.fcast.enabled);
             bridge.set_cfg_fcast_name(config.fcast.name.clone().unwrap_or_default().into());
+            bridge.set_cfg_fcast_require_pairing(config.fcast.require_pairing);
             bridge.set_cfg_raop_enabled(config.raop.enabled);
             bridge.set_cfg_raop_name(config.raop.name.clone().unwrap_or_default().into());
             bridge.set_cfg_chromecast_enabled(config.chromecast.enabled);
             bridge
                 .set_cfg_chromecast_name(config.chromecast.name.clone().unwrap_or_default().into());
+            bridge.set_cfg_dlna_enabled(config.dlna.enabled);
+            bridge.set_cfg_dlna_name(config.dlna.name.clone().unwrap_or_default().into());
             bridge.set_cfg_airplay_enabled(config.airplay.enabled);
             bridge.set_cfg_interface_show_window(config.interface.show_window);
             bridge.set_cfg_interface_tray(config.interface.tray);
             bridge.set_cfg_interface_start_fullscreen(config.interface.start_fullscreen);
             bridge.set_cfg_interface_fullscreen_player(config.interface.fullscreen_player);
             bridge.set_cfg_interface_headless(config.interface.headless);
+            bridge.set_cfg_interface_mpris(config.interface.mpris);
             // Formatted the way the drawer's dropdown labels it, so the active
             // option is the highlighted one: unset means the default mode,
             // shown under its own name rather than as "Default".
@@ -628,6 +803,46 @@ fn handle_command(ui: MainWindow, cmd: UpdateGuiCommand, renderer_tx: &RendererM
                     .unwrap_or_else(|| "Default".to_owned())
                     .into(),
             );
+            bridge.set_cfg_playback_resume(
+                config
+                    .playback
+                    .resume
+                    .clone()
+                    .unwrap_or_else(|| "Default".to_owned())
+                    .into(),
+            );
+            bridge.set_cfg_playback_subtitle_delay_ms(delay_ms_text(
+                config.playback.subtitle_delay_ms,
+            ));
+            bridge.set_cfg_playback_audio_delay_ms(delay_ms_text(config.playback.audio_delay_ms));
+            bridge.set_cfg_subtitles_size(
+                config
+                    .subtitles
+                    .size
+                    .clone()
+                    .unwrap_or_else(|| "Default".to_owned())
+                    .into(),
+            );
+            bridge.set_cfg_subtitles_position(
+                config
+                    .subtitles
+                    .position
+                    .clone()
+                    .unwrap_or_else(|| "Default".to_owned())
+                    .into(),
+            );
+            bridge.set_cfg_subtitles_background(config.subtitles.background);
+            bridge.set_cfg_subtitles_outline(config.subtitles.outline);
+            bridge.set_cfg_subtitles_bold(config.subtitles.bold);
+            bridge.set_cfg_subtitles_font(config.subtitles.font.clone().unwrap_or_default().into());
+            bridge.set_cfg_subtitles_secondary_size(
+                config
+                    .subtitles
+                    .secondary_size
+                    .clone()
+                    .unwrap_or_else(|| "Default".to_owned())
+                    .into(),
+            );
             bridge.set_cfg_discovery_exclude_interfaces(
                 config
                     .discovery
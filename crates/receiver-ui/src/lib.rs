@@ -132,20 +132,35 @@ fn overlay_change_applies(has_frame: bool, engine: &fcast_video::cue::CueEngine)
     has_frame && engine.take_dirty()
 }
 
+/// The sink's cue engines: the subtitle track's and the secondary track's.
+fn cue_engines(sink: &video::FSink) -> [fcast_video::cue::CueEngine; 2] {
+    [sink.cue_engine(), sink.secondary_cues().engine().clone()]
+}
+
 impl<S> VideoTick<S> {
     /// Fold an overlay change into the cached frame, and say whether that
     /// leaves something to draw.
     ///
     /// The ordering that makes it correct is [`overlay_change_applies`].
-    fn fold_overlay_change(&mut self, engine: &fcast_video::cue::CueEngine) -> bool {
-        if !overlay_change_applies(self.cached_frame.is_some(), engine) {
+    fn fold_overlay_change(&mut self, engines: &[fcast_video::cue::CueEngine]) -> bool {
+        let has_frame = self.cached_frame.is_some();
+        // Every engine's notification is taken, not only the first raised one:
+        // the overlays below are read from all of them.
+        let mut changed = false;
+        for engine in engines {
+            changed |= overlay_change_applies(has_frame, engine);
+        }
+        if !changed {
             return false;
         }
         let frame = self
             .cached_frame
             .as_mut()
             .expect("`overlay_change_applies` answered true, so there is a frame");
-        frame.overlays = engine.current_overlays().into_iter().collect();
+        frame.overlays = engines
+            .iter()
+            .flat_map(|engine| engine.current_overlays())
+            .collect();
         self.force_render = true;
         true
     }
@@ -490,8 +505,7 @@ pub fn run<S: VideoSink + 'static>(
                     // The cue engine changed without a frame carrying it. A new frame already
                     // arrives with the engine's overlays on it, so this is for the frames that
                     // are NOT coming: everything visible while PAUSED.
-                    let engine = sink.cue_engine();
-                    if t.fold_overlay_change(&engine) {
+                    if t.fold_overlay_change(&cue_engines(sink)) {
                         debug!("paused-repaint: overlay change folded in a render pass");
                     }
 
@@ -562,6 +576,10 @@ pub fn run<S: VideoSink + 'static>(
                     }
                     if let Some(cost) = render_cost {
                         t.note_render_cost(cost);
+                        receiver_core::metrics::record_render(
+                            cost,
+                            t.render_latency.debug_snapshot().1,
+                        );
 
                         if bridge.get_show_inspector() {
                             use slint::ToSharedString;
@@ -659,10 +677,10 @@ pub fn run<S: VideoSink + 'static>(
                     // is what left a paused seek's cue unpainted until the viewer
                     // resumed -- self-clocked parks winit's redraw loop, so nothing
                     // else would come back to ask.
-                    let Some(engine) = t.sink_elem.as_ref().map(|sink| sink.cue_engine()) else {
+                    let Some(engines) = t.sink_elem.as_ref().map(cue_engines) else {
                         return;
                     };
-                    if !t.fold_overlay_change(&engine) {
+                    if !t.fold_overlay_change(&engines) {
                         return;
                     }
                     let frame = t
@@ -845,10 +863,11 @@ pub fn run<S: VideoSink + 'static>(
         async move {
             gstreamer::init_and_load_plugins();
 
-            let (video_sink_elem, cue_engine) = if let Some(ui_weak) = ui_weak {
+            let (video_sink_elem, cue_engine, secondary_cues) = if let Some(ui_weak) = ui_weak {
                 let sink = video::FSink::new();
                 // Cloned out here because the player only ever sees the bare `gst::Element`.
                 let cue_engine = sink.cue_engine();
+                let secondary_cues = sink.secondary_cues();
                 {
                     let ui_weak = ui_weak.clone();
                     sink.connect("frame-available", false, move |_| {
@@ -871,15 +890,20 @@ pub fn run<S: VideoSink + 'static>(
 
                 let video_sink_elem = sink.clone();
                 *sink_mutex.lock() = Some(sink);
-                (Some(video_sink_elem), Some(cue_engine))
+                (
+                    Some(video_sink_elem),
+                    Some(cue_engine),
+                    Some(secondary_cues),
+                )
             } else {
-                (None, None)
+                (None, None, None)
             };
 
             let app = application::Application::new(
                 gui,
                 video_sink_elem.map(|e| e.upcast()),
                 cue_engine,
+                secondary_cues,
                 msg_tx,
                 #[cfg(target_os = "android")]
                 android_app,
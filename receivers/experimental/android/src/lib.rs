@@ -75,7 +75,10 @@ pub extern "C" fn Java_org_fcast_rsreceiver_android_MainActivity_getDeviceNameRa
     let event_tx = EVENT_TX.lock();
     if let Some(event_tx) = event_tx.as_ref() {
         let _ = event_tx.send(rcore::Message::Raop(rcore::Raop::ConfigAvailable(
-            rcore::Configuration { hw_addr: hash },
+            rcore::Configuration {
+                hw_addr: hash,
+                password: None,
+            },
         )));
     }
 
@@ -90,7 +93,7 @@ pub extern "C" fn Java_org_fcast_rsreceiver_android_MainActivity_getRaopTxtAttri
     attrs: jni::objects::JObject,
 ) {
     let attrs = env.get_map(&attrs).unwrap();
-    for (k, v) in rcore::txt_properties() {
+    for (k, v) in rcore::txt_properties(false) {
         let k = env.new_string(k).unwrap();
         let v = env.new_string(v).unwrap();
         attrs.put(&mut env, &k, &v).unwrap();
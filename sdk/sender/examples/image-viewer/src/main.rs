@@ -12,9 +12,10 @@ use fcast_sender_sdk::{
     DeviceDiscovererEventHandler,
     context::CastContext,
     device::{
-        CastingDevice, CompanionSource, CompanionSourceDescriptor, DeviceConnectionState,
-        DeviceEventHandler, DeviceInfo, LoadRequest, MediaTrack, MediaTrackType, PlaybackState,
-        QueueItem, QueuePosition, QueueState, ReceiverError, Source, TrackList,
+        CastingDevice, Chapter, CompanionSource, CompanionSourceDescriptor, DeviceConnectionState,
+        DeviceEventHandler, DeviceInfo, HistoryEntry, LoadRequest, MediaDelay, MediaTrack,
+        MediaTrackType, PlaybackState, QueueItem, QueuePosition, QueueState, ReceiverError,
+        SleepTimer, Source, SubtitleStyle, TrackList,
     },
 };
 use slint::{ToSharedString, VecModel};
@@ -103,6 +104,18 @@ impl DeviceEventHandler for DevEventHandler {
     fn queue_changed(&self, _queue: QueueState) {}
 
     fn command_error(&self, _error: ReceiverError) {}
+
+    fn paired(&self, _token: String) {}
+
+    fn chapters_available(&self, _chapters: Vec<Chapter>) {}
+
+    fn sleep_timer_changed(&self, _timer: SleepTimer) {}
+
+    fn playback_history(&self, _entries: Vec<HistoryEntry>) {}
+
+    fn subtitle_style_changed(&self, _style: SubtitleStyle) {}
+
+    fn delay_changed(&self, _delay: MediaDelay) {}
 }
 
 struct ImageEntry {
@@ -304,7 +317,7 @@ fn run(ui_weak: slint::Weak<MainWindow>, msg_tx: Sender<Message>, msg_rx: Receiv
                 fn load_window(device: &dyn CastingDevice, images: &[ImageEntry], id: usize) {
                     let (lo, hi) = window_range(id, images.len());
                     let items = images[lo..=hi].iter().map(create_item).collect();
-                    let start_index = Some((id - lo) as u8);
+                    let start_index = Some((id - lo) as u32);
                     if let Err(e) = device.load(LoadRequest::Queue { items, start_index }, None) {
                         log::warn!("Failed to load queue: {e}");
                     }
@@ -323,7 +336,7 @@ fn run(ui_weak: slint::Weak<MainWindow>, msg_tx: Sender<Message>, msg_rx: Receiv
                         // move, so select it there first. Selecting before removing also keeps us
                         // from ever removing the currently playing item, which the receiver
                         // refuses.
-                        let select_idx = (id - o_lo) as u8;
+                        let select_idx = (id - o_lo) as u32;
                         if let Err(e) = device.queue_select(QueuePosition::Index(select_idx)) {
                             log::warn!("Failed to select queue item: {e}");
                         }
@@ -353,10 +353,12 @@ impl InnerDevice {
                         content_type,
                         duration: None,
                         metadata: meta_to_gcast_meta(metadata),
+                        tracks: None,
                     },
                     request_id,
                     auto_play: None,
                     playback_rate: speed,
+                    active_track_ids: None,
                 })
                 .await?;
                 if let Some(volume) = volume {
@@ -367,24 +369,26 @@ impl InnerDevice {
                 let queue_items = items
                     .into_iter()
                     .map(|item| QueueItem {
-                        autoplay: true,
+                        item_id: None,
+                        autoplay: Some(true),
                         media: MediaInformation {
                             content_id: item.content_location,
                             stream_type: StreamType::None,
                             content_type: item.content_type,
                             duration: None,
                             metadata: None,
+                            tracks: None,
                         },
-                        playback_duration: i32::MAX,
-                        start_time: 0.0,
+                        playback_duration: None,
+                        start_time: Some(0.0),
                     })
                     .collect::<Vec<QueueItem>>();
                 let request_id = self.request_id.inc();
                 self.send_media_channel_message(namespaces::Media::QueueLoad {
                     request_id,
                     items: queue_items,
-                    repeat_mode: QueueRepeatMode::All,
-                    start_index: 0,
+                    repeat_mode: Some(QueueRepeatMode::All),
+                    start_index: Some(0),
                     queue_type: Some("PLAYLIST".to_string()),
                 })
                 .await?;
@@ -428,9 +432,12 @@ impl InnerDevice {
             Command::JumpPlaylist(jump) => {
                 let request_id = self.request_id.inc();
                 self.send_media_channel_message(namespaces::Media::QueueUpdate {
-                    media_session_id: self.media_session_id.to_string(),
+                    media_session_id: json::Value::String(self.media_session_id.to_string()),
                     request_id,
+                    current_item_id: None,
                     jump: Some(jump),
+                    repeat_mode: None,
+                    shuffle: None,
                 })
                 .await?;
             }
@@ -884,6 +891,26 @@ impl CastingDevice for ChromecastDevice {
         self.send_command(Command::Seek(time_seconds))
     }
 
+    fn seek_relative(&self, _offset_seconds: f64) -> Result<(), CastingDeviceError> {
+        Err(CastingDeviceError::UnsupportedFeature)
+    }
+
+    fn step_frame(&self, _forward: bool) -> Result<(), CastingDeviceError> {
+        Err(CastingDeviceError::UnsupportedFeature)
+    }
+
+    fn next_chapter(&self) -> Result<(), CastingDeviceError> {
+        Err(CastingDeviceError::UnsupportedFeature)
+    }
+
+    fn previous_chapter(&self) -> Result<(), CastingDeviceError> {
+        Err(CastingDeviceError::UnsupportedFeature)
+    }
+
+    fn seek_to_chapter(&self, _index: u32) -> Result<(), CastingDeviceError> {
+        Err(CastingDeviceError::UnsupportedFeature)
+    }
+
     fn stop_playback(&self) -> Result<(), CastingDeviceError> {
         self.send_command(Command::Stop)
     }
@@ -1075,6 +1102,14 @@ impl CastingDevice for ChromecastDevice {
         Err(CastingDeviceError::UnsupportedFeature)
     }
 
+    fn queue_move(
+        &self,
+        _from: QueuePosition,
+        _to: QueuePosition,
+    ) -> Result<(), CastingDeviceError> {
+        Err(CastingDeviceError::UnsupportedFeature)
+    }
+
     fn queue_add(
         &self,
         _item: crate::device::QueueItem,
@@ -1087,6 +1122,33 @@ impl CastingDevice for ChromecastDevice {
         Err(CastingDeviceError::UnsupportedFeature)
     }
 
+    fn set_queue_mode(
+        &self,
+        _repeat: crate::device::RepeatMode,
+        _shuffle: bool,
+    ) -> Result<(), CastingDeviceError> {
+        Err(CastingDeviceError::UnsupportedFeature)
+    }
+
+    fn set_sleep_timer(&self, _timer: crate::device::SleepTimer) -> Result<(), CastingDeviceError> {
+        Err(CastingDeviceError::UnsupportedFeature)
+    }
+
+    fn request_playback_history(&self, _limit: u32) -> Result<(), CastingDeviceError> {
+        Err(CastingDeviceError::UnsupportedFeature)
+    }
+
+    fn set_subtitle_style(
+        &self,
+        _style: crate::device::SubtitleStyle,
+    ) -> Result<(), CastingDeviceError> {
+        Err(CastingDeviceError::UnsupportedFeature)
+    }
+
+    fn set_delay(&self, _delay: crate::device::MediaDelay) -> Result<(), CastingDeviceError> {
+        Err(CastingDeviceError::UnsupportedFeature)
+    }
+
     fn load_queue(&self, _queue: crate::device::Queue) -> Result<(), CastingDeviceError> {
         Err(CastingDeviceError::UnsupportedFeature)
     }
@@ -1097,6 +1159,12 @@ impl CastingDevice for ChromecastDevice {
         ))
     }
 
+    fn pair(&self, _pin: String) -> Result<(), CastingDeviceError> {
+        Err(CastingDeviceError::UnsupportedFeature)
+    }
+
+    fn set_pairing_token(&self, _token: Option<String>) {}
+
     fn queue_insert(
         &self,
         _item: crate::device::MediaItem,
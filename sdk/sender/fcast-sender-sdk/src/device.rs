@@ -263,6 +263,11 @@ pub enum MediaTrackType {
     Video,
     Audio,
     Subtitle,
+    /// A second subtitle track shown above the first, for
+    /// [`CastingDevice::change_track`] only: its ids are those of
+    /// [`MediaTrackType::Subtitle`] tracks. See
+    /// [`DeviceFeature::SecondarySubtitle`].
+    SecondarySubtitle,
 }
 
 #[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
@@ -274,6 +279,39 @@ pub struct MediaTrack {
     pub typ: MediaTrackType,
 }
 
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Chapter {
+    /// Start of the chapter in seconds.
+    pub start: f64,
+    /// Length of the chapter in seconds, if known.
+    pub length: Option<f64>,
+    pub title: String,
+    /// Label for a "skip" button, for chapters like intros or recaps.
+    pub skip_label: Option<String>,
+}
+
+/// An item the receiver played, from [`DeviceEventHandler::playback_history`].
+///
+/// Cast it again by loading `url` with `container`. Request headers and the
+/// URL's query are not recorded, so items that needed them will not play.
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct HistoryEntry {
+    pub url: String,
+    pub container: String,
+    pub title: Option<String>,
+    pub thumbnail_url: Option<String>,
+    /// Seconds of the item that were played.
+    pub watched: f64,
+    /// Duration in seconds, `None` for live streams.
+    pub duration: Option<f64>,
+    /// When playback started, in seconds since the Unix epoch.
+    pub played_at: u64,
+    /// Display name of the sender that cast the item, if it gave one.
+    pub sender_name: Option<String>,
+}
+
 #[allow(unused_variables)]
 #[cfg_attr(feature = "uniffi", uniffi::export(with_foreign))]
 pub trait DeviceEventHandler: Send + Sync {
@@ -318,6 +356,33 @@ pub trait DeviceEventHandler: Send + Sync {
     /// mutation that was out of range, targeted the playing item, or hit
     /// the queue size cap). FCast v4 only.
     fn command_error(&self, error: ReceiverError);
+
+    /// The receiver accepted the PIN passed to [`CastingDevice::pair`] and
+    /// issued `token`. Store it and hand it to
+    /// [`CastingDevice::set_pairing_token`] before the next connect so the
+    /// receiver skips the PIN. FCast v4 only.
+    fn paired(&self, token: String);
+    /// The chapters of the media playing on the receiver, in start order. An
+    /// empty list means the media has none. FCast v4 only.
+    fn chapters_available(&self, chapters: Vec<Chapter>);
+    /// The receiver's sleep timer was set, changed, or cancelled, by this or
+    /// another sender or on the receiver itself. Also fires on connect and
+    /// with [`SleepTimer::Off`] once the timer has stopped playback. A
+    /// deadline's remaining time holds as of delivery, count it down locally.
+    /// FCast v4 only.
+    fn sleep_timer_changed(&self, timer: SleepTimer);
+    /// The receiver's answer to [`CastingDevice::request_playback_history`],
+    /// most recently played first. FCast v4 only.
+    fn playback_history(&self, entries: Vec<HistoryEntry>);
+    /// The receiver's subtitle style changed, by this or another sender or
+    /// in the receiver's settings. Also fires on connect. Every field is set.
+    /// FCast v4 only.
+    fn subtitle_style_changed(&self, style: SubtitleStyle);
+    /// The current item's subtitle or audio delay changed, by this or another
+    /// sender or on the receiver. Also fires on connect and when a new item
+    /// starts over from the receiver's defaults. Every field is set. FCast v4
+    /// only.
+    fn delay_changed(&self, delay: MediaDelay);
 }
 
 #[cfg_attr(feature = "uniffi", derive(uniffi::Object))]
@@ -387,6 +452,17 @@ pub enum DeviceFeature {
     ChangeTrack,
     Queue,
     SetProgressUpdateInterval,
+    SeekRelative,
+    FrameStep,
+    Chapters,
+    SleepTimer,
+    PlaybackHistory,
+    SubtitleStyle,
+    MediaDelay,
+    /// Two subtitle tracks at once, through [`MediaTrackType::SecondarySubtitle`].
+    /// The FCast receiver only shows subtitles added from a separate file
+    /// there, and answers other tracks with [`ReceiverError::UnsupportedFormat`].
+    SecondarySubtitle,
 }
 
 #[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
@@ -498,7 +574,7 @@ impl QueueItem {
 pub enum QueuePosition {
     Front,
     Back,
-    Index(u8),
+    Index(u32),
 }
 
 /// Where a [`MediaItem`] is fetched from.
@@ -537,6 +613,10 @@ pub struct MediaItem {
     pub request_headers: Option<HashMap<String, String>>,
     pub title: Option<String>,
     pub thumbnail_url: Option<String>,
+    /// Ask the receiver to start at the position it remembers from an earlier,
+    /// unfinished play of this source instead of at `start_time`. FCast v4
+    /// only.
+    pub resume: bool,
 }
 
 /// One entry in a [`Queue`]: a media item plus optional playback duration.
@@ -554,7 +634,10 @@ pub struct QueueEntry {
 #[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
 #[derive(Debug, Clone, PartialEq)]
 pub struct Queue {
-    /// The items to enqueue (the receiver caps a queue at 256 items).
+    /// The items to enqueue. Receivers that predate wide queue indexes cap a
+    /// queue at 256 items and only get a 256 item window around
+    /// `start_index`; newer ones advertise their own cap, and long queues
+    /// are sent a page at a time.
     pub items: Vec<QueueEntry>,
     /// Zero-based index of the item to start playing. Defaults to the first.
     /// Values past the end of `items` are clamped to the last item.
@@ -564,10 +647,61 @@ pub struct Queue {
     pub autoplay: bool,
 }
 
+/// How the receiver's autoplay repeats a queue. FCast v4 only.
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RepeatMode {
+    /// Stop after the last item.
+    #[default]
+    Off,
+    /// Restart the current item when it finishes.
+    One,
+    /// Wrap from the last item back to the first.
+    All,
+}
+
+/// When the receiver stops playback on its own. FCast v4 only.
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum SleepTimer {
+    #[default]
+    Off,
+    /// Stop when the current item finishes instead of autoplaying the next
+    /// one. The volume fades out over the item's last `fade_secs`.
+    EndOfItem { fade_secs: f64 },
+    /// Stop after `remaining_secs`, fading the volume out over the last
+    /// `fade_secs`.
+    Deadline { remaining_secs: f64, fade_secs: f64 },
+}
+
+/// How the receiver draws subtitles. FCast v4 only.
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct SubtitleStyle {
+    /// Text size as a factor of the receiver's normal size, 0.5 to 3.0.
+    pub size: Option<f32>,
+    /// Distance of the text from the bottom, as a fraction of the picture
+    /// height, 0.0 to 0.5.
+    pub position: Option<f32>,
+    /// Whether a tinted box is drawn behind the text.
+    pub background: Option<bool>,
+}
+
+/// How far the current item's subtitles and audio are shifted against its
+/// video, in seconds. Positive is later, and the receiver allows up to ten
+/// seconds either way. FCast v4 only.
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct MediaDelay {
+    pub subtitle_secs: Option<f64>,
+    pub audio_secs: Option<f64>,
+}
+
 /// The SDK's live mirror of the receiver's queue.
 ///
 /// Delivered to [`DeviceEventHandler::queue_changed`] whenever the queue
-/// changes (the initial load, an insertion, a removal, or a selection),
+/// changes (the initial load, an insertion, a removal, a move, a selection,
+/// or a repeat/shuffle change),
 /// regardless of whether this sender or another sender caused the change.  When
 /// the queue ends (playback stops or a single-item load replaces it), one final
 /// empty snapshot with no items is delivered.
@@ -581,6 +715,12 @@ pub struct QueueState {
     /// Zero-based index of the currently playing item, if any.
     pub current_index: Option<u32>,
     pub autoplay: bool,
+    /// The receiver's repeat mode. It belongs to the receiver, not the queue,
+    /// so it carries over to the next queue.
+    pub repeat: RepeatMode,
+    /// Whether autoplay walks the queue in a random order. Carries over like
+    /// `repeat`.
+    pub shuffle: bool,
 }
 
 /// Where an external subtitle's content comes from.
@@ -626,6 +766,7 @@ pub struct TrackList {
     pub selected_video: Option<u32>,
     pub selected_audio: Option<u32>,
     pub selected_subtitle: Option<u32>,
+    pub selected_secondary_subtitle: Option<u32>,
 }
 
 /// An error the receiver reported in response to a command this sender issued
@@ -651,6 +792,8 @@ pub enum ReceiverError {
     InvalidPayloadType,
     /// An opaque internal receiver error.
     Internal,
+    /// The receiver requires pairing and this sender has not paired yet.
+    Unauthorized,
     /// An error kind not known to this version of the SDK.
     Unknown,
 }
@@ -706,7 +849,7 @@ pub enum LoadRequest {
     },
     Queue {
         items: Vec<QueueItem>,
-        start_index: Option<u8>,
+        start_index: Option<u32>,
     },
 }
 
@@ -726,6 +869,23 @@ pub trait CastingDevice: Send + Sync {
     fn name(&self) -> String;
     fn set_name(&self, name: String);
     fn seek(&self, time_seconds: f64) -> Result<(), CastingDeviceError>;
+    /// Seek by `offset_seconds` from the receiver's current position, negative
+    /// to go back. FCast v4 only (see [`DeviceFeature::SeekRelative`]).
+    fn seek_relative(&self, offset_seconds: f64) -> Result<(), CastingDeviceError>;
+    /// Step a single frame forward or backward. Only valid while paused.
+    /// FCast v4 only (see [`DeviceFeature::FrameStep`]).
+    fn step_frame(&self, forward: bool) -> Result<(), CastingDeviceError>;
+    /// Seek to the start of the next chapter. FCast v4 only (see
+    /// [`DeviceFeature::Chapters`]).
+    fn next_chapter(&self) -> Result<(), CastingDeviceError>;
+    /// Seek to the start of the current chapter, or of the previous one when
+    /// playback is only just into the current. FCast v4 only (see
+    /// [`DeviceFeature::Chapters`]).
+    fn previous_chapter(&self) -> Result<(), CastingDeviceError>;
+    /// Seek to the start of the chapter at `index` in the list from
+    /// [`DeviceEventHandler::chapters_available`]. FCast v4 only (see
+    /// [`DeviceFeature::Chapters`]).
+    fn seek_to_chapter(&self, index: u32) -> Result<(), CastingDeviceError>;
     /// Stop the media that is playing on the receiver.
     ///
     /// This will usually result in the receiver closing the media viewer and
@@ -782,9 +942,34 @@ pub trait CastingDevice: Send + Sync {
         track_type: MediaTrackType,
     ) -> Result<(), CastingDeviceError>;
     fn queue_remove(&self, position: QueuePosition) -> Result<(), CastingDeviceError>;
+    /// Move the item at `from` so it ends up at `to`. The current item keeps
+    /// playing.
+    fn queue_move(&self, from: QueuePosition, to: QueuePosition) -> Result<(), CastingDeviceError>;
     fn queue_add(&self, item: QueueItem, position: QueuePosition)
         -> Result<(), CastingDeviceError>;
     fn queue_select(&self, position: QueuePosition) -> Result<(), CastingDeviceError>;
+    /// Set how the receiver's autoplay walks the queue. The receiver confirms
+    /// the change through [`DeviceEventHandler::queue_changed`]. Repeat and
+    /// shuffle only take effect on a queue loaded with `autoplay`.
+    fn set_queue_mode(&self, repeat: RepeatMode, shuffle: bool) -> Result<(), CastingDeviceError>;
+    /// Arm, replace, or cancel ([`SleepTimer::Off`]) the receiver's sleep
+    /// timer. The receiver confirms through
+    /// [`DeviceEventHandler::sleep_timer_changed`].
+    fn set_sleep_timer(&self, timer: SleepTimer) -> Result<(), CastingDeviceError>;
+    /// Ask the receiver for what it played recently, at most `limit` items or
+    /// all of them when zero. The answer arrives through
+    /// [`DeviceEventHandler::playback_history`].
+    fn request_playback_history(&self, limit: u32) -> Result<(), CastingDeviceError>;
+    /// Change how the receiver draws subtitles. Fields left `None` keep their
+    /// value. The receiver clamps out-of-range values, keeps the style for
+    /// later sessions, and confirms through
+    /// [`DeviceEventHandler::subtitle_style_changed`].
+    fn set_subtitle_style(&self, style: SubtitleStyle) -> Result<(), CastingDeviceError>;
+    /// Shift the current item's subtitles or audio against its video, to fix
+    /// out-of-sync subtitles or a lagging speaker. Fields left `None` keep
+    /// their value. The next item starts over from the receiver's defaults.
+    /// The receiver confirms through [`DeviceEventHandler::delay_changed`].
+    fn set_delay(&self, delay: MediaDelay) -> Result<(), CastingDeviceError>;
 
     /// Load a queue of media items and begin playback.
     ///
@@ -815,6 +1000,16 @@ pub trait CastingDevice: Send + Sync {
     /// floored to 100 ms. Supported on FCast v4 and Chromecast (see
     /// [`DeviceFeature::SetProgressUpdateInterval`]).
     fn set_progress_update_interval(&self, interval_millis: u64) -> Result<(), CastingDeviceError>;
+
+    /// Answer a receiver that requires pairing with the PIN it shows on
+    /// screen. The receiver rejects commands with
+    /// [`ReceiverError::Unauthorized`] until this succeeds, which is reported
+    /// through [`DeviceEventHandler::paired`]. FCast v4 only.
+    fn pair(&self, pin: String) -> Result<(), CastingDeviceError>;
+
+    /// Set the token from an earlier [`DeviceEventHandler::paired`] to present
+    /// on the next connect. Has no effect on protocols without pairing.
+    fn set_pairing_token(&self, token: Option<String>);
 }
 
 #[cfg(test)]
@@ -2,7 +2,7 @@ use std::{
     collections::HashMap,
     net::SocketAddr,
     sync::{
-        atomic::{AtomicBool, AtomicU64, Ordering},
+        atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering},
         Arc, Mutex,
     },
     time::Duration,
@@ -33,9 +33,10 @@ use crate::{
     device::{
         ApplicationInfo, CastingDevice, CastingDeviceError, CompanionSource,
         CompanionSourceDescriptor, DeviceConnectionState, DeviceEventHandler, DeviceFeature,
-        DeviceInfo, LoadRequest, MediaItem, MediaLocator, MediaTrack, MediaTrackType, Metadata,
-        PlaybackState, PlaylistItem, ProtocolType, Queue, QueueEntry, QueueItem, QueuePosition,
-        QueueState, ReceiverError, Source, SubtitleContent, SubtitleSource, TrackList,
+        DeviceInfo, HistoryEntry, LoadRequest, MediaDelay, MediaItem, MediaLocator, MediaTrack,
+        MediaTrackType, Metadata, PlaybackState, PlaylistItem, ProtocolType, Queue, QueueEntry,
+        QueueItem, QueuePosition, QueueState, ReceiverError, RepeatMode, SleepTimer, Source,
+        SubtitleContent, SubtitleSource, SubtitleStyle, TrackList,
     },
     utils, IpAddr,
 };
@@ -81,6 +82,11 @@ enum Command {
     },
     SetProgressUpdateInterval(u64),
     SeekVideo(f64),
+    SeekRelative(f64),
+    StepFrame {
+        forward: bool,
+    },
+    SeekToChapter(v4::ChapterTarget),
     StopVideo,
     PauseVideo,
     ResumeVideo,
@@ -107,6 +113,10 @@ enum Command {
     QueueRemove {
         position: QueuePosition,
     },
+    QueueMove {
+        from: QueuePosition,
+        to: QueuePosition,
+    },
     QueueInsert {
         item: MediaItem,
         playback_duration: Option<f64>,
@@ -115,6 +125,19 @@ enum Command {
     QueueSelect {
         position: QueuePosition,
     },
+    SetQueueMode {
+        repeat: RepeatMode,
+        shuffle: bool,
+    },
+    SetSleepTimer(SleepTimer),
+    RequestPlaybackHistory {
+        limit: u32,
+    },
+    SetSubtitleStyle(SubtitleStyle),
+    SetDelay(MediaDelay),
+    Pair {
+        pin: String,
+    },
 }
 
 struct State {
@@ -125,6 +148,7 @@ struct State {
     name: String,
     port: u16,
     txt_records: HashMap<String, String>,
+    pairing_token: Option<String>,
 }
 
 impl State {
@@ -137,6 +161,7 @@ impl State {
             name: device_info.name,
             port: device_info.port,
             txt_records: device_info.txt_records,
+            pairing_token: None,
         }
     }
 }
@@ -146,6 +171,8 @@ pub struct FCastDevice {
     state: Mutex<State>,
     session_version: FCastVersion,
     supports_whep: Arc<AtomicBool>,
+    /// The queue cap the receiver advertised, or the legacy 256 items.
+    max_queue_items: Arc<AtomicU32>,
 }
 
 impl FCastDevice {
@@ -154,6 +181,7 @@ impl FCastDevice {
             state: Mutex::new(State::new(device_info, rt_handle)),
             session_version: FCastVersion::new(),
             supports_whep: Arc::new(AtomicBool::new(false)),
+            max_queue_items: Arc::new(AtomicU32::new(v4::LEGACY_MAX_QUEUE_ITEMS)),
         }
     }
 }
@@ -251,7 +279,7 @@ enum V4Load {
     Single(Source),
     Queue {
         entries: Vec<QueueEntry>,
-        start_index: Option<u8>,
+        start_index: Option<u32>,
         autoplay: bool,
     },
 }
@@ -295,21 +323,38 @@ enum Action {
     Introduction {
         supports_whep: bool,
         capabilities: Option<crate::device::ReceiverCapabilities>,
+        max_queue_items: u32,
     },
     /// The receiver assigned the companion provider ID.
     CompanionReady,
     LoadedV4(V4Load),
+    /// A relayed `QueueInsert`, or a `QueueInsertBatch` with its entries in
+    /// order.
     QueueInserted {
-        entry: QueueEntry,
+        entries: Vec<QueueEntry>,
         position: QueuePosition,
     },
     QueueRemoved {
         position: QueuePosition,
     },
+    QueueMoved {
+        from: QueuePosition,
+        to: QueuePosition,
+    },
     QueueItemSelected {
         position: QueuePosition,
     },
+    QueueModeChanged {
+        repeat: RepeatMode,
+        shuffle: bool,
+    },
     ReceiverError(ReceiverError),
+    Paired(String),
+    ChaptersAvailable(Vec<crate::device::Chapter>),
+    SleepTimerChanged(SleepTimer),
+    PlaybackHistory(Vec<HistoryEntry>),
+    SubtitleStyleChanged(SubtitleStyle),
+    DelayChanged(MediaDelay),
 }
 
 /// Convert the v4 `ReceiverCapabilities` flatbuffer into the public
@@ -394,13 +439,17 @@ macro_rules! json_from_body {
 
 /// Read a `QueuePosition` off any flatbuffer message that carries one
 /// (`QueueInsert`, `QueueRemove`, `QueueItemSelected`). They share the same
-/// `position_type()` / `position_as_index()` accessors.
+/// `position_type()` / `position_as_index()` accessors; `QueueMove` names its
+/// two positions explicitly.
 macro_rules! read_queue_position {
     ($msg:expr) => {
-        match $msg.position_type() {
+        read_queue_position!($msg, position_type, position_as_index)
+    };
+    ($msg:expr, $typ:ident, $as_index:ident) => {
+        match $msg.$typ() {
             v4::flat::QueuePosition::Index => $msg
-                .position_as_index()
-                .map(|i| QueuePosition::Index(i.index())),
+                .$as_index()
+                .map(|i| QueuePosition::Index(v4::read_queue_index(&i))),
             v4::flat::QueuePosition::Front => Some(QueuePosition::Front),
             v4::flat::QueuePosition::Back => Some(QueuePosition::Back),
             _ => None,
@@ -636,6 +685,9 @@ impl DeviceStateMachine {
                     v4::flat::MediaTrackType::Video => crate::device::MediaTrackType::Video,
                     v4::flat::MediaTrackType::Audio => crate::device::MediaTrackType::Audio,
                     v4::flat::MediaTrackType::Subtitle => crate::device::MediaTrackType::Subtitle,
+                    v4::flat::MediaTrackType::SecondarySubtitle => {
+                        crate::device::MediaTrackType::SecondarySubtitle
+                    }
                     _ => {
                         warn!(
                             "Got invalid track type in ChangeTrack message (type={:?})",
@@ -655,11 +707,44 @@ impl DeviceStateMachine {
                 warn!("Got error: {msg:?}");
                 Action::ReceiverError(receiver_error_from_flat(msg.kind()))
             }
+            v4::flat::Message::PairingResult => {
+                let msg = union!(packet.payload_as_pairing_result());
+                Action::Paired(msg.token().to_owned())
+            }
+            v4::flat::Message::ChaptersAvailable => {
+                let msg = union!(packet.payload_as_chapters_available());
+                let chapters = msg.chapters().map(v4::read_chapters).unwrap_or_default();
+                Action::ChaptersAvailable(
+                    chapters
+                        .into_iter()
+                        .map(|chapter| crate::device::Chapter {
+                            start: chapter.start,
+                            length: chapter.length,
+                            title: chapter.title,
+                            skip_label: chapter.skip_label,
+                        })
+                        .collect(),
+                )
+            }
             v4::flat::Message::QueueInsert => {
                 let msg = union!(packet.payload_as_queue_insert());
                 match read_queue_position!(msg) {
                     Some(position) => Action::QueueInserted {
-                        entry: queue_entry_from_flat(&msg.item()),
+                        entries: vec![queue_entry_from_flat(&msg.item())],
+                        position,
+                    },
+                    None => Action::None,
+                }
+            }
+            v4::flat::Message::QueueInsertBatch => {
+                let msg = union!(packet.payload_as_queue_insert_batch());
+                match read_queue_position!(msg) {
+                    Some(position) => Action::QueueInserted {
+                        entries: msg
+                            .items()
+                            .iter()
+                            .map(|qi| queue_entry_from_flat(&qi))
+                            .collect(),
                         position,
                     },
                     None => Action::None,
@@ -672,6 +757,16 @@ impl DeviceStateMachine {
                     None => Action::None,
                 }
             }
+            v4::flat::Message::QueueMove => {
+                let msg = union!(packet.payload_as_queue_move());
+                match (
+                    read_queue_position!(msg, from_type, from_as_index),
+                    read_queue_position!(msg, to_type, to_as_index),
+                ) {
+                    (Some(from), Some(to)) => Action::QueueMoved { from, to },
+                    _ => Action::None,
+                }
+            }
             v4::flat::Message::QueueItemSelected => {
                 let msg = union!(packet.payload_as_queue_item_selected());
                 match read_queue_position!(msg) {
@@ -679,6 +774,67 @@ impl DeviceStateMachine {
                     None => Action::None,
                 }
             }
+            v4::flat::Message::QueueModeChanged => {
+                let msg = union!(packet.payload_as_queue_mode_changed());
+                let repeat = match msg.repeat() {
+                    v4::flat::RepeatMode::One => RepeatMode::One,
+                    v4::flat::RepeatMode::All => RepeatMode::All,
+                    _ => RepeatMode::Off,
+                };
+                Action::QueueModeChanged {
+                    repeat,
+                    shuffle: msg.shuffle(),
+                }
+            }
+            v4::flat::Message::SleepTimerChanged => {
+                let msg = union!(packet.payload_as_sleep_timer_changed());
+                let secs = |time: Option<&v4::flat::Time>| {
+                    time.map_or(0.0, |t| Duration::from_micros(t.micros()).as_secs_f64())
+                };
+                let fade_secs = secs(msg.fade());
+                Action::SleepTimerChanged(match msg.mode() {
+                    v4::flat::SleepTimerMode::EndOfItem => SleepTimer::EndOfItem { fade_secs },
+                    v4::flat::SleepTimerMode::Deadline => SleepTimer::Deadline {
+                        remaining_secs: secs(msg.remaining()),
+                        fade_secs,
+                    },
+                    _ => SleepTimer::Off,
+                })
+            }
+            v4::flat::Message::PlaybackHistory => {
+                let msg = union!(packet.payload_as_playback_history());
+                Action::PlaybackHistory(
+                    v4::read_history(&msg)
+                        .into_iter()
+                        .map(|entry| HistoryEntry {
+                            url: entry.url,
+                            container: entry.container,
+                            title: entry.title,
+                            thumbnail_url: entry.thumbnail_url,
+                            watched: entry.watched,
+                            duration: entry.duration,
+                            played_at: entry.played_at,
+                            sender_name: entry.sender_name,
+                        })
+                        .collect(),
+                )
+            }
+            v4::flat::Message::SubtitleStyleChanged => {
+                let msg = union!(packet.payload_as_subtitle_style_changed());
+                Action::SubtitleStyleChanged(SubtitleStyle {
+                    size: msg.size(),
+                    position: msg.position(),
+                    background: msg.background(),
+                })
+            }
+            v4::flat::Message::DelayChanged => {
+                let msg = union!(packet.payload_as_delay_changed());
+                let secs = |micros: i64| micros as f64 / 1_000_000.0;
+                Action::DelayChanged(MediaDelay {
+                    subtitle_secs: msg.subtitle_micros().map(secs),
+                    audio_secs: msg.audio_micros().map(secs),
+                })
+            }
             v4::flat::Message::ReceiverIntroduction => {
                 let msg = union!(packet.payload_as_receiver_introduction());
                 debug!("Receiver introduction: {msg:?}");
@@ -694,6 +850,7 @@ impl DeviceStateMachine {
                 Action::Introduction {
                     supports_whep,
                     capabilities,
+                    max_queue_items: msg.max_queue_items().unwrap_or(v4::LEGACY_MAX_QUEUE_ITEMS),
                 }
             }
             v4::flat::Message::CompanionResourceRequest => {
@@ -723,7 +880,7 @@ impl DeviceStateMachine {
                             .collect();
                         V4Load::Queue {
                             entries,
-                            start_index: queue.start_index(),
+                            start_index: v4::read_queue_start_index(&queue),
                             autoplay: queue.autoplay(),
                         }
                     }
@@ -791,7 +948,8 @@ impl DeviceStateMachine {
 }
 
 /// The SDK's mirror of the receiver's queue, reconstructed from the `Load`,
-/// `QueueInsert`, `QueueRemove`, and `QueueItemSelected` broadcasts (and
+/// `QueueInsert`, `QueueRemove`, `QueueMove`, `QueueItemSelected`, and
+/// `QueueModeChanged` broadcasts (and
 /// updated optimistically for this sender's own mutations, since the receiver
 /// only relays those to *other* senders). The mutation methods replicate the
 /// receiver's accept/reject rules (`application.rs`) and report whether the
@@ -805,6 +963,9 @@ struct QueueMirror {
     items: Vec<QueueEntry>,
     current_index: Option<u32>,
     autoplay: bool,
+    /// Receiver-wide, so kept while no queue is active.
+    repeat: RepeatMode,
+    shuffle: bool,
 }
 
 impl QueueMirror {
@@ -813,11 +974,24 @@ impl QueueMirror {
             items: self.items.clone(),
             current_index: self.current_index,
             autoplay: self.autoplay,
+            repeat: self.repeat,
+            shuffle: self.shuffle,
         })
     }
 
     fn clear(&mut self) {
-        *self = Self::default();
+        *self = Self {
+            repeat: self.repeat,
+            shuffle: self.shuffle,
+            ..Self::default()
+        };
+    }
+
+    fn set_mode(&mut self, repeat: RepeatMode, shuffle: bool) -> bool {
+        let changed = self.repeat != repeat || self.shuffle != shuffle;
+        self.repeat = repeat;
+        self.shuffle = shuffle;
+        changed && self.active
     }
 
     fn set(&mut self, items: Vec<QueueEntry>, start_index: Option<u32>, autoplay: bool) {
@@ -836,13 +1010,20 @@ impl QueueMirror {
         }
     }
 
-    fn insert(&mut self, entry: QueueEntry, position: &QueuePosition) -> bool {
+    /// Insert `entries` in order at `position`. The receiver takes a batch
+    /// whole or not at all.
+    fn insert(
+        &mut self,
+        entries: Vec<QueueEntry>,
+        position: &QueuePosition,
+        max_items: usize,
+    ) -> bool {
         if !self.active {
             return false;
         }
-        // The receiver refuses inserts into an empty or full queue (capped at 256
-        // items) and positions past the end (`Back` appends).
-        if self.items.is_empty() || self.items.len() > u8::MAX as usize {
+        // The receiver refuses inserts into an empty queue, past its advertised cap
+        // and at positions past the end (`Back` appends).
+        if self.items.is_empty() || self.items.len() + entries.len() > max_items {
             return false;
         }
         let idx = match position {
@@ -853,11 +1034,12 @@ impl QueueMirror {
         if idx > self.items.len() {
             return false;
         }
-        self.items.insert(idx, entry);
+        let count = entries.len() as u32;
+        self.items.splice(idx..idx, entries);
         // Mirror the receiver's index bookkeeping (application.rs).
         if let Some(cur) = self.current_index.as_mut() {
             if idx as u32 <= *cur {
-                *cur += 1;
+                *cur += count;
             }
         }
         true
@@ -882,6 +1064,32 @@ impl QueueMirror {
         true
     }
 
+    fn move_item(&mut self, from: &QueuePosition, to: &QueuePosition) -> bool {
+        if !self.active {
+            return false;
+        }
+        let (from, to) = (self.resolve(from), self.resolve(to));
+        // The receiver refuses out-of-range positions and ignores a move onto itself.
+        if from >= self.items.len() || to >= self.items.len() || from == to {
+            return false;
+        }
+        let entry = self.items.remove(from);
+        self.items.insert(to, entry);
+        // The current item follows the move, everything between the two
+        // positions shifts by one (application.rs).
+        if let Some(cur) = self.current_index.as_mut() {
+            let (from, to) = (from as u32, to as u32);
+            if *cur == from {
+                *cur = to;
+            } else if from < *cur && *cur <= to {
+                *cur -= 1;
+            } else if to <= *cur && *cur < from {
+                *cur += 1;
+            }
+        }
+        true
+    }
+
     fn select(&mut self, position: &QueuePosition) -> bool {
         if !self.active {
             return false;
@@ -906,6 +1114,7 @@ struct TrackMirror {
     selected_video: Option<u32>,
     selected_audio: Option<u32>,
     selected_subtitle: Option<u32>,
+    selected_secondary_subtitle: Option<u32>,
 }
 
 impl TrackMirror {
@@ -915,6 +1124,7 @@ impl TrackMirror {
             selected_video: self.selected_video,
             selected_audio: self.selected_audio,
             selected_subtitle: self.selected_subtitle,
+            selected_secondary_subtitle: self.selected_secondary_subtitle,
         }
     }
 
@@ -923,10 +1133,22 @@ impl TrackMirror {
             MediaTrackType::Video => self.selected_video = id,
             MediaTrackType::Audio => self.selected_audio = id,
             MediaTrackType::Subtitle => self.selected_subtitle = id,
+            MediaTrackType::SecondarySubtitle => self.selected_secondary_subtitle = id,
         }
     }
 }
 
+/// Split `items` into pages of at most `page_len`.
+fn queue_pages<T>(mut items: Vec<T>, page_len: usize) -> Vec<Vec<T>> {
+    let mut pages = Vec::new();
+    while !items.is_empty() {
+        let rest = items.split_off(page_len.min(items.len()));
+        pages.push(items);
+        items = rest;
+    }
+    pages
+}
+
 fn to_v4_queue_position(position: QueuePosition) -> v4::QueuePosition {
     match position {
         QueuePosition::Front => v4::QueuePosition::Front,
@@ -952,6 +1174,7 @@ fn media_item_from_flat(item: &v4::flat::MediaItem<'_>) -> MediaItem {
         request_headers: None,
         title: item.title().map(|s| s.to_owned()),
         thumbnail_url: item.thumbnail_url().map(|s| s.to_owned()),
+        resume: item.resume(),
     }
 }
 
@@ -980,6 +1203,7 @@ fn receiver_error_from_flat(kind: v4::flat::ErrorKind) -> ReceiverError {
         K::QueueFull => ReceiverError::QueueFull,
         K::InvalidPayloadType => ReceiverError::InvalidPayloadType,
         K::Internal => ReceiverError::Internal,
+        K::Unauthorized => ReceiverError::Unauthorized,
         _ => ReceiverError::Unknown,
     }
 }
@@ -1025,6 +1249,7 @@ fn queue_item_to_entry(item: QueueItem) -> QueueEntry {
             request_headers,
             title,
             thumbnail_url,
+            resume: false,
         },
         playback_duration: None,
     }
@@ -1096,9 +1321,13 @@ struct InnerDevice {
     session_version: FCastVersion,
     app_info: Option<ApplicationInfo>,
     supports_whep: Arc<AtomicBool>,
+    max_queue_items: Arc<AtomicU32>,
     state_machine: DeviceStateMachine,
     companion_sources: HashMap<u32, WrappedCompanionSource>,
     receiver_fingerprint: Option<Vec<u8>>,
+    /// Presented in the `SenderIntroduction`; replaced when the receiver
+    /// issues a new one so a reconnect does not ask for the PIN again.
+    pairing_token: Option<String>,
     signaller: Option<Arc<dyn crate::device::FWRTCSignaller>>,
     queue_mirror: QueueMirror,
     track_mirror: TrackMirror,
@@ -1115,6 +1344,8 @@ struct InnerDevice {
     /// connect time lands in this window. Replayed in order once the ID
     /// arrives.
     pending_companion_cmds: Vec<Command>,
+    /// Id of the last `PlaybackHistoryRequest`.
+    history_request_id: u32,
 }
 
 impl InnerDevice {
@@ -1123,7 +1354,9 @@ impl InnerDevice {
         event_handler: Arc<dyn DeviceEventHandler>,
         session_version: FCastVersion,
         supports_whep: Arc<AtomicBool>,
+        max_queue_items: Arc<AtomicU32>,
         receiver_fingerprint: Option<Vec<u8>>,
+        pairing_token: Option<String>,
     ) -> Self {
         Self {
             event_handler,
@@ -1131,14 +1364,17 @@ impl InnerDevice {
             session_version,
             app_info,
             supports_whep,
+            max_queue_items,
             state_machine: DeviceStateMachine::new(receiver_fingerprint.is_some()),
             companion_sources: HashMap::new(),
             receiver_fingerprint,
+            pairing_token,
             signaller: None,
             queue_mirror: QueueMirror::default(),
             track_mirror: TrackMirror::default(),
             load_in_flight: false,
             pending_companion_cmds: Vec::new(),
+            history_request_id: 0,
         }
     }
 
@@ -1159,7 +1395,11 @@ impl InnerDevice {
     fn clear_queue_mirror(&mut self) {
         if self.queue_mirror.active {
             self.queue_mirror.clear();
-            self.event_handler.queue_changed(QueueState::default());
+            self.event_handler.queue_changed(QueueState {
+                repeat: self.queue_mirror.repeat,
+                shuffle: self.queue_mirror.shuffle,
+                ..QueueState::default()
+            });
         }
     }
 
@@ -1205,22 +1445,39 @@ impl InnerDevice {
             thumbnail_url: item.thumbnail_url,
             metadata: None,
             extra_metadata: None,
+            resume: item.resume,
         })
     }
 
+    fn max_queue_items(&self) -> usize {
+        self.max_queue_items.load(Ordering::Relaxed) as usize
+    }
+
     async fn load_rich_queue(&mut self, queue: Queue) -> anyhow::Result<()> {
         let autoplay = queue.autoplay;
-        // The wire index is a u8 and the receiver refuses out-of-range start indexes,
-        // so clamp to the last item instead of letting `as u8` wrap to an
-        // arbitrary in-range value. The mirror below reuses the clamped index,
-        // keeping both views on the same item.
-        let start_index = queue.start_index.map(|i| {
-            i.min(queue.items.len().saturating_sub(1) as u32)
-                .min(u8::MAX as u32) as u8
-        });
-        let mut wire_items = Vec::with_capacity(queue.items.len());
-        let mut entries = Vec::with_capacity(queue.items.len());
-        for entry in queue.items {
+        let max_items = self.max_queue_items();
+        let mut items = queue.items;
+        // The receiver refuses out-of-range start indexes, so clamp to the last
+        // item. The mirror below reuses the clamped index, keeping both views
+        // on the same item.
+        let mut start =
+            (queue.start_index.unwrap_or(0) as usize).min(items.len().saturating_sub(1));
+        if items.len() > max_items {
+            // Send as much of the queue as the receiver takes, from the start item on
+            // where possible.
+            let lo = start.min(items.len() - max_items);
+            warn!(
+                len = items.len(),
+                max_items, "Queue exceeds the receiver's cap, dropping the items outside it"
+            );
+            items.truncate(lo + max_items);
+            items.drain(..lo);
+            start -= lo;
+        }
+
+        let mut wire_items = Vec::with_capacity(items.len());
+        let mut entries = Vec::with_capacity(items.len());
+        for entry in items {
             // Resolve companion sources up front so the fd is consumed exactly once and
             // only the resulting URL is kept in the mirror.
             let resolved = self.resolve_media_item(entry.item)?;
@@ -1231,11 +1488,38 @@ impl InnerDevice {
                 playback_duration: entry.playback_duration,
             });
         }
-        let msg =
-            v4::MessageBuilder::new().load_queue(wire_items.into_iter(), start_index, autoplay);
+
+        // Receivers with wide queues take long ones a page per packet: the page
+        // holding the start item is loaded so playback starts right away, then
+        // the pages before it are inserted in front and the rest appended.
+        // Older receivers know no `QueueInsertBatch` and get a single load.
+        let page_len = if max_items > v4::LEGACY_MAX_QUEUE_ITEMS as usize {
+            v4::QUEUE_PAGE_ITEMS
+        } else {
+            usize::MAX
+        };
+        let mut pages = queue_pages(wire_items, page_len);
+        let start_page = start / page_len;
+        let later = pages.split_off((start_page + 1).min(pages.len()));
+        let current = pages.pop().unwrap_or_default();
+        let msg = v4::MessageBuilder::new().load_queue(
+            current.into_iter(),
+            Some((start - start_page * page_len) as u32),
+            autoplay,
+        );
         self.send_bytes(Opcode::Flatbuf, &msg).await?;
-        self.queue_mirror
-            .set(entries, start_index.map(|i| i as u32), autoplay);
+        for (page_idx, page) in pages.into_iter().enumerate() {
+            let position = v4::QueuePosition::Index((page_idx * page_len) as u32);
+            let msg = v4::MessageBuilder::new().queue_insert_batch(page.into_iter(), position);
+            self.send_bytes(Opcode::Flatbuf, &msg).await?;
+        }
+        for page in later {
+            let msg = v4::MessageBuilder::new()
+                .queue_insert_batch(page.into_iter(), v4::QueuePosition::Back);
+            self.send_bytes(Opcode::Flatbuf, &msg).await?;
+        }
+
+        self.queue_mirror.set(entries, Some(start as u32), autoplay);
         self.emit_queue_changed();
         Ok(())
     }
@@ -1542,6 +1826,7 @@ impl InnerDevice {
                     thumbnail_url: None,
                     metadata: None,
                     extra_metadata: None,
+                    resume: false,
                 };
 
                 let msg = v4::MessageBuilder::new().load_single(item);
@@ -1848,7 +2133,8 @@ impl InnerDevice {
                     }
                 };
 
-                let msg = v4::MessageBuilder::new().sender_introduction(&info);
+                let msg = v4::MessageBuilder::new()
+                    .sender_introduction(&info, self.pairing_token.as_deref());
                 self.send_bytes(Opcode::Flatbuf, &msg)
                     .await
                     .context("Failed to send InitialSenderMessage")?;
@@ -1960,8 +2246,11 @@ impl InnerDevice {
             Action::Introduction {
                 supports_whep,
                 capabilities,
+                max_queue_items,
             } => {
                 self.supports_whep.store(supports_whep, Ordering::Relaxed);
+                self.max_queue_items
+                    .store(max_queue_items, Ordering::Relaxed);
 
                 if !*has_emitted_connected_event {
                     self.emit_connected(*used_remote_addr, *local_addr, capabilities);
@@ -1998,13 +2287,13 @@ impl InnerDevice {
                             shared_state.source = Some(source);
                         }
                     }
-                    self.queue_mirror
-                        .set(entries, start_index.map(|i| i as u32), autoplay);
+                    self.queue_mirror.set(entries, start_index, autoplay);
                     self.emit_queue_changed();
                 }
             },
-            Action::QueueInserted { entry, position } => {
-                if self.queue_mirror.insert(entry, &position) {
+            Action::QueueInserted { entries, position } => {
+                let max_items = self.max_queue_items();
+                if self.queue_mirror.insert(entries, &position, max_items) {
                     self.emit_queue_changed();
                 }
             }
@@ -2013,14 +2302,33 @@ impl InnerDevice {
                     self.emit_queue_changed();
                 }
             }
+            Action::QueueMoved { from, to } => {
+                if self.queue_mirror.move_item(&from, &to) {
+                    self.emit_queue_changed();
+                }
+            }
             Action::QueueItemSelected { position } => {
                 if self.queue_mirror.select(&position) {
                     self.emit_queue_changed();
                 }
             }
+            Action::QueueModeChanged { repeat, shuffle } => {
+                if self.queue_mirror.set_mode(repeat, shuffle) {
+                    self.emit_queue_changed();
+                }
+            }
             Action::ReceiverError(error) => {
                 self.event_handler.command_error(error);
             }
+            Action::Paired(token) => {
+                self.pairing_token = Some(token.clone());
+                self.event_handler.paired(token);
+            }
+            Action::ChaptersAvailable(chapters) => self.event_handler.chapters_available(chapters),
+            Action::SleepTimerChanged(timer) => self.event_handler.sleep_timer_changed(timer),
+            Action::PlaybackHistory(entries) => self.event_handler.playback_history(entries),
+            Action::SubtitleStyleChanged(style) => self.event_handler.subtitle_style_changed(style),
+            Action::DelayChanged(delay) => self.event_handler.delay_changed(delay),
         }
 
         Ok(false)
@@ -2228,6 +2536,24 @@ impl InnerDevice {
                 self.send_progress_update_interval(interval_millis).await?
             }
             Command::SeekVideo(time) => self.seek(Duration::from_secs_f64(time)).await?,
+            Command::SeekRelative(offset_seconds) => {
+                let msg =
+                    v4::MessageBuilder::new().seek_relative((offset_seconds * 1_000_000.0) as i64);
+                self.send_bytes(Opcode::Flatbuf, &msg).await?;
+            }
+            Command::StepFrame { forward } => {
+                let direction = if forward {
+                    v4::flat::FrameStepDirection::Forward
+                } else {
+                    v4::flat::FrameStepDirection::Backward
+                };
+                let msg = v4::MessageBuilder::new().frame_step(direction);
+                self.send_bytes(Opcode::Flatbuf, &msg).await?;
+            }
+            Command::SeekToChapter(target) => {
+                let msg = v4::MessageBuilder::new().seek_to_chapter(target);
+                self.send_bytes(Opcode::Flatbuf, &msg).await?;
+            }
             Command::StopVideo => self.stop_playback().await?,
             Command::PauseVideo => match self.state_machine.variant {
                 StateVariant::V2 | StateVariant::V3 => self.send_empty(Opcode::Pause).await?,
@@ -2308,6 +2634,9 @@ impl InnerDevice {
                         crate::device::MediaTrackType::Subtitle => {
                             v4::flat::MediaTrackType::Subtitle
                         }
+                        crate::device::MediaTrackType::SecondarySubtitle => {
+                            v4::flat::MediaTrackType::SecondarySubtitle
+                        }
                     },
                 );
                 self.send_bytes(Opcode::Flatbuf, &msg).await?;
@@ -2346,6 +2675,14 @@ impl InnerDevice {
                     self.emit_queue_changed();
                 }
             }
+            Command::QueueMove { from, to } => {
+                let msg = v4::MessageBuilder::new()
+                    .queue_move(to_v4_queue_position(from), to_v4_queue_position(to));
+                self.send_bytes(Opcode::Flatbuf, &msg).await?;
+                if self.queue_mirror.move_item(&from, &to) {
+                    self.emit_queue_changed();
+                }
+            }
             Command::QueueInsert {
                 item,
                 playback_duration,
@@ -2361,13 +2698,12 @@ impl InnerDevice {
                     to_v4_queue_position(position),
                 );
                 self.send_bytes(Opcode::Flatbuf, &msg).await?;
-                if self.queue_mirror.insert(
-                    QueueEntry {
-                        item: resolved,
-                        playback_duration,
-                    },
-                    &position,
-                ) {
+                let entry = QueueEntry {
+                    item: resolved,
+                    playback_duration,
+                };
+                let max_items = self.max_queue_items();
+                if self.queue_mirror.insert(vec![entry], &position, max_items) {
                     self.emit_queue_changed();
                 }
             }
@@ -2378,6 +2714,72 @@ impl InnerDevice {
                     self.emit_queue_changed();
                 }
             }
+            Command::SetQueueMode { repeat, shuffle } => {
+                let repeat = match repeat {
+                    RepeatMode::Off => v4::flat::RepeatMode::Off,
+                    RepeatMode::One => v4::flat::RepeatMode::One,
+                    RepeatMode::All => v4::flat::RepeatMode::All,
+                };
+                // Unlike the queue mutations the receiver confirms this to every
+                // sender, so the mirror follows the confirmation.
+                let msg = v4::MessageBuilder::new().queue_mode_changed(repeat, shuffle);
+                self.send_bytes(Opcode::Flatbuf, &msg).await?;
+            }
+            Command::SetSleepTimer(timer) => {
+                let time = |secs: f64| {
+                    let micros = Duration::try_from_secs_f64(secs)
+                        .unwrap_or_default()
+                        .as_micros();
+                    v4::flat::Time::new(u64::try_from(micros).unwrap_or(u64::MAX))
+                };
+                let (mode, remaining, fade) = match timer {
+                    SleepTimer::Off => (v4::flat::SleepTimerMode::Off, None, None),
+                    SleepTimer::EndOfItem { fade_secs } => (
+                        v4::flat::SleepTimerMode::EndOfItem,
+                        None,
+                        Some(time(fade_secs)),
+                    ),
+                    SleepTimer::Deadline {
+                        remaining_secs,
+                        fade_secs,
+                    } => (
+                        v4::flat::SleepTimerMode::Deadline,
+                        Some(time(remaining_secs)),
+                        Some(time(fade_secs)),
+                    ),
+                };
+                let msg = v4::MessageBuilder::new().sleep_timer_changed(mode, remaining, fade);
+                self.send_bytes(Opcode::Flatbuf, &msg).await?;
+            }
+            Command::RequestPlaybackHistory { limit } => {
+                self.history_request_id = self.history_request_id.wrapping_add(1);
+                let msg = v4::MessageBuilder::new()
+                    .playback_history_request(self.history_request_id, limit);
+                self.send_bytes(Opcode::Flatbuf, &msg).await?;
+            }
+            Command::SetSubtitleStyle(style) => {
+                let msg = v4::MessageBuilder::new().subtitle_style_changed(
+                    style.size,
+                    style.position,
+                    style.background,
+                );
+                self.send_bytes(Opcode::Flatbuf, &msg).await?;
+            }
+            Command::SetDelay(delay) => {
+                // The receiver clamps, so saturating here is harmless. A NaN
+                // has no delay to send and leaves the field as it is.
+                let micros = |secs: Option<f64>| {
+                    secs.filter(|secs| !secs.is_nan())
+                        .map(|secs| (secs * 1_000_000.0).round() as i64)
+                };
+                let msg = v4::MessageBuilder::new()
+                    .delay_changed(micros(delay.subtitle_secs), micros(delay.audio_secs));
+                self.send_bytes(Opcode::Flatbuf, &msg).await?;
+            }
+            Command::Pair { pin } => {
+                let msg = v4::MessageBuilder::new().pairing_request(&pin);
+                self.send_bytes(Opcode::Flatbuf, &msg).await?;
+            }
         }
 
         Ok(false)
@@ -2421,6 +2823,8 @@ impl InnerDevice {
         self.state_machine = DeviceStateMachine::new(self.receiver_fingerprint.is_some());
         self.queue_mirror = QueueMirror::default();
         self.track_mirror = TrackMirror::default();
+        self.max_queue_items
+            .store(v4::LEGACY_MAX_QUEUE_ITEMS, Ordering::Relaxed);
         self.load_in_flight = false;
         // Close any companion sources left over from a previous connection so a
         // dropped-then-reconnected session doesn't leak file descriptors.
@@ -2551,6 +2955,16 @@ impl FCastDevice {
         }
     }
 
+    /// Whether `position` can be addressed on this receiver. An index past the
+    /// legacy 256 items would be read as index 0 by receivers that don't
+    /// know the wide field.
+    fn fits_queue(&self, position: &QueuePosition) -> bool {
+        match position {
+            QueuePosition::Index(idx) => *idx < self.max_queue_items.load(Ordering::Relaxed),
+            QueuePosition::Front | QueuePosition::Back => true,
+        }
+    }
+
     fn load_url(
         &self,
         content_type: String,
@@ -2571,6 +2985,14 @@ impl FCastDevice {
             request_headers,
         })
     }
+
+    fn seek_to_chapter_target(&self, target: v4::ChapterTarget) -> Result<(), CastingDeviceError> {
+        if self.supports_feature(DeviceFeature::Chapters) {
+            self.send_command(Command::SeekToChapter(target))
+        } else {
+            Err(CastingDeviceError::UnsupportedFeature)
+        }
+    }
 }
 
 #[cfg_attr(feature = "uniffi", uniffi::export)]
@@ -2598,7 +3020,15 @@ impl CastingDevice for FCastDevice {
             | DeviceFeature::FWRTCSignalling
             | DeviceFeature::ChangeTrack
             | DeviceFeature::Queue
-            | DeviceFeature::SetProgressUpdateInterval => session_version == 4,
+            | DeviceFeature::SetProgressUpdateInterval
+            | DeviceFeature::SeekRelative
+            | DeviceFeature::FrameStep
+            | DeviceFeature::Chapters
+            | DeviceFeature::SleepTimer
+            | DeviceFeature::PlaybackHistory
+            | DeviceFeature::SubtitleStyle
+            | DeviceFeature::MediaDelay
+            | DeviceFeature::SecondarySubtitle => session_version == 4,
         }
     }
 
@@ -2616,6 +3046,34 @@ impl CastingDevice for FCastDevice {
         self.send_command(Command::SeekVideo(time_seconds))
     }
 
+    fn seek_relative(&self, offset_seconds: f64) -> Result<(), CastingDeviceError> {
+        if self.supports_feature(DeviceFeature::SeekRelative) {
+            self.send_command(Command::SeekRelative(offset_seconds))
+        } else {
+            Err(CastingDeviceError::UnsupportedFeature)
+        }
+    }
+
+    fn step_frame(&self, forward: bool) -> Result<(), CastingDeviceError> {
+        if self.supports_feature(DeviceFeature::FrameStep) {
+            self.send_command(Command::StepFrame { forward })
+        } else {
+            Err(CastingDeviceError::UnsupportedFeature)
+        }
+    }
+
+    fn next_chapter(&self) -> Result<(), CastingDeviceError> {
+        self.seek_to_chapter_target(v4::ChapterTarget::Next)
+    }
+
+    fn previous_chapter(&self) -> Result<(), CastingDeviceError> {
+        self.seek_to_chapter_target(v4::ChapterTarget::Previous)
+    }
+
+    fn seek_to_chapter(&self, index: u32) -> Result<(), CastingDeviceError> {
+        self.seek_to_chapter_target(v4::ChapterTarget::Index(index))
+    }
+
     fn stop_playback(&self) -> Result<(), CastingDeviceError> {
         self.send_command(Command::StopVideo)
     }
@@ -2742,7 +3200,7 @@ impl CastingDevice for FCastDevice {
                 // Route the legacy lossy queue-load through the rich path.
                 let queue = Queue {
                     items: items.into_iter().map(queue_item_to_entry).collect(),
-                    start_index: start_index.map(|i| i as u32),
+                    start_index,
                     autoplay: false,
                 };
                 self.send_command(Command::LoadQueue(queue))
@@ -2835,7 +3293,9 @@ impl CastingDevice for FCastDevice {
                 event_handler,
                 self.session_version.clone(),
                 Arc::clone(&self.supports_whep),
+                Arc::clone(&self.max_queue_items),
                 fingerprint,
+                state.pairing_token.clone(),
             )
             .work(
                 addrs,
@@ -2904,19 +3364,30 @@ impl CastingDevice for FCastDevice {
     }
 
     fn queue_remove(&self, position: QueuePosition) -> Result<(), CastingDeviceError> {
-        if self.supports_feature(DeviceFeature::Queue) {
+        if self.supports_feature(DeviceFeature::Queue) && self.fits_queue(&position) {
             self.send_command(Command::QueueRemove { position })
         } else {
             Err(CastingDeviceError::UnsupportedFeature)
         }
     }
 
+    fn queue_move(&self, from: QueuePosition, to: QueuePosition) -> Result<(), CastingDeviceError> {
+        if self.supports_feature(DeviceFeature::Queue)
+            && self.fits_queue(&from)
+            && self.fits_queue(&to)
+        {
+            self.send_command(Command::QueueMove { from, to })
+        } else {
+            Err(CastingDeviceError::UnsupportedFeature)
+        }
+    }
+
     fn queue_add(
         &self,
         item: crate::device::QueueItem,
         position: QueuePosition,
     ) -> Result<(), CastingDeviceError> {
-        if self.supports_feature(DeviceFeature::Queue) {
+        if self.supports_feature(DeviceFeature::Queue) && self.fits_queue(&position) {
             let entry = queue_item_to_entry(item);
             self.send_command(Command::QueueInsert {
                 item: entry.item,
@@ -2929,13 +3400,53 @@ impl CastingDevice for FCastDevice {
     }
 
     fn queue_select(&self, position: QueuePosition) -> Result<(), CastingDeviceError> {
-        if self.supports_feature(DeviceFeature::Queue) {
+        if self.supports_feature(DeviceFeature::Queue) && self.fits_queue(&position) {
             self.send_command(Command::QueueSelect { position })
         } else {
             Err(CastingDeviceError::UnsupportedFeature)
         }
     }
 
+    fn set_queue_mode(&self, repeat: RepeatMode, shuffle: bool) -> Result<(), CastingDeviceError> {
+        if self.supports_feature(DeviceFeature::Queue) {
+            self.send_command(Command::SetQueueMode { repeat, shuffle })
+        } else {
+            Err(CastingDeviceError::UnsupportedFeature)
+        }
+    }
+
+    fn set_sleep_timer(&self, timer: SleepTimer) -> Result<(), CastingDeviceError> {
+        if self.supports_feature(DeviceFeature::SleepTimer) {
+            self.send_command(Command::SetSleepTimer(timer))
+        } else {
+            Err(CastingDeviceError::UnsupportedFeature)
+        }
+    }
+
+    fn request_playback_history(&self, limit: u32) -> Result<(), CastingDeviceError> {
+        if self.supports_feature(DeviceFeature::PlaybackHistory) {
+            self.send_command(Command::RequestPlaybackHistory { limit })
+        } else {
+            Err(CastingDeviceError::UnsupportedFeature)
+        }
+    }
+
+    fn set_subtitle_style(&self, style: SubtitleStyle) -> Result<(), CastingDeviceError> {
+        if self.supports_feature(DeviceFeature::SubtitleStyle) {
+            self.send_command(Command::SetSubtitleStyle(style))
+        } else {
+            Err(CastingDeviceError::UnsupportedFeature)
+        }
+    }
+
+    fn set_delay(&self, delay: MediaDelay) -> Result<(), CastingDeviceError> {
+        if self.supports_feature(DeviceFeature::MediaDelay) {
+            self.send_command(Command::SetDelay(delay))
+        } else {
+            Err(CastingDeviceError::UnsupportedFeature)
+        }
+    }
+
     fn load_queue(&self, queue: Queue) -> Result<(), CastingDeviceError> {
         if self.supports_feature(DeviceFeature::Queue) {
             self.send_command(Command::LoadQueue(queue))
@@ -2950,7 +3461,7 @@ impl CastingDevice for FCastDevice {
         playback_duration: Option<f64>,
         position: QueuePosition,
     ) -> Result<(), CastingDeviceError> {
-        if self.supports_feature(DeviceFeature::Queue) {
+        if self.supports_feature(DeviceFeature::Queue) && self.fits_queue(&position) {
             self.send_command(Command::QueueInsert {
                 item,
                 playback_duration,
@@ -2991,6 +3502,18 @@ impl CastingDevice for FCastDevice {
             name: subtitle.name,
         })
     }
+
+    fn pair(&self, pin: String) -> Result<(), CastingDeviceError> {
+        if self.session_version.get() < 4 {
+            return Err(CastingDeviceError::UnsupportedFeature);
+        }
+        self.send_command(Command::Pair { pin })
+    }
+
+    fn set_pairing_token(&self, token: Option<String>) {
+        let mut state = self.state.lock().unwrap();
+        state.pairing_token = token;
+    }
 }
 
 /// Minimal async byte sink, so [`serve_resource`] can write to an in-memory
@@ -3386,11 +3909,14 @@ mod tests {
                 request_headers: None,
                 title: None,
                 thumbnail_url: None,
+                resume: false,
             },
             playback_duration: None,
         }
     }
 
+    const LEGACY_MAX: usize = v4::LEGACY_MAX_QUEUE_ITEMS as usize;
+
     fn mirror_with(n: usize, start_index: Option<u32>) -> QueueMirror {
         let mut mirror = QueueMirror::default();
         mirror.set((0..n as u32).map(test_entry).collect(), start_index, true);
@@ -3417,7 +3943,7 @@ mod tests {
         let mut mirror = mirror_with(2, Some(0));
         assert!(!mirror.remove(&QueuePosition::Index(2)));
         assert!(!mirror.select(&QueuePosition::Index(2)));
-        assert!(!mirror.insert(test_entry(9), &QueuePosition::Index(3)));
+        assert!(!mirror.insert(vec![test_entry(9)], &QueuePosition::Index(3), LEGACY_MAX));
         assert_eq!(mirror.items.len(), 2);
         assert_eq!(mirror.current_index, Some(0));
     }
@@ -3425,19 +3951,43 @@ mod tests {
     #[test]
     fn queue_mirror_refuses_insert_into_empty_or_full_queue() {
         let mut mirror = mirror_with(0, None);
-        assert!(!mirror.insert(test_entry(0), &QueuePosition::Front));
+        assert!(!mirror.insert(vec![test_entry(0)], &QueuePosition::Front, LEGACY_MAX));
 
-        let mut mirror = mirror_with(u8::MAX as usize + 1, Some(0));
-        assert!(!mirror.insert(test_entry(0), &QueuePosition::Back));
-        assert_eq!(mirror.items.len(), u8::MAX as usize + 1);
+        let mut mirror = mirror_with(LEGACY_MAX, Some(0));
+        assert!(!mirror.insert(vec![test_entry(0)], &QueuePosition::Back, LEGACY_MAX));
+        assert_eq!(mirror.items.len(), LEGACY_MAX);
+        // A receiver with wide queues takes it.
+        assert!(mirror.insert(vec![test_entry(0)], &QueuePosition::Back, 1 << 16));
+    }
+
+    #[test]
+    fn queue_mirror_inserts_batches_whole() {
+        let mut mirror = mirror_with(3, Some(1));
+        let batch = |n: u32| (10..10 + n).map(test_entry).collect::<Vec<_>>();
+        // Past the cap: nothing is inserted.
+        assert!(!mirror.insert(batch(3), &QueuePosition::Front, 5));
+        assert_eq!(mirror.items.len(), 3);
+        assert!(mirror.insert(batch(2), &QueuePosition::Index(1), 5));
+        assert_eq!(mirror.items.len(), 5);
+        assert_eq!(mirror.current_index, Some(3));
+        assert_eq!(mirror.items[1], test_entry(10));
+        assert_eq!(mirror.items[2], test_entry(11));
+    }
+
+    #[test]
+    fn queue_pages_split_into_ordered_pages() {
+        let pages = queue_pages((0..7).collect(), 3);
+        assert_eq!(pages, vec![vec![0, 1, 2], vec![3, 4, 5], vec![6]]);
+        assert_eq!(queue_pages((0..7).collect(), usize::MAX).len(), 1);
+        assert!(queue_pages(Vec::<u32>::new(), 3).is_empty());
     }
 
     #[test]
     fn queue_mirror_insert_shifts_current_index() {
         let mut mirror = mirror_with(2, Some(1));
-        assert!(mirror.insert(test_entry(9), &QueuePosition::Front));
+        assert!(mirror.insert(vec![test_entry(9)], &QueuePosition::Front, LEGACY_MAX));
         assert_eq!(mirror.current_index, Some(2));
-        assert!(mirror.insert(test_entry(10), &QueuePosition::Back));
+        assert!(mirror.insert(vec![test_entry(10)], &QueuePosition::Back, LEGACY_MAX));
         assert_eq!(mirror.current_index, Some(2));
     }
 
@@ -3450,11 +4000,47 @@ mod tests {
         assert!(!mirror.select(&QueuePosition::Index(2)));
     }
 
+    #[test]
+    fn queue_mirror_move_keeps_the_current_item() {
+        let url = |mirror: &QueueMirror, idx: usize| match &mirror.items[idx].item.source {
+            MediaLocator::Url { url } => url.clone(),
+            _ => unreachable!(),
+        };
+        let mut mirror = mirror_with(4, Some(1));
+        // The playing item moves along.
+        assert!(mirror.move_item(&QueuePosition::Index(1), &QueuePosition::Back));
+        assert_eq!(mirror.current_index, Some(3));
+        assert_eq!(url(&mirror, 3), "http://example.test/1.mp4");
+        // Moving an item over it shifts it by one.
+        assert!(mirror.move_item(&QueuePosition::Back, &QueuePosition::Front));
+        assert!(mirror.move_item(&QueuePosition::Index(3), &QueuePosition::Index(1)));
+        assert_eq!(mirror.current_index, Some(0));
+        assert_eq!(url(&mirror, 1), "http://example.test/3.mp4");
+
+        assert!(!mirror.move_item(&QueuePosition::Index(2), &QueuePosition::Index(2)));
+        assert!(!mirror.move_item(&QueuePosition::Index(4), &QueuePosition::Front));
+    }
+
+    #[test]
+    fn queue_mirror_keeps_the_mode_across_queues() {
+        let mut mirror = QueueMirror::default();
+        // Modes arrive before any queue (on connect) and are not reported alone.
+        assert!(!mirror.set_mode(RepeatMode::All, true));
+        mirror.set(vec![test_entry(0)], None, true);
+        let snapshot = mirror.snapshot().unwrap();
+        assert_eq!((snapshot.repeat, snapshot.shuffle), (RepeatMode::All, true));
+
+        assert!(mirror.set_mode(RepeatMode::One, true));
+        assert!(!mirror.set_mode(RepeatMode::One, true));
+        mirror.clear();
+        assert_eq!((mirror.repeat, mirror.shuffle), (RepeatMode::One, true));
+    }
+
     #[test]
     fn queue_mirror_inactive_ignores_everything() {
         let mut mirror = QueueMirror::default();
         assert!(mirror.snapshot().is_none());
-        assert!(!mirror.insert(test_entry(0), &QueuePosition::Front));
+        assert!(!mirror.insert(vec![test_entry(0)], &QueuePosition::Front, LEGACY_MAX));
         assert!(!mirror.remove(&QueuePosition::Front));
         assert!(!mirror.select(&QueuePosition::Front));
     }
@@ -3617,6 +4203,133 @@ mod tests {
         );
     }
 
+    #[test]
+    fn v4_secondary_subtitle_change_track_keeps_its_type() {
+        let mut state_machine = init_v4();
+
+        let msg = v4::MessageBuilder::new().change_track(
+            Some(0x1000_0000),
+            v4::flat::MediaTrackType::SecondarySubtitle,
+        );
+        assert_eq!(
+            state_machine.handle_packet(Opcode::Flatbuf, Some(&msg)),
+            Action::ChangeTrack {
+                id: Some(0x1000_0000),
+                typ: crate::device::MediaTrackType::SecondarySubtitle,
+            }
+        );
+    }
+
+    #[test]
+    fn v4_chapters_available_maps_to_device_chapters() {
+        let mut state_machine = init_v4();
+
+        let msg = v4::MessageBuilder::new().chapters_available(&[v4::Chapter {
+            start: 12.5,
+            length: None,
+            title: "Opening".to_owned(),
+            skip_label: Some("Skip opening".to_owned()),
+        }]);
+        assert_eq!(
+            state_machine.handle_packet(Opcode::Flatbuf, Some(&msg)),
+            Action::ChaptersAvailable(vec![crate::device::Chapter {
+                start: 12.5,
+                length: None,
+                title: "Opening".to_owned(),
+                skip_label: Some("Skip opening".to_owned()),
+            }])
+        );
+    }
+
+    #[test]
+    fn v4_sleep_timer_changed_maps_to_seconds() {
+        let mut state_machine = init_v4();
+
+        let msg = v4::MessageBuilder::new().sleep_timer_changed(
+            v4::flat::SleepTimerMode::Deadline,
+            Some(v4::flat::Time::new(90_000_000)),
+            Some(v4::flat::Time::new(30_000_000)),
+        );
+        assert_eq!(
+            state_machine.handle_packet(Opcode::Flatbuf, Some(&msg)),
+            Action::SleepTimerChanged(SleepTimer::Deadline {
+                remaining_secs: 90.0,
+                fade_secs: 30.0,
+            })
+        );
+        let msg = v4::MessageBuilder::new().sleep_timer_changed(
+            v4::flat::SleepTimerMode::Off,
+            None,
+            None,
+        );
+        assert_eq!(
+            state_machine.handle_packet(Opcode::Flatbuf, Some(&msg)),
+            Action::SleepTimerChanged(SleepTimer::Off)
+        );
+    }
+
+    #[test]
+    fn v4_playback_history_maps_to_device_entries() {
+        let mut state_machine = init_v4();
+
+        let msg = v4::MessageBuilder::new().playback_history(
+            1,
+            &[v4::HistoryEntry {
+                url: "https://example.com/episode.mp4".to_owned(),
+                container: "video/mp4".to_owned(),
+                title: Some("Episode 1".to_owned()),
+                thumbnail_url: None,
+                watched: 120.0,
+                duration: Some(1800.0),
+                played_at: 1_760_000_000,
+                sender_name: Some("Phone".to_owned()),
+            }],
+        );
+        assert_eq!(
+            state_machine.handle_packet(Opcode::Flatbuf, Some(&msg)),
+            Action::PlaybackHistory(vec![HistoryEntry {
+                url: "https://example.com/episode.mp4".to_owned(),
+                container: "video/mp4".to_owned(),
+                title: Some("Episode 1".to_owned()),
+                thumbnail_url: None,
+                watched: 120.0,
+                duration: Some(1800.0),
+                played_at: 1_760_000_000,
+                sender_name: Some("Phone".to_owned()),
+            }])
+        );
+    }
+
+    #[test]
+    fn v4_subtitle_style_changed_maps_to_device_style() {
+        let mut state_machine = init_v4();
+
+        let msg =
+            v4::MessageBuilder::new().subtitle_style_changed(Some(1.35), Some(0.04), Some(true));
+        assert_eq!(
+            state_machine.handle_packet(Opcode::Flatbuf, Some(&msg)),
+            Action::SubtitleStyleChanged(SubtitleStyle {
+                size: Some(1.35),
+                position: Some(0.04),
+                background: Some(true),
+            })
+        );
+    }
+
+    #[test]
+    fn v4_delay_changed_maps_to_seconds() {
+        let mut state_machine = init_v4();
+
+        let msg = v4::MessageBuilder::new().delay_changed(Some(-250_000), Some(1_500_000));
+        assert_eq!(
+            state_machine.handle_packet(Opcode::Flatbuf, Some(&msg)),
+            Action::DelayChanged(MediaDelay {
+                subtitle_secs: Some(-0.25),
+                audio_secs: Some(1.5),
+            })
+        );
+    }
+
     /// v3 has no track messages, so tracks can only ever have come from a v4
     /// session.
     #[test]
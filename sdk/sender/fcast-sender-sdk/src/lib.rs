@@ -20,9 +20,9 @@
 //!
 //! use fcast_sender_sdk::context::CastContext;
 //! use fcast_sender_sdk::device::{
-//!     ApplicationInfo, DeviceConnectionState, DeviceEventHandler, DeviceInfo,
-//!     LoadRequest, PlaybackState, ProtocolType, Source, MediaTrack, MediaTrackType,
-//!     QueueState, ReceiverError, TrackList,
+//!     ApplicationInfo, Chapter, DeviceConnectionState, DeviceEventHandler, DeviceInfo,
+//!     HistoryEntry, LoadRequest, MediaDelay, PlaybackState, ProtocolType, Source, MediaTrack,
+//!     MediaTrackType, QueueState, ReceiverError, SleepTimer, SubtitleStyle, TrackList,
 //! };
 //! use fcast_sender_sdk::{DeviceDiscovererEventHandler, IpAddr};
 //!
@@ -83,6 +83,30 @@
 //!      fn command_error(&self, error: ReceiverError) {
 //!          println!("Command error: {error:?}");
 //!      }
+//!
+//!      fn paired(&self, token: String) {
+//!          println!("Paired, token: {token}");
+//!      }
+//!
+//!      fn chapters_available(&self, chapters: Vec<Chapter>) {
+//!          println!("Chapters available: {chapters:?}");
+//!      }
+//!
+//!      fn sleep_timer_changed(&self, timer: SleepTimer) {
+//!          println!("Sleep timer changed: {timer:?}");
+//!      }
+//!
+//!      fn playback_history(&self, entries: Vec<HistoryEntry>) {
+//!          println!("Playback history: {entries:?}");
+//!      }
+//!
+//!      fn subtitle_style_changed(&self, style: SubtitleStyle) {
+//!          println!("Subtitle style changed: {style:?}");
+//!      }
+//!
+//!      fn delay_changed(&self, delay: MediaDelay) {
+//!          println!("Delay changed: {delay:?}");
+//!      }
 //! }
 //!
 //! struct DiscovererEventHandler {}
@@ -136,6 +136,10 @@ class _ScaffoldState extends State<_Scaffold> {
                                       'Receiver rejected a command: ${event.error}',
                                     );
                                     break;
+                                  case DeviceEvent_Paired():
+                                    break;
+                                  case DeviceEvent_ChaptersAvailable():
+                                    break;
                                   case DeviceEvent_PlaybackStopped():
                                     break;
                                   case DeviceEvent_PlaybackError():
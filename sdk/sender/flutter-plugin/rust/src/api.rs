@@ -3,12 +3,13 @@ use std::{collections::HashMap, sync::Arc};
 use fcast_sender_sdk_raw::context;
 pub use fcast_sender_sdk_raw::{
     device::{
-        self, ApplicationInfo, AudioCapabilities, CastingDeviceError, CompanionSource,
+        self, ApplicationInfo, AudioCapabilities, CastingDeviceError, Chapter, CompanionSource,
         CompanionSourceDescriptor, DeviceConnectionState, DeviceFeature, DeviceInfo,
-        DisplayCapabilities, LoadRequest, MediaCapabilities, MediaItem, MediaLocator, MediaTrack,
-        MediaTrackType, Metadata, PlaybackState, PlaylistItem, ProtocolType, Queue, QueueEntry,
-        QueueItem, QueuePosition, QueueState, ReceiverCapabilities, ReceiverError, Source,
-        SubtitleContent, SubtitleSource, TrackList, VideoResolution,
+        DisplayCapabilities, HistoryEntry, LoadRequest, MediaCapabilities, MediaDelay, MediaItem,
+        MediaLocator, MediaTrack, MediaTrackType, Metadata, PlaybackState, PlaylistItem,
+        ProtocolType, Queue, QueueEntry, QueueItem, QueuePosition, QueueState,
+        ReceiverCapabilities, ReceiverError, RepeatMode, SleepTimer, Source, SubtitleContent,
+        SubtitleSource, SubtitleStyle, TrackList, VideoResolution,
     },
     IpAddr,
 };
@@ -185,6 +186,7 @@ pub enum _MediaTrackType {
     Video,
     Audio,
     Subtitle,
+    SecondarySubtitle,
 }
 
 #[frb(mirror(MediaTrack))]
@@ -195,12 +197,21 @@ pub struct _MediaTrack {
     pub typ: MediaTrackType,
 }
 
+#[frb(mirror(Chapter))]
+pub struct _Chapter {
+    pub start: f64,
+    pub length: Option<f64>,
+    pub title: String,
+    pub skip_label: Option<String>,
+}
+
 #[frb(mirror(TrackList))]
 pub struct _TrackList {
     pub tracks: Vec<MediaTrack>,
     pub selected_video: Option<u32>,
     pub selected_audio: Option<u32>,
     pub selected_subtitle: Option<u32>,
+    pub selected_secondary_subtitle: Option<u32>,
 }
 
 #[frb(mirror(MediaLocator))]
@@ -219,6 +230,7 @@ pub struct _MediaItem {
     pub request_headers: Option<HashMap<String, String>>,
     pub title: Option<String>,
     pub thumbnail_url: Option<String>,
+    pub resume: bool,
 }
 
 #[frb(mirror(QueueEntry))]
@@ -234,18 +246,59 @@ pub struct _Queue {
     pub autoplay: bool,
 }
 
+#[frb(mirror(RepeatMode))]
+pub enum _RepeatMode {
+    Off,
+    One,
+    All,
+}
+
+#[frb(mirror(HistoryEntry))]
+pub struct _HistoryEntry {
+    pub url: String,
+    pub container: String,
+    pub title: Option<String>,
+    pub thumbnail_url: Option<String>,
+    pub watched: f64,
+    pub duration: Option<f64>,
+    pub played_at: u64,
+    pub sender_name: Option<String>,
+}
+
+#[frb(mirror(SleepTimer))]
+pub enum _SleepTimer {
+    Off,
+    EndOfItem { fade_secs: f64 },
+    Deadline { remaining_secs: f64, fade_secs: f64 },
+}
+
+#[frb(mirror(SubtitleStyle))]
+pub struct _SubtitleStyle {
+    pub size: Option<f32>,
+    pub position: Option<f32>,
+    pub background: Option<bool>,
+}
+
+#[frb(mirror(MediaDelay))]
+pub struct _MediaDelay {
+    pub subtitle_secs: Option<f64>,
+    pub audio_secs: Option<f64>,
+}
+
 #[frb(mirror(QueueState))]
 pub struct _QueueState {
     pub items: Vec<QueueEntry>,
     pub current_index: Option<u32>,
     pub autoplay: bool,
+    pub repeat: RepeatMode,
+    pub shuffle: bool,
 }
 
 #[frb(mirror(QueuePosition))]
 pub enum _QueuePosition {
     Front,
     Back,
-    Index(u8),
+    Index(u32),
 }
 
 #[frb(mirror(SubtitleContent))]
@@ -276,6 +329,7 @@ pub enum _ReceiverError {
     QueueFull,
     InvalidPayloadType,
     Internal,
+    Unauthorized,
     Unknown,
 }
 
@@ -336,6 +390,24 @@ pub enum DeviceEvent {
     CommandError {
         error: ReceiverError,
     },
+    Paired {
+        token: String,
+    },
+    ChaptersAvailable {
+        chapters: Vec<Chapter>,
+    },
+    SleepTimerChanged {
+        timer: SleepTimer,
+    },
+    PlaybackHistory {
+        entries: Vec<HistoryEntry>,
+    },
+    SubtitleStyleChanged {
+        style: SubtitleStyle,
+    },
+    DelayChanged {
+        delay: MediaDelay,
+    },
     PlaybackStopped,
     PlaybackError {
         message: String,
@@ -454,6 +526,48 @@ impl device::DeviceEventHandler for DeviceEventHandler {
             (self.on_event)(DeviceEvent::CommandError { error }).await;
         });
     }
+
+    #[frb(ignore)]
+    fn paired(&self, token: String) {
+        futures::executor::block_on(async {
+            (self.on_event)(DeviceEvent::Paired { token }).await;
+        });
+    }
+
+    #[frb(ignore)]
+    fn chapters_available(&self, chapters: Vec<Chapter>) {
+        futures::executor::block_on(async {
+            (self.on_event)(DeviceEvent::ChaptersAvailable { chapters }).await;
+        });
+    }
+
+    #[frb(ignore)]
+    fn sleep_timer_changed(&self, timer: SleepTimer) {
+        futures::executor::block_on(async {
+            (self.on_event)(DeviceEvent::SleepTimerChanged { timer }).await;
+        });
+    }
+
+    #[frb(ignore)]
+    fn playback_history(&self, entries: Vec<HistoryEntry>) {
+        futures::executor::block_on(async {
+            (self.on_event)(DeviceEvent::PlaybackHistory { entries }).await;
+        });
+    }
+
+    #[frb(ignore)]
+    fn subtitle_style_changed(&self, style: SubtitleStyle) {
+        futures::executor::block_on(async {
+            (self.on_event)(DeviceEvent::SubtitleStyleChanged { style }).await;
+        });
+    }
+
+    #[frb(ignore)]
+    fn delay_changed(&self, delay: MediaDelay) {
+        futures::executor::block_on(async {
+            (self.on_event)(DeviceEvent::DelayChanged { delay }).await;
+        });
+    }
 }
 
 #[frb(mirror(CastingDeviceError))]
@@ -486,6 +600,14 @@ pub enum _DeviceFeature {
     ChangeTrack,
     Queue,
     SetProgressUpdateInterval,
+    SeekRelative,
+    FrameStep,
+    Chapters,
+    SleepTimer,
+    PlaybackHistory,
+    SubtitleStyle,
+    MediaDelay,
+    SecondarySubtitle,
 }
 
 macro_rules! device_error_converter {
@@ -568,7 +690,7 @@ pub enum _LoadRequest {
     },
     Queue {
         items: Vec<QueueItem>,
-        start_index: Option<u8>,
+        start_index: Option<u32>,
     },
 }
 
@@ -606,6 +728,36 @@ impl CastingDevice {
         device_error_converter!(self.0.seek(time_seconds))
     }
 
+    /// Seek relative to the current position, negative to go back. FCast v4.
+    #[frb(sync)]
+    pub fn seek_relative(&self, offset_seconds: f64) -> Result<(), _CastingDeviceError> {
+        device_error_converter!(self.0.seek_relative(offset_seconds))
+    }
+
+    /// Step one frame while paused. FCast v4.
+    #[frb(sync)]
+    pub fn step_frame(&self, forward: bool) -> Result<(), _CastingDeviceError> {
+        device_error_converter!(self.0.step_frame(forward))
+    }
+
+    /// Seek to the next chapter. FCast v4.
+    #[frb(sync)]
+    pub fn next_chapter(&self) -> Result<(), _CastingDeviceError> {
+        device_error_converter!(self.0.next_chapter())
+    }
+
+    /// Seek to the start of the current or previous chapter. FCast v4.
+    #[frb(sync)]
+    pub fn previous_chapter(&self) -> Result<(), _CastingDeviceError> {
+        device_error_converter!(self.0.previous_chapter())
+    }
+
+    /// Seek to the chapter at `index` of [`DeviceEvent::ChaptersAvailable`]. FCast v4.
+    #[frb(sync)]
+    pub fn seek_to_chapter(&self, index: u32) -> Result<(), _CastingDeviceError> {
+        device_error_converter!(self.0.seek_to_chapter(index))
+    }
+
     #[frb(sync)]
     pub fn stop_playback(&self) -> Result<(), _CastingDeviceError> {
         device_error_converter!(self.0.stop_playback())
@@ -645,6 +797,19 @@ impl CastingDevice {
         device_error_converter!(self.0.set_progress_update_interval(interval_millis))
     }
 
+    /// Answer a receiver that requires pairing with the PIN it shows.
+    /// Success is reported as [`DeviceEvent::Paired`]. FCast v4 only.
+    #[frb(sync)]
+    pub fn pair(&self, pin: String) -> Result<(), _CastingDeviceError> {
+        device_error_converter!(self.0.pair(pin))
+    }
+
+    /// Token from an earlier [`DeviceEvent::Paired`] to present on connect.
+    #[frb(sync)]
+    pub fn set_pairing_token(&self, token: Option<String>) {
+        self.0.set_pairing_token(token)
+    }
+
     #[frb(sync)]
     pub fn playlist_item_next(&self) -> Result<(), _CastingDeviceError> {
         device_error_converter!(self.0.playlist_item_next())
@@ -752,12 +917,60 @@ impl CastingDevice {
         device_error_converter!(self.0.queue_remove(position))
     }
 
+    /// Move an item within the active queue, keeping the current item playing.
+    /// FCast v4 only.
+    #[frb(sync)]
+    pub fn queue_move(
+        &self,
+        from: QueuePosition,
+        to: QueuePosition,
+    ) -> Result<(), _CastingDeviceError> {
+        device_error_converter!(self.0.queue_move(from, to))
+    }
+
     /// Select (jump to) an item in the active queue. FCast v4 only.
     #[frb(sync)]
     pub fn queue_select(&self, position: QueuePosition) -> Result<(), _CastingDeviceError> {
         device_error_converter!(self.0.queue_select(position))
     }
 
+    /// Set the receiver's repeat and shuffle modes. FCast v4 only.
+    #[frb(sync)]
+    pub fn set_queue_mode(
+        &self,
+        repeat: RepeatMode,
+        shuffle: bool,
+    ) -> Result<(), _CastingDeviceError> {
+        device_error_converter!(self.0.set_queue_mode(repeat, shuffle))
+    }
+
+    /// Arm, replace, or cancel the receiver's sleep timer. FCast v4 only.
+    #[frb(sync)]
+    pub fn set_sleep_timer(&self, timer: SleepTimer) -> Result<(), _CastingDeviceError> {
+        device_error_converter!(self.0.set_sleep_timer(timer))
+    }
+
+    /// Ask the receiver for its playback history, answered with
+    /// [`DeviceEvent::PlaybackHistory`]. FCast v4 only.
+    #[frb(sync)]
+    pub fn request_playback_history(&self, limit: u32) -> Result<(), _CastingDeviceError> {
+        device_error_converter!(self.0.request_playback_history(limit))
+    }
+
+    /// Change how the receiver draws subtitles, `None` fields keep their
+    /// value. FCast v4 only.
+    #[frb(sync)]
+    pub fn set_subtitle_style(&self, style: SubtitleStyle) -> Result<(), _CastingDeviceError> {
+        device_error_converter!(self.0.set_subtitle_style(style))
+    }
+
+    /// Shift the current item's subtitles or audio against its video,
+    /// `None` fields keep their value. FCast v4 only.
+    #[frb(sync)]
+    pub fn set_delay(&self, delay: MediaDelay) -> Result<(), _CastingDeviceError> {
+        device_error_converter!(self.0.set_delay(delay))
+    }
+
     /// Add an external subtitle source to the current media. FCast v4 only.
     #[frb(sync)]
     pub fn add_subtitle_source(&self, subtitle: SubtitleSource) -> Result<(), _CastingDeviceError> {
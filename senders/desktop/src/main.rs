@@ -901,6 +901,8 @@ impl Application {
                             device::MediaTrackType::Video => &mut videos,
                             device::MediaTrackType::Audio => &mut audios,
                             device::MediaTrackType::Subtitle => &mut subtitles,
+                            // Never advertised, it only names a selection.
+                            device::MediaTrackType::SecondarySubtitle => continue,
                         };
                         let language = isolang::Language::from_639_1(&track.language)
                             .or_else(|| isolang::Language::from_639_3(&track.language))
@@ -924,6 +926,8 @@ impl Application {
                         device::MediaTrackType::Video => bridge.set_current_video_track(id),
                         device::MediaTrackType::Audio => bridge.set_current_audio_track(id),
                         device::MediaTrackType::Subtitle => bridge.set_current_subtitle_track(id),
+                        // This sender has no control for it.
+                        device::MediaTrackType::SecondarySubtitle => (),
                     }
                 }
             })?;
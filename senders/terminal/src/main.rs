@@ -2,8 +2,9 @@ use clap::{Parser, Subcommand};
 use fcast_sender_sdk::{
     context::CastContext,
     device::{
-        DeviceConnectionState, DeviceEventHandler, DeviceInfo, LoadRequest, MediaTrack,
-        MediaTrackType, PlaybackState, QueueState, ReceiverError, Source, TrackList,
+        Chapter, DeviceConnectionState, DeviceEventHandler, DeviceInfo, HistoryEntry, LoadRequest,
+        MediaDelay, MediaTrack, MediaTrackType, PlaybackState, QueueState, ReceiverError,
+        SleepTimer, Source, SubtitleStyle, TrackList,
     },
     url_format_ip_addr, DeviceDiscovererEventHandler,
 };
@@ -57,6 +58,77 @@ enum Command {
         #[arg(long, short)]
         timestamp: f64,
     },
+    /// Seek relative to the current position
+    Skip {
+        /// Seconds to skip, negative to go back
+        #[arg(long, short, allow_hyphen_values = true)]
+        seconds: f64,
+    },
+    /// Step a single frame while paused
+    StepFrame {
+        /// Step backward instead of forward
+        #[arg(long, short)]
+        backward: bool,
+    },
+    /// Jump between the chapters of the playing media
+    Chapter {
+        /// Chapter index to seek to
+        #[arg(long, short, conflicts_with_all = ["next", "previous"])]
+        index: Option<u32>,
+        /// Seek to the next chapter
+        #[arg(long, short, conflicts_with = "previous")]
+        next: bool,
+        /// Seek to the start of the current or previous chapter
+        #[arg(long, short)]
+        previous: bool,
+    },
+    /// Stop playback after a while, or when the current media ends
+    SleepTimer {
+        /// Minutes until playback stops
+        #[arg(long, short, conflicts_with_all = ["end_of_media", "off"])]
+        minutes: Option<f64>,
+        /// Stop when the current media ends
+        #[arg(long, short, conflicts_with = "off")]
+        end_of_media: bool,
+        /// Cancel the sleep timer
+        #[arg(long)]
+        off: bool,
+        /// Seconds to fade the volume out over before stopping
+        #[arg(long, short, default_value_t = 0.0)]
+        fade: f64,
+    },
+    /// List what the receiver played recently
+    History {
+        /// Show at most this many items, 0 for all
+        #[arg(long, short, default_value_t = 10)]
+        limit: u32,
+    },
+    /// Change how the receiver draws subtitles
+    SubtitleStyle {
+        /// Text size as a factor of the normal size, 0.5 to 3.0
+        #[arg(long, short)]
+        size: Option<f32>,
+        /// Distance from the bottom as a fraction of the picture height, 0.0 to 0.5
+        #[arg(long, short)]
+        position: Option<f32>,
+        /// Draw a box behind the text
+        #[arg(long, short)]
+        background: Option<bool>,
+    },
+    /// Shift the current item's subtitles or audio against its video
+    Delay {
+        /// Seconds to show subtitles later, negative for earlier
+        #[arg(long, short, allow_negative_numbers = true)]
+        subtitle: Option<f64>,
+        /// Seconds to play audio later, negative for earlier
+        #[arg(long, short, allow_negative_numbers = true)]
+        audio: Option<f64>,
+    },
+    /// Show a second subtitle track above the current one
+    SecondarySubtitle {
+        /// Id of a subtitle track (see `listen`), none to hide it
+        id: Option<u32>,
+    },
     /// Pause media
     Pause,
     /// Resume media
@@ -116,6 +188,7 @@ struct TerminalSender {
 enum Event {
     Connected(fcast_sender_sdk::IpAddr),
     Disconnected,
+    History,
 }
 
 struct EventHandler {
@@ -193,6 +266,39 @@ impl DeviceEventHandler for EventHandler {
     fn command_error(&self, error: ReceiverError) {
         eprintln!("Receiver rejected command: {error:?}");
     }
+
+    fn paired(&self, token: String) {
+        println!("Paired with receiver, token: {token}");
+    }
+
+    fn chapters_available(&self, chapters: Vec<Chapter>) {
+        println!("Chapters available: {chapters:#?}");
+    }
+
+    fn sleep_timer_changed(&self, timer: SleepTimer) {
+        println!("Sleep timer changed: {timer:?}");
+    }
+
+    fn playback_history(&self, entries: Vec<HistoryEntry>) {
+        for entry in &entries {
+            let title = entry.title.as_deref().unwrap_or(&entry.url);
+            let from = entry.sender_name.as_deref().unwrap_or("unknown sender");
+            println!(
+                "{title} ({}, watched {:.0}s, from {from})",
+                entry.container, entry.watched
+            );
+            println!("    {}", entry.url);
+        }
+        let _ = self.tx.send(Event::History);
+    }
+
+    fn subtitle_style_changed(&self, style: SubtitleStyle) {
+        println!("Subtitle style changed: {style:?}");
+    }
+
+    fn delay_changed(&self, delay: MediaDelay) {
+        println!("Delay changed: {delay:?}");
+    }
 }
 
 /// Discovery handler that prints every event for the `scan` subcommand.
@@ -492,6 +598,67 @@ async fn main() {
             }
         }
         Command::Seek { timestamp } => device.seek(timestamp).unwrap(),
+        Command::Skip { seconds } => device.seek_relative(seconds).unwrap(),
+        Command::StepFrame { backward } => device.step_frame(!backward).unwrap(),
+        Command::Chapter {
+            index,
+            next,
+            previous,
+        } => match index {
+            Some(index) => device.seek_to_chapter(index).unwrap(),
+            None if previous => device.previous_chapter().unwrap(),
+            None if next => device.next_chapter().unwrap(),
+            None => {
+                eprintln!("Specify --index, --next or --previous");
+                std::process::exit(1);
+            }
+        },
+        Command::SleepTimer {
+            minutes,
+            end_of_media,
+            off,
+            fade,
+        } => {
+            let timer = match minutes {
+                Some(minutes) => SleepTimer::Deadline {
+                    remaining_secs: minutes * 60.0,
+                    fade_secs: fade,
+                },
+                None if end_of_media => SleepTimer::EndOfItem { fade_secs: fade },
+                None if off => SleepTimer::Off,
+                None => {
+                    eprintln!("Specify --minutes, --end-of-media or --off");
+                    std::process::exit(1);
+                }
+            };
+            device.set_sleep_timer(timer).unwrap();
+        }
+        Command::History { limit } => {
+            device.request_playback_history(limit).unwrap();
+            if rx.recv_timeout(Duration::from_secs(5)) != Ok(Event::History) {
+                eprintln!("The receiver did not send its history");
+            }
+        }
+        Command::SubtitleStyle {
+            size,
+            position,
+            background,
+        } => device
+            .set_subtitle_style(SubtitleStyle {
+                size,
+                position,
+                background,
+            })
+            .unwrap(),
+        Command::Delay { subtitle, audio } => device
+            .set_delay(MediaDelay {
+                subtitle_secs: subtitle,
+                audio_secs: audio,
+            })
+            .unwrap(),
+        Command::SecondarySubtitle { id } => device
+            .change_track(id, MediaTrackType::SecondarySubtitle)
+            .unwrap(),
         Command::Pause => device.pause_playback().unwrap(),
         Command::Resume => device.resume_playback().unwrap(),
         Command::Stop => device.stop_playback().unwrap(),
@@ -390,7 +390,9 @@ pub struct Engine<'a> {
     state_mark: usize,
     /// The queue index of the most recent `QueueItemSelected` the receiver
     /// broadcast to this sender (e.g. an autoplay or gapless advance).
-    last_queue_selected: Option<u8>,
+    last_queue_selected: Option<u32>,
+    /// The queue cap from the receiver's `ReceiverIntroduction`.
+    max_queue_items: u32,
 }
 
 struct CompanionResource {
@@ -444,6 +446,7 @@ impl<'a> Engine<'a> {
             state_log: Vec::new(),
             state_mark: 0,
             last_queue_selected: None,
+            max_queue_items: v4::LEGACY_MAX_QUEUE_ITEMS,
             second_track_ids: Default::default(),
             second_last_track_state: [None; 3],
             last_state_v4: None,
@@ -746,9 +749,12 @@ impl<'a> Engine<'a> {
                     FlatAction::None
                 }
                 Message::ReceiverIntroduction => {
-                    packet
+                    let intro = packet
                         .payload_as_receiver_introduction()
                         .ok_or_else(|| anyhow!("malformed ReceiverIntroduction"))?;
+                    self.max_queue_items = intro
+                        .max_queue_items()
+                        .unwrap_or(v4::LEGACY_MAX_QUEUE_ITEMS);
                     if self.expect.receiver_intro {
                         self.expect.receiver_intro = false;
                         info!("receiver introduction received");
@@ -801,8 +807,9 @@ impl<'a> Engine<'a> {
                         .payload_as_queue_item_selected()
                         .ok_or_else(|| anyhow!("malformed QueueItemSelected"))?;
                     if let Some(index) = selected.position_as_index() {
-                        debug!(index = index.index(), "QueueItemSelected broadcast");
-                        self.last_queue_selected = Some(index.index());
+                        let index = v4::read_queue_index(&index);
+                        debug!(index, "QueueItemSelected broadcast");
+                        self.last_queue_selected = Some(index);
                     }
                     FlatAction::None
                 }
@@ -1237,6 +1244,7 @@ impl<'a> Engine<'a> {
                 let (expected, label) = match kind {
                     QueueMutationKind::Insert => (v4::flat::Message::QueueInsert, "QueueInsert"),
                     QueueMutationKind::Remove => (v4::flat::Message::QueueRemove, "QueueRemove"),
+                    QueueMutationKind::Move => (v4::flat::Message::QueueMove, "QueueMove"),
                     QueueMutationKind::Select => {
                         (v4::flat::Message::QueueItemSelected, "QueueItemSelected")
                     }
@@ -1335,7 +1343,7 @@ impl<'a> Engine<'a> {
             app_name: Some("fast-2".to_owned()),
             app_version: Some(env!("CARGO_PKG_VERSION").to_owned()),
         };
-        let msg = v4::MessageBuilder::new().sender_introduction(&info);
+        let msg = v4::MessageBuilder::new().sender_introduction(&info, None);
         conn.write(Opcode::Flatbuf, Some(&msg)).await?;
 
         let deadline = Instant::now() + MAX_SETTLE;
@@ -1906,7 +1914,7 @@ impl<'a> Engine<'a> {
 
     /// Wait for the receiver to broadcast a `QueueItemSelected` naming
     /// `index` (a receiver-initiated advance: autoplay or gapless).
-    async fn await_queue_select(&mut self, index: u8) -> Result<()> {
+    async fn await_queue_select(&mut self, index: u32) -> Result<()> {
         let deadline = Instant::now() + MAX_SETTLE;
         loop {
             if self.last_queue_selected == Some(index) {
@@ -2120,7 +2128,7 @@ impl<'a> Engine<'a> {
                     app_name: Some("fast".to_owned()),
                     app_version: Some(env!("CARGO_PKG_VERSION").to_owned()),
                 };
-                let msg = v4::MessageBuilder::new().sender_introduction(&info);
+                let msg = v4::MessageBuilder::new().sender_introduction(&info, None);
                 self.conn.write(Opcode::Flatbuf, Some(&msg)).await?;
             }
             Op::PlayV4 { file_id } => {
@@ -2165,6 +2173,7 @@ impl<'a> Engine<'a> {
                     thumbnail_url: None,
                     metadata: None,
                     extra_metadata: None,
+                    resume: false,
                 };
                 let msg = v4::MessageBuilder::new().load_single(item);
                 self.conn.write(Opcode::Flatbuf, Some(&msg)).await?;
@@ -2190,10 +2199,46 @@ impl<'a> Engine<'a> {
                 let msg = v4::MessageBuilder::new().queue_insert(item, None, *position);
                 self.conn.write(Opcode::Flatbuf, Some(&msg)).await?;
             }
+            Op::QueueInsertBatchV4 {
+                file_id,
+                count,
+                position,
+            } => {
+                let items = (0..*count)
+                    .map(|_| self.media_item_v4(*file_id))
+                    .collect::<Result<Vec<_>>>()?;
+                let msg = v4::MessageBuilder::new()
+                    .queue_insert_batch(items.into_iter().map(|it| (it, None)), *position);
+                self.conn.write(Opcode::Flatbuf, Some(&msg)).await?;
+            }
+            Op::FillQueueV4 { file_id } => {
+                let total = self.max_queue_items as usize;
+                let mut sent = 0;
+                while sent < total {
+                    let count = v4::QUEUE_PAGE_ITEMS.min(total - sent);
+                    let items = (0..count)
+                        .map(|_| self.media_item_v4(*file_id))
+                        .collect::<Result<Vec<_>>>()?
+                        .into_iter()
+                        .map(|it| (it, None));
+                    let msg = if sent == 0 {
+                        v4::MessageBuilder::new().load_queue(items, Some(0), false)
+                    } else {
+                        v4::MessageBuilder::new().queue_insert_batch(items, v4::QueuePosition::Back)
+                    };
+                    self.conn.write(Opcode::Flatbuf, Some(&msg)).await?;
+                    sent += count;
+                }
+                info!(total, "queue filled to the advertised cap");
+            }
             Op::QueueRemoveV4 { position } => {
                 let msg = v4::MessageBuilder::new().queue_remove(*position);
                 self.conn.write(Opcode::Flatbuf, Some(&msg)).await?;
             }
+            Op::QueueMoveV4 { from, to } => {
+                let msg = v4::MessageBuilder::new().queue_move(*from, *to);
+                self.conn.write(Opcode::Flatbuf, Some(&msg)).await?;
+            }
             Op::QueueSelectV4 { position } => {
                 let msg = v4::MessageBuilder::new().queue_select(*position);
                 self.conn.write(Opcode::Flatbuf, Some(&msg)).await?;
@@ -2267,6 +2312,8 @@ impl<'a> Engine<'a> {
                     false,
                     false,
                     0.01,
+                    false,
+                    None,
                 );
                 self.conn.write(Opcode::Flatbuf, Some(&msg)).await?;
             }
@@ -2405,6 +2452,7 @@ impl<'a> Engine<'a> {
                     thumbnail_url: None,
                     metadata: None,
                     extra_metadata: None,
+                    resume: false,
                 };
                 let msg = v4::MessageBuilder::new().load_single(item);
                 self.expect.companion_served = Some(*resource_id);
@@ -2433,6 +2481,7 @@ impl<'a> Engine<'a> {
                     thumbnail_url: None,
                     metadata: None,
                     extra_metadata: None,
+                    resume: false,
                 };
                 let msg = v4::MessageBuilder::new().load_single(item);
                 self.conn.write(Opcode::Flatbuf, Some(&msg)).await?;
@@ -2579,6 +2628,7 @@ impl<'a> Engine<'a> {
             thumbnail_url: None,
             metadata: None,
             extra_metadata: None,
+            resume: false,
         })
     }
 
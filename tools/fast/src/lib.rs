@@ -76,16 +76,31 @@ pub enum Send {
     },
     LoadQueueV4 {
         items: &'static [PlaylistItem],
-        start_index: Option<u8>,
+        start_index: Option<u32>,
         autoplay: bool,
     },
     QueueInsertV4 {
         file_id: u32,
         position: QueuePosition,
     },
+    /// A `QueueInsertBatch` of `count` copies of `file_id`.
+    QueueInsertBatchV4 {
+        file_id: u32,
+        count: u32,
+        position: QueuePosition,
+    },
+    /// Fill the queue up to the cap the receiver advertised, a page per
+    /// packet: a queue load, then `QueueInsertBatch`es at the back.
+    FillQueueV4 {
+        file_id: u32,
+    },
     QueueRemoveV4 {
         position: QueuePosition,
     },
+    QueueMoveV4 {
+        from: QueuePosition,
+        to: QueuePosition,
+    },
     QueueSelectV4 {
         position: QueuePosition,
     },
@@ -100,7 +115,7 @@ pub enum Send {
     LoadQueueRepeatV4 {
         file_id: u32,
         count: u32,
-        start_index: Option<u8>,
+        start_index: Option<u32>,
     },
     ErrorV4(ErrorKind),
     CompanionHelloResponseV4,
@@ -247,7 +262,7 @@ pub enum Step {
     /// Wait for a receiver-initiated `QueueItemSelected` broadcast naming
     /// this queue index (an autoplay or gapless advance).
     AwaitQueueSelect {
-        index: u8,
+        index: u32,
     },
     OpenSecondSender,
     SetSecondSenderInterval {
@@ -302,6 +317,7 @@ pub enum Step {
 pub enum QueueMutationKind {
     Insert,
     Remove,
+    Move,
     Select,
 }
 
@@ -367,6 +383,7 @@ cases!(
     cast_queue_select_no_load_v4,
     cast_queue_select_out_of_range_v4,
     cast_queue_remove_current_v4,
+    cast_queue_move_v4,
     cast_pause_resume_v4,
     cast_pause_during_load_v4,
     subtitle_change_and_disable_v4,
@@ -430,6 +447,8 @@ cases!(
     progress_interval_min_clamp_v4,
     queue_load_no_start_index_v4,
     queue_full_v4,
+    queue_batch_insert_full_v4,
+    queue_wide_index_v4,
     queue_insert_front_v4,
     queue_select_prefetched_v4,
     queue_select_prefetched_video_v4,
@@ -444,6 +463,7 @@ cases!(
     multi_sender_progress_isolation_v4,
     multi_sender_queue_insert_broadcast_v4,
     multi_sender_queue_remove_broadcast_v4,
+    multi_sender_queue_move_broadcast_v4,
     multi_sender_queue_select_broadcast_v4,
     multi_sender_stop_broadcast_v4,
     multi_sender_external_subs_v4,
@@ -1240,6 +1260,41 @@ define_test_case!(
     ]
 );
 
+define_test_case!(
+    cast_queue_move_v4,
+    &[
+        recv!(Receive::Version),
+        send!(Send::Version(4)),
+        send!(Send::SenderIntroduction),
+        recv!(Receive::ReceiverIntroduction),
+        serve!("image/flowers.jpg", 0, "image/jpeg"),
+        serve!("image/garden.jpg", 1, "image/jpeg"),
+        send!(Send::LoadQueueV4 {
+            items: &[PlaylistItem { file_id: 0 }, PlaylistItem { file_id: 1 }],
+            start_index: Some(0),
+            autoplay: false,
+        }),
+        Step::SleepMillis(500),
+        // Moving the playing item is allowed and does not reload it.
+        send!(Send::QueueMoveV4 {
+            from: QueuePosition::Front,
+            to: QueuePosition::Back,
+        }),
+        Step::SleepMillis(500),
+        send!(Send::QueueMoveV4 {
+            from: QueuePosition::Index(2),
+            to: QueuePosition::Front,
+        }),
+        recv!(Receive::Error(ErrorKind::QueuePositionOutOfRange)),
+        send!(Send::QueueMoveV4 {
+            from: QueuePosition::Front,
+            to: QueuePosition::Index(2),
+        }),
+        recv!(Receive::Error(ErrorKind::QueuePositionOutOfRange)),
+        send!(Send::StopV4),
+    ]
+);
+
 define_test_case!(
     cast_video_set_volume_v4,
     &[
@@ -2992,11 +3047,7 @@ define_test_case!(
         send!(Send::SenderIntroduction),
         recv!(Receive::ReceiverIntroduction),
         serve!("image/flowers.jpg", 0, "image/jpeg"),
-        send!(Send::LoadQueueRepeatV4 {
-            file_id: 0,
-            count: 256,
-            start_index: Some(0),
-        }),
+        send!(Send::FillQueueV4 { file_id: 0 }),
         Step::SleepMillis(500),
         send!(Send::QueueInsertV4 {
             file_id: 0,
@@ -3007,6 +3058,64 @@ define_test_case!(
     ]
 );
 
+define_test_case!(
+    queue_batch_insert_full_v4,
+    &[
+        recv!(Receive::Version),
+        send!(Send::Version(4)),
+        send!(Send::SenderIntroduction),
+        recv!(Receive::ReceiverIntroduction),
+        serve!("image/flowers.jpg", 0, "image/jpeg"),
+        send!(Send::FillQueueV4 { file_id: 0 }),
+        Step::SleepMillis(500),
+        send!(Send::QueueRemoveV4 {
+            position: QueuePosition::Back,
+        }),
+        Step::SleepMillis(500),
+        // One slot free: a batch of two is refused whole.
+        send!(Send::QueueInsertBatchV4 {
+            file_id: 0,
+            count: 2,
+            position: QueuePosition::Front,
+        }),
+        recv!(Receive::Error(ErrorKind::QueueFull)),
+        send!(Send::StopV4),
+    ]
+);
+
+define_test_case!(
+    queue_wide_index_v4,
+    &[
+        recv!(Receive::Version),
+        send!(Send::Version(4)),
+        send!(Send::SenderIntroduction),
+        recv!(Receive::ReceiverIntroduction),
+        serve!("image/flowers.jpg", 0, "image/jpeg"),
+        serve!("image/garden.jpg", 1, "image/jpeg"),
+        send!(Send::LoadQueueRepeatV4 {
+            file_id: 0,
+            count: 300,
+            start_index: Some(290),
+        }),
+        Step::SleepMillis(500),
+        send!(Send::QueueInsertBatchV4 {
+            file_id: 1,
+            count: 10,
+            position: QueuePosition::Index(280),
+        }),
+        Step::SleepMillis(500),
+        send!(Send::QueueSelectV4 {
+            position: QueuePosition::Index(309),
+        }),
+        Step::SleepMillis(500),
+        send!(Send::QueueSelectV4 {
+            position: QueuePosition::Index(310),
+        }),
+        recv!(Receive::Error(ErrorKind::QueuePositionOutOfRange)),
+        send!(Send::StopV4),
+    ]
+);
+
 define_test_case!(
     queue_insert_front_v4,
     &[
@@ -3349,6 +3458,31 @@ define_test_case!(
     ]
 );
 
+define_test_case!(
+    multi_sender_queue_move_broadcast_v4,
+    &[
+        recv!(Receive::Version),
+        send!(Send::Version(4)),
+        send!(Send::SenderIntroduction),
+        recv!(Receive::ReceiverIntroduction),
+        Step::OpenSecondSender,
+        serve!("image/flowers.jpg", 0, "image/jpeg"),
+        serve!("image/garden.jpg", 1, "image/jpeg"),
+        send!(Send::LoadQueueV4 {
+            items: &[PlaylistItem { file_id: 0 }, PlaylistItem { file_id: 1 }],
+            start_index: Some(0),
+            autoplay: false,
+        }),
+        Step::SleepMillis(500),
+        send!(Send::QueueMoveV4 {
+            from: QueuePosition::Back,
+            to: QueuePosition::Front,
+        }),
+        Step::ExpectQueueMutationOnSecondSender(QueueMutationKind::Move),
+        send!(Send::StopV4),
+    ]
+);
+
 define_test_case!(
     multi_sender_queue_select_broadcast_v4,
     &[
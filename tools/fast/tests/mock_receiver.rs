@@ -1128,6 +1128,8 @@ async fn v4_server_handshake(
         false,
         false,
         0.01,
+        false,
+        None,
     );
     send_flat(&mut tls, &intro).await;
     Some(tls)
@@ -1295,6 +1297,10 @@ async fn v4_queue_load_and_modify() {
         Step::Send(Send::QueueRemoveV4 {
             position: v4::QueuePosition::Index(0),
         }),
+        Step::Send(Send::QueueMoveV4 {
+            from: v4::QueuePosition::Back,
+            to: v4::QueuePosition::Front,
+        }),
         Step::Send(Send::QueueSelectV4 {
             position: v4::QueuePosition::Front,
         }),